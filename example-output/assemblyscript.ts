@@ -53,46 +53,202 @@ export class WasiMutSlice<T> {
     }
 }
 
-/**
- * ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
+/*
+* ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
  */
 
+// @ts-ignore: decorator
+@unmanaged
+export class Handle {
+    static fromRaw(handle: WasiHandle): Handle {
+        return changetype<Handle>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
 /**
  * Error codes.
  */
 export type CryptoErrno = u16;
 
 export namespace CryptoErrno {
+    /**
+     * Operation succeeded.
+     */
     export const SUCCESS: CryptoErrno = 0;
+    /**
+     * An error occurred when trying to during a conversion from a host type to a guest type.
+     *
+     * Only an internal bug can throw this error.
+     */
     export const GUEST_ERROR: CryptoErrno = 1;
+    /**
+     * The requested operation is valid, but not implemented by the host.
+     */
     export const NOT_IMPLEMENTED: CryptoErrno = 2;
+    /**
+     * The requested feature is not supported by the chosen algorithm.
+     */
     export const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    /**
+     * The requested operation is valid, but was administratively prohibited.
+     */
     export const PROHIBITED_OPERATION: CryptoErrno = 4;
+    /**
+     * Unsupported encoding for an import or export operation.
+     */
     export const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    /**
+     * The requested algorithm is not supported by the host.
+     */
     export const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    /**
+     * The requested option is not supported by the currently selected algorithm.
+     */
     export const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    /**
+     * An invalid or incompatible key was supplied.
+     *
+     * The key may not be valid, or was generated for a different algorithm or parameters set.
+     */
     export const INVALID_KEY: CryptoErrno = 8;
+    /**
+     * The currently selected algorithm doesn't support the requested output length.
+     *
+     * This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+     */
     export const INVALID_LENGTH: CryptoErrno = 9;
+    /**
+     * A signature or authentication tag verification failed.
+     */
     export const VERIFICATION_FAILED: CryptoErrno = 10;
+    /**
+     * A secure random numbers generator is not available.
+     *
+     * The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+     */
     export const RNG_ERROR: CryptoErrno = 11;
+    /**
+     * An error was returned by the underlying cryptography library.
+     *
+     * The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+     *
+     * Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+     *
+     * Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+     * This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+     */
     export const ALGORITHM_FAILURE: CryptoErrno = 12;
+    /**
+     * The supplied signature is invalid, or incompatible with the chosen algorithm.
+     */
     export const INVALID_SIGNATURE: CryptoErrno = 13;
+    /**
+     * An attempt was made to close a handle that was already closed.
+     */
     export const CLOSED: CryptoErrno = 14;
+    /**
+     * A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+     */
     export const INVALID_HANDLE: CryptoErrno = 15;
+    /**
+     * The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+     */
     export const OVERFLOW: CryptoErrno = 16;
+    /**
+     * An internal error occurred.
+     *
+     * This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+     */
     export const INTERNAL_ERROR: CryptoErrno = 17;
+    /**
+     * Too many handles are currently open, and a new one cannot be created.
+     *
+     * Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+     */
     export const TOO_MANY_HANDLES: CryptoErrno = 18;
+    /**
+     * A key was provided, but the chosen algorithm doesn't support keys.
+     *
+     * This is returned by symmetric operations.
+     *
+     * Many hash functions, in particular, do not support keys without being used in particular constructions.
+     * Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+     *
+     * These functions must refuse to create the context and return this error instead.
+     */
     export const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    /**
+     * A key is required for the chosen algorithm, but none was given.
+     */
     export const KEY_REQUIRED: CryptoErrno = 20;
+    /**
+     * The provided authentication tag is invalid or incompatible with the current algorithm.
+     *
+     * This error is returned by decryption functions and tag verification functions.
+     *
+     * Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+     */
     export const INVALID_TAG: CryptoErrno = 21;
+    /**
+     * The requested operation is incompatible with the current scheme.
+     *
+     * For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+     * This error code will be returned instead.
+     */
     export const INVALID_OPERATION: CryptoErrno = 22;
+    /**
+     * A nonce is required.
+     *
+     * Most encryption schemes require a nonce.
+     *
+     * In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+     * If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+     */
     export const NONCE_REQUIRED: CryptoErrno = 23;
+    /**
+     * The provided nonce doesn't have a correct size for the given cipher.
+     */
     export const INVALID_NONCE: CryptoErrno = 24;
+    /**
+     * The named option was not set.
+     *
+     * The caller tried to read the value of an option that was not set.
+     * This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+     */
     export const OPTION_NOT_SET: CryptoErrno = 25;
+    /**
+     * A key or key pair matching the requested identifier cannot be found using the supplied information.
+     *
+     * This error is returned by a secrets manager via the `keypair_from_id()` function.
+     */
     export const NOT_FOUND: CryptoErrno = 26;
+    /**
+     * The algorithm requires parameters that haven't been set.
+     *
+     * Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+     */
     export const PARAMETERS_MISSING: CryptoErrno = 27;
+    /**
+     * A requested computation is not done yet, and additional calls to the function are required.
+     *
+     * Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+     *
+     * In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+     */
     export const IN_PROGRESS: CryptoErrno = 28;
+    /**
+     * Multiple keys have been provided, but they do not share the same type.
+     *
+     * This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+     */
     export const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    /**
+     * A managed key or secret expired and cannot be used any more.
+     */
     export const EXPIRED: CryptoErrno = 30;
 }
 
@@ -102,9 +258,21 @@ export namespace CryptoErrno {
 export type KeypairEncoding = u16;
 
 export namespace KeypairEncoding {
+    /**
+     * Raw bytes.
+     */
     export const RAW: KeypairEncoding = 0;
-    export const PKCS_8: KeypairEncoding = 1;
+    /**
+     * PCSK8/DER encoding.
+     */
+    export const PKCS8: KeypairEncoding = 1;
+    /**
+     * PEM encoding.
+     */
     export const PEM: KeypairEncoding = 2;
+    /**
+     * Implementation-defined encoding.
+     */
     export const LOCAL: KeypairEncoding = 3;
 }
 
@@ -114,11 +282,29 @@ export namespace KeypairEncoding {
 export type PublickeyEncoding = u16;
 
 export namespace PublickeyEncoding {
+    /**
+     * Raw bytes.
+     */
     export const RAW: PublickeyEncoding = 0;
-    export const PKCS_8: PublickeyEncoding = 1;
+    /**
+     * PKCS8/DER encoding.
+     */
+    export const PKCS8: PublickeyEncoding = 1;
+    /**
+     * PEM encoding.
+     */
     export const PEM: PublickeyEncoding = 2;
+    /**
+     * SEC encoding.
+     */
     export const SEC: PublickeyEncoding = 3;
+    /**
+     * Compressed SEC encoding.
+     */
     export const COMPRESSED_SEC: PublickeyEncoding = 4;
+    /**
+     * Implementation-defined encoding.
+     */
     export const LOCAL: PublickeyEncoding = 5;
 }
 
@@ -128,11 +314,29 @@ export namespace PublickeyEncoding {
 export type SecretkeyEncoding = u16;
 
 export namespace SecretkeyEncoding {
+    /**
+     * Raw bytes.
+     */
     export const RAW: SecretkeyEncoding = 0;
-    export const PKCS_8: SecretkeyEncoding = 1;
+    /**
+     * PKCS8/DER encoding.
+     */
+    export const PKCS8: SecretkeyEncoding = 1;
+    /**
+     * PEM encoding.
+     */
     export const PEM: SecretkeyEncoding = 2;
+    /**
+     * SEC encoding.
+     */
     export const SEC: SecretkeyEncoding = 3;
+    /**
+     * Compressed SEC encoding.
+     */
     export const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    /**
+     * Implementation-defined encoding.
+     */
     export const LOCAL: SecretkeyEncoding = 5;
 }
 
@@ -142,7 +346,13 @@ export namespace SecretkeyEncoding {
 export type SignatureEncoding = u16;
 
 export namespace SignatureEncoding {
+    /**
+     * Raw bytes.
+     */
     export const RAW: SignatureEncoding = 0;
+    /**
+     * DER encoding.
+     */
     export const DER: SignatureEncoding = 1;
 }
 
@@ -189,7 +399,17 @@ export type U64 = u64;
  *
  * An `array_output` handle is automatically closed after its full content has been consumed.
  */
-export type ArrayOutput = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class ArrayOutput {
+    static fromRaw(handle: WasiHandle): ArrayOutput {
+        return changetype<ArrayOutput>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A set of options.
@@ -198,19 +418,49 @@ export type ArrayOutput = WasiHandle;
  *
  * The exact set of allowed options depends on the algorithm being used.
  */
-export type Options = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class Options {
+    static fromRaw(handle: WasiHandle): Options {
+        return changetype<Options>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A handle to the optional secrets management facilities offered by a host.
  *
  * This is used to generate, retrieve and invalidate managed keys.
  */
-export type SecretsManager = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class SecretsManager {
+    static fromRaw(handle: WasiHandle): SecretsManager {
+        return changetype<SecretsManager>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A key pair.
  */
-export type Keypair = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class Keypair {
+    static fromRaw(handle: WasiHandle): Keypair {
+        return changetype<Keypair>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A state to absorb data to be signed.
@@ -219,27 +469,77 @@ export type Keypair = WasiHandle;
  *
  * A subsequent signature would sign all the data accumulated since the creation of the state object.
  */
-export type SignatureState = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class SignatureState {
+    static fromRaw(handle: WasiHandle): SignatureState {
+        return changetype<SignatureState>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A signature.
  */
-export type Signature = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class Signature {
+    static fromRaw(handle: WasiHandle): Signature {
+        return changetype<Signature>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A public key, for key exchange and signature verification.
  */
-export type Publickey = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class Publickey {
+    static fromRaw(handle: WasiHandle): Publickey {
+        return changetype<Publickey>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A secret key, for key exchange mechanisms.
  */
-export type Secretkey = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class Secretkey {
+    static fromRaw(handle: WasiHandle): Secretkey {
+        return changetype<Secretkey>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A state to absorb signed data to be verified.
  */
-export type SignatureVerificationState = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class SignatureVerificationState {
+    static fromRaw(handle: WasiHandle): SignatureVerificationState {
+        return changetype<SignatureVerificationState>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A state to perform symmetric operations.
@@ -247,7 +547,17 @@ export type SignatureVerificationState = WasiHandle;
  * The state is not reset nor invalidated after an option has been performed.
  * Incremental updates and sessions are thus supported.
  */
-export type SymmetricState = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class SymmetricState {
+    static fromRaw(handle: WasiHandle): SymmetricState {
+        return changetype<SymmetricState>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * A symmetric key.
@@ -256,7 +566,17 @@ export type SymmetricState = WasiHandle;
  *
  * If it was imported, the host will wipe it from memory as soon as the handle is closed.
  */
-export type SymmetricKey = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class SymmetricKey {
+    static fromRaw(handle: WasiHandle): SymmetricKey {
+        return changetype<SymmetricKey>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * An authentication tag.
@@ -269,7 +589,17 @@ export type SymmetricKey = WasiHandle;
  *
  * The host is reponsible for securely wiping them from memory on close.
  */
-export type SymmetricTag = WasiHandle;
+// @ts-ignore: decorator
+@unmanaged
+export class SymmetricTag {
+    static fromRaw(handle: WasiHandle): SymmetricTag {
+        return changetype<SymmetricTag>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
 
 /**
  * Options index, only required by the Interface Types translation layer.
@@ -292,7 +622,6 @@ export class OptOptions {
     tag: u8;
     private __pad8_0: u8;
     private __pad16_0: u16;
-    private __pad32_0: u32;
 
     constructor(tag: u8) {
         this.tag = tag;
@@ -384,7 +713,6 @@ export class OptSymmetricKey {
     tag: u8;
     private __pad8_0: u8;
     private __pad16_0: u16;
-    private __pad32_0: u32;
 
     constructor(tag: u8) {
         this.tag = tag;
@@ -602,8 +930,8 @@ export declare function symmetricKeyId(
     symmetric_key: SymmetricKey,
     symmetric_key_id: WasiMutPtr<u8>,
     symmetric_key_id_max_len: Size,
-    result_0_ptr: WasiMutPtr<Size>,
-    result_1_ptr: WasiMutPtr<Version>
+    result0_ptr: WasiMutPtr<Size>,
+    result1_ptr: WasiMutPtr<Version>
 ): CryptoErrno;
 
 /**
@@ -0,0 +1,1534 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
+
+namespace wasi_ephemeral_crypto_symmetric {
+
+    enum class Handle : WasiHandle {};
+
+    /// Error codes.
+    enum class CryptoErrno : uint16_t {
+        /// Operation succeeded.
+        SUCCESS = 0,
+        /// An error occurred when trying to during a conversion from a host type to a guest type.
+        ///
+        /// Only an internal bug can throw this error.
+        GUEST_ERROR = 1,
+        /// The requested operation is valid, but not implemented by the host.
+        NOT_IMPLEMENTED = 2,
+        /// The requested feature is not supported by the chosen algorithm.
+        UNSUPPORTED_FEATURE = 3,
+        /// The requested operation is valid, but was administratively prohibited.
+        PROHIBITED_OPERATION = 4,
+        /// Unsupported encoding for an import or export operation.
+        UNSUPPORTED_ENCODING = 5,
+        /// The requested algorithm is not supported by the host.
+        UNSUPPORTED_ALGORITHM = 6,
+        /// The requested option is not supported by the currently selected algorithm.
+        UNSUPPORTED_OPTION = 7,
+        /// An invalid or incompatible key was supplied.
+        ///
+        /// The key may not be valid, or was generated for a different algorithm or parameters set.
+        INVALID_KEY = 8,
+        /// The currently selected algorithm doesn't support the requested output length.
+        ///
+        /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+        INVALID_LENGTH = 9,
+        /// A signature or authentication tag verification failed.
+        VERIFICATION_FAILED = 10,
+        /// A secure random numbers generator is not available.
+        ///
+        /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+        RNG_ERROR = 11,
+        /// An error was returned by the underlying cryptography library.
+        ///
+        /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+        ///
+        /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+        ///
+        /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+        /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+        ALGORITHM_FAILURE = 12,
+        /// The supplied signature is invalid, or incompatible with the chosen algorithm.
+        INVALID_SIGNATURE = 13,
+        /// An attempt was made to close a handle that was already closed.
+        CLOSED = 14,
+        /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+        INVALID_HANDLE = 15,
+        /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+        OVERFLOW = 16,
+        /// An internal error occurred.
+        ///
+        /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+        INTERNAL_ERROR = 17,
+        /// Too many handles are currently open, and a new one cannot be created.
+        ///
+        /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+        TOO_MANY_HANDLES = 18,
+        /// A key was provided, but the chosen algorithm doesn't support keys.
+        ///
+        /// This is returned by symmetric operations.
+        ///
+        /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+        /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+        ///
+        /// These functions must refuse to create the context and return this error instead.
+        KEY_NOT_SUPPORTED = 19,
+        /// A key is required for the chosen algorithm, but none was given.
+        KEY_REQUIRED = 20,
+        /// The provided authentication tag is invalid or incompatible with the current algorithm.
+        ///
+        /// This error is returned by decryption functions and tag verification functions.
+        ///
+        /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+        INVALID_TAG = 21,
+        /// The requested operation is incompatible with the current scheme.
+        ///
+        /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+        /// This error code will be returned instead.
+        INVALID_OPERATION = 22,
+        /// A nonce is required.
+        ///
+        /// Most encryption schemes require a nonce.
+        ///
+        /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+        /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+        NONCE_REQUIRED = 23,
+        /// The provided nonce doesn't have a correct size for the given cipher.
+        INVALID_NONCE = 24,
+        /// The named option was not set.
+        ///
+        /// The caller tried to read the value of an option that was not set.
+        /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+        OPTION_NOT_SET = 25,
+        /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+        ///
+        /// This error is returned by a secrets manager via the `keypair_from_id()` function.
+        NOT_FOUND = 26,
+        /// The algorithm requires parameters that haven't been set.
+        ///
+        /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+        PARAMETERS_MISSING = 27,
+        /// A requested computation is not done yet, and additional calls to the function are required.
+        ///
+        /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+        ///
+        /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+        IN_PROGRESS = 28,
+        /// Multiple keys have been provided, but they do not share the same type.
+        ///
+        /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+        INCOMPATIBLE_KEYS = 29,
+        /// A managed key or secret expired and cannot be used any more.
+        EXPIRED = 30,
+    };
+
+    /// Encoding to use for importing or exporting a key pair.
+    enum class KeypairEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// PCSK8/DER encoding.
+        PKCS8 = 1,
+        /// PEM encoding.
+        PEM = 2,
+        /// Implementation-defined encoding.
+        LOCAL = 3,
+    };
+
+    /// Encoding to use for importing or exporting a public key.
+    enum class PublickeyEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// PKCS8/DER encoding.
+        PKCS8 = 1,
+        /// PEM encoding.
+        PEM = 2,
+        /// SEC encoding.
+        SEC = 3,
+        /// Compressed SEC encoding.
+        COMPRESSED_SEC = 4,
+        /// Implementation-defined encoding.
+        LOCAL = 5,
+    };
+
+    /// Encoding to use for importing or exporting a secret key.
+    enum class SecretkeyEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// PKCS8/DER encoding.
+        PKCS8 = 1,
+        /// PEM encoding.
+        PEM = 2,
+        /// SEC encoding.
+        SEC = 3,
+        /// Compressed SEC encoding.
+        COMPRESSED_SEC = 4,
+        /// Implementation-defined encoding.
+        LOCAL = 5,
+    };
+
+    /// Encoding to use for importing or exporting a signature.
+    enum class SignatureEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// DER encoding.
+        DER = 1,
+    };
+
+    /// An algorithm category.
+    enum class AlgorithmType : uint16_t {
+        SIGNATURES = 0,
+        SYMMETRIC = 1,
+        KEY_EXCHANGE = 2,
+    };
+
+    /// Version of a managed key.
+    ///
+    /// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+    using Version = uint64_t;
+
+    /// Size of a value.
+    using Size = size_t;
+
+    /// A UNIX timestamp, in seconds since 01/01/1970.
+    using Timestamp = uint64_t;
+
+    /// A 64-bit value
+    using U64 = uint64_t;
+
+    /// Handle for functions returning output whose size may be large or not known in advance.
+    ///
+    /// An `array_output` object contains a host-allocated byte array.
+    ///
+    /// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+    /// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+    ///
+    /// An `array_output` handle is automatically closed after its full content has been consumed.
+    enum class ArrayOutput : WasiHandle {};
+
+    /// A set of options.
+    ///
+    /// This type is used to set non-default parameters.
+    ///
+    /// The exact set of allowed options depends on the algorithm being used.
+    enum class Options : WasiHandle {};
+
+    /// A handle to the optional secrets management facilities offered by a host.
+    ///
+    /// This is used to generate, retrieve and invalidate managed keys.
+    enum class SecretsManager : WasiHandle {};
+
+    /// A key pair.
+    enum class Keypair : WasiHandle {};
+
+    /// A state to absorb data to be signed.
+    ///
+    /// After a signature has been computed or verified, the state remains valid for further operations.
+    ///
+    /// A subsequent signature would sign all the data accumulated since the creation of the state object.
+    enum class SignatureState : WasiHandle {};
+
+    /// A signature.
+    enum class Signature : WasiHandle {};
+
+    /// A public key, for key exchange and signature verification.
+    enum class Publickey : WasiHandle {};
+
+    /// A secret key, for key exchange mechanisms.
+    enum class Secretkey : WasiHandle {};
+
+    /// A state to absorb signed data to be verified.
+    enum class SignatureVerificationState : WasiHandle {};
+
+    /// A state to perform symmetric operations.
+    ///
+    /// The state is not reset nor invalidated after an option has been performed.
+    /// Incremental updates and sessions are thus supported.
+    enum class SymmetricState : WasiHandle {};
+
+    /// A symmetric key.
+    ///
+    /// The key can be imported from raw bytes, or can be a reference to a managed key.
+    ///
+    /// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+    enum class SymmetricKey : WasiHandle {};
+
+    /// An authentication tag.
+    ///
+    /// This is an object returned by functions computing authentication tags.
+    ///
+    /// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+    ///
+    /// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+    ///
+    /// The host is reponsible for securely wiping them from memory on close.
+    enum class SymmetricTag : WasiHandle {};
+
+    /// Options index, only required by the Interface Types translation layer.
+    enum class OptOptionsU : uint8_t {
+        SOME = 0,
+        NONE = 1,
+    };
+
+    /// An optional options set.
+    ///
+    /// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+    struct OptOptions {
+        enum class Tag : uint8_t {
+            SOME = 0,
+            NONE = 1,
+        };
+
+        Tag tag;
+        union {
+            Options some;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- some: Options if tag=0
+
+        static OptOptions make_some(Options val) {
+            OptOptions tu{};
+            tu.tag = Tag::SOME;
+            tu.member.some = val;
+            return tu;
+        }
+
+        void emplace_some(Options val) {
+            tag = Tag::SOME;
+            member.some = val;
+        }
+
+        const Options *get_if_some() const {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        Options *get_if_some() {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        bool holds_some() const {
+            return tag == Tag::SOME;
+        }
+
+        // --- none: (no associated content) if tag=1
+
+        static OptOptions make_none() {
+            OptOptions tu{};
+            tu.tag = Tag::NONE;
+            return tu;
+        }
+
+        void emplace_none() {
+            tag = Tag::NONE;
+        }
+
+        bool holds_none() const {
+            return tag == Tag::NONE;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(OptOptions, member) == 4, "unexpected layout");
+    #endif
+
+    /// Symmetric key index, only required by the Interface Types translation layer.
+    enum class OptSymmetricKeyU : uint8_t {
+        SOME = 0,
+        NONE = 1,
+    };
+
+    /// An optional symmetric key.
+    ///
+    /// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+    struct OptSymmetricKey {
+        enum class Tag : uint8_t {
+            SOME = 0,
+            NONE = 1,
+        };
+
+        Tag tag;
+        union {
+            SymmetricKey some;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- some: SymmetricKey if tag=0
+
+        static OptSymmetricKey make_some(SymmetricKey val) {
+            OptSymmetricKey tu{};
+            tu.tag = Tag::SOME;
+            tu.member.some = val;
+            return tu;
+        }
+
+        void emplace_some(SymmetricKey val) {
+            tag = Tag::SOME;
+            member.some = val;
+        }
+
+        const SymmetricKey *get_if_some() const {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        SymmetricKey *get_if_some() {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        bool holds_some() const {
+            return tag == Tag::SOME;
+        }
+
+        // --- none: (no associated content) if tag=1
+
+        static OptSymmetricKey make_none() {
+            OptSymmetricKey tu{};
+            tu.tag = Tag::NONE;
+            return tu;
+        }
+
+        void emplace_none() {
+            tag = Tag::NONE;
+        }
+
+        bool holds_none() const {
+            return tag == Tag::NONE;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(OptSymmetricKey, member) == 4, "unexpected layout");
+    #endif
+
+    namespace raw {
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_generate")
+        CryptoErrno symmetric_key_generate(
+            WasiPtr<Char8> algorithm_ptr,
+            size_t algorithm_len,
+            OptOptions options,
+            WasiMutPtr<SymmetricKey> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_import")
+        CryptoErrno symmetric_key_import(
+            WasiPtr<Char8> algorithm_ptr,
+            size_t algorithm_len,
+            WasiPtr<uint8_t> raw,
+            Size raw_len,
+            WasiMutPtr<SymmetricKey> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_export")
+        CryptoErrno symmetric_key_export(
+            SymmetricKey symmetric_key,
+            WasiMutPtr<ArrayOutput> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_close")
+        CryptoErrno symmetric_key_close(
+            SymmetricKey symmetric_key
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_generate_managed")
+        CryptoErrno symmetric_key_generate_managed(
+            SecretsManager secrets_manager,
+            WasiPtr<Char8> algorithm_ptr,
+            size_t algorithm_len,
+            OptOptions options,
+            WasiMutPtr<SymmetricKey> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_store_managed")
+        CryptoErrno symmetric_key_store_managed(
+            SecretsManager secrets_manager,
+            SymmetricKey symmetric_key,
+            WasiMutPtr<uint8_t> symmetric_key_id,
+            Size symmetric_key_id_max_len
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_replace_managed")
+        CryptoErrno symmetric_key_replace_managed(
+            SecretsManager secrets_manager,
+            SymmetricKey symmetric_key_old,
+            SymmetricKey symmetric_key_new,
+            WasiMutPtr<Version> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_id")
+        CryptoErrno symmetric_key_id(
+            SymmetricKey symmetric_key,
+            WasiMutPtr<uint8_t> symmetric_key_id,
+            Size symmetric_key_id_max_len,
+            WasiMutPtr<Size> result0_ptr,
+            WasiMutPtr<Version> result1_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_key_from_id")
+        CryptoErrno symmetric_key_from_id(
+            SecretsManager secrets_manager,
+            WasiPtr<uint8_t> symmetric_key_id,
+            Size symmetric_key_id_len,
+            Version symmetric_key_version,
+            WasiMutPtr<SymmetricKey> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_open")
+        CryptoErrno symmetric_state_open(
+            WasiPtr<Char8> algorithm_ptr,
+            size_t algorithm_len,
+            OptSymmetricKey key,
+            OptOptions options,
+            WasiMutPtr<SymmetricState> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_options_get")
+        CryptoErrno symmetric_state_options_get(
+            SymmetricState handle,
+            WasiPtr<Char8> name_ptr,
+            size_t name_len,
+            WasiMutPtr<uint8_t> value,
+            Size value_max_len,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_options_get_u64")
+        CryptoErrno symmetric_state_options_get_u64(
+            SymmetricState handle,
+            WasiPtr<Char8> name_ptr,
+            size_t name_len,
+            WasiMutPtr<U64> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_close")
+        CryptoErrno symmetric_state_close(
+            SymmetricState handle
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_absorb")
+        CryptoErrno symmetric_state_absorb(
+            SymmetricState handle,
+            WasiPtr<uint8_t> data,
+            Size data_len
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_squeeze")
+        CryptoErrno symmetric_state_squeeze(
+            SymmetricState handle,
+            WasiMutPtr<uint8_t> out,
+            Size out_len
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_squeeze_tag")
+        CryptoErrno symmetric_state_squeeze_tag(
+            SymmetricState handle,
+            WasiMutPtr<SymmetricTag> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_squeeze_key")
+        CryptoErrno symmetric_state_squeeze_key(
+            SymmetricState handle,
+            WasiPtr<Char8> alg_str_ptr,
+            size_t alg_str_len,
+            WasiMutPtr<SymmetricKey> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_max_tag_len")
+        CryptoErrno symmetric_state_max_tag_len(
+            SymmetricState handle,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_encrypt")
+        CryptoErrno symmetric_state_encrypt(
+            SymmetricState handle,
+            WasiMutPtr<uint8_t> out,
+            Size out_len,
+            WasiPtr<uint8_t> data,
+            Size data_len,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_encrypt_detached")
+        CryptoErrno symmetric_state_encrypt_detached(
+            SymmetricState handle,
+            WasiMutPtr<uint8_t> out,
+            Size out_len,
+            WasiPtr<uint8_t> data,
+            Size data_len,
+            WasiMutPtr<SymmetricTag> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_decrypt")
+        CryptoErrno symmetric_state_decrypt(
+            SymmetricState handle,
+            WasiMutPtr<uint8_t> out,
+            Size out_len,
+            WasiPtr<uint8_t> data,
+            Size data_len,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_decrypt_detached")
+        CryptoErrno symmetric_state_decrypt_detached(
+            SymmetricState handle,
+            WasiMutPtr<uint8_t> out,
+            Size out_len,
+            WasiPtr<uint8_t> data,
+            Size data_len,
+            WasiPtr<uint8_t> raw_tag,
+            Size raw_tag_len,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_state_ratchet")
+        CryptoErrno symmetric_state_ratchet(
+            SymmetricState handle
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_tag_len")
+        CryptoErrno symmetric_tag_len(
+            SymmetricTag symmetric_tag,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_tag_pull")
+        CryptoErrno symmetric_tag_pull(
+            SymmetricTag symmetric_tag,
+            WasiMutPtr<uint8_t> buf,
+            Size buf_len,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_tag_verify")
+        CryptoErrno symmetric_tag_verify(
+            SymmetricTag symmetric_tag,
+            WasiPtr<uint8_t> expected_raw_tag_ptr,
+            Size expected_raw_tag_len
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_symmetric", "symmetric_tag_close")
+        CryptoErrno symmetric_tag_close(
+            SymmetricTag symmetric_tag
+        );
+
+    } // namespace raw
+
+    /// Generate a new symmetric key for a given algorithm.
+    ///
+    /// `options` can be `None` to use the default parameters, or an algoritm-specific set of parameters to override.
+    ///
+    /// This function may return `unsupported_feature` if key generation is not supported by the host for the chosen algorithm, or `unsupported_algorithm` if the algorithm is not supported by the host.
+    inline WasiExpected<SymmetricKey, CryptoErrno> symmetric_key_generate(WasiString algorithm, OptOptions options) {
+        SymmetricKey result_ptr{};
+        const CryptoErrno res = raw::symmetric_key_generate(algorithm.ptr, algorithm.len, options, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricKey, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricKey, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Create a symmetric key from raw material.
+    ///
+    /// The algorithm is internally stored along with the key, and trying to use the key with an operation expecting a different algorithm will return `invalid_key`.
+    ///
+    /// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+    inline WasiExpected<SymmetricKey, CryptoErrno> symmetric_key_import(WasiString algorithm, WasiPtr<uint8_t> raw, Size raw_len) {
+        SymmetricKey result_ptr{};
+        const CryptoErrno res = raw::symmetric_key_import(algorithm.ptr, algorithm.len, raw, raw_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricKey, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricKey, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Export a symmetric key as raw material.
+    ///
+    /// This is mainly useful to export a managed key.
+    ///
+    /// May return `prohibited_operation` if this operation is denied.
+    inline WasiExpected<ArrayOutput, CryptoErrno> symmetric_key_export(SymmetricKey symmetric_key) {
+        ArrayOutput result_ptr{};
+        const CryptoErrno res = raw::symmetric_key_export(symmetric_key, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<ArrayOutput, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<ArrayOutput, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Destroy a symmetric key.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    inline WasiExpected<void, CryptoErrno> symmetric_key_close(SymmetricKey symmetric_key) {
+        const CryptoErrno res = raw::symmetric_key_close(symmetric_key);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// __(optional)__
+    /// Generate a new managed symmetric key.
+    ///
+    /// The key is generated and stored by the secrets management facilities.
+    ///
+    /// It may be used through its identifier, but the host may not allow it to be exported.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+    /// or `unsupported_algorithm` if a key cannot be created for the chosen algorithm.
+    ///
+    /// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+    ///
+    /// This is also an optional import, meaning that the function may not even exist.
+    inline WasiExpected<SymmetricKey, CryptoErrno> symmetric_key_generate_managed(SecretsManager secrets_manager, WasiString algorithm, OptOptions options) {
+        SymmetricKey result_ptr{};
+        const CryptoErrno res = raw::symmetric_key_generate_managed(secrets_manager, algorithm.ptr, algorithm.len, options, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricKey, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricKey, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// __(optional)__
+    /// Store a symmetric key into the secrets manager.
+    ///
+    /// On success, the function stores the key identifier into `$symmetric_key_id`,
+    /// into which up to `$symmetric_key_id_max_len` can be written.
+    ///
+    /// The function returns `overflow` if the supplied buffer is too small.
+    inline WasiExpected<void, CryptoErrno> symmetric_key_store_managed(SecretsManager secrets_manager, SymmetricKey symmetric_key, WasiMutPtr<uint8_t> symmetric_key_id, Size symmetric_key_id_max_len) {
+        const CryptoErrno res = raw::symmetric_key_store_managed(secrets_manager, symmetric_key, symmetric_key_id, symmetric_key_id_max_len);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// __(optional)__
+    /// Replace a managed symmetric key.
+    ///
+    /// This function crates a new version of a managed symmetric key, by replacing `$kp_old` with `$kp_new`.
+    ///
+    /// It does several things:
+    ///
+    /// - The key identifier for `$symmetric_key_new` is set to the one of `$symmetric_key_old`.
+    /// - A new, unique version identifier is assigned to `$kp_new`. This version will be equivalent to using `$version_latest` until the key is replaced.
+    /// - The `$symmetric_key_old` handle is closed.
+    ///
+    /// Both keys must share the same algorithm and have compatible parameters. If this is not the case, `incompatible_keys` is returned.
+    ///
+    /// The function may also return the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+    /// or if keys cannot be rotated.
+    ///
+    /// Finally, `prohibited_operation` can be returned if `$symmetric_key_new` wasn't created by the secrets manager, and the secrets manager prohibits imported keys.
+    ///
+    /// If the operation succeeded, the new version is returned.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    inline WasiExpected<Version, CryptoErrno> symmetric_key_replace_managed(SecretsManager secrets_manager, SymmetricKey symmetric_key_old, SymmetricKey symmetric_key_new) {
+        Version result_ptr{};
+        const CryptoErrno res = raw::symmetric_key_replace_managed(secrets_manager, symmetric_key_old, symmetric_key_new, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Version, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Version, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// __(optional)__
+    /// Return the key identifier and version of a managed symmetric key.
+    ///
+    /// If the key is not managed, `unsupported_feature` is returned instead.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    inline WasiExpected<std::tuple<Size, Version>, CryptoErrno> symmetric_key_id(SymmetricKey symmetric_key, WasiMutPtr<uint8_t> symmetric_key_id, Size symmetric_key_id_max_len) {
+        Size result0_ptr{};
+        Version result1_ptr{};
+        const CryptoErrno res = raw::symmetric_key_id(symmetric_key, symmetric_key_id, symmetric_key_id_max_len, &result0_ptr, &result1_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<std::tuple<Size, Version>, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<std::tuple<Size, Version>, CryptoErrno>::from_value(std::make_tuple(result0_ptr, result1_ptr));
+    }
+
+    /// __(optional)__
+    /// Return a managed symmetric key from a key identifier.
+    ///
+    /// `kp_version` can be set to `version_latest` to retrieve the most recent version of a symmetric key.
+    ///
+    /// If no key matching the provided information is found, `not_found` is returned instead.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    inline WasiExpected<SymmetricKey, CryptoErrno> symmetric_key_from_id(SecretsManager secrets_manager, WasiPtr<uint8_t> symmetric_key_id, Size symmetric_key_id_len, Version symmetric_key_version) {
+        SymmetricKey result_ptr{};
+        const CryptoErrno res = raw::symmetric_key_from_id(secrets_manager, symmetric_key_id, symmetric_key_id_len, symmetric_key_version, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricKey, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricKey, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Create a new state to aborb and produce data using symmetric operations.
+    ///
+    /// The state remains valid after every operation in order to support incremental updates.
+    ///
+    /// The function has two optional parameters: a key and an options set.
+    ///
+    /// It will fail with a `key_not_supported` error code if a key was provided but the chosen algorithm doesn't natively support keying.
+    ///
+    /// On the other hand, if a key is required, but was not provided, a `key_required` error will be thrown.
+    ///
+    /// Some algorithms may require additional parameters. They have to be supplied as an options set:
+    ///
+    /// ```rust
+    /// let options_handle = ctx.options_open()?;
+    /// ctx.options_set("context", b"My application")?;
+    /// ctx.options_set_u64("fanout", 16)?;
+    /// let state_handle = ctx.symmetric_state_open("BLAKE2b-512", None, Some(options_handle))?;
+    /// ```
+    ///
+    /// If some parameters are mandatory but were not set, the `parameters_missing` error code will be returned.
+    ///
+    /// A notable exception is the `nonce` parameter, that is common to most AEAD constructions.
+    ///
+    /// If a nonce is required but was not supplied:
+    ///
+    /// - If it is safe to do so, the host will automatically generate a nonce. This is true for nonces that are large enough to be randomly generated, or if the host is able to maintain a global counter.
+    /// - If not, the function will fail and return the dedicated `nonce_required` error code.
+    ///
+    /// A nonce that was automatically generated can be retrieved after the function returns with `symmetric_state_get(state_handle, "nonce")`.
+    ///
+    /// **Sample usage patterns:**
+    ///
+    /// - **Hashing**
+    ///
+    /// ```rust
+    /// let mut out = [0u8; 64];
+    /// let state_handle = ctx.symmetric_state_open("SHAKE-128", None, None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+    /// ```
+    ///
+    /// - **MAC**
+    ///
+    /// ```rust
+    /// let mut raw_tag = [0u8; 64];
+    /// let key_handle = ctx.symmetric_key_import("HMAC/SHA-512", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+    /// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// ctx.symmetric_tag_pull(computed_tag_handle, &mut raw_tag)?;
+    /// ```
+    ///
+    /// Verification:
+    ///
+    /// ```rust
+    /// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+    /// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+    /// ```
+    ///
+    /// - **Tuple hashing**
+    ///
+    /// ```rust
+    /// let mut out = [0u8; 64];
+    /// let state_handle = ctx.symmetric_state_open("TupleHashXOF256", None, None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"value 1")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"value 2")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"value 3")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+    /// ```
+    /// Unlike MACs and regular hash functions, inputs are domain separated instead of being concatenated.
+    ///
+    /// - **Key derivation using extract-and-expand**
+    ///
+    /// Extract:
+    ///
+    /// ```rust
+    /// let mut prk = vec![0u8; 64];
+    /// let key_handle = ctx.symmetric_key_import("HKDF-EXTRACT/SHA-512", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("HKDF-EXTRACT/SHA-512", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"salt")?;
+    /// let prk_handle = ctx.symmetric_state_squeeze_key(state_handle, "HKDF-EXPAND/SHA-512")?;
+    /// ```
+    ///
+    /// Expand:
+    ///
+    /// ```rust
+    /// let mut subkey = vec![0u8; 32];
+    /// let state_handle = ctx.symmetric_state_open("HKDF-EXPAND/SHA-512", Some(prk_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"info")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut subkey)?;
+    /// ```
+    ///
+    /// - **Key derivation using a XOF**
+    ///
+    /// ```rust
+    /// let mut subkey1 = vec![0u8; 32];
+    /// let mut subkey2 = vec![0u8; 32];
+    /// let key_handle = ctx.symmetric_key_import("BLAKE3", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("BLAKE3", Some(key_handle), None)?;
+    /// ctx.symmetric_absorb(state_handle, b"context")?;
+    /// ctx.squeeze(state_handle, &mut subkey1)?;
+    /// ctx.squeeze(state_handle, &mut subkey2)?;
+    /// ```
+    ///
+    /// - **Password hashing**
+    ///
+    /// ```rust
+    /// let mut memory = vec![0u8; 1_000_000_000];
+    /// let options_handle = ctx.symmetric_options_open()?;
+    /// ctx.symmetric_options_set_guest_buffer(options_handle, "memory", &mut memory)?;
+    /// ctx.symmetric_options_set_u64(options_handle, "opslimit", 5)?;
+    /// ctx.symmetric_options_set_u64(options_handle, "parallelism", 8)?;
+    ///
+    /// let state_handle = ctx.symmetric_state_open("ARGON2-ID-13", None, Some(options))?;
+    /// ctx.symmtric_state_absorb(state_handle, b"password")?;
+    ///
+    /// let pw_str_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// let mut pw_str = vec![0u8; ctx.symmetric_tag_len(pw_str_handle)?];
+    /// ctx.symmetric_tag_pull(pw_str_handle, &mut pw_str)?;
+    /// ```
+    ///
+    /// - **AEAD encryption with an explicit nonce**
+    ///
+    /// ```rust
+    /// let key_handle = ctx.symmetric_key_generate("AES-256-GCM", None)?;
+    /// let message = b"test";
+    ///
+    /// let options_handle = ctx.symmetric_options_open()?;
+    /// ctx.symmetric_options_set(options_handle, "nonce", nonce)?;
+    ///
+    /// let state_handle = ctx.symmetric_state_open("AES-256-GCM", Some(key_handle), Some(options_handle))?;
+    /// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+    /// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+    /// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+    /// ```
+    ///
+    /// - **AEAD encryption with automatic nonce generation**
+    ///
+    /// ```rust
+    /// let key_handle = ctx.symmetric_key_generate("AES-256-GCM-SIV", None)?;
+    /// let message = b"test";
+    /// let mut nonce = [0u8; 24];
+    ///
+    /// let state_handle = ctx.symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), None)?;
+    ///
+    /// let nonce_handle = ctx.symmetric_state_options_get(state_handle, "nonce")?;
+    /// ctx.array_output_pull(nonce_handle, &mut nonce)?;
+    ///
+    /// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+    /// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+    /// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+    /// ```
+    ///
+    /// - **Session authenticated modes**
+    ///
+    /// ```rust
+    /// let mut out = [0u8; 16];
+    /// let mut out2 = [0u8; 16];
+    /// let mut ciphertext = [0u8; 20];
+    /// let key_handle = ctx.symmetric_key_generate("Xoodyak-128", None)?;
+    /// let state_handle = ctx.symmetric_state_open("Xoodyak-128", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, b"abcd")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out2)?;
+    /// ctx.symmetric_state_ratchet(state_handle)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+    /// let next_key_handle = ctx.symmetric_state_squeeze_key(state_handle, "Xoodyak-128")?;
+    /// // ...
+    /// ```
+    inline WasiExpected<SymmetricState, CryptoErrno> symmetric_state_open(WasiString algorithm, OptSymmetricKey key, OptOptions options) {
+        SymmetricState result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_open(algorithm.ptr, algorithm.len, key, options, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricState, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricState, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Retrieve a parameter from the current state.
+    ///
+    /// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+    ///
+    /// The function may return `options_not_set` if an option was not set, which is different from an empty value.
+    ///
+    /// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+    inline WasiExpected<Size, CryptoErrno> symmetric_state_options_get(SymmetricState handle, WasiString name, WasiMutPtr<uint8_t> value, Size value_max_len) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_options_get(handle, name.ptr, name.len, value, value_max_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Retrieve an integer parameter from the current state.
+    ///
+    /// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+    ///
+    /// The function may return `options_not_set` if an option was not set.
+    ///
+    /// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+    inline WasiExpected<U64, CryptoErrno> symmetric_state_options_get_u64(SymmetricState handle, WasiString name) {
+        U64 result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_options_get_u64(handle, name.ptr, name.len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<U64, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<U64, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Destroy a symmetric state.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    inline WasiExpected<void, CryptoErrno> symmetric_state_close(SymmetricState handle) {
+        const CryptoErrno res = raw::symmetric_state_close(handle);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Absorb data into the state.
+    ///
+    /// - **Hash functions:** adds data to be hashed.
+    /// - **MAC functions:** adds data to be authenticated.
+    /// - **Tuplehash-like constructions:** adds a new tuple to the state.
+    /// - **Key derivation functions:** adds to the IKM or to the subkey information.
+    /// - **AEAD constructions:** adds additional data to be authenticated.
+    /// - **Stateful hash objects, permutation-based constructions:** absorbs.
+    ///
+    /// If the chosen algorithm doesn't accept input data, the `invalid_operation` error code is returned.
+    ///
+    /// If too much data has been fed for the algorithm, `overflow` may be thrown.
+    inline WasiExpected<void, CryptoErrno> symmetric_state_absorb(SymmetricState handle, WasiPtr<uint8_t> data, Size data_len) {
+        const CryptoErrno res = raw::symmetric_state_absorb(handle, data, data_len);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Squeeze bytes from the state.
+    ///
+    /// - **Hash functions:** this tries to output an `out_len` bytes digest from the absorbed data. The hash function output will be truncated if necessary. If the requested size is too large, the `invalid_len` error code is returned.
+    /// - **Key derivation functions:** : outputs an arbitrary-long derived key.
+    /// - **RNGs, DRBGs, stream ciphers:**: outputs arbitrary-long data.
+    /// - **Stateful hash objects, permutation-based constructions:** squeeze.
+    ///
+    /// Other kinds of algorithms may return `invalid_operation` instead.
+    ///
+    /// For password-stretching functions, the function may return `in_progress`.
+    /// In that case, the guest should retry with the same parameters until the function completes.
+    inline WasiExpected<void, CryptoErrno> symmetric_state_squeeze(SymmetricState handle, WasiMutPtr<uint8_t> out, Size out_len) {
+        const CryptoErrno res = raw::symmetric_state_squeeze(handle, out, out_len);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Compute and return a tag for all the data injected into the state so far.
+    ///
+    /// - **MAC functions**: returns a tag authenticating the absorbed data.
+    /// - **Tuplehash-like constructions:** returns a tag authenticating all the absorbed tuples.
+    /// - **Password-hashing functions:** returns a standard string containing all the required parameters for password verification.
+    ///
+    /// Other kinds of algorithms may return `invalid_operation` instead.
+    ///
+    /// For password-stretching functions, the function may return `in_progress`.
+    /// In that case, the guest should retry with the same parameters until the function completes.
+    inline WasiExpected<SymmetricTag, CryptoErrno> symmetric_state_squeeze_tag(SymmetricState handle) {
+        SymmetricTag result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_squeeze_tag(handle, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricTag, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricTag, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Use the current state to produce a key for a target algorithm.
+    ///
+    /// For extract-then-expand constructions, this returns the PRK.
+    /// For session-base authentication encryption, this returns a key that can be used to resume a session without storing a nonce.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting this operation.
+    inline WasiExpected<SymmetricKey, CryptoErrno> symmetric_state_squeeze_key(SymmetricState handle, WasiString alg_str) {
+        SymmetricKey result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_squeeze_key(handle, alg_str.ptr, alg_str.len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricKey, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricKey, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Return the maximum length of an authentication tag for the current algorithm.
+    ///
+    /// This allows guests to compute the size required to store a ciphertext along with its authentication tag.
+    ///
+    /// The returned length may include the encryption mode's padding requirements in addition to the actual tag.
+    ///
+    /// For an encryption operation, the size of the output buffer should be `input_len + symmetric_state_max_tag_len()`.
+    ///
+    /// For a decryption operation, the size of the buffer that will store the decrypted data must be `ciphertext_len - symmetric_state_max_tag_len()`.
+    inline WasiExpected<Size, CryptoErrno> symmetric_state_max_tag_len(SymmetricState handle) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_max_tag_len(handle, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Encrypt data with an attached tag.
+    ///
+    /// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+    /// - **AEAD:** encrypts `data` into `out`, including the authentication tag to the output. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The `symmetric_state_max_tag_len()` function can be used to retrieve the overhead of adding the tag, as well as padding if necessary.
+    /// - **SHOE, Xoodyak, Strobe:** encrypts data, squeezes a tag and appends it to the output.
+    ///
+    /// If `out` and `data` are the same address, encryption may happen in-place.
+    ///
+    /// The function returns the actual size of the ciphertext along with the tag.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    inline WasiExpected<Size, CryptoErrno> symmetric_state_encrypt(SymmetricState handle, WasiMutPtr<uint8_t> out, Size out_len, WasiPtr<uint8_t> data, Size data_len) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_encrypt(handle, out, out_len, data, data_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Encrypt data, with a detached tag.
+    ///
+    /// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+    /// - **AEAD:** encrypts `data` into `out` and returns the tag separately. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The output and input buffers must be of the same length.
+    /// - **SHOE, Xoodyak, Strobe:** encrypts data and squeezes a tag.
+    ///
+    /// If `out` and `data` are the same address, encryption may happen in-place.
+    ///
+    /// The function returns the tag.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    inline WasiExpected<SymmetricTag, CryptoErrno> symmetric_state_encrypt_detached(SymmetricState handle, WasiMutPtr<uint8_t> out, Size out_len, WasiPtr<uint8_t> data, Size data_len) {
+        SymmetricTag result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_encrypt_detached(handle, out, out_len, data, data_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SymmetricTag, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SymmetricTag, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+    /// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+    /// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the one that was appended to the ciphertext.
+    ///
+    /// If `out` and `data` are the same address, decryption may happen in-place.
+    ///
+    /// `out_len` must be exactly `data_len` + `max_tag_len` bytes.
+    ///
+    /// The function returns the actual size of the decrypted message, which can be smaller than `out_len` for modes that requires padding.
+    ///
+    /// `invalid_tag` is returned if the tag didn't verify.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    inline WasiExpected<Size, CryptoErrno> symmetric_state_decrypt(SymmetricState handle, WasiMutPtr<uint8_t> out, Size out_len, WasiPtr<uint8_t> data, Size data_len) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_decrypt(handle, out, out_len, data, data_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+    /// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+    /// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the expected one.
+    ///
+    /// `raw_tag` is the expected tag, as raw bytes.
+    ///
+    /// `out` and `data` be must have the same length.
+    /// If they also share the same address, decryption may happen in-place.
+    ///
+    /// The function returns the actual size of the decrypted message.
+    ///
+    /// `invalid_tag` is returned if the tag verification failed.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    inline WasiExpected<Size, CryptoErrno> symmetric_state_decrypt_detached(SymmetricState handle, WasiMutPtr<uint8_t> out, Size out_len, WasiPtr<uint8_t> data, Size data_len, WasiPtr<uint8_t> raw_tag, Size raw_tag_len) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::symmetric_state_decrypt_detached(handle, out, out_len, data, data_len, raw_tag, raw_tag_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Make it impossible to recover the previous state.
+    ///
+    /// This operation is supported by some systems keeping a rolling state over an entire session, for forward security.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting ratcheting.
+    inline WasiExpected<void, CryptoErrno> symmetric_state_ratchet(SymmetricState handle) {
+        const CryptoErrno res = raw::symmetric_state_ratchet(handle);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Return the length of an authentication tag.
+    ///
+    /// This function can be used by a guest to allocate the correct buffer size to copy a computed authentication tag.
+    inline WasiExpected<Size, CryptoErrno> symmetric_tag_len(SymmetricTag symmetric_tag) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::symmetric_tag_len(symmetric_tag, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Copy an authentication tag into a guest-allocated buffer.
+    ///
+    /// The handle automatically becomes invalid after this operation. Manually closing it is not required.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let mut raw_tag = [0u8; 16];
+    /// ctx.symmetric_tag_pull(raw_tag_handle, &mut raw_tag)?;
+    /// ```
+    ///
+    /// The function returns `overflow` if the supplied buffer is too small to copy the tag.
+    ///
+    /// Otherwise, it returns the number of bytes that have been copied.
+    inline WasiExpected<Size, CryptoErrno> symmetric_tag_pull(SymmetricTag symmetric_tag, WasiMutPtr<uint8_t> buf, Size buf_len) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::symmetric_tag_pull(symmetric_tag, buf, buf_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Verify that a computed authentication tag matches the expected value, in constant-time.
+    ///
+    /// The expected tag must be provided as a raw byte string.
+    ///
+    /// The function returns `invalid_tag` if the tags don't match.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let key_handle = ctx.symmetric_key_import("HMAC/SHA-256", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("HMAC/SHA-256", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+    /// ```
+    inline WasiExpected<void, CryptoErrno> symmetric_tag_verify(SymmetricTag symmetric_tag, WasiPtr<uint8_t> expected_raw_tag_ptr, Size expected_raw_tag_len) {
+        const CryptoErrno res = raw::symmetric_tag_verify(symmetric_tag, expected_raw_tag_ptr, expected_raw_tag_len);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Explicitly destroy an unused authentication tag.
+    ///
+    /// This is usually not necessary, as `symmetric_tag_pull()` automatically closes a tag after it has been copied.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    inline WasiExpected<void, CryptoErrno> symmetric_tag_close(SymmetricTag symmetric_tag) {
+        const CryptoErrno res = raw::symmetric_tag_close(symmetric_tag);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Owned `SymmetricState` handle, closed with `symmetric_state_close()` when dropped.
+    class UniqueSymmetricState {
+      public:
+        explicit UniqueSymmetricState(SymmetricState handle) noexcept : handle_(handle), valid_(true) {}
+        UniqueSymmetricState(const UniqueSymmetricState &) = delete;
+        UniqueSymmetricState &operator=(const UniqueSymmetricState &) = delete;
+
+        UniqueSymmetricState(UniqueSymmetricState &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {
+            other.valid_ = false;
+        }
+
+        UniqueSymmetricState &operator=(UniqueSymmetricState &&other) noexcept {
+            if (this != &other) {
+                reset();
+                handle_ = other.handle_;
+                valid_ = other.valid_;
+                other.valid_ = false;
+            }
+            return *this;
+        }
+
+        ~UniqueSymmetricState() {
+            reset();
+        }
+
+        SymmetricState get() const noexcept {
+            return handle_;
+        }
+
+        SymmetricState release() noexcept {
+            valid_ = false;
+            return handle_;
+        }
+
+        void reset() noexcept {
+            if (valid_) {
+                (void)raw::symmetric_state_close(handle_);
+                valid_ = false;
+            }
+        }
+
+      private:
+        SymmetricState handle_;
+        bool valid_;
+    };
+
+    /// Owned `SymmetricKey` handle, closed with `symmetric_key_close()` when dropped.
+    class UniqueSymmetricKey {
+      public:
+        explicit UniqueSymmetricKey(SymmetricKey handle) noexcept : handle_(handle), valid_(true) {}
+        UniqueSymmetricKey(const UniqueSymmetricKey &) = delete;
+        UniqueSymmetricKey &operator=(const UniqueSymmetricKey &) = delete;
+
+        UniqueSymmetricKey(UniqueSymmetricKey &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {
+            other.valid_ = false;
+        }
+
+        UniqueSymmetricKey &operator=(UniqueSymmetricKey &&other) noexcept {
+            if (this != &other) {
+                reset();
+                handle_ = other.handle_;
+                valid_ = other.valid_;
+                other.valid_ = false;
+            }
+            return *this;
+        }
+
+        ~UniqueSymmetricKey() {
+            reset();
+        }
+
+        SymmetricKey get() const noexcept {
+            return handle_;
+        }
+
+        SymmetricKey release() noexcept {
+            valid_ = false;
+            return handle_;
+        }
+
+        void reset() noexcept {
+            if (valid_) {
+                (void)raw::symmetric_key_close(handle_);
+                valid_ = false;
+            }
+        }
+
+      private:
+        SymmetricKey handle_;
+        bool valid_;
+    };
+
+    /// Owned `SymmetricTag` handle, closed with `symmetric_tag_close()` when dropped.
+    class UniqueSymmetricTag {
+      public:
+        explicit UniqueSymmetricTag(SymmetricTag handle) noexcept : handle_(handle), valid_(true) {}
+        UniqueSymmetricTag(const UniqueSymmetricTag &) = delete;
+        UniqueSymmetricTag &operator=(const UniqueSymmetricTag &) = delete;
+
+        UniqueSymmetricTag(UniqueSymmetricTag &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {
+            other.valid_ = false;
+        }
+
+        UniqueSymmetricTag &operator=(UniqueSymmetricTag &&other) noexcept {
+            if (this != &other) {
+                reset();
+                handle_ = other.handle_;
+                valid_ = other.valid_;
+                other.valid_ = false;
+            }
+            return *this;
+        }
+
+        ~UniqueSymmetricTag() {
+            reset();
+        }
+
+        SymmetricTag get() const noexcept {
+            return handle_;
+        }
+
+        SymmetricTag release() noexcept {
+            valid_ = false;
+            return handle_;
+        }
+
+        void reset() noexcept {
+            if (valid_) {
+                (void)raw::symmetric_tag_close(handle_);
+                valid_ = false;
+            }
+        }
+
+      private:
+        SymmetricTag handle_;
+        bool valid_;
+    };
+
+} // namespace wasi_ephemeral_crypto_symmetric
+
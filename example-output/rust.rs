@@ -1,3 +1,4 @@
+
 //
 // This file was automatically generated by witx-codegen - Do not edit manually.
 //
@@ -7,14 +8,25 @@ pub enum Error {
     WasiError(i32),
 }
 impl std::error::Error for Error {}
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::WasiError(e) => write!(f, "Wasi error {}", e),
         }
     }
 }
 
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
 pub type WasiHandle = i32;
 pub type Char8 = u8;
 pub type Char32 = u32;
@@ -38,10 +50,10 @@ pub struct WasiMutSlice<T> {
 
 impl<T> WasiSlice<T> {
     pub fn as_slice(&self) -> &[T] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
     }
 
-    pub fn from_slice(&self, slice: &[T]) -> Self {
+    pub fn from_slice(slice: &[T]) -> Self {
         WasiSlice {
             ptr: slice.as_ptr() as _,
             len: slice.len(),
@@ -51,21 +63,21 @@ impl<T> WasiSlice<T> {
 
 impl<T> WasiMutSlice<T> {
     pub fn as_slice(&self) -> &[T] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
     }
 
-    pub fn as_mut_slice(&self) -> &mut [T] {
-        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
     }
 
-    pub fn from_slice(&self, slice: &[T]) -> Self {
+    pub fn from_slice(slice: &[T]) -> Self {
         WasiMutSlice {
             ptr: slice.as_ptr() as _,
             len: slice.len(),
         }
     }
 
-    pub fn from_mut_slice(&self, slice: &mut [T]) -> Self {
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
         WasiMutSlice {
             ptr: slice.as_mut_ptr(),
             len: slice.len(),
@@ -91,15 +103,15 @@ impl<T: AsRef<str>> From<T> for WasiString {
 }
 
 impl WasiString {
-    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
-        std::str::from_utf8(unsafe { std::slice::from_raw_parts(self.ptr, self.len) })
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
     }
 
     pub fn as_slice(&self) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
     }
 
-    pub fn from_slice(&self, slice: &[u8]) -> Self {
+    pub fn from_slice(slice: &[u8]) -> Self {
         WasiString {
             ptr: slice.as_ptr() as _,
             len: slice.len(),
@@ -109,42 +121,128 @@ impl WasiString {
 
 // ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
 
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Handle(pub WasiHandle);
+
 /// Error codes.
 pub type CryptoErrno = u16;
 
 #[allow(non_snake_case)]
 pub mod CRYPTO_ERRNO {
     use super::CryptoErrno;
+    /// Operation succeeded.
     pub const SUCCESS: CryptoErrno = 0;
+    /// An error occurred when trying to during a conversion from a host type to a guest type.
+    ///
+    /// Only an internal bug can throw this error.
     pub const GUEST_ERROR: CryptoErrno = 1;
+    /// The requested operation is valid, but not implemented by the host.
     pub const NOT_IMPLEMENTED: CryptoErrno = 2;
+    /// The requested feature is not supported by the chosen algorithm.
     pub const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    /// The requested operation is valid, but was administratively prohibited.
     pub const PROHIBITED_OPERATION: CryptoErrno = 4;
+    /// Unsupported encoding for an import or export operation.
     pub const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    /// The requested algorithm is not supported by the host.
     pub const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    /// The requested option is not supported by the currently selected algorithm.
     pub const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    /// An invalid or incompatible key was supplied.
+    ///
+    /// The key may not be valid, or was generated for a different algorithm or parameters set.
     pub const INVALID_KEY: CryptoErrno = 8;
+    /// The currently selected algorithm doesn't support the requested output length.
+    ///
+    /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
     pub const INVALID_LENGTH: CryptoErrno = 9;
+    /// A signature or authentication tag verification failed.
     pub const VERIFICATION_FAILED: CryptoErrno = 10;
+    /// A secure random numbers generator is not available.
+    ///
+    /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
     pub const RNG_ERROR: CryptoErrno = 11;
+    /// An error was returned by the underlying cryptography library.
+    ///
+    /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    ///
+    /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    ///
+    /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
     pub const ALGORITHM_FAILURE: CryptoErrno = 12;
+    /// The supplied signature is invalid, or incompatible with the chosen algorithm.
     pub const INVALID_SIGNATURE: CryptoErrno = 13;
+    /// An attempt was made to close a handle that was already closed.
     pub const CLOSED: CryptoErrno = 14;
+    /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
     pub const INVALID_HANDLE: CryptoErrno = 15;
+    /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
     pub const OVERFLOW: CryptoErrno = 16;
+    /// An internal error occurred.
+    ///
+    /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
     pub const INTERNAL_ERROR: CryptoErrno = 17;
+    /// Too many handles are currently open, and a new one cannot be created.
+    ///
+    /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
     pub const TOO_MANY_HANDLES: CryptoErrno = 18;
+    /// A key was provided, but the chosen algorithm doesn't support keys.
+    ///
+    /// This is returned by symmetric operations.
+    ///
+    /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+    /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    ///
+    /// These functions must refuse to create the context and return this error instead.
     pub const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    /// A key is required for the chosen algorithm, but none was given.
     pub const KEY_REQUIRED: CryptoErrno = 20;
+    /// The provided authentication tag is invalid or incompatible with the current algorithm.
+    ///
+    /// This error is returned by decryption functions and tag verification functions.
+    ///
+    /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
     pub const INVALID_TAG: CryptoErrno = 21;
+    /// The requested operation is incompatible with the current scheme.
+    ///
+    /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    /// This error code will be returned instead.
     pub const INVALID_OPERATION: CryptoErrno = 22;
+    /// A nonce is required.
+    ///
+    /// Most encryption schemes require a nonce.
+    ///
+    /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
     pub const NONCE_REQUIRED: CryptoErrno = 23;
+    /// The provided nonce doesn't have a correct size for the given cipher.
     pub const INVALID_NONCE: CryptoErrno = 24;
+    /// The named option was not set.
+    ///
+    /// The caller tried to read the value of an option that was not set.
+    /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
     pub const OPTION_NOT_SET: CryptoErrno = 25;
+    /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+    ///
+    /// This error is returned by a secrets manager via the `keypair_from_id()` function.
     pub const NOT_FOUND: CryptoErrno = 26;
+    /// The algorithm requires parameters that haven't been set.
+    ///
+    /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
     pub const PARAMETERS_MISSING: CryptoErrno = 27;
+    /// A requested computation is not done yet, and additional calls to the function are required.
+    ///
+    /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    ///
+    /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
     pub const IN_PROGRESS: CryptoErrno = 28;
+    /// Multiple keys have been provided, but they do not share the same type.
+    ///
+    /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
     pub const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    /// A managed key or secret expired and cannot be used any more.
     pub const EXPIRED: CryptoErrno = 30;
 }
 
@@ -154,9 +252,13 @@ pub type KeypairEncoding = u16;
 #[allow(non_snake_case)]
 pub mod KEYPAIR_ENCODING {
     use super::KeypairEncoding;
+    /// Raw bytes.
     pub const RAW: KeypairEncoding = 0;
-    pub const PKCS_8: KeypairEncoding = 1;
+    /// PCSK8/DER encoding.
+    pub const PKCS8: KeypairEncoding = 1;
+    /// PEM encoding.
     pub const PEM: KeypairEncoding = 2;
+    /// Implementation-defined encoding.
     pub const LOCAL: KeypairEncoding = 3;
 }
 
@@ -166,11 +268,17 @@ pub type PublickeyEncoding = u16;
 #[allow(non_snake_case)]
 pub mod PUBLICKEY_ENCODING {
     use super::PublickeyEncoding;
+    /// Raw bytes.
     pub const RAW: PublickeyEncoding = 0;
-    pub const PKCS_8: PublickeyEncoding = 1;
+    /// PKCS8/DER encoding.
+    pub const PKCS8: PublickeyEncoding = 1;
+    /// PEM encoding.
     pub const PEM: PublickeyEncoding = 2;
+    /// SEC encoding.
     pub const SEC: PublickeyEncoding = 3;
+    /// Compressed SEC encoding.
     pub const COMPRESSED_SEC: PublickeyEncoding = 4;
+    /// Implementation-defined encoding.
     pub const LOCAL: PublickeyEncoding = 5;
 }
 
@@ -180,11 +288,17 @@ pub type SecretkeyEncoding = u16;
 #[allow(non_snake_case)]
 pub mod SECRETKEY_ENCODING {
     use super::SecretkeyEncoding;
+    /// Raw bytes.
     pub const RAW: SecretkeyEncoding = 0;
-    pub const PKCS_8: SecretkeyEncoding = 1;
+    /// PKCS8/DER encoding.
+    pub const PKCS8: SecretkeyEncoding = 1;
+    /// PEM encoding.
     pub const PEM: SecretkeyEncoding = 2;
+    /// SEC encoding.
     pub const SEC: SecretkeyEncoding = 3;
+    /// Compressed SEC encoding.
     pub const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    /// Implementation-defined encoding.
     pub const LOCAL: SecretkeyEncoding = 5;
 }
 
@@ -194,7 +308,9 @@ pub type SignatureEncoding = u16;
 #[allow(non_snake_case)]
 pub mod SIGNATURE_ENCODING {
     use super::SignatureEncoding;
+    /// Raw bytes.
     pub const RAW: SignatureEncoding = 0;
+    /// DER encoding.
     pub const DER: SignatureEncoding = 1;
 }
 
@@ -231,54 +347,76 @@ pub type U64 = u64;
 /// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
 ///
 /// An `array_output` handle is automatically closed after its full content has been consumed.
-pub type ArrayOutput = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ArrayOutput(pub WasiHandle);
 
 /// A set of options.
 ///
 /// This type is used to set non-default parameters.
 ///
 /// The exact set of allowed options depends on the algorithm being used.
-pub type Options = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Options(pub WasiHandle);
 
 /// A handle to the optional secrets management facilities offered by a host.
 ///
 /// This is used to generate, retrieve and invalidate managed keys.
-pub type SecretsManager = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SecretsManager(pub WasiHandle);
 
 /// A key pair.
-pub type Keypair = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Keypair(pub WasiHandle);
 
 /// A state to absorb data to be signed.
 ///
 /// After a signature has been computed or verified, the state remains valid for further operations.
 ///
 /// A subsequent signature would sign all the data accumulated since the creation of the state object.
-pub type SignatureState = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SignatureState(pub WasiHandle);
 
 /// A signature.
-pub type Signature = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Signature(pub WasiHandle);
 
 /// A public key, for key exchange and signature verification.
-pub type Publickey = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Publickey(pub WasiHandle);
 
 /// A secret key, for key exchange mechanisms.
-pub type Secretkey = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Secretkey(pub WasiHandle);
 
 /// A state to absorb signed data to be verified.
-pub type SignatureVerificationState = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SignatureVerificationState(pub WasiHandle);
 
 /// A state to perform symmetric operations.
 ///
 /// The state is not reset nor invalidated after an option has been performed.
 /// Incremental updates and sessions are thus supported.
-pub type SymmetricState = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SymmetricState(pub WasiHandle);
 
 /// A symmetric key.
 ///
 /// The key can be imported from raw bytes, or can be a reference to a managed key.
 ///
 /// If it was imported, the host will wipe it from memory as soon as the handle is closed.
-pub type SymmetricKey = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SymmetricKey(pub WasiHandle);
 
 /// An authentication tag.
 ///
@@ -289,7 +427,9 @@ pub type SymmetricKey = WasiHandle;
 /// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
 ///
 /// The host is reponsible for securely wiping them from memory on close.
-pub type SymmetricTag = WasiHandle;
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SymmetricTag(pub WasiHandle);
 
 /// Options index, only required by the Interface Types translation layer.
 pub type OptOptionsU = u8;
@@ -305,32 +445,50 @@ pub mod OPT_OPTIONS_U {
 ///
 /// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
 #[repr(C)]
+#[derive(Copy, Clone)]
 pub union OptOptionsMember {
     some: Options, // if tag=0
-                   // none with no associated value if tag=1
+    // none with no associated value if tag=1
 }
 
-#[repr(C, packed)]
+#[repr(C)]
+#[derive(Copy, Clone)]
 pub struct OptOptions {
     pub tag: u8,
     __pad8_0: u8,
     __pad16_0: u16,
-    __pad32_0: u32,
-    pub member: std::mem::MaybeUninit<OptOptionsMember>,
+    pub member: core::mem::MaybeUninit<OptOptionsMember>,
 }
 
+const _: () = {
+    assert!(core::mem::size_of::<OptOptions>() == 8);
+    assert!(core::mem::align_of::<OptOptions>() == 4);
+    assert!(core::mem::offset_of!(OptOptions, tag) == 0);
+    assert!(core::mem::offset_of!(OptOptions, member) == 4);
+};
+
 impl OptOptions {
     fn new(tag: u8) -> Self {
-        let mut tu = unsafe { std::mem::zeroed::<Self>() };
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
         tu.tag = tag;
         tu
     }
 
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<OptOptionsRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(OptOptionsRef::Some(unsafe { &self.member.assume_init_ref().some })),
+            1 => Ok(OptOptionsRef::None),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
     // --- some: Options if tag=0
 
     pub fn new_some(val: Options) -> Self {
         let mut tu = Self::new(0);
-        tu.member = std::mem::MaybeUninit::new(OptOptionsMember { some: val });
+        tu.member = core::mem::MaybeUninit::new(OptOptionsMember { some: val });
         tu
     }
 
@@ -342,7 +500,7 @@ impl OptOptions {
     pub fn set_some(&mut self, val: Options) {
         assert_eq!(self.tag, 0);
         let uval = OptOptionsMember { some: val };
-        unsafe { *self.member.as_mut_ptr() = uval };
+        self.member = core::mem::MaybeUninit::new(uval);
     }
 
     pub fn is_some(&self) -> bool {
@@ -360,6 +518,63 @@ impl OptOptions {
     }
 }
 
+/// Content of a `OptOptions`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptOptionsEnum {
+    Some(Options),
+    None,
+}
+
+/// Reference to the content of a `OptOptions`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptOptionsRef<'a> {
+    Some(&'a Options),
+    None,
+}
+
+impl<'a> core::convert::TryFrom<&'a OptOptions> for OptOptionsRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a OptOptions) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<OptOptionsRef<'_>> for OptOptionsEnum {
+    fn from(content: OptOptionsRef<'_>) -> Self {
+        match content {
+            OptOptionsRef::Some(val) => OptOptionsEnum::Some(*val),
+            OptOptionsRef::None => OptOptionsEnum::None,
+        }
+    }
+}
+
+impl core::convert::TryFrom<OptOptions> for OptOptionsEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: OptOptions) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<OptOptionsEnum> for OptOptions {
+    fn from(content: OptOptionsEnum) -> Self {
+        match content {
+            OptOptionsEnum::Some(val) => Self::new_some(val),
+            OptOptionsEnum::None => Self::new_none(),
+        }
+    }
+}
+
+impl core::fmt::Debug for OptOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
 /// Symmetric key index, only required by the Interface Types translation layer.
 pub type OptSymmetricKeyU = u8;
 
@@ -374,32 +589,50 @@ pub mod OPT_SYMMETRIC_KEY_U {
 ///
 /// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
 #[repr(C)]
+#[derive(Copy, Clone)]
 pub union OptSymmetricKeyMember {
     some: SymmetricKey, // if tag=0
-                        // none with no associated value if tag=1
+    // none with no associated value if tag=1
 }
 
-#[repr(C, packed)]
+#[repr(C)]
+#[derive(Copy, Clone)]
 pub struct OptSymmetricKey {
     pub tag: u8,
     __pad8_0: u8,
     __pad16_0: u16,
-    __pad32_0: u32,
-    pub member: std::mem::MaybeUninit<OptSymmetricKeyMember>,
+    pub member: core::mem::MaybeUninit<OptSymmetricKeyMember>,
 }
 
+const _: () = {
+    assert!(core::mem::size_of::<OptSymmetricKey>() == 8);
+    assert!(core::mem::align_of::<OptSymmetricKey>() == 4);
+    assert!(core::mem::offset_of!(OptSymmetricKey, tag) == 0);
+    assert!(core::mem::offset_of!(OptSymmetricKey, member) == 4);
+};
+
 impl OptSymmetricKey {
     fn new(tag: u8) -> Self {
-        let mut tu = unsafe { std::mem::zeroed::<Self>() };
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
         tu.tag = tag;
         tu
     }
 
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<OptSymmetricKeyRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(OptSymmetricKeyRef::Some(unsafe { &self.member.assume_init_ref().some })),
+            1 => Ok(OptSymmetricKeyRef::None),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
     // --- some: SymmetricKey if tag=0
 
     pub fn new_some(val: SymmetricKey) -> Self {
         let mut tu = Self::new(0);
-        tu.member = std::mem::MaybeUninit::new(OptSymmetricKeyMember { some: val });
+        tu.member = core::mem::MaybeUninit::new(OptSymmetricKeyMember { some: val });
         tu
     }
 
@@ -411,7 +644,7 @@ impl OptSymmetricKey {
     pub fn set_some(&mut self, val: SymmetricKey) {
         assert_eq!(self.tag, 0);
         let uval = OptSymmetricKeyMember { some: val };
-        unsafe { *self.member.as_mut_ptr() = uval };
+        self.member = core::mem::MaybeUninit::new(uval);
     }
 
     pub fn is_some(&self) -> bool {
@@ -429,6 +662,304 @@ impl OptSymmetricKey {
     }
 }
 
+/// Content of a `OptSymmetricKey`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptSymmetricKeyEnum {
+    Some(SymmetricKey),
+    None,
+}
+
+/// Reference to the content of a `OptSymmetricKey`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptSymmetricKeyRef<'a> {
+    Some(&'a SymmetricKey),
+    None,
+}
+
+impl<'a> core::convert::TryFrom<&'a OptSymmetricKey> for OptSymmetricKeyRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a OptSymmetricKey) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<OptSymmetricKeyRef<'_>> for OptSymmetricKeyEnum {
+    fn from(content: OptSymmetricKeyRef<'_>) -> Self {
+        match content {
+            OptSymmetricKeyRef::Some(val) => OptSymmetricKeyEnum::Some(*val),
+            OptSymmetricKeyRef::None => OptSymmetricKeyEnum::None,
+        }
+    }
+}
+
+impl core::convert::TryFrom<OptSymmetricKey> for OptSymmetricKeyEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: OptSymmetricKey) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<OptSymmetricKeyEnum> for OptSymmetricKey {
+    fn from(content: OptSymmetricKeyEnum) -> Self {
+        match content {
+            OptSymmetricKeyEnum::Some(val) => Self::new_some(val),
+            OptSymmetricKeyEnum::None => Self::new_none(),
+        }
+    }
+}
+
+impl core::fmt::Debug for OptSymmetricKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
+/// Error returned by the functions of the `wasi_ephemeral_crypto_symmetric` module.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum WasiEphemeralCryptoSymmetricError {
+    /// An error occurred when trying to during a conversion from a host type to a guest type.
+    ///
+    /// Only an internal bug can throw this error.
+    GuestError,
+    /// The requested operation is valid, but not implemented by the host.
+    NotImplemented,
+    /// The requested feature is not supported by the chosen algorithm.
+    UnsupportedFeature,
+    /// The requested operation is valid, but was administratively prohibited.
+    ProhibitedOperation,
+    /// Unsupported encoding for an import or export operation.
+    UnsupportedEncoding,
+    /// The requested algorithm is not supported by the host.
+    UnsupportedAlgorithm,
+    /// The requested option is not supported by the currently selected algorithm.
+    UnsupportedOption,
+    /// An invalid or incompatible key was supplied.
+    ///
+    /// The key may not be valid, or was generated for a different algorithm or parameters set.
+    InvalidKey,
+    /// The currently selected algorithm doesn't support the requested output length.
+    ///
+    /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+    InvalidLength,
+    /// A signature or authentication tag verification failed.
+    VerificationFailed,
+    /// A secure random numbers generator is not available.
+    ///
+    /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+    RngError,
+    /// An error was returned by the underlying cryptography library.
+    ///
+    /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    ///
+    /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    ///
+    /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+    AlgorithmFailure,
+    /// The supplied signature is invalid, or incompatible with the chosen algorithm.
+    InvalidSignature,
+    /// An attempt was made to close a handle that was already closed.
+    Closed,
+    /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+    InvalidHandle,
+    /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+    Overflow,
+    /// An internal error occurred.
+    ///
+    /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+    InternalError,
+    /// Too many handles are currently open, and a new one cannot be created.
+    ///
+    /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+    TooManyHandles,
+    /// A key was provided, but the chosen algorithm doesn't support keys.
+    ///
+    /// This is returned by symmetric operations.
+    ///
+    /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+    /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    ///
+    /// These functions must refuse to create the context and return this error instead.
+    KeyNotSupported,
+    /// A key is required for the chosen algorithm, but none was given.
+    KeyRequired,
+    /// The provided authentication tag is invalid or incompatible with the current algorithm.
+    ///
+    /// This error is returned by decryption functions and tag verification functions.
+    ///
+    /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+    InvalidTag,
+    /// The requested operation is incompatible with the current scheme.
+    ///
+    /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    /// This error code will be returned instead.
+    InvalidOperation,
+    /// A nonce is required.
+    ///
+    /// Most encryption schemes require a nonce.
+    ///
+    /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+    NonceRequired,
+    /// The provided nonce doesn't have a correct size for the given cipher.
+    InvalidNonce,
+    /// The named option was not set.
+    ///
+    /// The caller tried to read the value of an option that was not set.
+    /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+    OptionNotSet,
+    /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+    ///
+    /// This error is returned by a secrets manager via the `keypair_from_id()` function.
+    NotFound,
+    /// The algorithm requires parameters that haven't been set.
+    ///
+    /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+    ParametersMissing,
+    /// A requested computation is not done yet, and additional calls to the function are required.
+    ///
+    /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    ///
+    /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+    InProgress,
+    /// Multiple keys have been provided, but they do not share the same type.
+    ///
+    /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+    IncompatibleKeys,
+    /// A managed key or secret expired and cannot be used any more.
+    Expired,
+    /// An error code that is not an error case of `CryptoErrno`.
+    Other(CryptoErrno),
+}
+
+impl From<CryptoErrno> for WasiEphemeralCryptoSymmetricError {
+    fn from(e: CryptoErrno) -> Self {
+        match e {
+            CRYPTO_ERRNO::GUEST_ERROR => WasiEphemeralCryptoSymmetricError::GuestError,
+            CRYPTO_ERRNO::NOT_IMPLEMENTED => WasiEphemeralCryptoSymmetricError::NotImplemented,
+            CRYPTO_ERRNO::UNSUPPORTED_FEATURE => WasiEphemeralCryptoSymmetricError::UnsupportedFeature,
+            CRYPTO_ERRNO::PROHIBITED_OPERATION => WasiEphemeralCryptoSymmetricError::ProhibitedOperation,
+            CRYPTO_ERRNO::UNSUPPORTED_ENCODING => WasiEphemeralCryptoSymmetricError::UnsupportedEncoding,
+            CRYPTO_ERRNO::UNSUPPORTED_ALGORITHM => WasiEphemeralCryptoSymmetricError::UnsupportedAlgorithm,
+            CRYPTO_ERRNO::UNSUPPORTED_OPTION => WasiEphemeralCryptoSymmetricError::UnsupportedOption,
+            CRYPTO_ERRNO::INVALID_KEY => WasiEphemeralCryptoSymmetricError::InvalidKey,
+            CRYPTO_ERRNO::INVALID_LENGTH => WasiEphemeralCryptoSymmetricError::InvalidLength,
+            CRYPTO_ERRNO::VERIFICATION_FAILED => WasiEphemeralCryptoSymmetricError::VerificationFailed,
+            CRYPTO_ERRNO::RNG_ERROR => WasiEphemeralCryptoSymmetricError::RngError,
+            CRYPTO_ERRNO::ALGORITHM_FAILURE => WasiEphemeralCryptoSymmetricError::AlgorithmFailure,
+            CRYPTO_ERRNO::INVALID_SIGNATURE => WasiEphemeralCryptoSymmetricError::InvalidSignature,
+            CRYPTO_ERRNO::CLOSED => WasiEphemeralCryptoSymmetricError::Closed,
+            CRYPTO_ERRNO::INVALID_HANDLE => WasiEphemeralCryptoSymmetricError::InvalidHandle,
+            CRYPTO_ERRNO::OVERFLOW => WasiEphemeralCryptoSymmetricError::Overflow,
+            CRYPTO_ERRNO::INTERNAL_ERROR => WasiEphemeralCryptoSymmetricError::InternalError,
+            CRYPTO_ERRNO::TOO_MANY_HANDLES => WasiEphemeralCryptoSymmetricError::TooManyHandles,
+            CRYPTO_ERRNO::KEY_NOT_SUPPORTED => WasiEphemeralCryptoSymmetricError::KeyNotSupported,
+            CRYPTO_ERRNO::KEY_REQUIRED => WasiEphemeralCryptoSymmetricError::KeyRequired,
+            CRYPTO_ERRNO::INVALID_TAG => WasiEphemeralCryptoSymmetricError::InvalidTag,
+            CRYPTO_ERRNO::INVALID_OPERATION => WasiEphemeralCryptoSymmetricError::InvalidOperation,
+            CRYPTO_ERRNO::NONCE_REQUIRED => WasiEphemeralCryptoSymmetricError::NonceRequired,
+            CRYPTO_ERRNO::INVALID_NONCE => WasiEphemeralCryptoSymmetricError::InvalidNonce,
+            CRYPTO_ERRNO::OPTION_NOT_SET => WasiEphemeralCryptoSymmetricError::OptionNotSet,
+            CRYPTO_ERRNO::NOT_FOUND => WasiEphemeralCryptoSymmetricError::NotFound,
+            CRYPTO_ERRNO::PARAMETERS_MISSING => WasiEphemeralCryptoSymmetricError::ParametersMissing,
+            CRYPTO_ERRNO::IN_PROGRESS => WasiEphemeralCryptoSymmetricError::InProgress,
+            CRYPTO_ERRNO::INCOMPATIBLE_KEYS => WasiEphemeralCryptoSymmetricError::IncompatibleKeys,
+            CRYPTO_ERRNO::EXPIRED => WasiEphemeralCryptoSymmetricError::Expired,
+            e => WasiEphemeralCryptoSymmetricError::Other(e),
+        }
+    }
+}
+
+impl From<WasiEphemeralCryptoSymmetricError> for CryptoErrno {
+    fn from(e: WasiEphemeralCryptoSymmetricError) -> Self {
+        match e {
+            WasiEphemeralCryptoSymmetricError::GuestError => CRYPTO_ERRNO::GUEST_ERROR,
+            WasiEphemeralCryptoSymmetricError::NotImplemented => CRYPTO_ERRNO::NOT_IMPLEMENTED,
+            WasiEphemeralCryptoSymmetricError::UnsupportedFeature => CRYPTO_ERRNO::UNSUPPORTED_FEATURE,
+            WasiEphemeralCryptoSymmetricError::ProhibitedOperation => CRYPTO_ERRNO::PROHIBITED_OPERATION,
+            WasiEphemeralCryptoSymmetricError::UnsupportedEncoding => CRYPTO_ERRNO::UNSUPPORTED_ENCODING,
+            WasiEphemeralCryptoSymmetricError::UnsupportedAlgorithm => CRYPTO_ERRNO::UNSUPPORTED_ALGORITHM,
+            WasiEphemeralCryptoSymmetricError::UnsupportedOption => CRYPTO_ERRNO::UNSUPPORTED_OPTION,
+            WasiEphemeralCryptoSymmetricError::InvalidKey => CRYPTO_ERRNO::INVALID_KEY,
+            WasiEphemeralCryptoSymmetricError::InvalidLength => CRYPTO_ERRNO::INVALID_LENGTH,
+            WasiEphemeralCryptoSymmetricError::VerificationFailed => CRYPTO_ERRNO::VERIFICATION_FAILED,
+            WasiEphemeralCryptoSymmetricError::RngError => CRYPTO_ERRNO::RNG_ERROR,
+            WasiEphemeralCryptoSymmetricError::AlgorithmFailure => CRYPTO_ERRNO::ALGORITHM_FAILURE,
+            WasiEphemeralCryptoSymmetricError::InvalidSignature => CRYPTO_ERRNO::INVALID_SIGNATURE,
+            WasiEphemeralCryptoSymmetricError::Closed => CRYPTO_ERRNO::CLOSED,
+            WasiEphemeralCryptoSymmetricError::InvalidHandle => CRYPTO_ERRNO::INVALID_HANDLE,
+            WasiEphemeralCryptoSymmetricError::Overflow => CRYPTO_ERRNO::OVERFLOW,
+            WasiEphemeralCryptoSymmetricError::InternalError => CRYPTO_ERRNO::INTERNAL_ERROR,
+            WasiEphemeralCryptoSymmetricError::TooManyHandles => CRYPTO_ERRNO::TOO_MANY_HANDLES,
+            WasiEphemeralCryptoSymmetricError::KeyNotSupported => CRYPTO_ERRNO::KEY_NOT_SUPPORTED,
+            WasiEphemeralCryptoSymmetricError::KeyRequired => CRYPTO_ERRNO::KEY_REQUIRED,
+            WasiEphemeralCryptoSymmetricError::InvalidTag => CRYPTO_ERRNO::INVALID_TAG,
+            WasiEphemeralCryptoSymmetricError::InvalidOperation => CRYPTO_ERRNO::INVALID_OPERATION,
+            WasiEphemeralCryptoSymmetricError::NonceRequired => CRYPTO_ERRNO::NONCE_REQUIRED,
+            WasiEphemeralCryptoSymmetricError::InvalidNonce => CRYPTO_ERRNO::INVALID_NONCE,
+            WasiEphemeralCryptoSymmetricError::OptionNotSet => CRYPTO_ERRNO::OPTION_NOT_SET,
+            WasiEphemeralCryptoSymmetricError::NotFound => CRYPTO_ERRNO::NOT_FOUND,
+            WasiEphemeralCryptoSymmetricError::ParametersMissing => CRYPTO_ERRNO::PARAMETERS_MISSING,
+            WasiEphemeralCryptoSymmetricError::InProgress => CRYPTO_ERRNO::IN_PROGRESS,
+            WasiEphemeralCryptoSymmetricError::IncompatibleKeys => CRYPTO_ERRNO::INCOMPATIBLE_KEYS,
+            WasiEphemeralCryptoSymmetricError::Expired => CRYPTO_ERRNO::EXPIRED,
+            WasiEphemeralCryptoSymmetricError::Other(e) => e,
+        }
+    }
+}
+
+impl From<WasiEphemeralCryptoSymmetricError> for Error {
+    fn from(e: WasiEphemeralCryptoSymmetricError) -> Self {
+        Error::WasiError(CryptoErrno::from(e) as _)
+    }
+}
+
+impl core::fmt::Display for WasiEphemeralCryptoSymmetricError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            WasiEphemeralCryptoSymmetricError::GuestError => "An error occurred when trying to during a conversion from a host type to a guest type.",
+            WasiEphemeralCryptoSymmetricError::NotImplemented => "The requested operation is valid, but not implemented by the host.",
+            WasiEphemeralCryptoSymmetricError::UnsupportedFeature => "The requested feature is not supported by the chosen algorithm.",
+            WasiEphemeralCryptoSymmetricError::ProhibitedOperation => "The requested operation is valid, but was administratively prohibited.",
+            WasiEphemeralCryptoSymmetricError::UnsupportedEncoding => "Unsupported encoding for an import or export operation.",
+            WasiEphemeralCryptoSymmetricError::UnsupportedAlgorithm => "The requested algorithm is not supported by the host.",
+            WasiEphemeralCryptoSymmetricError::UnsupportedOption => "The requested option is not supported by the currently selected algorithm.",
+            WasiEphemeralCryptoSymmetricError::InvalidKey => "An invalid or incompatible key was supplied.",
+            WasiEphemeralCryptoSymmetricError::InvalidLength => "The currently selected algorithm doesn't support the requested output length.",
+            WasiEphemeralCryptoSymmetricError::VerificationFailed => "A signature or authentication tag verification failed.",
+            WasiEphemeralCryptoSymmetricError::RngError => "A secure random numbers generator is not available.",
+            WasiEphemeralCryptoSymmetricError::AlgorithmFailure => "An error was returned by the underlying cryptography library.",
+            WasiEphemeralCryptoSymmetricError::InvalidSignature => "The supplied signature is invalid, or incompatible with the chosen algorithm.",
+            WasiEphemeralCryptoSymmetricError::Closed => "An attempt was made to close a handle that was already closed.",
+            WasiEphemeralCryptoSymmetricError::InvalidHandle => "A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.",
+            WasiEphemeralCryptoSymmetricError::Overflow => "The host needs to copy data to a guest-allocated buffer, but that buffer is too small.",
+            WasiEphemeralCryptoSymmetricError::InternalError => "An internal error occurred.",
+            WasiEphemeralCryptoSymmetricError::TooManyHandles => "Too many handles are currently open, and a new one cannot be created.",
+            WasiEphemeralCryptoSymmetricError::KeyNotSupported => "A key was provided, but the chosen algorithm doesn't support keys.",
+            WasiEphemeralCryptoSymmetricError::KeyRequired => "A key is required for the chosen algorithm, but none was given.",
+            WasiEphemeralCryptoSymmetricError::InvalidTag => "The provided authentication tag is invalid or incompatible with the current algorithm.",
+            WasiEphemeralCryptoSymmetricError::InvalidOperation => "The requested operation is incompatible with the current scheme.",
+            WasiEphemeralCryptoSymmetricError::NonceRequired => "A nonce is required.",
+            WasiEphemeralCryptoSymmetricError::InvalidNonce => "The provided nonce doesn't have a correct size for the given cipher.",
+            WasiEphemeralCryptoSymmetricError::OptionNotSet => "The named option was not set.",
+            WasiEphemeralCryptoSymmetricError::NotFound => "A key or key pair matching the requested identifier cannot be found using the supplied information.",
+            WasiEphemeralCryptoSymmetricError::ParametersMissing => "The algorithm requires parameters that haven't been set.",
+            WasiEphemeralCryptoSymmetricError::InProgress => "A requested computation is not done yet, and additional calls to the function are required.",
+            WasiEphemeralCryptoSymmetricError::IncompatibleKeys => "Multiple keys have been provided, but they do not share the same type.",
+            WasiEphemeralCryptoSymmetricError::Expired => "A managed key or secret expired and cannot be used any more.",
+            WasiEphemeralCryptoSymmetricError::Other(e) => return write!(f, "Unknown error {}", e),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for WasiEphemeralCryptoSymmetricError {}
+
 /// Generate a new symmetric key for a given algorithm.
 ///
 /// `options` can be `None` to use the default parameters, or an algoritm-specific set of parameters to override.
@@ -438,27 +969,44 @@ pub fn symmetric_key_generate(
     algorithm_ptr: WasiPtr<Char8>,
     algorithm_len: usize,
     options: OptOptions,
-) -> Result<SymmetricKey, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_generate(
+) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_generate(
+                algorithm_ptr: WasiPtr<Char8>,
+                algorithm_len: usize,
+                options: OptOptions,
+                result_ptr: WasiMutPtr<SymmetricKey>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_generate(
             algorithm_ptr: WasiPtr<Char8>,
             algorithm_len: usize,
             options: OptOptions,
             result_ptr: WasiMutPtr<SymmetricKey>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_key_generate(
-            algorithm_ptr,
-            algorithm_len,
-            options,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_generate(algorithm_ptr, algorithm_len, options)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_key_generate(
+        algorithm_ptr,
+        algorithm_len,
+        options,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -473,29 +1021,47 @@ pub fn symmetric_key_import(
     algorithm_len: usize,
     raw: WasiPtr<u8>,
     raw_len: Size,
-) -> Result<SymmetricKey, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_import(
+) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_import(
+                algorithm_ptr: WasiPtr<Char8>,
+                algorithm_len: usize,
+                raw: WasiPtr<u8>,
+                raw_len: Size,
+                result_ptr: WasiMutPtr<SymmetricKey>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_import(
             algorithm_ptr: WasiPtr<Char8>,
             algorithm_len: usize,
             raw: WasiPtr<u8>,
             raw_len: Size,
             result_ptr: WasiMutPtr<SymmetricKey>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_key_import(
-            algorithm_ptr,
-            algorithm_len,
-            raw,
-            raw_len,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_import(algorithm_ptr, algorithm_len, raw, raw_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_key_import(
+        algorithm_ptr,
+        algorithm_len,
+        raw,
+        raw_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -505,18 +1071,40 @@ pub fn symmetric_key_import(
 /// This is mainly useful to export a managed key.
 ///
 /// May return `prohibited_operation` if this operation is denied.
-pub fn symmetric_key_export(symmetric_key: SymmetricKey) -> Result<ArrayOutput, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_export(
+pub fn symmetric_key_export(
+    symmetric_key: SymmetricKey,
+) -> Result<ArrayOutput, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_export(
+                symmetric_key: SymmetricKey,
+                result_ptr: WasiMutPtr<ArrayOutput>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_export(
             symmetric_key: SymmetricKey,
             result_ptr: WasiMutPtr<ArrayOutput>,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_export(symmetric_key)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe { symmetric_key_export(symmetric_key, result_ptr.as_mut_ptr()) };
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_key_export(
+        symmetric_key,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -524,14 +1112,35 @@ pub fn symmetric_key_export(symmetric_key: SymmetricKey) -> Result<ArrayOutput,
 /// Destroy a symmetric key.
 ///
 /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
-pub fn symmetric_key_close(symmetric_key: SymmetricKey) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_close(symmetric_key: SymmetricKey) -> CryptoErrno;
+pub fn symmetric_key_close(
+    symmetric_key: SymmetricKey,
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_close(
+                symmetric_key: SymmetricKey,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_close(
+            symmetric_key: SymmetricKey,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_close(symmetric_key)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let res = unsafe { symmetric_key_close(symmetric_key) };
+    let res = unsafe { raw::symmetric_key_close(
+        symmetric_key,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
@@ -554,29 +1163,47 @@ pub fn symmetric_key_generate_managed(
     algorithm_ptr: WasiPtr<Char8>,
     algorithm_len: usize,
     options: OptOptions,
-) -> Result<SymmetricKey, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_generate_managed(
+) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_generate_managed(
+                secrets_manager: SecretsManager,
+                algorithm_ptr: WasiPtr<Char8>,
+                algorithm_len: usize,
+                options: OptOptions,
+                result_ptr: WasiMutPtr<SymmetricKey>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_generate_managed(
             secrets_manager: SecretsManager,
             algorithm_ptr: WasiPtr<Char8>,
             algorithm_len: usize,
             options: OptOptions,
             result_ptr: WasiMutPtr<SymmetricKey>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_key_generate_managed(
-            secrets_manager,
-            algorithm_ptr,
-            algorithm_len,
-            options,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_generate_managed(secrets_manager, algorithm_ptr, algorithm_len, options)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_key_generate_managed(
+        secrets_manager,
+        algorithm_ptr,
+        algorithm_len,
+        options,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -593,26 +1220,42 @@ pub fn symmetric_key_store_managed(
     symmetric_key: SymmetricKey,
     symmetric_key_id: WasiMutPtr<u8>,
     symmetric_key_id_max_len: Size,
-) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_store_managed(
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_store_managed(
+                secrets_manager: SecretsManager,
+                symmetric_key: SymmetricKey,
+                symmetric_key_id: WasiMutPtr<u8>,
+                symmetric_key_id_max_len: Size,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_store_managed(
             secrets_manager: SecretsManager,
             symmetric_key: SymmetricKey,
             symmetric_key_id: WasiMutPtr<u8>,
             symmetric_key_id_max_len: Size,
-        ) -> CryptoErrno;
-    }
-    let res = unsafe {
-        symmetric_key_store_managed(
-            secrets_manager,
-            symmetric_key,
-            symmetric_key_id,
-            symmetric_key_id_max_len,
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_store_managed(secrets_manager, symmetric_key, symmetric_key_id, symmetric_key_id_max_len)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::symmetric_key_store_managed(
+        secrets_manager,
+        symmetric_key,
+        symmetric_key_id,
+        symmetric_key_id_max_len,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
@@ -642,27 +1285,44 @@ pub fn symmetric_key_replace_managed(
     secrets_manager: SecretsManager,
     symmetric_key_old: SymmetricKey,
     symmetric_key_new: SymmetricKey,
-) -> Result<Version, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_replace_managed(
+) -> Result<Version, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_replace_managed(
+                secrets_manager: SecretsManager,
+                symmetric_key_old: SymmetricKey,
+                symmetric_key_new: SymmetricKey,
+                result_ptr: WasiMutPtr<Version>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_replace_managed(
             secrets_manager: SecretsManager,
             symmetric_key_old: SymmetricKey,
             symmetric_key_new: SymmetricKey,
             result_ptr: WasiMutPtr<Version>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_key_replace_managed(
-            secrets_manager,
-            symmetric_key_old,
-            symmetric_key_new,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_replace_managed(secrets_manager, symmetric_key_old, symmetric_key_new)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_key_replace_managed(
+        secrets_manager,
+        symmetric_key_old,
+        symmetric_key_new,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -677,32 +1337,51 @@ pub fn symmetric_key_id(
     symmetric_key: SymmetricKey,
     symmetric_key_id: WasiMutPtr<u8>,
     symmetric_key_id_max_len: Size,
-) -> Result<(Size, Version), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_id(
+) -> Result<(Size, Version), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_id(
+                symmetric_key: SymmetricKey,
+                symmetric_key_id: WasiMutPtr<u8>,
+                symmetric_key_id_max_len: Size,
+                result0_ptr: WasiMutPtr<Size>,
+                result1_ptr: WasiMutPtr<Version>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_id(
             symmetric_key: SymmetricKey,
             symmetric_key_id: WasiMutPtr<u8>,
             symmetric_key_id_max_len: Size,
-            result_0_ptr: WasiMutPtr<Size>,
-            result_1_ptr: WasiMutPtr<Version>,
-        ) -> CryptoErrno;
-    }
-    let mut result_0_ptr = std::mem::MaybeUninit::uninit();
-    let mut result_1_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_key_id(
-            symmetric_key,
-            symmetric_key_id,
-            symmetric_key_id_max_len,
-            result_0_ptr.as_mut_ptr(),
-            result_1_ptr.as_mut_ptr(),
-        )
-    };
+            result0_ptr: WasiMutPtr<Size>,
+            result1_ptr: WasiMutPtr<Version>,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_id(symmetric_key, symmetric_key_id, symmetric_key_id_max_len)) {
+                Ok((res0, res1)) => {
+                    unsafe { result0_ptr.write(res0) };
+                    unsafe { result1_ptr.write(res1) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result0_ptr = core::mem::MaybeUninit::uninit();
+    let mut result1_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_key_id(
+        symmetric_key,
+        symmetric_key_id,
+        symmetric_key_id_max_len,
+        result0_ptr.as_mut_ptr(),
+        result1_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
-    Ok(unsafe { (result_0_ptr.assume_init(), result_1_ptr.assume_init()) })
+    Ok(unsafe { (result0_ptr.assume_init(), result1_ptr.assume_init()) })
 }
 
 /// __(optional)__
@@ -718,29 +1397,47 @@ pub fn symmetric_key_from_id(
     symmetric_key_id: WasiPtr<u8>,
     symmetric_key_id_len: Size,
     symmetric_key_version: Version,
-) -> Result<SymmetricKey, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_key_from_id(
+) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_key_from_id(
+                secrets_manager: SecretsManager,
+                symmetric_key_id: WasiPtr<u8>,
+                symmetric_key_id_len: Size,
+                symmetric_key_version: Version,
+                result_ptr: WasiMutPtr<SymmetricKey>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_key_from_id(
             secrets_manager: SecretsManager,
             symmetric_key_id: WasiPtr<u8>,
             symmetric_key_id_len: Size,
             symmetric_key_version: Version,
             result_ptr: WasiMutPtr<SymmetricKey>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_key_from_id(
-            secrets_manager,
-            symmetric_key_id,
-            symmetric_key_id_len,
-            symmetric_key_version,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_key_from_id(secrets_manager, symmetric_key_id, symmetric_key_id_len, symmetric_key_version)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_key_from_id(
+        secrets_manager,
+        symmetric_key_id,
+        symmetric_key_id_len,
+        symmetric_key_version,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -926,29 +1623,47 @@ pub fn symmetric_state_open(
     algorithm_len: usize,
     key: OptSymmetricKey,
     options: OptOptions,
-) -> Result<SymmetricState, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_open(
+) -> Result<SymmetricState, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_open(
+                algorithm_ptr: WasiPtr<Char8>,
+                algorithm_len: usize,
+                key: OptSymmetricKey,
+                options: OptOptions,
+                result_ptr: WasiMutPtr<SymmetricState>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_open(
             algorithm_ptr: WasiPtr<Char8>,
             algorithm_len: usize,
             key: OptSymmetricKey,
             options: OptOptions,
             result_ptr: WasiMutPtr<SymmetricState>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_open(
-            algorithm_ptr,
-            algorithm_len,
-            key,
-            options,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_open(algorithm_ptr, algorithm_len, key, options)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_open(
+        algorithm_ptr,
+        algorithm_len,
+        key,
+        options,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -966,31 +1681,50 @@ pub fn symmetric_state_options_get(
     name_len: usize,
     value: WasiMutPtr<u8>,
     value_max_len: Size,
-) -> Result<Size, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_options_get(
+) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_options_get(
+                handle: SymmetricState,
+                name_ptr: WasiPtr<Char8>,
+                name_len: usize,
+                value: WasiMutPtr<u8>,
+                value_max_len: Size,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_options_get(
             handle: SymmetricState,
             name_ptr: WasiPtr<Char8>,
             name_len: usize,
             value: WasiMutPtr<u8>,
             value_max_len: Size,
             result_ptr: WasiMutPtr<Size>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_options_get(
-            handle,
-            name_ptr,
-            name_len,
-            value,
-            value_max_len,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_options_get(handle, name_ptr, name_len, value, value_max_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_options_get(
+        handle,
+        name_ptr,
+        name_len,
+        value,
+        value_max_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1002,26 +1736,48 @@ pub fn symmetric_state_options_get(
 /// The function may return `options_not_set` if an option was not set.
 ///
 /// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
-pub fn symmetric_state_options_get_u_64(
+pub fn symmetric_state_options_get_u64(
     handle: SymmetricState,
     name_ptr: WasiPtr<Char8>,
     name_len: usize,
-) -> Result<U64, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_options_get_u_64(
+) -> Result<U64, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_options_get_u64(
+                handle: SymmetricState,
+                name_ptr: WasiPtr<Char8>,
+                name_len: usize,
+                result_ptr: WasiMutPtr<U64>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_options_get_u64(
             handle: SymmetricState,
             name_ptr: WasiPtr<Char8>,
             name_len: usize,
             result_ptr: WasiMutPtr<U64>,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_options_get_u64(handle, name_ptr, name_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_options_get_u_64(handle, name_ptr, name_len, result_ptr.as_mut_ptr())
-    };
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_options_get_u64(
+        handle,
+        name_ptr,
+        name_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1029,14 +1785,35 @@ pub fn symmetric_state_options_get_u_64(
 /// Destroy a symmetric state.
 ///
 /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
-pub fn symmetric_state_close(handle: SymmetricState) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_close(handle: SymmetricState) -> CryptoErrno;
+pub fn symmetric_state_close(
+    handle: SymmetricState,
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_close(
+                handle: SymmetricState,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_close(
+            handle: SymmetricState,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_close(handle)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let res = unsafe { symmetric_state_close(handle) };
+    let res = unsafe { raw::symmetric_state_close(
+        handle,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
@@ -1057,18 +1834,39 @@ pub fn symmetric_state_absorb(
     handle: SymmetricState,
     data: WasiPtr<u8>,
     data_len: Size,
-) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_absorb(
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_absorb(
+                handle: SymmetricState,
+                data: WasiPtr<u8>,
+                data_len: Size,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_absorb(
             handle: SymmetricState,
             data: WasiPtr<u8>,
             data_len: Size,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_absorb(handle, data, data_len)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let res = unsafe { symmetric_state_absorb(handle, data, data_len) };
+    let res = unsafe { raw::symmetric_state_absorb(
+        handle,
+        data,
+        data_len,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
@@ -1088,18 +1886,39 @@ pub fn symmetric_state_squeeze(
     handle: SymmetricState,
     out: WasiMutPtr<u8>,
     out_len: Size,
-) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_squeeze(
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_squeeze(
+                handle: SymmetricState,
+                out: WasiMutPtr<u8>,
+                out_len: Size,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_squeeze(
             handle: SymmetricState,
             out: WasiMutPtr<u8>,
             out_len: Size,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_squeeze(handle, out, out_len)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let res = unsafe { symmetric_state_squeeze(handle, out, out_len) };
+    let res = unsafe { raw::symmetric_state_squeeze(
+        handle,
+        out,
+        out_len,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
@@ -1114,18 +1933,40 @@ pub fn symmetric_state_squeeze(
 ///
 /// For password-stretching functions, the function may return `in_progress`.
 /// In that case, the guest should retry with the same parameters until the function completes.
-pub fn symmetric_state_squeeze_tag(handle: SymmetricState) -> Result<SymmetricTag, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_squeeze_tag(
+pub fn symmetric_state_squeeze_tag(
+    handle: SymmetricState,
+) -> Result<SymmetricTag, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_squeeze_tag(
+                handle: SymmetricState,
+                result_ptr: WasiMutPtr<SymmetricTag>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_squeeze_tag(
             handle: SymmetricState,
             result_ptr: WasiMutPtr<SymmetricTag>,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_squeeze_tag(handle)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe { symmetric_state_squeeze_tag(handle, result_ptr.as_mut_ptr()) };
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_squeeze_tag(
+        handle,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1140,22 +1981,44 @@ pub fn symmetric_state_squeeze_key(
     handle: SymmetricState,
     alg_str_ptr: WasiPtr<Char8>,
     alg_str_len: usize,
-) -> Result<SymmetricKey, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_squeeze_key(
+) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_squeeze_key(
+                handle: SymmetricState,
+                alg_str_ptr: WasiPtr<Char8>,
+                alg_str_len: usize,
+                result_ptr: WasiMutPtr<SymmetricKey>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_squeeze_key(
             handle: SymmetricState,
             alg_str_ptr: WasiPtr<Char8>,
             alg_str_len: usize,
             result_ptr: WasiMutPtr<SymmetricKey>,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_squeeze_key(handle, alg_str_ptr, alg_str_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_squeeze_key(handle, alg_str_ptr, alg_str_len, result_ptr.as_mut_ptr())
-    };
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_squeeze_key(
+        handle,
+        alg_str_ptr,
+        alg_str_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1169,18 +2032,40 @@ pub fn symmetric_state_squeeze_key(
 /// For an encryption operation, the size of the output buffer should be `input_len + symmetric_state_max_tag_len()`.
 ///
 /// For a decryption operation, the size of the buffer that will store the decrypted data must be `ciphertext_len - symmetric_state_max_tag_len()`.
-pub fn symmetric_state_max_tag_len(handle: SymmetricState) -> Result<Size, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_max_tag_len(
+pub fn symmetric_state_max_tag_len(
+    handle: SymmetricState,
+) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_max_tag_len(
+                handle: SymmetricState,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_max_tag_len(
             handle: SymmetricState,
             result_ptr: WasiMutPtr<Size>,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_max_tag_len(handle)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe { symmetric_state_max_tag_len(handle, result_ptr.as_mut_ptr()) };
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_max_tag_len(
+        handle,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1202,31 +2087,50 @@ pub fn symmetric_state_encrypt(
     out_len: Size,
     data: WasiPtr<u8>,
     data_len: Size,
-) -> Result<Size, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_encrypt(
+) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_encrypt(
+                handle: SymmetricState,
+                out: WasiMutPtr<u8>,
+                out_len: Size,
+                data: WasiPtr<u8>,
+                data_len: Size,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_encrypt(
             handle: SymmetricState,
             out: WasiMutPtr<u8>,
             out_len: Size,
             data: WasiPtr<u8>,
             data_len: Size,
             result_ptr: WasiMutPtr<Size>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_encrypt(
-            handle,
-            out,
-            out_len,
-            data,
-            data_len,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_encrypt(handle, out, out_len, data, data_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_encrypt(
+        handle,
+        out,
+        out_len,
+        data,
+        data_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1248,31 +2152,50 @@ pub fn symmetric_state_encrypt_detached(
     out_len: Size,
     data: WasiPtr<u8>,
     data_len: Size,
-) -> Result<SymmetricTag, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_encrypt_detached(
+) -> Result<SymmetricTag, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_encrypt_detached(
+                handle: SymmetricState,
+                out: WasiMutPtr<u8>,
+                out_len: Size,
+                data: WasiPtr<u8>,
+                data_len: Size,
+                result_ptr: WasiMutPtr<SymmetricTag>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_encrypt_detached(
             handle: SymmetricState,
             out: WasiMutPtr<u8>,
             out_len: Size,
             data: WasiPtr<u8>,
             data_len: Size,
             result_ptr: WasiMutPtr<SymmetricTag>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_encrypt_detached(
-            handle,
-            out,
-            out_len,
-            data,
-            data_len,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_encrypt_detached(handle, out, out_len, data, data_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_encrypt_detached(
+        handle,
+        out,
+        out_len,
+        data,
+        data_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1296,31 +2219,50 @@ pub fn symmetric_state_decrypt(
     out_len: Size,
     data: WasiPtr<u8>,
     data_len: Size,
-) -> Result<Size, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_decrypt(
+) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_decrypt(
+                handle: SymmetricState,
+                out: WasiMutPtr<u8>,
+                out_len: Size,
+                data: WasiPtr<u8>,
+                data_len: Size,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_decrypt(
             handle: SymmetricState,
             out: WasiMutPtr<u8>,
             out_len: Size,
             data: WasiPtr<u8>,
             data_len: Size,
             result_ptr: WasiMutPtr<Size>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_decrypt(
-            handle,
-            out,
-            out_len,
-            data,
-            data_len,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_decrypt(handle, out, out_len, data, data_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_decrypt(
+        handle,
+        out,
+        out_len,
+        data,
+        data_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1347,10 +2289,25 @@ pub fn symmetric_state_decrypt_detached(
     data_len: Size,
     raw_tag: WasiPtr<u8>,
     raw_tag_len: Size,
-) -> Result<Size, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_decrypt_detached(
+) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_decrypt_detached(
+                handle: SymmetricState,
+                out: WasiMutPtr<u8>,
+                out_len: Size,
+                data: WasiPtr<u8>,
+                data_len: Size,
+                raw_tag: WasiPtr<u8>,
+                raw_tag_len: Size,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_decrypt_detached(
             handle: SymmetricState,
             out: WasiMutPtr<u8>,
             out_len: Size,
@@ -1359,23 +2316,29 @@ pub fn symmetric_state_decrypt_detached(
             raw_tag: WasiPtr<u8>,
             raw_tag_len: Size,
             result_ptr: WasiMutPtr<Size>,
-        ) -> CryptoErrno;
-    }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe {
-        symmetric_state_decrypt_detached(
-            handle,
-            out,
-            out_len,
-            data,
-            data_len,
-            raw_tag,
-            raw_tag_len,
-            result_ptr.as_mut_ptr(),
-        )
-    };
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_decrypt_detached(handle, out, out_len, data, data_len, raw_tag, raw_tag_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_state_decrypt_detached(
+        handle,
+        out,
+        out_len,
+        data,
+        data_len,
+        raw_tag,
+        raw_tag_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1385,14 +2348,35 @@ pub fn symmetric_state_decrypt_detached(
 /// This operation is supported by some systems keeping a rolling state over an entire session, for forward security.
 ///
 /// `invalid_operation` is returned for algorithms not supporting ratcheting.
-pub fn symmetric_state_ratchet(handle: SymmetricState) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_state_ratchet(handle: SymmetricState) -> CryptoErrno;
+pub fn symmetric_state_ratchet(
+    handle: SymmetricState,
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_state_ratchet(
+                handle: SymmetricState,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_state_ratchet(
+            handle: SymmetricState,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_state_ratchet(handle)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let res = unsafe { symmetric_state_ratchet(handle) };
+    let res = unsafe { raw::symmetric_state_ratchet(
+        handle,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
@@ -1400,18 +2384,40 @@ pub fn symmetric_state_ratchet(handle: SymmetricState) -> Result<(), Error> {
 /// Return the length of an authentication tag.
 ///
 /// This function can be used by a guest to allocate the correct buffer size to copy a computed authentication tag.
-pub fn symmetric_tag_len(symmetric_tag: SymmetricTag) -> Result<Size, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_tag_len(
+pub fn symmetric_tag_len(
+    symmetric_tag: SymmetricTag,
+) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_tag_len(
+                symmetric_tag: SymmetricTag,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_tag_len(
             symmetric_tag: SymmetricTag,
             result_ptr: WasiMutPtr<Size>,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_tag_len(symmetric_tag)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe { symmetric_tag_len(symmetric_tag, result_ptr.as_mut_ptr()) };
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_tag_len(
+        symmetric_tag,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1434,20 +2440,44 @@ pub fn symmetric_tag_pull(
     symmetric_tag: SymmetricTag,
     buf: WasiMutPtr<u8>,
     buf_len: Size,
-) -> Result<Size, Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_tag_pull(
+) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_tag_pull(
+                symmetric_tag: SymmetricTag,
+                buf: WasiMutPtr<u8>,
+                buf_len: Size,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_tag_pull(
             symmetric_tag: SymmetricTag,
             buf: WasiMutPtr<u8>,
             buf_len: Size,
             result_ptr: WasiMutPtr<Size>,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_tag_pull(symmetric_tag, buf, buf_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let mut result_ptr = std::mem::MaybeUninit::uninit();
-    let res = unsafe { symmetric_tag_pull(symmetric_tag, buf, buf_len, result_ptr.as_mut_ptr()) };
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::symmetric_tag_pull(
+        symmetric_tag,
+        buf,
+        buf_len,
+        result_ptr.as_mut_ptr(),
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(unsafe { result_ptr.assume_init() })
 }
@@ -1471,19 +2501,39 @@ pub fn symmetric_tag_verify(
     symmetric_tag: SymmetricTag,
     expected_raw_tag_ptr: WasiPtr<u8>,
     expected_raw_tag_len: Size,
-) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_tag_verify(
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_tag_verify(
+                symmetric_tag: SymmetricTag,
+                expected_raw_tag_ptr: WasiPtr<u8>,
+                expected_raw_tag_len: Size,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_tag_verify(
             symmetric_tag: SymmetricTag,
             expected_raw_tag_ptr: WasiPtr<u8>,
             expected_raw_tag_len: Size,
-        ) -> CryptoErrno;
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_tag_verify(symmetric_tag, expected_raw_tag_ptr, expected_raw_tag_len)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let res =
-        unsafe { symmetric_tag_verify(symmetric_tag, expected_raw_tag_ptr, expected_raw_tag_len) };
+    let res = unsafe { raw::symmetric_tag_verify(
+        symmetric_tag,
+        expected_raw_tag_ptr,
+        expected_raw_tag_len,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
@@ -1493,14 +2543,812 @@ pub fn symmetric_tag_verify(
 /// This is usually not necessary, as `symmetric_tag_pull()` automatically closes a tag after it has been copied.
 ///
 /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
-pub fn symmetric_tag_close(symmetric_tag: SymmetricTag) -> Result<(), Error> {
-    #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
-    extern "C" {
-        fn symmetric_tag_close(symmetric_tag: SymmetricTag) -> CryptoErrno;
+pub fn symmetric_tag_close(
+    symmetric_tag: SymmetricTag,
+) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_symmetric")]
+        extern "C" {
+            pub fn symmetric_tag_close(
+                symmetric_tag: SymmetricTag,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn symmetric_tag_close(
+            symmetric_tag: SymmetricTag,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_symmetric_mock(|mock| mock.symmetric_tag_close(symmetric_tag)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
     }
-    let res = unsafe { symmetric_tag_close(symmetric_tag) };
+    let res = unsafe { raw::symmetric_tag_close(
+        symmetric_tag,
+    )};
     if res != 0 {
-        return Err(Error::WasiError(res as _));
+        return Err(WasiEphemeralCryptoSymmetricError::from(res));
     }
     Ok(())
 }
+
+/// Implementation of the functions of the `wasi_ephemeral_crypto_symmetric` module used on native targets.
+/// Install it with `set_wasi_ephemeral_crypto_symmetric_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait WasiEphemeralCryptoSymmetricMock {
+    /// Generate a new symmetric key for a given algorithm.
+    ///
+    /// `options` can be `None` to use the default parameters, or an algoritm-specific set of parameters to override.
+    ///
+    /// This function may return `unsupported_feature` if key generation is not supported by the host for the chosen algorithm, or `unsupported_algorithm` if the algorithm is not supported by the host.
+    #[allow(unused_variables)]
+    fn symmetric_key_generate(
+        &self,
+        algorithm_ptr: WasiPtr<Char8>,
+        algorithm_len: usize,
+        options: OptOptions,
+    ) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_generate")
+    }
+
+    /// Create a symmetric key from raw material.
+    ///
+    /// The algorithm is internally stored along with the key, and trying to use the key with an operation expecting a different algorithm will return `invalid_key`.
+    ///
+    /// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+    #[allow(unused_variables)]
+    fn symmetric_key_import(
+        &self,
+        algorithm_ptr: WasiPtr<Char8>,
+        algorithm_len: usize,
+        raw: WasiPtr<u8>,
+        raw_len: Size,
+    ) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_import")
+    }
+
+    /// Export a symmetric key as raw material.
+    ///
+    /// This is mainly useful to export a managed key.
+    ///
+    /// May return `prohibited_operation` if this operation is denied.
+    #[allow(unused_variables)]
+    fn symmetric_key_export(
+        &self,
+        symmetric_key: SymmetricKey,
+    ) -> Result<ArrayOutput, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_export")
+    }
+
+    /// Destroy a symmetric key.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    #[allow(unused_variables)]
+    fn symmetric_key_close(
+        &self,
+        symmetric_key: SymmetricKey,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_close")
+    }
+
+    /// __(optional)__
+    /// Generate a new managed symmetric key.
+    ///
+    /// The key is generated and stored by the secrets management facilities.
+    ///
+    /// It may be used through its identifier, but the host may not allow it to be exported.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+    /// or `unsupported_algorithm` if a key cannot be created for the chosen algorithm.
+    ///
+    /// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+    ///
+    /// This is also an optional import, meaning that the function may not even exist.
+    #[allow(unused_variables)]
+    fn symmetric_key_generate_managed(
+        &self,
+        secrets_manager: SecretsManager,
+        algorithm_ptr: WasiPtr<Char8>,
+        algorithm_len: usize,
+        options: OptOptions,
+    ) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_generate_managed")
+    }
+
+    /// __(optional)__
+    /// Store a symmetric key into the secrets manager.
+    ///
+    /// On success, the function stores the key identifier into `$symmetric_key_id`,
+    /// into which up to `$symmetric_key_id_max_len` can be written.
+    ///
+    /// The function returns `overflow` if the supplied buffer is too small.
+    #[allow(unused_variables)]
+    fn symmetric_key_store_managed(
+        &self,
+        secrets_manager: SecretsManager,
+        symmetric_key: SymmetricKey,
+        symmetric_key_id: WasiMutPtr<u8>,
+        symmetric_key_id_max_len: Size,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_store_managed")
+    }
+
+    /// __(optional)__
+    /// Replace a managed symmetric key.
+    ///
+    /// This function crates a new version of a managed symmetric key, by replacing `$kp_old` with `$kp_new`.
+    ///
+    /// It does several things:
+    ///
+    /// - The key identifier for `$symmetric_key_new` is set to the one of `$symmetric_key_old`.
+    /// - A new, unique version identifier is assigned to `$kp_new`. This version will be equivalent to using `$version_latest` until the key is replaced.
+    /// - The `$symmetric_key_old` handle is closed.
+    ///
+    /// Both keys must share the same algorithm and have compatible parameters. If this is not the case, `incompatible_keys` is returned.
+    ///
+    /// The function may also return the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+    /// or if keys cannot be rotated.
+    ///
+    /// Finally, `prohibited_operation` can be returned if `$symmetric_key_new` wasn't created by the secrets manager, and the secrets manager prohibits imported keys.
+    ///
+    /// If the operation succeeded, the new version is returned.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    #[allow(unused_variables)]
+    fn symmetric_key_replace_managed(
+        &self,
+        secrets_manager: SecretsManager,
+        symmetric_key_old: SymmetricKey,
+        symmetric_key_new: SymmetricKey,
+    ) -> Result<Version, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_replace_managed")
+    }
+
+    /// __(optional)__
+    /// Return the key identifier and version of a managed symmetric key.
+    ///
+    /// If the key is not managed, `unsupported_feature` is returned instead.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    #[allow(unused_variables)]
+    fn symmetric_key_id(
+        &self,
+        symmetric_key: SymmetricKey,
+        symmetric_key_id: WasiMutPtr<u8>,
+        symmetric_key_id_max_len: Size,
+    ) -> Result<(Size, Version), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_id")
+    }
+
+    /// __(optional)__
+    /// Return a managed symmetric key from a key identifier.
+    ///
+    /// `kp_version` can be set to `version_latest` to retrieve the most recent version of a symmetric key.
+    ///
+    /// If no key matching the provided information is found, `not_found` is returned instead.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    #[allow(unused_variables)]
+    fn symmetric_key_from_id(
+        &self,
+        secrets_manager: SecretsManager,
+        symmetric_key_id: WasiPtr<u8>,
+        symmetric_key_id_len: Size,
+        symmetric_key_version: Version,
+    ) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_key_from_id")
+    }
+
+    /// Create a new state to aborb and produce data using symmetric operations.
+    ///
+    /// The state remains valid after every operation in order to support incremental updates.
+    ///
+    /// The function has two optional parameters: a key and an options set.
+    ///
+    /// It will fail with a `key_not_supported` error code if a key was provided but the chosen algorithm doesn't natively support keying.
+    ///
+    /// On the other hand, if a key is required, but was not provided, a `key_required` error will be thrown.
+    ///
+    /// Some algorithms may require additional parameters. They have to be supplied as an options set:
+    ///
+    /// ```rust
+    /// let options_handle = ctx.options_open()?;
+    /// ctx.options_set("context", b"My application")?;
+    /// ctx.options_set_u64("fanout", 16)?;
+    /// let state_handle = ctx.symmetric_state_open("BLAKE2b-512", None, Some(options_handle))?;
+    /// ```
+    ///
+    /// If some parameters are mandatory but were not set, the `parameters_missing` error code will be returned.
+    ///
+    /// A notable exception is the `nonce` parameter, that is common to most AEAD constructions.
+    ///
+    /// If a nonce is required but was not supplied:
+    ///
+    /// - If it is safe to do so, the host will automatically generate a nonce. This is true for nonces that are large enough to be randomly generated, or if the host is able to maintain a global counter.
+    /// - If not, the function will fail and return the dedicated `nonce_required` error code.
+    ///
+    /// A nonce that was automatically generated can be retrieved after the function returns with `symmetric_state_get(state_handle, "nonce")`.
+    ///
+    /// **Sample usage patterns:**
+    ///
+    /// - **Hashing**
+    ///
+    /// ```rust
+    /// let mut out = [0u8; 64];
+    /// let state_handle = ctx.symmetric_state_open("SHAKE-128", None, None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+    /// ```
+    ///
+    /// - **MAC**
+    ///
+    /// ```rust
+    /// let mut raw_tag = [0u8; 64];
+    /// let key_handle = ctx.symmetric_key_import("HMAC/SHA-512", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+    /// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// ctx.symmetric_tag_pull(computed_tag_handle, &mut raw_tag)?;
+    /// ```
+    ///
+    /// Verification:
+    ///
+    /// ```rust
+    /// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+    /// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+    /// ```
+    ///
+    /// - **Tuple hashing**
+    ///
+    /// ```rust
+    /// let mut out = [0u8; 64];
+    /// let state_handle = ctx.symmetric_state_open("TupleHashXOF256", None, None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"value 1")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"value 2")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"value 3")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+    /// ```
+    /// Unlike MACs and regular hash functions, inputs are domain separated instead of being concatenated.
+    ///
+    /// - **Key derivation using extract-and-expand**
+    ///
+    /// Extract:
+    ///
+    /// ```rust
+    /// let mut prk = vec![0u8; 64];
+    /// let key_handle = ctx.symmetric_key_import("HKDF-EXTRACT/SHA-512", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("HKDF-EXTRACT/SHA-512", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"salt")?;
+    /// let prk_handle = ctx.symmetric_state_squeeze_key(state_handle, "HKDF-EXPAND/SHA-512")?;
+    /// ```
+    ///
+    /// Expand:
+    ///
+    /// ```rust
+    /// let mut subkey = vec![0u8; 32];
+    /// let state_handle = ctx.symmetric_state_open("HKDF-EXPAND/SHA-512", Some(prk_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"info")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut subkey)?;
+    /// ```
+    ///
+    /// - **Key derivation using a XOF**
+    ///
+    /// ```rust
+    /// let mut subkey1 = vec![0u8; 32];
+    /// let mut subkey2 = vec![0u8; 32];
+    /// let key_handle = ctx.symmetric_key_import("BLAKE3", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("BLAKE3", Some(key_handle), None)?;
+    /// ctx.symmetric_absorb(state_handle, b"context")?;
+    /// ctx.squeeze(state_handle, &mut subkey1)?;
+    /// ctx.squeeze(state_handle, &mut subkey2)?;
+    /// ```
+    ///
+    /// - **Password hashing**
+    ///
+    /// ```rust
+    /// let mut memory = vec![0u8; 1_000_000_000];
+    /// let options_handle = ctx.symmetric_options_open()?;
+    /// ctx.symmetric_options_set_guest_buffer(options_handle, "memory", &mut memory)?;
+    /// ctx.symmetric_options_set_u64(options_handle, "opslimit", 5)?;
+    /// ctx.symmetric_options_set_u64(options_handle, "parallelism", 8)?;
+    ///
+    /// let state_handle = ctx.symmetric_state_open("ARGON2-ID-13", None, Some(options))?;
+    /// ctx.symmtric_state_absorb(state_handle, b"password")?;
+    ///
+    /// let pw_str_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// let mut pw_str = vec![0u8; ctx.symmetric_tag_len(pw_str_handle)?];
+    /// ctx.symmetric_tag_pull(pw_str_handle, &mut pw_str)?;
+    /// ```
+    ///
+    /// - **AEAD encryption with an explicit nonce**
+    ///
+    /// ```rust
+    /// let key_handle = ctx.symmetric_key_generate("AES-256-GCM", None)?;
+    /// let message = b"test";
+    ///
+    /// let options_handle = ctx.symmetric_options_open()?;
+    /// ctx.symmetric_options_set(options_handle, "nonce", nonce)?;
+    ///
+    /// let state_handle = ctx.symmetric_state_open("AES-256-GCM", Some(key_handle), Some(options_handle))?;
+    /// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+    /// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+    /// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+    /// ```
+    ///
+    /// - **AEAD encryption with automatic nonce generation**
+    ///
+    /// ```rust
+    /// let key_handle = ctx.symmetric_key_generate("AES-256-GCM-SIV", None)?;
+    /// let message = b"test";
+    /// let mut nonce = [0u8; 24];
+    ///
+    /// let state_handle = ctx.symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), None)?;
+    ///
+    /// let nonce_handle = ctx.symmetric_state_options_get(state_handle, "nonce")?;
+    /// ctx.array_output_pull(nonce_handle, &mut nonce)?;
+    ///
+    /// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+    /// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+    /// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+    /// ```
+    ///
+    /// - **Session authenticated modes**
+    ///
+    /// ```rust
+    /// let mut out = [0u8; 16];
+    /// let mut out2 = [0u8; 16];
+    /// let mut ciphertext = [0u8; 20];
+    /// let key_handle = ctx.symmetric_key_generate("Xoodyak-128", None)?;
+    /// let state_handle = ctx.symmetric_state_open("Xoodyak-128", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, b"abcd")?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+    /// ctx.symmetric_state_squeeze(state_handle, &mut out2)?;
+    /// ctx.symmetric_state_ratchet(state_handle)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+    /// let next_key_handle = ctx.symmetric_state_squeeze_key(state_handle, "Xoodyak-128")?;
+    /// // ...
+    /// ```
+    #[allow(unused_variables)]
+    fn symmetric_state_open(
+        &self,
+        algorithm_ptr: WasiPtr<Char8>,
+        algorithm_len: usize,
+        key: OptSymmetricKey,
+        options: OptOptions,
+    ) -> Result<SymmetricState, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_open")
+    }
+
+    /// Retrieve a parameter from the current state.
+    ///
+    /// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+    ///
+    /// The function may return `options_not_set` if an option was not set, which is different from an empty value.
+    ///
+    /// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+    #[allow(unused_variables)]
+    fn symmetric_state_options_get(
+        &self,
+        handle: SymmetricState,
+        name_ptr: WasiPtr<Char8>,
+        name_len: usize,
+        value: WasiMutPtr<u8>,
+        value_max_len: Size,
+    ) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_options_get")
+    }
+
+    /// Retrieve an integer parameter from the current state.
+    ///
+    /// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+    ///
+    /// The function may return `options_not_set` if an option was not set.
+    ///
+    /// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+    #[allow(unused_variables)]
+    fn symmetric_state_options_get_u64(
+        &self,
+        handle: SymmetricState,
+        name_ptr: WasiPtr<Char8>,
+        name_len: usize,
+    ) -> Result<U64, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_options_get_u64")
+    }
+
+    /// Destroy a symmetric state.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    #[allow(unused_variables)]
+    fn symmetric_state_close(
+        &self,
+        handle: SymmetricState,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_close")
+    }
+
+    /// Absorb data into the state.
+    ///
+    /// - **Hash functions:** adds data to be hashed.
+    /// - **MAC functions:** adds data to be authenticated.
+    /// - **Tuplehash-like constructions:** adds a new tuple to the state.
+    /// - **Key derivation functions:** adds to the IKM or to the subkey information.
+    /// - **AEAD constructions:** adds additional data to be authenticated.
+    /// - **Stateful hash objects, permutation-based constructions:** absorbs.
+    ///
+    /// If the chosen algorithm doesn't accept input data, the `invalid_operation` error code is returned.
+    ///
+    /// If too much data has been fed for the algorithm, `overflow` may be thrown.
+    #[allow(unused_variables)]
+    fn symmetric_state_absorb(
+        &self,
+        handle: SymmetricState,
+        data: WasiPtr<u8>,
+        data_len: Size,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_absorb")
+    }
+
+    /// Squeeze bytes from the state.
+    ///
+    /// - **Hash functions:** this tries to output an `out_len` bytes digest from the absorbed data. The hash function output will be truncated if necessary. If the requested size is too large, the `invalid_len` error code is returned.
+    /// - **Key derivation functions:** : outputs an arbitrary-long derived key.
+    /// - **RNGs, DRBGs, stream ciphers:**: outputs arbitrary-long data.
+    /// - **Stateful hash objects, permutation-based constructions:** squeeze.
+    ///
+    /// Other kinds of algorithms may return `invalid_operation` instead.
+    ///
+    /// For password-stretching functions, the function may return `in_progress`.
+    /// In that case, the guest should retry with the same parameters until the function completes.
+    #[allow(unused_variables)]
+    fn symmetric_state_squeeze(
+        &self,
+        handle: SymmetricState,
+        out: WasiMutPtr<u8>,
+        out_len: Size,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_squeeze")
+    }
+
+    /// Compute and return a tag for all the data injected into the state so far.
+    ///
+    /// - **MAC functions**: returns a tag authenticating the absorbed data.
+    /// - **Tuplehash-like constructions:** returns a tag authenticating all the absorbed tuples.
+    /// - **Password-hashing functions:** returns a standard string containing all the required parameters for password verification.
+    ///
+    /// Other kinds of algorithms may return `invalid_operation` instead.
+    ///
+    /// For password-stretching functions, the function may return `in_progress`.
+    /// In that case, the guest should retry with the same parameters until the function completes.
+    #[allow(unused_variables)]
+    fn symmetric_state_squeeze_tag(
+        &self,
+        handle: SymmetricState,
+    ) -> Result<SymmetricTag, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_squeeze_tag")
+    }
+
+    /// Use the current state to produce a key for a target algorithm.
+    ///
+    /// For extract-then-expand constructions, this returns the PRK.
+    /// For session-base authentication encryption, this returns a key that can be used to resume a session without storing a nonce.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting this operation.
+    #[allow(unused_variables)]
+    fn symmetric_state_squeeze_key(
+        &self,
+        handle: SymmetricState,
+        alg_str_ptr: WasiPtr<Char8>,
+        alg_str_len: usize,
+    ) -> Result<SymmetricKey, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_squeeze_key")
+    }
+
+    /// Return the maximum length of an authentication tag for the current algorithm.
+    ///
+    /// This allows guests to compute the size required to store a ciphertext along with its authentication tag.
+    ///
+    /// The returned length may include the encryption mode's padding requirements in addition to the actual tag.
+    ///
+    /// For an encryption operation, the size of the output buffer should be `input_len + symmetric_state_max_tag_len()`.
+    ///
+    /// For a decryption operation, the size of the buffer that will store the decrypted data must be `ciphertext_len - symmetric_state_max_tag_len()`.
+    #[allow(unused_variables)]
+    fn symmetric_state_max_tag_len(
+        &self,
+        handle: SymmetricState,
+    ) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_max_tag_len")
+    }
+
+    /// Encrypt data with an attached tag.
+    ///
+    /// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+    /// - **AEAD:** encrypts `data` into `out`, including the authentication tag to the output. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The `symmetric_state_max_tag_len()` function can be used to retrieve the overhead of adding the tag, as well as padding if necessary.
+    /// - **SHOE, Xoodyak, Strobe:** encrypts data, squeezes a tag and appends it to the output.
+    ///
+    /// If `out` and `data` are the same address, encryption may happen in-place.
+    ///
+    /// The function returns the actual size of the ciphertext along with the tag.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    #[allow(unused_variables)]
+    fn symmetric_state_encrypt(
+        &self,
+        handle: SymmetricState,
+        out: WasiMutPtr<u8>,
+        out_len: Size,
+        data: WasiPtr<u8>,
+        data_len: Size,
+    ) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_encrypt")
+    }
+
+    /// Encrypt data, with a detached tag.
+    ///
+    /// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+    /// - **AEAD:** encrypts `data` into `out` and returns the tag separately. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The output and input buffers must be of the same length.
+    /// - **SHOE, Xoodyak, Strobe:** encrypts data and squeezes a tag.
+    ///
+    /// If `out` and `data` are the same address, encryption may happen in-place.
+    ///
+    /// The function returns the tag.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    #[allow(unused_variables)]
+    fn symmetric_state_encrypt_detached(
+        &self,
+        handle: SymmetricState,
+        out: WasiMutPtr<u8>,
+        out_len: Size,
+        data: WasiPtr<u8>,
+        data_len: Size,
+    ) -> Result<SymmetricTag, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_encrypt_detached")
+    }
+
+    /// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+    /// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+    /// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the one that was appended to the ciphertext.
+    ///
+    /// If `out` and `data` are the same address, decryption may happen in-place.
+    ///
+    /// `out_len` must be exactly `data_len` + `max_tag_len` bytes.
+    ///
+    /// The function returns the actual size of the decrypted message, which can be smaller than `out_len` for modes that requires padding.
+    ///
+    /// `invalid_tag` is returned if the tag didn't verify.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    #[allow(unused_variables)]
+    fn symmetric_state_decrypt(
+        &self,
+        handle: SymmetricState,
+        out: WasiMutPtr<u8>,
+        out_len: Size,
+        data: WasiPtr<u8>,
+        data_len: Size,
+    ) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_decrypt")
+    }
+
+    /// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+    /// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+    /// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the expected one.
+    ///
+    /// `raw_tag` is the expected tag, as raw bytes.
+    ///
+    /// `out` and `data` be must have the same length.
+    /// If they also share the same address, decryption may happen in-place.
+    ///
+    /// The function returns the actual size of the decrypted message.
+    ///
+    /// `invalid_tag` is returned if the tag verification failed.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting encryption.
+    #[allow(unused_variables)]
+    fn symmetric_state_decrypt_detached(
+        &self,
+        handle: SymmetricState,
+        out: WasiMutPtr<u8>,
+        out_len: Size,
+        data: WasiPtr<u8>,
+        data_len: Size,
+        raw_tag: WasiPtr<u8>,
+        raw_tag_len: Size,
+    ) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_decrypt_detached")
+    }
+
+    /// Make it impossible to recover the previous state.
+    ///
+    /// This operation is supported by some systems keeping a rolling state over an entire session, for forward security.
+    ///
+    /// `invalid_operation` is returned for algorithms not supporting ratcheting.
+    #[allow(unused_variables)]
+    fn symmetric_state_ratchet(
+        &self,
+        handle: SymmetricState,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_state_ratchet")
+    }
+
+    /// Return the length of an authentication tag.
+    ///
+    /// This function can be used by a guest to allocate the correct buffer size to copy a computed authentication tag.
+    #[allow(unused_variables)]
+    fn symmetric_tag_len(
+        &self,
+        symmetric_tag: SymmetricTag,
+    ) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_tag_len")
+    }
+
+    /// Copy an authentication tag into a guest-allocated buffer.
+    ///
+    /// The handle automatically becomes invalid after this operation. Manually closing it is not required.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let mut raw_tag = [0u8; 16];
+    /// ctx.symmetric_tag_pull(raw_tag_handle, &mut raw_tag)?;
+    /// ```
+    ///
+    /// The function returns `overflow` if the supplied buffer is too small to copy the tag.
+    ///
+    /// Otherwise, it returns the number of bytes that have been copied.
+    #[allow(unused_variables)]
+    fn symmetric_tag_pull(
+        &self,
+        symmetric_tag: SymmetricTag,
+        buf: WasiMutPtr<u8>,
+        buf_len: Size,
+    ) -> Result<Size, WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_tag_pull")
+    }
+
+    /// Verify that a computed authentication tag matches the expected value, in constant-time.
+    ///
+    /// The expected tag must be provided as a raw byte string.
+    ///
+    /// The function returns `invalid_tag` if the tags don't match.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let key_handle = ctx.symmetric_key_import("HMAC/SHA-256", b"key")?;
+    /// let state_handle = ctx.symmetric_state_open("HMAC/SHA-256", Some(key_handle), None)?;
+    /// ctx.symmetric_state_absorb(state_handle, b"data")?;
+    /// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+    /// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+    /// ```
+    #[allow(unused_variables)]
+    fn symmetric_tag_verify(
+        &self,
+        symmetric_tag: SymmetricTag,
+        expected_raw_tag_ptr: WasiPtr<u8>,
+        expected_raw_tag_len: Size,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_tag_verify")
+    }
+
+    /// Explicitly destroy an unused authentication tag.
+    ///
+    /// This is usually not necessary, as `symmetric_tag_pull()` automatically closes a tag after it has been copied.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    #[allow(unused_variables)]
+    fn symmetric_tag_close(
+        &self,
+        symmetric_tag: SymmetricTag,
+    ) -> Result<(), WasiEphemeralCryptoSymmetricError> {
+        unimplemented!("symmetric_tag_close")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static WASI_EPHEMERAL_CRYPTO_SYMMETRIC_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn WasiEphemeralCryptoSymmetricMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `wasi_ephemeral_crypto_symmetric` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_wasi_ephemeral_crypto_symmetric_mock(mock: impl WasiEphemeralCryptoSymmetricMock + 'static) -> Option<std::rc::Rc<dyn WasiEphemeralCryptoSymmetricMock>> {
+    WASI_EPHEMERAL_CRYPTO_SYMMETRIC_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_wasi_ephemeral_crypto_symmetric_mock<R>(f: impl FnOnce(&dyn WasiEphemeralCryptoSymmetricMock) -> R) -> R {
+    let mock = WASI_EPHEMERAL_CRYPTO_SYMMETRIC_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `wasi_ephemeral_crypto_symmetric` module installed"))
+}
+
+/// Owned `SymmetricState` handle, closed with `symmetric_state_close()` when dropped.
+#[derive(Debug)]
+pub struct OwnedSymmetricState(SymmetricState);
+
+impl OwnedSymmetricState {
+    pub fn new(handle: SymmetricState) -> Self {
+        OwnedSymmetricState(handle)
+    }
+
+    pub fn get(&self) -> SymmetricState {
+        self.0
+    }
+
+    pub fn release(self) -> SymmetricState {
+        let handle = self.0;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedSymmetricState {
+    fn drop(&mut self) {
+        let _ = symmetric_state_close(self.0);
+    }
+}
+
+/// Owned `SymmetricKey` handle, closed with `symmetric_key_close()` when dropped.
+#[derive(Debug)]
+pub struct OwnedSymmetricKey(SymmetricKey);
+
+impl OwnedSymmetricKey {
+    pub fn new(handle: SymmetricKey) -> Self {
+        OwnedSymmetricKey(handle)
+    }
+
+    pub fn get(&self) -> SymmetricKey {
+        self.0
+    }
+
+    pub fn release(self) -> SymmetricKey {
+        let handle = self.0;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedSymmetricKey {
+    fn drop(&mut self) {
+        let _ = symmetric_key_close(self.0);
+    }
+}
+
+/// Owned `SymmetricTag` handle, closed with `symmetric_tag_close()` when dropped.
+#[derive(Debug)]
+pub struct OwnedSymmetricTag(SymmetricTag);
+
+impl OwnedSymmetricTag {
+    pub fn new(handle: SymmetricTag) -> Self {
+        OwnedSymmetricTag(handle)
+    }
+
+    pub fn get(&self) -> SymmetricTag {
+        self.0
+    }
+
+    pub fn release(self) -> SymmetricTag {
+        let handle = self.0;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedSymmetricTag {
+    fn drop(&mut self) {
+        let _ = symmetric_tag_close(self.0);
+    }
+}
+
@@ -1,3 +1,4 @@
+
 //
 // This file was automatically generated by witx-codegen - Do not edit manually.
 //
@@ -58,72 +59,174 @@ pub fn WasiMutSlice(comptime T: type) type {
 
 // ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
 
+pub const Handle = enum(WasiHandle) { _ };
+
 /// Error codes.
 pub const CryptoErrno = enum(u16) {
+    /// Operation succeeded.
     SUCCESS = 0,
+    /// An error occurred when trying to during a conversion from a host type to a guest type.
+    ///
+    /// Only an internal bug can throw this error.
     GUEST_ERROR = 1,
+    /// The requested operation is valid, but not implemented by the host.
     NOT_IMPLEMENTED = 2,
+    /// The requested feature is not supported by the chosen algorithm.
     UNSUPPORTED_FEATURE = 3,
+    /// The requested operation is valid, but was administratively prohibited.
     PROHIBITED_OPERATION = 4,
+    /// Unsupported encoding for an import or export operation.
     UNSUPPORTED_ENCODING = 5,
+    /// The requested algorithm is not supported by the host.
     UNSUPPORTED_ALGORITHM = 6,
+    /// The requested option is not supported by the currently selected algorithm.
     UNSUPPORTED_OPTION = 7,
+    /// An invalid or incompatible key was supplied.
+    ///
+    /// The key may not be valid, or was generated for a different algorithm or parameters set.
     INVALID_KEY = 8,
+    /// The currently selected algorithm doesn't support the requested output length.
+    ///
+    /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
     INVALID_LENGTH = 9,
+    /// A signature or authentication tag verification failed.
     VERIFICATION_FAILED = 10,
+    /// A secure random numbers generator is not available.
+    ///
+    /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
     RNG_ERROR = 11,
+    /// An error was returned by the underlying cryptography library.
+    ///
+    /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    ///
+    /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    ///
+    /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
     ALGORITHM_FAILURE = 12,
+    /// The supplied signature is invalid, or incompatible with the chosen algorithm.
     INVALID_SIGNATURE = 13,
+    /// An attempt was made to close a handle that was already closed.
     CLOSED = 14,
+    /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
     INVALID_HANDLE = 15,
+    /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
     OVERFLOW = 16,
+    /// An internal error occurred.
+    ///
+    /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
     INTERNAL_ERROR = 17,
+    /// Too many handles are currently open, and a new one cannot be created.
+    ///
+    /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
     TOO_MANY_HANDLES = 18,
+    /// A key was provided, but the chosen algorithm doesn't support keys.
+    ///
+    /// This is returned by symmetric operations.
+    ///
+    /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+    /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    ///
+    /// These functions must refuse to create the context and return this error instead.
     KEY_NOT_SUPPORTED = 19,
+    /// A key is required for the chosen algorithm, but none was given.
     KEY_REQUIRED = 20,
+    /// The provided authentication tag is invalid or incompatible with the current algorithm.
+    ///
+    /// This error is returned by decryption functions and tag verification functions.
+    ///
+    /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
     INVALID_TAG = 21,
+    /// The requested operation is incompatible with the current scheme.
+    ///
+    /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    /// This error code will be returned instead.
     INVALID_OPERATION = 22,
+    /// A nonce is required.
+    ///
+    /// Most encryption schemes require a nonce.
+    ///
+    /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
     NONCE_REQUIRED = 23,
+    /// The provided nonce doesn't have a correct size for the given cipher.
     INVALID_NONCE = 24,
+    /// The named option was not set.
+    ///
+    /// The caller tried to read the value of an option that was not set.
+    /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
     OPTION_NOT_SET = 25,
+    /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+    ///
+    /// This error is returned by a secrets manager via the `keypair_from_id()` function.
     NOT_FOUND = 26,
+    /// The algorithm requires parameters that haven't been set.
+    ///
+    /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
     PARAMETERS_MISSING = 27,
+    /// A requested computation is not done yet, and additional calls to the function are required.
+    ///
+    /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    ///
+    /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
     IN_PROGRESS = 28,
+    /// Multiple keys have been provided, but they do not share the same type.
+    ///
+    /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
     INCOMPATIBLE_KEYS = 29,
+    /// A managed key or secret expired and cannot be used any more.
     EXPIRED = 30,
 };
 
 /// Encoding to use for importing or exporting a key pair.
 pub const KeypairEncoding = enum(u16) {
+    /// Raw bytes.
     RAW = 0,
-    PKCS_8 = 1,
+    /// PCSK8/DER encoding.
+    PKCS8 = 1,
+    /// PEM encoding.
     PEM = 2,
+    /// Implementation-defined encoding.
     LOCAL = 3,
 };
 
 /// Encoding to use for importing or exporting a public key.
 pub const PublickeyEncoding = enum(u16) {
+    /// Raw bytes.
     RAW = 0,
-    PKCS_8 = 1,
+    /// PKCS8/DER encoding.
+    PKCS8 = 1,
+    /// PEM encoding.
     PEM = 2,
+    /// SEC encoding.
     SEC = 3,
+    /// Compressed SEC encoding.
     COMPRESSED_SEC = 4,
+    /// Implementation-defined encoding.
     LOCAL = 5,
 };
 
 /// Encoding to use for importing or exporting a secret key.
 pub const SecretkeyEncoding = enum(u16) {
+    /// Raw bytes.
     RAW = 0,
-    PKCS_8 = 1,
+    /// PKCS8/DER encoding.
+    PKCS8 = 1,
+    /// PEM encoding.
     PEM = 2,
+    /// SEC encoding.
     SEC = 3,
+    /// Compressed SEC encoding.
     COMPRESSED_SEC = 4,
+    /// Implementation-defined encoding.
     LOCAL = 5,
 };
 
 /// Encoding to use for importing or exporting a signature.
 pub const SignatureEncoding = enum(u16) {
+    /// Raw bytes.
     RAW = 0,
+    /// DER encoding.
     DER = 1,
 };
 
@@ -156,54 +259,54 @@ pub const U64 = u64;
 /// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
 ///
 /// An `array_output` handle is automatically closed after its full content has been consumed.
-pub const ArrayOutput = WasiHandle;
+pub const ArrayOutput = enum(WasiHandle) { _ };
 
 /// A set of options.
 ///
 /// This type is used to set non-default parameters.
 ///
 /// The exact set of allowed options depends on the algorithm being used.
-pub const Options = WasiHandle;
+pub const Options = enum(WasiHandle) { _ };
 
 /// A handle to the optional secrets management facilities offered by a host.
 ///
 /// This is used to generate, retrieve and invalidate managed keys.
-pub const SecretsManager = WasiHandle;
+pub const SecretsManager = enum(WasiHandle) { _ };
 
 /// A key pair.
-pub const Keypair = WasiHandle;
+pub const Keypair = enum(WasiHandle) { _ };
 
 /// A state to absorb data to be signed.
 ///
 /// After a signature has been computed or verified, the state remains valid for further operations.
 ///
 /// A subsequent signature would sign all the data accumulated since the creation of the state object.
-pub const SignatureState = WasiHandle;
+pub const SignatureState = enum(WasiHandle) { _ };
 
 /// A signature.
-pub const Signature = WasiHandle;
+pub const Signature = enum(WasiHandle) { _ };
 
 /// A public key, for key exchange and signature verification.
-pub const Publickey = WasiHandle;
+pub const Publickey = enum(WasiHandle) { _ };
 
 /// A secret key, for key exchange mechanisms.
-pub const Secretkey = WasiHandle;
+pub const Secretkey = enum(WasiHandle) { _ };
 
 /// A state to absorb signed data to be verified.
-pub const SignatureVerificationState = WasiHandle;
+pub const SignatureVerificationState = enum(WasiHandle) { _ };
 
 /// A state to perform symmetric operations.
 ///
 /// The state is not reset nor invalidated after an option has been performed.
 /// Incremental updates and sessions are thus supported.
-pub const SymmetricState = WasiHandle;
+pub const SymmetricState = enum(WasiHandle) { _ };
 
 /// A symmetric key.
 ///
 /// The key can be imported from raw bytes, or can be a reference to a managed key.
 ///
 /// If it was imported, the host will wipe it from memory as soon as the handle is closed.
-pub const SymmetricKey = WasiHandle;
+pub const SymmetricKey = enum(WasiHandle) { _ };
 
 /// An authentication tag.
 ///
@@ -214,7 +317,7 @@ pub const SymmetricKey = WasiHandle;
 /// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
 ///
 /// The host is reponsible for securely wiping them from memory on close.
-pub const SymmetricTag = WasiHandle;
+pub const SymmetricTag = enum(WasiHandle) { _ };
 
 /// Options index, only required by the Interface Types translation layer.
 pub const OptOptionsU = enum(u8) {
@@ -232,38 +335,41 @@ pub const OptOptions = extern struct {
     },
     __pad8_0: u8 = undefined,
     __pad16_0: u16 = undefined,
-    __pad32_0: u32 = undefined,
     member = extern union {
         some: Options,
     },
 
-    fn newSome(val: Options) OptOptions {
-        return OptOptions{ .tag = .some, .member = .{ .some = val } };
-    }
 
-    pub fn Some(self: OptOptions) Options {
-        std.debug.assert(self.tag == .some);
-        return self.member.some;
-    }
+fn newSome(val: Options) OptOptions {
+    return OptOptions{ .tag = .some, .member = .{ .some = val } };
+}
 
-    pub fn setSome(self: *OptOptions, val: Options) void {
-        std.debug.assert(self.tag == .some);
-        self.member.some = val;
-    }
+pub fn Some(self: OptOptions) Options {
+    std.debug.assert(self.tag == .some);
+    return self.member.some;
+}
 
-    fn isSome(self: OptOptions) bool {
-        return self.tag == .some;
-    }
+pub fn setSome(self: *OptOptions, val: Options) void {
+    std.debug.assert(self.tag == .some);
+    self.member.some = val;
+}
 
-    fn newNone() OptOptions {
-        return OptOptions{ .tag = .none };
-    }
+fn isSome(self: OptOptions) bool {
+    return self.tag == .some;
+}
+
+
+fn newNone() OptOptions {
+    return OptOptions { .tag = .none };
+}
+
+fn isNone(self: OptOptions) bool {
+    return self.tag == .none;
+}
 
-    fn isNone(self: OptOptions) bool {
-        return self.tag == .none;
-    }
 };
 
+
 /// Symmetric key index, only required by the Interface Types translation layer.
 pub const OptSymmetricKeyU = enum(u8) {
     SOME = 0,
@@ -280,38 +386,41 @@ pub const OptSymmetricKey = extern struct {
     },
     __pad8_0: u8 = undefined,
     __pad16_0: u16 = undefined,
-    __pad32_0: u32 = undefined,
     member = extern union {
         some: SymmetricKey,
     },
 
-    fn newSome(val: SymmetricKey) OptSymmetricKey {
-        return OptSymmetricKey{ .tag = .some, .member = .{ .some = val } };
-    }
 
-    pub fn Some(self: OptSymmetricKey) SymmetricKey {
-        std.debug.assert(self.tag == .some);
-        return self.member.some;
-    }
+fn newSome(val: SymmetricKey) OptSymmetricKey {
+    return OptSymmetricKey{ .tag = .some, .member = .{ .some = val } };
+}
 
-    pub fn setSome(self: *OptSymmetricKey, val: SymmetricKey) void {
-        std.debug.assert(self.tag == .some);
-        self.member.some = val;
-    }
+pub fn Some(self: OptSymmetricKey) SymmetricKey {
+    std.debug.assert(self.tag == .some);
+    return self.member.some;
+}
 
-    fn isSome(self: OptSymmetricKey) bool {
-        return self.tag == .some;
-    }
+pub fn setSome(self: *OptSymmetricKey, val: SymmetricKey) void {
+    std.debug.assert(self.tag == .some);
+    self.member.some = val;
+}
 
-    fn newNone() OptSymmetricKey {
-        return OptSymmetricKey{ .tag = .none };
-    }
+fn isSome(self: OptSymmetricKey) bool {
+    return self.tag == .some;
+}
+
+
+fn newNone() OptSymmetricKey {
+    return OptSymmetricKey { .tag = .none };
+}
+
+fn isNone(self: OptSymmetricKey) bool {
+    return self.tag == .none;
+}
 
-    fn isNone(self: OptSymmetricKey) bool {
-        return self.tag == .none;
-    }
 };
 
+
 pub const WasiEphemeralCryptoSymmetric = struct {
     /// Generate a new symmetric key for a given algorithm.
     ///
@@ -428,8 +537,8 @@ pub const WasiEphemeralCryptoSymmetric = struct {
         symmetric_key: SymmetricKey,
         symmetric_key_id: WasiMutPtr(u8),
         symmetric_key_id_max_len: Size,
-        result_0_ptr: WasiMutPtr(Size),
-        result_1_ptr: WasiMutPtr(Version),
+        result0_ptr: WasiMutPtr(Size),
+        result1_ptr: WasiMutPtr(Version),
     ) callconv(.C) CryptoErrno;
 
     /// __(optional)__
@@ -900,4 +1009,6 @@ pub const WasiEphemeralCryptoSymmetric = struct {
     pub extern "wasi_ephemeral_crypto_symmetric" fn symmetric_tag_close(
         symmetric_tag: SymmetricTag,
     ) callconv(.C) CryptoErrno;
+
 };
+
@@ -0,0 +1,27 @@
+//! Annotations are lines of documentation configuring the generated code, that are left out of
+//! the generated documentation.
+
+/// Marks a function implemented by the guest, see `signature::is_export`.
+pub const EXPORT_ANNOTATION: &str = "@export";
+
+/// Names the function closing handles of a type, see `handles::close_function_for`.
+pub const CLOSE_ANNOTATION: &str = "@close";
+
+/// Return `true` if `line` is an annotation.
+pub fn is_annotation(line: &str) -> bool {
+    matches!(
+        line.split_whitespace().next(),
+        Some(EXPORT_ANNOTATION) | Some(CLOSE_ANNOTATION)
+    )
+}
+
+/// Return `docs` without its annotations, and without the blank lines they leave at the end.
+pub fn strip_annotations(docs: &str) -> String {
+    let mut stripped = docs
+        .lines()
+        .filter(|line| !is_annotation(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    stripped.truncate(stripped.trim_end().len());
+    stripped
+}
@@ -1,6 +1,7 @@
 use super::tuple::Tuple;
 use crate::astype::*;
-use convert_case::{Case, Casing};
+use crate::naming::{to_case, NameKind, Namer};
+use convert_case::Case;
 
 pub trait IsNullable {
     fn is_nullable(&self) -> bool;
@@ -22,31 +23,118 @@ impl IsNullable for ASType {
     }
 }
 
+/// Reserved words, that are suffixed with `_` when used as identifiers
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "constructor",
+    "continue",
+    "debugger",
+    "declare",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "get",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "is",
+    "keyof",
+    "let",
+    "module",
+    "namespace",
+    "new",
+    "null",
+    "of",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "set",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+    "bool",
+    "f32",
+    "f64",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "isize",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "usize",
+    "v128",
+];
+
+fn escape_keyword(identifier: String) -> String {
+    if KEYWORDS.contains(&identifier.as_str()) {
+        format!("{}_", identifier)
+    } else {
+        identifier
+    }
+}
+
 pub trait Normalize {
     fn as_str(&self) -> &str;
 
-    fn as_type(&self) -> String {
-        self.as_str().to_case(Case::Pascal)
+    fn as_type(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Type, self.as_str(), Case::Pascal))
     }
 
-    fn as_fn(&self) -> String {
-        self.as_str().to_case(Case::Camel)
+    fn as_fn(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Fn, self.as_str(), Case::Camel))
     }
 
     fn as_fn_suffix(&self) -> String {
-        self.as_str().to_case(Case::UpperCamel)
+        to_case(self.as_str(), Case::UpperCamel)
     }
 
-    fn as_var(&self) -> String {
-        self.as_str().to_case(Case::Snake)
+    fn as_var(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Var, self.as_str(), Case::Snake))
     }
 
-    fn as_const(&self) -> String {
-        self.as_str().to_case(Case::UpperSnake)
+    fn as_const(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Const, self.as_str(), Case::UpperSnake))
     }
 
-    fn as_namespace(&self) -> String {
-        self.as_str().to_case(Case::Pascal)
+    fn as_namespace(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Namespace, self.as_str(), Case::Pascal))
     }
 }
 
@@ -59,21 +147,17 @@ impl<T: AsRef<str>> Normalize for T {
 pub trait ToLanguageRepresentation {
     fn as_astype(&self) -> &ASType;
 
-    fn to_string(&self) -> String {
-        self.as_lang()
-    }
-
-    fn as_lang(&self) -> String {
+    fn as_lang(&self, n: &Namer) -> String {
         match self.as_astype() {
-            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Alias(alias) => alias.name.as_type(n),
             ASType::Bool => "bool".to_string(),
             ASType::Char32 => "Char32".to_string(),
             ASType::Char8 => "Char8".to_string(),
             ASType::F32 => "f32".to_string(),
             ASType::F64 => "f64".to_string(),
-            ASType::Handle(_resource_name) => "WasiHandle".to_string(),
-            ASType::ConstPtr(pointee) => format!("WasiPtr<{}>", pointee.to_string()),
-            ASType::MutPtr(pointee) => format!("WasiMutPtr<{}>", pointee.to_string()),
+            ASType::Handle(resource_name) => resource_name.as_type(n),
+            ASType::ConstPtr(pointee) => format!("WasiPtr<{}>", pointee.as_lang(n)),
+            ASType::MutPtr(pointee) => format!("WasiMutPtr<{}>", pointee.as_lang(n)),
             ASType::Option(_) => todo!(),
             ASType::Result(_) => todo!(),
             ASType::S8 => "i8".to_string(),
@@ -88,16 +172,16 @@ pub trait ToLanguageRepresentation {
             ASType::Void => "void".to_string(),
             ASType::Constants(_) => unimplemented!(),
             ASType::Enum(enum_) => {
-                format!("{} /* Enum */", enum_.repr.as_ref().as_lang())
+                format!("{} /* Enum */", enum_.repr.as_ref().as_lang(n))
             }
             ASType::Struct(_) => unimplemented!(),
-            ASType::Tuple(tuple_members) => Tuple::name_for(tuple_members).as_type(),
+            ASType::Tuple(tuple_members) => Tuple::name_for(n, tuple_members).as_type(n),
             ASType::Union(_) => unimplemented!(),
-            ASType::Slice(element_type) => format!("WasiMutSlice<{}>", element_type.as_lang()),
+            ASType::Slice(element_type) => format!("WasiMutSlice<{}>", element_type.as_lang(n)),
             ASType::String(_) => "WasiString".to_string(),
-            ASType::ReadBuffer(element_type) => format!("WasiSlice<{}>", element_type.as_lang()),
+            ASType::ReadBuffer(element_type) => format!("WasiSlice<{}>", element_type.as_lang(n)),
             ASType::WriteBuffer(element_type) => {
-                format!("WasiMutSlice<{}>", element_type.to_string())
+                format!("WasiMutSlice<{}>", element_type.as_lang(n))
             }
         }
     }
@@ -105,6 +189,6 @@ pub trait ToLanguageRepresentation {
 
 impl ToLanguageRepresentation for ASType {
     fn as_astype(&self) -> &ASType {
-        &self
+        self
     }
 }
@@ -4,64 +4,45 @@ use std::io::Write;
 impl AssemblyScriptGenerator {
     pub fn define_func<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         module_name: &str,
         func_witx: &witx::Function,
     ) -> Result<(), Error> {
-        assert_eq!(func_witx.abi, witx::Abi::Preview1);
-        let name = func_witx.name.as_str().to_string();
-        let params_witx = &func_witx.params;
-        let mut params = vec![];
-        for param_witx in params_witx {
-            let param_name = param_witx.name.as_str();
-            let param_type = ASType::from(&param_witx.tref);
-            params.push((param_name.to_string(), param_type));
-        }
-
-        let results_witx = &func_witx.results;
-        assert_eq!(results_witx.len(), 1);
-        let result_witx = &results_witx[0];
-        let result = ASType::from(&result_witx.tref);
-        let result = match result {
-            ASType::Result(result) => result,
-            _ => unreachable!(),
-        };
-
-        let ok_type = result.ok_type.clone();
-
-        let docs = &func_witx.docs;
-        if !docs.is_empty() {
-            Self::write_docs(w, docs)?;
-        }
+        let signature = FuncSignature::new(func_witx)?;
+        let name = &signature.name;
 
-        let mut params_decomposed = vec![];
+        Self::write_func_docs(w, n, func_witx)?;
 
-        for param in &params {
-            let mut decomposed = param.1.decompose(&param.0, false);
-            params_decomposed.append(&mut decomposed);
-        }
+        let params_decomposed: Vec<_> = signature
+            .params_decomposed
+            .iter()
+            .chain(signature.results_decomposed.iter())
+            .collect();
+        let result_str = match &signature.returns {
+            FuncReturn::Expected(result) => result.error_type.as_lang(n),
+            FuncReturn::Value(type_) => type_.as_lang(n),
+            FuncReturn::Nothing => "void".to_string(),
+            FuncReturn::Never => "never".to_string(),
+        };
 
-        let mut results = vec![];
-        // A tuple in a result is expanded into additional parameters, transformed to pointers
-        if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
-            for (i, tuple_member) in tuple_members.iter().enumerate() {
-                let name = format!("result{}_ptr", i);
-                results.push((name, tuple_member.type_.clone()));
+        if signature.export {
+            // The function name is also the name of the exported symbol, which is renamed if it
+            // isn't a valid identifier
+            if name.as_fn(n) == *name {
+                w.indent()?.write(format!("export function {}(", name))?;
+            } else {
+                w.write_line(format!("export {{ {} as {} }};", name.as_fn(n), name))?
+                    .indent()?
+                    .write(format!("function {}(", name.as_fn(n)))?;
             }
         } else {
-            let name = "result_ptr";
-            results.push((name.to_string(), ok_type));
+            w.write_line("// @ts-ignore: decorator")?
+                .write_line(format!("@external(\"{}\", \"{}\")", module_name, name))?
+                .indent()?
+                .write(format!("export declare function {}(", name.as_fn(n)))?;
         }
-        for result in &results {
-            let mut decomposed = result.1.decompose(&result.0, true);
-            params_decomposed.append(&mut decomposed);
-        }
-
-        w.write_line("// @ts-ignore: decorator")?
-            .write_line(format!("@external(\"{}\", \"{}\")", module_name, name))?
-            .indent()?
-            .write(format!("export declare function {}(", name.as_fn()))?;
 
-        if !params_decomposed.is_empty() || !results.is_empty() {
+        if !params_decomposed.is_empty() {
             w.eol()?;
         }
         for (i, param) in params_decomposed.iter().enumerate() {
@@ -72,19 +53,43 @@ impl AssemblyScriptGenerator {
             };
             w.write_line_continued(format!(
                 "{}: {}{}",
-                param.name.as_var(),
-                param.type_.as_lang(),
+                param.name.as_var(n),
+                param.type_.as_lang(n),
                 eol
             ))?;
         }
 
-        w.write_line(format!("): {};", result.error_type.as_lang()))?;
+        if signature.export {
+            w.write_line(format!("): {} {{", result_str))?;
+            w.new_block()
+                .write_line("throw new Error(\"Not implemented\");")?;
+            w.write_line("}")?;
+        } else {
+            w.write_line(format!("): {};", result_str))?;
+        }
         w.eob()?;
 
-        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
-        let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
-        assert_eq!(params_count_witx, params_decomposed.len() + 1);
-
         Ok(())
     }
+
+    /// Write the documentation of a function, with a `@param` tag for every documented parameter.
+    fn write_func_docs<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let mut docs = func_witx.docs.trim_end().to_string();
+        for param in func_witx
+            .params
+            .iter()
+            .filter(|param| !param.docs.is_empty())
+        {
+            if !docs.is_empty() {
+                docs.push('\n');
+            }
+            docs.push_str(&format!("@param {} ", param.name.as_str().as_var(n)));
+            docs.push_str(param.docs.trim_end());
+        }
+        Self::write_docs(w, &docs)
+    }
 }
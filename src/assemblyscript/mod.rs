@@ -6,9 +6,14 @@ mod tuple;
 mod union;
 
 use super::*;
+use crate::annotations::strip_annotations;
 use crate::astype::*;
 use crate::error::*;
+use crate::handles::resources_to_define;
+use crate::naming::Namer;
 use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
 use common::*;
 use std::io::Write;
 
@@ -23,17 +28,23 @@ impl AssemblyScriptGenerator {
 }
 
 impl<T: Write> Generator<T> for AssemblyScriptGenerator {
+    fn prelude_types(&self) -> &'static [&'static str] {
+        Self::PRELUDE_TYPES
+    }
+
     fn generate(
         &self,
         writer: &mut T,
-        module_witx: witx::Module,
+        module_witx: &witx::Module,
         options: &Options,
+        n: &Namer,
     ) -> Result<(), Error> {
         let mut w = PrettyWriter::new(writer, "    ");
         let module_name = match &self.module_name {
             None => module_witx.name().as_str().to_string(),
             Some(module_name) => module_name.to_string(),
         };
+        let selection = Selection::new(module_witx, &options.filter);
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
@@ -48,29 +59,39 @@ impl<T: Write> Generator<T> for AssemblyScriptGenerator {
         Self::write_comments(&mut w, &module_title_comments)?;
         w.eob()?;
 
-        for type_ in module_witx.typenames() {
+        for resource in resources_to_define(module_witx, &selection) {
+            if skip_imports && &resource.resource_id.module_id != module_id {
+                continue;
+            }
+            let resource_name = resource.resource_id.name.as_str();
+            Self::write_docs(&mut w, &resource.docs)?;
+            Self::define_resource(&mut w, n, resource_name)?;
+            w.eob()?;
+        }
+
+        for type_ in &selection.typenames {
             if skip_imports && &type_.module != module_id {
                 continue;
             }
             let constants_for_type: Vec<_> = module_witx
                 .constants()
-                .into_iter()
                 .filter_map(|x| {
                     if x.ty == type_.name {
                         Some(ASConstant {
                             name: x.name.as_str().to_string(),
                             value: x.value,
+                            docs: x.docs.clone(),
                         })
                     } else {
                         None
                     }
                 })
                 .collect();
-            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+            Self::define_type(&mut w, n, type_.as_ref(), &constants_for_type)?;
         }
 
-        for func in module_witx.funcs() {
-            Self::define_func(&mut w, &module_name, func.as_ref())?;
+        for func in &selection.funcs {
+            Self::define_func(&mut w, n, &module_name, func.as_ref())?;
         }
 
         Ok(())
@@ -79,6 +100,7 @@ impl<T: Write> Generator<T> for AssemblyScriptGenerator {
 
 impl AssemblyScriptGenerator {
     fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
         if docs.is_empty() {
             return Ok(());
         }
@@ -91,6 +113,7 @@ impl AssemblyScriptGenerator {
     }
 
     fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
         if docs.is_empty() {
             return Ok(());
         }
@@ -104,50 +127,82 @@ impl AssemblyScriptGenerator {
 
     fn define_as_alias<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         other_type: &ASType,
     ) -> Result<(), Error> {
         w.write_line(format!(
             "export type {} = {};",
-            name.as_type(),
-            other_type.as_lang()
+            name.as_type(n),
+            other_type.as_lang(n)
         ))?;
         Ok(())
     }
 
     fn define_as_atom<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         type_: &ASType,
     ) -> Result<(), Error> {
         w.write_line(format!(
             "export type {} = {};",
-            name.as_type(),
-            type_.as_lang()
+            name.as_type(n),
+            type_.as_lang(n)
         ))?;
         Ok(())
     }
 
+    fn define_resource<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        resource_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line("// @ts-ignore: decorator")?
+            .write_line("@unmanaged")?
+            .write_line(format!("export class {} {{", resource_name.as_type(n)))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!(
+                "static fromRaw(handle: WasiHandle): {} {{",
+                resource_name.as_type(n)
+            ))?;
+            w.new_block().write_line(format!(
+                "return changetype<{}>(handle as usize);",
+                resource_name.as_type(n)
+            ))?;
+            w.write_line("}")?.eob()?;
+            w.write_line("toRaw(): WasiHandle {")?;
+            w.new_block()
+                .write_line("return changetype<usize>(this) as WasiHandle;")?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
     fn define_as_enum<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         enum_: &ASEnum,
     ) -> Result<(), Error> {
         let repr = enum_.repr.as_ref();
         w.write_line(format!(
             "export type {} = {};",
-            name.as_type(),
-            repr.as_lang()
+            name.as_type(n),
+            repr.as_lang(n)
         ))?;
         w.eob()?;
-        w.write_line(format!("export namespace {} {{", name.as_namespace()))?;
+        w.write_line(format!("export namespace {} {{", name.as_namespace(n)))?;
         {
             let mut w = w.new_block();
             for choice in &enum_.choices {
+                Self::write_docs(&mut w, &choice.docs)?;
                 w.write_line(format!(
                     "export const {}: {} = {};",
-                    choice.name.as_const(),
-                    name.as_type(),
+                    choice.name.as_const(n),
+                    name.as_type(n),
                     choice.value
                 ))?;
             }
@@ -158,22 +213,24 @@ impl AssemblyScriptGenerator {
 
     fn define_as_constants<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         constants: &ASConstants,
     ) -> Result<(), Error> {
         let repr = constants.repr.as_ref();
         w.write_line(format!(
             "export type {} = {};",
-            name.as_type(),
-            repr.as_lang()
+            name.as_type(n),
+            repr.as_lang(n)
         ))?;
         w.eob()?;
-        Self::define_constants_for_type(w, name, &constants.constants)?;
+        Self::define_constants_for_type(w, n, name, &constants.constants)?;
         Ok(())
     }
 
     fn define_as_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         type_: &ASType,
     ) -> Result<(), Error> {
@@ -193,16 +250,16 @@ impl AssemblyScriptGenerator {
             | ASType::S32
             | ASType::S64
             | ASType::USize
-            | ASType::Handle(_)
             | ASType::Slice(_)
             | ASType::String(_)
             | ASType::ReadBuffer(_)
-            | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
-            ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
-            ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
-            ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
-            ASType::Tuple(members) => Self::define_as_tuple(w, name, members)?,
-            ASType::Struct(members) => Self::define_as_struct(w, name, members)?,
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, n, name, type_)?,
+            ASType::Handle(_) => Self::define_resource(w, n, name)?,
+            ASType::Enum(enum_) => Self::define_as_enum(w, n, name, enum_)?,
+            ASType::Union(union_) => Self::define_as_union(w, n, name, union_)?,
+            ASType::Constants(constants) => Self::define_as_constants(w, n, name, constants)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, n, name, members)?,
+            ASType::Struct(members) => Self::define_as_struct(w, n, name, members)?,
             _ => {
                 dbg!(type_);
                 unimplemented!();
@@ -213,13 +270,14 @@ impl AssemblyScriptGenerator {
 
     fn define_constants_for_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         type_name: &str,
         constants: &[ASConstant],
     ) -> Result<(), Error> {
         if constants.is_empty() {
             return Ok(());
         }
-        w.write_line(format!("export namespace {} {{", type_name.as_namespace()))?;
+        w.write_line(format!("export namespace {} {{", type_name.as_namespace(n)))?;
         {
             let mut w = w.new_block();
             let mut hex = false;
@@ -241,10 +299,11 @@ impl AssemblyScriptGenerator {
                 } else {
                     format!("{}", constant.value)
                 };
+                Self::write_docs(&mut w, &constant.docs)?;
                 w.write_line(format!(
                     "export const {}: {} = {};",
-                    constant.name.as_const(),
-                    type_name.as_type(),
+                    constant.name.as_const(n),
+                    type_name.as_type(n),
                     value_s
                 ))?;
             }
@@ -256,6 +315,7 @@ impl AssemblyScriptGenerator {
 
     fn define_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         type_witx: &witx::NamedType,
         constants: &[ASConstant],
     ) -> Result<(), Error> {
@@ -267,15 +327,15 @@ impl AssemblyScriptGenerator {
         let tref = &type_witx.tref;
         match tref {
             witx::TypeRef::Name(other_type) => {
-                Self::define_as_alias(w, type_name, &ASType::from(&other_type.tref))?
+                Self::define_as_alias(w, n, type_name, &ASType::from(&other_type.tref))?
             }
             witx::TypeRef::Value(type_witx) => {
                 let t = ASType::from(type_witx.as_ref());
-                Self::define_as_type(w, type_name, &t)?
+                Self::define_as_type(w, n, type_name, &t)?
             }
         }
         w.eob()?;
-        Self::define_constants_for_type(w, type_name, constants)?;
+        Self::define_constants_for_type(w, n, type_name, constants)?;
         Ok(())
     }
 }
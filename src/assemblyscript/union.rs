@@ -4,20 +4,22 @@ use std::io::Write;
 impl AssemblyScriptGenerator {
     fn define_union_member_accessors<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         union_name: &str,
         i: usize,
         member: &ASUnionMember,
     ) -> Result<(), Error> {
         let member_type = member.type_.as_ref();
+        Self::write_docs(w, &member.docs)?;
         match member_type {
             ASType::Void => {
                 w.write_line(format!(
                     "static {}(): {} {{",
-                    member.name.as_fn(),
-                    union_name.as_type()
+                    member.name.as_fn(n),
+                    union_name.as_type(n)
                 ))?
                 .indent()?
-                .write_line(format!("return {}.new({});", union_name.as_type(), i))?
+                .write_line(format!("return {}.new({});", union_name.as_type(n), i))?
                 .write_line("}")?
                 .eob()?;
 
@@ -35,13 +37,13 @@ impl AssemblyScriptGenerator {
             _ => {
                 w.write_line(format!(
                     "static {}(val: {}): {} {{",
-                    member.name.as_fn(),
-                    member_type.as_lang(),
-                    union_name.as_type()
+                    member.name.as_fn(n),
+                    member_type.as_lang(n),
+                    union_name.as_type(n)
                 ))?;
                 w.new_block().write_line(format!(
                     "return {}.new({}, val);",
-                    union_name.as_type(),
+                    union_name.as_type(n),
                     i
                 ))?;
                 w.write_line("}")?.eob()?;
@@ -49,7 +51,7 @@ impl AssemblyScriptGenerator {
                 w.write_line(format!(
                     "set{}(val: {}): void {{",
                     member.name.as_fn_suffix(),
-                    member_type.as_lang()
+                    member_type.as_lang(n)
                 ))?;
                 {
                     w.new_block()
@@ -68,13 +70,13 @@ impl AssemblyScriptGenerator {
                     w.write_line(format!(
                         "get{}(): {} | null {{",
                         member.name.as_fn_suffix(),
-                        member_type.as_lang()
+                        member_type.as_lang(n)
                     ))?;
                 } else {
                     w.write_line(format!(
                         "get{}(): {} {{",
                         member.name.as_fn_suffix(),
-                        member_type.as_lang()
+                        member_type.as_lang(n)
                     ))?;
                 }
                 {
@@ -82,7 +84,7 @@ impl AssemblyScriptGenerator {
                     if member_type.is_nullable() {
                         w.write_line(format!("if (this.tag !== {}) {{ return null; }}", i))?;
                     }
-                    w.write_line(format!("return this.get<{}>();", member_type.as_lang()))?;
+                    w.write_line(format!("return this.get<{}>();", member_type.as_lang(n)))?;
                 }
                 w.write_line("}")?;
             }
@@ -92,6 +94,7 @@ impl AssemblyScriptGenerator {
 
     fn define_union_member<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         union_name: &str,
         i: usize,
         member: &ASUnionMember,
@@ -101,52 +104,43 @@ impl AssemblyScriptGenerator {
             ASType::Void => {
                 w.write_line(format!(
                     "// --- {}: (no associated content) if tag={}",
-                    member.name.as_var(),
+                    member.name.as_var(n),
                     i
                 ))?;
             }
             _ => {
                 w.write_line(format!(
                     "// --- {}: {} if tag={}",
-                    member.name.as_var(),
-                    member_type.as_lang(),
+                    member.name.as_var(n),
+                    member_type.as_lang(n),
                     i
                 ))?;
             }
         }
         w.eob()?;
-        Self::define_union_member_accessors(w, union_name, i, member)?;
+        Self::define_union_member_accessors(w, n, union_name, i, member)?;
         Ok(())
     }
 
     pub fn define_as_union<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         union_: &ASUnion,
     ) -> Result<(), Error> {
         let tag_repr = union_.tag_repr.as_ref();
         w.write_line("// @ts-ignore: decorator")?
             .write_line("@unmanaged")?
-            .write_line(format!("export class {} {{", name.as_type()))?;
+            .write_line(format!("export class {} {{", name.as_type(n)))?;
         {
             let mut w = w.new_block();
-            w.write_line(format!("tag: {};", tag_repr.as_lang()))?;
-            let pad_len = union_.padding_after_tag;
-            for i in 0..(pad_len & 1) {
-                w.write_line(format!("private __pad8_{}: u8;", i))?;
-            }
-            for i in 0..(pad_len & 3) / 2 {
-                w.write_line(format!("private __pad16_{}: u16;", i))?;
-            }
-            for i in 0..(pad_len & 7) / 4 {
-                w.write_line(format!("private __pad32_{}: u32;", i))?;
-            }
-            for i in 0..pad_len / 8 {
-                w.write_line(format!("private __pad64_{}: u64;", i))?;
+            w.write_line(format!("tag: {};", tag_repr.as_lang(n)))?;
+            for pad_bits in padding_fields(union_.padding_after_tag) {
+                w.write_line(format!("private __pad{}_0: u{};", pad_bits, pad_bits))?;
             }
             w.eob()?;
 
-            w.write_line(format!("constructor(tag: {}) {{", tag_repr.as_lang()))?;
+            w.write_line(format!("constructor(tag: {}) {{", tag_repr.as_lang(n)))?;
             {
                 let mut w = w.new_block();
                 w.write_line("this.tag = tag;")?.write_line(format!(
@@ -158,12 +152,12 @@ impl AssemblyScriptGenerator {
 
             w.write_line("// @ts-ignore: default")?.write_line(format!(
                 "static new<T>(tag: {}, val: T = 0): {} {{",
-                tag_repr.as_lang(),
-                name.as_type()
+                tag_repr.as_lang(n),
+                name.as_type(n)
             ))?;
             {
                 let mut w = w.new_block();
-                w.write_line(format!("let tu = new {}(tag);", name.as_type()))?
+                w.write_line(format!("let tu = new {}(tag);", name.as_type(n)))?
                     .write_line("tu.set(val);")?
                     .write_line("return tu;")?;
             }
@@ -210,7 +204,7 @@ impl AssemblyScriptGenerator {
 
             for (i, member) in union_.members.iter().enumerate() {
                 w.eob()?;
-                Self::define_union_member(&mut w, name, i, member)?;
+                Self::define_union_member(&mut w, n, name, i, member)?;
             }
         }
         w.write_line("}")?.eob()?;
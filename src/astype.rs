@@ -13,12 +13,14 @@ pub struct ASStructMember {
     pub offset: usize,
     pub type_: Rc<ASType>,
     pub padding: usize,
+    pub docs: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ASEnumChoice {
     pub name: String,
     pub value: usize,
+    pub docs: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -31,6 +33,7 @@ pub struct ASEnum {
 pub struct ASUnionMember {
     pub name: String,
     pub type_: Rc<ASType>,
+    pub docs: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -69,6 +72,7 @@ pub struct ASResult {
 pub struct ASConstant {
     pub name: String,
     pub value: u64,
+    pub docs: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -147,6 +151,37 @@ impl From<&witx::BuiltinType> for ASType {
     }
 }
 
+/// Return the padding after each member of a record, including the trailing padding after the last one.
+fn member_paddings(record: &witx::RecordDatatype) -> Vec<usize> {
+    let layout_witx = record.member_layout(true);
+    let ends = layout_witx
+        .iter()
+        .skip(1)
+        .map(|member_witx| member_witx.offset)
+        .chain(std::iter::once(record.mem_size(true)));
+    layout_witx
+        .iter()
+        .zip(ends)
+        .map(|(member_witx, end)| end - member_witx.offset - member_witx.member.tref.mem_size(true))
+        .collect()
+}
+
+/// Return the sizes, in bits, of the integer fields to insert for `len` bytes of padding, smallest first.
+pub fn padding_fields(len: usize) -> Vec<usize> {
+    let mut fields = vec![];
+    if len & 1 != 0 {
+        fields.push(8);
+    }
+    if len & 2 != 0 {
+        fields.push(16);
+    }
+    if len & 4 != 0 {
+        fields.push(32);
+    }
+    fields.extend(std::iter::repeat_n(64, len / 8));
+    fields
+}
+
 impl From<&witx::Type> for ASType {
     fn from(type_witx: &witx::Type) -> Self {
         match type_witx {
@@ -179,18 +214,8 @@ impl From<&witx::Type> for ASType {
                     };
                     tuple_members.push(member);
                 }
-                // Perform a second pass to compute padding between members
-                let n = if layout_witx.is_empty() {
-                    0
-                } else {
-                    layout_witx.len() - 1
-                };
-                for (i, member_witx) in layout_witx.iter().enumerate().take(n) {
-                    let member_tref = &member_witx.member.tref;
-                    let member_size = member_tref.mem_size(true);
-                    let member_padding =
-                        layout_witx[i + 1].offset - member_witx.offset - member_size;
-                    tuple_members[i].padding = member_padding;
+                for (member, padding) in tuple_members.iter_mut().zip(member_paddings(record)) {
+                    member.padding = padding;
                 }
                 ASType::Tuple(tuple_members)
             }
@@ -208,21 +233,12 @@ impl From<&witx::Type> for ASType {
                         offset: member_offset,
                         type_: Rc::new(ASType::from(member_tref)),
                         padding: 0,
+                        docs: member_witx.member.docs.clone(),
                     };
                     struct_members.push(member);
                 }
-                // Perform a second pass to compute padding between members
-                let n = if layout_witx.is_empty() {
-                    0
-                } else {
-                    layout_witx.len() - 1
-                };
-                for (i, member_witx) in layout_witx.iter().enumerate().take(n) {
-                    let member_tref = &member_witx.member.tref;
-                    let member_size = member_tref.mem_size(true);
-                    let member_padding =
-                        layout_witx[i + 1].offset - member_witx.offset - member_size;
-                    struct_members[i].padding = member_padding;
+                for (member, padding) in struct_members.iter_mut().zip(member_paddings(record)) {
+                    member.padding = padding;
                 }
                 ASType::Struct(struct_members)
             }
@@ -236,6 +252,7 @@ impl From<&witx::Type> for ASType {
                     let constant = ASConstant {
                         name: constant_name,
                         value: 1u64 << idx,
+                        docs: contants_witx.member.docs.clone(),
                     };
                     constants.push(constant);
                 }
@@ -262,6 +279,7 @@ impl From<&witx::Type> for ASType {
                     let choice = ASEnumChoice {
                         name: choice_name,
                         value: idx,
+                        docs: choice_witx.docs.clone(),
                     };
                     choices.push(choice);
                 }
@@ -317,9 +335,8 @@ impl From<&witx::Type> for ASType {
                     None => ASType::Void,
                     Some(type_witx) => ASType::from(type_witx),
                 };
-                let full_size = variant.mem_size(true);
                 let tag_size = variant.tag_repr.mem_size(true);
-                let padding_after_tag = full_size - tag_size;
+                let padding_after_tag = result_offset - tag_size;
                 ASType::Result(ASResult {
                     tag_repr: Rc::new(tag_repr),
                     result_offset,
@@ -343,12 +360,13 @@ impl From<&witx::Type> for ASType {
                     let member = ASUnionMember {
                         name: member_name,
                         type_: Rc::new(member_type),
+                        docs: member_witx.docs.clone(),
                     };
                     members.push(member);
                 }
                 let full_size = variant.mem_size(true);
                 let tag_size = variant.tag_repr.mem_size(true);
-                let padding_after_tag = full_size - tag_size;
+                let padding_after_tag = member_offset - tag_size;
                 let max_member_size = full_size - member_offset;
                 ASType::Union(ASUnion {
                     tag_repr: Rc::new(tag_repr),
@@ -409,6 +427,80 @@ impl ASType {
         }
     }
 
+    /// Return the kind of type, as used in error messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ASType::Alias(_) => "alias",
+            ASType::Bool => "bool",
+            ASType::Char8 | ASType::Char32 => "char",
+            ASType::F32 | ASType::F64 => "float",
+            ASType::S8
+            | ASType::S16
+            | ASType::S32
+            | ASType::S64
+            | ASType::U8
+            | ASType::U16
+            | ASType::U32
+            | ASType::U64
+            | ASType::USize => "integer",
+            ASType::Handle(_) => "handle",
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "pointer",
+            ASType::Option(_) => "option",
+            ASType::Result(_) => "result",
+            ASType::Void => "void",
+            ASType::Constants(_) => "constants",
+            ASType::Enum(_) => "enum",
+            ASType::Struct(_) => "struct",
+            ASType::Tuple(_) => "tuple",
+            ASType::Union(_) => "union",
+            ASType::Slice(_) => "slice",
+            ASType::String(_) => "string",
+            ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => "buffer",
+        }
+    }
+
+    /// Return the named types (as `Alias`) and the handles (as `Handle`) this type directly refers to.
+    pub fn references(&self) -> Vec<&ASType> {
+        let mut references = vec![];
+        self.collect_references(&mut references);
+        references
+    }
+
+    fn collect_references<'t>(&'t self, references: &mut Vec<&'t ASType>) {
+        match self {
+            ASType::Alias(_) | ASType::Handle(_) => references.push(self),
+            ASType::Constants(constants) => constants.repr.collect_references(references),
+            ASType::Enum(enum_) => enum_.repr.collect_references(references),
+            ASType::Result(result) => {
+                result.ok_type.collect_references(references);
+                result.error_type.collect_references(references);
+            }
+            ASType::Option(option) => option.type_.collect_references(references),
+            ASType::Tuple(members) => {
+                for member in members {
+                    member.type_.collect_references(references);
+                }
+            }
+            ASType::Struct(members) => {
+                for member in members {
+                    member.type_.collect_references(references);
+                }
+            }
+            ASType::Union(union_) => {
+                for member in &union_.members {
+                    member.type_.collect_references(references);
+                }
+            }
+            ASType::ConstPtr(type_)
+            | ASType::MutPtr(type_)
+            | ASType::Slice(type_)
+            | ASType::String(type_)
+            | ASType::ReadBuffer(type_)
+            | ASType::WriteBuffer(type_) => type_.collect_references(references),
+            _ => {}
+        }
+    }
+
     pub fn decompose(&self, name: &str, as_mut_pointers: bool) -> Vec<ASTypeDecomposed> {
         let leaf = self.leaf();
 
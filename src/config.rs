@@ -0,0 +1,178 @@
+use crate::error::*;
+use crate::naming::{self, NameKind, Naming};
+use crate::selection::Filter;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_CONFIG_FILE: &str = "witx-codegen.toml";
+
+/// Generation settings, read from a `witx-codegen.toml` file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// WITX files, relative to the configuration file
+    pub witx_files: Vec<PathBuf>,
+    /// Directory to write outputs without an explicit output file to, relative to the configuration file
+    pub out_dir: Option<PathBuf>,
+    pub skip_imports: bool,
+    pub skip_header: bool,
+    /// Only use `core` in the Rust output
+    pub no_std: bool,
+    /// Implement `Serialize` and `Deserialize` for the Rust types, behind a `serde` feature
+    pub serde: bool,
+    /// Implement `arbitrary::Arbitrary` for the Rust types, behind an `arbitrary` feature
+    pub arbitrary: bool,
+    /// Patterns of the names of functions and types to generate code for
+    pub include: Vec<String>,
+    /// Patterns of the names of functions and types not to generate code for
+    pub exclude: Vec<String>,
+    /// Only generate types used by the selected functions
+    pub drop_unused_types: bool,
+    /// Check that the layout of every type matches the one computed by the witx crate
+    pub verify_layout: bool,
+    /// Module names to use instead of the ones from the WITX files, indexed by the latter
+    pub module_names: HashMap<String, String>,
+    pub naming: NamingConfig,
+    /// Outputs, indexed by output type
+    pub targets: BTreeMap<String, TargetConfig>,
+}
+
+/// Settings for a single output type, overriding the global ones.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TargetConfig {
+    /// Output file, relative to the configuration file, or `-` for the standard output.
+    /// Defaults to a file in `out_dir` if set, or to the standard output.
+    pub output: Option<PathBuf>,
+    pub skip_imports: Option<bool>,
+    pub skip_header: Option<bool>,
+    pub no_std: Option<bool>,
+    pub serde: Option<bool>,
+    pub arbitrary: Option<bool>,
+    pub include: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+    pub drop_unused_types: Option<bool>,
+    pub module_names: HashMap<String, String>,
+    pub naming: NamingConfig,
+}
+
+/// Cases to use for each category of identifiers, such as `"snake"` or `"pascal"`, as well as
+/// prefixes and suffixes to add to them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamingConfig {
+    pub types: Option<String>,
+    pub functions: Option<String>,
+    pub variables: Option<String>,
+    pub constants: Option<String>,
+    pub namespaces: Option<String>,
+    pub type_prefix: Option<String>,
+    pub function_prefix: Option<String>,
+    pub variable_prefix: Option<String>,
+    pub constant_prefix: Option<String>,
+    pub namespace_prefix: Option<String>,
+    pub type_suffix: Option<String>,
+    pub function_suffix: Option<String>,
+    pub variable_suffix: Option<String>,
+    pub constant_suffix: Option<String>,
+    pub namespace_suffix: Option<String>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("{}: {}", path.display(), e)))?;
+        let mut config: Config = toml::from_str(&content)
+            .map_err(|e| Error::Config(format!("{}: {}", path.display(), e)))?;
+
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        for witx_file in config.witx_files.iter_mut() {
+            *witx_file = base_dir.join(&witx_file);
+        }
+        if let Some(out_dir) = config.out_dir.as_mut() {
+            *out_dir = base_dir.join(&out_dir);
+        }
+        for target in config.targets.values_mut() {
+            if let Some(output) = target.output.as_mut() {
+                if output.as_path() != Path::new("-") {
+                    *output = base_dir.join(&output);
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Return the module name to use for the WITX module `module_name` in the `target` output.
+    pub fn module_name(&self, target: &TargetConfig, module_name: &str) -> Option<String> {
+        target
+            .module_names
+            .get(module_name)
+            .or_else(|| self.module_names.get(module_name))
+            .cloned()
+    }
+
+    /// Return the filter for the `target` output.
+    pub fn filter(&self, target: &TargetConfig) -> Filter {
+        Filter::new(
+            target.include.as_ref().unwrap_or(&self.include).clone(),
+            target.exclude.as_ref().unwrap_or(&self.exclude).clone(),
+            target.drop_unused_types.unwrap_or(self.drop_unused_types),
+        )
+    }
+
+    /// Return the naming overrides for the `target` output.
+    pub fn naming(&self, target: &TargetConfig) -> Result<Naming, Error> {
+        let mut naming = Naming::default();
+        for naming_config in &[&self.naming, &target.naming] {
+            let settings = [
+                (
+                    NameKind::Type,
+                    &naming_config.types,
+                    &naming_config.type_prefix,
+                    &naming_config.type_suffix,
+                ),
+                (
+                    NameKind::Fn,
+                    &naming_config.functions,
+                    &naming_config.function_prefix,
+                    &naming_config.function_suffix,
+                ),
+                (
+                    NameKind::Var,
+                    &naming_config.variables,
+                    &naming_config.variable_prefix,
+                    &naming_config.variable_suffix,
+                ),
+                (
+                    NameKind::Const,
+                    &naming_config.constants,
+                    &naming_config.constant_prefix,
+                    &naming_config.constant_suffix,
+                ),
+                (
+                    NameKind::Namespace,
+                    &naming_config.namespaces,
+                    &naming_config.namespace_prefix,
+                    &naming_config.namespace_suffix,
+                ),
+            ];
+            for (kind, case_name, prefix, suffix) in settings.iter() {
+                if let Some(case_name) = case_name {
+                    let case = naming::parse_case(case_name).ok_or_else(|| {
+                        Error::Config(format!("Unsupported case: [{}]", case_name))
+                    })?;
+                    naming.set_case(*kind, case);
+                }
+                if let Some(prefix) = prefix {
+                    naming.set_prefix(*kind, prefix);
+                }
+                if let Some(suffix) = suffix {
+                    naming.set_suffix(*kind, suffix);
+                }
+            }
+        }
+        Ok(naming)
+    }
+}
@@ -0,0 +1,205 @@
+use super::tuple::Tuple;
+use crate::astype::*;
+use crate::error::*;
+use crate::naming::{to_case, NameKind, Namer};
+use convert_case::Case;
+
+/// Reserved words and standard macros, that are suffixed with `_` when used as identifiers
+const KEYWORDS: &[&str] = &[
+    "alignas",
+    "alignof",
+    "and",
+    "and_eq",
+    "asm",
+    "auto",
+    "bitand",
+    "bitor",
+    "bool",
+    "break",
+    "case",
+    "catch",
+    "char",
+    "char8_t",
+    "char16_t",
+    "char32_t",
+    "class",
+    "compl",
+    "concept",
+    "const",
+    "consteval",
+    "constexpr",
+    "constinit",
+    "const_cast",
+    "continue",
+    "co_await",
+    "co_return",
+    "co_yield",
+    "decltype",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "dynamic_cast",
+    "else",
+    "enum",
+    "explicit",
+    "export",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "friend",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "mutable",
+    "namespace",
+    "new",
+    "noexcept",
+    "not",
+    "not_eq",
+    "nullptr",
+    "operator",
+    "or",
+    "or_eq",
+    "private",
+    "protected",
+    "public",
+    "register",
+    "reinterpret_cast",
+    "requires",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "static_assert",
+    "static_cast",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "thread_local",
+    "throw",
+    "true",
+    "try",
+    "typedef",
+    "typeid",
+    "typename",
+    "union",
+    "unsigned",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "wchar_t",
+    "while",
+    "xor",
+    "xor_eq",
+    "EOF",
+    "NULL",
+    "assert",
+    "errno",
+    "offsetof",
+    "stderr",
+    "stdin",
+    "stdout",
+];
+
+fn escape_keyword(identifier: String) -> String {
+    if KEYWORDS.contains(&identifier.as_str()) {
+        format!("{}_", identifier)
+    } else {
+        identifier
+    }
+}
+
+#[allow(dead_code)]
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    fn as_type(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Type, self.as_str(), Case::Pascal))
+    }
+
+    fn as_fn(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Fn, self.as_str(), Case::Snake))
+    }
+
+    fn as_fn_suffix(&self) -> String {
+        to_case(self.as_str(), Case::Snake)
+    }
+
+    fn as_var(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Var, self.as_str(), Case::Snake))
+    }
+
+    fn as_const(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Const, self.as_str(), Case::UpperSnake))
+    }
+
+    fn as_namespace(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Namespace, self.as_str(), Case::Snake))
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    fn as_lang(&self, n: &Namer) -> Result<String, Error> {
+        let lang = match self.as_astype() {
+            ASType::Alias(alias) => alias.name.as_type(n),
+            ASType::Bool => "bool".to_string(),
+            ASType::Char32 => "Char32".to_string(),
+            ASType::Char8 => "Char8".to_string(),
+            ASType::F32 => "float".to_string(),
+            ASType::F64 => "double".to_string(),
+            ASType::Handle(resource_name) => resource_name.as_type(n),
+            ASType::ConstPtr(pointee) => format!("WasiPtr<{}>", pointee.as_lang(n)?),
+            ASType::MutPtr(pointee) => format!("WasiMutPtr<{}>", pointee.as_lang(n)?),
+            ASType::S8 => "int8_t".to_string(),
+            ASType::S16 => "int16_t".to_string(),
+            ASType::S32 => "int32_t".to_string(),
+            ASType::S64 => "int64_t".to_string(),
+            ASType::U8 => "uint8_t".to_string(),
+            ASType::U16 => "uint16_t".to_string(),
+            ASType::U32 => "uint32_t".to_string(),
+            ASType::U64 => "uint64_t".to_string(),
+            ASType::USize => "size_t".to_string(),
+            ASType::Void => "void".to_string(),
+            ASType::Enum(enum_) => enum_.repr.as_ref().as_lang(n)?,
+            ASType::Tuple(tuple_members) => Tuple::name_for(n, tuple_members)?.as_type(n),
+            ASType::Slice(element_type) => format!("WasiMutSlice<{}>", element_type.as_lang(n)?),
+            ASType::String(_) => "WasiString".to_string(),
+            ASType::ReadBuffer(element_type) => format!("WasiSlice<{}>", element_type.as_lang(n)?),
+            ASType::WriteBuffer(element_type) => {
+                format!("WasiMutSlice<{}>", element_type.as_lang(n)?)
+            }
+            ASType::Option(_)
+            | ASType::Result(_)
+            | ASType::Constants(_)
+            | ASType::Struct(_)
+            | ASType::Union(_) => {
+                return Err(Error::Unsupported(format!(
+                    "{} types must be named to be used in C++ bindings",
+                    self.as_astype().kind()
+                )))
+            }
+        };
+        Ok(lang)
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
@@ -0,0 +1,214 @@
+use super::*;
+use std::io::Write;
+
+impl CppGenerator {
+    pub fn define_func_raw<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let signature = FuncSignature::new(func_witx)?;
+        let params = signature
+            .params_decomposed
+            .iter()
+            .chain(signature.results_decomposed.iter())
+            .collect::<Vec<_>>();
+
+        let (attributes, result_str) = match &signature.returns {
+            FuncReturn::Expected(result) => ("", result.error_type.as_lang(n)?),
+            FuncReturn::Value(type_) => ("", type_.as_lang(n)?),
+            FuncReturn::Nothing => ("", "void".to_string()),
+            FuncReturn::Never => ("[[noreturn]] ", "void".to_string()),
+        };
+        w.write_line(format!(
+            "extern \"C\" {}WITX_CODEGEN_IMPORT(\"{}\", \"{}\")",
+            attributes, module_name, signature.name
+        ))?;
+        w.indent()?
+            .write(format!("{} {}(", result_str, signature.name.as_fn(n)))?;
+        if params.is_empty() {
+            w.write(");")?.eol()?;
+        } else {
+            w.eol()?;
+            for (i, param) in params.iter().enumerate() {
+                let eol = if i + 1 == params.len() { "" } else { "," };
+                w.write_line_continued(format!(
+                    "{} {}{}",
+                    param.type_.as_lang(n)?,
+                    param.name.as_var(n),
+                    eol
+                ))?;
+            }
+            w.write_line(");")?;
+        }
+        w.eob()?;
+        Ok(())
+    }
+
+    pub fn define_func<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let signature = FuncSignature::new(func_witx)?;
+        if signature.export {
+            return Self::define_func_export(w, n, func_witx, &signature);
+        }
+        let mut results_decomposed_deref = vec![];
+        for result_ptr_type in &signature.results_decomposed {
+            match result_ptr_type.type_.as_ref() {
+                ASType::MutPtr(result_type) => results_decomposed_deref.push(ASTypeDecomposed {
+                    name: result_ptr_type.name.clone(),
+                    type_: result_type.clone(),
+                }),
+                _ => {
+                    return Err(Error::Unsupported(format!(
+                        "result [{}] of function [{}] is not returned through a pointer",
+                        result_ptr_type.name, signature.name
+                    )))
+                }
+            }
+        }
+        let results_set = results_decomposed_deref
+            .iter()
+            .map(|result| result.type_.as_lang(n))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (attributes, cpp_fn_result_str) = match &signature.returns {
+            FuncReturn::Expected(result) => {
+                let ok_str = match results_set.len() {
+                    0 => "void".to_string(),
+                    1 => results_set[0].clone(),
+                    _ => format!("std::tuple<{}>", results_set.join(", ")),
+                };
+                let error_type_str = result.error_type.as_lang(n)?;
+                ("", format!("WasiExpected<{}, {}>", ok_str, error_type_str))
+            }
+            FuncReturn::Value(type_) => ("", type_.as_lang(n)?),
+            FuncReturn::Nothing => ("", "void".to_string()),
+            FuncReturn::Never => ("[[noreturn]] ", "void".to_string()),
+        };
+        let params_str = signature
+            .params
+            .iter()
+            .map(|param| Ok(format!("{} {}", param.1.as_lang(n)?, param.0.as_var(n))))
+            .collect::<Result<Vec<_>, Error>>()?
+            .join(", ");
+
+        Self::write_docs(w, &func_docs(func_witx, |name| name.as_var(n)))?;
+        w.write_line(format!(
+            "{}inline {} {}({}) {{",
+            attributes,
+            cpp_fn_result_str,
+            signature.name.as_fn(n),
+            params_str
+        ))?;
+        {
+            let mut w = w.new_block();
+            for result in &results_decomposed_deref {
+                w.write_line(format!(
+                    "{} {}{{}};",
+                    result.type_.as_lang(n)?,
+                    result.name.as_var(n)
+                ))?;
+            }
+
+            let mut args = vec![];
+            for param in &signature.params {
+                match param.1.decompose(&param.0, false).len() {
+                    0 => {}
+                    1 => args.push(param.0.as_var(n)),
+                    _ => {
+                        args.push(format!("{}.ptr", param.0.as_var(n)));
+                        args.push(format!("{}.len", param.0.as_var(n)));
+                    }
+                }
+            }
+            for result in &results_decomposed_deref {
+                args.push(format!("&{}", result.name.as_var(n)));
+            }
+            let call_str = format!("raw::{}({})", signature.name.as_fn(n), args.join(", "));
+            match &signature.returns {
+                FuncReturn::Expected(result) => {
+                    let error_type_str = result.error_type.as_lang(n)?;
+                    w.write_line(format!("const {} res = {};", error_type_str, call_str))?;
+                    w.write_line(format!("if (res != {}{{}}) {{", error_type_str))?;
+                    w.new_block()
+                        .write_line(format!("return {}::from_error(res);", cpp_fn_result_str))?;
+                    w.write_line("}")?;
+                    let res_str = match results_decomposed_deref.len() {
+                        0 => "".to_string(),
+                        1 => results_decomposed_deref[0].name.as_var(n),
+                        _ => format!(
+                            "std::make_tuple({})",
+                            results_decomposed_deref
+                                .iter()
+                                .map(|result| result.name.as_var(n))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    };
+                    w.write_line(format!(
+                        "return {}::from_value({});",
+                        cpp_fn_result_str, res_str
+                    ))?;
+                }
+                FuncReturn::Value(_) => {
+                    w.write_line(format!("return {};", call_str))?;
+                }
+                FuncReturn::Nothing | FuncReturn::Never => {
+                    w.write_line(format!("{};", call_str))?;
+                }
+            }
+        }
+        w.write_line("}")?;
+        w.eob()?;
+
+        Ok(())
+    }
+
+    /// Declare an exported function, to be defined by the application.
+    fn define_func_export<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        func_witx: &witx::Function,
+        signature: &FuncSignature,
+    ) -> Result<(), Error> {
+        let params = signature
+            .params_decomposed
+            .iter()
+            .chain(signature.results_decomposed.iter())
+            .collect::<Vec<_>>();
+        let (attributes, result_str) = match &signature.returns {
+            FuncReturn::Expected(result) => ("", result.error_type.as_lang(n)?),
+            FuncReturn::Value(type_) => ("", type_.as_lang(n)?),
+            FuncReturn::Nothing => ("", "void".to_string()),
+            FuncReturn::Never => ("[[noreturn]] ", "void".to_string()),
+        };
+
+        Self::write_docs(w, &func_docs(func_witx, |name| name.as_var(n)))?;
+        w.write_line(format!(
+            "extern \"C\" {}WITX_CODEGEN_EXPORT(\"{}\")",
+            attributes, signature.name
+        ))?;
+        w.indent()?
+            .write(format!("{} {}(", result_str, signature.name.as_fn(n)))?;
+        if params.is_empty() {
+            w.write(");")?.eol()?;
+        } else {
+            w.eol()?;
+            for (i, param) in params.iter().enumerate() {
+                let eol = if i + 1 == params.len() { "" } else { "," };
+                w.write_line_continued(format!(
+                    "{} {}{}",
+                    param.type_.as_lang(n)?,
+                    param.name.as_var(n),
+                    eol
+                ))?;
+            }
+            w.write_line(");")?;
+        }
+        w.eob()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use super::*;
+use crate::handles::*;
+use std::io::Write;
+
+impl CppGenerator {
+    pub fn define_handle_wrappers<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_witx: &witx::Module,
+        selection: &Selection,
+    ) -> Result<(), Error> {
+        for type_ in &selection.typenames {
+            if handle_resource(&ASType::from(&type_.tref)).is_none() {
+                continue;
+            }
+            let type_name = type_.name.as_str();
+            if let Some(close_func) = close_function_for(module_witx, type_name)
+                .filter(|close_func| selection.has_func(close_func.name.as_str()))
+            {
+                Self::define_handle_wrapper(w, n, type_name, close_func.name.as_str())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn define_handle_wrapper<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        close_fn_name: &str,
+    ) -> Result<(), Error> {
+        let class_name = format!("unique_{}", type_name).as_type(n);
+        let handle_type = type_name.as_type(n);
+
+        w.write_line(format!(
+            "/// Owned `{}` handle, closed with `{}()` when dropped.",
+            handle_type,
+            close_fn_name.as_fn(n)
+        ))?;
+        w.write_line(format!("class {} {{", class_name))?;
+        w.write_line("  public:")?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!(
+                "explicit {}({} handle) noexcept : handle_(handle), valid_(true) {{}}",
+                class_name, handle_type
+            ))?;
+            w.write_line(format!("{}(const {} &) = delete;", class_name, class_name))?;
+            w.write_line(format!(
+                "{} &operator=(const {} &) = delete;",
+                class_name, class_name
+            ))?;
+            w.eob()?;
+
+            w.write_line(format!(
+                "{}({} &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {{",
+                class_name, class_name
+            ))?;
+            w.new_block().write_line("other.valid_ = false;")?;
+            w.write_line("}")?.eob()?;
+
+            w.write_line(format!(
+                "{} &operator=({} &&other) noexcept {{",
+                class_name, class_name
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("if (this != &other) {")?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line("reset();")?;
+                    w.write_line("handle_ = other.handle_;")?;
+                    w.write_line("valid_ = other.valid_;")?;
+                    w.write_line("other.valid_ = false;")?;
+                }
+                w.write_line("}")?;
+                w.write_line("return *this;")?;
+            }
+            w.write_line("}")?.eob()?;
+
+            w.write_line(format!("~{}() {{", class_name))?;
+            w.new_block().write_line("reset();")?;
+            w.write_line("}")?.eob()?;
+
+            w.write_line(format!("{} get() const noexcept {{", handle_type))?;
+            w.new_block().write_line("return handle_;")?;
+            w.write_line("}")?.eob()?;
+
+            w.write_line(format!("{} release() noexcept {{", handle_type))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("valid_ = false;")?;
+                w.write_line("return handle_;")?;
+            }
+            w.write_line("}")?.eob()?;
+
+            w.write_line("void reset() noexcept {")?;
+            {
+                let mut w = w.new_block();
+                w.write_line("if (valid_) {")?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line(format!("(void)raw::{}(handle_);", close_fn_name.as_fn(n)))?;
+                    w.write_line("valid_ = false;")?;
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+        w.write_line("  private:")?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("{} handle_;", handle_type))?;
+            w.write_line("bool valid_;")?;
+        }
+        w.write_line("};")?;
+        w.eob()?;
+        Ok(())
+    }
+}
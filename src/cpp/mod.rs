@@ -0,0 +1,352 @@
+mod common;
+mod function;
+mod handle;
+mod header;
+mod r#struct;
+mod tuple;
+mod union;
+
+use super::*;
+use crate::annotations::strip_annotations;
+use crate::astype::*;
+use crate::error::*;
+use crate::handles::resources_to_define;
+use crate::naming::Namer;
+use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
+use common::*;
+use std::io::Write;
+
+pub struct CppGenerator {
+    module_name: Option<String>,
+}
+
+impl CppGenerator {
+    pub fn new(module_name: Option<String>) -> Self {
+        CppGenerator { module_name }
+    }
+}
+
+impl<T: Write> Generator<T> for CppGenerator {
+    fn prelude_types(&self) -> &'static [&'static str] {
+        Self::PRELUDE_TYPES
+    }
+
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: &witx::Module,
+        options: &Options,
+        n: &Namer,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "    ");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+        let selection = Selection::new(module_witx, &options.filter);
+
+        if !options.skip_header {
+            Self::header(&mut w)?;
+        }
+
+        let module_title_comments = format!(
+            "---------------------- Module: [{}] ----------------------",
+            module_name
+        );
+        Self::write_comments(&mut w, &module_title_comments)?;
+        w.eob()?;
+
+        w.write_line(format!("namespace {} {{", module_name.as_namespace(n)))?;
+        w.eob()?;
+        {
+            let mut w = w.new_block();
+
+            for resource in resources_to_define(module_witx, &selection) {
+                let resource_name = resource.resource_id.name.as_str();
+                Self::write_docs(&mut w, &resource.docs)?;
+                Self::define_resource(&mut w, n, resource_name)?;
+                w.eob()?;
+            }
+
+            // Namespaces are closed, so types imported from other modules cannot be referred to
+            // without qualification. They are always defined again in the module namespace.
+            for type_ in &selection.typenames {
+                let constants_for_type: Vec<_> = module_witx
+                    .constants()
+                    .filter_map(|x| {
+                        if x.ty == type_.name {
+                            Some(ASConstant {
+                                name: x.name.as_str().to_string(),
+                                value: x.value,
+                                docs: x.docs.clone(),
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                Self::define_type(&mut w, n, type_.as_ref(), &constants_for_type)?;
+            }
+
+            w.write_line("namespace raw {")?;
+            w.eob()?;
+            {
+                let mut w = w.new_block();
+                for func in selection.funcs.iter().filter(|func| !is_export(func)) {
+                    Self::define_func_raw(&mut w, n, &module_name, func.as_ref())?;
+                }
+            }
+            w.write_line("} // namespace raw")?;
+            w.eob()?;
+
+            for func in &selection.funcs {
+                Self::define_func(&mut w, n, func.as_ref())?;
+            }
+
+            Self::define_handle_wrappers(&mut w, n, module_witx, &selection)?;
+        }
+        w.write_line(format!("}} // namespace {}", module_name.as_namespace(n)))?;
+        w.eob()?;
+
+        Ok(())
+    }
+}
+
+impl CppGenerator {
+    fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
+        if docs.is_empty() {
+            return Ok(());
+        }
+        for docs_line in docs.lines() {
+            w.write_line(format!("/// {}", docs_line))?;
+        }
+        Ok(())
+    }
+
+    /// Check the offsets of the members of a type, which are only known for `wasm32`.
+    /// Offsets are given as member names and values.
+    fn write_layout_asserts<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        offsets: &[(String, usize)],
+    ) -> Result<(), Error> {
+        w.write_line("#if defined(__wasm32__)")?;
+        for (member_name, offset) in offsets {
+            w.write_line(format!(
+                "static_assert(offsetof({}, {}) == {}, \"unexpected layout\");",
+                type_name.as_type(n),
+                member_name,
+                offset
+            ))?;
+        }
+        w.write_line("#endif")?;
+        Ok(())
+    }
+
+    fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
+        if docs.is_empty() {
+            return Ok(());
+        }
+        for docs_line in docs.lines() {
+            w.write_line(format!("// {}", docs_line))?;
+        }
+        Ok(())
+    }
+
+    fn define_as_alias<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        other_type: &ASType,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "using {} = {};",
+            name.as_type(n),
+            other_type.as_lang(n)?
+        ))?;
+        Ok(())
+    }
+
+    fn define_as_atom<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "using {} = {};",
+            name.as_type(n),
+            type_.as_lang(n)?
+        ))?;
+        Ok(())
+    }
+
+    fn define_resource<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        resource_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "enum class {} : WasiHandle {{}};",
+            resource_name.as_type(n)
+        ))?;
+        Ok(())
+    }
+
+    fn define_as_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        enum_: &ASEnum,
+    ) -> Result<(), Error> {
+        let repr = enum_.repr.as_ref();
+        w.write_line(format!(
+            "enum class {} : {} {{",
+            name.as_type(n),
+            repr.as_lang(n)?
+        ))?;
+        {
+            let mut w = w.new_block();
+            for choice in &enum_.choices {
+                Self::write_docs(&mut w, &choice.docs)?;
+                w.write_line(format!("{} = {},", choice.name.as_const(n), choice.value))?;
+            }
+        }
+        w.write_line("};")?;
+        Ok(())
+    }
+
+    fn define_as_constants<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        constants: &ASConstants,
+    ) -> Result<(), Error> {
+        let repr = constants.repr.as_ref();
+        w.write_line(format!("using {} = {};", name.as_type(n), repr.as_lang(n)?))?;
+        w.eob()?;
+        Self::define_constants_for_type(w, n, name, &constants.constants)?;
+        Ok(())
+    }
+
+    fn define_as_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        match type_ {
+            ASType::Alias(_)
+            | ASType::Bool
+            | ASType::Char8
+            | ASType::Char32
+            | ASType::F32
+            | ASType::F64
+            | ASType::U8
+            | ASType::U16
+            | ASType::U32
+            | ASType::U64
+            | ASType::S8
+            | ASType::S16
+            | ASType::S32
+            | ASType::S64
+            | ASType::USize
+            | ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, n, name, type_)?,
+            ASType::Handle(_) => Self::define_resource(w, n, name)?,
+            ASType::Enum(enum_) => Self::define_as_enum(w, n, name, enum_)?,
+            ASType::Union(union_) => Self::define_as_union(w, n, name, union_)?,
+            ASType::Constants(constants) => Self::define_as_constants(w, n, name, constants)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, n, name, members)?,
+            ASType::Struct(members) => Self::define_as_struct(w, n, name, members)?,
+            _ => {
+                return Err(Error::Unsupported(format!(
+                    "type [{}] is a {}, that can't be defined in C++ bindings",
+                    name,
+                    type_.kind()
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn define_constants_for_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        if constants.is_empty() {
+            return Ok(());
+        }
+        w.write_line(format!("namespace {} {{", type_name.as_namespace(n)))?;
+        {
+            let mut w = w.new_block();
+            let mut hex = false;
+            let mut single_bits: usize = 0;
+            for constant in constants {
+                if constant.value > 0xffff {
+                    hex = true;
+                }
+                if constant.value.count_ones() == 1 {
+                    single_bits += 1;
+                }
+            }
+            if constants.len() > 2 && single_bits == constants.len() {
+                hex = true;
+            }
+            for constant in constants {
+                let value_s = if hex {
+                    format!("0x{:x}", constant.value)
+                } else {
+                    format!("{}", constant.value)
+                };
+                Self::write_docs(&mut w, &constant.docs)?;
+                w.write_line(format!(
+                    "constexpr {} {} = {};",
+                    type_name.as_type(n),
+                    constant.name.as_const(n),
+                    value_s
+                ))?;
+            }
+        }
+        w.write_line(format!("}} // namespace {}", type_name.as_namespace(n)))?;
+        Ok(())
+    }
+
+    fn define_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_witx: &witx::NamedType,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let docs = &type_witx.docs;
+        if !docs.is_empty() {
+            Self::write_docs(w, docs)?;
+        }
+        let type_name = type_witx.name.as_str();
+        let tref = &type_witx.tref;
+        match tref {
+            witx::TypeRef::Name(other_type) => {
+                Self::define_as_alias(w, n, type_name, &ASType::from(&other_type.tref))?
+            }
+            witx::TypeRef::Value(type_witx) => {
+                let t = ASType::from(type_witx.as_ref());
+                Self::define_as_type(w, n, type_name, &t)?
+            }
+        }
+        w.eob()?;
+        if !constants.is_empty() {
+            Self::define_constants_for_type(w, n, type_name, constants)?;
+            w.eob()?;
+        }
+        Ok(())
+    }
+}
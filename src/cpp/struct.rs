@@ -0,0 +1,36 @@
+use super::*;
+use std::io::Write;
+
+impl CppGenerator {
+    pub fn define_as_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        members: &[ASStructMember],
+    ) -> Result<(), Error> {
+        w.write_line(format!("struct {} {{", name.as_type(n)))?;
+        {
+            let mut w = w.new_block();
+            for (i, member) in members.iter().enumerate() {
+                let member_type = member.type_.as_ref();
+                Self::write_docs(&mut w, &member.docs)?;
+                w.write_line(format!(
+                    "{} {};",
+                    member_type.as_lang(n)?,
+                    member.name.as_var(n)
+                ))?;
+
+                for pad_bits in padding_fields(member.padding) {
+                    w.write_line(format!("uint{}_t __pad{}_{};", pad_bits, pad_bits, i))?;
+                }
+            }
+        }
+        w.write_line("};")?;
+        let offsets: Vec<_> = members
+            .iter()
+            .map(|member| (member.name.as_var(n), member.offset))
+            .collect();
+        Self::write_layout_asserts(w, n, name, &offsets)?;
+        Ok(())
+    }
+}
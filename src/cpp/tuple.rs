@@ -0,0 +1,48 @@
+use super::*;
+use std::io::Write;
+
+pub struct Tuple;
+
+impl Tuple {
+    pub fn name_for(n: &Namer, tuple_members: &[ASTupleMember]) -> Result<String, Error> {
+        let member_types = tuple_members
+            .iter()
+            .map(|member| member.type_.as_lang(n))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(format!(
+            "WasiTuple{}{}",
+            tuple_members.len(),
+            member_types.join("_")
+        ))
+    }
+}
+
+impl CppGenerator {
+    pub fn define_as_tuple<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        members: &[ASTupleMember],
+    ) -> Result<(), Error> {
+        w.write_line(format!("struct {} {{ // -- Tuple", name.as_type(n)))?;
+        {
+            let mut w = w.new_block();
+            for (i, member) in members.iter().enumerate() {
+                let member_type = member.type_.as_ref();
+                w.write_line(format!("{} v{};", member_type.as_lang(n)?, i))?;
+
+                for pad_bits in padding_fields(member.padding) {
+                    w.write_line(format!("uint{}_t __pad{}_{};", pad_bits, pad_bits, i))?;
+                }
+            }
+        }
+        w.write_line("};")?;
+        let offsets: Vec<_> = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| (format!("v{}", i), member.offset))
+            .collect();
+        Self::write_layout_asserts(w, n, name, &offsets)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use super::*;
+use std::io::Write;
+
+impl CppGenerator {
+    fn define_union_member_accessors<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        union_name: &str,
+        member: &ASUnionMember,
+    ) -> Result<(), Error> {
+        let name = &member.name;
+        let member_is_void = matches!(member.type_.as_ref(), ASType::Void);
+
+        if member_is_void {
+            // make_*
+            w.write_line(format!(
+                "static {} make_{}() {{",
+                union_name.as_type(n),
+                name.as_fn_suffix()
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("{} tu{{}};", union_name.as_type(n)))?;
+                w.write_line(format!("tu.tag = Tag::{};", name.as_const(n)))?;
+                w.write_line("return tu;")?;
+            }
+            w.write_line("}")?.eob()?;
+
+            // emplace_*
+            w.write_line(format!("void emplace_{}() {{", name.as_fn_suffix()))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("tag = Tag::{};", name.as_const(n)))?;
+            }
+            w.write_line("}")?.eob()?;
+        } else {
+            // !member_is_void
+            let member_type = member.type_.as_lang(n)?;
+
+            // make_*
+            w.write_line(format!(
+                "static {} make_{}({} val) {{",
+                union_name.as_type(n),
+                name.as_fn_suffix(),
+                member_type
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("{} tu{{}};", union_name.as_type(n)))?;
+                w.write_line(format!("tu.tag = Tag::{};", name.as_const(n)))?;
+                w.write_line(format!("tu.member.{} = val;", name.as_var(n)))?;
+                w.write_line("return tu;")?;
+            }
+            w.write_line("}")?.eob()?;
+
+            // emplace_*
+            w.write_line(format!(
+                "void emplace_{}({} val) {{",
+                name.as_fn_suffix(),
+                member_type
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("tag = Tag::{};", name.as_const(n)))?;
+                w.write_line(format!("member.{} = val;", name.as_var(n)))?;
+            }
+            w.write_line("}")?.eob()?;
+
+            // get_if_*
+            w.write_line(format!(
+                "const {} *get_if_{}() const {{",
+                member_type,
+                name.as_fn_suffix()
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!(
+                    "return holds_{}() ? &member.{} : nullptr;",
+                    name.as_fn_suffix(),
+                    name.as_var(n)
+                ))?;
+            }
+            w.write_line("}")?.eob()?;
+
+            w.write_line(format!(
+                "{} *get_if_{}() {{",
+                member_type,
+                name.as_fn_suffix()
+            ))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!(
+                    "return holds_{}() ? &member.{} : nullptr;",
+                    name.as_fn_suffix(),
+                    name.as_var(n)
+                ))?;
+            }
+            w.write_line("}")?.eob()?;
+        }
+
+        // holds_*
+        w.write_line(format!("bool holds_{}() const {{", name.as_fn_suffix()))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("return tag == Tag::{};", name.as_const(n)))?;
+        }
+        w.write_line("}")?;
+
+        Ok(())
+    }
+
+    fn define_union_member<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        union_name: &str,
+        i: usize,
+        member: &ASUnionMember,
+    ) -> Result<(), Error> {
+        let member_type = member.type_.as_ref();
+        match member_type {
+            ASType::Void => {
+                w.write_line(format!(
+                    "// --- {}: (no associated content) if tag={}",
+                    member.name.as_var(n),
+                    i
+                ))?;
+            }
+            _ => {
+                w.write_line(format!(
+                    "// --- {}: {} if tag={}",
+                    member.name.as_var(n),
+                    member_type.as_lang(n)?,
+                    i
+                ))?;
+            }
+        }
+        w.eob()?;
+        Self::define_union_member_accessors(w, n, union_name, member)?;
+        Ok(())
+    }
+
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let tag_repr = union_.tag_repr.as_ref();
+        w.write_line(format!("struct {} {{", name.as_type(n)))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("enum class Tag : {} {{", tag_repr.as_lang(n)?))?;
+            {
+                let mut w = w.new_block();
+                for (i, member) in union_.members.iter().enumerate() {
+                    Self::write_docs(&mut w, &member.docs)?;
+                    w.write_line(format!("{} = {},", member.name.as_const(n), i))?;
+                }
+            }
+            w.write_line("};")?;
+            w.eob()?;
+
+            // The member is naturally aligned, so no explicit padding is required after the tag
+            w.write_line("Tag tag;")?;
+            w.write_line("union {")?;
+            {
+                let mut w = w.new_block();
+                for member in &union_.members {
+                    let member_is_void = matches!(member.type_.as_ref(), ASType::Void);
+                    if !member_is_void {
+                        w.write_line(format!(
+                            "{} {};",
+                            member.type_.as_lang(n)?,
+                            member.name.as_var(n),
+                        ))?;
+                    }
+                }
+            }
+            w.write_line("} member;")?;
+            w.eob()?;
+
+            w.write_line("Tag index() const {")?;
+            w.new_block().write_line("return tag;")?;
+            w.write_line("}")?;
+
+            for (i, member) in union_.members.iter().enumerate() {
+                w.eob()?;
+                Self::define_union_member(&mut w, n, name, i, member)?;
+            }
+        }
+        w.write_line("};")?;
+        Self::write_layout_asserts(w, n, name, &[("member".to_string(), union_.member_offset)])?;
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::astype::*;
 
+#[allow(dead_code)]
 pub trait Normalize {
     fn as_str(&self) -> &str;
 
@@ -93,6 +94,6 @@ pub trait ToLanguageRepresentation {
 
 impl ToLanguageRepresentation for ASType {
     fn as_astype(&self) -> &ASType {
-        &self
+        self
     }
 }
@@ -7,51 +7,42 @@ impl DocGenerator {
         _module_name: &str,
         func_witx: &witx::Function,
     ) -> Result<(), Error> {
-        assert_eq!(func_witx.abi, witx::Abi::Preview1);
-        let name = func_witx.name.as_str().to_string();
-        let params_witx = &func_witx.params;
-        let mut params = vec![];
-        for param_witx in params_witx {
-            let param_name = param_witx.name.as_str();
-            let param_type = ASType::from(&param_witx.tref);
-            params.push((param_name.to_string(), param_type));
-        }
-
-        let results_witx = &func_witx.results;
-        assert_eq!(results_witx.len(), 1);
-        let result_witx = &results_witx[0];
-        let result = ASType::from(&result_witx.tref);
-        let result = match result {
-            ASType::Result(result) => result,
-            _ => unreachable!(),
-        };
+        let signature = FuncSignature::new(func_witx)?;
+        let name = &signature.name;
+        let params = &signature.params;
+        let results = &signature.results;
 
-        let ok_type = result.ok_type.clone();
-
-        let mut results = vec![];
-        // A tuple in a result is expanded into additional parameters, transformed to pointers
-        if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
-            for (i, tuple_member) in tuple_members.iter().enumerate() {
-                let name = format!("result{}_ptr", i);
-                results.push((name, tuple_member.type_.clone()));
+        w.write_line(format!("### {}", name.as_fn()))?;
+        if signature.export {
+            w.write_line("Exported: implemented by the guest and called by the host.")?;
+        }
+        match &signature.returns {
+            FuncReturn::Expected(result) => {
+                w.write_line(format!(
+                    "Returned error type: {}",
+                    result.error_type.as_lang()
+                ))?;
+            }
+            FuncReturn::Value(type_) => {
+                w.write_line(format!("Returned value: {}", type_.as_lang()))?;
+            }
+            FuncReturn::Nothing => {}
+            FuncReturn::Never => {
+                w.write_line("This function does not return.")?;
             }
-        } else {
-            let name = "result";
-            results.push((name.to_string(), ok_type));
         }
-
-        w.write_lines(format!(
-            "### {}\nReturned error type: {}",
-            name.as_fn(),
-            result.error_type.as_lang()
-        ))?;
         w.eob()?;
         if !params.is_empty() {
             w.write_line("#### Input:")?.eob()?;
             {
                 let mut w = w.new_block();
-                for param in &params {
-                    w.write_line(format!("{}: {}", param.0.as_var(), param.1.as_lang()))?;
+                for (param, param_witx) in params.iter().zip(&func_witx.params) {
+                    w.write_line(format!(
+                        "{}: {}{}",
+                        param.0.as_var(),
+                        param.1.as_lang(),
+                        inline_docs(&param_witx.docs)
+                    ))?;
                 }
             }
         }
@@ -65,13 +56,15 @@ impl DocGenerator {
                     w.write_line("#### Output:")?.eob()?;
                     {
                         let mut w = w.new_block();
-                        for result in &results {
+                        for result in results {
                             let result_as_ptr = ASType::MutPtr(result.1.clone());
-                            w.write_line(result_as_ptr.as_lang().to_string())?;
+                            w.write_line(result_as_ptr.as_lang())?;
                         }
                     }
                 }
             }
+        } else if let FuncReturn::Nothing = signature.returns {
+            w.write_line("This function has no output.")?;
         }
 
         let docs = &func_witx.docs;
@@ -5,9 +5,13 @@ mod tuple;
 mod union;
 
 use super::*;
+use crate::annotations::strip_annotations;
 use crate::astype::*;
 use crate::error::*;
+use crate::naming::Namer;
 use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
 use common::*;
 use std::io::Write;
 
@@ -25,14 +29,16 @@ impl<T: Write> Generator<T> for DocGenerator {
     fn generate(
         &self,
         writer: &mut T,
-        module_witx: witx::Module,
+        module_witx: &witx::Module,
         options: &Options,
+        _n: &Namer,
     ) -> Result<(), Error> {
         let mut w = PrettyWriter::new(writer, "* ");
         let module_name = match &self.module_name {
             None => module_witx.name().as_str().to_string(),
             Some(module_name) => module_name.to_string(),
         };
+        let selection = Selection::new(module_witx, &options.filter);
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
@@ -48,7 +54,7 @@ impl<T: Write> Generator<T> for DocGenerator {
         w.write_line("## Table of contents")?.eob()?;
         w.write_line("### Types list:")?.eob()?;
         w.write("[**[All](#types)**]")?;
-        for type_ in module_witx.typenames() {
+        for type_ in &selection.typenames {
             if skip_imports && &type_.module != module_id {
                 continue;
             }
@@ -58,7 +64,7 @@ impl<T: Write> Generator<T> for DocGenerator {
         w.eol()?.eob()?;
         w.write_line("### Functions list:")?.eob()?;
         w.write("[**[All](#functions)**]")?;
-        for func in module_witx.funcs() {
+        for func in &selection.funcs {
             let func_name = func.name.as_str();
             w.write(format!(" - [{}]", func_name.as_fn()))?;
         }
@@ -66,18 +72,18 @@ impl<T: Write> Generator<T> for DocGenerator {
 
         w.write_line("## Types")?.eob()?;
 
-        for type_ in module_witx.typenames() {
+        for type_ in &selection.typenames {
             if skip_imports && &type_.module != module_id {
                 continue;
             }
             let constants_for_type: Vec<_> = module_witx
                 .constants()
-                .into_iter()
                 .filter_map(|x| {
                     if x.ty == type_.name {
                         Some(ASConstant {
                             name: x.name.as_str().to_string(),
                             value: x.value,
+                            docs: x.docs.clone(),
                         })
                     } else {
                         None
@@ -89,7 +95,7 @@ impl<T: Write> Generator<T> for DocGenerator {
 
         w.write_line("## Functions")?.eob()?;
 
-        for func in module_witx.funcs() {
+        for func in &selection.funcs {
             Self::define_func(&mut w, &module_name, func.as_ref())?;
         }
 
@@ -99,6 +105,7 @@ impl<T: Write> Generator<T> for DocGenerator {
 
 impl DocGenerator {
     fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
         if docs.is_empty() {
             return Ok(());
         }
@@ -158,7 +165,12 @@ impl DocGenerator {
         {
             let mut w = w.new_block();
             for choice in &enum_.choices {
-                w.write_line(format!("{}: {}", choice.name.as_const(), name.as_type()))?;
+                w.write_line(format!(
+                    "{}: {}{}",
+                    choice.name.as_const(),
+                    name.as_type(),
+                    inline_docs(&choice.docs)
+                ))?;
             }
         }
         Ok(())
@@ -250,7 +262,12 @@ impl DocGenerator {
                 } else {
                     format!("{}", constant.value)
                 };
-                w.write_line(format!("{} = `{}`", constant.name.as_const(), value_s))?;
+                w.write_line(format!(
+                    "{} = `{}`{}",
+                    constant.name.as_const(),
+                    value_s,
+                    inline_docs(&constant.docs)
+                ))?;
             }
         }
         Ok(())
@@ -283,3 +300,20 @@ impl DocGenerator {
         Ok(())
     }
 }
+
+/// Return `docs` as a single line, to be appended to a list item describing a member.
+fn inline_docs(docs: &str) -> String {
+    let docs = docs
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("<", "\\<")
+        .replace(">", "\\>");
+    if docs.is_empty() {
+        docs
+    } else {
+        format!(" - {}", docs)
+    }
+}
@@ -17,9 +17,10 @@ impl DocGenerator {
             for member in members {
                 let member_type = member.type_.as_ref();
                 w.write_line(format!(
-                    "{}: {}",
+                    "{}: {}{}",
                     member.name.as_var(),
-                    member_type.as_lang()
+                    member_type.as_lang(),
+                    inline_docs(&member.docs)
                 ))?;
             }
         }
@@ -10,9 +10,10 @@ impl DocGenerator {
     ) -> Result<(), Error> {
         let member_type = member.type_.as_ref();
         w.write_line(format!(
-            "{}: {}",
+            "{}: {}{}",
             member.name.as_var(),
             member_type.as_lang(),
+            inline_docs(&member.docs)
         ))?;
         Ok(())
     }
@@ -1,3 +1,4 @@
+use crate::naming::Collision;
 use std::fmt;
 use witx::WitxError;
 
@@ -5,17 +6,48 @@ use witx::WitxError;
 pub enum Error {
     Witx(WitxError),
     Io(std::io::Error),
+    Config(String),
+    Usage(String),
+    NameCollision(Collision),
+    LayoutMismatch(String),
+    Unsupported(String),
+    UnsupportedOutputType(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", &self)
+        match self {
+            Error::Config(e) => write!(f, "Configuration error: {}", e),
+            Error::Usage(e) => write!(f, "{}", e),
+            Error::NameCollision(collision) => match &collision.other_name {
+                Some(other_name) => write!(
+                    f,
+                    "Name collision: [{}] and [{}] are both converted to [{}] ({:?})",
+                    other_name, collision.name, collision.identifier, collision.kind
+                ),
+                None => write!(
+                    f,
+                    "Name collision: [{}] is converted to [{}], which is reserved ({:?})",
+                    collision.name, collision.identifier, collision.kind
+                ),
+            },
+            Error::Unsupported(e) => write!(f, "Unsupported: {}", e),
+            Error::LayoutMismatch(e) => write!(f, "Layout mismatch: {}", e),
+            Error::UnsupportedOutputType(output_type) => {
+                write!(f, "Unsupported output type: [{}]", output_type)
+            }
+            _ => write!(f, "{:?}", &self),
+        }
     }
 }
 
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match self {
+            Error::Witx(e) => Some(e),
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
     }
 }
 
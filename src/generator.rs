@@ -1,12 +1,20 @@
 use super::*;
 use crate::error::*;
+use crate::naming::Namer;
 use std::io::Write;
 
 pub trait Generator<T: Write> {
+    /// Return the names of the types defined by the header, that no other type can be named.
+    fn prelude_types(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Generate code for a module, converting names to identifiers with `n`.
     fn generate(
         &self,
         writer: &mut T,
-        module_witx: witx::Module,
+        module_witx: &witx::Module,
         options: &Options,
+        n: &Namer,
     ) -> Result<(), Error>;
 }
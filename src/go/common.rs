@@ -0,0 +1,212 @@
+use super::tuple::Tuple;
+use crate::astype::*;
+use crate::error::*;
+use crate::naming::{to_case, NameKind, Namer};
+use convert_case::Case;
+
+/// Keywords, and predeclared and local identifiers the generated code refers to
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+    "bool",
+    "float32",
+    "float64",
+    "int8",
+    "int16",
+    "int32",
+    "int64",
+    "panic",
+    "string",
+    "uint8",
+    "uint16",
+    "uint32",
+    "uint64",
+    "api",
+    "builder",
+    "context",
+    "ctx",
+    "errno",
+    "errors",
+    "host",
+    "mod",
+    "r",
+    "runtime",
+    "stack",
+    "w",
+    "wazero",
+];
+
+pub fn escape_keyword(identifier: String) -> String {
+    if KEYWORDS.contains(&identifier.as_str()) {
+        format!("{}_", identifier)
+    } else {
+        identifier
+    }
+}
+
+#[allow(dead_code)]
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    fn as_type(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Type, self.as_str(), Case::Pascal))
+    }
+
+    fn as_fn(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Fn, self.as_str(), Case::Pascal))
+    }
+
+    fn as_var(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Var, self.as_str(), Case::Camel))
+    }
+
+    /// Struct fields are exported, so that they can be accessed from other packages.
+    fn as_field(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Var, self.as_str(), Case::Pascal))
+    }
+
+    fn as_const(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Const, self.as_str(), Case::Pascal))
+    }
+
+    fn as_namespace(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Namespace, self.as_str(), Case::Pascal))
+    }
+
+    fn as_package(&self) -> String {
+        escape_keyword(to_case(self.as_str(), Case::Flat))
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// Follow aliases down to the type they name.
+pub fn resolve(type_: &ASType) -> &ASType {
+    match type_ {
+        ASType::Alias(alias) => resolve(&alias.type_),
+        type_ => type_,
+    }
+}
+
+/// Return `true` if the elements of a buffer of `type_` are bytes, exchanged as `[]byte`.
+pub fn is_byte(type_: &ASType) -> bool {
+    matches!(resolve(type_), ASType::U8 | ASType::Char8)
+}
+
+/// Return the name of the `memReader` and `memWriter` methods accessing values of `type_` in
+/// guest memory, and the Go type they use, if it is stored as a single integer, float or boolean.
+pub fn primitive(type_: &ASType) -> Option<(&'static str, &'static str)> {
+    let primitive = match resolve(type_) {
+        ASType::Bool => ("boolean", "bool"),
+        ASType::Char8 | ASType::U8 | ASType::S8 => ("u8", "uint8"),
+        ASType::U16 | ASType::S16 => ("u16", "uint16"),
+        ASType::Char32
+        | ASType::U32
+        | ASType::S32
+        | ASType::USize
+        | ASType::Handle(_)
+        | ASType::ConstPtr(_)
+        | ASType::MutPtr(_) => ("u32", "uint32"),
+        ASType::U64 | ASType::S64 => ("u64", "uint64"),
+        ASType::F32 => ("f32", "float32"),
+        ASType::F64 => ("f64", "float64"),
+        ASType::Enum(enum_) => return primitive(&enum_.repr),
+        ASType::Constants(constants) => return primitive(&constants.repr),
+        _ => return None,
+    };
+    Some(primitive)
+}
+
+/// Return the type the aliases defined by the header stand for.
+fn underlying(type_name: &str) -> &str {
+    match type_name {
+        "Char8" => "uint8",
+        "Char32" | "WasiPtr" | "WasiUSize" => "uint32",
+        "WasiHandle" => "int32",
+        type_name => type_name,
+    }
+}
+
+/// Return `value` converted to `to_type`, unless it already has that type.
+pub fn convert(value: &str, from_type: &str, to_type: &str) -> String {
+    if underlying(from_type) == underlying(to_type) {
+        value.to_string()
+    } else {
+        format!("{}({})", to_type, value)
+    }
+}
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    /// Return the Go type of values of this type.
+    fn as_lang(&self, n: &Namer) -> Result<String, Error> {
+        let lang = match self.as_astype() {
+            ASType::Alias(alias) => alias.name.as_type(n),
+            ASType::Bool => "bool".to_string(),
+            ASType::Char32 => "Char32".to_string(),
+            ASType::Char8 => "Char8".to_string(),
+            ASType::F32 => "float32".to_string(),
+            ASType::F64 => "float64".to_string(),
+            ASType::Handle(resource_name) => resource_name.as_type(n),
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "WasiPtr".to_string(),
+            ASType::S8 => "int8".to_string(),
+            ASType::S16 => "int16".to_string(),
+            ASType::S32 => "int32".to_string(),
+            ASType::S64 => "int64".to_string(),
+            ASType::U8 => "uint8".to_string(),
+            ASType::U16 => "uint16".to_string(),
+            ASType::U32 => "uint32".to_string(),
+            ASType::U64 => "uint64".to_string(),
+            ASType::USize => "WasiUSize".to_string(),
+            ASType::Void => "".to_string(),
+            ASType::Constants(constants) => constants.repr.as_lang(n)?,
+            ASType::Enum(enum_) => enum_.repr.as_lang(n)?,
+            ASType::Tuple(tuple_members) => Tuple::name_for(n, tuple_members)?.as_type(n),
+            ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => {
+                "WasiSlice".to_string()
+            }
+            ASType::String(_) => "WasiString".to_string(),
+            ASType::Option(_) | ASType::Result(_) | ASType::Struct(_) | ASType::Union(_) => {
+                return Err(Error::Unsupported(format!(
+                    "{} types must be named to be used in Go bindings",
+                    self.as_astype().kind()
+                )))
+            }
+        };
+        Ok(lang)
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
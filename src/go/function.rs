@@ -0,0 +1,380 @@
+use super::*;
+use crate::layout::size_align;
+use std::io::Write;
+
+/// Return the `api.ValueType` of a WebAssembly type.
+fn value_type(type_: &witx::WasmType) -> &'static str {
+    match type_ {
+        witx::WasmType::I32 => "api.ValueTypeI32",
+        witx::WasmType::I64 => "api.ValueTypeI64",
+        witx::WasmType::F32 => "api.ValueTypeF32",
+        witx::WasmType::F64 => "api.ValueTypeF64",
+    }
+}
+
+/// Return the expression decoding the `index`th value of the stack, and its Go type.
+fn decode(type_: &witx::WasmType, index: usize) -> (String, &'static str) {
+    match type_ {
+        witx::WasmType::I32 => (format!("api.DecodeU32(stack[{}])", index), "uint32"),
+        witx::WasmType::I64 => (format!("stack[{}]", index), "uint64"),
+        witx::WasmType::F32 => (format!("api.DecodeF32(stack[{}])", index), "float32"),
+        witx::WasmType::F64 => (format!("api.DecodeF64(stack[{}])", index), "float64"),
+    }
+}
+
+/// Return the expression encoding `value`, of type `type_`, as a stack value.
+fn encode(
+    n: &Namer,
+    wasm_type: &witx::WasmType,
+    type_: &ASType,
+    value: &str,
+) -> Result<String, Error> {
+    let lang_type = type_.as_lang(n)?;
+    let encoded = match wasm_type {
+        witx::WasmType::I32 if matches!(resolve(type_), ASType::Bool) => {
+            format!("api.EncodeU32(boolToU32({}))", value)
+        }
+        witx::WasmType::I32 => format!("api.EncodeU32({})", convert(value, &lang_type, "uint32")),
+        witx::WasmType::I64 => convert(value, &lang_type, "uint64"),
+        witx::WasmType::F32 => format!("api.EncodeF32({})", convert(value, &lang_type, "float32")),
+        witx::WasmType::F64 => format!("api.EncodeF64({})", convert(value, &lang_type, "float64")),
+    };
+    Ok(encoded)
+}
+
+/// Return the size of the elements of a slice of `type_` in guest memory.
+fn element_size(type_: &ASType) -> Result<usize, Error> {
+    Ok(size_align(type_, "")?.size)
+}
+
+/// Return the values written to result pointers, leaving out the ones with no content.
+fn written_results(signature: &FuncSignature) -> Vec<(&str, &ASType)> {
+    signature
+        .results
+        .iter()
+        .filter(|(_, type_)| !matches!(type_.leaf(), ASType::Void))
+        .map(|(name, type_)| (name.as_str(), type_.as_ref()))
+        .collect()
+}
+
+/// Return the name of the variable holding a value to write to a result pointer.
+fn result_var(n: &Namer, name: &str) -> String {
+    name.trim_end_matches("_ptr").as_var(n)
+}
+
+/// Return the Go type of a parameter passed to the host implementation. Strings are copied,
+/// byte slices and buffers written by the host are views of guest memory, and other slices are
+/// copied into Go slices.
+fn param_type(n: &Namer, type_: &ASType) -> Result<String, Error> {
+    let param_type = match resolve(type_) {
+        ASType::String(_) => "string".to_string(),
+        ASType::Slice(element_type) | ASType::ReadBuffer(element_type)
+            if !is_byte(element_type) =>
+        {
+            format!("[]{}", element_type.as_lang(n)?)
+        }
+        ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => "[]byte".to_string(),
+        _ => type_.as_lang(n)?,
+    };
+    Ok(param_type)
+}
+
+/// Return `true` if passing a parameter of `type_` to the host implementation reads guest memory.
+fn reads_memory(type_: &ASType) -> bool {
+    matches!(
+        resolve(type_),
+        ASType::String(_)
+            | ASType::Slice(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_)
+            | ASType::Struct(_)
+            | ASType::Tuple(_)
+            | ASType::Union(_)
+    )
+}
+
+/// Return the expression converting the stack values of a parameter to the value passed to the
+/// host implementation. `raw` are the decoded stack values, with their Go types.
+fn param_value(n: &Namer, type_: &ASType, raw: &[(String, &str)]) -> Result<String, Error> {
+    let value = match resolve(type_) {
+        ASType::String(_) => format!("string(r.bytes({}, {}))", raw[0].0, raw[1].0),
+        ASType::Slice(element_type) | ASType::ReadBuffer(element_type) => {
+            if is_byte(element_type) {
+                format!("r.bytes({}, {})", raw[0].0, raw[1].0)
+            } else {
+                format!(
+                    "readSlice(&r, {}, {}, {}, func(offset uint32) {} {{ return {} }})",
+                    raw[0].0,
+                    raw[1].0,
+                    element_size(element_type)?,
+                    element_type.as_lang(n)?,
+                    read_value(n, element_type, "offset")?
+                )
+            }
+        }
+        ASType::WriteBuffer(element_type) => {
+            if is_byte(element_type) {
+                format!("r.bytes({}, {})", raw[0].0, raw[1].0)
+            } else {
+                format!(
+                    "r.slice({}, {}, {})",
+                    raw[0].0,
+                    raw[1].0,
+                    element_size(element_type)?
+                )
+            }
+        }
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => read_value(n, type_, &raw[0].0)?,
+        ASType::Bool => format!("{} != 0", raw[0].0),
+        _ => convert(&raw[0].0, raw[0].1, &type_.as_lang(n)?),
+    };
+    Ok(value)
+}
+
+impl GoHostGenerator {
+    /// Define an interface with a method for every function imported by the guest, to be
+    /// implemented by the host, and the function registering an implementation with a runtime.
+    pub fn define_host<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        selection: &Selection,
+    ) -> Result<(), Error> {
+        let funcs: Vec<_> = selection
+            .funcs
+            .iter()
+            .filter(|func| !is_export(func))
+            .collect();
+        if funcs.is_empty() {
+            return Ok(());
+        }
+        let host_name = format!("{}_host", module_name).as_type(n);
+        let define_name = format!("define_{}_imports", module_name).as_fn(n);
+
+        Self::write_comments(
+            w,
+            &format!(
+                "{} is implemented by the host to provide the functions of the `{}` module.
+Register an implementation with {}.
+Methods receive the module calling them, whose memory parameters are read from.
+Functions returning an error code return it last, results only being written if it is zero.",
+                host_name, module_name, define_name
+            ),
+        )?;
+        w.write_line(format!("type {} interface {{", host_name))?;
+        {
+            let mut w = w.new_block();
+            for (i, func) in funcs.iter().enumerate() {
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::define_host_method(&mut w, n, func)?;
+            }
+        }
+        w.write_line("}")?.eob()?;
+
+        Self::write_comments(
+            w,
+            &format!(
+                "{} returns a builder for the `{}` host module, whose functions call host.
+Instantiate it before the guest modules importing them.",
+                define_name, module_name
+            ),
+        )?;
+        w.write_line(format!(
+            "func {}(runtime wazero.Runtime, host {}) wazero.HostModuleBuilder {{",
+            define_name, host_name
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!(
+                "builder := runtime.NewHostModuleBuilder({:?})",
+                module_name
+            ))?;
+            for func in &funcs {
+                Self::define_func(&mut w, n, func)?;
+            }
+            w.write_line("return builder")?;
+        }
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+
+    fn define_host_method<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let signature = FuncSignature::new(func_witx)?;
+        let mut params = vec![
+            "ctx context.Context".to_string(),
+            "mod api.Module".to_string(),
+        ];
+        for (name, type_) in &signature.params {
+            params.push(format!("{} {}", name.as_var(n), param_type(n, type_)?));
+        }
+        let results = match &signature.returns {
+            FuncReturn::Expected(result) => written_results(&signature)
+                .iter()
+                .map(|(_, type_)| type_.as_lang(n))
+                .chain(std::iter::once(result.error_type.as_lang(n)))
+                .collect::<Result<_, _>>()?,
+            FuncReturn::Value(type_) => vec![type_.as_lang(n)?],
+            FuncReturn::Nothing | FuncReturn::Never => vec![],
+        };
+        let results = match results.len() {
+            0 => "".to_string(),
+            1 => format!(" {}", results[0]),
+            _ => format!(" ({})", results.join(", ")),
+        };
+        Self::write_comments(w, &func_docs(func_witx, |name| name.as_var(n)))?;
+        w.write_line(format!(
+            "{}({}){}",
+            signature.name.as_fn(n),
+            params.join(", "),
+            results
+        ))?;
+        Ok(())
+    }
+
+    /// Add the function called by the guest to `builder`, converting its arguments for the host
+    /// implementation and writing the results to the result pointers.
+    fn define_func<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let signature = FuncSignature::new(func_witx)?;
+        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
+        let mut body = vec![];
+
+        // Stack values are the decomposed parameters, then the result pointers
+        let mut index = 0;
+        let mut args = vec![];
+        let mut memory_read = false;
+        for (name, type_) in &signature.params {
+            let decomposed_count = type_.decompose(name, false).len();
+            let raw: Vec<_> = (index..index + decomposed_count)
+                .map(|i| decode(&signature_witx.params[i], i))
+                .collect();
+            index += decomposed_count;
+            memory_read |= reads_memory(type_);
+            let raw = if decomposed_count > 1 {
+                let decomposed = type_.decompose(name, false);
+                let mut vars = vec![];
+                for (decomposed, (value, go_type)) in decomposed.iter().zip(raw) {
+                    let var = decomposed.name.as_var(n);
+                    body.push(format!("{} := {}", var, value));
+                    vars.push((var, go_type));
+                }
+                vars
+            } else {
+                raw
+            };
+            if decomposed_count > 0 {
+                body.push(format!(
+                    "{} := {}",
+                    name.as_var(n),
+                    param_value(n, type_, &raw)?
+                ));
+                args.push(name.as_var(n));
+            }
+        }
+        if memory_read {
+            body.insert(0, "r := memReader{mem: mod.Memory(), ok: true}".to_string());
+            body.push("if !r.ok {".to_string());
+            body.push("\tpanic(errMemoryAccess)".to_string());
+            body.push("}".to_string());
+        }
+        let call_str = format!(
+            "host.{}({})",
+            signature.name.as_fn(n),
+            vec!["ctx".to_string(), "mod".to_string()]
+                .into_iter()
+                .chain(args)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        match &signature.returns {
+            FuncReturn::Expected(result) => {
+                let results = written_results(&signature);
+                let mut ptrs = vec![];
+                for (name, _) in &results {
+                    let ptr = name.as_var(n);
+                    body.push(format!(
+                        "{} := {}",
+                        ptr,
+                        decode(&signature_witx.params[index], index).0
+                    ));
+                    index += 1;
+                    ptrs.push(ptr);
+                }
+                let vars = results
+                    .iter()
+                    .map(|(name, _)| result_var(n, name))
+                    .chain(std::iter::once("errno".to_string()))
+                    .collect::<Vec<_>>();
+                body.push(format!("{} := {}", vars.join(", "), call_str));
+                if !results.is_empty() {
+                    body.push("if errno == 0 {".to_string());
+                    body.push("\tw := memWriter{mem: mod.Memory(), ok: true}".to_string());
+                    for ((name, type_), ptr) in results.iter().zip(ptrs) {
+                        body.push(format!(
+                            "\t{}",
+                            write_value(n, type_, &ptr, &result_var(n, name))?
+                        ));
+                    }
+                    body.push("\tif !w.ok {".to_string());
+                    body.push("\t\tpanic(errMemoryAccess)".to_string());
+                    body.push("\t}".to_string());
+                    body.push("}".to_string());
+                }
+                body.push(format!(
+                    "stack[0] = {}",
+                    encode(n, &signature_witx.results[0], &result.error_type, "errno")?
+                ));
+            }
+            FuncReturn::Value(type_) => {
+                body.push(format!(
+                    "stack[0] = {}",
+                    encode(n, &signature_witx.results[0], type_, &call_str)?
+                ));
+            }
+            FuncReturn::Nothing => body.push(call_str),
+            FuncReturn::Never => {
+                body.push(call_str);
+                body.push(format!(
+                    "panic({:?})",
+                    format!("{} returned", signature.name)
+                ));
+            }
+        }
+
+        let value_types =
+            |types: &[witx::WasmType]| types.iter().map(value_type).collect::<Vec<_>>().join(", ");
+        let param_names = signature
+            .params_decomposed
+            .iter()
+            .chain(signature.results_decomposed.iter())
+            .map(|param| format!("{:?}", param.name))
+            .collect::<Vec<_>>();
+
+        w.write_line("builder.NewFunctionBuilder().")?;
+        let mut w = w.new_block();
+        w.write_line(
+            "WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {",
+        )?;
+        w.new_block().write_lines(body.join("\n"))?;
+        w.write_line(format!(
+            "}}), []api.ValueType{{{}}}, []api.ValueType{{{}}}).",
+            value_types(&signature_witx.params),
+            value_types(&signature_witx.results)
+        ))?;
+        if !param_names.is_empty() {
+            w.write_line(format!("WithParameterNames({}).", param_names.join(", ")))?;
+        }
+        w.write_line(format!("Export({:?})", signature.name))?;
+        Ok(())
+    }
+}
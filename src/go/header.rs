@@ -0,0 +1,216 @@
+use super::*;
+use std::io::Write;
+
+impl GoHostGenerator {
+    /// Types defined by the header.
+    pub const PRELUDE_TYPES: &'static [&'static str] = &[
+        "Char32",
+        "Char8",
+        "WasiHandle",
+        "WasiPtr",
+        "WasiSlice",
+        "WasiString",
+        "WasiUSize",
+    ];
+
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>, module_name: &str) -> Result<(), Error> {
+        w.write_lines(
+            "//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+",
+        )?;
+        w.eob()?;
+        w.write_line(format!("package {}", module_name.as_package()))?;
+        w.eob()?;
+        w.write_lines(
+            "import (
+	\"context\"
+	\"errors\"
+
+	\"github.com/tetratelabs/wazero\"
+	\"github.com/tetratelabs/wazero/api\"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New(\"invalid guest memory access\")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+",
+        )?;
+        w.eob()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use super::*;
+use std::io::Write;
+
+/// Return the address `offset` bytes after the start of a value. Arguments of calls with
+/// multiple arguments are compact, as formatted by `gofmt`.
+pub fn at(offset: usize, compact: bool) -> String {
+    match (offset, compact) {
+        (0, _) => "offset".to_string(),
+        (offset, true) => format!("offset+{}", offset),
+        (offset, false) => format!("offset + {}", offset),
+    }
+}
+
+/// Return the name of the `memReader` and `memWriter` methods accessing values of `type_`, if
+/// it isn't stored as a single integer, float or boolean.
+fn composite_method(n: &Namer, type_: &ASType) -> Result<String, Error> {
+    match resolve(type_) {
+        ASType::String(_) => Ok("WasiString".to_string()),
+        ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => {
+            Ok("WasiSlice".to_string())
+        }
+        _ => defined_name(n, type_),
+    }
+}
+
+/// Return the name of the type `type_` names, that the methods are defined for.
+fn defined_name(n: &Namer, type_: &ASType) -> Result<String, Error> {
+    match type_ {
+        ASType::Alias(alias) if matches!(alias.type_.as_ref(), ASType::Alias(_)) => {
+            defined_name(n, &alias.type_)
+        }
+        type_ => type_.as_lang(n),
+    }
+}
+
+/// Return the expression reading a value of `type_` at `address` with the `r` reader.
+pub fn read_value(n: &Namer, type_: &ASType, address: &str) -> Result<String, Error> {
+    let value = match primitive(type_) {
+        Some((method, go_type)) => convert(
+            &format!("r.{}({})", method, address),
+            go_type,
+            &type_.as_lang(n)?,
+        ),
+        None => format!("r.{}({})", composite_method(n, type_)?, address),
+    };
+    Ok(value)
+}
+
+/// Return the statement writing `value`, of type `type_`, at `address` with the `w` writer.
+pub fn write_value(n: &Namer, type_: &ASType, address: &str, value: &str) -> Result<String, Error> {
+    let statement = match primitive(type_) {
+        Some((method, go_type)) => format!(
+            "w.{}({}, {})",
+            method,
+            address,
+            convert(value, &type_.as_lang(n)?, go_type)
+        ),
+        None => format!("w.{}({}, {})", composite_method(n, type_)?, address, value),
+    };
+    Ok(statement)
+}
+
+impl GoHostGenerator {
+    /// Define the `memReader` and `memWriter` methods reading and writing values of the `name`
+    /// type at `offset`, with the given bodies, and the exported functions calling them.
+    /// `validated` is `true` if reading or writing a value checks that it is valid.
+    pub fn define_memory_functions<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        read_body: &[String],
+        write_body: &[String],
+        validated: bool,
+    ) -> Result<(), Error> {
+        let type_name = name.as_type(n);
+        let failure = if validated {
+            "if it is out of range or invalid"
+        } else {
+            "if it is out of range"
+        };
+
+        w.write_line(format!(
+            "func (r *memReader) {}(offset uint32) {} {{",
+            type_name, type_name
+        ))?;
+        w.new_block().write_lines(read_body.join("\n"))?;
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!(
+            "func (w *memWriter) {}(offset uint32, v {}) {{",
+            type_name, type_name
+        ))?;
+        w.new_block().write_lines(write_body.join("\n"))?;
+        w.write_line("}")?.eob()?;
+
+        let read_name = format!("read_{}", name).as_fn(n);
+        w.write_line(format!(
+            "// {} reads a {} from guest memory at offset, and returns false {}.",
+            read_name, type_name, failure
+        ))?;
+        w.write_line(format!(
+            "func {}(mem api.Memory, offset uint32) ({}, bool) {{",
+            read_name, type_name
+        ))?;
+        w.new_block().write_lines(format!(
+            "r := memReader{{mem: mem, ok: true}}
+v := r.{}(offset)
+return v, r.ok",
+            type_name
+        ))?;
+        w.write_line("}")?.eob()?;
+
+        let write_name = format!("write_{}", name).as_fn(n);
+        w.write_line(format!(
+            "// {} writes a {} to guest memory at offset, and returns false {}.",
+            write_name, type_name, failure
+        ))?;
+        w.write_line(format!(
+            "func {}(mem api.Memory, offset uint32, v {}) bool {{",
+            write_name, type_name
+        ))?;
+        w.new_block().write_lines(format!(
+            "w := memWriter{{mem: mem, ok: true}}
+w.{}(offset, v)
+return w.ok",
+            type_name
+        ))?;
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+mod common;
+mod function;
+mod header;
+mod memory;
+mod r#struct;
+mod tuple;
+mod union;
+
+use super::*;
+use crate::annotations::strip_annotations;
+use crate::astype::*;
+use crate::error::*;
+use crate::handles::resources_to_define;
+use crate::naming::Namer;
+use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
+use common::*;
+use memory::*;
+use std::io::Write;
+
+pub struct GoHostGenerator {
+    module_name: Option<String>,
+}
+
+impl GoHostGenerator {
+    pub fn new(module_name: Option<String>) -> Self {
+        GoHostGenerator { module_name }
+    }
+}
+
+impl<T: Write> Generator<T> for GoHostGenerator {
+    fn prelude_types(&self) -> &'static [&'static str] {
+        Self::PRELUDE_TYPES
+    }
+
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: &witx::Module,
+        options: &Options,
+        n: &Namer,
+    ) -> Result<(), Error> {
+        // Definitions are followed by a blank line, that `gofmt` removes at the end of the file
+        let mut buffer = vec![];
+        let mut w = PrettyWriter::new(&mut buffer, "\t");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+        let selection = Selection::new(module_witx, &options.filter);
+        let module_id = module_witx.module_id();
+        let skip_imports = options.skip_imports;
+
+        if !options.skip_header {
+            Self::header(&mut w, &module_name)?;
+        } else {
+            // Separate the module from the previous one in the same file
+            w.eob()?;
+        }
+
+        let module_title_comments = format!(
+            "---------------------- Module: [{}] ----------------------",
+            module_name
+        );
+        Self::write_comments(&mut w, &module_title_comments)?;
+        w.eob()?;
+
+        for resource in resources_to_define(module_witx, &selection) {
+            if skip_imports && &resource.resource_id.module_id != module_id {
+                continue;
+            }
+            let resource_name = resource.resource_id.name.as_str();
+            Self::write_comments(&mut w, &resource.docs)?;
+            Self::define_resource(&mut w, n, resource_name)?;
+            w.eob()?;
+        }
+
+        for type_ in &selection.typenames {
+            if skip_imports && &type_.module != module_id {
+                continue;
+            }
+            let constants_for_type: Vec<_> = module_witx
+                .constants()
+                .filter_map(|x| {
+                    if x.ty == type_.name {
+                        Some(ASConstant {
+                            name: x.name.as_str().to_string(),
+                            value: x.value,
+                            docs: x.docs.clone(),
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            Self::define_type(&mut w, n, type_.as_ref(), &constants_for_type)?;
+        }
+
+        Self::define_host(&mut w, n, &module_name, &selection)?;
+        drop(w);
+
+        let end = buffer.len() - buffer.iter().rev().take_while(|&&c| c == b'\n').count();
+        writer.write_all(&buffer[..end])?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// Return the lines of a block whose first column is aligned, as formatted by `gofmt`.
+/// Each line is a documentation string, written as comments, and two columns. Comments
+/// interrupt the alignment.
+fn aligned(lines: &[(&str, String, String)]) -> Vec<String> {
+    let mut aligned = vec![];
+    let mut width = 0;
+    for (i, (docs, first, second)) in lines.iter().enumerate() {
+        if i == 0 || !docs.is_empty() {
+            width = lines[i..]
+                .iter()
+                .enumerate()
+                .take_while(|(j, (docs, _, _))| *j == 0 || docs.is_empty())
+                .map(|(_, (_, first, _))| first.len())
+                .max()
+                .unwrap_or(0);
+        }
+        for docs_line in docs.lines() {
+            match docs_line.trim_end() {
+                "" => aligned.push("//".to_string()),
+                docs_line => aligned.push(format!("// {}", docs_line)),
+            }
+        }
+        aligned.push(format!("{:width$} {}", first, second, width = width));
+    }
+    aligned
+}
+
+impl GoHostGenerator {
+    fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
+        if docs.is_empty() {
+            return Ok(());
+        }
+        for docs_line in docs.lines() {
+            match docs_line.trim_end() {
+                "" => w.write_line("//")?,
+                docs_line => w.write_line(format!("// {}", docs_line))?,
+            };
+        }
+        Ok(())
+    }
+
+    /// Define the typed constants of a type, in a `const` block.
+    fn define_const_block<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        constants: &[(String, String, &str)],
+    ) -> Result<(), Error> {
+        if constants.is_empty() {
+            return Ok(());
+        }
+        let lines: Vec<_> = constants
+            .iter()
+            .map(|(name, value, docs)| {
+                (
+                    *docs,
+                    name.clone(),
+                    format!("{} = {}", type_name.as_type(n), value),
+                )
+            })
+            .collect();
+        w.write_line("const (")?;
+        w.new_block().write_lines(aligned(&lines).join("\n"))?;
+        w.write_line(")")?.eob()?;
+        Ok(())
+    }
+
+    /// Define a struct with the given fields, each one a documentation string, a name and a type.
+    fn define_go_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        fields: &[(&str, String, String)],
+    ) -> Result<(), Error> {
+        w.write_line(format!("type {} struct {{", name.as_type(n)))?;
+        w.new_block().write_lines(aligned(fields).join("\n"))?;
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+
+    /// Return the body of a `memReader` method returning a struct with the given fields, each one
+    /// a name and the expression reading it.
+    fn struct_literal(n: &Namer, name: &str, fields: &[(String, String)]) -> Vec<String> {
+        if fields.is_empty() {
+            return vec![format!("return {}{{}}", name.as_type(n))];
+        }
+        let lines: Vec<_> = fields
+            .iter()
+            .map(|(field, value)| ("", format!("{}:", field), format!("{},", value)))
+            .collect();
+        let mut body = vec![format!("return {}{{", name.as_type(n))];
+        body.extend(
+            aligned(&lines)
+                .into_iter()
+                .map(|line| format!("\t{}", line)),
+        );
+        body.push("}".to_string());
+        body
+    }
+
+    fn define_as_alias<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        other_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "type {} = {}",
+            name.as_type(n),
+            other_name.as_type(n)
+        ))?;
+        Ok(())
+    }
+
+    fn define_as_atom<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        // Strings and slices keep the methods of the header types
+        match type_ {
+            ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => {
+                w.write_line(format!("type {} = {}", name.as_type(n), type_.as_lang(n)?))?;
+                Ok(())
+            }
+            _ => {
+                w.write_line(format!("type {} {}", name.as_type(n), type_.as_lang(n)?))?;
+                Ok(())
+            }
+        }
+    }
+
+    fn define_resource<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        resource_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!("type {} WasiHandle", resource_name.as_type(n)))?;
+        Ok(())
+    }
+
+    fn define_as_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        enum_: &ASEnum,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "type {} {}",
+            name.as_type(n),
+            enum_.repr.as_lang(n)?
+        ))?;
+        w.eob()?;
+        let constants: Vec<_> = enum_
+            .choices
+            .iter()
+            .map(|choice| {
+                (
+                    format!("{}_{}", name, choice.name).as_const(n),
+                    choice.value.to_string(),
+                    choice.docs.as_str(),
+                )
+            })
+            .collect();
+        Self::define_const_block(w, n, name, &constants)?;
+        Ok(())
+    }
+
+    fn define_as_constants<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        constants: &ASConstants,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "type {} {}",
+            name.as_type(n),
+            constants.repr.as_lang(n)?
+        ))?;
+        w.eob()?;
+        Self::define_constants_for_type(w, n, name, &constants.constants)?;
+        Ok(())
+    }
+
+    fn define_as_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        match type_ {
+            ASType::Alias(_)
+            | ASType::Bool
+            | ASType::Char8
+            | ASType::Char32
+            | ASType::F32
+            | ASType::F64
+            | ASType::U8
+            | ASType::U16
+            | ASType::U32
+            | ASType::U64
+            | ASType::S8
+            | ASType::S16
+            | ASType::S32
+            | ASType::S64
+            | ASType::USize
+            | ASType::ConstPtr(_)
+            | ASType::MutPtr(_)
+            | ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => {
+                Self::define_as_atom(w, n, name, type_)?;
+                w.eob()?;
+            }
+            ASType::Handle(_) => {
+                Self::define_resource(w, n, name)?;
+                w.eob()?;
+            }
+            ASType::Enum(enum_) => Self::define_as_enum(w, n, name, enum_)?,
+            ASType::Union(union_) => Self::define_as_union(w, n, name, union_)?,
+            ASType::Constants(constants) => Self::define_as_constants(w, n, name, constants)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, n, name, members)?,
+            ASType::Struct(members) => Self::define_as_struct(w, n, name, members)?,
+            _ => {
+                return Err(Error::Unsupported(format!(
+                    "type [{}] is a {}, that can't be defined in Go bindings",
+                    name,
+                    type_.kind()
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn define_constants_for_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        if constants.is_empty() {
+            return Ok(());
+        }
+
+        let mut hex = false;
+        let mut single_bits: usize = 0;
+        for constant in constants {
+            if constant.value > 0xffff {
+                hex = true;
+            }
+            if constant.value.count_ones() == 1 {
+                single_bits += 1;
+            }
+        }
+        if constants.len() > 2 && single_bits == constants.len() {
+            hex = true;
+        }
+        let constants: Vec<_> = constants
+            .iter()
+            .map(|constant| {
+                let value_s = if hex {
+                    format!("0x{:x}", constant.value)
+                } else {
+                    format!("{}", constant.value)
+                };
+                (
+                    format!("{}_{}", type_name, constant.name).as_const(n),
+                    value_s,
+                    constant.docs.as_str(),
+                )
+            })
+            .collect();
+        Self::define_const_block(w, n, type_name, &constants)?;
+        Ok(())
+    }
+
+    fn define_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_witx: &witx::NamedType,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let docs = &type_witx.docs;
+        if !docs.is_empty() {
+            Self::write_comments(w, docs)?;
+        }
+        let type_name = type_witx.name.as_str();
+        let tref = &type_witx.tref;
+        match tref {
+            witx::TypeRef::Name(other_type) => {
+                Self::define_as_alias(w, n, type_name, other_type.name.as_str())?;
+                w.eob()?;
+            }
+            witx::TypeRef::Value(type_witx) => {
+                let t = ASType::from(type_witx.as_ref());
+                Self::define_as_type(w, n, type_name, &t)?
+            }
+        }
+        Self::define_constants_for_type(w, n, type_name, constants)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+use std::io::Write;
+
+impl GoHostGenerator {
+    pub fn define_as_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        members: &[ASStructMember],
+    ) -> Result<(), Error> {
+        let fields = members
+            .iter()
+            .map(|member| {
+                Ok((
+                    member.docs.as_str(),
+                    member.name.as_field(n),
+                    member.type_.as_lang(n)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Self::define_go_struct(w, n, name, &fields)?;
+
+        let read_fields = members
+            .iter()
+            .map(|member| {
+                Ok((
+                    member.name.as_field(n),
+                    read_value(n, &member.type_, &at(member.offset, false))?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let write_body = members
+            .iter()
+            .map(|member| {
+                write_value(
+                    n,
+                    &member.type_,
+                    &at(member.offset, true),
+                    &format!("v.{}", member.name.as_field(n)),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::define_memory_functions(
+            w,
+            n,
+            name,
+            &Self::struct_literal(n, name, &read_fields),
+            &write_body,
+            false,
+        )
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+use std::io::Write;
+
+pub struct Tuple;
+
+impl Tuple {
+    pub fn name_for(n: &Namer, tuple_members: &[ASTupleMember]) -> Result<String, Error> {
+        let member_types = tuple_members
+            .iter()
+            .map(|member| member.type_.as_lang(n))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(format!(
+            "WasiTuple{}{}",
+            tuple_members.len(),
+            member_types.join("_")
+        ))
+    }
+}
+
+impl GoHostGenerator {
+    pub fn define_as_tuple<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        members: &[ASTupleMember],
+    ) -> Result<(), Error> {
+        let fields = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| Ok(("", format!("V{}", i), member.type_.as_lang(n)?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Self::define_go_struct(w, n, name, &fields)?;
+
+        let read_fields = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| {
+                Ok((
+                    format!("V{}", i),
+                    read_value(n, &member.type_, &at(member.offset, false))?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let write_body = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| {
+                write_value(
+                    n,
+                    &member.type_,
+                    &at(member.offset, true),
+                    &format!("v.V{}", i),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::define_memory_functions(
+            w,
+            n,
+            name,
+            &Self::struct_literal(n, name, &read_fields),
+            &write_body,
+            false,
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+use std::io::Write;
+
+impl GoHostGenerator {
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let tag_type = union_.tag_repr.as_lang(n)?;
+        let cases: Vec<_> = union_
+            .members
+            .iter()
+            .filter(|member| !matches!(member.type_.as_ref(), ASType::Void))
+            .collect();
+
+        // Only the field of the case selected by the tag is meaningful
+        let mut fields = vec![("", "Tag".to_string(), tag_type.clone())];
+        for member in &cases {
+            fields.push(("", member.name.as_field(n), member.type_.as_lang(n)?));
+        }
+        Self::define_go_struct(w, n, name, &fields)?;
+
+        let tags: Vec<_> = union_
+            .members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| {
+                (
+                    format!("{}_{}", name, member.name).as_const(n),
+                    format!("{} = {}", tag_type, i),
+                    member.docs.as_str(),
+                )
+            })
+            .collect();
+        let lines: Vec<_> = tags
+            .iter()
+            .map(|(tag, value, docs)| (*docs, tag.clone(), value.clone()))
+            .collect();
+        w.write_line("const (")?;
+        w.new_block().write_lines(aligned(&lines).join("\n"))?;
+        w.write_line(")")?.eob()?;
+
+        let member_at = at(union_.member_offset, false);
+        let mut read_body = vec![format!(
+            "v := {}{{Tag: {}}}",
+            name.as_type(n),
+            read_value(n, &union_.tag_repr, "offset")?
+        )];
+        read_body.push("switch v.Tag {".to_string());
+        let mut write_body = vec![
+            write_value(n, &union_.tag_repr, "offset", "v.Tag")?,
+            "switch v.Tag {".to_string(),
+        ];
+        for (member, (tag, _, _)) in union_.members.iter().zip(tags.iter()) {
+            read_body.push(format!("case {}:", tag));
+            write_body.push(format!("case {}:", tag));
+            if matches!(member.type_.as_ref(), ASType::Void) {
+                continue;
+            }
+            let field = member.name.as_field(n);
+            read_body.push(format!(
+                "\tv.{} = {}",
+                field,
+                read_value(n, &member.type_, &member_at)?
+            ));
+            write_body.push(format!(
+                "\t{}",
+                write_value(
+                    n,
+                    &member.type_,
+                    &at(union_.member_offset, true),
+                    &format!("v.{}", field),
+                )?
+            ));
+        }
+        read_body.extend(vec![
+            "default:".to_string(),
+            "\tr.ok = false".to_string(),
+            "}".to_string(),
+            "return v".to_string(),
+        ]);
+        write_body.extend(vec![
+            "default:".to_string(),
+            "\tw.ok = false".to_string(),
+            "}".to_string(),
+        ]);
+        Self::define_memory_functions(w, n, name, &read_body, &write_body, true)
+    }
+}
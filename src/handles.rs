@@ -0,0 +1,92 @@
+use crate::annotations::CLOSE_ANNOTATION;
+use crate::astype::*;
+use crate::selection::Selection;
+use crate::signature::is_export;
+use std::rc::Rc;
+
+/// Return the function named by a `@close <function>` line in `docs`, if there is one.
+fn close_annotation(docs: &str) -> Option<String> {
+    docs.lines().find_map(|line| {
+        let mut tokens = line.split_whitespace();
+        match (tokens.next(), tokens.next()) {
+            (Some(CLOSE_ANNOTATION), Some(func_name)) => {
+                Some(func_name.trim_start_matches('$').to_string())
+            }
+            _ => None,
+        }
+    })
+}
+
+fn is_close_function_name(func_name: &str) -> bool {
+    func_name == "close" || func_name.ends_with("_close")
+}
+
+/// Return the name of the resource handles of type `type_` refer to.
+pub fn handle_resource(type_: &ASType) -> Option<&str> {
+    match type_.leaf() {
+        ASType::Handle(resource_name) => Some(resource_name),
+        _ => None,
+    }
+}
+
+/// Return the resources of `selection` that need a type of their own.
+///
+/// Handle types are defined as distinct types, even if several of them refer to the same
+/// resource, so that they can't be mixed up. A handle type named after its resource stands for
+/// it, so the resource isn't defined again.
+pub fn resources_to_define<'a>(
+    module_witx: &'a witx::Module,
+    selection: &'a Selection,
+) -> impl Iterator<Item = &'a witx::Resource> + 'a {
+    selection
+        .resources
+        .iter()
+        .filter(move |resource| module_witx.typename(&resource.resource_id.name).is_none())
+        .map(|resource| resource.as_ref())
+}
+
+/// Return the function closing handles of the named type `type_name`, if there is one.
+///
+/// A `@close <function>` line in the documentation of the type, or of its resource, takes
+/// precedence. Otherwise, the function has to take the handle as its only parameter, and to be
+/// either named `<type_name>_close` or `<resource_name>_close`, or be the only `close` or
+/// `*_close` function accepting that handle type. Exported functions are never used.
+pub fn close_function_for(
+    module_witx: &witx::Module,
+    type_name: &str,
+) -> Option<Rc<witx::Function>> {
+    let type_witx = module_witx.typename(&witx::Id::new(type_name))?;
+    let resource_name = handle_resource(&ASType::from(&type_witx.tref))?.to_string();
+    let resource_docs = module_witx
+        .resources()
+        .find(|resource| resource.resource_id.name.as_str() == resource_name)
+        .map(|resource| resource.docs.clone())
+        .unwrap_or_default();
+
+    if let Some(func_name) =
+        close_annotation(&type_witx.docs).or_else(|| close_annotation(&resource_docs))
+    {
+        return module_witx
+            .func(&witx::Id::new(func_name))
+            .filter(|func| !is_export(func));
+    }
+
+    let candidates: Vec<_> = module_witx
+        .funcs()
+        .filter(|func| {
+            is_close_function_name(func.name.as_str())
+                && !is_export(func)
+                && func.params.len() == 1
+                && matches!(&func.params[0].tref, witx::TypeRef::Name(param_type) if param_type.name.as_str() == type_name)
+        })
+        .collect();
+    let by_name = candidates.iter().find(|func| {
+        func.name.as_str() == format!("{}_close", type_name)
+            || func.name.as_str() == format!("{}_close", resource_name)
+    });
+    match (by_name, candidates.len()) {
+        (Some(func), _) => Some(func.clone()),
+        (None, 1) => Some(candidates[0].clone()),
+        _ => None,
+    }
+}
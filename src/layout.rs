@@ -0,0 +1,267 @@
+use crate::astype::*;
+use crate::error::*;
+use std::rc::Rc;
+use witx::{Layout as _, SizeAlign};
+
+/// Check that the sizes, alignments, offsets and padding computed for every type of the module
+/// are consistent with each other, and with the layout computed by the `witx` crate.
+pub fn verify(module_witx: &witx::Module) -> Result<(), Error> {
+    for type_witx in module_witx.typenames() {
+        verify_type(
+            &ASType::from(&type_witx.tref),
+            &type_witx.tref,
+            type_witx.name.as_str(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Check the layout of `type_` against the one computed by the `witx` crate for `tref`: its size
+/// and alignment, the offset of every member of structs and tuples, and the offset of the payload
+/// of variants.
+fn verify_type(type_: &ASType, tref: &witx::TypeRef, path: &str) -> Result<(), Error> {
+    let actual = size_align(type_, path)?;
+    let expected = tref.mem_size_align(true);
+    check(path, "size", actual.size, expected.size)?;
+    check(path, "alignment", actual.align, expected.align)?;
+
+    let payload_offset = |variant: &witx::Variant| {
+        let offset = variant.payload_offset(true);
+        (offset, offset - variant.tag_repr.mem_size(true))
+    };
+    match (type_, tref.type_().as_ref()) {
+        (ASType::Struct(members), witx::Type::Record(record)) => {
+            for (member, layout) in members.iter().zip(record.member_layout(true)) {
+                let member_path = format!("{}.{}", path, member.name);
+                check(&member_path, "offset", member.offset, layout.offset)?;
+                verify_type(&member.type_, &layout.member.tref, &member_path)?;
+            }
+        }
+        (ASType::Tuple(members), witx::Type::Record(record)) => {
+            let layouts = record.member_layout(true);
+            for (i, (member, layout)) in members.iter().zip(layouts).enumerate() {
+                let member_path = format!("{}.{}", path, i);
+                check(&member_path, "offset", member.offset, layout.offset)?;
+                verify_type(&member.type_, &layout.member.tref, &member_path)?;
+            }
+        }
+        (ASType::Option(option), witx::Type::Variant(variant)) => {
+            let (offset, _) = payload_offset(variant);
+            check(path, "payload offset", option.offset, offset)?;
+        }
+        (ASType::Result(result), witx::Type::Variant(variant)) => {
+            let (offset, padding_after_tag) = payload_offset(variant);
+            check(path, "payload offset", result.result_offset, offset)?;
+            check(
+                path,
+                "padding after the tag",
+                result.padding_after_tag,
+                padding_after_tag,
+            )?;
+        }
+        (ASType::Union(union_), witx::Type::Variant(variant)) => {
+            let (offset, padding_after_tag) = payload_offset(variant);
+            check(path, "payload offset", union_.member_offset, offset)?;
+            check(
+                path,
+                "padding after the tag",
+                union_.padding_after_tag,
+                padding_after_tag,
+            )?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn check(path: &str, what: &str, actual: usize, expected: usize) -> Result<(), Error> {
+    if actual != expected {
+        return Err(Error::LayoutMismatch(format!(
+            "{}: {} is {}, expected {}",
+            path, what, actual, expected
+        )));
+    }
+    Ok(())
+}
+
+pub fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// Compute the layout of a type from the offsets and padding that the generators emit.
+pub fn size_align(type_: &ASType, path: &str) -> Result<SizeAlign, Error> {
+    let sa = |size, align| SizeAlign { size, align };
+    let res = match type_ {
+        ASType::Void => sa(0, 1),
+        ASType::Bool | ASType::Char8 | ASType::S8 | ASType::U8 => sa(1, 1),
+        ASType::S16 | ASType::U16 => sa(2, 2),
+        ASType::Char32
+        | ASType::USize
+        | ASType::F32
+        | ASType::S32
+        | ASType::U32
+        | ASType::Handle(_)
+        | ASType::ConstPtr(_)
+        | ASType::MutPtr(_)
+        | ASType::ReadBuffer(_)
+        | ASType::WriteBuffer(_) => sa(4, 4),
+        ASType::F64 | ASType::S64 | ASType::U64 => sa(8, 8),
+        ASType::Slice(_) | ASType::String(_) => sa(8, 4),
+        ASType::Alias(alias) => size_align(&alias.type_, &alias.name)?,
+        ASType::Enum(enum_) => size_align(&enum_.repr, path)?,
+        ASType::Constants(constants) => size_align(&constants.repr, path)?,
+        ASType::Struct(members) => record_size_align(
+            path,
+            members.iter().map(|member| {
+                (
+                    member.name.clone(),
+                    member.offset,
+                    &member.type_,
+                    member.padding,
+                )
+            }),
+        )?,
+        ASType::Tuple(members) => record_size_align(
+            path,
+            members
+                .iter()
+                .enumerate()
+                .map(|(i, member)| (i.to_string(), member.offset, &member.type_, member.padding)),
+        )?,
+        ASType::Option(option) => variant_size_align(
+            path,
+            &option.tag_repr,
+            option.offset,
+            None,
+            &[("some".to_string(), &option.type_)],
+        )?,
+        ASType::Result(result) => variant_size_align(
+            path,
+            &result.tag_repr,
+            result.result_offset,
+            Some(result.padding_after_tag),
+            &[
+                ("ok".to_string(), &result.ok_type),
+                ("err".to_string(), &result.error_type),
+            ],
+        )?,
+        ASType::Union(union_) => {
+            let members: Vec<_> = union_
+                .members
+                .iter()
+                .map(|member| (member.name.clone(), &member.type_))
+                .collect();
+            let res = variant_size_align(
+                path,
+                &union_.tag_repr,
+                union_.member_offset,
+                Some(union_.padding_after_tag),
+                &members,
+            )?;
+            for (name, type_) in &members {
+                let member_path = format!("{}.{}", path, name);
+                let member_size = size_align(type_, &member_path)?.size;
+                if member_size > union_.max_member_size {
+                    return Err(Error::LayoutMismatch(format!(
+                        "{}: size is {}, larger than the {} bytes reserved for members",
+                        member_path, member_size, union_.max_member_size
+                    )));
+                }
+            }
+            check(
+                path,
+                "size",
+                union_.member_offset + union_.max_member_size,
+                res.size,
+            )?;
+            res
+        }
+    };
+    Ok(res)
+}
+
+/// Check that every member of a struct or tuple starts right after the previous one and its
+/// padding, and return its layout.
+fn record_size_align<'t>(
+    path: &str,
+    members: impl Iterator<Item = (String, usize, &'t Rc<ASType>, usize)>,
+) -> Result<SizeAlign, Error> {
+    let (mut end, mut align) = (0, 1);
+    for (name, offset, type_, padding) in members {
+        let member_path = format!("{}.{}", path, name);
+        let member = size_align(type_, &member_path)?;
+        check(
+            &member_path,
+            "offset after the padding of the previous member",
+            offset,
+            end,
+        )?;
+        end = offset + member.size + padding;
+        align = align.max(member.align);
+    }
+    Ok(SizeAlign { size: end, align })
+}
+
+/// Check that the payload of a variant starts right after the tag and its padding, and return
+/// its layout.
+fn variant_size_align(
+    path: &str,
+    tag_repr: &ASType,
+    payload_offset: usize,
+    padding_after_tag: Option<usize>,
+    payloads: &[(String, &Rc<ASType>)],
+) -> Result<SizeAlign, Error> {
+    let tag = size_align(tag_repr, path)?;
+    let (mut payload_size, mut payload_align) = (0, 1);
+    for (name, type_) in payloads {
+        let payload = size_align(type_, &format!("{}.{}", path, name))?;
+        payload_size = payload_size.max(payload.size);
+        payload_align = payload_align.max(payload.align);
+    }
+    if let Some(padding_after_tag) = padding_after_tag {
+        check(
+            path,
+            "payload offset after the tag padding",
+            tag.size + padding_after_tag,
+            payload_offset,
+        )?;
+    }
+    let align = tag.align.max(payload_align);
+    Ok(SizeAlign {
+        size: align_to(payload_offset + payload_size, align),
+        align,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WITX: &str = "(module $layout
+        (typename $padded (record (field $a u8) (field $b u8) (field $c u32))))";
+
+    #[test]
+    fn inner_member_offset_mismatch() {
+        let module_witx = witx::parse(WITX).unwrap();
+        let type_witx = module_witx.typename(&witx::Id::new("padded")).unwrap();
+        let mut type_ = ASType::from(&type_witx.tref);
+        verify_type(&type_, &type_witx.tref, "padded").unwrap();
+
+        // Moving `b` by one byte, along with the padding around it, keeps the size, the alignment
+        // and the offset of the last member unchanged
+        match &mut type_ {
+            ASType::Struct(members) => {
+                members[0].padding += 1;
+                members[1].offset += 1;
+                members[1].padding -= 1;
+            }
+            _ => panic!("padded isn't a struct"),
+        }
+        match verify_type(&type_, &type_witx.tref, "padded") {
+            Err(Error::LayoutMismatch(message)) => {
+                assert_eq!(message, "padded.b: offset is 2, expected 1")
+            }
+            res => panic!("unexpected result: {:?}", res.map(|_| ())),
+        }
+    }
+}
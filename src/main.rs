@@ -1,27 +1,46 @@
 #![forbid(unsafe_code)]
 
+mod annotations;
 mod assemblyscript;
 mod astype;
+mod config;
+mod cpp;
 mod doc;
 mod error;
 mod generator;
+mod go;
+mod handles;
+mod layout;
+mod naming;
 mod overview;
 mod pretty_writer;
+mod python;
 mod rust;
+mod selection;
+mod signature;
+mod watch;
 mod zig;
 
 #[macro_use]
 extern crate clap;
 
+use crate::config::Config;
 use crate::error::*;
 use crate::generator::*;
-use clap::Arg;
-use std::fs::File;
+use crate::naming::{NameKind, Namer, Naming};
+use crate::selection::Filter;
+use clap::{Arg, ArgMatches};
+use std::fs::{self, File};
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
 pub struct Options {
     skip_imports: bool,
     skip_header: bool,
+    no_std: bool,
+    serde: bool,
+    arbitrary: bool,
+    filter: Filter,
 }
 
 fn main() {
@@ -41,6 +60,23 @@ fn main() {
                 .multiple(false)
                 .help("Output file, or - for the standard output"),
         )
+        .arg(
+            Arg::with_name("out_dir")
+                .short("-d")
+                .long("--out-dir")
+                .value_name("out_dir")
+                .multiple(false)
+                .conflicts_with("output_file")
+                .help("Output directory, with one file per output type"),
+        )
+        .arg(
+            Arg::with_name("emit_crate")
+                .long("--emit-crate")
+                .value_name("crate_dir")
+                .multiple(false)
+                .conflicts_with_all(&["output_file", "out_dir"])
+                .help("Write a Rust crate to this directory, with a module and a feature for every WITX module"),
+        )
         .arg(
             Arg::with_name("skip_imports")
                 .short("I")
@@ -53,10 +89,67 @@ fn main() {
                 .long("--skip-header")
                 .help("Do not generate a header"),
         )
+        .arg(
+            Arg::with_name("no_std")
+                .long("--no-std")
+                .help("Only use `core` in the Rust output, for `no_std` guests"),
+        )
+        .arg(
+            Arg::with_name("serde")
+                .long("--serde")
+                .help("Implement `Serialize` and `Deserialize` for the Rust types when the `serde` feature is enabled"),
+        )
+        .arg(
+            Arg::with_name("arbitrary")
+                .long("--arbitrary")
+                .help("Implement `arbitrary::Arbitrary` for the Rust types when the `arbitrary` feature is enabled, for fuzzing"),
+        )
+        .arg(
+            Arg::with_name("include")
+                .long("--include")
+                .value_name("patterns")
+                .multiple(true)
+                .number_of_values(1)
+                .use_delimiter(true)
+                .help("Only generate code for the functions and types matching these patterns, and the types they depend on"),
+        )
+        .arg(
+            Arg::with_name("exclude")
+                .long("--exclude")
+                .value_name("patterns")
+                .multiple(true)
+                .number_of_values(1)
+                .use_delimiter(true)
+                .help("Do not generate code for the functions and types matching these patterns, unless other types depend on them"),
+        )
+        .arg(
+            Arg::with_name("drop_unused_types")
+                .long("--drop-unused-types")
+                .help("Only generate types used by the selected functions"),
+        )
+        .arg(
+            Arg::with_name("verify_layout")
+                .long("--verify-layout")
+                .help("Check that the layout of every type matches the one computed by the witx crate"),
+        )
+        .arg(
+            Arg::with_name("watch")
+                .short("-w")
+                .long("--watch")
+                .help("Regenerate the outputs every time the WITX files, or the configuration file, change"),
+        )
+        .arg(
+            Arg::with_name("config_file")
+                .short("-c")
+                .long("--config")
+                .value_name("config_file")
+                .multiple(false)
+                .conflicts_with("witx_files")
+                .help("Configuration file to use when no WITX files are given [default: witx-codegen.toml]"),
+        )
         .arg(
             Arg::with_name("witx_files")
                 .multiple(true)
-                .required(true)
                 .help("WITX files"),
         )
         .arg(
@@ -66,42 +159,290 @@ fn main() {
                 .value_name("output_type")
                 .multiple(false)
                 .default_value("assemblyscript")
-                .help("Output type. One in: {assemblyscript, zig, rust, overview, markdown}"),
+                .help("Output type, or comma-separated list of output types. One in: {assemblyscript, zig, rust, cpp, python-host, go-host, overview, markdown}"),
         )
         .get_matches();
-    // generate all or generate no heade,r no imports
-    let mut writer: Box<dyn Write> = match matches.value_of("output_file") {
-        None | Some("-") => Box::new(std::io::stdout()),
-        Some(file) => Box::new(File::create(file).unwrap()),
+
+    let config_file = Path::new(
+        matches
+            .value_of("config_file")
+            .unwrap_or(config::DEFAULT_CONFIG_FILE),
+    );
+    let run = || {
+        if matches.is_present("witx_files") {
+            generate_from_args(&matches)
+        } else {
+            generate_from_config(config_file)
+        }
     };
-    let module_name = matches.value_of("module_name").map(|x| x.to_string());
-    let skip_imports = matches.is_present("skip_imports");
-    let skip_header = matches.is_present("skip_header");
-    let mut options = Options {
-        skip_imports,
-        skip_header,
+    if matches.is_present("watch") {
+        watch::watch(|| watched_files(&matches, config_file), run);
+    }
+    if let Err(e) = run() {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Return the files the outputs are generated from, including the ones pulled in by `use` declarations.
+fn watched_files(matches: &ArgMatches, config_file: &Path) -> Vec<PathBuf> {
+    match matches.values_of("witx_files") {
+        Some(witx_files) => watch::dependencies(&witx_files.map(PathBuf::from).collect::<Vec<_>>()),
+        None => {
+            let witx_files = Config::load(config_file)
+                .map(|config| config.witx_files)
+                .unwrap_or_default();
+            let mut files = watch::dependencies(&witx_files);
+            files.push(config_file.to_path_buf());
+            files
+        }
+    }
+}
+
+fn generator_for<T: Write>(
+    output_type: &str,
+    module_name: Option<String>,
+) -> Result<Box<dyn Generator<T>>, Error> {
+    let generator = match output_type {
+        "assemblyscript" => Box::new(assemblyscript::AssemblyScriptGenerator::new(module_name))
+            as Box<dyn Generator<_>>,
+        "zig" => Box::new(zig::ZigGenerator::new(module_name)) as Box<dyn Generator<_>>,
+        "cpp" | "c++" => Box::new(cpp::CppGenerator::new(module_name)) as Box<dyn Generator<_>>,
+        "rust" => Box::new(rust::RustGenerator::new(module_name)) as Box<dyn Generator<_>>,
+        "python-host" => {
+            Box::new(python::PythonHostGenerator::new(module_name)) as Box<dyn Generator<_>>
+        }
+        "go-host" => Box::new(go::GoHostGenerator::new(module_name)) as Box<dyn Generator<_>>,
+        "overview" => {
+            Box::new(overview::OverviewGenerator::new(module_name)) as Box<dyn Generator<_>>
+        }
+        "markdown" | "doc" => {
+            Box::new(doc::DocGenerator::new(module_name)) as Box<dyn Generator<_>>
+        }
+        _ => return Err(Error::UnsupportedOutputType(output_type.to_string())),
     };
-    let output_type = matches.value_of("output_type").unwrap();
-    let witx_files = matches.values_of("witx_files").unwrap();
-    for witx_file in witx_files {
-        let witx = witx::load(witx_file).unwrap();
-        let generator = match output_type {
-            "assemblyscript" => Box::new(assemblyscript::AssemblyScriptGenerator::new(
-                module_name.clone(),
-            )) as Box<dyn Generator<_>>,
-            "zig" => Box::new(zig::ZigGenerator::new(module_name.clone())) as Box<dyn Generator<_>>,
-            "rust" => {
-                Box::new(rust::RustGenerator::new(module_name.clone())) as Box<dyn Generator<_>>
-            }
-            "overview" => Box::new(overview::OverviewGenerator::new(module_name.clone()))
-                as Box<dyn Generator<_>>,
-            "markdown" | "doc" => {
-                Box::new(doc::DocGenerator::new(module_name.clone())) as Box<dyn Generator<_>>
-            }
-            _ => panic!("Unsupported output type"),
-        };
-        generator.generate(&mut writer, witx, &options).unwrap();
+    Ok(generator)
+}
+
+/// Return the name of the file to write to in an output directory, for `output_type`.
+fn output_file_name(output_type: &str) -> Result<&'static str, Error> {
+    let file_name = match output_type {
+        "assemblyscript" => "assemblyscript.ts",
+        "zig" => "zig.zig",
+        "cpp" | "c++" => "cpp.hpp",
+        "rust" => "rust.rs",
+        "python-host" => "python_host.py",
+        "go-host" => "go_host.go",
+        "overview" => "overview.txt",
+        "markdown" | "doc" => "markdown.md",
+        _ => return Err(Error::UnsupportedOutputType(output_type.to_string())),
+    };
+    Ok(file_name)
+}
+
+fn create_writer(output_file: Option<&Path>) -> Result<Box<dyn Write>, Error> {
+    let writer: Box<dyn Write> = match output_file {
+        None => Box::new(std::io::stdout()),
+        Some(file) if file == Path::new("-") => Box::new(std::io::stdout()),
+        Some(file) => Box::new(File::create(file)?),
+    };
+    Ok(writer)
+}
+
+fn load_modules(witx_files: &[PathBuf], verify_layout: bool) -> Result<Vec<witx::Module>, Error> {
+    let modules = witx_files
+        .iter()
+        .map(|witx_file| witx::load(witx_file).map_err(Error::from))
+        .collect::<Result<Vec<_>, _>>()?;
+    if verify_layout {
+        for module_witx in &modules {
+            layout::verify(module_witx)?;
+        }
+    }
+    Ok(modules)
+}
+
+/// Generate code for all the modules, in order.
+/// Imports and headers are only generated along with the first module.
+fn generate<T: Write>(
+    writer: &mut T,
+    output_type: &str,
+    modules: &[witx::Module],
+    module_name_for: impl Fn(&str) -> Option<String>,
+    naming: Naming,
+    mut options: Options,
+) -> Result<(), Error> {
+    let n = Namer::new(naming);
+    for module_witx in modules {
+        let module_name = module_name_for(module_witx.name().as_str());
+        let generator = generator_for(output_type, module_name)?;
+        n.reserve(NameKind::Type, generator.prelude_types());
+        generator.generate(writer, module_witx, &options, &n)?;
         options.skip_imports = true;
         options.skip_header = true;
     }
+    n.check()
+}
+
+fn generate_from_args(matches: &ArgMatches) -> Result<(), Error> {
+    let module_name = matches.value_of("module_name").map(|x| x.to_string());
+    let output_types: Vec<_> = matches
+        .value_of("output_type")
+        .unwrap()
+        .split(',')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .collect();
+    if let Some(crate_dir) = matches.value_of("emit_crate") {
+        if matches.occurrences_of("output_type") > 0 && output_types != ["rust"] {
+            return Err(Error::Usage(
+                "Crates can only be emitted for the rust output type".to_string(),
+            ));
+        }
+        return emit_crate_from_args(matches, Path::new(crate_dir));
+    }
+    let out_dir = matches.value_of("out_dir").map(Path::new);
+    if output_types.len() != 1 && out_dir.is_none() {
+        return Err(Error::Usage(
+            "Multiple output types require an output directory".to_string(),
+        ));
+    }
+    let output_file_names = output_types
+        .iter()
+        .map(|output_type| output_file_name(output_type))
+        .collect::<Result<Vec<_>, _>>()?;
+    let witx_files: Vec<_> = matches
+        .values_of("witx_files")
+        .unwrap()
+        .map(PathBuf::from)
+        .collect();
+    let filter = filter_from_args(matches);
+    let modules = load_modules(&witx_files, matches.is_present("verify_layout"))?;
+    if let Some(out_dir) = out_dir {
+        fs::create_dir_all(out_dir)?;
+    }
+    for (output_type, output_file_name) in output_types.iter().zip(output_file_names) {
+        let output_file = match out_dir {
+            Some(out_dir) => Some(out_dir.join(output_file_name)),
+            None => matches.value_of("output_file").map(PathBuf::from),
+        };
+        let mut writer = create_writer(output_file.as_deref())?;
+        let options = Options {
+            skip_imports: matches.is_present("skip_imports"),
+            skip_header: matches.is_present("skip_header"),
+            no_std: matches.is_present("no_std"),
+            serde: matches.is_present("serde"),
+            arbitrary: matches.is_present("arbitrary"),
+            filter: filter.clone(),
+        };
+        generate(
+            &mut writer,
+            output_type,
+            &modules,
+            |_| module_name.clone(),
+            Naming::default(),
+            options,
+        )?;
+    }
+    Ok(())
+}
+
+fn filter_from_args(matches: &ArgMatches) -> Filter {
+    let patterns = |name| {
+        matches
+            .values_of(name)
+            .map(|x| x.map(|x| x.to_string()).collect())
+            .unwrap_or_default()
+    };
+    Filter::new(
+        patterns("include"),
+        patterns("exclude"),
+        matches.is_present("drop_unused_types"),
+    )
+}
+
+/// Emit a Rust crate for the WITX files and the modules they use.
+fn emit_crate_from_args(matches: &ArgMatches, crate_dir: &Path) -> Result<(), Error> {
+    let witx_files: Vec<_> = matches
+        .values_of("witx_files")
+        .unwrap()
+        .map(PathBuf::from)
+        .collect();
+    let mut modules = load_modules(
+        &watch::dependencies(&witx_files),
+        matches.is_present("verify_layout"),
+    )?;
+    modules.sort_by(|a, b| a.name().as_str().cmp(b.name().as_str()));
+    modules.dedup_by(|a, b| a.name() == b.name());
+    // Every module of the crate is linked against its own import module
+    let module_name = matches.value_of("module_name").map(|x| x.to_string());
+    if module_name.is_some() && modules.len() > 1 {
+        return Err(Error::Usage(format!(
+            "--module-name can't be used when the crate has more than one module ({})",
+            modules
+                .iter()
+                .map(|module_witx| module_witx.name().as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    let options = Options {
+        skip_imports: true,
+        skip_header: true,
+        no_std: matches.is_present("no_std"),
+        serde: matches.is_present("serde"),
+        arbitrary: matches.is_present("arbitrary"),
+        filter: filter_from_args(matches),
+    };
+    rust::RustGenerator::emit_crate(
+        crate_dir,
+        &modules,
+        module_name,
+        &Naming::default(),
+        options,
+    )
+}
+
+fn generate_from_config(config_file: &Path) -> Result<(), Error> {
+    let config = Config::load(config_file)?;
+    if config.witx_files.is_empty() {
+        return Err(Error::Config(format!(
+            "{}: no WITX files to process",
+            config_file.display()
+        )));
+    }
+    let mut output_files = vec![];
+    for (output_type, target) in &config.targets {
+        let output_file_name = output_file_name(output_type)?;
+        let output_file = match (&target.output, &config.out_dir) {
+            (Some(output), _) => Some(output.clone()),
+            (None, Some(out_dir)) => Some(out_dir.join(output_file_name)),
+            (None, None) => None,
+        };
+        output_files.push(output_file);
+    }
+    let modules = load_modules(&config.witx_files, config.verify_layout)?;
+    if let Some(out_dir) = &config.out_dir {
+        fs::create_dir_all(out_dir)?;
+    }
+    for ((output_type, target), output_file) in config.targets.iter().zip(output_files) {
+        let mut writer = create_writer(output_file.as_deref())?;
+        let options = Options {
+            skip_imports: target.skip_imports.unwrap_or(config.skip_imports),
+            skip_header: target.skip_header.unwrap_or(config.skip_header),
+            no_std: target.no_std.unwrap_or(config.no_std),
+            serde: target.serde.unwrap_or(config.serde),
+            arbitrary: target.arbitrary.unwrap_or(config.arbitrary),
+            filter: config.filter(target),
+        };
+        generate(
+            &mut writer,
+            output_type,
+            &modules,
+            |module_name| config.module_name(target, module_name),
+            config.naming(target)?,
+            options,
+        )?;
+    }
+    Ok(())
 }
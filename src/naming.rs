@@ -0,0 +1,205 @@
+use crate::error::*;
+use convert_case::{Case, Casing};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Split `name` into words, at `_`, `-` and spaces, and where lowercase letters or digits are
+/// followed by an uppercase letter. Letters and digits following each other stay in the same
+/// word, so that `u64` isn't split.
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let separator = c == '_' || c == '-' || c.is_whitespace();
+        let boundary = separator
+            || (c.is_uppercase() && i > 0 && {
+                let previous = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+                previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next_is_lower)
+            });
+        if boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if !separator {
+            word.push(c);
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Convert `name` to `case`, keeping letters and digits that follow each other together.
+pub fn to_case(name: &str, case: Case) -> String {
+    let words = words(name);
+    let lower = || words.iter().map(|word| word.to_lowercase());
+    let upper = || words.iter().map(|word| word.to_uppercase());
+    match case {
+        Case::Snake => lower().collect::<Vec<_>>().join("_"),
+        Case::UpperSnake => upper().collect::<Vec<_>>().join("_"),
+        Case::Kebab => lower().collect::<Vec<_>>().join("-"),
+        Case::Flat => lower().collect(),
+        Case::UpperFlat => upper().collect(),
+        Case::Lower => lower().collect::<Vec<_>>().join(" "),
+        Case::Upper => upper().collect::<Vec<_>>().join(" "),
+        Case::Pascal | Case::UpperCamel => words.iter().map(|word| capitalize(word)).collect(),
+        Case::Camel => lower()
+            .take(1)
+            .chain(words.iter().skip(1).map(|word| capitalize(word)))
+            .collect(),
+        _ => name.to_case(case),
+    }
+}
+
+/// Category of a generated identifier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum NameKind {
+    Type,
+    Fn,
+    Var,
+    Const,
+    Namespace,
+}
+
+impl NameKind {
+    /// Return `true` if identifiers of that kind share a single scope, so that different names
+    /// must not be converted to the same identifier.
+    fn is_global(self) -> bool {
+        matches!(self, NameKind::Type | NameKind::Fn | NameKind::Namespace)
+    }
+}
+
+/// Naming overrides, applied on top of the conventions of a backend.
+#[derive(Clone, Debug, Default)]
+pub struct Naming {
+    cases: HashMap<NameKind, Case>,
+    prefixes: HashMap<NameKind, String>,
+    suffixes: HashMap<NameKind, String>,
+}
+
+impl Naming {
+    pub fn set_case(&mut self, kind: NameKind, case: Case) {
+        self.cases.insert(kind, case);
+    }
+
+    pub fn set_prefix(&mut self, kind: NameKind, prefix: &str) {
+        self.prefixes.insert(kind, prefix.to_string());
+    }
+
+    pub fn set_suffix(&mut self, kind: NameKind, suffix: &str) {
+        self.suffixes.insert(kind, suffix.to_string());
+    }
+}
+
+/// A name converted to the same identifier as another name, or as an identifier reserved by a backend.
+#[derive(Clone, Debug)]
+pub struct Collision {
+    pub kind: NameKind,
+    pub name: String,
+    pub other_name: Option<String>,
+    pub identifier: String,
+}
+
+/// Converts names to identifiers, and records the identifiers generated for an output, to report
+/// different names converted to the same identifier.
+pub struct Namer {
+    naming: Naming,
+    /// Names that generated identifiers were converted from, or `None` for reserved identifiers
+    identifiers: RefCell<HashMap<(NameKind, String), Option<String>>>,
+    collisions: RefCell<Vec<Collision>>,
+}
+
+impl Namer {
+    pub fn new(naming: Naming) -> Self {
+        Namer {
+            naming,
+            identifiers: RefCell::new(HashMap::new()),
+            collisions: RefCell::new(vec![]),
+        }
+    }
+
+    /// Convert `name` to an identifier of the given kind, using `default_case` if the case wasn't
+    /// overridden.
+    pub fn convert(&self, kind: NameKind, name: &str, default_case: Case) -> String {
+        let naming = &self.naming;
+        let case = naming.cases.get(&kind).copied().unwrap_or(default_case);
+        let identifier = format!(
+            "{}{}{}",
+            naming.prefixes.get(&kind).map(String::as_str).unwrap_or(""),
+            to_case(name, case),
+            naming.suffixes.get(&kind).map(String::as_str).unwrap_or(""),
+        );
+        if kind.is_global() {
+            let previous_name = self
+                .identifiers
+                .borrow_mut()
+                .entry((kind, identifier.clone()))
+                .or_insert_with(|| Some(name.to_string()))
+                .clone();
+            let mut collisions = self.collisions.borrow_mut();
+            let known_collision = collisions
+                .iter()
+                .any(|collision| collision.kind == kind && collision.identifier == identifier);
+            if previous_name.as_deref() != Some(name) && !known_collision {
+                collisions.push(Collision {
+                    kind,
+                    name: name.to_string(),
+                    other_name: previous_name,
+                    identifier: identifier.clone(),
+                });
+            }
+        }
+        identifier
+    }
+
+    /// Reserve identifiers defined by a backend, so that names converted to them are reported as
+    /// collisions.
+    pub fn reserve(&self, kind: NameKind, identifiers: &[&str]) {
+        let mut reserved = self.identifiers.borrow_mut();
+        for identifier in identifiers {
+            reserved
+                .entry((kind, identifier.to_string()))
+                .or_insert(None);
+        }
+    }
+
+    /// Return an error for the first collision found, if there is one.
+    pub fn check(&self) -> Result<(), Error> {
+        match self.collisions.borrow().first() {
+            Some(collision) => Err(Error::NameCollision(collision.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parse the name of a case, as used in configuration files.
+pub fn parse_case(name: &str) -> Option<Case> {
+    let case = match name {
+        "camel" => Case::Camel,
+        "pascal" => Case::Pascal,
+        "snake" => Case::Snake,
+        "upper_snake" | "screaming_snake" => Case::UpperSnake,
+        "kebab" => Case::Kebab,
+        "flat" => Case::Flat,
+        "upper_flat" => Case::UpperFlat,
+        "lower" => Case::Lower,
+        "upper" => Case::Upper,
+        _ => return None,
+    };
+    Some(case)
+}
@@ -7,7 +7,10 @@ mod union;
 use super::*;
 use crate::astype::*;
 use crate::error::*;
+use crate::naming::Namer;
 use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
 use common::*;
 use std::io::Write;
 
@@ -25,14 +28,16 @@ impl<T: Write> Generator<T> for OverviewGenerator {
     fn generate(
         &self,
         writer: &mut T,
-        module_witx: witx::Module,
+        module_witx: &witx::Module,
         options: &Options,
+        _n: &Namer,
     ) -> Result<(), Error> {
         let mut w = PrettyWriter::new(writer, "    ");
         let module_name = match &self.module_name {
             None => module_witx.name().as_str().to_string(),
             Some(module_name) => module_name.to_string(),
         };
+        let selection = Selection::new(module_witx, &options.filter);
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
@@ -48,18 +53,18 @@ impl<T: Write> Generator<T> for OverviewGenerator {
         w.write_line(module_title_doc)?;
         w.eob()?;
 
-        for type_ in module_witx.typenames() {
+        for type_ in &selection.typenames {
             if skip_imports && &type_.module != module_id {
                 continue;
             }
             let constants_for_type: Vec<_> = module_witx
                 .constants()
-                .into_iter()
                 .filter_map(|x| {
                     if x.ty == type_.name {
                         Some(ASConstant {
                             name: x.name.as_str().to_string(),
                             value: x.value,
+                            docs: x.docs.clone(),
                         })
                     } else {
                         None
@@ -69,7 +74,7 @@ impl<T: Write> Generator<T> for OverviewGenerator {
             Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
         }
 
-        for func in module_witx.funcs() {
+        for func in &selection.funcs {
             Self::define_func(&mut w, &module_name, func.as_ref())?;
         }
 
@@ -120,7 +125,12 @@ impl OverviewGenerator {
         {
             let mut w = w.new_block();
             for choice in &enum_.choices {
-                w.write_line(format!("- {}: {}", choice.name.as_const(), name.as_type(),))?;
+                w.write_line(format!(
+                    "- {}: {}{}",
+                    choice.name.as_const(),
+                    name.as_type(),
+                    summary(&choice.docs)
+                ))?;
             }
         }
         Ok(())
@@ -210,7 +220,12 @@ impl OverviewGenerator {
                 } else {
                     format!("{}", constant.value)
                 };
-                w.write_line(format!("- {} = {}", constant.name.as_const(), value_s))?;
+                w.write_line(format!(
+                    "- {} = {}{}",
+                    constant.name.as_const(),
+                    value_s,
+                    summary(&constant.docs)
+                ))?;
             }
         }
         Ok(())
@@ -237,3 +252,11 @@ impl OverviewGenerator {
         Ok(())
     }
 }
+
+/// Return the first line of `docs`, to be appended to the description of a member.
+fn summary(docs: &str) -> String {
+    match docs.lines().next() {
+        Some(line) if !line.trim().is_empty() => format!(" // {}", line.trim()),
+        _ => "".to_string(),
+    }
+}
@@ -13,9 +13,10 @@ impl OverviewGenerator {
             for member in members {
                 let member_type = member.type_.as_ref();
                 w.write_line(format!(
-                    "- {}: {}",
+                    "- {}: {}{}",
                     member.name.as_var(),
-                    member_type.as_lang()
+                    member_type.as_lang(),
+                    summary(&member.docs)
                 ))?;
             }
         }
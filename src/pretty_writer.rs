@@ -24,6 +24,15 @@ impl<W: Write> Clone for PrettyWriter<W> {
 
 const DEFAULT_CONTINUATION_BYTES: &str = "    ";
 
+/// Return `buf` without trailing spaces and tabs.
+fn trim_end(buf: &[u8]) -> &[u8] {
+    let len = buf
+        .iter()
+        .rposition(|&c| c != b' ' && c != b'\t')
+        .map_or(0, |i| i + 1);
+    &buf[..len]
+}
+
 impl<W: Write> PrettyWriter<W> {
     /// Create a new `PrettyWriter` with `indent` initial units of indentation
     pub fn new_with_indent(writer: W, indent: u32, indent_bytes: &'static str) -> Self {
@@ -117,24 +126,28 @@ impl<W: Write> PrettyWriter<W> {
         Ok(self)
     }
 
-    /// Indent, write raw data and terminate with an end of line
+    /// Indent, write raw data without trailing whitespace and terminate with an end of line
     pub fn write_line<T: AsRef<[u8]>>(&mut self, buf: T) -> Result<&mut Self, Error> {
-        let buf = buf.as_ref();
+        let buf = trim_end(buf.as_ref());
+        if buf.is_empty() {
+            return self.eol();
+        }
         self.indent()?.write(buf)?.eol()
     }
 
     /// Write multiple indented lines
     pub fn write_lines<T: AsRef<[u8]>>(&mut self, buf: T) -> Result<&mut Self, Error> {
         let buf = buf.as_ref();
-        for line in buf.lines().flatten() {
+        for line in buf.lines().map_while(Result::ok) {
             self.write_line(line)?;
         }
         Ok(self)
     }
 
-    /// Indent, write raw data after a continuation and terminate with an end of line
+    /// Indent, write raw data without trailing whitespace after a continuation and terminate
+    /// with an end of line
     pub fn write_line_continued<T: AsRef<[u8]>>(&mut self, buf: T) -> Result<&mut Self, Error> {
-        let buf = buf.as_ref();
+        let buf = trim_end(buf.as_ref());
         self.continuation()?.write(buf)?.eol()
     }
 }
@@ -0,0 +1,150 @@
+use super::tuple::Tuple;
+use crate::astype::*;
+use crate::error::*;
+use crate::naming::{NameKind, Namer};
+use convert_case::Case;
+
+/// Keywords, and names the generated code refers to from the functions it defines
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield", "ctypes", "enum", "impl", "self", "typing", "wasmtime",
+];
+
+pub fn escape_keyword(identifier: String) -> String {
+    if KEYWORDS.contains(&identifier.as_str()) {
+        format!("{}_", identifier)
+    } else {
+        identifier
+    }
+}
+
+#[allow(dead_code)]
+pub trait Normalize {
+    fn as_str(&self) -> &str;
+
+    fn as_type(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Type, self.as_str(), Case::Pascal))
+    }
+
+    fn as_fn(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Fn, self.as_str(), Case::Snake))
+    }
+
+    fn as_var(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Var, self.as_str(), Case::Snake))
+    }
+
+    fn as_const(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Const, self.as_str(), Case::UpperSnake))
+    }
+
+    fn as_namespace(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Namespace, self.as_str(), Case::Pascal))
+    }
+}
+
+impl<T: AsRef<str>> Normalize for T {
+    fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// Follow aliases down to the type they name.
+pub fn resolve(type_: &ASType) -> &ASType {
+    match type_ {
+        ASType::Alias(alias) => resolve(&alias.type_),
+        type_ => type_,
+    }
+}
+
+/// Return `true` if the elements of a buffer of `type_` are bytes, exchanged as `bytes`.
+pub fn is_byte(type_: &ASType) -> bool {
+    matches!(resolve(type_), ASType::U8 | ASType::Char8)
+}
+
+pub trait ToLanguageRepresentation {
+    fn as_astype(&self) -> &ASType;
+
+    /// Return the `ctypes` type of values of this type in guest memory.
+    /// Enumerations and flags are stored as their integer representation.
+    fn as_lang(&self, n: &Namer) -> Result<String, Error> {
+        let lang = match self.as_astype() {
+            ASType::Alias(alias) => match resolve(&alias.type_) {
+                ASType::Enum(enum_) => enum_.repr.as_lang(n)?,
+                ASType::Constants(constants) => constants.repr.as_lang(n)?,
+                ASType::Handle(_) => "WasiHandle".to_string(),
+                _ => alias.name.as_type(n),
+            },
+            ASType::Bool => "ctypes.c_bool".to_string(),
+            ASType::Char32 => "Char32".to_string(),
+            ASType::Char8 => "Char8".to_string(),
+            ASType::F32 => "ctypes.c_float".to_string(),
+            ASType::F64 => "ctypes.c_double".to_string(),
+            ASType::Handle(_) => "WasiHandle".to_string(),
+            ASType::ConstPtr(_) | ASType::MutPtr(_) => "WasiPtr".to_string(),
+            ASType::S8 => "ctypes.c_int8".to_string(),
+            ASType::S16 => "ctypes.c_int16".to_string(),
+            ASType::S32 => "ctypes.c_int32".to_string(),
+            ASType::S64 => "ctypes.c_int64".to_string(),
+            ASType::U8 => "ctypes.c_uint8".to_string(),
+            ASType::U16 => "ctypes.c_uint16".to_string(),
+            ASType::U32 => "ctypes.c_uint32".to_string(),
+            ASType::U64 => "ctypes.c_uint64".to_string(),
+            ASType::USize => "WasiUSize".to_string(),
+            ASType::Void => "None".to_string(),
+            ASType::Constants(constants) => constants.repr.as_lang(n)?,
+            ASType::Enum(enum_) => enum_.repr.as_lang(n)?,
+            ASType::Tuple(tuple_members) => Tuple::name_for(n, tuple_members)?.as_type(n),
+            ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => {
+                "WasiSlice".to_string()
+            }
+            ASType::String(_) => "WasiString".to_string(),
+            ASType::Option(_) | ASType::Result(_) | ASType::Struct(_) | ASType::Union(_) => {
+                return Err(Error::Unsupported(format!(
+                    "{} types must be named to be used in Python bindings",
+                    self.as_astype().kind()
+                )))
+            }
+        };
+        Ok(lang)
+    }
+
+    /// Return the type annotation of values of this type passed to and returned by the host.
+    fn as_hint(&self, n: &Namer) -> Result<String, Error> {
+        let hint = match self.as_astype() {
+            ASType::Alias(alias) => match resolve(&alias.type_) {
+                ASType::Enum(_)
+                | ASType::Constants(_)
+                | ASType::Struct(_)
+                | ASType::Tuple(_)
+                | ASType::Union(_)
+                | ASType::Handle(_) => alias.name.as_type(n),
+                type_ => type_.as_hint(n)?,
+            },
+            ASType::Bool => "bool".to_string(),
+            ASType::F32 | ASType::F64 => "float".to_string(),
+            ASType::Void => "None".to_string(),
+            ASType::String(_) => "str".to_string(),
+            ASType::Slice(element_type) | ASType::ReadBuffer(element_type) => {
+                if is_byte(element_type) {
+                    "bytes".to_string()
+                } else {
+                    "ctypes.Array".to_string()
+                }
+            }
+            ASType::WriteBuffer(_) => "GuestBuffer".to_string(),
+            ASType::Tuple(tuple_members) => Tuple::name_for(n, tuple_members)?.as_type(n),
+            ASType::Handle(resource_name) => resource_name.as_type(n),
+            _ => "int".to_string(),
+        };
+        Ok(hint)
+    }
+}
+
+impl ToLanguageRepresentation for ASType {
+    fn as_astype(&self) -> &ASType {
+        self
+    }
+}
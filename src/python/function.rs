@@ -0,0 +1,284 @@
+use super::*;
+use std::io::Write;
+
+/// Return the `wasmtime.ValType` of a WebAssembly type.
+fn val_type(type_: &witx::WasmType) -> &'static str {
+    match type_ {
+        witx::WasmType::I32 => "wasmtime.ValType.i32()",
+        witx::WasmType::I64 => "wasmtime.ValType.i64()",
+        witx::WasmType::F32 => "wasmtime.ValType.f32()",
+        witx::WasmType::F64 => "wasmtime.ValType.f64()",
+    }
+}
+
+/// Return the values written to result pointers, leaving out the ones with no content.
+fn written_results(signature: &FuncSignature) -> Vec<(&str, &ASType)> {
+    signature
+        .results
+        .iter()
+        .filter(|(_, type_)| !matches!(type_.leaf(), ASType::Void))
+        .map(|(name, type_)| (name.as_str(), type_.as_ref()))
+        .collect()
+}
+
+/// Return the type annotation of a value written to a result pointer. Strings and slices can
+/// only be returned as references to guest memory.
+fn result_hint(n: &Namer, type_: &ASType) -> Result<String, Error> {
+    match resolve(type_) {
+        ASType::String(_) | ASType::Slice(_) | ASType::ReadBuffer(_) | ASType::WriteBuffer(_) => {
+            type_.as_lang(n)
+        }
+        _ => type_.as_hint(n),
+    }
+}
+
+/// Return the expression converting the WebAssembly arguments of a parameter to the value passed
+/// to the host implementation, reading what they point to from `_memory`.
+fn param_value(n: &Namer, name: &str, type_: &ASType) -> Result<String, Error> {
+    let ptr = format!("{}_ptr", name).as_var(n);
+    let len = format!("{}_len", name).as_var(n);
+    let value = match resolve(type_) {
+        ASType::String(_) => format!("_memory.read_string({}, {})", ptr, len),
+        ASType::Slice(element_type) | ASType::ReadBuffer(element_type) => {
+            if is_byte(element_type) {
+                format!("_memory.read({}, {})", ptr, len)
+            } else {
+                format!(
+                    "_memory.read_array({}, {}, {})",
+                    element_type.as_lang(n)?,
+                    ptr,
+                    len
+                )
+            }
+        }
+        ASType::WriteBuffer(element_type) => {
+            if is_byte(element_type) {
+                format!("GuestBuffer(_memory, {}, {})", ptr, len)
+            } else {
+                format!(
+                    "GuestBuffer(_memory, {}, {} * ctypes.sizeof({}))",
+                    ptr,
+                    len,
+                    element_type.as_lang(n)?
+                )
+            }
+        }
+        ASType::Struct(_) | ASType::Tuple(_) | ASType::Union(_) => {
+            format!(
+                "_memory.read_value({}, {})",
+                type_.as_lang(n)?,
+                name.as_var(n)
+            )
+        }
+        ASType::Enum(_) | ASType::Constants(_) | ASType::Handle(_)
+            if matches!(type_, ASType::Alias(_)) =>
+        {
+            format!("{}({})", type_.as_hint(n)?, name.as_var(n))
+        }
+        ASType::Bool => format!("bool({})", name.as_var(n)),
+        _ => name.as_var(n),
+    };
+    Ok(value)
+}
+
+impl PythonHostGenerator {
+    /// Define a class with a method for every function imported by the guest, to be implemented
+    /// by the host, and the function registering an implementation with a `wasmtime.Linker`.
+    pub fn define_host<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        selection: &Selection,
+    ) -> Result<(), Error> {
+        let funcs: Vec<_> = selection
+            .funcs
+            .iter()
+            .filter(|func| !is_export(func))
+            .collect();
+        if funcs.is_empty() {
+            return Ok(());
+        }
+        let host_name = format!("{}_host", module_name).as_type(n);
+        let define_name = format!("define_{}_imports", module_name).as_fn(n);
+
+        w.write_line(format!("class {}:", host_name))?;
+        {
+            let mut w = w.new_block();
+            Self::write_docs(
+                &mut w,
+                &format!(
+                    "Functions of the `{}` module, implemented by the host.
+
+Override them in a subclass, and register an instance with `{}()`.
+Methods receive the memory of the calling instance first.
+Functions returning an error code raise `WasiError` to return it.",
+                    module_name, define_name
+                ),
+            )?;
+            for func in &funcs {
+                w.eob()?;
+                Self::define_host_method(&mut w, n, func)?;
+            }
+        }
+        w.eob()?.eob()?;
+
+        w.write_line(format!(
+            "def {}(linker: wasmtime.Linker, store: wasmtime.Store, impl: {}) -> None:",
+            define_name, host_name
+        ))?;
+        {
+            let mut w = w.new_block();
+            Self::write_docs(
+                &mut w,
+                &format!(
+                    "Define the functions of the `{}` module in `linker`, implemented by `impl`.",
+                    module_name
+                ),
+            )?;
+            for func in &funcs {
+                w.eob()?;
+                Self::define_func(&mut w, n, module_name, func)?;
+            }
+        }
+        w.eob()?;
+        Ok(())
+    }
+
+    fn define_host_method<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let signature = FuncSignature::new(func_witx)?;
+        let mut params = vec!["self".to_string(), "memory: GuestMemory".to_string()];
+        for (name, type_) in &signature.params {
+            params.push(format!("{}: {}", name.as_var(n), type_.as_hint(n)?));
+        }
+        let result_hint = match &signature.returns {
+            FuncReturn::Expected(_) => {
+                let results = written_results(&signature);
+                match results.len() {
+                    0 => "None".to_string(),
+                    1 => result_hint(n, results[0].1)?,
+                    _ => format!(
+                        "tuple[{}]",
+                        results
+                            .iter()
+                            .map(|(_, type_)| result_hint(n, type_))
+                            .collect::<Result<Vec<_>, _>>()?
+                            .join(", ")
+                    ),
+                }
+            }
+            FuncReturn::Value(type_) => type_.as_hint(n)?,
+            FuncReturn::Nothing => "None".to_string(),
+            FuncReturn::Never => "typing.NoReturn".to_string(),
+        };
+        w.write_line(format!(
+            "def {}({}) -> {}:",
+            signature.name.as_fn(n),
+            params.join(", "),
+            result_hint
+        ))?;
+        {
+            let mut w = w.new_block();
+            Self::write_docs(&mut w, &func_docs(func_witx, |name| name.as_var(n)))?;
+            w.write_line(format!("raise NotImplementedError({:?})", signature.name))?;
+        }
+        Ok(())
+    }
+
+    /// Define the function called by the guest, converting its arguments for the host
+    /// implementation and writing the results to the result pointers, and add it to `linker`.
+    fn define_func<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        func_witx: &witx::Function,
+    ) -> Result<(), Error> {
+        let signature = FuncSignature::new(func_witx)?;
+        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
+        let callback_name = format!("_{}", signature.name.as_fn(n));
+        let params = std::iter::once("_caller".to_string())
+            .chain(
+                signature
+                    .params_decomposed
+                    .iter()
+                    .chain(signature.results_decomposed.iter())
+                    .map(|param| param.name.as_var(n)),
+            )
+            .collect::<Vec<_>>();
+        let mut args = vec!["_memory".to_string()];
+        for (name, type_) in &signature.params {
+            args.push(param_value(n, name, type_)?);
+        }
+        let call_str = format!("impl.{}({})", signature.name.as_fn(n), args.join(", "));
+
+        w.write_line(format!("def {}({}):", callback_name, params.join(", ")))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("_memory = GuestMemory(_caller)")?;
+            match &signature.returns {
+                FuncReturn::Expected(_) => {
+                    let results = written_results(&signature);
+                    w.write_line("try:")?;
+                    match results.len() {
+                        0 => w.new_block().write_line(&call_str)?,
+                        _ => w
+                            .new_block()
+                            .write_line(format!("_results = {}", call_str))?,
+                    };
+                    w.write_line("except WasiError as _error:")?;
+                    w.new_block().write_line("return int(_error.code)")?;
+                    for (i, (name, type_)) in results.iter().enumerate() {
+                        let value = match results.len() {
+                            1 => "_results".to_string(),
+                            _ => format!("_results[{}]", i),
+                        };
+                        w.write_line(format!(
+                            "_memory.write_value({}, {}, {})",
+                            type_.as_lang(n)?,
+                            name.as_var(n),
+                            value
+                        ))?;
+                    }
+                    w.write_line("return 0")?;
+                }
+                FuncReturn::Value(_) => {
+                    w.write_line(format!("return {}", call_str))?;
+                }
+                FuncReturn::Nothing => {
+                    w.write_line(&call_str)?;
+                }
+                FuncReturn::Never => {
+                    w.write_line(&call_str)?;
+                    w.write_line(format!(
+                        "raise RuntimeError(\"{} returned\")",
+                        signature.name
+                    ))?;
+                }
+            }
+        }
+        w.eob()?;
+
+        let val_types =
+            |types: &[witx::WasmType]| types.iter().map(val_type).collect::<Vec<_>>().join(", ");
+        w.write_line(format!(
+            "linker.define(store, {:?}, {:?}, wasmtime.Func(",
+            module_name, signature.name
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("store,")?;
+            w.write_line(format!(
+                "wasmtime.FuncType([{}], [{}]),",
+                val_types(&signature_witx.params),
+                val_types(&signature_witx.results)
+            ))?;
+            w.write_line(format!("{},", callback_name))?;
+            w.write_line("access_caller=True,")?;
+        }
+        w.write_line("))")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use super::*;
+use std::io::Write;
+
+impl PythonHostGenerator {
+    /// Types defined by the header.
+    pub const PRELUDE_TYPES: &'static [&'static str] = &[
+        "Char32",
+        "Char8",
+        "GuestBuffer",
+        "GuestMemory",
+        "WasiError",
+        "WasiHandle",
+        "WasiPtr",
+        "WasiSlice",
+        "WasiString",
+        "WasiUSize",
+    ];
+
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
+        w.write_lines(
+            "#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+",
+        )?;
+        w.eob()?.eob()?;
+        w.write_lines(
+            "class WasiString(ctypes.Structure):
+    \"\"\"A string in guest memory.\"\"\"
+
+    _pack_ = 1
+    _fields_ = [(\"ptr\", WasiPtr), (\"len\", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    \"\"\"A slice in guest memory, `len` being its number of elements.\"\"\"
+
+    _pack_ = 1
+    _fields_ = [(\"ptr\", WasiPtr), (\"len\", WasiUSize)]
+
+
+class WasiError(Exception):
+    \"\"\"Raised by a host function to return the error code `code` to the guest.\"\"\"
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    \"\"\"Memory exported by the guest instance calling a host function.\"\"\"
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get(\"memory\")
+            if self._memory is None:
+                raise RuntimeError(\"The guest doesn't export its memory\")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        \"\"\"Return the `length` bytes at address `ptr`.\"\"\"
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        \"\"\"Write `data` at address `ptr`.\"\"\"
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        \"\"\"Return the UTF-8 string of `length` bytes at address `ptr`.\"\"\"
+        return self.read(ptr, length).decode(\"utf-8\")
+
+    def read_value(self, ctype, ptr: int):
+        \"\"\"Return a copy of the value of type `ctype` at address `ptr`.\"\"\"
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        \"\"\"Return a copy of the `length` values of type `ctype` at address `ptr`.\"\"\"
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        \"\"\"Write `value`, converted to `ctype` if necessary, at address `ptr`.\"\"\"
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    \"\"\"A buffer of `size` bytes in guest memory, to be filled by a host function.\"\"\"
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        \"\"\"Write `data` to the buffer, `offset` bytes after its start.\"\"\"
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError(\"The data doesn't fit in the guest buffer\")
+        self.memory.write(self.ptr + offset, data)
+",
+        )?;
+        w.eob()?.eob()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,357 @@
+mod common;
+mod function;
+mod header;
+mod r#struct;
+mod tuple;
+mod union;
+
+use super::*;
+use crate::annotations::strip_annotations;
+use crate::astype::*;
+use crate::error::*;
+use crate::handles::resources_to_define;
+use crate::naming::Namer;
+use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
+use common::*;
+use std::io::Write;
+
+pub struct PythonHostGenerator {
+    module_name: Option<String>,
+}
+
+impl PythonHostGenerator {
+    pub fn new(module_name: Option<String>) -> Self {
+        PythonHostGenerator { module_name }
+    }
+}
+
+impl<T: Write> Generator<T> for PythonHostGenerator {
+    fn prelude_types(&self) -> &'static [&'static str] {
+        Self::PRELUDE_TYPES
+    }
+
+    fn generate(
+        &self,
+        writer: &mut T,
+        module_witx: &witx::Module,
+        options: &Options,
+        n: &Namer,
+    ) -> Result<(), Error> {
+        let mut w = PrettyWriter::new(writer, "    ");
+        let module_name = match &self.module_name {
+            None => module_witx.name().as_str().to_string(),
+            Some(module_name) => module_name.to_string(),
+        };
+        let selection = Selection::new(module_witx, &options.filter);
+        let module_id = module_witx.module_id();
+        let skip_imports = options.skip_imports;
+
+        if !options.skip_header {
+            Self::header(&mut w)?;
+        }
+
+        let module_title_comments = format!(
+            "---------------------- Module: [{}] ----------------------",
+            module_name
+        );
+        Self::write_comments(&mut w, &module_title_comments)?;
+        w.eob()?;
+
+        for resource in resources_to_define(module_witx, &selection) {
+            if skip_imports && &resource.resource_id.module_id != module_id {
+                continue;
+            }
+            let resource_name = resource.resource_id.name.as_str();
+            Self::write_comments(&mut w, &resource.docs)?;
+            Self::define_resource(&mut w, n, resource_name)?;
+            w.eob()?.eob()?;
+        }
+
+        for type_ in &selection.typenames {
+            if skip_imports && &type_.module != module_id {
+                continue;
+            }
+            let constants_for_type: Vec<_> = module_witx
+                .constants()
+                .filter_map(|x| {
+                    if x.ty == type_.name {
+                        Some(ASConstant {
+                            name: x.name.as_str().to_string(),
+                            value: x.value,
+                            docs: x.docs.clone(),
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            Self::define_type(&mut w, n, type_.as_ref(), &constants_for_type)?;
+        }
+
+        Self::define_host(&mut w, n, &module_name, &selection)?;
+
+        Ok(())
+    }
+}
+
+impl PythonHostGenerator {
+    /// Write a docstring. Backslashes are escaped, so that documentation is written verbatim.
+    fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
+        let docs = docs
+            .trim()
+            .replace('\\', "\\\\")
+            .replace("\"\"\"", "\\\"\\\"\\\"");
+        let mut lines = docs.lines();
+        let first_line = match lines.next() {
+            None => return Ok(()),
+            Some(first_line) => first_line,
+        };
+        if docs.lines().count() == 1 {
+            w.write_line(format!("\"\"\"{}\"\"\"", first_line))?;
+            return Ok(());
+        }
+        w.write_line(format!("\"\"\"{}", first_line))?;
+        for docs_line in lines {
+            match docs_line.trim_end() {
+                "" => w.eol()?,
+                docs_line => w.write_line(docs_line)?,
+            };
+        }
+        w.write_line("\"\"\"")?;
+        Ok(())
+    }
+
+    fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
+        if docs.is_empty() {
+            return Ok(());
+        }
+        for docs_line in docs.lines() {
+            match docs_line.trim_end() {
+                "" => w.write_line("#")?,
+                docs_line => w.write_line(format!("# {}", docs_line))?,
+            };
+        }
+        Ok(())
+    }
+
+    fn define_as_alias<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        other_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!("{} = {}", name.as_type(n), other_name.as_type(n)))?;
+        Ok(())
+    }
+
+    fn define_as_atom<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        type_: &ASType,
+    ) -> Result<(), Error> {
+        w.write_line(format!("{} = {}", name.as_type(n), type_.as_lang(n)?))?;
+        Ok(())
+    }
+
+    fn define_resource<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        resource_name: &str,
+    ) -> Result<(), Error> {
+        // Handles are stored as `WasiHandle`, the type only distinguishes them for type checkers
+        w.write_line(format!(
+            "{} = typing.NewType({:?}, int)",
+            resource_name.as_type(n),
+            resource_name.as_type(n)
+        ))?;
+        Ok(())
+    }
+
+    fn define_as_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        enum_: &ASEnum,
+        docs: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!("class {}(enum.IntEnum):", name.as_type(n)))?;
+        {
+            let mut w = w.new_block();
+            if !docs.is_empty() {
+                Self::write_docs(&mut w, docs)?;
+                w.eob()?;
+            }
+            for choice in &enum_.choices {
+                Self::write_comments(&mut w, &choice.docs)?;
+                w.write_line(format!("{} = {}", choice.name.as_const(n), choice.value))?;
+            }
+            if enum_.choices.is_empty() {
+                w.write_line("pass")?;
+            }
+        }
+        w.eob()?.eob()?;
+        Ok(())
+    }
+
+    fn define_as_constants<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        constants: &ASConstants,
+        docs: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!("class {}(enum.IntFlag):", name.as_type(n)))?;
+        {
+            let mut w = w.new_block();
+            if !docs.is_empty() {
+                Self::write_docs(&mut w, docs)?;
+                w.eob()?;
+            }
+            for (constant, value) in constants
+                .constants
+                .iter()
+                .zip(constant_values(&constants.constants))
+            {
+                Self::write_comments(&mut w, &constant.docs)?;
+                w.write_line(format!("{} = {}", constant.name.as_const(n), value))?;
+            }
+            if constants.constants.is_empty() {
+                w.write_line("pass")?;
+            }
+        }
+        w.eob()?.eob()?;
+        Ok(())
+    }
+
+    fn define_as_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        type_: &ASType,
+        docs: &str,
+    ) -> Result<(), Error> {
+        // Classes are documented with a docstring, other definitions with comments
+        match type_ {
+            ASType::Enum(enum_) => return Self::define_as_enum(w, n, name, enum_, docs),
+            ASType::Union(union_) => return Self::define_as_union(w, n, name, union_, docs),
+            ASType::Constants(constants) => {
+                return Self::define_as_constants(w, n, name, constants, docs)
+            }
+            ASType::Tuple(members) => return Self::define_as_tuple(w, n, name, members, docs),
+            ASType::Struct(members) => return Self::define_as_struct(w, n, name, members, docs),
+            _ => {}
+        }
+        Self::write_comments(w, docs)?;
+        match type_ {
+            ASType::Alias(_)
+            | ASType::Bool
+            | ASType::Char8
+            | ASType::Char32
+            | ASType::F32
+            | ASType::F64
+            | ASType::U8
+            | ASType::U16
+            | ASType::U32
+            | ASType::U64
+            | ASType::S8
+            | ASType::S16
+            | ASType::S32
+            | ASType::S64
+            | ASType::USize
+            | ASType::ConstPtr(_)
+            | ASType::MutPtr(_)
+            | ASType::Slice(_)
+            | ASType::String(_)
+            | ASType::ReadBuffer(_)
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, n, name, type_)?,
+            ASType::Handle(_) => Self::define_resource(w, n, name)?,
+            _ => {
+                return Err(Error::Unsupported(format!(
+                    "type [{}] is a {}, that can't be defined in Python bindings",
+                    name,
+                    type_.kind()
+                )))
+            }
+        }
+        w.eob()?.eob()?;
+        Ok(())
+    }
+
+    fn define_constants_for_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        if constants.is_empty() {
+            return Ok(());
+        }
+        for (constant, value) in constants.iter().zip(constant_values(constants)) {
+            Self::write_comments(w, &constant.docs)?;
+            w.write_line(format!(
+                "{} = {}",
+                format!("{}_{}", type_name, constant.name).as_const(n),
+                value
+            ))?;
+        }
+        w.eob()?.eob()?;
+        Ok(())
+    }
+
+    fn define_type<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_witx: &witx::NamedType,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let docs = &type_witx.docs;
+        let type_name = type_witx.name.as_str();
+        let tref = &type_witx.tref;
+        match tref {
+            witx::TypeRef::Name(other_type) => {
+                Self::write_comments(w, docs)?;
+                Self::define_as_alias(w, n, type_name, other_type.name.as_str())?;
+                w.eob()?.eob()?;
+            }
+            witx::TypeRef::Value(type_witx) => {
+                let t = ASType::from(type_witx.as_ref());
+                Self::define_as_type(w, n, type_name, &t, docs)?
+            }
+        }
+        Self::define_constants_for_type(w, n, type_name, constants)?;
+        Ok(())
+    }
+}
+
+/// Return the values of the constants, in hexadecimal if they are large, or if they are all
+/// single bits.
+fn constant_values(constants: &[ASConstant]) -> Vec<String> {
+    let mut hex = false;
+    let mut single_bits: usize = 0;
+    for constant in constants {
+        if constant.value > 0xffff {
+            hex = true;
+        }
+        if constant.value.count_ones() == 1 {
+            single_bits += 1;
+        }
+    }
+    if constants.len() > 2 && single_bits == constants.len() {
+        hex = true;
+    }
+    constants
+        .iter()
+        .map(|constant| {
+            if hex {
+                format!("0x{:x}", constant.value)
+            } else {
+                format!("{}", constant.value)
+            }
+        })
+        .collect()
+}
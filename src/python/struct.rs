@@ -0,0 +1,86 @@
+use super::*;
+use std::io::Write;
+
+impl PythonHostGenerator {
+    /// Define a `ctypes.Structure` with the given fields, each one a name, a `ctypes` type and
+    /// the number of padding bytes after it. Structures are packed, with explicit padding fields,
+    /// so that their layout matches the one computed from the WITX definition on any host.
+    pub fn define_structure<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        docs: &str,
+        fields: &[(String, String, usize)],
+    ) -> Result<(), Error> {
+        w.write_line(format!("class {}(ctypes.Structure):", name.as_type(n)))?;
+        {
+            let mut w = w.new_block();
+            if !docs.is_empty() {
+                Self::write_docs(&mut w, docs)?;
+                w.eob()?;
+            }
+            w.write_line("_pack_ = 1")?;
+            if fields.is_empty() {
+                w.write_line("_fields_ = []")?;
+            } else {
+                w.write_line("_fields_ = [")?;
+                {
+                    let mut w = w.new_block();
+                    for (i, (field_name, field_type, padding)) in fields.iter().enumerate() {
+                        w.write_line(format!("(\"{}\", {}),", field_name, field_type))?;
+                        if *padding > 0 {
+                            w.write_line(format!(
+                                "(\"_pad{}\", ctypes.c_uint8 * {}),",
+                                i, padding
+                            ))?;
+                        }
+                    }
+                }
+                w.write_line("]")?;
+            }
+        }
+        w.eob()?.eob()?;
+        Ok(())
+    }
+
+    pub fn define_as_struct<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        members: &[ASStructMember],
+        docs: &str,
+    ) -> Result<(), Error> {
+        let mut docs = docs.to_string();
+        let documented: Vec<_> = members
+            .iter()
+            .filter(|member| !member.docs.is_empty())
+            .collect();
+        if !documented.is_empty() {
+            docs.truncate(docs.trim_end().len());
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            docs.push_str("Fields:");
+            for member in documented {
+                for (i, line) in member.docs.lines().enumerate() {
+                    if i == 0 {
+                        docs.push_str(&format!("\n* `{}`: {}", member.name.as_var(n), line));
+                    } else {
+                        docs.push_str(&format!("\n  {}", line));
+                    }
+                }
+            }
+        }
+        let fields: Vec<_> = members
+            .iter()
+            .map(|member| {
+                Ok((
+                    member.name.as_var(n),
+                    member.type_.as_lang(n)?,
+                    member.padding,
+                ))
+            })
+            .collect::<Result<_, Error>>()?;
+        Self::define_structure(w, n, name, &docs, &fields)
+    }
+}
@@ -0,0 +1,35 @@
+use super::*;
+use std::io::Write;
+
+pub struct Tuple;
+
+impl Tuple {
+    pub fn name_for(n: &Namer, tuple_members: &[ASTupleMember]) -> Result<String, Error> {
+        let member_types = tuple_members
+            .iter()
+            .map(|member| Ok(member.type_.as_lang(n)?.replace("ctypes.c_", "")))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(format!(
+            "WasiTuple{}{}",
+            tuple_members.len(),
+            member_types.join("_")
+        ))
+    }
+}
+
+impl PythonHostGenerator {
+    pub fn define_as_tuple<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        members: &[ASTupleMember],
+        docs: &str,
+    ) -> Result<(), Error> {
+        let fields: Vec<_> = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| Ok((format!("v{}", i), member.type_.as_lang(n)?, member.padding)))
+            .collect::<Result<_, Error>>()?;
+        Self::define_structure(w, n, name, docs, &fields)
+    }
+}
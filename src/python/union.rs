@@ -0,0 +1,112 @@
+use super::*;
+use std::io::Write;
+
+impl PythonHostGenerator {
+    pub fn define_as_union<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        union_: &ASUnion,
+        docs: &str,
+    ) -> Result<(), Error> {
+        let union_name = name.as_type(n);
+        w.write_line(format!("class {}(ctypes.Structure):", union_name))?;
+        {
+            let mut w = w.new_block();
+            if !docs.is_empty() {
+                Self::write_docs(&mut w, docs)?;
+                w.eob()?;
+            }
+
+            // The last member gives the union the size of the largest case, trailing padding included
+            w.write_line("class _Member(ctypes.Union):")?;
+            {
+                let mut w = w.new_block();
+                w.write_line("_pack_ = 1")?;
+                w.write_line("_fields_ = [")?;
+                {
+                    let mut w = w.new_block();
+                    for member in &union_.members {
+                        if !matches!(member.type_.as_ref(), ASType::Void) {
+                            w.write_line(format!(
+                                "(\"{}\", {}),",
+                                member.name.as_var(n),
+                                member.type_.as_lang(n)?
+                            ))?;
+                        }
+                    }
+                    w.write_line(format!(
+                        "(\"_size\", ctypes.c_uint8 * {}),",
+                        union_.max_member_size
+                    ))?;
+                }
+                w.write_line("]")?;
+            }
+            w.eob()?;
+
+            w.write_line("_pack_ = 1")?;
+            w.write_line("_fields_ = [")?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("(\"tag\", {}),", union_.tag_repr.as_lang(n)?))?;
+                if union_.padding_after_tag > 0 {
+                    w.write_line(format!(
+                        "(\"_pad0\", ctypes.c_uint8 * {}),",
+                        union_.padding_after_tag
+                    ))?;
+                }
+                w.write_line("(\"member\", _Member),")?;
+            }
+            w.write_line("]")?;
+            w.eob()?;
+
+            for (i, member) in union_.members.iter().enumerate() {
+                Self::write_comments(&mut w, &member.docs)?;
+                w.write_line(format!("{} = {}", member.name.as_const(n), i))?;
+            }
+
+            for member in &union_.members {
+                w.eob()?;
+                Self::define_union_constructor(&mut w, n, &union_name, member)?;
+            }
+        }
+        w.eob()?.eob()?;
+        Ok(())
+    }
+
+    fn define_union_constructor<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        union_name: &str,
+        member: &ASUnionMember,
+    ) -> Result<(), Error> {
+        let member_is_void = matches!(member.type_.as_ref(), ASType::Void);
+        w.write_line("@classmethod")?;
+        if member_is_void {
+            w.write_line(format!(
+                "def new_{}(cls) -> {}:",
+                member.name.as_var(n),
+                union_name
+            ))?;
+        } else {
+            w.write_line(format!(
+                "def new_{}(cls, value) -> {}:",
+                member.name.as_var(n),
+                union_name
+            ))?;
+        }
+        {
+            let mut w = w.new_block();
+            w.write_line(format!(
+                "\"\"\"Return a `{}` with the `{}` case.\"\"\"",
+                union_name, member.name
+            ))?;
+            w.write_line(format!("union = cls(tag=cls.{})", member.name.as_const(n)))?;
+            if !member_is_void {
+                w.write_line(format!("union.member.{} = value", member.name.as_var(n)))?;
+            }
+            w.write_line("return union")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use super::*;
+use crate::naming::{NameKind, Namer, Naming};
+use std::fs::{self, File};
+use std::path::Path;
+
+const GENERATED_COMMENT: &str = "//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+";
+
+/// Name of the module containing the types shared by all the modules of a crate.
+const PRELUDE_MODULE: &str = "prelude";
+
+/// Return the names of the modules, among `modules`, that define the types and resources
+/// imported by `module_witx`.
+fn module_dependencies(module_witx: &witx::Module, modules: &[witx::Module]) -> Vec<String> {
+    let module_id = module_witx.module_id();
+    let imported_types = module_witx
+        .typenames()
+        .filter(|type_| &type_.module != module_id)
+        .map(|type_| type_.name.clone());
+    let imported_resources = module_witx
+        .resources()
+        .filter(|resource| &resource.resource_id.module_id != module_id)
+        .map(|resource| resource.resource_id.name.clone());
+    let mut dependencies = vec![];
+    for name in imported_types.chain(imported_resources) {
+        let defined_in = modules.iter().find(|other| {
+            other.name() != module_witx.name()
+                && (other
+                    .typename(&name)
+                    .is_some_and(|type_| &type_.module == other.module_id())
+                    || other.resources().any(|resource| {
+                        resource.resource_id.name == name
+                            && &resource.resource_id.module_id == other.module_id()
+                    }))
+        });
+        if let Some(other) = defined_in {
+            let other_name = other.name().as_str().to_string();
+            if !dependencies.contains(&other_name) {
+                dependencies.push(other_name);
+            }
+        }
+    }
+    dependencies.sort();
+    dependencies
+}
+
+/// Return `true` if Cargo accepts `name` as the name of a crate.
+fn is_valid_crate_name(name: &str) -> bool {
+    !name.starts_with(|c: char| c.is_ascii_digit())
+        && !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+impl RustGenerator {
+    /// Write a crate to `dir`, with a module for every WITX module, enabled by a feature of the
+    /// same name, and a module for the types they share.
+    pub fn emit_crate(
+        dir: &Path,
+        modules: &[witx::Module],
+        module_name: Option<String>,
+        naming: &Naming,
+        options: Options,
+    ) -> Result<(), Error> {
+        let crate_name = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| {
+                Error::Usage(format!(
+                    "{}: the crate name can't be derived from this directory",
+                    dir.display()
+                ))
+            })?;
+        if !is_valid_crate_name(crate_name) {
+            return Err(Error::Usage(format!(
+                "{}: [{}] is not a valid crate name, that must only contain letters, digits, `_` and `-`, and not start with a digit",
+                dir.display(),
+                crate_name
+            )));
+        }
+        let mut modules_dependencies = modules
+            .iter()
+            .map(|module_witx| {
+                (
+                    module_witx.name().as_str().to_string(),
+                    module_dependencies(module_witx, modules),
+                )
+            })
+            .collect::<Vec<_>>();
+        modules_dependencies.sort();
+
+        let src_dir = dir.join("src");
+        fs::create_dir_all(&src_dir)?;
+
+        let mut w = PrettyWriter::new(File::create(dir.join("Cargo.toml"))?, "    ");
+        w.write_line("[package]")?;
+        w.write_line(format!("name = \"{}\"", crate_name))?;
+        w.write_line("version = \"0.1.0\"")?;
+        w.write_line("edition = \"2018\"")?;
+        w.eob()?;
+        if options.serde || options.arbitrary {
+            w.write_line("[dependencies]")?;
+            if options.arbitrary {
+                w.write_line("arbitrary = { version = \"1\", optional = true }")?;
+            }
+            if options.serde {
+                w.write_line(
+                    "serde = { version = \"1\", default-features = false, features = [\"derive\"], optional = true }",
+                )?;
+            }
+            w.eob()?;
+        }
+        w.write_line("[features]")?;
+        w.write_line(format!(
+            "default = [{}]",
+            modules_dependencies
+                .iter()
+                .map(|(name, _)| format!("\"{}\"", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))?;
+        for (name, dependencies) in &modules_dependencies {
+            w.write_line(format!(
+                "{} = [{}]",
+                name,
+                dependencies
+                    .iter()
+                    .map(|dependency| format!("\"{}\"", dependency))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))?;
+        }
+        if options.no_std {
+            // Enables the helpers of the prelude returning owned values
+            w.write_line("alloc = []")?;
+        }
+
+        let mut w = PrettyWriter::new(File::create(src_dir.join("lib.rs"))?, "    ");
+        w.write_lines(GENERATED_COMMENT)?;
+        w.eob()?;
+        if options.no_std {
+            w.write_line("#![no_std]")?;
+            w.eob()?;
+        }
+        w.write_line(format!("pub mod {};", PRELUDE_MODULE))?;
+        for (name, _) in &modules_dependencies {
+            w.eob()?;
+            w.write_line(format!("#[cfg(feature = \"{}\")]", name))?;
+            w.write_line(format!("pub mod {};", name))?;
+        }
+
+        let mut w = PrettyWriter::new(
+            File::create(src_dir.join(PRELUDE_MODULE).with_extension("rs"))?,
+            "    ",
+        );
+        Self::header(&mut w, options.no_std)?;
+
+        for module_witx in modules {
+            let name = module_witx.name().as_str();
+            let dependencies = module_dependencies(module_witx, modules);
+            let mut file = File::create(src_dir.join(name).with_extension("rs"))?;
+            {
+                let mut w = PrettyWriter::new(&mut file, "    ");
+                w.write_lines(GENERATED_COMMENT)?;
+                w.eob()?;
+                let used_modules =
+                    std::iter::once(PRELUDE_MODULE).chain(dependencies.iter().map(String::as_str));
+                for used_module in used_modules {
+                    w.write_line("#[allow(unused_imports)]")?;
+                    w.write_line(format!("use crate::{}::*;", used_module))?;
+                }
+                w.eob()?;
+            }
+
+            let options = Options {
+                skip_imports: true,
+                skip_header: true,
+                no_std: options.no_std,
+                serde: options.serde,
+                arbitrary: options.arbitrary,
+                filter: options.filter.clone(),
+            };
+            // Modules are generated separately, so their names can't collide
+            let n = Namer::new(naming.clone());
+            n.reserve(NameKind::Type, RustGenerator::PRELUDE_TYPES);
+            let generator = RustGenerator {
+                module_name: module_name.clone(),
+                bindings_paths: std::iter::once(PRELUDE_MODULE)
+                    .chain(dependencies.iter().map(String::as_str))
+                    .chain(std::iter::once(name))
+                    .map(|module| format!("$crate::{}", module))
+                    .collect(),
+            };
+            generator.generate(&mut file, module_witx, &options, &n)?;
+            n.check()?;
+        }
+        Ok(())
+    }
+}
@@ -1,52 +1,61 @@
 use super::tuple::Tuple;
 use crate::astype::*;
-use convert_case::{Case, Casing};
+use crate::error::*;
+use crate::layout::{align_to, size_align};
+use crate::naming::{to_case, NameKind, Namer};
+use convert_case::Case;
 
-pub trait IsNullable {
-    fn is_nullable(&self) -> bool;
-}
+/// Reserved words, that can be used as identifiers only as raw identifiers
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "union", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+/// Reserved words that cannot be raw identifiers
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
 
-impl IsNullable for ASType {
-    fn is_nullable(&self) -> bool {
-        matches!(
-            self,
-            ASType::ConstPtr(_)
-                | ASType::MutPtr(_)
-                | ASType::ReadBuffer(_)
-                | ASType::WriteBuffer(_)
-                | ASType::Enum(_)
-                | ASType::Struct(_)
-                | ASType::Tuple(_)
-                | ASType::Union(_)
-        )
+fn escape_keyword(identifier: String) -> String {
+    if KEYWORDS.contains(&identifier.as_str()) {
+        format!("r#{}", identifier)
+    } else if NON_RAW_KEYWORDS.contains(&identifier.as_str()) {
+        format!("{}_", identifier)
+    } else {
+        identifier
     }
 }
 
 pub trait Normalize {
     fn as_str(&self) -> &str;
 
-    fn as_type(&self) -> String {
-        self.as_str().to_case(Case::Pascal)
+    fn as_type(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Type, self.as_str(), Case::Pascal))
     }
 
-    fn as_fn(&self) -> String {
-        self.as_str().to_case(Case::Snake)
+    fn as_fn(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Fn, self.as_str(), Case::Snake))
     }
 
     fn as_fn_suffix(&self) -> String {
-        self.as_str().to_case(Case::Snake)
+        to_case(self.as_str(), Case::Snake)
     }
 
-    fn as_var(&self) -> String {
-        self.as_str().to_case(Case::Snake)
+    fn as_variant(&self) -> String {
+        escape_keyword(to_case(self.as_str(), Case::Pascal))
     }
 
-    fn as_const(&self) -> String {
-        self.as_str().to_case(Case::UpperSnake)
+    fn as_var(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Var, self.as_str(), Case::Snake))
     }
 
-    fn as_namespace(&self) -> String {
-        self.as_str().to_string().to_case(Case::UpperSnake)
+    fn as_const(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Const, self.as_str(), Case::UpperSnake))
+    }
+
+    fn as_namespace(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Namespace, self.as_str(), Case::UpperSnake))
     }
 }
 
@@ -59,21 +68,17 @@ impl<T: AsRef<str>> Normalize for T {
 pub trait ToLanguageRepresentation {
     fn as_astype(&self) -> &ASType;
 
-    fn to_string(&self) -> String {
-        self.as_lang()
-    }
-
-    fn as_lang(&self) -> String {
+    fn as_lang(&self, n: &Namer) -> String {
         match self.as_astype() {
-            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Alias(alias) => alias.name.as_type(n),
             ASType::Bool => "bool".to_string(),
             ASType::Char32 => "Char32".to_string(),
             ASType::Char8 => "Char8".to_string(),
             ASType::F32 => "f32".to_string(),
             ASType::F64 => "f64".to_string(),
-            ASType::Handle(_resource_name) => "WasiHandle".to_string(),
-            ASType::ConstPtr(pointee) => format!("WasiPtr<{}>", pointee.to_string()),
-            ASType::MutPtr(pointee) => format!("WasiMutPtr<{}>", pointee.to_string()),
+            ASType::Handle(resource_name) => resource_name.as_type(n),
+            ASType::ConstPtr(pointee) => format!("WasiPtr<{}>", pointee.as_lang(n)),
+            ASType::MutPtr(pointee) => format!("WasiMutPtr<{}>", pointee.as_lang(n)),
             ASType::Option(_) => todo!(),
             ASType::Result(_) => todo!(),
             ASType::S8 => "i8".to_string(),
@@ -88,16 +93,16 @@ pub trait ToLanguageRepresentation {
             ASType::Void => "()".to_string(),
             ASType::Constants(_) => unimplemented!(),
             ASType::Enum(enum_) => {
-                format!("{} /* Enum */", enum_.repr.as_ref().as_lang())
+                format!("{} /* Enum */", enum_.repr.as_ref().as_lang(n))
             }
             ASType::Struct(_) => unimplemented!(),
-            ASType::Tuple(tuple_members) => Tuple::name_for(tuple_members).as_type(),
+            ASType::Tuple(tuple_members) => Tuple::name_for(n, tuple_members).as_type(n),
             ASType::Union(_) => unimplemented!(),
-            ASType::Slice(element_type) => format!("WasiMutSlice<{}>", element_type.as_lang()),
+            ASType::Slice(element_type) => format!("WasiMutSlice<{}>", element_type.as_lang(n)),
             ASType::String(_) => "WasiString".to_string(),
-            ASType::ReadBuffer(element_type) => format!("WasiSlice<{}>", element_type.as_lang()),
+            ASType::ReadBuffer(element_type) => format!("WasiSlice<{}>", element_type.as_lang(n)),
             ASType::WriteBuffer(element_type) => {
-                format!("WasiMutSlice<{}>", element_type.to_string())
+                format!("WasiMutSlice<{}>", element_type.as_lang(n))
             }
         }
     }
@@ -105,6 +110,171 @@ pub trait ToLanguageRepresentation {
 
 impl ToLanguageRepresentation for ASType {
     fn as_astype(&self) -> &ASType {
-        &self
+        self
+    }
+}
+
+/// Representation of a struct or tuple that puts its members at their WITX offsets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecordRepr {
+    /// `#[repr(C)]`, the natural layout of the members matches
+    Natural,
+    /// `#[repr(C, align(n))]`, the natural layout of the members matches, but the alignment of
+    /// some of them is lower than the WITX one
+    Aligned(usize),
+    /// `#[repr(C, packed)]`, with explicit padding fields
+    Packed,
+}
+
+impl RecordRepr {
+    /// Choose the representation of a record with the given WITX alignment, from the offset,
+    /// type and padding of its members.
+    pub fn new<'t>(
+        members: impl IntoIterator<Item = (usize, &'t ASType, usize)>,
+        align: usize,
+    ) -> Result<Self, Error> {
+        let (mut end, mut natural_end, mut natural_align) = (0, 0, 1);
+        for (offset, type_, padding) in members {
+            let member_align = rust_align(type_)?;
+            if align_to(natural_end, member_align) != offset {
+                return Ok(RecordRepr::Packed);
+            }
+            natural_end = offset + size_align(type_, "")?.size;
+            end = natural_end + padding;
+            natural_align = natural_align.max(member_align);
+        }
+        let repr = if align_to(natural_end, align) != end {
+            RecordRepr::Packed
+        } else if natural_align == align {
+            RecordRepr::Natural
+        } else {
+            RecordRepr::Aligned(align)
+        };
+        Ok(repr)
+    }
+
+    pub fn as_attribute(&self) -> String {
+        match self {
+            RecordRepr::Natural => "#[repr(C)]".to_string(),
+            RecordRepr::Aligned(align) => format!("#[repr(C, align({}))]", align),
+            RecordRepr::Packed => "#[repr(C, packed)]".to_string(),
+        }
+    }
+}
+
+/// Return the alignment of the Rust type generated for `type_`, which is lower than the WITX
+/// one for packed types. Tagged unions always have the WITX alignment.
+pub fn rust_align(type_: &ASType) -> Result<usize, Error> {
+    let align = size_align(type_, "")?.align;
+    let repr = match type_ {
+        ASType::Alias(alias) => return rust_align(&alias.type_),
+        ASType::Struct(members) => RecordRepr::new(
+            members
+                .iter()
+                .map(|member| (member.offset, member.type_.as_ref(), member.padding)),
+            align,
+        )?,
+        ASType::Tuple(members) => RecordRepr::new(
+            members
+                .iter()
+                .map(|member| (member.offset, member.type_.as_ref(), member.padding)),
+            align,
+        )?,
+        _ => RecordRepr::Natural,
+    };
+    match repr {
+        RecordRepr::Packed => Ok(1),
+        _ => Ok(align),
+    }
+}
+
+/// Return `true` if `#[derive(Debug)]` can be used on a type containing `type_`.
+pub fn implements_debug(type_: &ASType) -> bool {
+    match type_ {
+        ASType::Alias(alias) => implements_debug(&alias.type_),
+        ASType::Union(union_) => union_
+            .members
+            .iter()
+            .all(|member| implements_debug(&member.type_)),
+        ASType::Struct(members) => members.iter().all(|member| implements_debug(&member.type_)),
+        ASType::Tuple(members) => members.iter().all(|member| implements_debug(&member.type_)),
+        _ => true,
+    }
+}
+
+/// Return `true` if a type containing `type_` only holds values, so that it can be serialized or
+/// generated. Pointers, handles and the types borrowing memory from the host can't be.
+pub fn is_plain_value(type_: &ASType) -> bool {
+    match type_ {
+        ASType::Void
+        | ASType::Bool
+        | ASType::Char8
+        | ASType::Char32
+        | ASType::USize
+        | ASType::F32
+        | ASType::F64
+        | ASType::S8
+        | ASType::S16
+        | ASType::S32
+        | ASType::S64
+        | ASType::U8
+        | ASType::U16
+        | ASType::U32
+        | ASType::U64
+        | ASType::Enum(_)
+        | ASType::Constants(_) => true,
+        ASType::Alias(alias) => is_plain_value(&alias.type_),
+        ASType::Struct(members) => members.iter().all(|member| is_plain_value(&member.type_)),
+        ASType::Tuple(members) => members.iter().all(|member| is_plain_value(&member.type_)),
+        ASType::Union(union_) => union_
+            .members
+            .iter()
+            .all(|member| is_plain_value(&member.type_)),
+        _ => false,
+    }
+}
+
+/// Return the namespace of the enumeration or the set of flags `type_` refers to, if any.
+/// Their values are plain integers in Rust, so that helpers for them are defined there.
+pub fn enum_namespace(n: &Namer, type_: &ASType) -> Option<String> {
+    let mut type_ = type_;
+    while let ASType::Alias(alias) = type_ {
+        match alias.type_.as_ref() {
+            ASType::Enum(_) | ASType::Constants(_) => return Some(alias.name.as_namespace(n)),
+            other => type_ = other,
+        }
+    }
+    None
+}
+
+/// Return `true` if the layout of `type_` depends on the size of pointers, so that it only
+/// matches the WITX layout on 32-bit WebAssembly.
+pub fn depends_on_pointer_size(type_: &ASType) -> bool {
+    match type_ {
+        ASType::USize
+        | ASType::ConstPtr(_)
+        | ASType::MutPtr(_)
+        | ASType::Slice(_)
+        | ASType::String(_)
+        | ASType::ReadBuffer(_)
+        | ASType::WriteBuffer(_) => true,
+        ASType::Alias(alias) => depends_on_pointer_size(&alias.type_),
+        ASType::Enum(enum_) => depends_on_pointer_size(&enum_.repr),
+        ASType::Constants(constants) => depends_on_pointer_size(&constants.repr),
+        ASType::Struct(members) => members
+            .iter()
+            .any(|member| depends_on_pointer_size(&member.type_)),
+        ASType::Tuple(members) => members
+            .iter()
+            .any(|member| depends_on_pointer_size(&member.type_)),
+        ASType::Union(union_) => union_
+            .members
+            .iter()
+            .any(|member| depends_on_pointer_size(&member.type_)),
+        ASType::Option(option) => depends_on_pointer_size(&option.type_),
+        ASType::Result(result) => {
+            depends_on_pointer_size(&result.ok_type) || depends_on_pointer_size(&result.error_type)
+        }
+        _ => false,
     }
 }
@@ -0,0 +1,132 @@
+use super::*;
+use std::io::Write;
+
+/// Return the name and the definition of the enumeration used as the error type of all the
+/// imported functions of the selection, if they all use the same one.
+pub fn error_enum(selection: &Selection) -> Result<Option<(String, ASEnum)>, Error> {
+    let mut error_enum: Option<(String, ASEnum)> = None;
+    for func in selection.funcs.iter().filter(|func| !is_export(func)) {
+        let result = match FuncSignature::new(func)?.returns {
+            FuncReturn::Expected(result) => result,
+            _ => continue,
+        };
+        let (name, enum_) = match result.error_type.as_ref() {
+            ASType::Alias(alias) => match alias.type_.as_ref() {
+                ASType::Enum(enum_) => (&alias.name, enum_),
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        match &error_enum {
+            None => error_enum = Some((name.clone(), enum_.clone())),
+            Some((other_name, _)) if other_name != name => return Ok(None),
+            Some(_) => {}
+        }
+    }
+    Ok(error_enum)
+}
+
+impl RustGenerator {
+    /// Define an enum with a variant for every error case of the `errno_name` enumeration,
+    /// returned by the functions of the module instead of the numeric error code. The case with
+    /// the value `0` means success, and is not an error.
+    pub fn define_error_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        errno_name: &str,
+        errno: &ASEnum,
+        no_std: bool,
+    ) -> Result<(), Error> {
+        let error_name = format!("{}_error", module_name).as_type(n);
+        let errno_type = errno_name.as_type(n);
+        let errno_namespace = errno_name.as_namespace(n);
+        let error_choices: Vec<_> = errno
+            .choices
+            .iter()
+            .filter(|choice| choice.value != 0)
+            .collect();
+
+        w.write_line(format!(
+            "/// Error returned by the functions of the `{}` module.",
+            module_name
+        ))?;
+        w.write_line("#[derive(Debug, Copy, Clone, Eq, PartialEq)]")?;
+        w.write_line(format!("pub enum {} {{", error_name))?;
+        {
+            let mut w = w.new_block();
+            for choice in &error_choices {
+                Self::write_docs(&mut w, &choice.docs)?;
+                w.write_line(format!("{},", choice.name.as_variant()))?;
+            }
+            w.write_line(format!(
+                "/// An error code that is not an error case of `{}`.",
+                errno_type
+            ))?;
+            w.write_line(format!("Other({}),", errno_type))?;
+        }
+        w.write_line("}")?.eob()?;
+
+        let mut from_errno = vec!["match e {".to_string()];
+        let mut to_errno = vec!["match e {".to_string()];
+        let mut messages = vec!["let message = match self {".to_string()];
+        for choice in &error_choices {
+            let variant = format!("{}::{}", error_name, choice.name.as_variant());
+            let constant = format!("{}::{}", errno_namespace, choice.name.as_const(n));
+            let message = match choice.docs.lines().map(str::trim).next() {
+                Some(line) if !line.is_empty() => line,
+                _ => choice.name.as_str(),
+            };
+            from_errno.push(format!("    {} => {},", constant, variant));
+            to_errno.push(format!("    {} => {},", variant, constant));
+            messages.push(format!("    {} => {:?},", variant, message));
+        }
+        from_errno.push(format!("    e => {}::Other(e),", error_name));
+        from_errno.push("}".to_string());
+        to_errno.push(format!("    {}::Other(e) => e,", error_name));
+        to_errno.push("}".to_string());
+        messages.push(format!(
+            "    {}::Other(e) => return write!(f, \"Unknown error {{}}\", e),",
+            error_name
+        ));
+        messages.push("};".to_string());
+        messages.push("f.write_str(message)".to_string());
+
+        Self::define_from(
+            w,
+            &format!(" From<{}> for {}", errno_type, error_name),
+            &format!("e: {}", errno_type),
+            &from_errno,
+        )?;
+        w.eob()?;
+        Self::define_from(
+            w,
+            &format!(" From<{}> for {}", error_name, errno_type),
+            &format!("e: {}", error_name),
+            &to_errno,
+        )?;
+        w.eob()?;
+        Self::define_from(
+            w,
+            &format!(" From<{}> for Error", error_name),
+            &format!("e: {}", error_name),
+            &[format!("Error::WasiError({}::from(e) as _)", errno_type)],
+        )?;
+        w.eob()?;
+
+        w.write_line(format!("impl core::fmt::Display for {} {{", error_name))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {")?;
+            w.new_block().write_lines(messages.join("\n"))?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+
+        if !no_std {
+            w.write_line(format!("impl std::error::Error for {} {{}}", error_name))?;
+            w.eob()?;
+        }
+        Ok(())
+    }
+}
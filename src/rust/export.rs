@@ -0,0 +1,268 @@
+use super::*;
+use std::io::Write;
+
+impl RustGenerator {
+    /// Define a trait with a method for every exported function, and a macro exporting these
+    /// methods to the host for a type implementing it. `bindings_paths` are the paths of the
+    /// modules the types of the functions are defined in.
+    pub fn define_exports<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        bindings_paths: &[String],
+        selection: &Selection,
+    ) -> Result<(), Error> {
+        let mut signatures = vec![];
+        for func in selection.funcs.iter().filter(|func| is_export(func)) {
+            signatures.push((
+                FuncSignature::new(func)?,
+                func_docs(func, |name| name.as_var(n)),
+            ));
+        }
+        if signatures.is_empty() {
+            return Ok(());
+        }
+        let trait_name = format!("{}_exports", module_name).as_type(n);
+        let macro_name = format!("export_{}", module_name).as_fn(n);
+
+        w.write_line(format!(
+            "/// Functions of the `{}` module implemented by the guest.",
+            module_name
+        ))?;
+        w.write_line(format!(
+            "/// Implement this trait for a type, and export its methods to the host with `{}!(Type)`.",
+            macro_name
+        ))?;
+        w.write_line(format!("pub trait {} {{", trait_name))?;
+        {
+            let mut w = w.new_block();
+            for (i, (signature, docs)) in signatures.iter().enumerate() {
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::write_docs(&mut w, docs)?;
+                Self::define_trait_method(&mut w, n, signature, None, None, None)?;
+            }
+        }
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!(
+            "/// Export the methods of a type implementing `{}` to the host.",
+            trait_name
+        ))?;
+        w.write_line(
+            "/// If the bindings are not at the root of their crate, the paths of the modules defining their types",
+        )?;
+        w.write_line(format!(
+            "/// have to be given, as in `{}!(Type, with_types_in crate::bindings)`.",
+            macro_name
+        ))?;
+        w.write_line("#[macro_export]")?;
+        w.write_line(format!("macro_rules! {} {{", macro_name))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("($t:ty) => {")?;
+            w.new_block().write_line(format!(
+                "$crate::{}!($t, with_types_in {});",
+                macro_name,
+                bindings_paths.join(", ")
+            ))?;
+            w.write_line("};")?;
+            w.write_line("($t:ty, with_types_in $($path:path),+) => {")?;
+            {
+                let mut w = w.new_block();
+                w.write_line("const _: () = {")?;
+                {
+                    let mut w = w.new_block();
+                    w.write_line("$(")?;
+                    w.new_block()
+                        .write_line("#[allow(unused_imports)]")?
+                        .write_line("use $path::*;")?;
+                    w.write_line(")+")?;
+                    w.eob()?;
+                    for (i, (signature, _)) in signatures.iter().enumerate() {
+                        if i > 0 {
+                            w.eob()?;
+                        }
+                        Self::define_export_shim(&mut w, n, signature, &trait_name)?;
+                    }
+                }
+                w.write_line("};")?;
+            }
+            w.write_line("};")?;
+        }
+        w.write_line("}")?;
+        w.eob()?;
+        Ok(())
+    }
+
+    /// Declare a trait method for the function, returning its results instead of writing them
+    /// to result pointers, and `error_name` errors if set. `receiver` is prepended to the
+    /// parameters, and methods with a `default_body` are defined instead of being only declared.
+    pub fn define_trait_method<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        signature: &FuncSignature,
+        error_name: Option<&str>,
+        receiver: Option<&str>,
+        default_body: Option<&str>,
+    ) -> Result<(), Error> {
+        let params = receiver
+            .map(|receiver| receiver.to_string())
+            .into_iter()
+            .chain(
+                signature
+                    .params_decomposed
+                    .iter()
+                    .map(|param| format!("{}: {}", param.name.as_var(n), param.type_.as_lang(n))),
+            )
+            .collect::<Vec<_>>();
+        let result_str = match &signature.returns {
+            FuncReturn::Expected(result) => {
+                let results_set = results_decomposed_deref(signature)?
+                    .iter()
+                    .map(|result| result.type_.as_lang(n))
+                    .collect::<Vec<_>>();
+                let ok_str = match results_set.len() {
+                    0 => "()".to_string(),
+                    1 => results_set[0].clone(),
+                    _ => format!("({})", results_set.join(", ")),
+                };
+                let error_str = match error_name {
+                    Some(error_name) => error_name.to_string(),
+                    None => result.error_type.as_lang(n),
+                };
+                format!(" -> Result<{}, {}>", ok_str, error_str)
+            }
+            FuncReturn::Value(type_) => format!(" -> {}", type_.as_lang(n)),
+            FuncReturn::Nothing => "".to_string(),
+            FuncReturn::Never => " -> !".to_string(),
+        };
+        let end_str = match default_body {
+            None => ";",
+            Some(_) => " {",
+        };
+        w.indent()?
+            .write(format!("fn {}(", signature.name.as_fn(n)))?;
+        // Only the receiver, if any, is on the same line as the function name
+        if params.len() == receiver.is_some() as usize {
+            w.write(format!("{}){}{}", params.join(""), result_str, end_str))?
+                .eol()?;
+        } else {
+            w.eol()?;
+            for param in &params {
+                w.write_line_continued(format!("{},", param))?;
+            }
+            w.write_line(format!("){}{}", result_str, end_str))?;
+        }
+        if let Some(default_body) = default_body {
+            w.new_block().write_line(default_body)?;
+            w.write_line("}")?;
+        }
+        Ok(())
+    }
+
+    /// Call `call_str`, a function returning the results of `signature` directly, and write them
+    /// to the result pointers of `signature`, returning the error code if it failed. Errors are
+    /// converted from `error_name` if set.
+    pub fn write_results_dispatch<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        signature: &FuncSignature,
+        error_name: Option<&str>,
+        call_str: &str,
+    ) -> Result<(), Error> {
+        let results_decomposed = &signature.results_decomposed;
+        if let FuncReturn::Expected(_) = signature.returns {
+            w.write_line(format!("match {} {{", call_str))?;
+            {
+                let mut w = w.new_block();
+                let values = match results_decomposed.len() {
+                    1 => vec!["res".to_string()],
+                    len => (0..len).map(|i| format!("res{}", i)).collect(),
+                };
+                let res_str = match values.len() {
+                    1 => values[0].clone(),
+                    _ => format!("({})", values.join(", ")),
+                };
+                w.write_line(format!("Ok({}) => {{", res_str))?;
+                {
+                    let mut w = w.new_block();
+                    for (value, result_ptr) in values.iter().zip(results_decomposed) {
+                        w.write_line(format!(
+                            "unsafe {{ {}.write({}) }};",
+                            result_ptr.name.as_var(n),
+                            value
+                        ))?;
+                    }
+                    w.write_line("0")?;
+                }
+                w.write_line("}")?;
+                match error_name {
+                    Some(_) => w.write_line("Err(e) => e.into(),")?,
+                    None => w.write_line("Err(e) => e,")?,
+                };
+            }
+            w.write_line("}")?;
+        } else {
+            w.write_line(call_str)?;
+        }
+        Ok(())
+    }
+
+    /// Define a function exporting the method of `$t` for the function to the host.
+    fn define_export_shim<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        signature: &FuncSignature,
+        trait_name: &str,
+    ) -> Result<(), Error> {
+        let name = &signature.name;
+        let params_decomposed = &signature.params_decomposed;
+        let results_decomposed = &signature.results_decomposed;
+        let result_str = match &signature.returns {
+            FuncReturn::Expected(result) => format!(" -> {}", result.error_type.as_lang(n)),
+            FuncReturn::Value(type_) => format!(" -> {}", type_.as_lang(n)),
+            FuncReturn::Nothing => "".to_string(),
+            FuncReturn::Never => " -> !".to_string(),
+        };
+
+        // Function names are also the names of the exported symbols
+        if name.as_fn(n) == *name {
+            w.write_line("#[no_mangle]")?;
+        } else {
+            w.write_line(format!("#[export_name = \"{}\"]", name))?;
+        }
+        w.indent()?
+            .write(format!("pub extern \"C\" fn {}(", name.as_fn(n)))?;
+        if params_decomposed.is_empty() && results_decomposed.is_empty() {
+            w.write(format!("){} {{", result_str))?.eol()?;
+        } else {
+            w.eol()?;
+            for param in params_decomposed.iter().chain(results_decomposed.iter()) {
+                w.write_line_continued(format!(
+                    "{}: {},",
+                    param.name.as_var(n),
+                    param.type_.as_lang(n),
+                ))?;
+            }
+            w.write_line(format!("){} {{", result_str))?;
+        }
+        {
+            let mut w = w.new_block();
+            let call_str = format!(
+                "<$t as {}>::{}({})",
+                trait_name,
+                name.as_fn(n),
+                params_decomposed
+                    .iter()
+                    .map(|param| param.name.as_var(n))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            Self::write_results_dispatch(&mut w, n, signature, None, &call_str)?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+}
@@ -4,179 +4,180 @@ use std::io::Write;
 impl RustGenerator {
     pub fn define_func<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         module_name: &str,
         func_witx: &witx::Function,
+        error_name: Option<&str>,
+        no_std: bool,
     ) -> Result<(), Error> {
-        assert_eq!(func_witx.abi, witx::Abi::Preview1);
-        let name = func_witx.name.as_str().to_string();
-        let params_witx = &func_witx.params;
-        let mut params = vec![];
-        for param_witx in params_witx {
-            let param_name = param_witx.name.as_str();
-            let param_type = ASType::from(&param_witx.tref);
-            params.push((param_name.to_string(), param_type));
-        }
-
-        let results_witx = &func_witx.results;
-        assert_eq!(results_witx.len(), 1);
-        let result_witx = &results_witx[0];
-        let result = ASType::from(&result_witx.tref);
-        let result = match result {
-            ASType::Result(result) => result,
-            _ => unreachable!(),
-        };
-
-        let ok_type = result.ok_type.clone();
-
-        let docs = &func_witx.docs;
-        if !docs.is_empty() {
-            Self::write_docs(w, docs)?;
-        }
-
-        let mut params_decomposed = vec![];
-
-        for param in &params {
-            let mut decomposed = param.1.decompose(&param.0, false);
-            params_decomposed.append(&mut decomposed);
-        }
-
-        let mut results = vec![];
-        // A tuple in a result is expanded into additional parameters, transformed to pointers
-        if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
-            for (i, tuple_member) in tuple_members.iter().enumerate() {
-                let name = format!("result{}_ptr", i);
-                results.push((name, tuple_member.type_.clone()));
-            }
-        } else {
-            let name = "result_ptr";
-            results.push((name.to_string(), ok_type));
-        }
-        let mut results_decomposed = vec![];
-        for result in &results {
-            let mut decomposed = result.1.decompose(&result.0, true);
-            results_decomposed.append(&mut decomposed);
-        }
+        let signature = FuncSignature::new(func_witx)?;
 
-        Self::define_func_raw(
-            w,
-            module_name,
-            &name,
-            &params_decomposed,
-            &results_decomposed,
-            &result,
-        )?;
+        Self::write_docs(w, &func_docs(func_witx, |name| name.as_var(n)))?;
 
-        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
-        let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
-        assert_eq!(
-            params_count_witx,
-            params_decomposed.len() + results_decomposed.len() + 1
-        );
-
-        Ok(())
+        Self::define_func_raw(w, n, module_name, &signature, error_name, no_std)
     }
 
     fn define_func_raw<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         module_name: &str,
-        name: &str,
-        params_decomposed: &[ASTypeDecomposed],
-        results_decomposed: &[ASTypeDecomposed],
-        result: &ASResult,
+        signature: &FuncSignature,
+        error_name: Option<&str>,
+        no_std: bool,
     ) -> Result<(), Error> {
-        let results_decomposed_deref = results_decomposed
-            .iter()
-            .map(|result_ptr_type| match result_ptr_type.type_.as_ref() {
-                ASType::MutPtr(result_type) => ASTypeDecomposed {
-                    name: result_ptr_type.name.clone(),
-                    type_: result_type.clone(),
-                },
-                _ => panic!("Result type is not a pointer"),
-            })
-            .collect::<Vec<_>>();
+        let name = &signature.name;
+        let params_decomposed = &signature.params_decomposed;
+        let results_decomposed = &signature.results_decomposed;
+        let results_decomposed_deref = results_decomposed_deref(signature)?;
         let results_set = results_decomposed_deref
             .iter()
-            .map(|result| result.type_.as_lang())
+            .map(|result| result.type_.as_lang(n))
             .collect::<Vec<_>>();
-        let rust_fn_result_str = match results_set.len() {
-            0 => "()".to_string(),
-            1 => results_set[0].clone(),
-            _ => format!("({})", results_set.join(", ")),
+        let rust_fn_result_str = match &signature.returns {
+            FuncReturn::Expected(_) => {
+                let ok_str = match results_set.len() {
+                    0 => "()".to_string(),
+                    1 => results_set[0].clone(),
+                    _ => format!("({})", results_set.join(", ")),
+                };
+                format!(" -> Result<{}, {}>", ok_str, error_name.unwrap_or("Error"))
+            }
+            FuncReturn::Value(type_) => format!(" -> {}", type_.as_lang(n)),
+            FuncReturn::Nothing => "".to_string(),
+            FuncReturn::Never => " -> !".to_string(),
         };
-        w.indent()?.write(format!("pub fn {}(", name.as_fn()))?;
-        if !params_decomposed.is_empty() || !results_decomposed.is_empty() {
+        let raw_fn_result_str = match &signature.returns {
+            FuncReturn::Expected(result) => format!(" -> {}", result.error_type.as_lang(n)),
+            _ => rust_fn_result_str.clone(),
+        };
+        w.indent()?.write(format!("pub fn {}(", name.as_fn(n)))?;
+        if !params_decomposed.is_empty() {
             w.eol()?;
         }
         for param in params_decomposed {
             w.write_line_continued(format!(
                 "{}: {},",
-                param.name.as_var(),
-                param.type_.as_lang(),
+                param.name.as_var(n),
+                param.type_.as_lang(n),
             ))?;
         }
-        w.write_line(format!(") -> Result<{}, Error> {{", rust_fn_result_str))?;
+        w.write_line(format!("){} {{", rust_fn_result_str))?;
         {
             let mut w = w.new_block();
 
-            // Inner (raw) definition
+            // Inner (raw) definition, in a module so that parameters can't shadow it
+            w.write_line("mod raw {")?;
             {
+                let mut w = w.new_block();
+                // Types of the Rust language don't need to be imported
+                let raw_return_type = match &signature.returns {
+                    FuncReturn::Expected(result) => Some(result.error_type.as_ref()),
+                    FuncReturn::Value(type_) => Some(type_.as_ref()),
+                    FuncReturn::Nothing | FuncReturn::Never => None,
+                };
+                let needs_import = params_decomposed
+                    .iter()
+                    .chain(results_decomposed.iter())
+                    .map(|param| param.type_.as_ref())
+                    .chain(raw_return_type)
+                    .any(|type_| !is_builtin(type_));
+                if needs_import {
+                    w.write_line("use super::*;")?;
+                }
+                if !no_std {
+                    w.write_line("#[cfg(target_arch = \"wasm32\")]")?;
+                }
                 w.write_line(format!("#[link(wasm_import_module = \"{}\")]", module_name))?;
                 w.write_line("extern \"C\" {")?;
                 {
                     let mut w = w.new_block();
-                    w.indent()?.write(format!("fn {}(", name.as_fn()))?;
-                    if !params_decomposed.is_empty() {
-                        w.eol()?;
+                    // Function names are also the names of the imported symbols
+                    if name.as_fn(n) != *name {
+                        w.write_line(format!("#[link_name = \"{}\"]", name))?;
                     }
-                    for param in params_decomposed.iter().chain(results_decomposed.iter()) {
-                        w.write_line_continued(format!(
-                            "{}: {},",
-                            param.name.as_var(),
-                            param.type_.as_lang(),
-                        ))?;
+                    w.indent()?.write(format!("pub fn {}(", name.as_fn(n)))?;
+                    if params_decomposed.is_empty() && results_decomposed.is_empty() {
+                        w.write(format!("){};", raw_fn_result_str))?.eol()?;
+                    } else {
+                        w.eol()?;
+                        for param in params_decomposed.iter().chain(results_decomposed.iter()) {
+                            w.write_line_continued(format!(
+                                "{}: {},",
+                                param.name.as_var(n),
+                                param.type_.as_lang(n),
+                            ))?;
+                        }
+                        w.write_line(format!("){};", raw_fn_result_str))?;
                     }
-                    w.write_line(format!(") -> {};", result.error_type.as_lang()))?;
                 }
                 w.write_line("}")?;
+                if !no_std {
+                    Self::define_func_mock(&mut w, n, module_name, error_name, signature)?;
+                }
             }
+            w.write_line("}")?;
 
             // Wrapper
             for result in &results_decomposed_deref {
                 w.write_line(format!(
-                    "let mut {} = std::mem::MaybeUninit::uninit();",
-                    result.name.as_var()
+                    "let mut {} = core::mem::MaybeUninit::uninit();",
+                    result.name.as_var(n)
                 ))?;
             }
 
-            w.write_line(format!("let res = unsafe {{ {}(", name.as_fn()))?;
-            for param in params_decomposed {
-                w.write_line_continued(format!("{},", param.name.as_var()))?;
+            let call_prefix = match &signature.returns {
+                FuncReturn::Expected(_) => "let res = unsafe",
+                _ => "unsafe",
+            };
+            let call_suffix = match &signature.returns {
+                FuncReturn::Expected(_) => ";",
+                _ => "",
+            };
+            if params_decomposed.is_empty() && results_decomposed.is_empty() {
+                w.write_line(format!(
+                    "{} {{ raw::{}() }}{}",
+                    call_prefix,
+                    name.as_fn(n),
+                    call_suffix
+                ))?;
+            } else {
+                w.write_line(format!("{} {{ raw::{}(", call_prefix, name.as_fn(n)))?;
+                for param in params_decomposed {
+                    w.write_line_continued(format!("{},", param.name.as_var(n)))?;
+                }
+                for result in results_decomposed_deref.iter() {
+                    w.write_line_continued(format!("{}.as_mut_ptr(),", result.name.as_var(n)))?;
+                }
+                w.write_line(format!(")}}{}", call_suffix))?;
             }
-            for result in results_decomposed_deref.iter() {
-                w.write_line_continued(format!("{}.as_mut_ptr(),", result.name.as_var()))?;
+            if let FuncReturn::Expected(_) = signature.returns {
+                w.write_line("if res != 0 {")?;
+                match error_name {
+                    Some(error_name) => w
+                        .new_block()
+                        .write_line(format!("return Err({}::from(res));", error_name))?,
+                    None => w
+                        .new_block()
+                        .write_line("return Err(Error::WasiError(res as _));")?,
+                };
+                w.write_line("}")?;
+                let res_str = match results_decomposed.len() {
+                    0 => "()".to_string(),
+                    1 => format!(
+                        "unsafe {{ {}.assume_init() }}",
+                        results_decomposed_deref[0].name.as_var(n)
+                    ),
+                    _ => format!(
+                        "unsafe {{ ({}) }}",
+                        results_decomposed_deref
+                            .iter()
+                            .map(|result| format!("{}.assume_init()", result.name.as_var(n)))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                w.write_line(format!("Ok({})", res_str))?;
             }
-            w.write_line(")};")?;
-            w.write_lines(
-                "if res != 0 {
-    return Err(Error::WasiError(res as _));
-}",
-            )?;
-            let res_str = match results_decomposed.len() {
-                0 => "()".to_string(),
-                1 => format!(
-                    "unsafe {{ {}.assume_init() }}",
-                    results_decomposed_deref[0].name.as_var()
-                ),
-                _ => format!(
-                    "unsafe {{ ({}) }}",
-                    results_decomposed_deref
-                        .iter()
-                        .map(|result| format!("{}.assume_init()", result.name.as_var()))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                ),
-            };
-            w.write_line(format!("Ok({})", res_str))?;
         };
         w.write_line("}")?;
         w.eob()?;
@@ -184,3 +185,44 @@ impl RustGenerator {
         Ok(())
     }
 }
+
+/// Return the types the result pointers of a function point to.
+pub fn results_decomposed_deref(signature: &FuncSignature) -> Result<Vec<ASTypeDecomposed>, Error> {
+    let mut results_decomposed_deref = vec![];
+    for result_ptr_type in &signature.results_decomposed {
+        match result_ptr_type.type_.as_ref() {
+            ASType::MutPtr(result_type) => results_decomposed_deref.push(ASTypeDecomposed {
+                name: result_ptr_type.name.clone(),
+                type_: result_type.clone(),
+            }),
+            _ => {
+                return Err(Error::Unsupported(format!(
+                    "result [{}] of function [{}] is not returned through a pointer",
+                    result_ptr_type.name, signature.name
+                )))
+            }
+        }
+    }
+    Ok(results_decomposed_deref)
+}
+
+/// Return `true` if the type is represented by a type of the Rust language.
+fn is_builtin(type_: &ASType) -> bool {
+    match type_ {
+        ASType::Void
+        | ASType::Bool
+        | ASType::F32
+        | ASType::F64
+        | ASType::S8
+        | ASType::S16
+        | ASType::S32
+        | ASType::S64
+        | ASType::U8
+        | ASType::U16
+        | ASType::U32
+        | ASType::U64
+        | ASType::USize => true,
+        ASType::Enum(enum_) => is_builtin(&enum_.repr),
+        _ => false,
+    }
+}
@@ -0,0 +1,169 @@
+use super::*;
+use std::io::Write;
+
+/// Return an expression generating an arbitrary value of type `type_` from `u`.
+/// Enumerations and flags are plain integers in Rust, so their namespace provides the function
+/// generating valid values.
+pub fn arbitrary_value(n: &Namer, type_: &ASType) -> String {
+    match enum_namespace(n, type_) {
+        Some(namespace) => format!("{}::arbitrary(u)?", namespace),
+        None => "u.arbitrary()?".to_string(),
+    }
+}
+
+impl RustGenerator {
+    /// Write the header of an implementation of `arbitrary::Arbitrary`, up to the opening brace
+    /// of the `arbitrary()` function.
+    fn write_arbitrary_impl_header<T: Write>(
+        w: &mut PrettyWriter<T>,
+        type_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line("#[cfg(feature = \"arbitrary\")]")?;
+        w.write_line(format!(
+            "impl<'a> arbitrary::Arbitrary<'a> for {} {{",
+            type_name
+        ))?;
+        w.new_block().write_line(
+            "fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {",
+        )?;
+        Ok(())
+    }
+
+    /// Implement `arbitrary::Arbitrary` for a struct or a tuple, with `fields` the names of the
+    /// fields and the expressions generating their values.
+    pub fn define_record_arbitrary<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        fields: &[(String, String)],
+    ) -> Result<(), Error> {
+        let mut body = vec![format!("Ok({} {{", name.as_type(n))];
+        body.extend(
+            fields
+                .iter()
+                .map(|(field, value)| format!("    {}: {},", field, value)),
+        );
+        body.push("})".to_string());
+
+        w.eob()?;
+        Self::write_arbitrary_impl_header(w, &name.as_type(n))?;
+        {
+            let mut w = w.new_block();
+            w.new_block().write_lines(body.join("\n"))?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Implement `arbitrary::Arbitrary` for the enum with the content of a tagged union, picking
+    /// one of its cases, and for the union itself, converted from it so that the tag is valid.
+    pub fn define_union_arbitrary<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let enum_name = format!("{}_enum", name).as_type(n);
+        let cases: Vec<_> = union_
+            .members
+            .iter()
+            .map(|member| {
+                let variant = format!("{}::{}", enum_name, member.name.as_variant());
+                match member.type_.as_ref() {
+                    ASType::Void => variant,
+                    type_ => format!("{}({})", variant, arbitrary_value(n, type_)),
+                }
+            })
+            .collect();
+        let body = match cases.len() {
+            1 => format!("Ok({})", cases[0]),
+            len => {
+                let mut body = vec![format!("Ok(match u.int_in_range(0..={})? {{", len - 1)];
+                for (i, case) in cases.iter().enumerate() {
+                    if i + 1 < len {
+                        body.push(format!("    {} => {},", i, case));
+                    } else {
+                        body.push(format!("    _ => {},", case));
+                    }
+                }
+                body.push("})".to_string());
+                body.join("\n")
+            }
+        };
+
+        w.eob()?;
+        Self::write_arbitrary_impl_header(w, &enum_name)?;
+        {
+            let mut w = w.new_block();
+            w.new_block().write_lines(body)?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+        Self::write_arbitrary_impl_header(w, &name.as_type(n))?;
+        {
+            let mut w = w.new_block();
+            w.new_block().write_line(format!(
+                "<{} as arbitrary::Arbitrary>::arbitrary(u).map(Self::from)",
+                enum_name
+            ))?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Define `arbitrary()` in the namespace of an enumeration, returning one of its cases.
+    pub fn define_enum_arbitrary<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+    ) -> Result<(), Error> {
+        let type_name = name.as_type(n);
+        w.eob()?;
+        w.write_line(format!(
+            "/// Return an arbitrary case of `{}`, for fuzzing.",
+            type_name
+        ))?;
+        w.write_line("#[cfg(feature = \"arbitrary\")]")?;
+        w.write_line(format!(
+            "pub fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<{}> {{",
+            type_name
+        ))?;
+        w.new_block().write_line("u.choose(VALUES).copied()")?;
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Define `arbitrary()` in the namespace of a set of flags, returning a combination of them
+    /// with no other bits set.
+    pub fn define_flags_arbitrary<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+    ) -> Result<(), Error> {
+        let type_name = name.as_type(n);
+        w.eob()?;
+        w.write_line(format!(
+            "/// Return an arbitrary combination of the flags of `{}`, for fuzzing.",
+            type_name
+        ))?;
+        w.write_line("#[cfg(feature = \"arbitrary\")]")?;
+        w.write_line(format!(
+            "pub fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<{}> {{",
+            type_name
+        ))?;
+        w.new_block().write_lines(format!(
+            "let mut value: {} = 0;
+for flag in VALUES {{
+    if u.arbitrary()? {{
+        value |= flag;
+    }}
+}}
+Ok(value)",
+            type_name
+        ))?;
+        w.write_line("}")?;
+        Ok(())
+    }
+}
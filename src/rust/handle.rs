@@ -0,0 +1,78 @@
+use super::*;
+use crate::handles::*;
+use std::io::Write;
+
+impl RustGenerator {
+    pub fn define_handle_wrappers<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_witx: &witx::Module,
+        selection: &Selection,
+    ) -> Result<(), Error> {
+        for type_ in &selection.typenames {
+            if handle_resource(&ASType::from(&type_.tref)).is_none() {
+                continue;
+            }
+            let type_name = type_.name.as_str();
+            if let Some(close_func) = close_function_for(module_witx, type_name)
+                .filter(|close_func| selection.has_func(close_func.name.as_str()))
+            {
+                Self::define_handle_wrapper(w, n, type_name, close_func.name.as_str())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn define_handle_wrapper<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        close_fn_name: &str,
+    ) -> Result<(), Error> {
+        let wrapper_name = format!("owned_{}", type_name).as_type(n);
+        let handle_type = type_name.as_type(n);
+
+        w.write_line(format!(
+            "/// Owned `{}` handle, closed with `{}()` when dropped.",
+            handle_type,
+            close_fn_name.as_fn(n)
+        ))?;
+        w.write_line("#[derive(Debug)]")?;
+        w.write_line(format!("pub struct {}({});", wrapper_name, handle_type))?;
+        w.eob()?;
+
+        w.write_line(format!("impl {} {{", wrapper_name))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("pub fn new(handle: {}) -> Self {{", handle_type))?;
+            w.new_block()
+                .write_line(format!("{}(handle)", wrapper_name))?;
+            w.write_line("}")?.eob()?;
+
+            w.write_line(format!("pub fn get(&self) -> {} {{", handle_type))?;
+            w.new_block().write_line("self.0")?;
+            w.write_line("}")?.eob()?;
+
+            w.write_line(format!("pub fn release(self) -> {} {{", handle_type))?;
+            {
+                let mut w = w.new_block();
+                w.write_line("let handle = self.0;")?;
+                w.write_line("core::mem::forget(self);")?;
+                w.write_line("handle")?;
+            }
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!("impl Drop for {} {{", wrapper_name))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("fn drop(&mut self) {")?;
+            w.new_block()
+                .write_line(format!("let _ = {}(self.0);", close_fn_name.as_fn(n)))?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+}
@@ -2,7 +2,24 @@ use super::*;
 use std::io::Write;
 
 impl RustGenerator {
-    pub fn header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
+    /// Types defined by the header.
+    pub const PRELUDE_TYPES: &'static [&'static str] = &[
+        "Char32",
+        "Char8",
+        "Error",
+        "WasiHandle",
+        "WasiInvalidTag",
+        "WasiMutPtr",
+        "WasiMutSlice",
+        "WasiPtr",
+        "WasiSlice",
+        "WasiString",
+        "WasiStringBytesPtr",
+    ];
+
+    /// Write the types shared by all modules. With `no_std`, only `core` is used, and helpers
+    /// returning owned values are gated by an `alloc` feature.
+    pub fn header<T: Write>(w: &mut PrettyWriter<T>, no_std: bool) -> Result<(), Error> {
         w.write_lines(
             "
 //
@@ -14,16 +31,35 @@ impl RustGenerator {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Error {
     WasiError(i32),
-}
-impl std::error::Error for Error {}
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+}",
+        )?;
+        if !no_std {
+            w.write_line("impl std::error::Error for Error {}")?;
+        }
+        w.write_lines(
+            "impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::WasiError(e) => write!(f, \"Wasi error {}\", e),
         }
     }
 }
 
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, \"Invalid tag {}\", self.0)
+    }
+}",
+        )?;
+        if !no_std {
+            w.write_line("impl std::error::Error for WasiInvalidTag {}")?;
+        }
+        w.write_lines(
+            "
 pub type WasiHandle = i32;
 pub type Char8 = u8;
 pub type Char32 = u32;
@@ -47,10 +83,10 @@ pub struct WasiMutSlice<T> {
 
 impl<T> WasiSlice<T> {
     pub fn as_slice(&self) -> &[T] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
     }
 
-    pub fn from_slice(&self, slice: &[T]) -> Self {
+    pub fn from_slice(slice: &[T]) -> Self {
         WasiSlice {
             ptr: slice.as_ptr() as _,
             len: slice.len(),
@@ -60,21 +96,21 @@ impl<T> WasiSlice<T> {
 
 impl<T> WasiMutSlice<T> {
     pub fn as_slice(&self) -> &[T] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
     }
 
-    pub fn as_mut_slice(&self) -> &mut [T] {
-        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
     }
 
-    pub fn from_slice(&self, slice: &[T]) -> Self {
+    pub fn from_slice(slice: &[T]) -> Self {
         WasiMutSlice {
             ptr: slice.as_ptr() as _,
             len: slice.len(),
         }
     }
 
-    pub fn from_mut_slice(&self, slice: &mut [T]) -> Self {
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
         WasiMutSlice {
             ptr: slice.as_mut_ptr(),
             len: slice.len(),
@@ -100,15 +136,15 @@ impl<T: AsRef<str>> From<T> for WasiString {
 }
 
 impl WasiString {
-    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
-        std::str::from_utf8(unsafe { std::slice::from_raw_parts(self.ptr, self.len) })
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
     }
 
     pub fn as_slice(&self) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
     }
 
-    pub fn from_slice(&self, slice: &[u8]) -> Self {
+    pub fn from_slice(slice: &[u8]) -> Self {
         WasiString {
             ptr: slice.as_ptr() as _,
             len: slice.len(),
@@ -117,6 +153,35 @@ impl WasiString {
 }
 ",
         )?;
+        if no_std {
+            w.write_lines(
+                "
+#[cfg(feature = \"alloc\")]
+extern crate alloc;
+
+#[cfg(feature = \"alloc\")]
+impl<T: Clone> WasiSlice<T> {
+    pub fn to_vec(&self) -> alloc::vec::Vec<T> {
+        self.as_slice().to_vec()
+    }
+}
+
+#[cfg(feature = \"alloc\")]
+impl<T: Clone> WasiMutSlice<T> {
+    pub fn to_vec(&self) -> alloc::vec::Vec<T> {
+        self.as_slice().to_vec()
+    }
+}
+
+#[cfg(feature = \"alloc\")]
+impl WasiString {
+    pub fn to_string(&self) -> Result<alloc::string::String, core::str::Utf8Error> {
+        self.as_str().map(alloc::string::String::from)
+    }
+}
+",
+            )?;
+        }
         w.eob()?;
         Ok(())
     }
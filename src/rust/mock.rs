@@ -0,0 +1,164 @@
+use super::*;
+use std::io::Write;
+
+impl RustGenerator {
+    /// Define, for native targets, a trait with a method for every imported function, and a
+    /// thread-local registry for its implementation. Imported functions dispatch to that
+    /// implementation when not compiled for WebAssembly, so that guest code can be tested natively.
+    pub fn define_mock<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        error_name: Option<&str>,
+        selection: &Selection,
+    ) -> Result<(), Error> {
+        let mut signatures = vec![];
+        for func in selection.funcs.iter().filter(|func| !is_export(func)) {
+            signatures.push((
+                FuncSignature::new(func)?,
+                func_docs(func, |name| name.as_var(n)),
+            ));
+        }
+        if signatures.is_empty() {
+            return Ok(());
+        }
+        let trait_name = format!("{}_mock", module_name).as_type(n);
+        let registry_name = format!("{}_mock", module_name).as_const(n);
+
+        w.write_line(format!(
+            "/// Implementation of the functions of the `{}` module used on native targets.",
+            module_name
+        ))?;
+        w.write_line(format!(
+            "/// Install it with `{}()`. Functions that are not implemented panic.",
+            format!("set_{}_mock", module_name).as_fn(n)
+        ))?;
+        w.write_line("#[cfg(not(target_arch = \"wasm32\"))]")?;
+        w.write_line(format!("pub trait {} {{", trait_name))?;
+        {
+            let mut w = w.new_block();
+            for (i, (signature, docs)) in signatures.iter().enumerate() {
+                if i > 0 {
+                    w.eob()?;
+                }
+                Self::write_docs(&mut w, docs)?;
+                w.write_line("#[allow(unused_variables)]")?;
+                Self::define_trait_method(
+                    &mut w,
+                    n,
+                    signature,
+                    error_name,
+                    Some("&self"),
+                    Some(&format!("unimplemented!(\"{}\")", signature.name)),
+                )?;
+            }
+        }
+        w.write_line("}")?.eob()?;
+
+        w.write_line("#[cfg(not(target_arch = \"wasm32\"))]")?;
+        w.write_line("thread_local! {")?;
+        w.new_block().write_line(format!(
+            "static {}: std::cell::RefCell<Option<std::rc::Rc<dyn {}>>> = std::cell::RefCell::new(None);",
+            registry_name, trait_name
+        ))?;
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!(
+            "/// Install the implementation of the `{}` module used by the current thread, and return the previous one.",
+            module_name
+        ))?;
+        w.write_line("#[cfg(not(target_arch = \"wasm32\"))]")?;
+        w.write_line(format!(
+            "pub fn {}(mock: impl {} + 'static) -> Option<std::rc::Rc<dyn {}>> {{",
+            format!("set_{}_mock", module_name).as_fn(n),
+            trait_name,
+            trait_name
+        ))?;
+        w.new_block().write_line(format!(
+            "{}.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))",
+            registry_name
+        ))?;
+        w.write_line("}")?.eob()?;
+
+        w.write_line("#[cfg(not(target_arch = \"wasm32\"))]")?;
+        w.write_line(format!(
+            "fn {}<R>(f: impl FnOnce(&dyn {}) -> R) -> R {{",
+            format!("with_{}_mock", module_name).as_fn(n),
+            trait_name
+        ))?;
+        {
+            let mut w = w.new_block();
+            // The registry is not borrowed during the call, so that the implementation can call
+            // imported functions too
+            w.write_line(format!(
+                "let mock = {}.with(|registry| registry.borrow().clone());",
+                registry_name
+            ))?;
+            w.write_line(format!(
+                "f(mock.as_deref().expect(\"No implementation of the `{}` module installed\"))",
+                module_name
+            ))?;
+        }
+        w.write_line("}")?.eob()?;
+        Ok(())
+    }
+
+    /// Define the native version of a raw function, dispatching to the installed implementation.
+    pub fn define_func_mock<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        module_name: &str,
+        error_name: Option<&str>,
+        signature: &FuncSignature,
+    ) -> Result<(), Error> {
+        let params_decomposed = &signature.params_decomposed;
+        let result_str = match &signature.returns {
+            FuncReturn::Expected(result) => format!(" -> {}", result.error_type.as_lang(n)),
+            FuncReturn::Value(type_) => format!(" -> {}", type_.as_lang(n)),
+            FuncReturn::Nothing => "".to_string(),
+            FuncReturn::Never => " -> !".to_string(),
+        };
+        w.write_line("#[cfg(not(target_arch = \"wasm32\"))]")?;
+        w.indent()?
+            .write(format!("pub unsafe fn {}(", signature.name.as_fn(n)))?;
+        if params_decomposed.is_empty() && signature.results_decomposed.is_empty() {
+            w.write(format!("){} {{", result_str))?.eol()?;
+        } else {
+            w.eol()?;
+            for param in params_decomposed
+                .iter()
+                .chain(signature.results_decomposed.iter())
+            {
+                w.write_line_continued(format!(
+                    "{}: {},",
+                    param.name.as_var(n),
+                    param.type_.as_lang(n),
+                ))?;
+            }
+            w.write_line(format!("){} {{", result_str))?;
+        }
+        {
+            let mut w = w.new_block();
+            let call_str = format!(
+                "super::{}(|mock| mock.{}({}))",
+                format!("with_{}_mock", module_name).as_fn(n),
+                signature.name.as_fn(n),
+                params_decomposed
+                    .iter()
+                    .map(|param| param.name.as_var(n))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            match &signature.returns {
+                // The closure can't return `!`, so the compiler can't tell that this never returns
+                FuncReturn::Never => {
+                    w.write_line(format!("{};", call_str))?;
+                    w.write_line("unreachable!()")?;
+                }
+                _ => Self::write_results_dispatch(&mut w, n, signature, error_name, &call_str)?,
+            }
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+}
@@ -1,44 +1,70 @@
+mod cargo;
 mod common;
+mod errno;
+mod export;
 mod function;
+mod fuzzing;
+mod handle;
 mod header;
+mod mock;
+mod serialization;
 mod r#struct;
 mod tuple;
 mod union;
 
 use super::*;
+use crate::annotations::strip_annotations;
 use crate::astype::*;
 use crate::error::*;
+use crate::handles::resources_to_define;
+use crate::naming::Namer;
 use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
 use common::*;
+use function::results_decomposed_deref;
+use fuzzing::*;
+use serialization::*;
 use std::io::Write;
 
 pub struct RustGenerator {
     module_name: Option<String>,
+    /// Paths of the modules defining the types of the bindings, from the root of their crate
+    bindings_paths: Vec<String>,
 }
 
 impl RustGenerator {
     pub fn new(module_name: Option<String>) -> Self {
-        RustGenerator { module_name }
+        RustGenerator {
+            module_name,
+            bindings_paths: vec!["$crate".to_string()],
+        }
     }
 }
 
 impl<T: Write> Generator<T> for RustGenerator {
+    fn prelude_types(&self) -> &'static [&'static str] {
+        Self::PRELUDE_TYPES
+    }
+
     fn generate(
         &self,
         writer: &mut T,
-        module_witx: witx::Module,
+        module_witx: &witx::Module,
         options: &Options,
+        n: &Namer,
     ) -> Result<(), Error> {
         let mut w = PrettyWriter::new(writer, "    ");
         let module_name = match &self.module_name {
             None => module_witx.name().as_str().to_string(),
             Some(module_name) => module_name.to_string(),
         };
+        let selection = Selection::new(module_witx, &options.filter);
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
         if !options.skip_header {
-            Self::header(&mut w)?;
+            Self::header(&mut w, options.no_std)?;
         }
 
         let module_title_comments = format!(
@@ -48,37 +74,71 @@ impl<T: Write> Generator<T> for RustGenerator {
         Self::write_comments(&mut w, &module_title_comments)?;
         w.eob()?;
 
-        for type_ in module_witx.typenames() {
+        for resource in resources_to_define(module_witx, &selection) {
+            if skip_imports && &resource.resource_id.module_id != module_id {
+                continue;
+            }
+            let resource_name = resource.resource_id.name.as_str();
+            Self::write_docs(&mut w, &resource.docs)?;
+            Self::define_resource(&mut w, n, resource_name)?;
+            w.eob()?;
+        }
+
+        for type_ in &selection.typenames {
             if skip_imports && &type_.module != module_id {
                 continue;
             }
             let constants_for_type: Vec<_> = module_witx
                 .constants()
-                .into_iter()
                 .filter_map(|x| {
                     if x.ty == type_.name {
                         Some(ASConstant {
                             name: x.name.as_str().to_string(),
                             value: x.value,
+                            docs: x.docs.clone(),
                         })
                     } else {
                         None
                     }
                 })
                 .collect();
-            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+            Self::define_type(&mut w, n, type_.as_ref(), &constants_for_type, options)?;
+        }
+
+        let error_enum = errno::error_enum(&selection)?;
+        let error_name = error_enum
+            .as_ref()
+            .map(|_| format!("{}_error", module_name).as_type(n));
+        if let Some((errno_name, errno)) = &error_enum {
+            Self::define_error_enum(&mut w, n, &module_name, errno_name, errno, options.no_std)?;
         }
 
-        for func in module_witx.funcs() {
-            Self::define_func(&mut w, &module_name, func.as_ref())?;
+        for func in selection.funcs.iter().filter(|func| !is_export(func)) {
+            Self::define_func(
+                &mut w,
+                n,
+                &module_name,
+                func.as_ref(),
+                error_name.as_deref(),
+                options.no_std,
+            )?;
         }
 
+        // Mock implementations are registered in thread-local storage, which requires `std`
+        if !options.no_std {
+            Self::define_mock(&mut w, n, &module_name, error_name.as_deref(), &selection)?;
+        }
+        Self::define_exports(&mut w, n, &module_name, &self.bindings_paths, &selection)?;
+
+        Self::define_handle_wrappers(&mut w, n, module_witx, &selection)?;
+
         Ok(())
     }
 }
 
 impl RustGenerator {
     fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
         if docs.is_empty() {
             return Ok(());
         }
@@ -89,6 +149,7 @@ impl RustGenerator {
     }
 
     fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
         if docs.is_empty() {
             return Ok(());
         }
@@ -100,51 +161,86 @@ impl RustGenerator {
 
     fn define_as_alias<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         other_type: &ASType,
     ) -> Result<(), Error> {
         w.write_line(format!(
             "pub type {} = {};",
-            name.as_type(),
-            other_type.as_lang()
+            name.as_type(n),
+            other_type.as_lang(n)
         ))?;
         Ok(())
     }
 
     fn define_as_atom<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         type_: &ASType,
     ) -> Result<(), Error> {
         w.write_line(format!(
             "pub type {} = {};",
-            name.as_type(),
-            type_.as_lang()
+            name.as_type(n),
+            type_.as_lang(n)
         ))?;
         Ok(())
     }
 
+    fn define_resource<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        resource_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line("#[repr(transparent)]")?
+            .write_line("#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]")?
+            .write_line(format!(
+                "pub struct {}(pub WasiHandle);",
+                resource_name.as_type(n)
+            ))?;
+        Ok(())
+    }
+
     fn define_as_enum<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         enum_: &ASEnum,
+        options: &Options,
     ) -> Result<(), Error> {
         let repr = enum_.repr.as_ref();
-        w.write_line(format!("pub type {} = {};", name.as_type(), repr.as_lang()))?;
+        w.write_line(format!(
+            "pub type {} = {};",
+            name.as_type(n),
+            repr.as_lang(n)
+        ))?;
         w.eob()?;
         w.write_line("#[allow(non_snake_case)]")?;
-        w.write_line(format!("pub mod {} {{", name.as_namespace()))?;
+        w.write_line(format!("pub mod {} {{", name.as_namespace(n)))?;
         {
             let mut w = w.new_block();
-            w.write_line(format!("use super::{};", name.as_type()))?;
+            w.write_line(format!("use super::{};", name.as_type(n)))?;
             for choice in &enum_.choices {
+                Self::write_docs(&mut w, &choice.docs)?;
                 w.write_line(format!(
                     "pub const {}: {} = {};",
-                    choice.name.as_const(),
-                    name.as_type(),
+                    choice.name.as_const(n),
+                    name.as_type(n),
                     choice.value
                 ))?;
             }
+            let values: Vec<_> = enum_
+                .choices
+                .iter()
+                .map(|choice| choice.name.as_const(n))
+                .collect();
+            Self::define_values(&mut w, n, name, &values, options)?;
+            if options.serde {
+                Self::define_enum_serde(&mut w, n, name, enum_)?;
+            }
+            if options.arbitrary {
+                Self::define_enum_arbitrary(&mut w, n, name)?;
+            }
         }
         w.write_line("}")?;
         Ok(())
@@ -152,20 +248,28 @@ impl RustGenerator {
 
     fn define_as_constants<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         constants: &ASConstants,
+        options: &Options,
     ) -> Result<(), Error> {
         let repr = constants.repr.as_ref();
-        w.write_line(format!("pub type {} = {};", name.as_type(), repr.as_lang()))?;
+        w.write_line(format!(
+            "pub type {} = {};",
+            name.as_type(n),
+            repr.as_lang(n)
+        ))?;
         w.eob()?;
-        Self::define_constants_for_type(w, name, &constants.constants)?;
+        Self::define_constants_for_type(w, n, name, &constants.constants, true, options)?;
         Ok(())
     }
 
     fn define_as_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         type_: &ASType,
+        options: &Options,
     ) -> Result<(), Error> {
         match type_ {
             ASType::Alias(_)
@@ -183,16 +287,18 @@ impl RustGenerator {
             | ASType::S32
             | ASType::S64
             | ASType::USize
-            | ASType::Handle(_)
             | ASType::Slice(_)
             | ASType::String(_)
             | ASType::ReadBuffer(_)
-            | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
-            ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
-            ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
-            ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
-            ASType::Tuple(members) => Self::define_as_tuple(w, name, members)?,
-            ASType::Struct(members) => Self::define_as_struct(w, name, members)?,
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, n, name, type_)?,
+            ASType::Handle(_) => Self::define_resource(w, n, name)?,
+            ASType::Enum(enum_) => Self::define_as_enum(w, n, name, enum_, options)?,
+            ASType::Union(union_) => Self::define_as_union(w, n, name, union_, options)?,
+            ASType::Constants(constants) => {
+                Self::define_as_constants(w, n, name, constants, options)?
+            }
+            ASType::Tuple(members) => Self::define_as_tuple(w, n, name, members, options)?,
+            ASType::Struct(members) => Self::define_as_struct(w, n, name, members, options)?,
             _ => {
                 dbg!(type_);
                 unimplemented!();
@@ -203,17 +309,20 @@ impl RustGenerator {
 
     fn define_constants_for_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         type_name: &str,
         constants: &[ASConstant],
+        is_flags: bool,
+        options: &Options,
     ) -> Result<(), Error> {
         if constants.is_empty() {
             return Ok(());
         }
         w.write_line("#[allow(non_snake_case)]")?;
-        w.write_line(format!("pub mod {} {{", type_name.as_namespace()))?;
+        w.write_line(format!("pub mod {} {{", type_name.as_namespace(n)))?;
         {
             let mut w = w.new_block();
-            w.write_line(format!("use super::{};", type_name.as_type()))?;
+            w.write_line(format!("use super::{};", type_name.as_type(n)))?;
 
             let mut hex = false;
             let mut single_bits: usize = 0;
@@ -234,23 +343,67 @@ impl RustGenerator {
                 } else {
                     format!("{}", constant.value)
                 };
+                Self::write_docs(&mut w, &constant.docs)?;
                 w.write_line(format!(
                     "pub const {}: {} = {};",
-                    constant.name.as_const(),
-                    type_name.as_type(),
+                    constant.name.as_const(n),
+                    type_name.as_type(n),
                     value_s
                 ))?;
             }
+            if is_flags {
+                let values: Vec<_> = constants
+                    .iter()
+                    .map(|constant| constant.name.as_const(n))
+                    .collect();
+                Self::define_values(&mut w, n, type_name, &values, options)?;
+                if options.serde {
+                    Self::define_flags_serde(&mut w, n, type_name, constants)?;
+                }
+                if options.arbitrary {
+                    Self::define_flags_arbitrary(&mut w, n, type_name)?;
+                }
+            }
         }
         w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Define `VALUES`, the list of the cases of an enumeration or of the flags of a set, in its
+    /// namespace, when the helpers using it are enabled.
+    fn define_values<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        type_name: &str,
+        values: &[String],
+        options: &Options,
+    ) -> Result<(), Error> {
+        let features: Vec<_> = [("serde", options.serde), ("arbitrary", options.arbitrary)]
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(feature, _)| format!("feature = \"{}\"", feature))
+            .collect();
+        let cfg = match features.len() {
+            0 => return Ok(()),
+            1 => features[0].clone(),
+            _ => format!("any({})", features.join(", ")),
+        };
         w.eob()?;
+        w.write_line(format!("#[cfg({})]", cfg))?;
+        w.write_line(format!(
+            "const VALUES: &[{}] = &[{}];",
+            type_name.as_type(n),
+            values.join(", ")
+        ))?;
         Ok(())
     }
 
     fn define_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         type_witx: &witx::NamedType,
         constants: &[ASConstant],
+        options: &Options,
     ) -> Result<(), Error> {
         let docs = &type_witx.docs;
         if !docs.is_empty() {
@@ -260,15 +413,18 @@ impl RustGenerator {
         let tref = &type_witx.tref;
         match tref {
             witx::TypeRef::Name(other_type) => {
-                Self::define_as_alias(w, type_name, &ASType::from(&other_type.tref))?
+                Self::define_as_alias(w, n, type_name, &ASType::from(&other_type.tref))?
             }
             witx::TypeRef::Value(type_witx) => {
                 let t = ASType::from(type_witx.as_ref());
-                Self::define_as_type(w, type_name, &t)?
+                Self::define_as_type(w, n, type_name, &t, options)?
             }
         }
         w.eob()?;
-        Self::define_constants_for_type(w, type_name, constants)?;
+        if !constants.is_empty() {
+            Self::define_constants_for_type(w, n, type_name, constants, false, options)?;
+            w.eob()?;
+        }
         Ok(())
     }
 }
@@ -0,0 +1,248 @@
+use super::*;
+use std::io::Write;
+
+/// Derive `Serialize` and `Deserialize` when the `serde` feature is enabled.
+pub const SERDE_DERIVE: &str =
+    "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]";
+
+/// Leave the padding fields out of the serialized form.
+pub const SERDE_SKIP: &str = "#[cfg_attr(feature = \"serde\", serde(skip))]";
+
+/// Return the attribute serializing a field of type `type_` by name, if it is an enumeration or
+/// a set of flags. Those are plain integers in Rust, so their namespace provides the functions
+/// to use with `#[serde(with = ...)]`.
+pub fn serde_with_attribute(n: &Namer, type_: &ASType) -> Option<String> {
+    enum_namespace(n, type_).map(|namespace| {
+        format!(
+            "#[cfg_attr(feature = \"serde\", serde(with = \"{}\"))]",
+            namespace
+        )
+    })
+}
+
+/// Return the attribute serializing a field or a variant as `name` if its Rust name differs.
+pub fn serde_rename_attribute(name: &str, rust_name: &str) -> Option<String> {
+    if rust_name.trim_start_matches("r#") == name {
+        return None;
+    }
+    Some(format!(
+        "#[cfg_attr(feature = \"serde\", serde(rename = \"{}\"))]",
+        name
+    ))
+}
+
+impl RustGenerator {
+    /// Define, in the namespace of an enumeration or a set of flags, `CASES`, the WITX names
+    /// of the values of `VALUES`.
+    fn define_serde_cases<'t, T: Write>(
+        w: &mut PrettyWriter<T>,
+        cases: impl Iterator<Item = &'t str>,
+    ) -> Result<(), Error> {
+        w.write_line("#[cfg(feature = \"serde\")]")?;
+        w.write_line(format!(
+            "const CASES: &[&str] = &[{}];",
+            cases
+                .map(|case| format!("{:?}", case))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))?;
+        Ok(())
+    }
+
+    /// Define a visitor type deserializing a case name into `value_type`, as `value`, an
+    /// expression of the value of the case, `VALUES[i]`.
+    fn define_case_visitor<T: Write>(
+        w: &mut PrettyWriter<T>,
+        visitor_name: &str,
+        value_type: &str,
+        expecting: &str,
+        value: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!("struct {};", visitor_name))?;
+        w.write_line(format!(
+            "impl serde::de::Visitor<'_> for {} {{",
+            visitor_name
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("type Value = {};", value_type))?;
+            w.write_line(
+                "fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {",
+            )?;
+            w.new_block()
+                .write_line(format!("f.write_str({:?})", expecting))?;
+            w.write_line("}")?;
+            w.write_line(format!(
+                "fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<{}, E> {{",
+                value_type
+            ))?;
+            w.new_block().write_lines(format!(
+                "match CASES.iter().position(|case| *case == name) {{
+    Some(i) => Ok({}),
+    None => Err(E::unknown_variant(name, CASES)),
+}}",
+                value
+            ))?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Define `serialize()` and `deserialize()` in the namespace of an enumeration, to
+    /// (de)serialize its values as the WITX names of their cases.
+    pub fn define_enum_serde<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        enum_: &ASEnum,
+    ) -> Result<(), Error> {
+        let type_name = name.as_type(n);
+        Self::define_serde_cases(w, enum_.choices.iter().map(|choice| choice.name.as_str()))?;
+        w.eob()?;
+
+        w.write_line(format!(
+            "/// Serialize a `{}` as the name of its case, with `#[serde(with = \"{}\")]`.",
+            type_name,
+            name.as_namespace(n)
+        ))?;
+        w.write_line("#[cfg(feature = \"serde\")]")?;
+        w.write_line(format!(
+            "pub fn serialize<S: serde::Serializer>(value: &{}, serializer: S) -> Result<S::Ok, S::Error> {{",
+            type_name
+        ))?;
+        w.new_block().write_lines(format!(
+            "match VALUES.iter().position(|case| case == value) {{
+    Some(i) => serializer.serialize_str(CASES[i]),
+    None => Err(serde::ser::Error::custom(\"Invalid value for {}\")),
+}}",
+            type_name
+        ))?;
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!(
+            "/// Deserialize a `{}` from the name of its case, with `#[serde(with = \"{}\")]`.",
+            type_name,
+            name.as_namespace(n)
+        ))?;
+        w.write_line("#[cfg(feature = \"serde\")]")?;
+        w.write_line(format!(
+            "pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<{}, D::Error> {{",
+            type_name
+        ))?;
+        {
+            let mut w = w.new_block();
+            Self::define_case_visitor(
+                &mut w,
+                "CaseVisitor",
+                &type_name,
+                &format!("a case of {}", type_name),
+                "VALUES[i]",
+            )?;
+            w.write_line("deserializer.deserialize_str(CaseVisitor)")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Define `serialize()` and `deserialize()` in the namespace of a set of flags, to
+    /// (de)serialize them as the list of the WITX names of the flags that are set.
+    pub fn define_flags_serde<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        constants: &[ASConstant],
+    ) -> Result<(), Error> {
+        let type_name = name.as_type(n);
+        Self::define_serde_cases(w, constants.iter().map(|constant| constant.name.as_str()))?;
+        w.eob()?;
+
+        w.write_line(format!(
+            "/// Serialize a `{}` as the list of the names of the flags that are set, with `#[serde(with = \"{}\")]`.",
+            type_name,
+            name.as_namespace(n)
+        ))?;
+        w.write_line("#[cfg(feature = \"serde\")]")?;
+        w.write_line(format!(
+            "pub fn serialize<S: serde::Serializer>(value: &{}, serializer: S) -> Result<S::Ok, S::Error> {{",
+            type_name
+        ))?;
+        w.new_block().write_lines(format!(
+            "use serde::ser::SerializeSeq;
+if VALUES.iter().fold(*value, |rest, flag| rest & !flag) != 0 {{
+    return Err(serde::ser::Error::custom(\"Invalid flags for {}\"));
+}}
+let set = || CASES.iter().zip(VALUES).filter(|(_, flag)| *value & **flag != 0);
+let mut seq = serializer.serialize_seq(Some(set().count()))?;
+for (case, _) in set() {{
+    seq.serialize_element(case)?;
+}}
+seq.end()",
+            type_name
+        ))?;
+        w.write_line("}")?.eob()?;
+
+        w.write_line(format!(
+            "/// Deserialize a `{}` from the list of the names of the flags that are set, with `#[serde(with = \"{}\")]`.",
+            type_name,
+            name.as_namespace(n)
+        ))?;
+        w.write_line("#[cfg(feature = \"serde\")]")?;
+        w.write_line(format!(
+            "pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<{}, D::Error> {{",
+            type_name
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("struct Flag({});", type_name))?;
+            w.write_line("impl<'de> serde::Deserialize<'de> for Flag {")?;
+            {
+                let mut w = w.new_block();
+                w.write_line(
+                    "fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {",
+                )?;
+                {
+                    let mut w = w.new_block();
+                    Self::define_case_visitor(
+                        &mut w,
+                        "FlagVisitor",
+                        "Flag",
+                        &format!("a flag of {}", type_name),
+                        "Flag(VALUES[i])",
+                    )?;
+                    w.write_line("deserializer.deserialize_str(FlagVisitor)")?;
+                }
+                w.write_line("}")?;
+            }
+            w.write_line("}")?;
+            w.write_line("struct FlagsVisitor;")?;
+            w.write_line("impl<'de> serde::de::Visitor<'de> for FlagsVisitor {")?;
+            {
+                let mut w = w.new_block();
+                w.write_line(format!("type Value = {};", type_name))?;
+                w.write_line(
+                    "fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {",
+                )?;
+                w.new_block()
+                    .write_line(format!("f.write_str(\"a list of flags of {}\")", type_name))?;
+                w.write_line("}")?;
+                w.write_line(format!(
+                    "fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<{}, A::Error> {{",
+                    type_name
+                ))?;
+                w.new_block().write_lines(
+                    "let mut value = 0;
+while let Some(Flag(flag)) = seq.next_element()? {
+    value |= flag;
+}
+Ok(value)",
+                )?;
+                w.write_line("}")?;
+            }
+            w.write_line("}")?;
+            w.write_line("deserializer.deserialize_seq(FlagsVisitor)")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+}
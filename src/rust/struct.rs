@@ -1,41 +1,126 @@
 use super::*;
+use crate::layout::size_align;
 use std::io::Write;
 
 impl RustGenerator {
     pub fn define_as_struct<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         members: &[ASStructMember],
+        options: &Options,
     ) -> Result<(), Error> {
-        w.write_line("#[repr(C, packed)]")?
-            .write_line("#[derive(Copy,Clone)]")?
-            .write_line(format!("pub struct {} {{", name.as_type()))?;
+        let layout = size_align(&ASType::Struct(members.to_vec()), name)?;
+        let repr = RecordRepr::new(
+            members
+                .iter()
+                .map(|member| (member.offset, member.type_.as_ref(), member.padding)),
+            layout.align,
+        )?;
+        let derives = if members.iter().all(|member| implements_debug(&member.type_)) {
+            "Copy, Clone, Debug"
+        } else {
+            "Copy, Clone"
+        };
+        let plain_value = members.iter().all(|member| is_plain_value(&member.type_));
+        let serde = options.serde && plain_value;
+        w.write_line(repr.as_attribute())?
+            .write_line(format!("#[derive({})]", derives))?;
+        if serde {
+            w.write_line(SERDE_DERIVE)?;
+        }
+        w.write_line(format!("pub struct {} {{", name.as_type(n)))?;
         {
             let mut w = w.new_block();
-            for member in members {
+            for (i, member) in members.iter().enumerate() {
                 let member_type = member.type_.as_ref();
+                Self::write_docs(&mut w, &member.docs)?;
+                if serde {
+                    let attributes = serde_rename_attribute(&member.name, &member.name.as_var(n))
+                        .into_iter()
+                        .chain(serde_with_attribute(n, member_type));
+                    for attribute in attributes {
+                        w.write_line(attribute)?;
+                    }
+                }
                 w.write_line(format!(
                     "pub {}: {},",
-                    member.name.as_var(),
-                    member_type.as_lang()
+                    member.name.as_var(n),
+                    member_type.as_lang(n)
                 ))?;
 
-                let pad_len = member.padding;
-                for i in 0..(pad_len & 1) {
-                    w.write_line(format!("__pad8_{}: u8,", i))?;
-                }
-                for i in 0..(pad_len & 3) / 2 {
-                    w.write_line(format!("__pad16_{}: u16,", i))?;
-                }
-                for i in 0..(pad_len & 7) / 4 {
-                    w.write_line(format!("__pad32_{}: u32,", i))?;
-                }
-                for i in 0..pad_len / 8 {
-                    w.write_line(format!("__pad64_{}: u64,", i))?;
+                if repr == RecordRepr::Packed {
+                    for pad_bits in padding_fields(member.padding) {
+                        if serde {
+                            w.write_line(SERDE_SKIP)?;
+                        }
+                        w.write_line(format!("__pad{}_{}: u{},", pad_bits, i, pad_bits))?;
+                    }
                 }
             }
         }
         w.write_line("}")?.eob()?;
+
+        let offsets: Vec<_> = members
+            .iter()
+            .map(|member| (member.name.as_var(n), member.offset))
+            .collect();
+        let pointer_sized = members
+            .iter()
+            .any(|member| depends_on_pointer_size(&member.type_));
+        Self::define_layout_asserts(w, n, name, &offsets, layout, repr, pointer_sized)?;
+
+        if options.arbitrary && plain_value {
+            let mut fields = vec![];
+            for (i, member) in members.iter().enumerate() {
+                fields.push((member.name.as_var(n), arbitrary_value(n, &member.type_)));
+                if repr == RecordRepr::Packed {
+                    for pad_bits in padding_fields(member.padding) {
+                        fields.push((format!("__pad{}_{}", pad_bits, i), "0".to_string()));
+                    }
+                }
+            }
+            Self::define_record_arbitrary(w, n, name, &fields)?;
+        }
+        Ok(())
+    }
+
+    /// Check at compile time that the size, alignment and member offsets of a struct match the
+    /// WITX layout. Layouts depending on the size of pointers are only checked on WebAssembly.
+    pub fn define_layout_asserts<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        offsets: &[(String, usize)],
+        layout: witx::SizeAlign,
+        repr: RecordRepr,
+        pointer_sized: bool,
+    ) -> Result<(), Error> {
+        let name = name.as_type(n);
+        if pointer_sized {
+            w.write_line("#[cfg(target_arch = \"wasm32\")]")?;
+        }
+        w.write_line("const _: () = {")?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!(
+                "assert!(core::mem::size_of::<{}>() == {});",
+                name, layout.size
+            ))?;
+            if repr != RecordRepr::Packed {
+                w.write_line(format!(
+                    "assert!(core::mem::align_of::<{}>() == {});",
+                    name, layout.align
+                ))?;
+            }
+            for (member_name, offset) in offsets {
+                w.write_line(format!(
+                    "assert!(core::mem::offset_of!({}, {}) == {});",
+                    name, member_name, offset
+                ))?;
+            }
+        }
+        w.write_line("};")?;
         Ok(())
     }
 }
@@ -1,16 +1,17 @@
 use super::*;
+use crate::layout::size_align;
 use std::io::Write;
 
 pub struct Tuple;
 
 impl Tuple {
-    pub fn name_for(tuple_members: &[ASTupleMember]) -> String {
+    pub fn name_for(n: &Namer, tuple_members: &[ASTupleMember]) -> String {
         format!(
             "WasiTuple{}{}",
             tuple_members.len(),
             tuple_members
                 .iter()
-                .map(|member| member.type_.to_string())
+                .map(|member| member.type_.as_lang(n))
                 .collect::<Vec<_>>()
                 .join("_")
         )
@@ -20,34 +21,74 @@ impl Tuple {
 impl RustGenerator {
     pub fn define_as_tuple<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         members: &[ASTupleMember],
+        options: &Options,
     ) -> Result<(), Error> {
-        w.write_line("#[repr(C, packed)]")?
-            .write_line("#[derive(Copy, Clone, Debug)]")?
-            .write_line(format!("pub struct {} {{ // -- Tuple", name.as_type()))?;
+        let layout = size_align(&ASType::Tuple(members.to_vec()), name)?;
+        let repr = RecordRepr::new(
+            members
+                .iter()
+                .map(|member| (member.offset, member.type_.as_ref(), member.padding)),
+            layout.align,
+        )?;
+        let derives = if members.iter().all(|member| implements_debug(&member.type_)) {
+            "Copy, Clone, Debug"
+        } else {
+            "Copy, Clone"
+        };
+        let plain_value = members.iter().all(|member| is_plain_value(&member.type_));
+        let serde = options.serde && plain_value;
+        w.write_line(repr.as_attribute())?
+            .write_line(format!("#[derive({})]", derives))?;
+        if serde {
+            w.write_line(SERDE_DERIVE)?;
+        }
+        w.write_line(format!("pub struct {} {{ // -- Tuple", name.as_type(n)))?;
         {
             let mut w = w.new_block();
             for (i, member) in members.iter().enumerate() {
                 let member_type = member.type_.as_ref();
-                w.write_line(format!("pub v{}: {},", i, member_type.as_lang()))?;
-
-                let pad_len = member.padding;
-                for i in 0..(pad_len & 1) {
-                    w.write_line(format!("__pad8_{}: u8,", i))?;
+                if let Some(attribute) = serde_with_attribute(n, member_type).filter(|_| serde) {
+                    w.write_line(attribute)?;
                 }
-                for i in 0..(pad_len & 3) / 2 {
-                    w.write_line(format!("__pad16_{}: u16,", i))?;
-                }
-                for i in 0..(pad_len & 7) / 4 {
-                    w.write_line(format!("__pad32_{}: u32,", i))?;
-                }
-                for i in 0..pad_len / 8 {
-                    w.write_line(format!("__pad64_{}: u64,", i))?;
+                w.write_line(format!("pub v{}: {},", i, member_type.as_lang(n)))?;
+
+                if repr == RecordRepr::Packed {
+                    for pad_bits in padding_fields(member.padding) {
+                        if serde {
+                            w.write_line(SERDE_SKIP)?;
+                        }
+                        w.write_line(format!("__pad{}_{}: u{},", pad_bits, i, pad_bits))?;
+                    }
                 }
             }
         }
         w.write_line("}")?.eob()?;
+
+        let offsets: Vec<_> = members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| (format!("v{}", i), member.offset))
+            .collect();
+        let pointer_sized = members
+            .iter()
+            .any(|member| depends_on_pointer_size(&member.type_));
+        Self::define_layout_asserts(w, n, name, &offsets, layout, repr, pointer_sized)?;
+
+        if options.arbitrary && plain_value {
+            let mut fields = vec![];
+            for (i, member) in members.iter().enumerate() {
+                fields.push((format!("v{}", i), arbitrary_value(n, &member.type_)));
+                if repr == RecordRepr::Packed {
+                    for pad_bits in padding_fields(member.padding) {
+                        fields.push((format!("__pad{}_{}", pad_bits, i), "0".to_string()));
+                    }
+                }
+            }
+            Self::define_record_arbitrary(w, n, name, &fields)?;
+        }
         Ok(())
     }
 }
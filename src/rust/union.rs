@@ -1,9 +1,11 @@
 use super::*;
+use crate::layout::size_align;
 use std::io::Write;
 
 impl RustGenerator {
     fn define_union_member_accessors<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         _union_name: &str,
         i: usize,
         member: &ASUnionMember,
@@ -14,6 +16,7 @@ impl RustGenerator {
 
         if member_is_void {
             // new_*
+            Self::write_docs(w, &member.docs)?;
             w.write_line(format!("pub fn new_{}() -> Self {{", name.as_fn_suffix(),))?;
             {
                 let mut w = w.new_block();
@@ -23,18 +26,19 @@ impl RustGenerator {
         } else {
             // !member_is_void
             // new_*
+            Self::write_docs(w, &member.docs)?;
             w.write_line(format!(
                 "pub fn new_{}(val: {}) -> Self {{",
                 name.as_fn_suffix(),
-                member.type_.as_lang()
+                member.type_.as_lang(n)
             ))?;
             {
                 let mut w = w.new_block();
                 w.write_line(format!("let mut tu = Self::new({});", i))?;
                 w.write_line(format!(
-                    "tu.member = std::mem::MaybeUninit::new({} {{ {}: val }});",
-                    inner_name.as_type(),
-                    member.name.as_var()
+                    "tu.member = core::mem::MaybeUninit::new({} {{ {}: val }});",
+                    inner_name.as_type(n),
+                    member.name.as_var(n)
                 ))?;
                 w.write_line("tu")?;
             }
@@ -44,14 +48,14 @@ impl RustGenerator {
             w.write_line(format!(
                 "pub fn into_{}(self) -> {} {{",
                 name.as_fn_suffix(),
-                member.type_.as_lang()
+                member.type_.as_lang(n)
             ))?;
             {
                 let mut w = w.new_block();
                 w.write_line(format!("assert_eq!(self.tag, {});", i))?;
                 w.write_line(format!(
                     "unsafe {{ self.member.assume_init().{} }}",
-                    member.name.as_var()
+                    member.name.as_var(n)
                 ))?;
             }
             w.write_line("}")?.eob()?;
@@ -60,17 +64,17 @@ impl RustGenerator {
             w.write_line(format!(
                 "pub fn set_{}(&mut self, val: {}) {{",
                 name.as_fn_suffix(),
-                member.type_.as_lang()
+                member.type_.as_lang(n)
             ))?;
             {
                 let mut w = w.new_block();
                 w.write_line(format!("assert_eq!(self.tag, {});", i))?;
                 w.write_line(format!(
                     "let uval = {} {{ {}: val }};",
-                    inner_name.as_type(),
-                    member.name.as_var()
+                    inner_name.as_type(n),
+                    member.name.as_var(n)
                 ))?;
-                w.write_line("unsafe { *self.member.as_mut_ptr() = uval };")?;
+                w.write_line("self.member = core::mem::MaybeUninit::new(uval);")?;
             }
             w.write_line("}")?.eob()?;
         }
@@ -84,13 +88,14 @@ impl RustGenerator {
             let mut w = w.new_block();
             w.write_line(format!("self.tag == {}", i))?;
         }
-        w.write_line("}")?.eob()?;
+        w.write_line("}")?;
 
         Ok(())
     }
 
     fn define_union_member<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         union_name: &str,
         i: usize,
         member: &ASUnionMember,
@@ -101,33 +106,45 @@ impl RustGenerator {
             ASType::Void => {
                 w.write_line(format!(
                     "// --- {}: (no associated content) if tag={}",
-                    member.name.as_var(),
+                    member.name.as_var(n),
                     i
                 ))?;
             }
             _ => {
                 w.write_line(format!(
                     "// --- {}: {} if tag={}",
-                    member.name.as_var(),
-                    member_type.as_lang(),
+                    member.name.as_var(n),
+                    member_type.as_lang(n),
                     i
                 ))?;
             }
         }
         w.eob()?;
-        Self::define_union_member_accessors(w, union_name, i, member, inner_name)?;
+        Self::define_union_member_accessors(w, n, union_name, i, member, inner_name)?;
         Ok(())
     }
 
     pub fn define_as_union<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         union_: &ASUnion,
+        options: &Options,
     ) -> Result<(), Error> {
         let tag_repr = union_.tag_repr.as_ref();
+        let plain_value = is_plain_value(&ASType::Union(union_.clone()));
+        let serde = options.serde && plain_value;
         let inner_name = format!("{}_member", name);
+        let layout = size_align(&ASType::Union(union_.clone()), name)?;
+        let natural_align = union_
+            .members
+            .iter()
+            .map(|member| rust_align(&member.type_))
+            .chain(std::iter::once(rust_align(tag_repr)))
+            .try_fold(1, |align, member_align| member_align.map(|x| x.max(align)))?;
         w.write_line("#[repr(C)]")?
-            .write_line(format!("pub union {} {{", inner_name.as_type()))?;
+            .write_line("#[derive(Copy, Clone)]")?
+            .write_line(format!("pub union {} {{", inner_name.as_type(n)))?;
         {
             let mut w = w.new_block();
             for (i, member) in union_.members.iter().enumerate() {
@@ -135,14 +152,15 @@ impl RustGenerator {
                 if member_is_void {
                     w.write_line(format!(
                         "// {} with no associated value if tag={}",
-                        member.name.as_var(),
+                        member.name.as_var(n),
                         i
                     ))?;
                 } else {
+                    Self::write_docs(&mut w, &member.docs)?;
                     w.write_line(format!(
                         "{}: {}, // if tag={}",
-                        member.name.as_var(),
-                        member.type_.as_lang(),
+                        member.name.as_var(n),
+                        member.type_.as_lang(n),
                         i
                     ))?;
                 }
@@ -151,50 +169,341 @@ impl RustGenerator {
         w.write_line("}")?;
         w.eob()?;
 
-        w.write_line("#[repr(C, packed)]")?
-            .write_line(format!("pub struct {} {{", name.as_type()))?;
+        // The padding fields put the member at the WITX offset, even if its natural alignment
+        // is lower
+        if natural_align == layout.align {
+            w.write_line("#[repr(C)]")?;
+        } else {
+            w.write_line(format!("#[repr(C, align({}))]", layout.align))?;
+        }
+        w.write_line("#[derive(Copy, Clone)]")?;
+        if serde {
+            // Deserialized from the enum with the content of the union, and serialized by
+            // `define_union_enums()`, as unions with an invalid tag have no content
+            let enum_name = format!("{}_enum", name).as_type(n);
+            w.write_line("#[cfg_attr(feature = \"serde\", derive(serde::Deserialize))]")?
+                .write_line(format!(
+                    "#[cfg_attr(feature = \"serde\", serde(from = \"{}\"))]",
+                    enum_name
+                ))?;
+        }
+        w.write_line(format!("pub struct {} {{", name.as_type(n)))?;
         {
             let mut w = w.new_block();
-            w.write_line(format!("pub tag: {},", tag_repr.as_lang()))?;
-            let pad_len = union_.padding_after_tag;
-            for i in 0..(pad_len & 1) {
-                w.write_line(format!("__pad8_{}: u8,", i))?;
-            }
-            for i in 0..(pad_len & 3) / 2 {
-                w.write_line(format!("__pad16_{}: u16,", i))?;
-            }
-            for i in 0..(pad_len & 7) / 4 {
-                w.write_line(format!("__pad32_{}: u32,", i))?;
-            }
-            for i in 0..pad_len / 8 {
-                w.write_line(format!("__pad64_{}: u64,", i))?;
+            w.write_line(format!("pub tag: {},", tag_repr.as_lang(n)))?;
+            for pad_bits in padding_fields(union_.padding_after_tag) {
+                w.write_line(format!("__pad{}_0: u{},", pad_bits, pad_bits))?;
             }
             w.write_line(format!(
-                "pub member: std::mem::MaybeUninit<{}>,",
-                inner_name.as_type()
+                "pub member: core::mem::MaybeUninit<{}>,",
+                inner_name.as_type(n)
             ))?;
         }
         w.write_line("}")?;
         w.eob()?;
+        Self::define_layout_asserts(
+            w,
+            n,
+            name,
+            &[
+                ("tag".to_string(), 0),
+                ("member".to_string(), union_.member_offset),
+            ],
+            layout,
+            RecordRepr::Natural,
+            depends_on_pointer_size(&ASType::Union(union_.clone())),
+        )?;
+        w.eob()?;
 
-        w.write_line(format!("impl {} {{", name.as_type()))?;
+        w.write_line(format!("impl {} {{", name.as_type(n)))?;
         {
             let mut w = w.new_block();
-            w.write_line(format!("fn new(tag: {}) -> Self {{", tag_repr.as_lang()))?;
+            w.write_line(format!("fn new(tag: {}) -> Self {{", tag_repr.as_lang(n)))?;
             {
                 let mut w = w.new_block();
-                w.write_line("let mut tu = unsafe { std::mem::zeroed::<Self>() };")?;
+                w.write_line("let mut tu = unsafe { core::mem::zeroed::<Self>() };")?;
                 w.write_line("tu.tag = tag;")?;
                 w.write_line("tu")?;
             }
             w.write_line("}")?.eob()?;
 
+            Self::define_union_as_enum(&mut w, n, name, union_)?;
+
             for (i, member) in union_.members.iter().enumerate() {
                 w.eob()?;
-                Self::define_union_member(&mut w, name, i, member, &inner_name)?;
+                Self::define_union_member(&mut w, n, name, i, member, &inner_name)?;
+            }
+        }
+        w.write_line("}")?;
+
+        Self::define_union_enums(w, n, name, union_, serde)?;
+        if options.arbitrary && plain_value {
+            Self::define_union_arbitrary(w, n, name, union_)?;
+        }
+        Ok(())
+    }
+
+    /// Define the `as_enum()` method, returning a reference to the content of a tagged union.
+    fn define_union_as_enum<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        union_: &ASUnion,
+    ) -> Result<(), Error> {
+        let ref_name = format!("{}_ref", name).as_type(n);
+        w.write_line(
+            "/// Return the content of the union, for all the cases to be handled with `match`, or an",
+        )?;
+        w.write_line("/// error if the tag doesn't match any of them.")?;
+        w.write_line(format!(
+            "pub fn as_enum(&self) -> Result<{}<'_>, WasiInvalidTag> {{",
+            ref_name
+        ))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("match self.tag {")?;
+            {
+                let mut w = w.new_block();
+                for (i, member) in union_.members.iter().enumerate() {
+                    let variant = format!("{}::{}", ref_name, member.name.as_variant());
+                    match member.type_.as_ref() {
+                        ASType::Void => w.write_line(format!("{} => Ok({}),", i, variant))?,
+                        _ => w.write_line(format!(
+                            "{} => Ok({}(unsafe {{ &self.member.assume_init_ref().{} }})),",
+                            i,
+                            variant,
+                            member.name.as_var(n)
+                        ))?,
+                    };
+                }
+                w.write_line("tag => Err(WasiInvalidTag(u64::from(tag))),")?;
+            }
+            w.write_line("}")?;
+        }
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Define an enum with the content of a tagged union, an enum with references to it, and
+    /// conversions between them and the union.
+    fn define_union_enums<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        name: &str,
+        union_: &ASUnion,
+        serde: bool,
+    ) -> Result<(), Error> {
+        let union_name = name.as_type(n);
+        let enum_name = format!("{}_enum", name).as_type(n);
+        let ref_name = format!("{}_ref", name).as_type(n);
+        let derives = if implements_debug(&ASType::Union(union_.clone())) {
+            "Copy, Clone, Debug"
+        } else {
+            "Copy, Clone"
+        };
+        let members = &union_.members;
+        let is_void = |member: &ASUnionMember| matches!(member.type_.as_ref(), ASType::Void);
+
+        // Only the owned enum is (de)serialized, using the WITX names of the cases
+        for (type_name, docs, payload_prefix, lifetime, serde) in [
+            (
+                &enum_name,
+                format!("Content of a `{}`.", union_name),
+                "",
+                "",
+                serde,
+            ),
+            (
+                &ref_name,
+                format!("Reference to the content of a `{}`.", union_name),
+                "&'a ",
+                "<'a>",
+                false,
+            ),
+        ] {
+            w.eob()?;
+            Self::write_docs(w, &docs)?;
+            w.write_line(format!("#[derive({})]", derives))?;
+            if serde {
+                w.write_line(SERDE_DERIVE)?;
+            }
+            w.write_line(format!("pub enum {}{} {{", type_name, lifetime))?;
+            {
+                let mut w = w.new_block();
+                for member in members {
+                    Self::write_docs(&mut w, &member.docs)?;
+                    let variant = member.name.as_variant();
+                    if let Some(attribute) =
+                        serde_rename_attribute(&member.name, &variant).filter(|_| serde)
+                    {
+                        w.write_line(attribute)?;
+                    }
+                    if is_void(member) {
+                        w.write_line(format!("{},", variant))?;
+                    } else {
+                        let with_str = match serde_with_attribute(n, &member.type_) {
+                            Some(attribute) if serde => format!("{} ", attribute),
+                            _ => "".to_string(),
+                        };
+                        w.write_line(format!(
+                            "{}({}{}{}),",
+                            variant,
+                            with_str,
+                            payload_prefix,
+                            member.type_.as_lang(n)
+                        ))?;
+                    }
+                }
+            }
+            w.write_line("}")?;
+        }
+
+        let mut enum_to_union = vec!["match content {".to_string()];
+        enum_to_union.extend(members.iter().map(|member| {
+            let variant = member.name.as_variant();
+            let constructor = member.name.as_fn_suffix();
+            if is_void(member) {
+                format!(
+                    "    {}::{} => Self::new_{}(),",
+                    enum_name, variant, constructor
+                )
+            } else {
+                format!(
+                    "    {}::{}(val) => Self::new_{}(val),",
+                    enum_name, variant, constructor
+                )
+            }
+        }));
+        enum_to_union.push("}".to_string());
+        let mut ref_to_enum = vec!["match content {".to_string()];
+        ref_to_enum.extend(members.iter().map(|member| {
+            let variant = member.name.as_variant();
+            if is_void(member) {
+                format!(
+                    "    {}::{} => {}::{},",
+                    ref_name, variant, enum_name, variant
+                )
+            } else {
+                format!(
+                    "    {}::{}(val) => {}::{}(*val),",
+                    ref_name, variant, enum_name, variant
+                )
+            }
+        }));
+        ref_to_enum.push("}".to_string());
+
+        w.eob()?;
+        Self::define_try_from(
+            w,
+            &format!(
+                "<'a> core::convert::TryFrom<&'a {}> for {}<'a>",
+                union_name, ref_name
+            ),
+            &format!("tu: &'a {}", union_name),
+            "tu.as_enum()",
+        )?;
+        w.eob()?;
+        Self::define_from(
+            w,
+            &format!(" From<{}<'_>> for {}", ref_name, enum_name),
+            &format!("content: {}<'_>", ref_name),
+            &ref_to_enum,
+        )?;
+        w.eob()?;
+        Self::define_try_from(
+            w,
+            &format!(" core::convert::TryFrom<{}> for {}", union_name, enum_name),
+            &format!("tu: {}", union_name),
+            "tu.as_enum().map(Self::from)",
+        )?;
+        w.eob()?;
+        Self::define_from(
+            w,
+            &format!(" From<{}> for {}", enum_name, union_name),
+            &format!("content: {}", enum_name),
+            &enum_to_union,
+        )?;
+
+        if serde {
+            w.eob()?;
+            w.write_line("#[cfg(feature = \"serde\")]")?;
+            w.write_line(format!("impl serde::Serialize for {} {{", union_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(
+                    "fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {",
+                )?;
+                w.new_block().write_lines(format!(
+                    "match self.as_enum() {{
+    Ok(content) => {}::from(content).serialize(serializer),
+    Err(invalid_tag) => Err(serde::ser::Error::custom(invalid_tag)),
+}}",
+                    enum_name
+                ))?;
+                w.write_line("}")?;
+            }
+            w.write_line("}")?;
+        }
+
+        if derives.contains("Debug") {
+            w.eob()?;
+            w.write_line(format!("impl core::fmt::Debug for {} {{", union_name))?;
+            {
+                let mut w = w.new_block();
+                w.write_line(
+                    "fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {",
+                )?;
+                w.new_block().write_lines(
+                    "match self.as_enum() {
+    Ok(content) => content.fmt(f),
+    Err(invalid_tag) => invalid_tag.fmt(f),
+}",
+                )?;
+                w.write_line("}")?;
             }
+            w.write_line("}")?;
+        }
+        Ok(())
+    }
+
+    /// Implement `TryFrom`, with `WasiInvalidTag` as the error type, `impl_str` following `impl`
+    /// and `body` in the `try_from()` function.
+    fn define_try_from<T: Write>(
+        w: &mut PrettyWriter<T>,
+        impl_str: &str,
+        param: &str,
+        body: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!("impl{} {{", impl_str))?;
+        {
+            let mut w = w.new_block();
+            w.write_line("type Error = WasiInvalidTag;")?.eob()?;
+            w.write_line(format!(
+                "fn try_from({}) -> Result<Self, Self::Error> {{",
+                param
+            ))?;
+            w.new_block().write_line(body)?;
+            w.write_line("}")?;
         }
-        w.write_line("}")?.eob()?;
+        w.write_line("}")?;
+        Ok(())
+    }
+
+    /// Implement `From`, with `impl_str` following `impl` and `body` in the `from()` function.
+    pub fn define_from<T: Write>(
+        w: &mut PrettyWriter<T>,
+        impl_str: &str,
+        param: &str,
+        body: &[String],
+    ) -> Result<(), Error> {
+        w.write_line(format!("impl{} {{", impl_str))?;
+        {
+            let mut w = w.new_block();
+            w.write_line(format!("fn from({}) -> Self {{", param))?;
+            w.new_block().write_lines(body.join("\n"))?;
+            w.write_line("}")?;
+        }
+        w.write_line("}")?;
         Ok(())
     }
 }
@@ -0,0 +1,206 @@
+use crate::astype::*;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Name patterns selecting the functions and types to generate code for.
+///
+/// Patterns can include `*` (any sequence of characters) and `?` (any character) wildcards.
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+    drop_unused_types: bool,
+}
+
+impl Filter {
+    /// Create a filter selecting the functions and types matching `include` and not matching
+    /// `exclude`. If `drop_unused_types` is set, only types used by the selected functions are kept.
+    pub fn new(include: Vec<String>, exclude: Vec<String>, drop_unused_types: bool) -> Self {
+        Filter {
+            include,
+            exclude,
+            drop_unused_types,
+        }
+    }
+
+    /// Return `true` if `name` matches an inclusion pattern (if there are any), and no exclusion pattern.
+    pub fn matches(&self, name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|x| glob_match(x, name)))
+            && !self.exclude.iter().any(|x| glob_match(x, name))
+    }
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let (pattern, name): (Vec<_>, Vec<_>) = (pattern.chars().collect(), name.chars().collect());
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    backtrack = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Types, resources and functions of a module to generate code for.
+pub struct Selection {
+    pub typenames: Vec<Rc<witx::NamedType>>,
+    pub resources: Vec<Rc<witx::Resource>>,
+    pub funcs: Vec<Rc<witx::Function>>,
+}
+
+impl Selection {
+    /// Select the types, resources and functions whose name matches `filter`, along with all the
+    /// types and resources they depend on.
+    ///
+    /// Types are ordered so that they are defined after the types they depend on. Apart from
+    /// that, the order of the module is preserved.
+    pub fn new(module_witx: &witx::Module, filter: &Filter) -> Self {
+        let funcs: Vec<_> = module_witx
+            .funcs()
+            .filter(|func| filter.matches(func.name.as_str()))
+            .collect();
+        let types: Vec<_> = module_witx
+            .typenames()
+            .map(|type_| (type_.clone(), ASType::from(&type_.tref)))
+            .collect();
+        let mut graph = DependencyGraph::new(&types);
+
+        if !filter.drop_unused_types {
+            for (i, (type_, _)) in types.iter().enumerate() {
+                if filter.matches(type_.name.as_str()) {
+                    graph.select_type(i);
+                }
+            }
+            for resource in module_witx.resources() {
+                let resource_name = resource.resource_id.name.as_str();
+                if filter.matches(resource_name) {
+                    graph.resource_names.insert(resource_name.to_string());
+                }
+            }
+        }
+        for func in &funcs {
+            for param in func.params.iter().chain(func.results.iter()) {
+                graph.select_references(&ASType::from(&param.tref));
+            }
+        }
+
+        let typenames = graph
+            .sorted()
+            .into_iter()
+            .map(|i| types[i].0.clone())
+            .collect();
+        let resources = module_witx
+            .resources()
+            .filter(|resource| {
+                graph
+                    .resource_names
+                    .contains(resource.resource_id.name.as_str())
+            })
+            .cloned()
+            .collect();
+        Selection {
+            typenames,
+            resources,
+            funcs,
+        }
+    }
+
+    pub fn has_func(&self, func_name: &str) -> bool {
+        self.funcs
+            .iter()
+            .any(|func| func.name.as_str() == func_name)
+    }
+}
+
+struct DependencyGraph<'t> {
+    types: &'t [(Rc<witx::NamedType>, ASType)],
+    index: HashMap<&'t str, usize>,
+    selected: Vec<bool>,
+    resource_names: HashSet<String>,
+}
+
+impl<'t> DependencyGraph<'t> {
+    fn new(types: &'t [(Rc<witx::NamedType>, ASType)]) -> Self {
+        let index = types
+            .iter()
+            .enumerate()
+            .map(|(i, (type_, _))| (type_.name.as_str(), i))
+            .collect();
+        DependencyGraph {
+            types,
+            index,
+            selected: vec![false; types.len()],
+            resource_names: HashSet::new(),
+        }
+    }
+
+    /// Select the type at index `i`, as well as all the types and resources it depends on.
+    fn select_type(&mut self, i: usize) {
+        if self.selected[i] {
+            return;
+        }
+        self.selected[i] = true;
+        let types = self.types;
+        self.select_references(&types[i].1);
+    }
+
+    fn select_references(&mut self, type_: &ASType) {
+        for reference in type_.references() {
+            match reference {
+                ASType::Alias(alias) => {
+                    if let Some(&i) = self.index.get(alias.name.as_str()) {
+                        self.select_type(i);
+                    }
+                }
+                ASType::Handle(resource_name) => {
+                    self.resource_names.insert(resource_name.to_string());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Return the indices of the selected types, dependencies first.
+    fn sorted(&self) -> Vec<usize> {
+        let mut visited = vec![false; self.types.len()];
+        let mut sorted = vec![];
+        for i in 0..self.types.len() {
+            if self.selected[i] {
+                self.visit(i, &mut visited, &mut sorted);
+            }
+        }
+        sorted
+    }
+
+    fn visit(&self, i: usize, visited: &mut [bool], sorted: &mut Vec<usize>) {
+        // Types being visited are already marked, so that cycles through pointers terminate
+        if visited[i] {
+            return;
+        }
+        visited[i] = true;
+        for reference in self.types[i].1.references() {
+            if let ASType::Alias(alias) = reference {
+                if let Some(&j) = self.index.get(alias.name.as_str()) {
+                    self.visit(j, visited, sorted);
+                }
+            }
+        }
+        sorted.push(i);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::annotations::EXPORT_ANNOTATION;
+use crate::astype::*;
+use crate::error::*;
+use std::rc::Rc;
+
+/// Return `true` if the function is implemented by the guest and called by the host.
+///
+/// WITX declares all functions the same way, so exported functions are marked with an `@export`
+/// line in their documentation.
+pub fn is_export(func_witx: &witx::Function) -> bool {
+    func_witx
+        .docs
+        .lines()
+        .any(|line| line.trim() == EXPORT_ANNOTATION)
+}
+
+/// Return the documentation of a function, followed by a list of its documented parameters.
+/// Parameter names are converted with `param_name`.
+pub fn func_docs(func_witx: &witx::Function, param_name: impl Fn(&str) -> String) -> String {
+    let mut docs = func_witx.docs.clone();
+    let params: Vec<_> = func_witx
+        .params
+        .iter()
+        .filter(|param| !param.docs.is_empty())
+        .collect();
+    if params.is_empty() {
+        return docs;
+    }
+    docs.truncate(docs.trim_end().len());
+    if !docs.is_empty() {
+        docs.push_str("\n\n");
+    }
+    docs.push_str("# Parameters\n");
+    for param in params {
+        docs.push('\n');
+        for (i, line) in param.docs.lines().enumerate() {
+            if i == 0 {
+                docs.push_str(&format!(
+                    "* `{}`: {}",
+                    param_name(param.name.as_str()),
+                    line
+                ));
+            } else if line.is_empty() {
+                docs.push('\n');
+            } else {
+                docs.push_str(&format!("\n  {}", line));
+            }
+        }
+    }
+    docs
+}
+
+/// What a function returns, as a WebAssembly function.
+pub enum FuncReturn {
+    /// An error code. Values are written to the result pointers passed as additional parameters.
+    Expected(ASResult),
+    /// A single value, returned directly
+    Value(Rc<ASType>),
+    /// Nothing
+    Nothing,
+    /// The function never returns
+    Never,
+}
+
+/// Signature of a function, following the Preview1 ABI.
+pub struct FuncSignature {
+    pub name: String,
+    pub params: Vec<(String, ASType)>,
+    pub params_decomposed: Vec<ASTypeDecomposed>,
+    /// Values returned through pointers, before decomposition
+    pub results: Vec<(String, Rc<ASType>)>,
+    /// Result pointers, passed after the parameters
+    pub results_decomposed: Vec<ASTypeDecomposed>,
+    pub returns: FuncReturn,
+    /// The function is implemented by the guest
+    pub export: bool,
+}
+
+impl FuncSignature {
+    pub fn new(func_witx: &witx::Function) -> Result<Self, Error> {
+        let name = func_witx.name.as_str().to_string();
+        if func_witx.abi != witx::Abi::Preview1 {
+            return Err(Error::Unsupported(format!(
+                "function [{}] doesn't use the Preview1 ABI",
+                name
+            )));
+        }
+        let params_witx = &func_witx.params;
+        let mut params = vec![];
+        for param_witx in params_witx {
+            let param_name = param_witx.name.as_str();
+            let param_type = ASType::from(&param_witx.tref);
+            params.push((param_name.to_string(), param_type));
+        }
+
+        let mut params_decomposed = vec![];
+        for param in &params {
+            let mut decomposed = param.1.decompose(&param.0, false);
+            params_decomposed.append(&mut decomposed);
+        }
+
+        // WITX only accepts a single result with the Preview1 ABI
+        let mut results = vec![];
+        let returns = match func_witx.results.first() {
+            None if func_witx.noreturn => FuncReturn::Never,
+            None => FuncReturn::Nothing,
+            Some(result_witx) => match ASType::from(&result_witx.tref) {
+                ASType::Result(result) => {
+                    let ok_type = result.ok_type.clone();
+                    // A tuple in a result is expanded into additional parameters, transformed to pointers
+                    if let ASType::Tuple(tuple_members) = ok_type.as_ref().leaf() {
+                        for (i, tuple_member) in tuple_members.iter().enumerate() {
+                            let name = format!("result{}_ptr", i);
+                            results.push((name, tuple_member.type_.clone()));
+                        }
+                    } else {
+                        let name = "result_ptr";
+                        results.push((name.to_string(), ok_type));
+                    }
+                    FuncReturn::Expected(result)
+                }
+                result => FuncReturn::Value(Rc::new(result)),
+            },
+        };
+        let mut results_decomposed = vec![];
+        for result in &results {
+            let mut decomposed = result.1.decompose(&result.0, true);
+            results_decomposed.append(&mut decomposed);
+        }
+
+        let returns_value = matches!(returns, FuncReturn::Expected(_) | FuncReturn::Value(_));
+        let signature_witx = func_witx.wasm_signature(witx::CallMode::DefinedImport);
+        let params_count_witx = signature_witx.params.len() + signature_witx.results.len();
+        let params_count =
+            params_decomposed.len() + results_decomposed.len() + returns_value as usize;
+        if params_count != params_count_witx {
+            return Err(Error::Unsupported(format!(
+                "function [{}] has {} parameters in the WebAssembly signature, but {} were generated",
+                name, params_count_witx, params_count
+            )));
+        }
+
+        Ok(FuncSignature {
+            name,
+            params,
+            params_decomposed,
+            results,
+            results_decomposed,
+            returns,
+            export: is_export(func_witx),
+        })
+    }
+}
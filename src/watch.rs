@@ -0,0 +1,82 @@
+use crate::error::*;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Return the names of the modules pulled in by `(use ... from $module)` declarations.
+fn used_modules(source: &str) -> Vec<String> {
+    let source: String = source
+        .lines()
+        .map(|line| line.split(";;").next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut modules = vec![];
+    for (offset, _) in source.match_indices("(use") {
+        let decl = &source[offset + "(use".len()..];
+        let decl = &decl[..decl.find(')').unwrap_or(decl.len())];
+        let mut tokens = decl.split_whitespace();
+        if tokens.by_ref().any(|token| token == "from") {
+            if let Some(module) = tokens.next() {
+                modules.push(module.trim_start_matches('$').to_string());
+            }
+        }
+    }
+    modules
+}
+
+/// Return `witx_files` along with the files they pull in, recursively.
+/// Files that can't be read are still returned, so that they are loaded once they are created.
+pub fn dependencies(witx_files: &[PathBuf]) -> Vec<PathBuf> {
+    let mut files = vec![];
+    let mut seen = HashSet::new();
+    let mut pending = witx_files.to_vec();
+    while let Some(witx_file) = pending.pop() {
+        if !seen.insert(witx_file.clone()) {
+            continue;
+        }
+        if let Ok(source) = fs::read_to_string(&witx_file) {
+            let dir = witx_file.parent().unwrap_or_else(|| Path::new(""));
+            for module in used_modules(&source) {
+                pending.push(dir.join(module).with_extension("witx"));
+            }
+        }
+        files.push(witx_file);
+    }
+    files
+}
+
+/// Modification time and size of each file, if it exists.
+fn snapshot(files: &[PathBuf]) -> Vec<Option<(SystemTime, u64)>> {
+    files
+        .iter()
+        .map(|file| {
+            let metadata = fs::metadata(file).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        })
+        .collect()
+}
+
+/// Run `generate`, then run it again every time one of the files returned by `files` changes.
+///
+/// Errors are printed instead of being returned, so that the files can be fixed without
+/// restarting.
+pub fn watch(
+    files: impl Fn() -> Vec<PathBuf>,
+    mut generate: impl FnMut() -> Result<(), Error>,
+) -> ! {
+    loop {
+        let watched = files();
+        let before = snapshot(&watched);
+        match generate() {
+            Ok(()) => eprintln!("Outputs updated, watching for changes"),
+            Err(e) => eprintln!("{}", e),
+        }
+        while snapshot(&watched) == before {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
@@ -1,52 +1,130 @@
 use super::tuple::Tuple;
 use crate::astype::*;
-use convert_case::{Case, Casing};
+use crate::naming::{to_case, NameKind, Namer};
+use convert_case::Case;
 
-pub trait IsNullable {
-    fn is_nullable(&self) -> bool;
-}
+/// Keywords and primitive values, that can only be used as identifiers with the `@""` syntax
+const KEYWORDS: &[&str] = &[
+    "addrspace",
+    "align",
+    "allowzero",
+    "and",
+    "anyframe",
+    "anytype",
+    "asm",
+    "async",
+    "await",
+    "break",
+    "callconv",
+    "catch",
+    "comptime",
+    "const",
+    "continue",
+    "defer",
+    "else",
+    "enum",
+    "errdefer",
+    "error",
+    "export",
+    "extern",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "inline",
+    "linksection",
+    "noalias",
+    "noinline",
+    "nosuspend",
+    "null",
+    "opaque",
+    "or",
+    "orelse",
+    "packed",
+    "pub",
+    "resume",
+    "return",
+    "struct",
+    "suspend",
+    "switch",
+    "test",
+    "threadlocal",
+    "true",
+    "try",
+    "undefined",
+    "union",
+    "unreachable",
+    "usingnamespace",
+    "var",
+    "volatile",
+    "while",
+    "anyerror",
+    "anyopaque",
+    "bool",
+    "c_int",
+    "c_long",
+    "c_short",
+    "c_uint",
+    "c_ulong",
+    "c_ushort",
+    "comptime_float",
+    "comptime_int",
+    "f16",
+    "f32",
+    "f64",
+    "f80",
+    "f128",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "noreturn",
+    "type",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "void",
+];
 
-impl IsNullable for ASType {
-    fn is_nullable(&self) -> bool {
-        matches!(
-            self,
-            ASType::ConstPtr(_)
-                | ASType::MutPtr(_)
-                | ASType::ReadBuffer(_)
-                | ASType::WriteBuffer(_)
-                | ASType::Enum(_)
-                | ASType::Struct(_)
-                | ASType::Tuple(_)
-                | ASType::Union(_)
-        )
+pub fn escape_keyword(identifier: String) -> String {
+    if KEYWORDS.contains(&identifier.as_str()) {
+        format!("@\"{}\"", identifier)
+    } else {
+        identifier
     }
 }
 
+#[allow(dead_code)]
 pub trait Normalize {
     fn as_str(&self) -> &str;
 
-    fn as_type(&self) -> String {
-        self.as_str().to_case(Case::Pascal)
+    fn as_type(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Type, self.as_str(), Case::Pascal))
     }
 
-    fn as_fn(&self) -> String {
-        self.as_str().to_case(Case::Camel)
+    fn as_fn(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Fn, self.as_str(), Case::Camel))
     }
 
     fn as_fn_suffix(&self) -> String {
-        self.as_str().to_case(Case::UpperCamel)
+        to_case(self.as_str(), Case::UpperCamel)
     }
 
-    fn as_var(&self) -> String {
-        self.as_str().to_case(Case::Snake)
+    fn as_var(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Var, self.as_str(), Case::Snake))
     }
 
-    fn as_const(&self) -> String {
-        self.as_str().to_case(Case::UpperSnake)
+    fn as_const(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Const, self.as_str(), Case::UpperSnake))
     }
 
-    fn as_namespace(&self) -> String {
-        self.as_str().to_string().to_case(Case::Pascal)
+    fn as_namespace(&self, n: &Namer) -> String {
+        escape_keyword(n.convert(NameKind::Namespace, self.as_str(), Case::Pascal))
     }
 }
 
@@ -59,21 +137,17 @@ impl<T: AsRef<str>> Normalize for T {
 pub trait ToLanguageRepresentation {
     fn as_astype(&self) -> &ASType;
 
-    fn to_string(&self) -> String {
-        self.as_lang()
-    }
-
-    fn as_lang(&self) -> String {
+    fn as_lang(&self, n: &Namer) -> String {
         match self.as_astype() {
-            ASType::Alias(alias) => alias.name.as_type(),
+            ASType::Alias(alias) => alias.name.as_type(n),
             ASType::Bool => "bool".to_string(),
             ASType::Char32 => "Char32".to_string(),
             ASType::Char8 => "Char8".to_string(),
             ASType::F32 => "f32".to_string(),
             ASType::F64 => "f64".to_string(),
-            ASType::Handle(_resource_name) => "WasiHandle".to_string(),
-            ASType::ConstPtr(pointee) => format!("WasiPtr({})", pointee.to_string()),
-            ASType::MutPtr(pointee) => format!("WasiMutPtr({})", pointee.to_string()),
+            ASType::Handle(resource_name) => resource_name.as_type(n),
+            ASType::ConstPtr(pointee) => format!("WasiPtr({})", pointee.as_lang(n)),
+            ASType::MutPtr(pointee) => format!("WasiMutPtr({})", pointee.as_lang(n)),
             ASType::Option(_) => todo!(),
             ASType::Result(_) => todo!(),
             ASType::S8 => "i8".to_string(),
@@ -87,15 +161,15 @@ pub trait ToLanguageRepresentation {
             ASType::USize => "usize".to_string(),
             ASType::Void => "()".to_string(),
             ASType::Constants(_) => unimplemented!(),
-            ASType::Enum(enum_) => enum_.repr.as_ref().as_lang(),
+            ASType::Enum(enum_) => enum_.repr.as_ref().as_lang(n),
             ASType::Struct(_) => unimplemented!(),
-            ASType::Tuple(tuple_members) => Tuple::name_for(tuple_members).as_type(),
+            ASType::Tuple(tuple_members) => Tuple::name_for(n, tuple_members).as_type(n),
             ASType::Union(_) => unimplemented!(),
-            ASType::Slice(element_type) => format!("WasiMutSlice({})", element_type.as_lang()),
+            ASType::Slice(element_type) => format!("WasiMutSlice({})", element_type.as_lang(n)),
             ASType::String(_) => "WasiString".to_string(),
-            ASType::ReadBuffer(element_type) => format!("WasiSlice({})", element_type.as_lang()),
+            ASType::ReadBuffer(element_type) => format!("WasiSlice({})", element_type.as_lang(n)),
             ASType::WriteBuffer(element_type) => {
-                format!("WasiMutSlice({})", element_type.to_string())
+                format!("WasiMutSlice({})", element_type.as_lang(n))
             }
         }
     }
@@ -103,6 +177,6 @@ pub trait ToLanguageRepresentation {
 
 impl ToLanguageRepresentation for ASType {
     fn as_astype(&self) -> &ASType {
-        &self
+        self
     }
 }
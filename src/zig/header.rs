@@ -2,6 +2,19 @@ use super::*;
 use std::io::Write;
 
 impl ZigGenerator {
+    /// Types defined by the header.
+    pub const PRELUDE_TYPES: &'static [&'static str] = &[
+        "Char32",
+        "Char8",
+        "WasiHandle",
+        "WasiMutPtr",
+        "WasiMutSlice",
+        "WasiPtr",
+        "WasiSlice",
+        "WasiString",
+        "WasiStringBytesPtr",
+    ];
+
     pub fn header<T: Write>(w: &mut PrettyWriter<T>) -> Result<(), Error> {
         w.write_lines(
             "
@@ -6,9 +6,14 @@ mod tuple;
 mod union;
 
 use super::*;
+use crate::annotations::strip_annotations;
 use crate::astype::*;
 use crate::error::*;
+use crate::handles::resources_to_define;
+use crate::naming::Namer;
 use crate::pretty_writer::PrettyWriter;
+use crate::selection::Selection;
+use crate::signature::*;
 use common::*;
 use std::io::Write;
 
@@ -23,17 +28,23 @@ impl ZigGenerator {
 }
 
 impl<T: Write> Generator<T> for ZigGenerator {
+    fn prelude_types(&self) -> &'static [&'static str] {
+        Self::PRELUDE_TYPES
+    }
+
     fn generate(
         &self,
         writer: &mut T,
-        module_witx: witx::Module,
+        module_witx: &witx::Module,
         options: &Options,
+        n: &Namer,
     ) -> Result<(), Error> {
         let mut w = PrettyWriter::new(writer, "    ");
         let module_name = match &self.module_name {
             None => module_witx.name().as_str().to_string(),
             Some(module_name) => module_name.to_string(),
         };
+        let selection = Selection::new(module_witx, &options.filter);
         let module_id = module_witx.module_id();
         let skip_imports = options.skip_imports;
 
@@ -48,35 +59,45 @@ impl<T: Write> Generator<T> for ZigGenerator {
         Self::write_comments(&mut w, &module_title_comments)?;
         w.eob()?;
 
-        for type_ in module_witx.typenames() {
+        for resource in resources_to_define(module_witx, &selection) {
+            if skip_imports && &resource.resource_id.module_id != module_id {
+                continue;
+            }
+            let resource_name = resource.resource_id.name.as_str();
+            Self::write_docs(&mut w, &resource.docs)?;
+            Self::define_resource(&mut w, n, resource_name)?;
+            w.eob()?;
+        }
+
+        for type_ in &selection.typenames {
             if skip_imports && &type_.module != module_id {
                 continue;
             }
             let constants_for_type: Vec<_> = module_witx
                 .constants()
-                .into_iter()
                 .filter_map(|x| {
                     if x.ty == type_.name {
                         Some(ASConstant {
                             name: x.name.as_str().to_string(),
                             value: x.value,
+                            docs: x.docs.clone(),
                         })
                     } else {
                         None
                     }
                 })
                 .collect();
-            Self::define_type(&mut w, type_.as_ref(), &constants_for_type)?;
+            Self::define_type(&mut w, n, type_.as_ref(), &constants_for_type)?;
         }
 
         w.write_line(format!(
             "pub const {} = struct {{",
-            module_name.as_namespace()
+            module_name.as_namespace(n)
         ))?;
         {
             let mut w = w.new_block();
-            for func in module_witx.funcs() {
-                Self::define_func(&mut w, &module_name, func.as_ref())?;
+            for func in &selection.funcs {
+                Self::define_func(&mut w, n, &module_name, func.as_ref())?;
             }
         }
         w.write_line("};")?;
@@ -88,6 +109,7 @@ impl<T: Write> Generator<T> for ZigGenerator {
 
 impl ZigGenerator {
     fn write_docs<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
         if docs.is_empty() {
             return Ok(());
         }
@@ -98,6 +120,7 @@ impl ZigGenerator {
     }
 
     fn write_comments<T: Write>(w: &mut PrettyWriter<T>, docs: &str) -> Result<(), Error> {
+        let docs = strip_annotations(docs);
         if docs.is_empty() {
             return Ok(());
         }
@@ -109,45 +132,61 @@ impl ZigGenerator {
 
     fn define_as_alias<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         other_type: &ASType,
     ) -> Result<(), Error> {
         w.write_line(format!(
             "pub const {} = {};",
-            name.as_type(),
-            other_type.as_lang()
+            name.as_type(n),
+            other_type.as_lang(n)
         ))?;
         Ok(())
     }
 
     fn define_as_atom<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         type_: &ASType,
     ) -> Result<(), Error> {
         w.write_line(format!(
             "pub const {} = {};",
-            name.as_type(),
-            type_.as_lang()
+            name.as_type(n),
+            type_.as_lang(n)
+        ))?;
+        Ok(())
+    }
+
+    fn define_resource<T: Write>(
+        w: &mut PrettyWriter<T>,
+        n: &Namer,
+        resource_name: &str,
+    ) -> Result<(), Error> {
+        w.write_line(format!(
+            "pub const {} = enum(WasiHandle) {{ _ }};",
+            resource_name.as_type(n)
         ))?;
         Ok(())
     }
 
     fn define_as_enum<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         enum_: &ASEnum,
     ) -> Result<(), Error> {
         let repr = enum_.repr.as_ref();
         w.write_line(format!(
             "pub const {} = enum({}) {{",
-            name.as_type(),
-            repr.as_lang()
+            name.as_type(n),
+            repr.as_lang(n)
         ))?;
         {
             let mut w = w.new_block();
             for choice in &enum_.choices {
-                w.write_line(format!("{} = {},", choice.name.as_const(), choice.value))?;
+                Self::write_docs(&mut w, &choice.docs)?;
+                w.write_line(format!("{} = {},", choice.name.as_const(n), choice.value))?;
             }
         }
         w.write_line("};")?;
@@ -156,22 +195,24 @@ impl ZigGenerator {
 
     fn define_as_constants<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         constants: &ASConstants,
     ) -> Result<(), Error> {
         let repr = constants.repr.as_ref();
         w.write_line(format!(
             "pub const {} = {};",
-            name.as_type(),
-            repr.as_lang()
+            name.as_type(n),
+            repr.as_lang(n)
         ))?;
-        Self::define_constants_for_type(w, name, &constants.constants)?;
+        Self::define_constants_for_type(w, n, name, &constants.constants)?;
         w.eob()?;
         Ok(())
     }
 
     fn define_as_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         type_: &ASType,
     ) -> Result<(), Error> {
@@ -191,16 +232,16 @@ impl ZigGenerator {
             | ASType::S32
             | ASType::S64
             | ASType::USize
-            | ASType::Handle(_)
             | ASType::Slice(_)
             | ASType::String(_)
             | ASType::ReadBuffer(_)
-            | ASType::WriteBuffer(_) => Self::define_as_atom(w, name, type_)?,
-            ASType::Enum(enum_) => Self::define_as_enum(w, name, enum_)?,
-            ASType::Union(union_) => Self::define_as_union(w, name, union_)?,
-            ASType::Constants(constants) => Self::define_as_constants(w, name, constants)?,
-            ASType::Tuple(members) => Self::define_as_tuple(w, name, members)?,
-            ASType::Struct(members) => Self::define_as_struct(w, name, members)?,
+            | ASType::WriteBuffer(_) => Self::define_as_atom(w, n, name, type_)?,
+            ASType::Handle(_) => Self::define_resource(w, n, name)?,
+            ASType::Enum(enum_) => Self::define_as_enum(w, n, name, enum_)?,
+            ASType::Union(union_) => Self::define_as_union(w, n, name, union_)?,
+            ASType::Constants(constants) => Self::define_as_constants(w, n, name, constants)?,
+            ASType::Tuple(members) => Self::define_as_tuple(w, n, name, members)?,
+            ASType::Struct(members) => Self::define_as_struct(w, n, name, members)?,
             _ => {
                 dbg!(type_);
                 unimplemented!();
@@ -211,6 +252,7 @@ impl ZigGenerator {
 
     fn define_constants_for_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         type_name: &str,
         constants: &[ASConstant],
     ) -> Result<(), Error> {
@@ -237,10 +279,11 @@ impl ZigGenerator {
             } else {
                 format!("{}", constant.value)
             };
+            Self::write_docs(w, &constant.docs)?;
             w.write_line(format!(
                 "pub const {}: {} = {};",
-                format!("{}_{}", type_name, constant.name).as_const(),
-                type_name.as_type(),
+                format!("{}_{}", type_name, constant.name).as_const(n),
+                type_name.as_type(n),
                 value_s
             ))?;
         }
@@ -250,6 +293,7 @@ impl ZigGenerator {
 
     fn define_type<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         type_witx: &witx::NamedType,
         constants: &[ASConstant],
     ) -> Result<(), Error> {
@@ -261,15 +305,15 @@ impl ZigGenerator {
         let tref = &type_witx.tref;
         match tref {
             witx::TypeRef::Name(other_type) => {
-                Self::define_as_alias(w, type_name, &ASType::from(&other_type.tref))?
+                Self::define_as_alias(w, n, type_name, &ASType::from(&other_type.tref))?
             }
             witx::TypeRef::Value(type_witx) => {
                 let t = ASType::from(type_witx.as_ref());
-                Self::define_as_type(w, type_name, &t)?
+                Self::define_as_type(w, n, type_name, &t)?
             }
         }
         w.eob()?;
-        Self::define_constants_for_type(w, type_name, constants)?;
+        Self::define_constants_for_type(w, n, type_name, constants)?;
         Ok(())
     }
 }
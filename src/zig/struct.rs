@@ -4,32 +4,27 @@ use std::io::Write;
 impl ZigGenerator {
     pub fn define_as_struct<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         members: &[ASStructMember],
     ) -> Result<(), Error> {
-        w.write_line(format!("pub const {} = extern struct {{", name.as_type()))?;
+        w.write_line(format!("pub const {} = extern struct {{", name.as_type(n)))?;
         {
             let mut w = w.new_block();
-            for member in members {
+            for (i, member) in members.iter().enumerate() {
                 let member_type = member.type_.as_ref();
+                Self::write_docs(&mut w, &member.docs)?;
                 w.write_line(format!(
                     "{}: {},",
-                    member.name.as_var(),
-                    member_type.as_lang()
+                    member.name.as_var(n),
+                    member_type.as_lang(n)
                 ))?;
 
-                let pad_len = member.padding;
-                for i in 0..(pad_len & 1) {
-                    w.write_line(format!("__pad8_{}: u8 = undefined,", i))?;
-                }
-                for i in 0..(pad_len & 3) / 2 {
-                    w.write_line(format!("__pad16_{}: u16 = undefined,", i))?;
-                }
-                for i in 0..(pad_len & 7) / 4 {
-                    w.write_line(format!("__pad32_{}: u32 = undefined,", i))?;
-                }
-                for i in 0..pad_len / 8 {
-                    w.write_line(format!("__pad64_{}: u64 = undefined,", i))?;
+                for pad_bits in padding_fields(member.padding) {
+                    w.write_line(format!(
+                        "__pad{}_{}: u{} = undefined,",
+                        pad_bits, i, pad_bits
+                    ))?;
                 }
             }
         }
@@ -4,6 +4,7 @@ use std::io::Write;
 impl ZigGenerator {
     fn define_union_member_accessors<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         union_name: &str,
         _i: usize,
         member: &ASUnionMember,
@@ -16,14 +17,14 @@ impl ZigGenerator {
             w.write_line(format!(
                 "fn new{}() {} {{",
                 name.as_fn_suffix(),
-                union_name.as_type()
+                union_name.as_type(n)
             ))?;
             {
                 let mut w = w.new_block();
                 w.write_line(format!(
                     "return {} {{ .tag = .{} }};",
-                    union_name.as_type(),
-                    name.as_var(),
+                    union_name.as_type(n),
+                    name.as_var(n),
                 ))?;
             }
             w.write_line("}")?.eob()?;
@@ -33,16 +34,16 @@ impl ZigGenerator {
             w.write_line(format!(
                 "fn new{}(val: {}) {} {{",
                 name.as_fn_suffix(),
-                member.type_.as_lang(),
-                union_name.as_type()
+                member.type_.as_lang(n),
+                union_name.as_type(n)
             ))?;
             {
                 let mut w = w.new_block();
                 w.write_line(format!(
                     "return {}{{ .tag = .{}, .member = .{{ .{} = val }} }};",
-                    union_name.as_type(),
-                    name.as_var(),
-                    name.as_var()
+                    union_name.as_type(n),
+                    name.as_var(n),
+                    name.as_var(n)
                 ))?;
             }
             w.write_line("}")?.eob()?;
@@ -51,13 +52,16 @@ impl ZigGenerator {
             w.write_line(format!(
                 "pub fn {}(self: {}) {} {{",
                 name.as_fn_suffix(),
-                union_name.as_type(),
-                member.type_.as_lang()
+                union_name.as_type(n),
+                member.type_.as_lang(n)
             ))?;
             {
                 let mut w = w.new_block();
-                w.write_line(format!("std.debug.assert(self.tag == .{});", name.as_var()))?;
-                w.write_line(format!("return self.member.{};", member.name.as_var()))?;
+                w.write_line(format!(
+                    "std.debug.assert(self.tag == .{});",
+                    name.as_var(n)
+                ))?;
+                w.write_line(format!("return self.member.{};", member.name.as_var(n)))?;
             }
             w.write_line("}")?.eob()?;
 
@@ -65,13 +69,16 @@ impl ZigGenerator {
             w.write_line(format!(
                 "pub fn set{}(self: *{}, val: {}) void {{",
                 name.as_fn_suffix(),
-                union_name.as_type(),
-                member.type_.as_lang()
+                union_name.as_type(n),
+                member.type_.as_lang(n)
             ))?;
             {
                 let mut w = w.new_block();
-                w.write_line(format!("std.debug.assert(self.tag == .{});", name.as_var()))?;
-                w.write_line(format!("self.member.{} = val;", member.name.as_var()))?;
+                w.write_line(format!(
+                    "std.debug.assert(self.tag == .{});",
+                    name.as_var(n)
+                ))?;
+                w.write_line(format!("self.member.{} = val;", member.name.as_var(n)))?;
             }
             w.write_line("}")?.eob()?;
         }
@@ -80,11 +87,11 @@ impl ZigGenerator {
         w.write_line(format!(
             "fn is{}(self: {}) bool {{",
             name.as_fn_suffix(),
-            union_name.as_type(),
+            union_name.as_type(n),
         ))?;
         {
             let mut w = w.new_block();
-            w.write_line(format!("return self.tag == .{};", name.as_var()))?;
+            w.write_line(format!("return self.tag == .{};", name.as_var(n)))?;
         }
         w.write_line("}")?.eob()?;
 
@@ -93,54 +100,47 @@ impl ZigGenerator {
 
     fn define_union_member<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         union_name: &str,
         i: usize,
         member: &ASUnionMember,
     ) -> Result<(), Error> {
-        Self::define_union_member_accessors(w, union_name, i, member)?;
+        Self::define_union_member_accessors(w, n, union_name, i, member)?;
         Ok(())
     }
 
     pub fn define_as_union<T: Write>(
         w: &mut PrettyWriter<T>,
+        n: &Namer,
         name: &str,
         union_: &ASUnion,
     ) -> Result<(), Error> {
         let tag_repr = union_.tag_repr.as_ref();
-        w.write_line(format!("pub const {} = extern struct {{", name.as_type()))?;
+        w.write_line(format!("pub const {} = extern struct {{", name.as_type(n)))?;
         {
             let mut w = w.new_block();
-            w.write_line(format!("tag: enum({}) {{", tag_repr.as_lang()))?;
+            w.write_line(format!("tag: enum({}) {{", tag_repr.as_lang(n)))?;
             {
                 let mut w = w.new_block();
                 for (i, member) in union_.members.iter().enumerate() {
-                    w.write_line(format!("{} = {},", member.name.as_var(), i))?;
+                    Self::write_docs(&mut w, &member.docs)?;
+                    w.write_line(format!("{} = {},", member.name.as_var(n), i))?;
                 }
             }
             w.write_line("},")?;
-            let pad_len = union_.padding_after_tag;
-            for i in 0..(pad_len & 1) {
-                w.write_line(format!("__pad8_{}: u8 = undefined,", i))?;
-            }
-            for i in 0..(pad_len & 3) / 2 {
-                w.write_line(format!("__pad16_{}: u16 = undefined,", i))?;
-            }
-            for i in 0..(pad_len & 7) / 4 {
-                w.write_line(format!("__pad32_{}: u32 = undefined,", i))?;
-            }
-            for i in 0..pad_len / 8 {
-                w.write_line(format!("__pad64_{}: u64 = undefined,", i))?;
+            for pad_bits in padding_fields(union_.padding_after_tag) {
+                w.write_line(format!("__pad{}_0: u{} = undefined,", pad_bits, pad_bits))?;
             }
             w.write_line("member = extern union {")?;
             {
                 let mut w = w.new_block();
-                for (_i, member) in union_.members.iter().enumerate() {
+                for member in union_.members.iter() {
                     let member_is_void = matches!(member.type_.as_ref(), ASType::Void);
                     if !member_is_void {
                         w.write_line(format!(
                             "{}: {},",
-                            member.name.as_var(),
-                            member.type_.as_lang(),
+                            member.name.as_var(n),
+                            member.type_.as_lang(n),
                         ))?;
                     }
                 }
@@ -151,7 +151,7 @@ impl ZigGenerator {
 
         for (i, member) in union_.members.iter().enumerate() {
             w.eob()?;
-            Self::define_union_member(w, name, i, member)?;
+            Self::define_union_member(w, n, name, i, member)?;
         }
         w.write_line("};")?.eob()?;
         Ok(())
@@ -0,0 +1,62 @@
+//! Generate C++ bindings for the WITX files in `test/`, and check that they compile without
+//! warnings, as C++17 and as C++20, which also uses `std::span`.
+//!
+//! Bindings are only checked for syntax and types, with the host C++ compiler. Layout assertions
+//! are only compiled for `wasm32`.
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+const STANDARDS: &[&str] = &["c++17", "c++20"];
+
+fn cxx() -> Command {
+    Command::new(std::env::var_os("CXX").unwrap_or_else(|| "c++".into()))
+}
+
+#[test]
+fn cpp_compile() {
+    let out_dir = common::tmp_dir("cpp_compile");
+    for witx_file in common::witx_files() {
+        let name = witx_file.file_stem().unwrap().to_string_lossy();
+        let header_file = out_dir.join(format!("{}.hpp", name));
+        let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+            .arg("--output-type")
+            .arg("cpp")
+            .arg("--output")
+            .arg(&header_file)
+            .arg(&witx_file)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "Generation failed for {}: {}",
+            witx_file.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // Compiling the header itself would warn about `#pragma once` in the main file
+        let source_file = out_dir.join(format!("{}.cpp", name));
+        fs::write(&source_file, format!("#include \"{}.hpp\"\n", name)).unwrap();
+        for standard in STANDARDS {
+            let output = cxx()
+                .arg(format!("-std={}", standard))
+                .arg("-fsyntax-only")
+                .arg("-Wall")
+                .arg("-Wextra")
+                .arg("-Wpedantic")
+                .arg("-Werror")
+                .arg(&source_file)
+                .output()
+                .expect("a C++ compiler is required, set CXX to use another one than c++");
+            assert!(
+                output.status.success(),
+                "{} doesn't compile as {}:\n{}",
+                header_file.display(),
+                standard,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
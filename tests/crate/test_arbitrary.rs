@@ -0,0 +1,61 @@
+//! Copied to the tests of the crate emitted for `test/test_serde.witx` with `--arbitrary`.
+
+use arbitrary::{Arbitrary, Unstructured};
+use test_serde::test_serde::*;
+
+/// Return pseudo-random bytes, the same for every run.
+fn bytes(seed: u64) -> Vec<u8> {
+    let mut state = seed;
+    (0..64)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+fn check_color(color: Color) {
+    assert!(color <= COLOR::LIGHT_BLUE, "invalid color: {}", color);
+}
+
+fn check_pixel(pixel: &Pixel) {
+    check_color(pixel.color);
+    let all = PERMISSIONS::READ | PERMISSIONS::WRITE | PERMISSIONS::EXECUTE;
+    assert_eq!(pixel.r#type & !all, 0, "invalid permissions");
+}
+
+#[test]
+fn valid_values() {
+    let mut tags_seen = [false; 4];
+    let mut permissions_seen = 0;
+    for seed in 1..1000 {
+        let data = bytes(seed);
+        let shapes = Shapes::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        check_color(shapes.v1);
+        let shape = shapes.v0;
+        tags_seen[shape.tag as usize] = true;
+        match shape.as_enum().unwrap() {
+            ShapeRef::Point(pixel) => {
+                check_pixel(pixel);
+                permissions_seen |= pixel.r#type;
+            }
+            ShapeRef::Filled(color) => check_color(*color),
+            ShapeRef::Circle(_) | ShapeRef::Empty => {}
+        }
+    }
+    assert_eq!(tags_seen, [true; 4]);
+    assert_eq!(
+        permissions_seen,
+        PERMISSIONS::READ | PERMISSIONS::WRITE | PERMISSIONS::EXECUTE
+    );
+}
+
+#[test]
+fn exhausted_input() {
+    let pixel = Pixel::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    check_pixel(&pixel);
+    let shape = Shape::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    assert!(shape.tag < 4);
+}
@@ -0,0 +1,45 @@
+//! Copied to the tests of the crate emitted for `test/test_exports.witx`, as another crate
+//! implementing the functions it exports.
+
+use test_exports::prelude::*;
+use test_exports::test_exports::*;
+
+struct Guest;
+
+impl TestExportsExports for Guest {
+    fn on_tick() {}
+
+    fn interface_version() -> u32 {
+        2
+    }
+
+    fn on_data(_data: WasiPtr<u8>, data_len: Size) -> Result<Size, Errno> {
+        if data_len == 0 {
+            return Err(ERRNO::INVAL);
+        }
+        Ok(data_len)
+    }
+
+    fn dimensions() -> Result<(Size, Size), Errno> {
+        Ok((80, 25))
+    }
+
+    fn on_fatal(code: u32) -> ! {
+        panic!("fatal error {}", code)
+    }
+}
+
+test_exports::export_test_exports!(Guest);
+
+extern "C" {
+    fn interface_version() -> u32;
+    fn dimensions(width: WasiMutPtr<Size>, height: WasiMutPtr<Size>) -> Errno;
+}
+
+#[test]
+fn exported_functions() {
+    assert_eq!(unsafe { interface_version() }, 2);
+    let (mut width, mut height) = (0, 0);
+    assert_eq!(unsafe { dimensions(&mut width, &mut height) }, 0);
+    assert_eq!((width, height), (80, 25));
+}
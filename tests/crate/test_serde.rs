@@ -0,0 +1,67 @@
+//! Copied to the tests of the crate emitted for `test/test_serde.witx` with `--serde`.
+
+use test_serde::test_serde::*;
+
+fn pixel() -> Pixel {
+    Pixel {
+        x: 1,
+        y: 2,
+        color: COLOR::LIGHT_BLUE,
+        r#type: PERMISSIONS::READ | PERMISSIONS::EXECUTE,
+    }
+}
+
+/// Serialize `value`, check the JSON, and check that deserializing it gives the same JSON back.
+fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T, json: &str) {
+    assert_eq!(serde_json::to_string(value).unwrap(), json);
+    let value: T = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+}
+
+#[test]
+fn structs() {
+    round_trip(
+        &pixel(),
+        r#"{"x":1,"y":2,"color":"light_blue","type":["read","execute"]}"#,
+    );
+    round_trip(
+        &Shapes {
+            v0: Shape::new_empty(),
+            v1: COLOR::RED,
+        },
+        r#"{"v0":"empty","v1":"red"}"#,
+    );
+}
+
+#[test]
+fn unions() {
+    round_trip(
+        &Shape::new_point(pixel()),
+        r#"{"point":{"x":1,"y":2,"color":"light_blue","type":["read","execute"]}}"#,
+    );
+    round_trip(&Shape::new_circle(3), r#"{"circle":3}"#);
+    round_trip(&Shape::new_filled(COLOR::GREEN), r#"{"filled":"green"}"#);
+    round_trip(&Shape::new_empty(), r#""empty""#);
+
+    let shape: Shape = serde_json::from_str(r#"{"circle":3}"#).unwrap();
+    assert_eq!(shape.into_circle(), 3);
+}
+
+#[test]
+fn invalid_values() {
+    let error = serde_json::from_str::<Pixel>(r#"{"x":1,"y":2,"color":"purple","type":[]}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("unknown variant `purple`"), "{}", error);
+    let error = serde_json::from_str::<Pixel>(r#"{"x":1,"y":2,"color":"red","type":["delete"]}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("unknown variant `delete`"), "{}", error);
+
+    let mut pixel = pixel();
+    pixel.color = 42;
+    assert!(serde_json::to_string(&pixel).is_err());
+    pixel.color = COLOR::RED;
+    pixel.r#type = 0x80;
+    assert!(serde_json::to_string(&pixel).is_err());
+}
@@ -0,0 +1,235 @@
+//! Check that the crate emitted for `test/wasi_ephemeral_crypto_symmetric.witx`, which uses
+//! `test/wasi_ephemeral_crypto_common.witx`, builds with all its features, and with only some
+//! of them, with and without `--no-std`.
+//!
+//! With `--serde` and `--arbitrary`, the tests of `tests/crate/` also run against the crate
+//! emitted for `test/test_serde.witx`. Their dependencies are downloaded from the registry, so
+//! they are ignored unless `--include-ignored` is passed. The functions exported by the crate
+//! emitted for `test/test_exports.witx` are implemented by another crate, its tests.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const CRYPTO_WITX: &str = "test/wasi_ephemeral_crypto_symmetric.witx";
+
+fn cargo_check(crate_dir: &Path, target_dir: &Path, features: &[&str]) {
+    cargo(crate_dir, target_dir, "check", features);
+}
+
+fn cargo(crate_dir: &Path, target_dir: &Path, command: &str, features: &[&str]) {
+    let output = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .arg(command)
+        .arg("--offline")
+        .args(features)
+        .current_dir(crate_dir)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .env("RUSTFLAGS", "-Dwarnings")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "cargo {} failed for {} with {:?}:\n{}",
+        command,
+        crate_dir.display(),
+        features,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn emit_crate_with(crate_dir: &Path, witx_file: &str, args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+        .arg("--emit-crate")
+        .arg(crate_dir)
+        .args(args)
+        .arg(witx_file)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Crate generation failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn emit_crate() {
+    let out_dir = common::tmp_dir("emit_crate");
+    let crate_dir = out_dir.join("wasi_crypto");
+    emit_crate_with(&crate_dir, CRYPTO_WITX, &[]);
+    for module in &[
+        "prelude",
+        "wasi_ephemeral_crypto_common",
+        "wasi_ephemeral_crypto_symmetric",
+    ] {
+        let module_file = crate_dir.join("src").join(module).with_extension("rs");
+        assert!(module_file.exists(), "{} is missing", module_file.display());
+    }
+
+    let target_dir = out_dir.join("target");
+    cargo_check(&crate_dir, &target_dir, &[]);
+    cargo_check(
+        &crate_dir,
+        &target_dir,
+        &[
+            "--no-default-features",
+            "--features",
+            "wasi_ephemeral_crypto_common",
+        ],
+    );
+}
+
+#[test]
+fn emit_crate_no_std() {
+    let out_dir = common::tmp_dir("emit_crate_no_std");
+    let crate_dir = out_dir.join("wasi_crypto");
+    emit_crate_with(&crate_dir, CRYPTO_WITX, &["--no-std"]);
+
+    let target_dir = out_dir.join("target");
+    cargo_check(&crate_dir, &target_dir, &[]);
+    cargo_check(&crate_dir, &target_dir, &["--features", "alloc"]);
+}
+
+/// Download the dependencies of the crate in `crate_dir`, so that it can be built offline.
+/// Registry crates such as `serde_json` require network access, unless they were downloaded
+/// before and `CARGO_NET_OFFLINE` is set.
+fn cargo_fetch(crate_dir: &Path) {
+    let output = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .arg("fetch")
+        .current_dir(crate_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "The dependencies of {} can't be downloaded:\n{}",
+        crate_dir.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Emit a crate for `witx_file` to `crate_dir`, with `tests/crate/<test_name>.rs` as a test, and
+/// run it with `features` enabled.
+fn emit_test_crate(
+    crate_dir: &Path,
+    target_dir: &Path,
+    witx_file: &str,
+    args: &[&str],
+    test_name: &str,
+    features: &[&str],
+    dev_dependencies: &str,
+) {
+    emit_crate_with(crate_dir, witx_file, args);
+    let mut manifest = fs::read_to_string(crate_dir.join("Cargo.toml")).unwrap();
+    manifest.push_str(&format!("\n[dev-dependencies]\n{}", dev_dependencies));
+    fs::write(crate_dir.join("Cargo.toml"), manifest).unwrap();
+    fs::create_dir_all(crate_dir.join("tests")).unwrap();
+    let test_file = Path::new(test_name).with_extension("rs");
+    fs::copy(
+        Path::new("tests/crate").join(&test_file),
+        crate_dir.join("tests").join(&test_file),
+    )
+    .unwrap();
+    cargo_fetch(crate_dir);
+    cargo_check(crate_dir, target_dir, &[]);
+    cargo(crate_dir, target_dir, "test", features);
+}
+
+#[test]
+#[ignore = "downloads serde_json"]
+fn emit_crate_serde() {
+    let out_dir = common::tmp_dir("emit_crate_serde");
+    let target_dir = out_dir.join("target");
+    let crate_dir = out_dir.join("test_serde");
+    emit_test_crate(
+        &crate_dir,
+        &target_dir,
+        "test/test_serde.witx",
+        &["--serde"],
+        "test_serde",
+        &["--features", "serde"],
+        "serde_json = \"1\"\n",
+    );
+
+    let crate_dir = out_dir.join("test_serde_no_std");
+    emit_crate_with(&crate_dir, "test/test_serde.witx", &["--serde", "--no-std"]);
+    cargo_check(&crate_dir, &target_dir, &["--features", "serde"]);
+}
+
+#[test]
+#[ignore = "downloads arbitrary"]
+fn emit_crate_arbitrary() {
+    let out_dir = common::tmp_dir("emit_crate_arbitrary");
+    let target_dir = out_dir.join("target");
+    let crate_dir = out_dir.join("test_serde");
+    emit_test_crate(
+        &crate_dir,
+        &target_dir,
+        "test/test_serde.witx",
+        &["--arbitrary", "--serde"],
+        "test_arbitrary",
+        &["--features", "arbitrary"],
+        "",
+    );
+}
+
+#[test]
+fn emit_crate_exports() {
+    let out_dir = common::tmp_dir("emit_crate_exports");
+    let target_dir = out_dir.join("target");
+    let crate_dir = out_dir.join("test_exports");
+    emit_test_crate(
+        &crate_dir,
+        &target_dir,
+        "test/test_exports.witx",
+        &[],
+        "test_exports",
+        &[],
+        "",
+    );
+}
+
+#[test]
+fn emit_crate_rejects_invalid_names() {
+    let out_dir = common::tmp_dir("emit_crate_rejects_invalid_names");
+    for crate_name in &["wasi crypto", "1crypto"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+            .arg("--emit-crate")
+            .arg(out_dir.join(crate_name))
+            .arg(CRYPTO_WITX)
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{} was accepted", crate_name);
+        assert!(!out_dir.join(crate_name).exists());
+    }
+}
+
+#[test]
+fn emit_crate_rejects_module_name_for_multiple_modules() {
+    let out_dir = common::tmp_dir("emit_crate_rejects_module_name_for_multiple_modules");
+    let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+        .arg("--emit-crate")
+        .arg(out_dir.join("wasi_crypto"))
+        .arg("--module-name")
+        .arg("crypto")
+        .arg(CRYPTO_WITX)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(!out_dir.join("wasi_crypto").exists());
+}
+
+#[test]
+fn emit_crate_rejects_other_output_types() {
+    let out_dir = common::tmp_dir("emit_crate_rejects_other_output_types");
+    let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+        .arg("--emit-crate")
+        .arg(out_dir.join("wasi_crypto"))
+        .arg("--output-type")
+        .arg("zig")
+        .arg(CRYPTO_WITX)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
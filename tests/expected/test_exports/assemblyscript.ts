@@ -0,0 +1,126 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/*
+* ---------------------- Module: [test_exports] ----------------------
+ */
+
+export type Errno = u16;
+
+export namespace Errno {
+    export const SUCCESS: Errno = 0;
+    export const INVAL: Errno = 1;
+}
+
+export type Size = usize;
+
+/**
+ * Write a message to the host log.
+ */
+// @ts-ignore: decorator
+@external("test_exports", "log")
+export declare function log(
+    message_ptr: WasiPtr<Char8>,
+    message_len: usize
+): void;
+
+/**
+ * Called by the host on every tick.
+ */
+export { onTick as on_tick };
+function onTick(): void {
+    throw new Error("Not implemented");
+}
+
+/**
+ * Return the version of the guest interface.
+ */
+export { interfaceVersion as interface_version };
+function interfaceVersion(): u32 {
+    throw new Error("Not implemented");
+}
+
+/**
+ * Process data provided by the host, and return the number of bytes consumed.
+ */
+export { onData as on_data };
+function onData(
+    data: WasiPtr<u8>,
+    data_len: Size,
+    result_ptr: WasiMutPtr<Size>
+): Errno {
+    throw new Error("Not implemented");
+}
+
+/**
+ * Return the dimensions of the guest window.
+ */
+export function dimensions(
+    result0_ptr: WasiMutPtr<Size>,
+    result1_ptr: WasiMutPtr<Size>
+): Errno {
+    throw new Error("Not implemented");
+}
+
+/**
+ * Called by the host when the guest has to stop.
+ */
+export { onFatal as on_fatal };
+function onFatal(
+    code: u32
+): never {
+    throw new Error("Not implemented");
+}
+
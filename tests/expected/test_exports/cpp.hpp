@@ -0,0 +1,192 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [test_exports] ----------------------
+
+namespace test_exports {
+
+    enum class Errno : uint16_t {
+        SUCCESS = 0,
+        INVAL = 1,
+    };
+
+    using Size = size_t;
+
+    namespace raw {
+
+        extern "C" WITX_CODEGEN_IMPORT("test_exports", "log")
+        void log(
+            WasiPtr<Char8> message_ptr,
+            size_t message_len
+        );
+
+    } // namespace raw
+
+    /// Write a message to the host log.
+    inline void log(WasiString message) {
+        raw::log(message.ptr, message.len);
+    }
+
+    /// Called by the host on every tick.
+    extern "C" WITX_CODEGEN_EXPORT("on_tick")
+    void on_tick();
+
+    /// Return the version of the guest interface.
+    extern "C" WITX_CODEGEN_EXPORT("interface_version")
+    uint32_t interface_version();
+
+    /// Process data provided by the host, and return the number of bytes consumed.
+    extern "C" WITX_CODEGEN_EXPORT("on_data")
+    Errno on_data(
+        WasiPtr<uint8_t> data,
+        Size data_len,
+        WasiMutPtr<Size> result_ptr
+    );
+
+    /// Return the dimensions of the guest window.
+    extern "C" WITX_CODEGEN_EXPORT("dimensions")
+    Errno dimensions(
+        WasiMutPtr<Size> result0_ptr,
+        WasiMutPtr<Size> result1_ptr
+    );
+
+    /// Called by the host when the guest has to stop.
+    extern "C" [[noreturn]] WITX_CODEGEN_EXPORT("on_fatal")
+    void on_fatal(
+        uint32_t code
+    );
+
+} // namespace test_exports
+
@@ -0,0 +1,284 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [test_exports] ----------------------
+
+pub type Errno = u16;
+
+#[allow(non_snake_case)]
+pub mod ERRNO {
+    use super::Errno;
+    pub const SUCCESS: Errno = 0;
+    pub const INVAL: Errno = 1;
+}
+
+pub type Size = usize;
+
+/// Write a message to the host log.
+pub fn log(
+    message_ptr: WasiPtr<Char8>,
+    message_len: usize,
+) {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_exports")]
+        extern "C" {
+            pub fn log(
+                message_ptr: WasiPtr<Char8>,
+                message_len: usize,
+            );
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn log(
+            message_ptr: WasiPtr<Char8>,
+            message_len: usize,
+        ) {
+            super::with_test_exports_mock(|mock| mock.log(message_ptr, message_len))
+        }
+    }
+    unsafe { raw::log(
+        message_ptr,
+        message_len,
+    )}
+}
+
+/// Implementation of the functions of the `test_exports` module used on native targets.
+/// Install it with `set_test_exports_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait TestExportsMock {
+    /// Write a message to the host log.
+    #[allow(unused_variables)]
+    fn log(
+        &self,
+        message_ptr: WasiPtr<Char8>,
+        message_len: usize,
+    ) {
+        unimplemented!("log")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static TEST_EXPORTS_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn TestExportsMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `test_exports` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_test_exports_mock(mock: impl TestExportsMock + 'static) -> Option<std::rc::Rc<dyn TestExportsMock>> {
+    TEST_EXPORTS_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_test_exports_mock<R>(f: impl FnOnce(&dyn TestExportsMock) -> R) -> R {
+    let mock = TEST_EXPORTS_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `test_exports` module installed"))
+}
+
+/// Functions of the `test_exports` module implemented by the guest.
+/// Implement this trait for a type, and export its methods to the host with `export_test_exports!(Type)`.
+pub trait TestExportsExports {
+    /// Called by the host on every tick.
+    fn on_tick();
+
+    /// Return the version of the guest interface.
+    fn interface_version() -> u32;
+
+    /// Process data provided by the host, and return the number of bytes consumed.
+    fn on_data(
+        data: WasiPtr<u8>,
+        data_len: Size,
+    ) -> Result<Size, Errno>;
+
+    /// Return the dimensions of the guest window.
+    fn dimensions() -> Result<(Size, Size), Errno>;
+
+    /// Called by the host when the guest has to stop.
+    fn on_fatal(
+        code: u32,
+    ) -> !;
+}
+
+/// Export the methods of a type implementing `TestExportsExports` to the host.
+/// If the bindings are not at the root of their crate, the paths of the modules defining their types
+/// have to be given, as in `export_test_exports!(Type, with_types_in crate::bindings)`.
+#[macro_export]
+macro_rules! export_test_exports {
+    ($t:ty) => {
+        $crate::export_test_exports!($t, with_types_in $crate);
+    };
+    ($t:ty, with_types_in $($path:path),+) => {
+        const _: () = {
+            $(
+                #[allow(unused_imports)]
+                use $path::*;
+            )+
+
+            #[no_mangle]
+            pub extern "C" fn on_tick() {
+                <$t as TestExportsExports>::on_tick()
+            }
+
+            #[no_mangle]
+            pub extern "C" fn interface_version() -> u32 {
+                <$t as TestExportsExports>::interface_version()
+            }
+
+            #[no_mangle]
+            pub extern "C" fn on_data(
+                data: WasiPtr<u8>,
+                data_len: Size,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> Errno {
+                match <$t as TestExportsExports>::on_data(data, data_len) {
+                    Ok(res) => {
+                        unsafe { result_ptr.write(res) };
+                        0
+                    }
+                    Err(e) => e,
+                }
+            }
+
+            #[no_mangle]
+            pub extern "C" fn dimensions(
+                result0_ptr: WasiMutPtr<Size>,
+                result1_ptr: WasiMutPtr<Size>,
+            ) -> Errno {
+                match <$t as TestExportsExports>::dimensions() {
+                    Ok((res0, res1)) => {
+                        unsafe { result0_ptr.write(res0) };
+                        unsafe { result1_ptr.write(res1) };
+                        0
+                    }
+                    Err(e) => e,
+                }
+            }
+
+            #[no_mangle]
+            pub extern "C" fn on_fatal(
+                code: u32,
+            ) -> ! {
+                <$t as TestExportsExports>::on_fatal(code)
+            }
+        };
+    };
+}
+
@@ -0,0 +1,111 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [test_exports] ----------------------
+
+pub const Errno = enum(u16) {
+    SUCCESS = 0,
+    INVAL = 1,
+};
+
+pub const Size = usize;
+
+pub const TestExports = struct {
+    /// Write a message to the host log.
+    pub extern "test_exports" fn log(
+        message_ptr: WasiPtr(Char8),
+        message_len: usize,
+    ) callconv(.C) void;
+
+    /// Called by the host on every tick.
+    export fn on_tick() callconv(.C) void {
+        return @import("root").on_tick();
+    }
+
+    /// Return the version of the guest interface.
+    export fn interface_version() callconv(.C) u32 {
+        return @import("root").interface_version();
+    }
+
+    /// Process data provided by the host, and return the number of bytes consumed.
+    export fn on_data(
+        data: WasiPtr(u8),
+        data_len: Size,
+        result_ptr: WasiMutPtr(Size),
+    ) callconv(.C) Errno {
+        return @import("root").on_data(data, data_len, result_ptr);
+    }
+
+    /// Return the dimensions of the guest window.
+    export fn dimensions(
+        result0_ptr: WasiMutPtr(Size),
+        result1_ptr: WasiMutPtr(Size),
+    ) callconv(.C) Errno {
+        return @import("root").dimensions(result0_ptr, result1_ptr);
+    }
+
+    /// Called by the host when the guest has to stop.
+    export fn on_fatal(
+        code: u32,
+    ) callconv(.C) noreturn {
+        @import("root").on_fatal(code);
+    }
+
+};
+
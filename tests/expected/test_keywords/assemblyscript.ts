@@ -0,0 +1,193 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/*
+* ---------------------- Module: [test_keywords] ----------------------
+ */
+
+export type Errno = u16;
+
+export namespace Errno {
+    export const SUCCESS: Errno = 0;
+    export const INVAL: Errno = 1;
+}
+
+export type Type = u32;
+
+export type Match = u8;
+
+export namespace Match {
+    export const TYPE: Match = 0;
+    export const FN: Match = 1;
+    export const LOOP: Match = 2;
+}
+
+// @ts-ignore: decorator
+@unmanaged
+export class Self {
+    type_: u32;
+    private __pad32_0: u32;
+    async_: u64;
+}
+
+
+// @ts-ignore: decorator
+@unmanaged
+export class Loop {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 4);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): Loop {
+        let tu = new Loop(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 4);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- fn: u32 if tag=0
+
+    static fn(val: u32): Loop {
+        return Loop.new(0, val);
+    }
+
+    setFn(val: u32): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isFn(): bool {
+        return this.tag === 0;
+    }
+
+    getFn(): u32 {
+        return this.get<u32>();
+    }
+
+    // --- self: (no associated content) if tag=1
+
+    static self(): Loop {
+        return Loop.new(1);
+    }
+
+    setSelf(): void {
+        this.tag = 1;
+    }
+
+    isSelf(): bool {
+        return this.tag === 1;
+    }
+}
+
+
+/**
+ * Return the type of a match.
+ */
+// @ts-ignore: decorator
+@external("test_keywords", "match")
+export declare function match(
+    self: Self,
+    fn: Match,
+    result_ptr: WasiMutPtr<Loop>
+): Errno;
+
+/**
+ * Loop until the host stops.
+ */
+// @ts-ignore: decorator
+@external("test_keywords", "loop")
+export declare function loop(
+    async_: u32,
+    gen: u32
+): void;
+
+/**
+ * Called by the host to run asynchronous work.
+ */
+export { async_ as async };
+function async_(
+    type_: u32,
+    result_ptr: WasiMutPtr<Type>
+): Errno {
+    throw new Error("Not implemented");
+}
+
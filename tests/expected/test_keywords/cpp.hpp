@@ -0,0 +1,264 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [test_keywords] ----------------------
+
+namespace test_keywords {
+
+    enum class Errno : uint16_t {
+        SUCCESS = 0,
+        INVAL = 1,
+    };
+
+    using Type = uint32_t;
+
+    enum class Match : uint8_t {
+        TYPE = 0,
+        FN = 1,
+        LOOP = 2,
+    };
+
+    struct Self {
+        uint32_t type;
+        uint32_t __pad32_0;
+        uint64_t async;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(Self, type) == 0, "unexpected layout");
+    static_assert(offsetof(Self, async) == 8, "unexpected layout");
+    #endif
+
+    struct Loop {
+        enum class Tag : uint8_t {
+            FN = 0,
+            SELF = 1,
+        };
+
+        Tag tag;
+        union {
+            uint32_t fn;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- fn: uint32_t if tag=0
+
+        static Loop make_fn(uint32_t val) {
+            Loop tu{};
+            tu.tag = Tag::FN;
+            tu.member.fn = val;
+            return tu;
+        }
+
+        void emplace_fn(uint32_t val) {
+            tag = Tag::FN;
+            member.fn = val;
+        }
+
+        const uint32_t *get_if_fn() const {
+            return holds_fn() ? &member.fn : nullptr;
+        }
+
+        uint32_t *get_if_fn() {
+            return holds_fn() ? &member.fn : nullptr;
+        }
+
+        bool holds_fn() const {
+            return tag == Tag::FN;
+        }
+
+        // --- self: (no associated content) if tag=1
+
+        static Loop make_self() {
+            Loop tu{};
+            tu.tag = Tag::SELF;
+            return tu;
+        }
+
+        void emplace_self() {
+            tag = Tag::SELF;
+        }
+
+        bool holds_self() const {
+            return tag == Tag::SELF;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(Loop, member) == 4, "unexpected layout");
+    #endif
+
+    namespace raw {
+
+        extern "C" WITX_CODEGEN_IMPORT("test_keywords", "match")
+        Errno match(
+            Self self,
+            Match fn,
+            WasiMutPtr<Loop> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_keywords", "loop")
+        void loop(
+            uint32_t async,
+            uint32_t gen
+        );
+
+    } // namespace raw
+
+    /// Return the type of a match.
+    inline WasiExpected<Loop, Errno> match(Self self, Match fn) {
+        Loop result_ptr{};
+        const Errno res = raw::match(self, fn, &result_ptr);
+        if (res != Errno{}) {
+            return WasiExpected<Loop, Errno>::from_error(res);
+        }
+        return WasiExpected<Loop, Errno>::from_value(result_ptr);
+    }
+
+    /// Loop until the host stops.
+    inline void loop(uint32_t async, uint32_t gen) {
+        raw::loop(async, gen);
+    }
+
+    /// Called by the host to run asynchronous work.
+    extern "C" WITX_CODEGEN_EXPORT("async")
+    Errno async(
+        uint32_t type,
+        WasiMutPtr<Type> result_ptr
+    );
+
+} // namespace test_keywords
+
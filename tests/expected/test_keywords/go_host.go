@@ -0,0 +1,335 @@
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+package testkeywords
+
+import (
+	"context"
+	"errors"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New("invalid guest memory access")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// ---------------------- Module: [test_keywords] ----------------------
+
+type Errno uint16
+
+const (
+	ErrnoSuccess Errno = 0
+	ErrnoInval   Errno = 1
+)
+
+type Type uint32
+
+type Match uint8
+
+const (
+	MatchType Match = 0
+	MatchFn   Match = 1
+	MatchLoop Match = 2
+)
+
+type Self struct {
+	Type  uint32
+	Async uint64
+}
+
+func (r *memReader) Self(offset uint32) Self {
+	return Self{
+		Type:  r.u32(offset),
+		Async: r.u64(offset + 8),
+	}
+}
+
+func (w *memWriter) Self(offset uint32, v Self) {
+	w.u32(offset, v.Type)
+	w.u64(offset+8, v.Async)
+}
+
+// ReadSelf reads a Self from guest memory at offset, and returns false if it is out of range.
+func ReadSelf(mem api.Memory, offset uint32) (Self, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.Self(offset)
+	return v, r.ok
+}
+
+// WriteSelf writes a Self to guest memory at offset, and returns false if it is out of range.
+func WriteSelf(mem api.Memory, offset uint32, v Self) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.Self(offset, v)
+	return w.ok
+}
+
+type Loop struct {
+	Tag uint8
+	Fn  uint32
+}
+
+const (
+	LoopFn   uint8 = 0
+	LoopSelf uint8 = 1
+)
+
+func (r *memReader) Loop(offset uint32) Loop {
+	v := Loop{Tag: r.u8(offset)}
+	switch v.Tag {
+	case LoopFn:
+		v.Fn = r.u32(offset + 4)
+	case LoopSelf:
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) Loop(offset uint32, v Loop) {
+	w.u8(offset, v.Tag)
+	switch v.Tag {
+	case LoopFn:
+		w.u32(offset+4, v.Fn)
+	case LoopSelf:
+	default:
+		w.ok = false
+	}
+}
+
+// ReadLoop reads a Loop from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadLoop(mem api.Memory, offset uint32) (Loop, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.Loop(offset)
+	return v, r.ok
+}
+
+// WriteLoop writes a Loop to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteLoop(mem api.Memory, offset uint32, v Loop) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.Loop(offset, v)
+	return w.ok
+}
+
+// TestKeywordsHost is implemented by the host to provide the functions of the `test_keywords` module.
+// Register an implementation with DefineTestKeywordsImports.
+// Methods receive the module calling them, whose memory parameters are read from.
+// Functions returning an error code return it last, results only being written if it is zero.
+type TestKeywordsHost interface {
+	// Return the type of a match.
+	Match(ctx context.Context, mod api.Module, self Self, fn Match) (Loop, Errno)
+
+	// Loop until the host stops.
+	Loop(ctx context.Context, mod api.Module, async uint32, gen uint32)
+}
+
+// DefineTestKeywordsImports returns a builder for the `test_keywords` host module, whose functions call host.
+// Instantiate it before the guest modules importing them.
+func DefineTestKeywordsImports(runtime wazero.Runtime, host TestKeywordsHost) wazero.HostModuleBuilder {
+	builder := runtime.NewHostModuleBuilder("test_keywords")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			self := r.Self(api.DecodeU32(stack[0]))
+			fn := Match(api.DecodeU32(stack[1]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[2])
+			result, errno := host.Match(ctx, mod, self, fn)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.Loop(resultPtr, result)
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("self", "fn", "result_ptr").
+		Export("match")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			async := api.DecodeU32(stack[0])
+			gen := api.DecodeU32(stack[1])
+			host.Loop(ctx, mod, async, gen)
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{}).
+		WithParameterNames("async", "gen").
+		Export("loop")
+	return builder
+}
@@ -0,0 +1,201 @@
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    """A string in guest memory."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    """A slice in guest memory, `len` being its number of elements."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host function to return the error code `code` to the guest."""
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    """Memory exported by the guest instance calling a host function."""
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get("memory")
+            if self._memory is None:
+                raise RuntimeError("The guest doesn't export its memory")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        """Return the `length` bytes at address `ptr`."""
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        """Write `data` at address `ptr`."""
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        """Return the UTF-8 string of `length` bytes at address `ptr`."""
+        return self.read(ptr, length).decode("utf-8")
+
+    def read_value(self, ctype, ptr: int):
+        """Return a copy of the value of type `ctype` at address `ptr`."""
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        """Return a copy of the `length` values of type `ctype` at address `ptr`."""
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        """Write `value`, converted to `ctype` if necessary, at address `ptr`."""
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    """A buffer of `size` bytes in guest memory, to be filled by a host function."""
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        """Write `data` to the buffer, `offset` bytes after its start."""
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        self.memory.write(self.ptr + offset, data)
+
+
+# ---------------------- Module: [test_keywords] ----------------------
+
+class Errno(enum.IntEnum):
+    SUCCESS = 0
+    INVAL = 1
+
+
+Type = ctypes.c_uint32
+
+
+class Match(enum.IntEnum):
+    TYPE = 0
+    FN = 1
+    LOOP = 2
+
+
+class Self(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("type", ctypes.c_uint32),
+        ("_pad0", ctypes.c_uint8 * 4),
+        ("async_", ctypes.c_uint64),
+    ]
+
+
+class Loop(ctypes.Structure):
+    class _Member(ctypes.Union):
+        _pack_ = 1
+        _fields_ = [
+            ("fn", ctypes.c_uint32),
+            ("_size", ctypes.c_uint8 * 4),
+        ]
+
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("_pad0", ctypes.c_uint8 * 3),
+        ("member", _Member),
+    ]
+
+    FN = 0
+    SELF = 1
+
+    @classmethod
+    def new_fn(cls, value) -> Loop:
+        """Return a `Loop` with the `fn` case."""
+        union = cls(tag=cls.FN)
+        union.member.fn = value
+        return union
+
+    @classmethod
+    def new_self_(cls) -> Loop:
+        """Return a `Loop` with the `self` case."""
+        union = cls(tag=cls.SELF)
+        return union
+
+
+class TestKeywordsHost:
+    """Functions of the `test_keywords` module, implemented by the host.
+
+    Override them in a subclass, and register an instance with `define_test_keywords_imports()`.
+    Methods receive the memory of the calling instance first.
+    Functions returning an error code raise `WasiError` to return it.
+    """
+
+    def match(self, memory: GuestMemory, self_: Self, fn: Match) -> Loop:
+        """Return the type of a match."""
+        raise NotImplementedError("match")
+
+    def loop(self, memory: GuestMemory, async_: int, gen: int) -> None:
+        """Loop until the host stops."""
+        raise NotImplementedError("loop")
+
+
+def define_test_keywords_imports(linker: wasmtime.Linker, store: wasmtime.Store, impl: TestKeywordsHost) -> None:
+    """Define the functions of the `test_keywords` module in `linker`, implemented by `impl`."""
+
+    def _match(_caller, self_, fn, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.match(_memory, _memory.read_value(Self, self_), Match(fn))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Loop, result_ptr, _results)
+        return 0
+
+    linker.define(store, "test_keywords", "match", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _match,
+        access_caller=True,
+    ))
+
+    def _loop(_caller, async_, gen):
+        _memory = GuestMemory(_caller)
+        impl.loop(_memory, async_, gen)
+
+    linker.define(store, "test_keywords", "loop", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], []),
+        _loop,
+        access_caller=True,
+    ))
+
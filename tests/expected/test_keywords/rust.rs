@@ -0,0 +1,489 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [test_keywords] ----------------------
+
+pub type Errno = u16;
+
+#[allow(non_snake_case)]
+pub mod ERRNO {
+    use super::Errno;
+    pub const SUCCESS: Errno = 0;
+    pub const INVAL: Errno = 1;
+}
+
+pub type Type = u32;
+
+pub type Match = u8;
+
+#[allow(non_snake_case)]
+pub mod MATCH {
+    use super::Match;
+    pub const TYPE: Match = 0;
+    pub const FN: Match = 1;
+    pub const LOOP: Match = 2;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Self_ {
+    pub r#type: u32,
+    pub r#async: u64,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<Self_>() == 16);
+    assert!(core::mem::align_of::<Self_>() == 8);
+    assert!(core::mem::offset_of!(Self_, r#type) == 0);
+    assert!(core::mem::offset_of!(Self_, r#async) == 8);
+};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union LoopMember {
+    r#fn: u32, // if tag=0
+    // self_ with no associated value if tag=1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Loop {
+    pub tag: u8,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    pub member: core::mem::MaybeUninit<LoopMember>,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<Loop>() == 8);
+    assert!(core::mem::align_of::<Loop>() == 4);
+    assert!(core::mem::offset_of!(Loop, tag) == 0);
+    assert!(core::mem::offset_of!(Loop, member) == 4);
+};
+
+impl Loop {
+    fn new(tag: u8) -> Self {
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<LoopRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(LoopRef::Fn(unsafe { &self.member.assume_init_ref().r#fn })),
+            1 => Ok(LoopRef::Self_),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
+    // --- r#fn: u32 if tag=0
+
+    pub fn new_fn(val: u32) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = core::mem::MaybeUninit::new(LoopMember { r#fn: val });
+        tu
+    }
+
+    pub fn into_fn(self) -> u32 {
+        assert_eq!(self.tag, 0);
+        unsafe { self.member.assume_init().r#fn }
+    }
+
+    pub fn set_fn(&mut self, val: u32) {
+        assert_eq!(self.tag, 0);
+        let uval = LoopMember { r#fn: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_fn(&self) -> bool {
+        self.tag == 0
+    }
+
+    // --- self_: (no associated content) if tag=1
+
+    pub fn new_self() -> Self {
+        Self::new(1)
+    }
+
+    pub fn is_self(&self) -> bool {
+        self.tag == 1
+    }
+}
+
+/// Content of a `Loop`.
+#[derive(Copy, Clone, Debug)]
+pub enum LoopEnum {
+    Fn(u32),
+    Self_,
+}
+
+/// Reference to the content of a `Loop`.
+#[derive(Copy, Clone, Debug)]
+pub enum LoopRef<'a> {
+    Fn(&'a u32),
+    Self_,
+}
+
+impl<'a> core::convert::TryFrom<&'a Loop> for LoopRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a Loop) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<LoopRef<'_>> for LoopEnum {
+    fn from(content: LoopRef<'_>) -> Self {
+        match content {
+            LoopRef::Fn(val) => LoopEnum::Fn(*val),
+            LoopRef::Self_ => LoopEnum::Self_,
+        }
+    }
+}
+
+impl core::convert::TryFrom<Loop> for LoopEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: Loop) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<LoopEnum> for Loop {
+    fn from(content: LoopEnum) -> Self {
+        match content {
+            LoopEnum::Fn(val) => Self::new_fn(val),
+            LoopEnum::Self_ => Self::new_self(),
+        }
+    }
+}
+
+impl core::fmt::Debug for Loop {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
+/// Error returned by the functions of the `test_keywords` module.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TestKeywordsError {
+    Inval,
+    /// An error code that is not an error case of `Errno`.
+    Other(Errno),
+}
+
+impl From<Errno> for TestKeywordsError {
+    fn from(e: Errno) -> Self {
+        match e {
+            ERRNO::INVAL => TestKeywordsError::Inval,
+            e => TestKeywordsError::Other(e),
+        }
+    }
+}
+
+impl From<TestKeywordsError> for Errno {
+    fn from(e: TestKeywordsError) -> Self {
+        match e {
+            TestKeywordsError::Inval => ERRNO::INVAL,
+            TestKeywordsError::Other(e) => e,
+        }
+    }
+}
+
+impl From<TestKeywordsError> for Error {
+    fn from(e: TestKeywordsError) -> Self {
+        Error::WasiError(Errno::from(e) as _)
+    }
+}
+
+impl core::fmt::Display for TestKeywordsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            TestKeywordsError::Inval => "inval",
+            TestKeywordsError::Other(e) => return write!(f, "Unknown error {}", e),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for TestKeywordsError {}
+
+/// Return the type of a match.
+pub fn r#match(
+    self_: Self_,
+    r#fn: Match,
+) -> Result<Loop, TestKeywordsError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_keywords")]
+        extern "C" {
+            #[link_name = "match"]
+            pub fn r#match(
+                self_: Self_,
+                r#fn: Match,
+                result_ptr: WasiMutPtr<Loop>,
+            ) -> Errno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn r#match(
+            self_: Self_,
+            r#fn: Match,
+            result_ptr: WasiMutPtr<Loop>,
+        ) -> Errno {
+            match super::with_test_keywords_mock(|mock| mock.r#match(self_, r#fn)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::r#match(
+        self_,
+        r#fn,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(TestKeywordsError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Loop until the host stops.
+pub fn r#loop(
+    r#async: u32,
+    r#gen: u32,
+) {
+    mod raw {
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_keywords")]
+        extern "C" {
+            #[link_name = "loop"]
+            pub fn r#loop(
+                r#async: u32,
+                r#gen: u32,
+            );
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn r#loop(
+            r#async: u32,
+            r#gen: u32,
+        ) {
+            super::with_test_keywords_mock(|mock| mock.r#loop(r#async, r#gen))
+        }
+    }
+    unsafe { raw::r#loop(
+        r#async,
+        r#gen,
+    )}
+}
+
+/// Implementation of the functions of the `test_keywords` module used on native targets.
+/// Install it with `set_test_keywords_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait TestKeywordsMock {
+    /// Return the type of a match.
+    #[allow(unused_variables)]
+    fn r#match(
+        &self,
+        self_: Self_,
+        r#fn: Match,
+    ) -> Result<Loop, TestKeywordsError> {
+        unimplemented!("match")
+    }
+
+    /// Loop until the host stops.
+    #[allow(unused_variables)]
+    fn r#loop(
+        &self,
+        r#async: u32,
+        r#gen: u32,
+    ) {
+        unimplemented!("loop")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static TEST_KEYWORDS_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn TestKeywordsMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `test_keywords` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_test_keywords_mock(mock: impl TestKeywordsMock + 'static) -> Option<std::rc::Rc<dyn TestKeywordsMock>> {
+    TEST_KEYWORDS_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_test_keywords_mock<R>(f: impl FnOnce(&dyn TestKeywordsMock) -> R) -> R {
+    let mock = TEST_KEYWORDS_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `test_keywords` module installed"))
+}
+
+/// Functions of the `test_keywords` module implemented by the guest.
+/// Implement this trait for a type, and export its methods to the host with `export_test_keywords!(Type)`.
+pub trait TestKeywordsExports {
+    /// Called by the host to run asynchronous work.
+    fn r#async(
+        r#type: u32,
+    ) -> Result<Type, Errno>;
+}
+
+/// Export the methods of a type implementing `TestKeywordsExports` to the host.
+/// If the bindings are not at the root of their crate, the paths of the modules defining their types
+/// have to be given, as in `export_test_keywords!(Type, with_types_in crate::bindings)`.
+#[macro_export]
+macro_rules! export_test_keywords {
+    ($t:ty) => {
+        $crate::export_test_keywords!($t, with_types_in $crate);
+    };
+    ($t:ty, with_types_in $($path:path),+) => {
+        const _: () = {
+            $(
+                #[allow(unused_imports)]
+                use $path::*;
+            )+
+
+            #[export_name = "async"]
+            pub extern "C" fn r#async(
+                r#type: u32,
+                result_ptr: WasiMutPtr<Type>,
+            ) -> Errno {
+                match <$t as TestKeywordsExports>::r#async(r#type) {
+                    Ok(res) => {
+                        unsafe { result_ptr.write(res) };
+                        0
+                    }
+                    Err(e) => e,
+                }
+            }
+        };
+    };
+}
+
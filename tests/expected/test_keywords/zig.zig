@@ -0,0 +1,147 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [test_keywords] ----------------------
+
+pub const Errno = enum(u16) {
+    SUCCESS = 0,
+    INVAL = 1,
+};
+
+pub const Type = u32;
+
+pub const Match = enum(u8) {
+    TYPE = 0,
+    FN = 1,
+    LOOP = 2,
+};
+
+pub const Self = extern struct {
+    @"type": u32,
+    __pad32_0: u32 = undefined,
+    @"async": u64,
+};
+
+
+pub const Loop = extern struct {
+    tag: enum(u8) {
+        @"fn" = 0,
+        self = 1,
+    },
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    member = extern union {
+        @"fn": u32,
+    },
+
+
+fn newFn(val: u32) Loop {
+    return Loop{ .tag = .@"fn", .member = .{ .@"fn" = val } };
+}
+
+pub fn Fn(self: Loop) u32 {
+    std.debug.assert(self.tag == .@"fn");
+    return self.member.@"fn";
+}
+
+pub fn setFn(self: *Loop, val: u32) void {
+    std.debug.assert(self.tag == .@"fn");
+    self.member.@"fn" = val;
+}
+
+fn isFn(self: Loop) bool {
+    return self.tag == .@"fn";
+}
+
+
+fn newSelf() Loop {
+    return Loop { .tag = .self };
+}
+
+fn isSelf(self: Loop) bool {
+    return self.tag == .self;
+}
+
+};
+
+
+pub const TestKeywords = struct {
+    /// Return the type of a match.
+    pub extern "test_keywords" fn match(
+        self: Self,
+        @"fn": Match,
+        result_ptr: WasiMutPtr(Loop),
+    ) callconv(.C) Errno;
+
+    /// Loop until the host stops.
+    pub extern "test_keywords" fn loop(
+        @"async": u32,
+        gen: u32,
+    ) callconv(.C) void;
+
+    /// Called by the host to run asynchronous work.
+    export fn @"async"(
+        @"type": u32,
+        result_ptr: WasiMutPtr(Type),
+    ) callconv(.C) Errno {
+        return @import("root").@"async"(@"type", result_ptr);
+    }
+
+};
+
@@ -0,0 +1,198 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/*
+* ---------------------- Module: [test_layout] ----------------------
+ */
+
+/**
+ * A tagged union, whose members are 4-byte aligned
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class SmallUnion {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 4);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): SmallUnion {
+        let tu = new SmallUnion(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 4);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- word: u32 if tag=0
+
+    static word(val: u32): SmallUnion {
+        return SmallUnion.new(0, val);
+    }
+
+    setWord(val: u32): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isWord(): bool {
+        return this.tag === 0;
+    }
+
+    getWord(): u32 {
+        return this.get<u32>();
+    }
+
+    // --- half_word: u16 if tag=1
+
+    static halfWord(val: u16): SmallUnion {
+        return SmallUnion.new(1, val);
+    }
+
+    setHalfWord(val: u16): void {
+        this.tag = 1;
+        this.set(val);
+    }
+
+    isHalfWord(): bool {
+        return this.tag === 1;
+    }
+
+    getHalfWord(): u16 {
+        return this.get<u16>();
+    }
+}
+
+
+/**
+ * A structure with padding between members, and after the last one
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class PaddedRecord {
+    flag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    private __pad32_0: u32;
+    value: u64;
+    count: u16;
+    private __pad16_2: u16;
+    private __pad32_2: u32;
+}
+
+
+/**
+ * A tuple with padding between members, and after the last one
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class PaddedTuple { // -- Tuple
+    v0: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    v1: u32;
+    v2: u16;
+    private __pad16_2: u16;
+}
+
+
+/**
+ * A structure that only contains a tagged union
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class WrappedUnion {
+    value: SmallUnion;
+}
+
+
+/**
+ * A structure with a tagged union after a smaller member
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class UnalignedUnion {
+    flag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    value: SmallUnion;
+}
+
+
@@ -0,0 +1,270 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [test_layout] ----------------------
+
+namespace test_layout {
+
+    /// A tagged union, whose members are 4-byte aligned
+    struct SmallUnion {
+        enum class Tag : uint8_t {
+            WORD = 0,
+            HALF_WORD = 1,
+        };
+
+        Tag tag;
+        union {
+            uint32_t word;
+            uint16_t half_word;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- word: uint32_t if tag=0
+
+        static SmallUnion make_word(uint32_t val) {
+            SmallUnion tu{};
+            tu.tag = Tag::WORD;
+            tu.member.word = val;
+            return tu;
+        }
+
+        void emplace_word(uint32_t val) {
+            tag = Tag::WORD;
+            member.word = val;
+        }
+
+        const uint32_t *get_if_word() const {
+            return holds_word() ? &member.word : nullptr;
+        }
+
+        uint32_t *get_if_word() {
+            return holds_word() ? &member.word : nullptr;
+        }
+
+        bool holds_word() const {
+            return tag == Tag::WORD;
+        }
+
+        // --- half_word: uint16_t if tag=1
+
+        static SmallUnion make_half_word(uint16_t val) {
+            SmallUnion tu{};
+            tu.tag = Tag::HALF_WORD;
+            tu.member.half_word = val;
+            return tu;
+        }
+
+        void emplace_half_word(uint16_t val) {
+            tag = Tag::HALF_WORD;
+            member.half_word = val;
+        }
+
+        const uint16_t *get_if_half_word() const {
+            return holds_half_word() ? &member.half_word : nullptr;
+        }
+
+        uint16_t *get_if_half_word() {
+            return holds_half_word() ? &member.half_word : nullptr;
+        }
+
+        bool holds_half_word() const {
+            return tag == Tag::HALF_WORD;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(SmallUnion, member) == 4, "unexpected layout");
+    #endif
+
+    /// A structure with padding between members, and after the last one
+    struct PaddedRecord {
+        uint8_t flag;
+        uint8_t __pad8_0;
+        uint16_t __pad16_0;
+        uint32_t __pad32_0;
+        uint64_t value;
+        uint16_t count;
+        uint16_t __pad16_2;
+        uint32_t __pad32_2;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(PaddedRecord, flag) == 0, "unexpected layout");
+    static_assert(offsetof(PaddedRecord, value) == 8, "unexpected layout");
+    static_assert(offsetof(PaddedRecord, count) == 16, "unexpected layout");
+    #endif
+
+    /// A tuple with padding between members, and after the last one
+    struct PaddedTuple { // -- Tuple
+        uint8_t v0;
+        uint8_t __pad8_0;
+        uint16_t __pad16_0;
+        uint32_t v1;
+        uint16_t v2;
+        uint16_t __pad16_2;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(PaddedTuple, v0) == 0, "unexpected layout");
+    static_assert(offsetof(PaddedTuple, v1) == 4, "unexpected layout");
+    static_assert(offsetof(PaddedTuple, v2) == 8, "unexpected layout");
+    #endif
+
+    /// A structure that only contains a tagged union
+    struct WrappedUnion {
+        SmallUnion value;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(WrappedUnion, value) == 0, "unexpected layout");
+    #endif
+
+    /// A structure with a tagged union after a smaller member
+    struct UnalignedUnion {
+        uint8_t flag;
+        uint8_t __pad8_0;
+        uint16_t __pad16_0;
+        SmallUnion value;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(UnalignedUnion, flag) == 0, "unexpected layout");
+    static_assert(offsetof(UnalignedUnion, value) == 4, "unexpected layout");
+    #endif
+
+    namespace raw {
+
+    } // namespace raw
+
+} // namespace test_layout
+
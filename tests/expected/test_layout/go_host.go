@@ -0,0 +1,374 @@
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+package testlayout
+
+import (
+	"context"
+	"errors"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New("invalid guest memory access")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// ---------------------- Module: [test_layout] ----------------------
+
+// A tagged union, whose members are 4-byte aligned
+type SmallUnion struct {
+	Tag      uint8
+	Word     uint32
+	HalfWord uint16
+}
+
+const (
+	SmallUnionWord     uint8 = 0
+	SmallUnionHalfWord uint8 = 1
+)
+
+func (r *memReader) SmallUnion(offset uint32) SmallUnion {
+	v := SmallUnion{Tag: r.u8(offset)}
+	switch v.Tag {
+	case SmallUnionWord:
+		v.Word = r.u32(offset + 4)
+	case SmallUnionHalfWord:
+		v.HalfWord = r.u16(offset + 4)
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) SmallUnion(offset uint32, v SmallUnion) {
+	w.u8(offset, v.Tag)
+	switch v.Tag {
+	case SmallUnionWord:
+		w.u32(offset+4, v.Word)
+	case SmallUnionHalfWord:
+		w.u16(offset+4, v.HalfWord)
+	default:
+		w.ok = false
+	}
+}
+
+// ReadSmallUnion reads a SmallUnion from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadSmallUnion(mem api.Memory, offset uint32) (SmallUnion, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.SmallUnion(offset)
+	return v, r.ok
+}
+
+// WriteSmallUnion writes a SmallUnion to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteSmallUnion(mem api.Memory, offset uint32, v SmallUnion) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.SmallUnion(offset, v)
+	return w.ok
+}
+
+// A structure with padding between members, and after the last one
+type PaddedRecord struct {
+	Flag  uint8
+	Value uint64
+	Count uint16
+}
+
+func (r *memReader) PaddedRecord(offset uint32) PaddedRecord {
+	return PaddedRecord{
+		Flag:  r.u8(offset),
+		Value: r.u64(offset + 8),
+		Count: r.u16(offset + 16),
+	}
+}
+
+func (w *memWriter) PaddedRecord(offset uint32, v PaddedRecord) {
+	w.u8(offset, v.Flag)
+	w.u64(offset+8, v.Value)
+	w.u16(offset+16, v.Count)
+}
+
+// ReadPaddedRecord reads a PaddedRecord from guest memory at offset, and returns false if it is out of range.
+func ReadPaddedRecord(mem api.Memory, offset uint32) (PaddedRecord, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.PaddedRecord(offset)
+	return v, r.ok
+}
+
+// WritePaddedRecord writes a PaddedRecord to guest memory at offset, and returns false if it is out of range.
+func WritePaddedRecord(mem api.Memory, offset uint32, v PaddedRecord) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.PaddedRecord(offset, v)
+	return w.ok
+}
+
+// A tuple with padding between members, and after the last one
+type PaddedTuple struct {
+	V0 uint8
+	V1 uint32
+	V2 uint16
+}
+
+func (r *memReader) PaddedTuple(offset uint32) PaddedTuple {
+	return PaddedTuple{
+		V0: r.u8(offset),
+		V1: r.u32(offset + 4),
+		V2: r.u16(offset + 8),
+	}
+}
+
+func (w *memWriter) PaddedTuple(offset uint32, v PaddedTuple) {
+	w.u8(offset, v.V0)
+	w.u32(offset+4, v.V1)
+	w.u16(offset+8, v.V2)
+}
+
+// ReadPaddedTuple reads a PaddedTuple from guest memory at offset, and returns false if it is out of range.
+func ReadPaddedTuple(mem api.Memory, offset uint32) (PaddedTuple, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.PaddedTuple(offset)
+	return v, r.ok
+}
+
+// WritePaddedTuple writes a PaddedTuple to guest memory at offset, and returns false if it is out of range.
+func WritePaddedTuple(mem api.Memory, offset uint32, v PaddedTuple) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.PaddedTuple(offset, v)
+	return w.ok
+}
+
+// A structure that only contains a tagged union
+type WrappedUnion struct {
+	Value SmallUnion
+}
+
+func (r *memReader) WrappedUnion(offset uint32) WrappedUnion {
+	return WrappedUnion{
+		Value: r.SmallUnion(offset),
+	}
+}
+
+func (w *memWriter) WrappedUnion(offset uint32, v WrappedUnion) {
+	w.SmallUnion(offset, v.Value)
+}
+
+// ReadWrappedUnion reads a WrappedUnion from guest memory at offset, and returns false if it is out of range.
+func ReadWrappedUnion(mem api.Memory, offset uint32) (WrappedUnion, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.WrappedUnion(offset)
+	return v, r.ok
+}
+
+// WriteWrappedUnion writes a WrappedUnion to guest memory at offset, and returns false if it is out of range.
+func WriteWrappedUnion(mem api.Memory, offset uint32, v WrappedUnion) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.WrappedUnion(offset, v)
+	return w.ok
+}
+
+// A structure with a tagged union after a smaller member
+type UnalignedUnion struct {
+	Flag  uint8
+	Value SmallUnion
+}
+
+func (r *memReader) UnalignedUnion(offset uint32) UnalignedUnion {
+	return UnalignedUnion{
+		Flag:  r.u8(offset),
+		Value: r.SmallUnion(offset + 4),
+	}
+}
+
+func (w *memWriter) UnalignedUnion(offset uint32, v UnalignedUnion) {
+	w.u8(offset, v.Flag)
+	w.SmallUnion(offset+4, v.Value)
+}
+
+// ReadUnalignedUnion reads a UnalignedUnion from guest memory at offset, and returns false if it is out of range.
+func ReadUnalignedUnion(mem api.Memory, offset uint32) (UnalignedUnion, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.UnalignedUnion(offset)
+	return v, r.ok
+}
+
+// WriteUnalignedUnion writes a UnalignedUnion to guest memory at offset, and returns false if it is out of range.
+func WriteUnalignedUnion(mem api.Memory, offset uint32, v UnalignedUnion) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.UnalignedUnion(offset, v)
+	return w.ok
+}
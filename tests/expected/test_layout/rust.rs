@@ -0,0 +1,330 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [test_layout] ----------------------
+
+/// A tagged union, whose members are 4-byte aligned
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union SmallUnionMember {
+    word: u32, // if tag=0
+    half_word: u16, // if tag=1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SmallUnion {
+    pub tag: u8,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    pub member: core::mem::MaybeUninit<SmallUnionMember>,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<SmallUnion>() == 8);
+    assert!(core::mem::align_of::<SmallUnion>() == 4);
+    assert!(core::mem::offset_of!(SmallUnion, tag) == 0);
+    assert!(core::mem::offset_of!(SmallUnion, member) == 4);
+};
+
+impl SmallUnion {
+    fn new(tag: u8) -> Self {
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<SmallUnionRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(SmallUnionRef::Word(unsafe { &self.member.assume_init_ref().word })),
+            1 => Ok(SmallUnionRef::HalfWord(unsafe { &self.member.assume_init_ref().half_word })),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
+    // --- word: u32 if tag=0
+
+    pub fn new_word(val: u32) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = core::mem::MaybeUninit::new(SmallUnionMember { word: val });
+        tu
+    }
+
+    pub fn into_word(self) -> u32 {
+        assert_eq!(self.tag, 0);
+        unsafe { self.member.assume_init().word }
+    }
+
+    pub fn set_word(&mut self, val: u32) {
+        assert_eq!(self.tag, 0);
+        let uval = SmallUnionMember { word: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_word(&self) -> bool {
+        self.tag == 0
+    }
+
+    // --- half_word: u16 if tag=1
+
+    pub fn new_half_word(val: u16) -> Self {
+        let mut tu = Self::new(1);
+        tu.member = core::mem::MaybeUninit::new(SmallUnionMember { half_word: val });
+        tu
+    }
+
+    pub fn into_half_word(self) -> u16 {
+        assert_eq!(self.tag, 1);
+        unsafe { self.member.assume_init().half_word }
+    }
+
+    pub fn set_half_word(&mut self, val: u16) {
+        assert_eq!(self.tag, 1);
+        let uval = SmallUnionMember { half_word: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_half_word(&self) -> bool {
+        self.tag == 1
+    }
+}
+
+/// Content of a `SmallUnion`.
+#[derive(Copy, Clone, Debug)]
+pub enum SmallUnionEnum {
+    Word(u32),
+    HalfWord(u16),
+}
+
+/// Reference to the content of a `SmallUnion`.
+#[derive(Copy, Clone, Debug)]
+pub enum SmallUnionRef<'a> {
+    Word(&'a u32),
+    HalfWord(&'a u16),
+}
+
+impl<'a> core::convert::TryFrom<&'a SmallUnion> for SmallUnionRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a SmallUnion) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<SmallUnionRef<'_>> for SmallUnionEnum {
+    fn from(content: SmallUnionRef<'_>) -> Self {
+        match content {
+            SmallUnionRef::Word(val) => SmallUnionEnum::Word(*val),
+            SmallUnionRef::HalfWord(val) => SmallUnionEnum::HalfWord(*val),
+        }
+    }
+}
+
+impl core::convert::TryFrom<SmallUnion> for SmallUnionEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: SmallUnion) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<SmallUnionEnum> for SmallUnion {
+    fn from(content: SmallUnionEnum) -> Self {
+        match content {
+            SmallUnionEnum::Word(val) => Self::new_word(val),
+            SmallUnionEnum::HalfWord(val) => Self::new_half_word(val),
+        }
+    }
+}
+
+impl core::fmt::Debug for SmallUnion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
+/// A structure with padding between members, and after the last one
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct PaddedRecord {
+    pub flag: u8,
+    pub value: u64,
+    pub count: u16,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<PaddedRecord>() == 24);
+    assert!(core::mem::align_of::<PaddedRecord>() == 8);
+    assert!(core::mem::offset_of!(PaddedRecord, flag) == 0);
+    assert!(core::mem::offset_of!(PaddedRecord, value) == 8);
+    assert!(core::mem::offset_of!(PaddedRecord, count) == 16);
+};
+
+/// A tuple with padding between members, and after the last one
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct PaddedTuple { // -- Tuple
+    pub v0: u8,
+    pub v1: u32,
+    pub v2: u16,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<PaddedTuple>() == 12);
+    assert!(core::mem::align_of::<PaddedTuple>() == 4);
+    assert!(core::mem::offset_of!(PaddedTuple, v0) == 0);
+    assert!(core::mem::offset_of!(PaddedTuple, v1) == 4);
+    assert!(core::mem::offset_of!(PaddedTuple, v2) == 8);
+};
+
+/// A structure that only contains a tagged union
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WrappedUnion {
+    pub value: SmallUnion,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<WrappedUnion>() == 8);
+    assert!(core::mem::align_of::<WrappedUnion>() == 4);
+    assert!(core::mem::offset_of!(WrappedUnion, value) == 0);
+};
+
+/// A structure with a tagged union after a smaller member
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct UnalignedUnion {
+    pub flag: u8,
+    pub value: SmallUnion,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<UnalignedUnion>() == 12);
+    assert!(core::mem::align_of::<UnalignedUnion>() == 4);
+    assert!(core::mem::offset_of!(UnalignedUnion, flag) == 0);
+    assert!(core::mem::offset_of!(UnalignedUnion, value) == 4);
+};
+
@@ -0,0 +1,157 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [test_layout] ----------------------
+
+/// A tagged union, whose members are 4-byte aligned
+pub const SmallUnion = extern struct {
+    tag: enum(u8) {
+        word = 0,
+        half_word = 1,
+    },
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    member = extern union {
+        word: u32,
+        half_word: u16,
+    },
+
+
+fn newWord(val: u32) SmallUnion {
+    return SmallUnion{ .tag = .word, .member = .{ .word = val } };
+}
+
+pub fn Word(self: SmallUnion) u32 {
+    std.debug.assert(self.tag == .word);
+    return self.member.word;
+}
+
+pub fn setWord(self: *SmallUnion, val: u32) void {
+    std.debug.assert(self.tag == .word);
+    self.member.word = val;
+}
+
+fn isWord(self: SmallUnion) bool {
+    return self.tag == .word;
+}
+
+
+fn newHalfWord(val: u16) SmallUnion {
+    return SmallUnion{ .tag = .half_word, .member = .{ .half_word = val } };
+}
+
+pub fn HalfWord(self: SmallUnion) u16 {
+    std.debug.assert(self.tag == .half_word);
+    return self.member.half_word;
+}
+
+pub fn setHalfWord(self: *SmallUnion, val: u16) void {
+    std.debug.assert(self.tag == .half_word);
+    self.member.half_word = val;
+}
+
+fn isHalfWord(self: SmallUnion) bool {
+    return self.tag == .half_word;
+}
+
+};
+
+
+/// A structure with padding between members, and after the last one
+pub const PaddedRecord = extern struct {
+    flag: u8,
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    __pad32_0: u32 = undefined,
+    value: u64,
+    count: u16,
+    __pad16_2: u16 = undefined,
+    __pad32_2: u32 = undefined,
+};
+
+
+/// A tuple with padding between members, and after the last one
+pub const PaddedTuple = extern struct { // -- Tuple
+    v0: u8,
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    v1: u32,
+    v2: u16,
+    __pad16_2: u16 = undefined,
+};
+
+
+/// A structure that only contains a tagged union
+pub const WrappedUnion = extern struct {
+    value: SmallUnion,
+};
+
+
+/// A structure with a tagged union after a smaller member
+pub const UnalignedUnion = extern struct {
+    flag: u8,
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    value: SmallUnion,
+};
+
+
+pub const TestLayout = struct {
+};
+
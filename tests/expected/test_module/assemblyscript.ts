@@ -0,0 +1,370 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/*
+* ---------------------- Module: [test_module] ----------------------
+ */
+
+/**
+ * An enumeration
+ */
+export type TestErrno = u16;
+
+export namespace TestErrno {
+    /**
+     * Operation succeeded.
+     */
+    export const SUCCESS: TestErrno = 0;
+    /**
+     * A guest error occurred
+     */
+    export const GUEST_ERROR: TestErrno = 1;
+    /**
+     * Something else went wrong
+     */
+    export const SOME_OTHER_ERROR: TestErrno = 2;
+}
+
+/**
+ * A boolean alias
+ */
+export type TestBool = bool;
+
+/**
+ * A u32 alias
+ */
+export type TestMediumInt = u32;
+
+export namespace TestMediumInt {
+    export const ZERO: TestMediumInt = 0;
+    export const ONE: TestMediumInt = 1;
+    export const TWO: TestMediumInt = 2;
+    export const THREE: TestMediumInt = 3;
+}
+
+/**
+ * A u64 alias
+ */
+export type TestBigInt = u64;
+
+export namespace TestBigInt {
+    export const ZERO: TestBigInt = 0x0;
+    export const A_HUNDRED: TestBigInt = 0x64;
+    export const A_BIG_VALUE: TestBigInt = 0xff00000000000000;
+    export const A_BIGGER_VALUE: TestBigInt = 0xffffffffffffffff;
+}
+
+/**
+ * Flags
+ */
+export type TestBigFlags = u8;
+
+export namespace TestBigFlags {
+    /**
+     * The first flag
+     */
+    export const A: TestBigFlags = 0x1;
+    export const B: TestBigFlags = 0x2;
+    export const C: TestBigFlags = 0x4;
+    export const D: TestBigFlags = 0x8;
+}
+
+
+/**
+ * A structure
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestStruct {
+    /**
+     * A boolean
+     */
+    a_boolean: bool;
+    /**
+     * A byte
+     */
+    a_byte: u8;
+    private __pad16_1: u16;
+    /**
+     * A string
+     *
+     * Its content is not copied.
+     */
+    a_string: WasiString;
+}
+
+
+/**
+ * A tuple
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestTuple { // -- Tuple
+    v0: TestBool;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    v1: TestMediumInt;
+    v2: TestBigInt;
+}
+
+
+/**
+ * A string
+ */
+export type TestString = WasiString;
+
+/**
+ * An output buffer
+ */
+export type TestOutputBuffer = WasiMutSlice<u16>;
+
+/**
+ * An input buffer
+ */
+export type TestInputBuffer = WasiSlice<u16>;
+
+/**
+ * A tagged union
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class TestTaggedUnion {
+    tag: u16;
+    private __pad16_0: u16;
+
+    constructor(tag: u16) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 8);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u16, val: T = 0): TestTaggedUnion {
+        let tu = new TestTaggedUnion(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 8);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- first_choice: u8 if tag=0
+
+    /**
+     * The first choice
+     */
+    static firstChoice(val: u8): TestTaggedUnion {
+        return TestTaggedUnion.new(0, val);
+    }
+
+    setFirstChoice(val: u8): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isFirstChoice(): bool {
+        return this.tag === 0;
+    }
+
+    getFirstChoice(): u8 {
+        return this.get<u8>();
+    }
+
+    // --- second_choice: WasiString if tag=1
+
+    static secondChoice(val: WasiString): TestTaggedUnion {
+        return TestTaggedUnion.new(1, val);
+    }
+
+    setSecondChoice(val: WasiString): void {
+        this.tag = 1;
+        this.set(val);
+    }
+
+    isSecondChoice(): bool {
+        return this.tag === 1;
+    }
+
+    getSecondChoice(): WasiString {
+        return this.get<WasiString>();
+    }
+
+    // --- third_choice: f32 if tag=2
+
+    static thirdChoice(val: f32): TestTaggedUnion {
+        return TestTaggedUnion.new(2, val);
+    }
+
+    setThirdChoice(val: f32): void {
+        this.tag = 2;
+        this.set(val);
+    }
+
+    isThirdChoice(): bool {
+        return this.tag === 2;
+    }
+
+    getThirdChoice(): f32 {
+        return this.get<f32>();
+    }
+
+    // --- empty_choice: (no associated content) if tag=3
+
+    /**
+     * A choice without a value
+     */
+    static emptyChoice(): TestTaggedUnion {
+        return TestTaggedUnion.new(3);
+    }
+
+    setEmptyChoice(): void {
+        this.tag = 3;
+    }
+
+    isEmptyChoice(): bool {
+        return this.tag === 3;
+    }
+}
+
+
+/**
+ * This function returns multiple values
+ * @param some_parameter A number
+ * @param some_other_parameter A string
+ *
+ * It can be empty.
+ */
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_multiple_values")
+export declare function aFunctionThatReturnsMultipleValues(
+    some_parameter: u64,
+    some_other_parameter_ptr: WasiPtr<Char8>,
+    some_other_parameter_len: usize,
+    result0_ptr: WasiMutPtr<TestMediumInt>,
+    result1_ptr: WasiMutPtr<TestBigInt>
+): TestErrno;
+
+/**
+ * This function returns an actual tuple (expanded into multiple values)
+ */
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_an_actual_tuple")
+export declare function aFunctionThatReturnsAnActualTuple(
+    some_parameter: u64,
+    result0_ptr: WasiMutPtr<TestBool>,
+    result1_ptr: WasiMutPtr<TestMediumInt>,
+    result2_ptr: WasiMutPtr<TestBigInt>
+): TestErrno;
+
+/**
+ * This function returns nothing
+ */
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_nothing")
+export declare function aFunctionThatReturnsNothing(
+    some_parameter: u64
+): TestErrno;
+
+/**
+ * This function gets a string
+ */
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_gets_a_string")
+export declare function aFunctionThatGetsAString(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+    result_ptr: WasiMutPtr<TestBool>
+): TestErrno;
+
+/**
+ * This function return a tagged union
+ */
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_returns_a_tagged_union")
+export declare function aFunctionThatReturnsATaggedUnion(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+    result_ptr: WasiMutPtr<TestTaggedUnion>
+): TestErrno;
+
+/**
+ * This function gets and returns a string
+ */
+// @ts-ignore: decorator
+@external("test_module", "a_function_that_gets_and_returns_a_string")
+export declare function aFunctionThatGetsAndReturnsAString(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+    result_ptr: WasiMutPtr<TestString>
+): TestErrno;
+
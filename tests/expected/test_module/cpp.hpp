@@ -0,0 +1,462 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [test_module] ----------------------
+
+namespace test_module {
+
+    /// An enumeration
+    enum class TestErrno : uint16_t {
+        /// Operation succeeded.
+        SUCCESS = 0,
+        /// A guest error occurred
+        GUEST_ERROR = 1,
+        /// Something else went wrong
+        SOME_OTHER_ERROR = 2,
+    };
+
+    /// A boolean alias
+    using TestBool = bool;
+
+    /// A u32 alias
+    using TestMediumInt = uint32_t;
+
+    namespace test_medium_int {
+        constexpr TestMediumInt ZERO = 0;
+        constexpr TestMediumInt ONE = 1;
+        constexpr TestMediumInt TWO = 2;
+        constexpr TestMediumInt THREE = 3;
+    } // namespace test_medium_int
+
+    /// A u64 alias
+    using TestBigInt = uint64_t;
+
+    namespace test_big_int {
+        constexpr TestBigInt ZERO = 0x0;
+        constexpr TestBigInt A_HUNDRED = 0x64;
+        constexpr TestBigInt A_BIG_VALUE = 0xff00000000000000;
+        constexpr TestBigInt A_BIGGER_VALUE = 0xffffffffffffffff;
+    } // namespace test_big_int
+
+    /// Flags
+    using TestBigFlags = uint8_t;
+
+    namespace test_big_flags {
+        /// The first flag
+        constexpr TestBigFlags A = 0x1;
+        constexpr TestBigFlags B = 0x2;
+        constexpr TestBigFlags C = 0x4;
+        constexpr TestBigFlags D = 0x8;
+    } // namespace test_big_flags
+
+    /// A structure
+    struct TestStruct {
+        /// A boolean
+        bool a_boolean;
+        /// A byte
+        uint8_t a_byte;
+        uint16_t __pad16_1;
+        /// A string
+        ///
+        /// Its content is not copied.
+        WasiString a_string;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(TestStruct, a_boolean) == 0, "unexpected layout");
+    static_assert(offsetof(TestStruct, a_byte) == 1, "unexpected layout");
+    static_assert(offsetof(TestStruct, a_string) == 4, "unexpected layout");
+    #endif
+
+    /// A tuple
+    struct TestTuple { // -- Tuple
+        TestBool v0;
+        uint8_t __pad8_0;
+        uint16_t __pad16_0;
+        TestMediumInt v1;
+        TestBigInt v2;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(TestTuple, v0) == 0, "unexpected layout");
+    static_assert(offsetof(TestTuple, v1) == 4, "unexpected layout");
+    static_assert(offsetof(TestTuple, v2) == 8, "unexpected layout");
+    #endif
+
+    /// A string
+    using TestString = WasiString;
+
+    /// An output buffer
+    using TestOutputBuffer = WasiMutSlice<uint16_t>;
+
+    /// An input buffer
+    using TestInputBuffer = WasiSlice<uint16_t>;
+
+    /// A tagged union
+    struct TestTaggedUnion {
+        enum class Tag : uint16_t {
+            /// The first choice
+            FIRST_CHOICE = 0,
+            SECOND_CHOICE = 1,
+            THIRD_CHOICE = 2,
+            /// A choice without a value
+            EMPTY_CHOICE = 3,
+        };
+
+        Tag tag;
+        union {
+            uint8_t first_choice;
+            WasiString second_choice;
+            float third_choice;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- first_choice: uint8_t if tag=0
+
+        static TestTaggedUnion make_first_choice(uint8_t val) {
+            TestTaggedUnion tu{};
+            tu.tag = Tag::FIRST_CHOICE;
+            tu.member.first_choice = val;
+            return tu;
+        }
+
+        void emplace_first_choice(uint8_t val) {
+            tag = Tag::FIRST_CHOICE;
+            member.first_choice = val;
+        }
+
+        const uint8_t *get_if_first_choice() const {
+            return holds_first_choice() ? &member.first_choice : nullptr;
+        }
+
+        uint8_t *get_if_first_choice() {
+            return holds_first_choice() ? &member.first_choice : nullptr;
+        }
+
+        bool holds_first_choice() const {
+            return tag == Tag::FIRST_CHOICE;
+        }
+
+        // --- second_choice: WasiString if tag=1
+
+        static TestTaggedUnion make_second_choice(WasiString val) {
+            TestTaggedUnion tu{};
+            tu.tag = Tag::SECOND_CHOICE;
+            tu.member.second_choice = val;
+            return tu;
+        }
+
+        void emplace_second_choice(WasiString val) {
+            tag = Tag::SECOND_CHOICE;
+            member.second_choice = val;
+        }
+
+        const WasiString *get_if_second_choice() const {
+            return holds_second_choice() ? &member.second_choice : nullptr;
+        }
+
+        WasiString *get_if_second_choice() {
+            return holds_second_choice() ? &member.second_choice : nullptr;
+        }
+
+        bool holds_second_choice() const {
+            return tag == Tag::SECOND_CHOICE;
+        }
+
+        // --- third_choice: float if tag=2
+
+        static TestTaggedUnion make_third_choice(float val) {
+            TestTaggedUnion tu{};
+            tu.tag = Tag::THIRD_CHOICE;
+            tu.member.third_choice = val;
+            return tu;
+        }
+
+        void emplace_third_choice(float val) {
+            tag = Tag::THIRD_CHOICE;
+            member.third_choice = val;
+        }
+
+        const float *get_if_third_choice() const {
+            return holds_third_choice() ? &member.third_choice : nullptr;
+        }
+
+        float *get_if_third_choice() {
+            return holds_third_choice() ? &member.third_choice : nullptr;
+        }
+
+        bool holds_third_choice() const {
+            return tag == Tag::THIRD_CHOICE;
+        }
+
+        // --- empty_choice: (no associated content) if tag=3
+
+        static TestTaggedUnion make_empty_choice() {
+            TestTaggedUnion tu{};
+            tu.tag = Tag::EMPTY_CHOICE;
+            return tu;
+        }
+
+        void emplace_empty_choice() {
+            tag = Tag::EMPTY_CHOICE;
+        }
+
+        bool holds_empty_choice() const {
+            return tag == Tag::EMPTY_CHOICE;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(TestTaggedUnion, member) == 4, "unexpected layout");
+    #endif
+
+    namespace raw {
+
+        extern "C" WITX_CODEGEN_IMPORT("test_module", "a_function_that_returns_multiple_values")
+        TestErrno a_function_that_returns_multiple_values(
+            uint64_t some_parameter,
+            WasiPtr<Char8> some_other_parameter_ptr,
+            size_t some_other_parameter_len,
+            WasiMutPtr<TestMediumInt> result0_ptr,
+            WasiMutPtr<TestBigInt> result1_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_module", "a_function_that_returns_an_actual_tuple")
+        TestErrno a_function_that_returns_an_actual_tuple(
+            uint64_t some_parameter,
+            WasiMutPtr<TestBool> result0_ptr,
+            WasiMutPtr<TestMediumInt> result1_ptr,
+            WasiMutPtr<TestBigInt> result2_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_module", "a_function_that_returns_nothing")
+        TestErrno a_function_that_returns_nothing(
+            uint64_t some_parameter
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_module", "a_function_that_gets_a_string")
+        TestErrno a_function_that_gets_a_string(
+            WasiPtr<Char8> str_ptr,
+            size_t str_len,
+            WasiMutPtr<TestBool> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_module", "a_function_that_returns_a_tagged_union")
+        TestErrno a_function_that_returns_a_tagged_union(
+            WasiPtr<Char8> str_ptr,
+            size_t str_len,
+            WasiMutPtr<TestTaggedUnion> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_module", "a_function_that_gets_and_returns_a_string")
+        TestErrno a_function_that_gets_and_returns_a_string(
+            WasiPtr<Char8> str_ptr,
+            size_t str_len,
+            WasiMutPtr<TestString> result_ptr
+        );
+
+    } // namespace raw
+
+    /// This function returns multiple values
+    ///
+    /// # Parameters
+    ///
+    /// * `some_parameter`: A number
+    /// * `some_other_parameter`: A string
+    ///
+    ///   It can be empty.
+    inline WasiExpected<std::tuple<TestMediumInt, TestBigInt>, TestErrno> a_function_that_returns_multiple_values(uint64_t some_parameter, WasiString some_other_parameter) {
+        TestMediumInt result0_ptr{};
+        TestBigInt result1_ptr{};
+        const TestErrno res = raw::a_function_that_returns_multiple_values(some_parameter, some_other_parameter.ptr, some_other_parameter.len, &result0_ptr, &result1_ptr);
+        if (res != TestErrno{}) {
+            return WasiExpected<std::tuple<TestMediumInt, TestBigInt>, TestErrno>::from_error(res);
+        }
+        return WasiExpected<std::tuple<TestMediumInt, TestBigInt>, TestErrno>::from_value(std::make_tuple(result0_ptr, result1_ptr));
+    }
+
+    /// This function returns an actual tuple (expanded into multiple values)
+    inline WasiExpected<std::tuple<TestBool, TestMediumInt, TestBigInt>, TestErrno> a_function_that_returns_an_actual_tuple(uint64_t some_parameter) {
+        TestBool result0_ptr{};
+        TestMediumInt result1_ptr{};
+        TestBigInt result2_ptr{};
+        const TestErrno res = raw::a_function_that_returns_an_actual_tuple(some_parameter, &result0_ptr, &result1_ptr, &result2_ptr);
+        if (res != TestErrno{}) {
+            return WasiExpected<std::tuple<TestBool, TestMediumInt, TestBigInt>, TestErrno>::from_error(res);
+        }
+        return WasiExpected<std::tuple<TestBool, TestMediumInt, TestBigInt>, TestErrno>::from_value(std::make_tuple(result0_ptr, result1_ptr, result2_ptr));
+    }
+
+    /// This function returns nothing
+    inline WasiExpected<void, TestErrno> a_function_that_returns_nothing(uint64_t some_parameter) {
+        const TestErrno res = raw::a_function_that_returns_nothing(some_parameter);
+        if (res != TestErrno{}) {
+            return WasiExpected<void, TestErrno>::from_error(res);
+        }
+        return WasiExpected<void, TestErrno>::from_value();
+    }
+
+    /// This function gets a string
+    inline WasiExpected<TestBool, TestErrno> a_function_that_gets_a_string(WasiString str) {
+        TestBool result_ptr{};
+        const TestErrno res = raw::a_function_that_gets_a_string(str.ptr, str.len, &result_ptr);
+        if (res != TestErrno{}) {
+            return WasiExpected<TestBool, TestErrno>::from_error(res);
+        }
+        return WasiExpected<TestBool, TestErrno>::from_value(result_ptr);
+    }
+
+    /// This function return a tagged union
+    inline WasiExpected<TestTaggedUnion, TestErrno> a_function_that_returns_a_tagged_union(TestString str) {
+        TestTaggedUnion result_ptr{};
+        const TestErrno res = raw::a_function_that_returns_a_tagged_union(str.ptr, str.len, &result_ptr);
+        if (res != TestErrno{}) {
+            return WasiExpected<TestTaggedUnion, TestErrno>::from_error(res);
+        }
+        return WasiExpected<TestTaggedUnion, TestErrno>::from_value(result_ptr);
+    }
+
+    /// This function gets and returns a string
+    inline WasiExpected<TestString, TestErrno> a_function_that_gets_and_returns_a_string(TestString str) {
+        TestString result_ptr{};
+        const TestErrno res = raw::a_function_that_gets_and_returns_a_string(str.ptr, str.len, &result_ptr);
+        if (res != TestErrno{}) {
+            return WasiExpected<TestString, TestErrno>::from_error(res);
+        }
+        return WasiExpected<TestString, TestErrno>::from_value(result_ptr);
+    }
+
+} // namespace test_module
+
@@ -0,0 +1,541 @@
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+package testmodule
+
+import (
+	"context"
+	"errors"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New("invalid guest memory access")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// ---------------------- Module: [test_module] ----------------------
+
+// An enumeration
+type TestErrno uint16
+
+const (
+	// Operation succeeded.
+	TestErrnoSuccess TestErrno = 0
+	// A guest error occurred
+	TestErrnoGuestError TestErrno = 1
+	// Something else went wrong
+	TestErrnoSomeOtherError TestErrno = 2
+)
+
+// A boolean alias
+type TestBool bool
+
+// A u32 alias
+type TestMediumInt uint32
+
+const (
+	TestMediumIntZero  TestMediumInt = 0
+	TestMediumIntOne   TestMediumInt = 1
+	TestMediumIntTwo   TestMediumInt = 2
+	TestMediumIntThree TestMediumInt = 3
+)
+
+// A u64 alias
+type TestBigInt uint64
+
+const (
+	TestBigIntZero         TestBigInt = 0x0
+	TestBigIntAHundred     TestBigInt = 0x64
+	TestBigIntABigValue    TestBigInt = 0xff00000000000000
+	TestBigIntABiggerValue TestBigInt = 0xffffffffffffffff
+)
+
+// Flags
+type TestBigFlags uint8
+
+const (
+	// The first flag
+	TestBigFlagsA TestBigFlags = 0x1
+	TestBigFlagsB TestBigFlags = 0x2
+	TestBigFlagsC TestBigFlags = 0x4
+	TestBigFlagsD TestBigFlags = 0x8
+)
+
+// A structure
+type TestStruct struct {
+	// A boolean
+	ABoolean bool
+	// A byte
+	AByte uint8
+	// A string
+	//
+	// Its content is not copied.
+	AString WasiString
+}
+
+func (r *memReader) TestStruct(offset uint32) TestStruct {
+	return TestStruct{
+		ABoolean: r.boolean(offset),
+		AByte:    r.u8(offset + 1),
+		AString:  r.WasiString(offset + 4),
+	}
+}
+
+func (w *memWriter) TestStruct(offset uint32, v TestStruct) {
+	w.boolean(offset, v.ABoolean)
+	w.u8(offset+1, v.AByte)
+	w.WasiString(offset+4, v.AString)
+}
+
+// ReadTestStruct reads a TestStruct from guest memory at offset, and returns false if it is out of range.
+func ReadTestStruct(mem api.Memory, offset uint32) (TestStruct, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.TestStruct(offset)
+	return v, r.ok
+}
+
+// WriteTestStruct writes a TestStruct to guest memory at offset, and returns false if it is out of range.
+func WriteTestStruct(mem api.Memory, offset uint32, v TestStruct) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.TestStruct(offset, v)
+	return w.ok
+}
+
+// A tuple
+type TestTuple struct {
+	V0 TestBool
+	V1 TestMediumInt
+	V2 TestBigInt
+}
+
+func (r *memReader) TestTuple(offset uint32) TestTuple {
+	return TestTuple{
+		V0: TestBool(r.boolean(offset)),
+		V1: TestMediumInt(r.u32(offset + 4)),
+		V2: TestBigInt(r.u64(offset + 8)),
+	}
+}
+
+func (w *memWriter) TestTuple(offset uint32, v TestTuple) {
+	w.boolean(offset, bool(v.V0))
+	w.u32(offset+4, uint32(v.V1))
+	w.u64(offset+8, uint64(v.V2))
+}
+
+// ReadTestTuple reads a TestTuple from guest memory at offset, and returns false if it is out of range.
+func ReadTestTuple(mem api.Memory, offset uint32) (TestTuple, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.TestTuple(offset)
+	return v, r.ok
+}
+
+// WriteTestTuple writes a TestTuple to guest memory at offset, and returns false if it is out of range.
+func WriteTestTuple(mem api.Memory, offset uint32, v TestTuple) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.TestTuple(offset, v)
+	return w.ok
+}
+
+// A string
+type TestString = WasiString
+
+// An output buffer
+type TestOutputBuffer = WasiSlice
+
+// An input buffer
+type TestInputBuffer = WasiSlice
+
+// A tagged union
+type TestTaggedUnion struct {
+	Tag          uint16
+	FirstChoice  uint8
+	SecondChoice WasiString
+	ThirdChoice  float32
+}
+
+const (
+	// The first choice
+	TestTaggedUnionFirstChoice  uint16 = 0
+	TestTaggedUnionSecondChoice uint16 = 1
+	TestTaggedUnionThirdChoice  uint16 = 2
+	// A choice without a value
+	TestTaggedUnionEmptyChoice uint16 = 3
+)
+
+func (r *memReader) TestTaggedUnion(offset uint32) TestTaggedUnion {
+	v := TestTaggedUnion{Tag: r.u16(offset)}
+	switch v.Tag {
+	case TestTaggedUnionFirstChoice:
+		v.FirstChoice = r.u8(offset + 4)
+	case TestTaggedUnionSecondChoice:
+		v.SecondChoice = r.WasiString(offset + 4)
+	case TestTaggedUnionThirdChoice:
+		v.ThirdChoice = r.f32(offset + 4)
+	case TestTaggedUnionEmptyChoice:
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) TestTaggedUnion(offset uint32, v TestTaggedUnion) {
+	w.u16(offset, v.Tag)
+	switch v.Tag {
+	case TestTaggedUnionFirstChoice:
+		w.u8(offset+4, v.FirstChoice)
+	case TestTaggedUnionSecondChoice:
+		w.WasiString(offset+4, v.SecondChoice)
+	case TestTaggedUnionThirdChoice:
+		w.f32(offset+4, v.ThirdChoice)
+	case TestTaggedUnionEmptyChoice:
+	default:
+		w.ok = false
+	}
+}
+
+// ReadTestTaggedUnion reads a TestTaggedUnion from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadTestTaggedUnion(mem api.Memory, offset uint32) (TestTaggedUnion, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.TestTaggedUnion(offset)
+	return v, r.ok
+}
+
+// WriteTestTaggedUnion writes a TestTaggedUnion to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteTestTaggedUnion(mem api.Memory, offset uint32, v TestTaggedUnion) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.TestTaggedUnion(offset, v)
+	return w.ok
+}
+
+// TestModuleHost is implemented by the host to provide the functions of the `test_module` module.
+// Register an implementation with DefineTestModuleImports.
+// Methods receive the module calling them, whose memory parameters are read from.
+// Functions returning an error code return it last, results only being written if it is zero.
+type TestModuleHost interface {
+	// This function returns multiple values
+	//
+	// # Parameters
+	//
+	// * `someParameter`: A number
+	// * `someOtherParameter`: A string
+	//
+	//   It can be empty.
+	AFunctionThatReturnsMultipleValues(ctx context.Context, mod api.Module, someParameter uint64, someOtherParameter string) (TestMediumInt, TestBigInt, TestErrno)
+
+	// This function returns an actual tuple (expanded into multiple values)
+	AFunctionThatReturnsAnActualTuple(ctx context.Context, mod api.Module, someParameter uint64) (TestBool, TestMediumInt, TestBigInt, TestErrno)
+
+	// This function returns nothing
+	AFunctionThatReturnsNothing(ctx context.Context, mod api.Module, someParameter uint64) TestErrno
+
+	// This function gets a string
+	AFunctionThatGetsAString(ctx context.Context, mod api.Module, str string) (TestBool, TestErrno)
+
+	// This function return a tagged union
+	AFunctionThatReturnsATaggedUnion(ctx context.Context, mod api.Module, str string) (TestTaggedUnion, TestErrno)
+
+	// This function gets and returns a string
+	AFunctionThatGetsAndReturnsAString(ctx context.Context, mod api.Module, str string) (TestString, TestErrno)
+}
+
+// DefineTestModuleImports returns a builder for the `test_module` host module, whose functions call host.
+// Instantiate it before the guest modules importing them.
+func DefineTestModuleImports(runtime wazero.Runtime, host TestModuleHost) wazero.HostModuleBuilder {
+	builder := runtime.NewHostModuleBuilder("test_module")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			someParameter := stack[0]
+			someOtherParameterPtr := api.DecodeU32(stack[1])
+			someOtherParameterLen := api.DecodeU32(stack[2])
+			someOtherParameter := string(r.bytes(someOtherParameterPtr, someOtherParameterLen))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			result0Ptr := api.DecodeU32(stack[3])
+			result1Ptr := api.DecodeU32(stack[4])
+			result0, result1, errno := host.AFunctionThatReturnsMultipleValues(ctx, mod, someParameter, someOtherParameter)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(result0Ptr, uint32(result0))
+				w.u64(result1Ptr, uint64(result1))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI64, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("some_parameter", "some_other_parameter_ptr", "some_other_parameter_len", "result0_ptr", "result1_ptr").
+		Export("a_function_that_returns_multiple_values")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			someParameter := stack[0]
+			result0Ptr := api.DecodeU32(stack[1])
+			result1Ptr := api.DecodeU32(stack[2])
+			result2Ptr := api.DecodeU32(stack[3])
+			result0, result1, result2, errno := host.AFunctionThatReturnsAnActualTuple(ctx, mod, someParameter)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.boolean(result0Ptr, bool(result0))
+				w.u32(result1Ptr, uint32(result1))
+				w.u64(result2Ptr, uint64(result2))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI64, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("some_parameter", "result0_ptr", "result1_ptr", "result2_ptr").
+		Export("a_function_that_returns_an_actual_tuple")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			someParameter := stack[0]
+			errno := host.AFunctionThatReturnsNothing(ctx, mod, someParameter)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI64}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("some_parameter").
+		Export("a_function_that_returns_nothing")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			strPtr := api.DecodeU32(stack[0])
+			strLen := api.DecodeU32(stack[1])
+			str := string(r.bytes(strPtr, strLen))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[2])
+			result, errno := host.AFunctionThatGetsAString(ctx, mod, str)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.boolean(resultPtr, bool(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("str_ptr", "str_len", "result_ptr").
+		Export("a_function_that_gets_a_string")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			strPtr := api.DecodeU32(stack[0])
+			strLen := api.DecodeU32(stack[1])
+			str := string(r.bytes(strPtr, strLen))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[2])
+			result, errno := host.AFunctionThatReturnsATaggedUnion(ctx, mod, str)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.TestTaggedUnion(resultPtr, result)
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("str_ptr", "str_len", "result_ptr").
+		Export("a_function_that_returns_a_tagged_union")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			strPtr := api.DecodeU32(stack[0])
+			strLen := api.DecodeU32(stack[1])
+			str := string(r.bytes(strPtr, strLen))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[2])
+			result, errno := host.AFunctionThatGetsAndReturnsAString(ctx, mod, str)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.WasiString(resultPtr, result)
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("str_ptr", "str_len", "result_ptr").
+		Export("a_function_that_gets_and_returns_a_string")
+	return builder
+}
@@ -0,0 +1,384 @@
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    """A string in guest memory."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    """A slice in guest memory, `len` being its number of elements."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host function to return the error code `code` to the guest."""
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    """Memory exported by the guest instance calling a host function."""
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get("memory")
+            if self._memory is None:
+                raise RuntimeError("The guest doesn't export its memory")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        """Return the `length` bytes at address `ptr`."""
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        """Write `data` at address `ptr`."""
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        """Return the UTF-8 string of `length` bytes at address `ptr`."""
+        return self.read(ptr, length).decode("utf-8")
+
+    def read_value(self, ctype, ptr: int):
+        """Return a copy of the value of type `ctype` at address `ptr`."""
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        """Return a copy of the `length` values of type `ctype` at address `ptr`."""
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        """Write `value`, converted to `ctype` if necessary, at address `ptr`."""
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    """A buffer of `size` bytes in guest memory, to be filled by a host function."""
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        """Write `data` to the buffer, `offset` bytes after its start."""
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        self.memory.write(self.ptr + offset, data)
+
+
+# ---------------------- Module: [test_module] ----------------------
+
+class TestErrno(enum.IntEnum):
+    """An enumeration"""
+
+    # Operation succeeded.
+    SUCCESS = 0
+    # A guest error occurred
+    GUEST_ERROR = 1
+    # Something else went wrong
+    SOME_OTHER_ERROR = 2
+
+
+# A boolean alias
+TestBool = ctypes.c_bool
+
+
+# A u32 alias
+TestMediumInt = ctypes.c_uint32
+
+
+TEST_MEDIUM_INT_ZERO = 0
+TEST_MEDIUM_INT_ONE = 1
+TEST_MEDIUM_INT_TWO = 2
+TEST_MEDIUM_INT_THREE = 3
+
+
+# A u64 alias
+TestBigInt = ctypes.c_uint64
+
+
+TEST_BIG_INT_ZERO = 0x0
+TEST_BIG_INT_A_HUNDRED = 0x64
+TEST_BIG_INT_A_BIG_VALUE = 0xff00000000000000
+TEST_BIG_INT_A_BIGGER_VALUE = 0xffffffffffffffff
+
+
+class TestBigFlags(enum.IntFlag):
+    """Flags"""
+
+    # The first flag
+    A = 0x1
+    B = 0x2
+    C = 0x4
+    D = 0x8
+
+
+class TestStruct(ctypes.Structure):
+    """A structure
+
+    Fields:
+    * `a_boolean`: A boolean
+    * `a_byte`: A byte
+    * `a_string`: A string
+
+      Its content is not copied.
+    """
+
+    _pack_ = 1
+    _fields_ = [
+        ("a_boolean", ctypes.c_bool),
+        ("a_byte", ctypes.c_uint8),
+        ("_pad1", ctypes.c_uint8 * 2),
+        ("a_string", WasiString),
+    ]
+
+
+class TestTuple(ctypes.Structure):
+    """A tuple"""
+
+    _pack_ = 1
+    _fields_ = [
+        ("v0", TestBool),
+        ("_pad0", ctypes.c_uint8 * 3),
+        ("v1", TestMediumInt),
+        ("v2", TestBigInt),
+    ]
+
+
+# A string
+TestString = WasiString
+
+
+# An output buffer
+TestOutputBuffer = WasiSlice
+
+
+# An input buffer
+TestInputBuffer = WasiSlice
+
+
+class TestTaggedUnion(ctypes.Structure):
+    """A tagged union"""
+
+    class _Member(ctypes.Union):
+        _pack_ = 1
+        _fields_ = [
+            ("first_choice", ctypes.c_uint8),
+            ("second_choice", WasiString),
+            ("third_choice", ctypes.c_float),
+            ("_size", ctypes.c_uint8 * 8),
+        ]
+
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint16),
+        ("_pad0", ctypes.c_uint8 * 2),
+        ("member", _Member),
+    ]
+
+    # The first choice
+    FIRST_CHOICE = 0
+    SECOND_CHOICE = 1
+    THIRD_CHOICE = 2
+    # A choice without a value
+    EMPTY_CHOICE = 3
+
+    @classmethod
+    def new_first_choice(cls, value) -> TestTaggedUnion:
+        """Return a `TestTaggedUnion` with the `first_choice` case."""
+        union = cls(tag=cls.FIRST_CHOICE)
+        union.member.first_choice = value
+        return union
+
+    @classmethod
+    def new_second_choice(cls, value) -> TestTaggedUnion:
+        """Return a `TestTaggedUnion` with the `second_choice` case."""
+        union = cls(tag=cls.SECOND_CHOICE)
+        union.member.second_choice = value
+        return union
+
+    @classmethod
+    def new_third_choice(cls, value) -> TestTaggedUnion:
+        """Return a `TestTaggedUnion` with the `third_choice` case."""
+        union = cls(tag=cls.THIRD_CHOICE)
+        union.member.third_choice = value
+        return union
+
+    @classmethod
+    def new_empty_choice(cls) -> TestTaggedUnion:
+        """Return a `TestTaggedUnion` with the `empty_choice` case."""
+        union = cls(tag=cls.EMPTY_CHOICE)
+        return union
+
+
+class TestModuleHost:
+    """Functions of the `test_module` module, implemented by the host.
+
+    Override them in a subclass, and register an instance with `define_test_module_imports()`.
+    Methods receive the memory of the calling instance first.
+    Functions returning an error code raise `WasiError` to return it.
+    """
+
+    def a_function_that_returns_multiple_values(self, memory: GuestMemory, some_parameter: int, some_other_parameter: str) -> tuple[int, int]:
+        """This function returns multiple values
+
+        # Parameters
+
+        * `some_parameter`: A number
+        * `some_other_parameter`: A string
+
+          It can be empty.
+        """
+        raise NotImplementedError("a_function_that_returns_multiple_values")
+
+    def a_function_that_returns_an_actual_tuple(self, memory: GuestMemory, some_parameter: int) -> tuple[bool, int, int]:
+        """This function returns an actual tuple (expanded into multiple values)"""
+        raise NotImplementedError("a_function_that_returns_an_actual_tuple")
+
+    def a_function_that_returns_nothing(self, memory: GuestMemory, some_parameter: int) -> None:
+        """This function returns nothing"""
+        raise NotImplementedError("a_function_that_returns_nothing")
+
+    def a_function_that_gets_a_string(self, memory: GuestMemory, str: str) -> bool:
+        """This function gets a string"""
+        raise NotImplementedError("a_function_that_gets_a_string")
+
+    def a_function_that_returns_a_tagged_union(self, memory: GuestMemory, str: str) -> TestTaggedUnion:
+        """This function return a tagged union"""
+        raise NotImplementedError("a_function_that_returns_a_tagged_union")
+
+    def a_function_that_gets_and_returns_a_string(self, memory: GuestMemory, str: str) -> TestString:
+        """This function gets and returns a string"""
+        raise NotImplementedError("a_function_that_gets_and_returns_a_string")
+
+
+def define_test_module_imports(linker: wasmtime.Linker, store: wasmtime.Store, impl: TestModuleHost) -> None:
+    """Define the functions of the `test_module` module in `linker`, implemented by `impl`."""
+
+    def _a_function_that_returns_multiple_values(_caller, some_parameter, some_other_parameter_ptr, some_other_parameter_len, result0_ptr, result1_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.a_function_that_returns_multiple_values(_memory, some_parameter, _memory.read_string(some_other_parameter_ptr, some_other_parameter_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(TestMediumInt, result0_ptr, _results[0])
+        _memory.write_value(TestBigInt, result1_ptr, _results[1])
+        return 0
+
+    linker.define(store, "test_module", "a_function_that_returns_multiple_values", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i64(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _a_function_that_returns_multiple_values,
+        access_caller=True,
+    ))
+
+    def _a_function_that_returns_an_actual_tuple(_caller, some_parameter, result0_ptr, result1_ptr, result2_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.a_function_that_returns_an_actual_tuple(_memory, some_parameter)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(TestBool, result0_ptr, _results[0])
+        _memory.write_value(TestMediumInt, result1_ptr, _results[1])
+        _memory.write_value(TestBigInt, result2_ptr, _results[2])
+        return 0
+
+    linker.define(store, "test_module", "a_function_that_returns_an_actual_tuple", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i64(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _a_function_that_returns_an_actual_tuple,
+        access_caller=True,
+    ))
+
+    def _a_function_that_returns_nothing(_caller, some_parameter):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.a_function_that_returns_nothing(_memory, some_parameter)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "test_module", "a_function_that_returns_nothing", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i64()], [wasmtime.ValType.i32()]),
+        _a_function_that_returns_nothing,
+        access_caller=True,
+    ))
+
+    def _a_function_that_gets_a_string(_caller, str_ptr, str_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.a_function_that_gets_a_string(_memory, _memory.read_string(str_ptr, str_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(TestBool, result_ptr, _results)
+        return 0
+
+    linker.define(store, "test_module", "a_function_that_gets_a_string", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _a_function_that_gets_a_string,
+        access_caller=True,
+    ))
+
+    def _a_function_that_returns_a_tagged_union(_caller, str_ptr, str_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.a_function_that_returns_a_tagged_union(_memory, _memory.read_string(str_ptr, str_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(TestTaggedUnion, result_ptr, _results)
+        return 0
+
+    linker.define(store, "test_module", "a_function_that_returns_a_tagged_union", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _a_function_that_returns_a_tagged_union,
+        access_caller=True,
+    ))
+
+    def _a_function_that_gets_and_returns_a_string(_caller, str_ptr, str_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.a_function_that_gets_and_returns_a_string(_memory, _memory.read_string(str_ptr, str_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(TestString, result_ptr, _results)
+        return 0
+
+    linker.define(store, "test_module", "a_function_that_gets_and_returns_a_string", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _a_function_that_gets_and_returns_a_string,
+        access_caller=True,
+    ))
+
@@ -0,0 +1,832 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [test_module] ----------------------
+
+/// An enumeration
+pub type TestErrno = u16;
+
+#[allow(non_snake_case)]
+pub mod TEST_ERRNO {
+    use super::TestErrno;
+    /// Operation succeeded.
+    pub const SUCCESS: TestErrno = 0;
+    /// A guest error occurred
+    pub const GUEST_ERROR: TestErrno = 1;
+    /// Something else went wrong
+    pub const SOME_OTHER_ERROR: TestErrno = 2;
+}
+
+/// A boolean alias
+pub type TestBool = bool;
+
+/// A u32 alias
+pub type TestMediumInt = u32;
+
+#[allow(non_snake_case)]
+pub mod TEST_MEDIUM_INT {
+    use super::TestMediumInt;
+    pub const ZERO: TestMediumInt = 0;
+    pub const ONE: TestMediumInt = 1;
+    pub const TWO: TestMediumInt = 2;
+    pub const THREE: TestMediumInt = 3;
+}
+
+/// A u64 alias
+pub type TestBigInt = u64;
+
+#[allow(non_snake_case)]
+pub mod TEST_BIG_INT {
+    use super::TestBigInt;
+    pub const ZERO: TestBigInt = 0x0;
+    pub const A_HUNDRED: TestBigInt = 0x64;
+    pub const A_BIG_VALUE: TestBigInt = 0xff00000000000000;
+    pub const A_BIGGER_VALUE: TestBigInt = 0xffffffffffffffff;
+}
+
+/// Flags
+pub type TestBigFlags = u8;
+
+#[allow(non_snake_case)]
+pub mod TEST_BIG_FLAGS {
+    use super::TestBigFlags;
+    /// The first flag
+    pub const A: TestBigFlags = 0x1;
+    pub const B: TestBigFlags = 0x2;
+    pub const C: TestBigFlags = 0x4;
+    pub const D: TestBigFlags = 0x8;
+}
+
+/// A structure
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct TestStruct {
+    /// A boolean
+    pub a_boolean: bool,
+    /// A byte
+    pub a_byte: u8,
+    /// A string
+    ///
+    /// Its content is not copied.
+    pub a_string: WasiString,
+}
+
+#[cfg(target_arch = "wasm32")]
+const _: () = {
+    assert!(core::mem::size_of::<TestStruct>() == 12);
+    assert!(core::mem::align_of::<TestStruct>() == 4);
+    assert!(core::mem::offset_of!(TestStruct, a_boolean) == 0);
+    assert!(core::mem::offset_of!(TestStruct, a_byte) == 1);
+    assert!(core::mem::offset_of!(TestStruct, a_string) == 4);
+};
+
+/// A tuple
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct TestTuple { // -- Tuple
+    pub v0: TestBool,
+    pub v1: TestMediumInt,
+    pub v2: TestBigInt,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<TestTuple>() == 16);
+    assert!(core::mem::align_of::<TestTuple>() == 8);
+    assert!(core::mem::offset_of!(TestTuple, v0) == 0);
+    assert!(core::mem::offset_of!(TestTuple, v1) == 4);
+    assert!(core::mem::offset_of!(TestTuple, v2) == 8);
+};
+
+/// A string
+pub type TestString = WasiString;
+
+/// An output buffer
+pub type TestOutputBuffer = WasiMutSlice<u16>;
+
+/// An input buffer
+pub type TestInputBuffer = WasiSlice<u16>;
+
+/// A tagged union
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union TestTaggedUnionMember {
+    /// The first choice
+    first_choice: u8, // if tag=0
+    second_choice: WasiString, // if tag=1
+    third_choice: f32, // if tag=2
+    // empty_choice with no associated value if tag=3
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct TestTaggedUnion {
+    pub tag: u16,
+    __pad16_0: u16,
+    pub member: core::mem::MaybeUninit<TestTaggedUnionMember>,
+}
+
+#[cfg(target_arch = "wasm32")]
+const _: () = {
+    assert!(core::mem::size_of::<TestTaggedUnion>() == 12);
+    assert!(core::mem::align_of::<TestTaggedUnion>() == 4);
+    assert!(core::mem::offset_of!(TestTaggedUnion, tag) == 0);
+    assert!(core::mem::offset_of!(TestTaggedUnion, member) == 4);
+};
+
+impl TestTaggedUnion {
+    fn new(tag: u16) -> Self {
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<TestTaggedUnionRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(TestTaggedUnionRef::FirstChoice(unsafe { &self.member.assume_init_ref().first_choice })),
+            1 => Ok(TestTaggedUnionRef::SecondChoice(unsafe { &self.member.assume_init_ref().second_choice })),
+            2 => Ok(TestTaggedUnionRef::ThirdChoice(unsafe { &self.member.assume_init_ref().third_choice })),
+            3 => Ok(TestTaggedUnionRef::EmptyChoice),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
+    // --- first_choice: u8 if tag=0
+
+    /// The first choice
+    pub fn new_first_choice(val: u8) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = core::mem::MaybeUninit::new(TestTaggedUnionMember { first_choice: val });
+        tu
+    }
+
+    pub fn into_first_choice(self) -> u8 {
+        assert_eq!(self.tag, 0);
+        unsafe { self.member.assume_init().first_choice }
+    }
+
+    pub fn set_first_choice(&mut self, val: u8) {
+        assert_eq!(self.tag, 0);
+        let uval = TestTaggedUnionMember { first_choice: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_first_choice(&self) -> bool {
+        self.tag == 0
+    }
+
+    // --- second_choice: WasiString if tag=1
+
+    pub fn new_second_choice(val: WasiString) -> Self {
+        let mut tu = Self::new(1);
+        tu.member = core::mem::MaybeUninit::new(TestTaggedUnionMember { second_choice: val });
+        tu
+    }
+
+    pub fn into_second_choice(self) -> WasiString {
+        assert_eq!(self.tag, 1);
+        unsafe { self.member.assume_init().second_choice }
+    }
+
+    pub fn set_second_choice(&mut self, val: WasiString) {
+        assert_eq!(self.tag, 1);
+        let uval = TestTaggedUnionMember { second_choice: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_second_choice(&self) -> bool {
+        self.tag == 1
+    }
+
+    // --- third_choice: f32 if tag=2
+
+    pub fn new_third_choice(val: f32) -> Self {
+        let mut tu = Self::new(2);
+        tu.member = core::mem::MaybeUninit::new(TestTaggedUnionMember { third_choice: val });
+        tu
+    }
+
+    pub fn into_third_choice(self) -> f32 {
+        assert_eq!(self.tag, 2);
+        unsafe { self.member.assume_init().third_choice }
+    }
+
+    pub fn set_third_choice(&mut self, val: f32) {
+        assert_eq!(self.tag, 2);
+        let uval = TestTaggedUnionMember { third_choice: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_third_choice(&self) -> bool {
+        self.tag == 2
+    }
+
+    // --- empty_choice: (no associated content) if tag=3
+
+    /// A choice without a value
+    pub fn new_empty_choice() -> Self {
+        Self::new(3)
+    }
+
+    pub fn is_empty_choice(&self) -> bool {
+        self.tag == 3
+    }
+}
+
+/// Content of a `TestTaggedUnion`.
+#[derive(Copy, Clone, Debug)]
+pub enum TestTaggedUnionEnum {
+    /// The first choice
+    FirstChoice(u8),
+    SecondChoice(WasiString),
+    ThirdChoice(f32),
+    /// A choice without a value
+    EmptyChoice,
+}
+
+/// Reference to the content of a `TestTaggedUnion`.
+#[derive(Copy, Clone, Debug)]
+pub enum TestTaggedUnionRef<'a> {
+    /// The first choice
+    FirstChoice(&'a u8),
+    SecondChoice(&'a WasiString),
+    ThirdChoice(&'a f32),
+    /// A choice without a value
+    EmptyChoice,
+}
+
+impl<'a> core::convert::TryFrom<&'a TestTaggedUnion> for TestTaggedUnionRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a TestTaggedUnion) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<TestTaggedUnionRef<'_>> for TestTaggedUnionEnum {
+    fn from(content: TestTaggedUnionRef<'_>) -> Self {
+        match content {
+            TestTaggedUnionRef::FirstChoice(val) => TestTaggedUnionEnum::FirstChoice(*val),
+            TestTaggedUnionRef::SecondChoice(val) => TestTaggedUnionEnum::SecondChoice(*val),
+            TestTaggedUnionRef::ThirdChoice(val) => TestTaggedUnionEnum::ThirdChoice(*val),
+            TestTaggedUnionRef::EmptyChoice => TestTaggedUnionEnum::EmptyChoice,
+        }
+    }
+}
+
+impl core::convert::TryFrom<TestTaggedUnion> for TestTaggedUnionEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: TestTaggedUnion) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<TestTaggedUnionEnum> for TestTaggedUnion {
+    fn from(content: TestTaggedUnionEnum) -> Self {
+        match content {
+            TestTaggedUnionEnum::FirstChoice(val) => Self::new_first_choice(val),
+            TestTaggedUnionEnum::SecondChoice(val) => Self::new_second_choice(val),
+            TestTaggedUnionEnum::ThirdChoice(val) => Self::new_third_choice(val),
+            TestTaggedUnionEnum::EmptyChoice => Self::new_empty_choice(),
+        }
+    }
+}
+
+impl core::fmt::Debug for TestTaggedUnion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
+/// Error returned by the functions of the `test_module` module.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TestModuleError {
+    /// A guest error occurred
+    GuestError,
+    /// Something else went wrong
+    SomeOtherError,
+    /// An error code that is not an error case of `TestErrno`.
+    Other(TestErrno),
+}
+
+impl From<TestErrno> for TestModuleError {
+    fn from(e: TestErrno) -> Self {
+        match e {
+            TEST_ERRNO::GUEST_ERROR => TestModuleError::GuestError,
+            TEST_ERRNO::SOME_OTHER_ERROR => TestModuleError::SomeOtherError,
+            e => TestModuleError::Other(e),
+        }
+    }
+}
+
+impl From<TestModuleError> for TestErrno {
+    fn from(e: TestModuleError) -> Self {
+        match e {
+            TestModuleError::GuestError => TEST_ERRNO::GUEST_ERROR,
+            TestModuleError::SomeOtherError => TEST_ERRNO::SOME_OTHER_ERROR,
+            TestModuleError::Other(e) => e,
+        }
+    }
+}
+
+impl From<TestModuleError> for Error {
+    fn from(e: TestModuleError) -> Self {
+        Error::WasiError(TestErrno::from(e) as _)
+    }
+}
+
+impl core::fmt::Display for TestModuleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            TestModuleError::GuestError => "A guest error occurred",
+            TestModuleError::SomeOtherError => "Something else went wrong",
+            TestModuleError::Other(e) => return write!(f, "Unknown error {}", e),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for TestModuleError {}
+
+/// This function returns multiple values
+///
+/// # Parameters
+///
+/// * `some_parameter`: A number
+/// * `some_other_parameter`: A string
+///
+///   It can be empty.
+pub fn a_function_that_returns_multiple_values(
+    some_parameter: u64,
+    some_other_parameter_ptr: WasiPtr<Char8>,
+    some_other_parameter_len: usize,
+) -> Result<(TestMediumInt, TestBigInt), TestModuleError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_module")]
+        extern "C" {
+            pub fn a_function_that_returns_multiple_values(
+                some_parameter: u64,
+                some_other_parameter_ptr: WasiPtr<Char8>,
+                some_other_parameter_len: usize,
+                result0_ptr: WasiMutPtr<TestMediumInt>,
+                result1_ptr: WasiMutPtr<TestBigInt>,
+            ) -> TestErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn a_function_that_returns_multiple_values(
+            some_parameter: u64,
+            some_other_parameter_ptr: WasiPtr<Char8>,
+            some_other_parameter_len: usize,
+            result0_ptr: WasiMutPtr<TestMediumInt>,
+            result1_ptr: WasiMutPtr<TestBigInt>,
+        ) -> TestErrno {
+            match super::with_test_module_mock(|mock| mock.a_function_that_returns_multiple_values(some_parameter, some_other_parameter_ptr, some_other_parameter_len)) {
+                Ok((res0, res1)) => {
+                    unsafe { result0_ptr.write(res0) };
+                    unsafe { result1_ptr.write(res1) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result0_ptr = core::mem::MaybeUninit::uninit();
+    let mut result1_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::a_function_that_returns_multiple_values(
+        some_parameter,
+        some_other_parameter_ptr,
+        some_other_parameter_len,
+        result0_ptr.as_mut_ptr(),
+        result1_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(TestModuleError::from(res));
+    }
+    Ok(unsafe { (result0_ptr.assume_init(), result1_ptr.assume_init()) })
+}
+
+/// This function returns an actual tuple (expanded into multiple values)
+pub fn a_function_that_returns_an_actual_tuple(
+    some_parameter: u64,
+) -> Result<(TestBool, TestMediumInt, TestBigInt), TestModuleError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_module")]
+        extern "C" {
+            pub fn a_function_that_returns_an_actual_tuple(
+                some_parameter: u64,
+                result0_ptr: WasiMutPtr<TestBool>,
+                result1_ptr: WasiMutPtr<TestMediumInt>,
+                result2_ptr: WasiMutPtr<TestBigInt>,
+            ) -> TestErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn a_function_that_returns_an_actual_tuple(
+            some_parameter: u64,
+            result0_ptr: WasiMutPtr<TestBool>,
+            result1_ptr: WasiMutPtr<TestMediumInt>,
+            result2_ptr: WasiMutPtr<TestBigInt>,
+        ) -> TestErrno {
+            match super::with_test_module_mock(|mock| mock.a_function_that_returns_an_actual_tuple(some_parameter)) {
+                Ok((res0, res1, res2)) => {
+                    unsafe { result0_ptr.write(res0) };
+                    unsafe { result1_ptr.write(res1) };
+                    unsafe { result2_ptr.write(res2) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result0_ptr = core::mem::MaybeUninit::uninit();
+    let mut result1_ptr = core::mem::MaybeUninit::uninit();
+    let mut result2_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::a_function_that_returns_an_actual_tuple(
+        some_parameter,
+        result0_ptr.as_mut_ptr(),
+        result1_ptr.as_mut_ptr(),
+        result2_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(TestModuleError::from(res));
+    }
+    Ok(unsafe { (result0_ptr.assume_init(), result1_ptr.assume_init(), result2_ptr.assume_init()) })
+}
+
+/// This function returns nothing
+pub fn a_function_that_returns_nothing(
+    some_parameter: u64,
+) -> Result<(), TestModuleError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_module")]
+        extern "C" {
+            pub fn a_function_that_returns_nothing(
+                some_parameter: u64,
+            ) -> TestErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn a_function_that_returns_nothing(
+            some_parameter: u64,
+        ) -> TestErrno {
+            match super::with_test_module_mock(|mock| mock.a_function_that_returns_nothing(some_parameter)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::a_function_that_returns_nothing(
+        some_parameter,
+    )};
+    if res != 0 {
+        return Err(TestModuleError::from(res));
+    }
+    Ok(())
+}
+
+/// This function gets a string
+pub fn a_function_that_gets_a_string(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+) -> Result<TestBool, TestModuleError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_module")]
+        extern "C" {
+            pub fn a_function_that_gets_a_string(
+                str_ptr: WasiPtr<Char8>,
+                str_len: usize,
+                result_ptr: WasiMutPtr<TestBool>,
+            ) -> TestErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn a_function_that_gets_a_string(
+            str_ptr: WasiPtr<Char8>,
+            str_len: usize,
+            result_ptr: WasiMutPtr<TestBool>,
+        ) -> TestErrno {
+            match super::with_test_module_mock(|mock| mock.a_function_that_gets_a_string(str_ptr, str_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::a_function_that_gets_a_string(
+        str_ptr,
+        str_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(TestModuleError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// This function return a tagged union
+pub fn a_function_that_returns_a_tagged_union(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+) -> Result<TestTaggedUnion, TestModuleError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_module")]
+        extern "C" {
+            pub fn a_function_that_returns_a_tagged_union(
+                str_ptr: WasiPtr<Char8>,
+                str_len: usize,
+                result_ptr: WasiMutPtr<TestTaggedUnion>,
+            ) -> TestErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn a_function_that_returns_a_tagged_union(
+            str_ptr: WasiPtr<Char8>,
+            str_len: usize,
+            result_ptr: WasiMutPtr<TestTaggedUnion>,
+        ) -> TestErrno {
+            match super::with_test_module_mock(|mock| mock.a_function_that_returns_a_tagged_union(str_ptr, str_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::a_function_that_returns_a_tagged_union(
+        str_ptr,
+        str_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(TestModuleError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// This function gets and returns a string
+pub fn a_function_that_gets_and_returns_a_string(
+    str_ptr: WasiPtr<Char8>,
+    str_len: usize,
+) -> Result<TestString, TestModuleError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_module")]
+        extern "C" {
+            pub fn a_function_that_gets_and_returns_a_string(
+                str_ptr: WasiPtr<Char8>,
+                str_len: usize,
+                result_ptr: WasiMutPtr<TestString>,
+            ) -> TestErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn a_function_that_gets_and_returns_a_string(
+            str_ptr: WasiPtr<Char8>,
+            str_len: usize,
+            result_ptr: WasiMutPtr<TestString>,
+        ) -> TestErrno {
+            match super::with_test_module_mock(|mock| mock.a_function_that_gets_and_returns_a_string(str_ptr, str_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::a_function_that_gets_and_returns_a_string(
+        str_ptr,
+        str_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(TestModuleError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Implementation of the functions of the `test_module` module used on native targets.
+/// Install it with `set_test_module_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait TestModuleMock {
+    /// This function returns multiple values
+    ///
+    /// # Parameters
+    ///
+    /// * `some_parameter`: A number
+    /// * `some_other_parameter`: A string
+    ///
+    ///   It can be empty.
+    #[allow(unused_variables)]
+    fn a_function_that_returns_multiple_values(
+        &self,
+        some_parameter: u64,
+        some_other_parameter_ptr: WasiPtr<Char8>,
+        some_other_parameter_len: usize,
+    ) -> Result<(TestMediumInt, TestBigInt), TestModuleError> {
+        unimplemented!("a_function_that_returns_multiple_values")
+    }
+
+    /// This function returns an actual tuple (expanded into multiple values)
+    #[allow(unused_variables)]
+    fn a_function_that_returns_an_actual_tuple(
+        &self,
+        some_parameter: u64,
+    ) -> Result<(TestBool, TestMediumInt, TestBigInt), TestModuleError> {
+        unimplemented!("a_function_that_returns_an_actual_tuple")
+    }
+
+    /// This function returns nothing
+    #[allow(unused_variables)]
+    fn a_function_that_returns_nothing(
+        &self,
+        some_parameter: u64,
+    ) -> Result<(), TestModuleError> {
+        unimplemented!("a_function_that_returns_nothing")
+    }
+
+    /// This function gets a string
+    #[allow(unused_variables)]
+    fn a_function_that_gets_a_string(
+        &self,
+        str_ptr: WasiPtr<Char8>,
+        str_len: usize,
+    ) -> Result<TestBool, TestModuleError> {
+        unimplemented!("a_function_that_gets_a_string")
+    }
+
+    /// This function return a tagged union
+    #[allow(unused_variables)]
+    fn a_function_that_returns_a_tagged_union(
+        &self,
+        str_ptr: WasiPtr<Char8>,
+        str_len: usize,
+    ) -> Result<TestTaggedUnion, TestModuleError> {
+        unimplemented!("a_function_that_returns_a_tagged_union")
+    }
+
+    /// This function gets and returns a string
+    #[allow(unused_variables)]
+    fn a_function_that_gets_and_returns_a_string(
+        &self,
+        str_ptr: WasiPtr<Char8>,
+        str_len: usize,
+    ) -> Result<TestString, TestModuleError> {
+        unimplemented!("a_function_that_gets_and_returns_a_string")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static TEST_MODULE_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn TestModuleMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `test_module` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_test_module_mock(mock: impl TestModuleMock + 'static) -> Option<std::rc::Rc<dyn TestModuleMock>> {
+    TEST_MODULE_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_test_module_mock<R>(f: impl FnOnce(&dyn TestModuleMock) -> R) -> R {
+    let mock = TEST_MODULE_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `test_module` module installed"))
+}
+
@@ -0,0 +1,272 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [test_module] ----------------------
+
+/// An enumeration
+pub const TestErrno = enum(u16) {
+    /// Operation succeeded.
+    SUCCESS = 0,
+    /// A guest error occurred
+    GUEST_ERROR = 1,
+    /// Something else went wrong
+    SOME_OTHER_ERROR = 2,
+};
+
+/// A boolean alias
+pub const TestBool = bool;
+
+/// A u32 alias
+pub const TestMediumInt = u32;
+
+pub const TEST_MEDIUM_INT_ZERO: TestMediumInt = 0;
+pub const TEST_MEDIUM_INT_ONE: TestMediumInt = 1;
+pub const TEST_MEDIUM_INT_TWO: TestMediumInt = 2;
+pub const TEST_MEDIUM_INT_THREE: TestMediumInt = 3;
+
+/// A u64 alias
+pub const TestBigInt = u64;
+
+pub const TEST_BIG_INT_ZERO: TestBigInt = 0x0;
+pub const TEST_BIG_INT_A_HUNDRED: TestBigInt = 0x64;
+pub const TEST_BIG_INT_A_BIG_VALUE: TestBigInt = 0xff00000000000000;
+pub const TEST_BIG_INT_A_BIGGER_VALUE: TestBigInt = 0xffffffffffffffff;
+
+/// Flags
+pub const TestBigFlags = u8;
+/// The first flag
+pub const TEST_BIG_FLAGS_A: TestBigFlags = 0x1;
+pub const TEST_BIG_FLAGS_B: TestBigFlags = 0x2;
+pub const TEST_BIG_FLAGS_C: TestBigFlags = 0x4;
+pub const TEST_BIG_FLAGS_D: TestBigFlags = 0x8;
+
+
+
+/// A structure
+pub const TestStruct = extern struct {
+    /// A boolean
+    a_boolean: bool,
+    /// A byte
+    a_byte: u8,
+    __pad16_1: u16 = undefined,
+    /// A string
+    ///
+    /// Its content is not copied.
+    a_string: WasiString,
+};
+
+
+/// A tuple
+pub const TestTuple = extern struct { // -- Tuple
+    v0: TestBool,
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    v1: TestMediumInt,
+    v2: TestBigInt,
+};
+
+
+/// A string
+pub const TestString = WasiString;
+
+/// An output buffer
+pub const TestOutputBuffer = WasiMutSlice(u16);
+
+/// An input buffer
+pub const TestInputBuffer = WasiSlice(u16);
+
+/// A tagged union
+pub const TestTaggedUnion = extern struct {
+    tag: enum(u16) {
+        /// The first choice
+        first_choice = 0,
+        second_choice = 1,
+        third_choice = 2,
+        /// A choice without a value
+        empty_choice = 3,
+    },
+    __pad16_0: u16 = undefined,
+    member = extern union {
+        first_choice: u8,
+        second_choice: WasiString,
+        third_choice: f32,
+    },
+
+
+fn newFirstChoice(val: u8) TestTaggedUnion {
+    return TestTaggedUnion{ .tag = .first_choice, .member = .{ .first_choice = val } };
+}
+
+pub fn FirstChoice(self: TestTaggedUnion) u8 {
+    std.debug.assert(self.tag == .first_choice);
+    return self.member.first_choice;
+}
+
+pub fn setFirstChoice(self: *TestTaggedUnion, val: u8) void {
+    std.debug.assert(self.tag == .first_choice);
+    self.member.first_choice = val;
+}
+
+fn isFirstChoice(self: TestTaggedUnion) bool {
+    return self.tag == .first_choice;
+}
+
+
+fn newSecondChoice(val: WasiString) TestTaggedUnion {
+    return TestTaggedUnion{ .tag = .second_choice, .member = .{ .second_choice = val } };
+}
+
+pub fn SecondChoice(self: TestTaggedUnion) WasiString {
+    std.debug.assert(self.tag == .second_choice);
+    return self.member.second_choice;
+}
+
+pub fn setSecondChoice(self: *TestTaggedUnion, val: WasiString) void {
+    std.debug.assert(self.tag == .second_choice);
+    self.member.second_choice = val;
+}
+
+fn isSecondChoice(self: TestTaggedUnion) bool {
+    return self.tag == .second_choice;
+}
+
+
+fn newThirdChoice(val: f32) TestTaggedUnion {
+    return TestTaggedUnion{ .tag = .third_choice, .member = .{ .third_choice = val } };
+}
+
+pub fn ThirdChoice(self: TestTaggedUnion) f32 {
+    std.debug.assert(self.tag == .third_choice);
+    return self.member.third_choice;
+}
+
+pub fn setThirdChoice(self: *TestTaggedUnion, val: f32) void {
+    std.debug.assert(self.tag == .third_choice);
+    self.member.third_choice = val;
+}
+
+fn isThirdChoice(self: TestTaggedUnion) bool {
+    return self.tag == .third_choice;
+}
+
+
+fn newEmptyChoice() TestTaggedUnion {
+    return TestTaggedUnion { .tag = .empty_choice };
+}
+
+fn isEmptyChoice(self: TestTaggedUnion) bool {
+    return self.tag == .empty_choice;
+}
+
+};
+
+
+pub const TestModule = struct {
+    /// This function returns multiple values
+    ///
+    /// # Parameters
+    ///
+    /// * `some_parameter`: A number
+    /// * `some_other_parameter`: A string
+    ///
+    ///   It can be empty.
+    pub extern "test_module" fn a_function_that_returns_multiple_values(
+        some_parameter: u64,
+        some_other_parameter_ptr: WasiPtr(Char8),
+        some_other_parameter_len: usize,
+        result0_ptr: WasiMutPtr(TestMediumInt),
+        result1_ptr: WasiMutPtr(TestBigInt),
+    ) callconv(.C) TestErrno;
+
+    /// This function returns an actual tuple (expanded into multiple values)
+    pub extern "test_module" fn a_function_that_returns_an_actual_tuple(
+        some_parameter: u64,
+        result0_ptr: WasiMutPtr(TestBool),
+        result1_ptr: WasiMutPtr(TestMediumInt),
+        result2_ptr: WasiMutPtr(TestBigInt),
+    ) callconv(.C) TestErrno;
+
+    /// This function returns nothing
+    pub extern "test_module" fn a_function_that_returns_nothing(
+        some_parameter: u64,
+    ) callconv(.C) TestErrno;
+
+    /// This function gets a string
+    pub extern "test_module" fn a_function_that_gets_a_string(
+        str_ptr: WasiPtr(Char8),
+        str_len: usize,
+        result_ptr: WasiMutPtr(TestBool),
+    ) callconv(.C) TestErrno;
+
+    /// This function return a tagged union
+    pub extern "test_module" fn a_function_that_returns_a_tagged_union(
+        str_ptr: WasiPtr(Char8),
+        str_len: usize,
+        result_ptr: WasiMutPtr(TestTaggedUnion),
+    ) callconv(.C) TestErrno;
+
+    /// This function gets and returns a string
+    pub extern "test_module" fn a_function_that_gets_and_returns_a_string(
+        str_ptr: WasiPtr(Char8),
+        str_len: usize,
+        result_ptr: WasiMutPtr(TestString),
+    ) callconv(.C) TestErrno;
+
+};
+
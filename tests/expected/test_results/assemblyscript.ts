@@ -0,0 +1,148 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/*
+* ---------------------- Module: [test_results] ----------------------
+ */
+
+export type Errno = u16;
+
+export namespace Errno {
+    export const SUCCESS: Errno = 0;
+    /**
+     * Bad file descriptor.
+     */
+    export const BADF: Errno = 1;
+}
+
+export type Exitcode = u32;
+
+// @ts-ignore: decorator
+@unmanaged
+export class Fd {
+    static fromRaw(handle: WasiHandle): Fd {
+        return changetype<Fd>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+export type Fdflags = u16;
+
+export namespace Fdflags {
+    export const APPEND: Fdflags = 1;
+    export const NONBLOCK: Fdflags = 2;
+}
+
+
+/**
+ * Terminate the process.
+ */
+// @ts-ignore: decorator
+@external("test_results", "proc_exit")
+export declare function procExit(
+    rval: Exitcode
+): never;
+
+/**
+ * Yield execution.
+ */
+// @ts-ignore: decorator
+@external("test_results", "sched_yield")
+export declare function schedYield(): void;
+
+/**
+ * Return a random number.
+ */
+// @ts-ignore: decorator
+@external("test_results", "random_u32")
+export declare function randomU32(): u32;
+
+/**
+ * Return the flags of a file descriptor.
+ */
+// @ts-ignore: decorator
+@external("test_results", "fd_flags")
+export declare function fdFlags(
+    fd: Fd
+): Fdflags;
+
+/**
+ * Duplicate a file descriptor.
+ */
+// @ts-ignore: decorator
+@external("test_results", "fd_dup")
+export declare function fdDup(
+    fd: Fd
+): Fd;
+
+/**
+ * Return a pointer to a buffer.
+ */
+// @ts-ignore: decorator
+@external("test_results", "buffer_ptr")
+export declare function bufferPtr(): WasiPtr<u8>;
+
+/**
+ * Close a file descriptor.
+ */
+// @ts-ignore: decorator
+@external("test_results", "fd_close")
+export declare function fdClose(
+    fd: Fd
+): Errno;
+
@@ -0,0 +1,276 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [test_results] ----------------------
+
+namespace test_results {
+
+    enum class Errno : uint16_t {
+        SUCCESS = 0,
+        /// Bad file descriptor.
+        BADF = 1,
+    };
+
+    using Exitcode = uint32_t;
+
+    enum class Fd : WasiHandle {};
+
+    using Fdflags = uint16_t;
+
+    namespace fdflags {
+        constexpr Fdflags APPEND = 1;
+        constexpr Fdflags NONBLOCK = 2;
+    } // namespace fdflags
+
+    namespace raw {
+
+        extern "C" [[noreturn]] WITX_CODEGEN_IMPORT("test_results", "proc_exit")
+        void proc_exit(
+            Exitcode rval
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_results", "sched_yield")
+        void sched_yield();
+
+        extern "C" WITX_CODEGEN_IMPORT("test_results", "random_u32")
+        uint32_t random_u32();
+
+        extern "C" WITX_CODEGEN_IMPORT("test_results", "fd_flags")
+        Fdflags fd_flags(
+            Fd fd
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_results", "fd_dup")
+        Fd fd_dup(
+            Fd fd
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("test_results", "buffer_ptr")
+        WasiPtr<uint8_t> buffer_ptr();
+
+        extern "C" WITX_CODEGEN_IMPORT("test_results", "fd_close")
+        Errno fd_close(
+            Fd fd
+        );
+
+    } // namespace raw
+
+    /// Terminate the process.
+    [[noreturn]] inline void proc_exit(Exitcode rval) {
+        raw::proc_exit(rval);
+    }
+
+    /// Yield execution.
+    inline void sched_yield() {
+        raw::sched_yield();
+    }
+
+    /// Return a random number.
+    inline uint32_t random_u32() {
+        return raw::random_u32();
+    }
+
+    /// Return the flags of a file descriptor.
+    inline Fdflags fd_flags(Fd fd) {
+        return raw::fd_flags(fd);
+    }
+
+    /// Duplicate a file descriptor.
+    inline Fd fd_dup(Fd fd) {
+        return raw::fd_dup(fd);
+    }
+
+    /// Return a pointer to a buffer.
+    inline WasiPtr<uint8_t> buffer_ptr() {
+        return raw::buffer_ptr();
+    }
+
+    /// Close a file descriptor.
+    inline WasiExpected<void, Errno> fd_close(Fd fd) {
+        const Errno res = raw::fd_close(fd);
+        if (res != Errno{}) {
+            return WasiExpected<void, Errno>::from_error(res);
+        }
+        return WasiExpected<void, Errno>::from_value();
+    }
+
+    /// Owned `Fd` handle, closed with `fd_close()` when dropped.
+    class UniqueFd {
+      public:
+        explicit UniqueFd(Fd handle) noexcept : handle_(handle), valid_(true) {}
+        UniqueFd(const UniqueFd &) = delete;
+        UniqueFd &operator=(const UniqueFd &) = delete;
+
+        UniqueFd(UniqueFd &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {
+            other.valid_ = false;
+        }
+
+        UniqueFd &operator=(UniqueFd &&other) noexcept {
+            if (this != &other) {
+                reset();
+                handle_ = other.handle_;
+                valid_ = other.valid_;
+                other.valid_ = false;
+            }
+            return *this;
+        }
+
+        ~UniqueFd() {
+            reset();
+        }
+
+        Fd get() const noexcept {
+            return handle_;
+        }
+
+        Fd release() noexcept {
+            valid_ = false;
+            return handle_;
+        }
+
+        void reset() noexcept {
+            if (valid_) {
+                (void)raw::fd_close(handle_);
+                valid_ = false;
+            }
+        }
+
+      private:
+        Fd handle_;
+        bool valid_;
+    };
+
+} // namespace test_results
+
@@ -0,0 +1,238 @@
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    """A string in guest memory."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    """A slice in guest memory, `len` being its number of elements."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host function to return the error code `code` to the guest."""
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    """Memory exported by the guest instance calling a host function."""
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get("memory")
+            if self._memory is None:
+                raise RuntimeError("The guest doesn't export its memory")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        """Return the `length` bytes at address `ptr`."""
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        """Write `data` at address `ptr`."""
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        """Return the UTF-8 string of `length` bytes at address `ptr`."""
+        return self.read(ptr, length).decode("utf-8")
+
+    def read_value(self, ctype, ptr: int):
+        """Return a copy of the value of type `ctype` at address `ptr`."""
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        """Return a copy of the `length` values of type `ctype` at address `ptr`."""
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        """Write `value`, converted to `ctype` if necessary, at address `ptr`."""
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    """A buffer of `size` bytes in guest memory, to be filled by a host function."""
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        """Write `data` to the buffer, `offset` bytes after its start."""
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        self.memory.write(self.ptr + offset, data)
+
+
+# ---------------------- Module: [test_results] ----------------------
+
+class Errno(enum.IntEnum):
+    SUCCESS = 0
+    # Bad file descriptor.
+    BADF = 1
+
+
+Exitcode = ctypes.c_uint32
+
+
+Fd = typing.NewType("Fd", int)
+
+
+class Fdflags(enum.IntFlag):
+    APPEND = 1
+    NONBLOCK = 2
+
+
+class TestResultsHost:
+    """Functions of the `test_results` module, implemented by the host.
+
+    Override them in a subclass, and register an instance with `define_test_results_imports()`.
+    Methods receive the memory of the calling instance first.
+    Functions returning an error code raise `WasiError` to return it.
+    """
+
+    def proc_exit(self, memory: GuestMemory, rval: int) -> typing.NoReturn:
+        """Terminate the process."""
+        raise NotImplementedError("proc_exit")
+
+    def sched_yield(self, memory: GuestMemory) -> None:
+        """Yield execution."""
+        raise NotImplementedError("sched_yield")
+
+    def random_u32(self, memory: GuestMemory) -> int:
+        """Return a random number."""
+        raise NotImplementedError("random_u32")
+
+    def fd_flags(self, memory: GuestMemory, fd: Fd) -> Fdflags:
+        """Return the flags of a file descriptor."""
+        raise NotImplementedError("fd_flags")
+
+    def fd_dup(self, memory: GuestMemory, fd: Fd) -> Fd:
+        """Duplicate a file descriptor."""
+        raise NotImplementedError("fd_dup")
+
+    def buffer_ptr(self, memory: GuestMemory) -> int:
+        """Return a pointer to a buffer."""
+        raise NotImplementedError("buffer_ptr")
+
+    def fd_close(self, memory: GuestMemory, fd: Fd) -> None:
+        """Close a file descriptor."""
+        raise NotImplementedError("fd_close")
+
+
+def define_test_results_imports(linker: wasmtime.Linker, store: wasmtime.Store, impl: TestResultsHost) -> None:
+    """Define the functions of the `test_results` module in `linker`, implemented by `impl`."""
+
+    def _proc_exit(_caller, rval):
+        _memory = GuestMemory(_caller)
+        impl.proc_exit(_memory, rval)
+        raise RuntimeError("proc_exit returned")
+
+    linker.define(store, "test_results", "proc_exit", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], []),
+        _proc_exit,
+        access_caller=True,
+    ))
+
+    def _sched_yield(_caller):
+        _memory = GuestMemory(_caller)
+        impl.sched_yield(_memory)
+
+    linker.define(store, "test_results", "sched_yield", wasmtime.Func(
+        store,
+        wasmtime.FuncType([], []),
+        _sched_yield,
+        access_caller=True,
+    ))
+
+    def _random_u32(_caller):
+        _memory = GuestMemory(_caller)
+        return impl.random_u32(_memory)
+
+    linker.define(store, "test_results", "random_u32", wasmtime.Func(
+        store,
+        wasmtime.FuncType([], [wasmtime.ValType.i32()]),
+        _random_u32,
+        access_caller=True,
+    ))
+
+    def _fd_flags(_caller, fd):
+        _memory = GuestMemory(_caller)
+        return impl.fd_flags(_memory, Fd(fd))
+
+    linker.define(store, "test_results", "fd_flags", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _fd_flags,
+        access_caller=True,
+    ))
+
+    def _fd_dup(_caller, fd):
+        _memory = GuestMemory(_caller)
+        return impl.fd_dup(_memory, Fd(fd))
+
+    linker.define(store, "test_results", "fd_dup", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _fd_dup,
+        access_caller=True,
+    ))
+
+    def _buffer_ptr(_caller):
+        _memory = GuestMemory(_caller)
+        return impl.buffer_ptr(_memory)
+
+    linker.define(store, "test_results", "buffer_ptr", wasmtime.Func(
+        store,
+        wasmtime.FuncType([], [wasmtime.ValType.i32()]),
+        _buffer_ptr,
+        access_caller=True,
+    ))
+
+    def _fd_close(_caller, fd):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.fd_close(_memory, Fd(fd))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "test_results", "fd_close", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _fd_close,
+        access_caller=True,
+    ))
+
@@ -0,0 +1,454 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [test_results] ----------------------
+
+pub type Errno = u16;
+
+#[allow(non_snake_case)]
+pub mod ERRNO {
+    use super::Errno;
+    pub const SUCCESS: Errno = 0;
+    /// Bad file descriptor.
+    pub const BADF: Errno = 1;
+}
+
+pub type Exitcode = u32;
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Fd(pub WasiHandle);
+
+pub type Fdflags = u16;
+
+#[allow(non_snake_case)]
+pub mod FDFLAGS {
+    use super::Fdflags;
+    pub const APPEND: Fdflags = 1;
+    pub const NONBLOCK: Fdflags = 2;
+}
+
+/// Error returned by the functions of the `test_results` module.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TestResultsError {
+    /// Bad file descriptor.
+    Badf,
+    /// An error code that is not an error case of `Errno`.
+    Other(Errno),
+}
+
+impl From<Errno> for TestResultsError {
+    fn from(e: Errno) -> Self {
+        match e {
+            ERRNO::BADF => TestResultsError::Badf,
+            e => TestResultsError::Other(e),
+        }
+    }
+}
+
+impl From<TestResultsError> for Errno {
+    fn from(e: TestResultsError) -> Self {
+        match e {
+            TestResultsError::Badf => ERRNO::BADF,
+            TestResultsError::Other(e) => e,
+        }
+    }
+}
+
+impl From<TestResultsError> for Error {
+    fn from(e: TestResultsError) -> Self {
+        Error::WasiError(Errno::from(e) as _)
+    }
+}
+
+impl core::fmt::Display for TestResultsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            TestResultsError::Badf => "Bad file descriptor.",
+            TestResultsError::Other(e) => return write!(f, "Unknown error {}", e),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for TestResultsError {}
+
+/// Terminate the process.
+pub fn proc_exit(
+    rval: Exitcode,
+) -> ! {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_results")]
+        extern "C" {
+            pub fn proc_exit(
+                rval: Exitcode,
+            ) -> !;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn proc_exit(
+            rval: Exitcode,
+        ) -> ! {
+            super::with_test_results_mock(|mock| mock.proc_exit(rval));
+            unreachable!()
+        }
+    }
+    unsafe { raw::proc_exit(
+        rval,
+    )}
+}
+
+/// Yield execution.
+pub fn sched_yield() {
+    mod raw {
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_results")]
+        extern "C" {
+            pub fn sched_yield();
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn sched_yield() {
+            super::with_test_results_mock(|mock| mock.sched_yield())
+        }
+    }
+    unsafe { raw::sched_yield() }
+}
+
+/// Return a random number.
+pub fn random_u32() -> u32 {
+    mod raw {
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_results")]
+        extern "C" {
+            pub fn random_u32() -> u32;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn random_u32() -> u32 {
+            super::with_test_results_mock(|mock| mock.random_u32())
+        }
+    }
+    unsafe { raw::random_u32() }
+}
+
+/// Return the flags of a file descriptor.
+pub fn fd_flags(
+    fd: Fd,
+) -> Fdflags {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_results")]
+        extern "C" {
+            pub fn fd_flags(
+                fd: Fd,
+            ) -> Fdflags;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn fd_flags(
+            fd: Fd,
+        ) -> Fdflags {
+            super::with_test_results_mock(|mock| mock.fd_flags(fd))
+        }
+    }
+    unsafe { raw::fd_flags(
+        fd,
+    )}
+}
+
+/// Duplicate a file descriptor.
+pub fn fd_dup(
+    fd: Fd,
+) -> Fd {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_results")]
+        extern "C" {
+            pub fn fd_dup(
+                fd: Fd,
+            ) -> Fd;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn fd_dup(
+            fd: Fd,
+        ) -> Fd {
+            super::with_test_results_mock(|mock| mock.fd_dup(fd))
+        }
+    }
+    unsafe { raw::fd_dup(
+        fd,
+    )}
+}
+
+/// Return a pointer to a buffer.
+pub fn buffer_ptr() -> WasiPtr<u8> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_results")]
+        extern "C" {
+            pub fn buffer_ptr() -> WasiPtr<u8>;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn buffer_ptr() -> WasiPtr<u8> {
+            super::with_test_results_mock(|mock| mock.buffer_ptr())
+        }
+    }
+    unsafe { raw::buffer_ptr() }
+}
+
+/// Close a file descriptor.
+pub fn fd_close(
+    fd: Fd,
+) -> Result<(), TestResultsError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_results")]
+        extern "C" {
+            pub fn fd_close(
+                fd: Fd,
+            ) -> Errno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn fd_close(
+            fd: Fd,
+        ) -> Errno {
+            match super::with_test_results_mock(|mock| mock.fd_close(fd)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::fd_close(
+        fd,
+    )};
+    if res != 0 {
+        return Err(TestResultsError::from(res));
+    }
+    Ok(())
+}
+
+/// Implementation of the functions of the `test_results` module used on native targets.
+/// Install it with `set_test_results_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait TestResultsMock {
+    /// Terminate the process.
+    #[allow(unused_variables)]
+    fn proc_exit(
+        &self,
+        rval: Exitcode,
+    ) -> ! {
+        unimplemented!("proc_exit")
+    }
+
+    /// Yield execution.
+    #[allow(unused_variables)]
+    fn sched_yield(&self) {
+        unimplemented!("sched_yield")
+    }
+
+    /// Return a random number.
+    #[allow(unused_variables)]
+    fn random_u32(&self) -> u32 {
+        unimplemented!("random_u32")
+    }
+
+    /// Return the flags of a file descriptor.
+    #[allow(unused_variables)]
+    fn fd_flags(
+        &self,
+        fd: Fd,
+    ) -> Fdflags {
+        unimplemented!("fd_flags")
+    }
+
+    /// Duplicate a file descriptor.
+    #[allow(unused_variables)]
+    fn fd_dup(
+        &self,
+        fd: Fd,
+    ) -> Fd {
+        unimplemented!("fd_dup")
+    }
+
+    /// Return a pointer to a buffer.
+    #[allow(unused_variables)]
+    fn buffer_ptr(&self) -> WasiPtr<u8> {
+        unimplemented!("buffer_ptr")
+    }
+
+    /// Close a file descriptor.
+    #[allow(unused_variables)]
+    fn fd_close(
+        &self,
+        fd: Fd,
+    ) -> Result<(), TestResultsError> {
+        unimplemented!("fd_close")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static TEST_RESULTS_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn TestResultsMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `test_results` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_test_results_mock(mock: impl TestResultsMock + 'static) -> Option<std::rc::Rc<dyn TestResultsMock>> {
+    TEST_RESULTS_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_test_results_mock<R>(f: impl FnOnce(&dyn TestResultsMock) -> R) -> R {
+    let mock = TEST_RESULTS_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `test_results` module installed"))
+}
+
+/// Owned `Fd` handle, closed with `fd_close()` when dropped.
+#[derive(Debug)]
+pub struct OwnedFd(Fd);
+
+impl OwnedFd {
+    pub fn new(handle: Fd) -> Self {
+        OwnedFd(handle)
+    }
+
+    pub fn get(&self) -> Fd {
+        self.0
+    }
+
+    pub fn release(self) -> Fd {
+        let handle = self.0;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedFd {
+    fn drop(&mut self) {
+        let _ = fd_close(self.0);
+    }
+}
+
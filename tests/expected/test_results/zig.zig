@@ -0,0 +1,109 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [test_results] ----------------------
+
+pub const Errno = enum(u16) {
+    SUCCESS = 0,
+    /// Bad file descriptor.
+    BADF = 1,
+};
+
+pub const Exitcode = u32;
+
+pub const Fd = enum(WasiHandle) { _ };
+
+pub const Fdflags = u16;
+pub const FDFLAGS_APPEND: Fdflags = 1;
+pub const FDFLAGS_NONBLOCK: Fdflags = 2;
+
+
+
+pub const TestResults = struct {
+    /// Terminate the process.
+    pub extern "test_results" fn proc_exit(
+        rval: Exitcode,
+    ) callconv(.C) noreturn;
+
+    /// Yield execution.
+    pub extern "test_results" fn sched_yield() callconv(.C) void;
+
+    /// Return a random number.
+    pub extern "test_results" fn random_u32() callconv(.C) u32;
+
+    /// Return the flags of a file descriptor.
+    pub extern "test_results" fn fd_flags(
+        fd: Fd,
+    ) callconv(.C) Fdflags;
+
+    /// Duplicate a file descriptor.
+    pub extern "test_results" fn fd_dup(
+        fd: Fd,
+    ) callconv(.C) Fd;
+
+    /// Return a pointer to a buffer.
+    pub extern "test_results" fn buffer_ptr() callconv(.C) WasiPtr(u8);
+
+    /// Close a file descriptor.
+    pub extern "test_results" fn fd_close(
+        fd: Fd,
+    ) callconv(.C) Errno;
+
+};
+
@@ -0,0 +1,248 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/*
+* ---------------------- Module: [test_serde] ----------------------
+ */
+
+/**
+ * A color, serialized as the name of its case
+ */
+export type Color = u8;
+
+export namespace Color {
+    export const RED: Color = 0;
+    export const GREEN: Color = 1;
+    export const LIGHT_BLUE: Color = 2;
+}
+
+/**
+ * Permissions, serialized as the list of the names of the flags that are set
+ */
+export type Permissions = u16;
+
+export namespace Permissions {
+    export const READ: Permissions = 0x1;
+    export const WRITE: Permissions = 0x2;
+    export const EXECUTE: Permissions = 0x4;
+}
+
+
+/**
+ * A structure with padding, and a field whose name is a keyword
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Pixel {
+    x: u16;
+    private __pad16_0: u16;
+    private __pad32_0: u32;
+    y: u64;
+    color: Color;
+    private __pad8_2: u8;
+    type_: Permissions;
+    private __pad32_3: u32;
+}
+
+
+/**
+ * A tagged union, serialized as an enum
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Shape {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+    private __pad32_0: u32;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 8, 0, 24);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): Shape {
+        let tu = new Shape(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 8;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 8;
+        memory.fill(valBuf, 0, 24);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- point: Pixel if tag=0
+
+    static point(val: Pixel): Shape {
+        return Shape.new(0, val);
+    }
+
+    setPoint(val: Pixel): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isPoint(): bool {
+        return this.tag === 0;
+    }
+
+    getPoint(): Pixel {
+        return this.get<Pixel>();
+    }
+
+    // --- circle: u32 if tag=1
+
+    static circle(val: u32): Shape {
+        return Shape.new(1, val);
+    }
+
+    setCircle(val: u32): void {
+        this.tag = 1;
+        this.set(val);
+    }
+
+    isCircle(): bool {
+        return this.tag === 1;
+    }
+
+    getCircle(): u32 {
+        return this.get<u32>();
+    }
+
+    // --- filled: Color if tag=2
+
+    static filled(val: Color): Shape {
+        return Shape.new(2, val);
+    }
+
+    setFilled(val: Color): void {
+        this.tag = 2;
+        this.set(val);
+    }
+
+    isFilled(): bool {
+        return this.tag === 2;
+    }
+
+    getFilled(): Color {
+        return this.get<Color>();
+    }
+
+    // --- empty: (no associated content) if tag=3
+
+    static empty(): Shape {
+        return Shape.new(3);
+    }
+
+    setEmpty(): void {
+        this.tag = 3;
+    }
+
+    isEmpty(): bool {
+        return this.tag === 3;
+    }
+}
+
+
+// @ts-ignore: decorator
+@unmanaged
+export class Shapes { // -- Tuple
+    v0: Shape;
+    v1: Color;
+    private __pad8_1: u8;
+    private __pad16_1: u16;
+    private __pad32_1: u32;
+}
+
+
+/**
+ * A structure that can't be serialized
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Label {
+    text: WasiString;
+    color: Color;
+    private __pad8_1: u8;
+    private __pad16_1: u16;
+}
+
+
+// @ts-ignore: decorator
+@external("test_serde", "draw")
+export declare function draw(
+    shape: Shape,
+    label: Label
+): void;
+
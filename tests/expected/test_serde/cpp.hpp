@@ -0,0 +1,331 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [test_serde] ----------------------
+
+namespace test_serde {
+
+    /// A color, serialized as the name of its case
+    enum class Color : uint8_t {
+        RED = 0,
+        GREEN = 1,
+        LIGHT_BLUE = 2,
+    };
+
+    /// Permissions, serialized as the list of the names of the flags that are set
+    using Permissions = uint16_t;
+
+    namespace permissions {
+        constexpr Permissions READ = 0x1;
+        constexpr Permissions WRITE = 0x2;
+        constexpr Permissions EXECUTE = 0x4;
+    } // namespace permissions
+
+    /// A structure with padding, and a field whose name is a keyword
+    struct Pixel {
+        uint16_t x;
+        uint16_t __pad16_0;
+        uint32_t __pad32_0;
+        uint64_t y;
+        Color color;
+        uint8_t __pad8_2;
+        Permissions type;
+        uint32_t __pad32_3;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(Pixel, x) == 0, "unexpected layout");
+    static_assert(offsetof(Pixel, y) == 8, "unexpected layout");
+    static_assert(offsetof(Pixel, color) == 16, "unexpected layout");
+    static_assert(offsetof(Pixel, type) == 18, "unexpected layout");
+    #endif
+
+    /// A tagged union, serialized as an enum
+    struct Shape {
+        enum class Tag : uint8_t {
+            POINT = 0,
+            CIRCLE = 1,
+            FILLED = 2,
+            EMPTY = 3,
+        };
+
+        Tag tag;
+        union {
+            Pixel point;
+            uint32_t circle;
+            Color filled;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- point: Pixel if tag=0
+
+        static Shape make_point(Pixel val) {
+            Shape tu{};
+            tu.tag = Tag::POINT;
+            tu.member.point = val;
+            return tu;
+        }
+
+        void emplace_point(Pixel val) {
+            tag = Tag::POINT;
+            member.point = val;
+        }
+
+        const Pixel *get_if_point() const {
+            return holds_point() ? &member.point : nullptr;
+        }
+
+        Pixel *get_if_point() {
+            return holds_point() ? &member.point : nullptr;
+        }
+
+        bool holds_point() const {
+            return tag == Tag::POINT;
+        }
+
+        // --- circle: uint32_t if tag=1
+
+        static Shape make_circle(uint32_t val) {
+            Shape tu{};
+            tu.tag = Tag::CIRCLE;
+            tu.member.circle = val;
+            return tu;
+        }
+
+        void emplace_circle(uint32_t val) {
+            tag = Tag::CIRCLE;
+            member.circle = val;
+        }
+
+        const uint32_t *get_if_circle() const {
+            return holds_circle() ? &member.circle : nullptr;
+        }
+
+        uint32_t *get_if_circle() {
+            return holds_circle() ? &member.circle : nullptr;
+        }
+
+        bool holds_circle() const {
+            return tag == Tag::CIRCLE;
+        }
+
+        // --- filled: Color if tag=2
+
+        static Shape make_filled(Color val) {
+            Shape tu{};
+            tu.tag = Tag::FILLED;
+            tu.member.filled = val;
+            return tu;
+        }
+
+        void emplace_filled(Color val) {
+            tag = Tag::FILLED;
+            member.filled = val;
+        }
+
+        const Color *get_if_filled() const {
+            return holds_filled() ? &member.filled : nullptr;
+        }
+
+        Color *get_if_filled() {
+            return holds_filled() ? &member.filled : nullptr;
+        }
+
+        bool holds_filled() const {
+            return tag == Tag::FILLED;
+        }
+
+        // --- empty: (no associated content) if tag=3
+
+        static Shape make_empty() {
+            Shape tu{};
+            tu.tag = Tag::EMPTY;
+            return tu;
+        }
+
+        void emplace_empty() {
+            tag = Tag::EMPTY;
+        }
+
+        bool holds_empty() const {
+            return tag == Tag::EMPTY;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(Shape, member) == 8, "unexpected layout");
+    #endif
+
+    struct Shapes { // -- Tuple
+        Shape v0;
+        Color v1;
+        uint8_t __pad8_1;
+        uint16_t __pad16_1;
+        uint32_t __pad32_1;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(Shapes, v0) == 0, "unexpected layout");
+    static_assert(offsetof(Shapes, v1) == 32, "unexpected layout");
+    #endif
+
+    /// A structure that can't be serialized
+    struct Label {
+        WasiString text;
+        Color color;
+        uint8_t __pad8_1;
+        uint16_t __pad16_1;
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(Label, text) == 0, "unexpected layout");
+    static_assert(offsetof(Label, color) == 8, "unexpected layout");
+    #endif
+
+    namespace raw {
+
+        extern "C" WITX_CODEGEN_IMPORT("test_serde", "draw")
+        void draw(
+            Shape shape,
+            Label label
+        );
+
+    } // namespace raw
+
+    inline void draw(Shape shape, Label label) {
+        raw::draw(shape, label);
+    }
+
+} // namespace test_serde
+
@@ -0,0 +1,398 @@
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+package testserde
+
+import (
+	"context"
+	"errors"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New("invalid guest memory access")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// ---------------------- Module: [test_serde] ----------------------
+
+// A color, serialized as the name of its case
+type Color uint8
+
+const (
+	ColorRed       Color = 0
+	ColorGreen     Color = 1
+	ColorLightBlue Color = 2
+)
+
+// Permissions, serialized as the list of the names of the flags that are set
+type Permissions uint16
+
+const (
+	PermissionsRead    Permissions = 0x1
+	PermissionsWrite   Permissions = 0x2
+	PermissionsExecute Permissions = 0x4
+)
+
+// A structure with padding, and a field whose name is a keyword
+type Pixel struct {
+	X     uint16
+	Y     uint64
+	Color Color
+	Type  Permissions
+}
+
+func (r *memReader) Pixel(offset uint32) Pixel {
+	return Pixel{
+		X:     r.u16(offset),
+		Y:     r.u64(offset + 8),
+		Color: Color(r.u8(offset + 16)),
+		Type:  Permissions(r.u16(offset + 18)),
+	}
+}
+
+func (w *memWriter) Pixel(offset uint32, v Pixel) {
+	w.u16(offset, v.X)
+	w.u64(offset+8, v.Y)
+	w.u8(offset+16, uint8(v.Color))
+	w.u16(offset+18, uint16(v.Type))
+}
+
+// ReadPixel reads a Pixel from guest memory at offset, and returns false if it is out of range.
+func ReadPixel(mem api.Memory, offset uint32) (Pixel, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.Pixel(offset)
+	return v, r.ok
+}
+
+// WritePixel writes a Pixel to guest memory at offset, and returns false if it is out of range.
+func WritePixel(mem api.Memory, offset uint32, v Pixel) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.Pixel(offset, v)
+	return w.ok
+}
+
+// A tagged union, serialized as an enum
+type Shape struct {
+	Tag    uint8
+	Point  Pixel
+	Circle uint32
+	Filled Color
+}
+
+const (
+	ShapePoint  uint8 = 0
+	ShapeCircle uint8 = 1
+	ShapeFilled uint8 = 2
+	ShapeEmpty  uint8 = 3
+)
+
+func (r *memReader) Shape(offset uint32) Shape {
+	v := Shape{Tag: r.u8(offset)}
+	switch v.Tag {
+	case ShapePoint:
+		v.Point = r.Pixel(offset + 8)
+	case ShapeCircle:
+		v.Circle = r.u32(offset + 8)
+	case ShapeFilled:
+		v.Filled = Color(r.u8(offset + 8))
+	case ShapeEmpty:
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) Shape(offset uint32, v Shape) {
+	w.u8(offset, v.Tag)
+	switch v.Tag {
+	case ShapePoint:
+		w.Pixel(offset+8, v.Point)
+	case ShapeCircle:
+		w.u32(offset+8, v.Circle)
+	case ShapeFilled:
+		w.u8(offset+8, uint8(v.Filled))
+	case ShapeEmpty:
+	default:
+		w.ok = false
+	}
+}
+
+// ReadShape reads a Shape from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadShape(mem api.Memory, offset uint32) (Shape, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.Shape(offset)
+	return v, r.ok
+}
+
+// WriteShape writes a Shape to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteShape(mem api.Memory, offset uint32, v Shape) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.Shape(offset, v)
+	return w.ok
+}
+
+type Shapes struct {
+	V0 Shape
+	V1 Color
+}
+
+func (r *memReader) Shapes(offset uint32) Shapes {
+	return Shapes{
+		V0: r.Shape(offset),
+		V1: Color(r.u8(offset + 32)),
+	}
+}
+
+func (w *memWriter) Shapes(offset uint32, v Shapes) {
+	w.Shape(offset, v.V0)
+	w.u8(offset+32, uint8(v.V1))
+}
+
+// ReadShapes reads a Shapes from guest memory at offset, and returns false if it is out of range.
+func ReadShapes(mem api.Memory, offset uint32) (Shapes, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.Shapes(offset)
+	return v, r.ok
+}
+
+// WriteShapes writes a Shapes to guest memory at offset, and returns false if it is out of range.
+func WriteShapes(mem api.Memory, offset uint32, v Shapes) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.Shapes(offset, v)
+	return w.ok
+}
+
+// A structure that can't be serialized
+type Label struct {
+	Text  WasiString
+	Color Color
+}
+
+func (r *memReader) Label(offset uint32) Label {
+	return Label{
+		Text:  r.WasiString(offset),
+		Color: Color(r.u8(offset + 8)),
+	}
+}
+
+func (w *memWriter) Label(offset uint32, v Label) {
+	w.WasiString(offset, v.Text)
+	w.u8(offset+8, uint8(v.Color))
+}
+
+// ReadLabel reads a Label from guest memory at offset, and returns false if it is out of range.
+func ReadLabel(mem api.Memory, offset uint32) (Label, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.Label(offset)
+	return v, r.ok
+}
+
+// WriteLabel writes a Label to guest memory at offset, and returns false if it is out of range.
+func WriteLabel(mem api.Memory, offset uint32, v Label) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.Label(offset, v)
+	return w.ok
+}
+
+// TestSerdeHost is implemented by the host to provide the functions of the `test_serde` module.
+// Register an implementation with DefineTestSerdeImports.
+// Methods receive the module calling them, whose memory parameters are read from.
+// Functions returning an error code return it last, results only being written if it is zero.
+type TestSerdeHost interface {
+	Draw(ctx context.Context, mod api.Module, shape Shape, label Label)
+}
+
+// DefineTestSerdeImports returns a builder for the `test_serde` host module, whose functions call host.
+// Instantiate it before the guest modules importing them.
+func DefineTestSerdeImports(runtime wazero.Runtime, host TestSerdeHost) wazero.HostModuleBuilder {
+	builder := runtime.NewHostModuleBuilder("test_serde")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			shape := r.Shape(api.DecodeU32(stack[0]))
+			label := r.Label(api.DecodeU32(stack[1]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			host.Draw(ctx, mod, shape, label)
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{}).
+		WithParameterNames("shape", "label").
+		Export("draw")
+	return builder
+}
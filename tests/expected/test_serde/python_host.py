@@ -0,0 +1,228 @@
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    """A string in guest memory."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    """A slice in guest memory, `len` being its number of elements."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host function to return the error code `code` to the guest."""
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    """Memory exported by the guest instance calling a host function."""
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get("memory")
+            if self._memory is None:
+                raise RuntimeError("The guest doesn't export its memory")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        """Return the `length` bytes at address `ptr`."""
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        """Write `data` at address `ptr`."""
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        """Return the UTF-8 string of `length` bytes at address `ptr`."""
+        return self.read(ptr, length).decode("utf-8")
+
+    def read_value(self, ctype, ptr: int):
+        """Return a copy of the value of type `ctype` at address `ptr`."""
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        """Return a copy of the `length` values of type `ctype` at address `ptr`."""
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        """Write `value`, converted to `ctype` if necessary, at address `ptr`."""
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    """A buffer of `size` bytes in guest memory, to be filled by a host function."""
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        """Write `data` to the buffer, `offset` bytes after its start."""
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        self.memory.write(self.ptr + offset, data)
+
+
+# ---------------------- Module: [test_serde] ----------------------
+
+class Color(enum.IntEnum):
+    """A color, serialized as the name of its case"""
+
+    RED = 0
+    GREEN = 1
+    LIGHT_BLUE = 2
+
+
+class Permissions(enum.IntFlag):
+    """Permissions, serialized as the list of the names of the flags that are set"""
+
+    READ = 0x1
+    WRITE = 0x2
+    EXECUTE = 0x4
+
+
+class Pixel(ctypes.Structure):
+    """A structure with padding, and a field whose name is a keyword"""
+
+    _pack_ = 1
+    _fields_ = [
+        ("x", ctypes.c_uint16),
+        ("_pad0", ctypes.c_uint8 * 6),
+        ("y", ctypes.c_uint64),
+        ("color", ctypes.c_uint8),
+        ("_pad2", ctypes.c_uint8 * 1),
+        ("type", ctypes.c_uint16),
+        ("_pad3", ctypes.c_uint8 * 4),
+    ]
+
+
+class Shape(ctypes.Structure):
+    """A tagged union, serialized as an enum"""
+
+    class _Member(ctypes.Union):
+        _pack_ = 1
+        _fields_ = [
+            ("point", Pixel),
+            ("circle", ctypes.c_uint32),
+            ("filled", ctypes.c_uint8),
+            ("_size", ctypes.c_uint8 * 24),
+        ]
+
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("_pad0", ctypes.c_uint8 * 7),
+        ("member", _Member),
+    ]
+
+    POINT = 0
+    CIRCLE = 1
+    FILLED = 2
+    EMPTY = 3
+
+    @classmethod
+    def new_point(cls, value) -> Shape:
+        """Return a `Shape` with the `point` case."""
+        union = cls(tag=cls.POINT)
+        union.member.point = value
+        return union
+
+    @classmethod
+    def new_circle(cls, value) -> Shape:
+        """Return a `Shape` with the `circle` case."""
+        union = cls(tag=cls.CIRCLE)
+        union.member.circle = value
+        return union
+
+    @classmethod
+    def new_filled(cls, value) -> Shape:
+        """Return a `Shape` with the `filled` case."""
+        union = cls(tag=cls.FILLED)
+        union.member.filled = value
+        return union
+
+    @classmethod
+    def new_empty(cls) -> Shape:
+        """Return a `Shape` with the `empty` case."""
+        union = cls(tag=cls.EMPTY)
+        return union
+
+
+class Shapes(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("v0", Shape),
+        ("v1", ctypes.c_uint8),
+        ("_pad1", ctypes.c_uint8 * 7),
+    ]
+
+
+class Label(ctypes.Structure):
+    """A structure that can't be serialized"""
+
+    _pack_ = 1
+    _fields_ = [
+        ("text", WasiString),
+        ("color", ctypes.c_uint8),
+        ("_pad1", ctypes.c_uint8 * 3),
+    ]
+
+
+class TestSerdeHost:
+    """Functions of the `test_serde` module, implemented by the host.
+
+    Override them in a subclass, and register an instance with `define_test_serde_imports()`.
+    Methods receive the memory of the calling instance first.
+    Functions returning an error code raise `WasiError` to return it.
+    """
+
+    def draw(self, memory: GuestMemory, shape: Shape, label: Label) -> None:
+        raise NotImplementedError("draw")
+
+
+def define_test_serde_imports(linker: wasmtime.Linker, store: wasmtime.Store, impl: TestSerdeHost) -> None:
+    """Define the functions of the `test_serde` module in `linker`, implemented by `impl`."""
+
+    def _draw(_caller, shape, label):
+        _memory = GuestMemory(_caller)
+        impl.draw(_memory, _memory.read_value(Shape, shape), _memory.read_value(Label, label))
+
+    linker.define(store, "test_serde", "draw", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], []),
+        _draw,
+        access_caller=True,
+    ))
+
@@ -0,0 +1,444 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [test_serde] ----------------------
+
+/// A color, serialized as the name of its case
+pub type Color = u8;
+
+#[allow(non_snake_case)]
+pub mod COLOR {
+    use super::Color;
+    pub const RED: Color = 0;
+    pub const GREEN: Color = 1;
+    pub const LIGHT_BLUE: Color = 2;
+}
+
+/// Permissions, serialized as the list of the names of the flags that are set
+pub type Permissions = u16;
+
+#[allow(non_snake_case)]
+pub mod PERMISSIONS {
+    use super::Permissions;
+    pub const READ: Permissions = 0x1;
+    pub const WRITE: Permissions = 0x2;
+    pub const EXECUTE: Permissions = 0x4;
+}
+
+/// A structure with padding, and a field whose name is a keyword
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Pixel {
+    pub x: u16,
+    pub y: u64,
+    pub color: Color,
+    pub r#type: Permissions,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<Pixel>() == 24);
+    assert!(core::mem::align_of::<Pixel>() == 8);
+    assert!(core::mem::offset_of!(Pixel, x) == 0);
+    assert!(core::mem::offset_of!(Pixel, y) == 8);
+    assert!(core::mem::offset_of!(Pixel, color) == 16);
+    assert!(core::mem::offset_of!(Pixel, r#type) == 18);
+};
+
+/// A tagged union, serialized as an enum
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ShapeMember {
+    point: Pixel, // if tag=0
+    circle: u32, // if tag=1
+    filled: Color, // if tag=2
+    // empty with no associated value if tag=3
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Shape {
+    pub tag: u8,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    __pad32_0: u32,
+    pub member: core::mem::MaybeUninit<ShapeMember>,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<Shape>() == 32);
+    assert!(core::mem::align_of::<Shape>() == 8);
+    assert!(core::mem::offset_of!(Shape, tag) == 0);
+    assert!(core::mem::offset_of!(Shape, member) == 8);
+};
+
+impl Shape {
+    fn new(tag: u8) -> Self {
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<ShapeRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(ShapeRef::Point(unsafe { &self.member.assume_init_ref().point })),
+            1 => Ok(ShapeRef::Circle(unsafe { &self.member.assume_init_ref().circle })),
+            2 => Ok(ShapeRef::Filled(unsafe { &self.member.assume_init_ref().filled })),
+            3 => Ok(ShapeRef::Empty),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
+    // --- point: Pixel if tag=0
+
+    pub fn new_point(val: Pixel) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = core::mem::MaybeUninit::new(ShapeMember { point: val });
+        tu
+    }
+
+    pub fn into_point(self) -> Pixel {
+        assert_eq!(self.tag, 0);
+        unsafe { self.member.assume_init().point }
+    }
+
+    pub fn set_point(&mut self, val: Pixel) {
+        assert_eq!(self.tag, 0);
+        let uval = ShapeMember { point: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_point(&self) -> bool {
+        self.tag == 0
+    }
+
+    // --- circle: u32 if tag=1
+
+    pub fn new_circle(val: u32) -> Self {
+        let mut tu = Self::new(1);
+        tu.member = core::mem::MaybeUninit::new(ShapeMember { circle: val });
+        tu
+    }
+
+    pub fn into_circle(self) -> u32 {
+        assert_eq!(self.tag, 1);
+        unsafe { self.member.assume_init().circle }
+    }
+
+    pub fn set_circle(&mut self, val: u32) {
+        assert_eq!(self.tag, 1);
+        let uval = ShapeMember { circle: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_circle(&self) -> bool {
+        self.tag == 1
+    }
+
+    // --- filled: Color if tag=2
+
+    pub fn new_filled(val: Color) -> Self {
+        let mut tu = Self::new(2);
+        tu.member = core::mem::MaybeUninit::new(ShapeMember { filled: val });
+        tu
+    }
+
+    pub fn into_filled(self) -> Color {
+        assert_eq!(self.tag, 2);
+        unsafe { self.member.assume_init().filled }
+    }
+
+    pub fn set_filled(&mut self, val: Color) {
+        assert_eq!(self.tag, 2);
+        let uval = ShapeMember { filled: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_filled(&self) -> bool {
+        self.tag == 2
+    }
+
+    // --- empty: (no associated content) if tag=3
+
+    pub fn new_empty() -> Self {
+        Self::new(3)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tag == 3
+    }
+}
+
+/// Content of a `Shape`.
+#[derive(Copy, Clone, Debug)]
+pub enum ShapeEnum {
+    Point(Pixel),
+    Circle(u32),
+    Filled(Color),
+    Empty,
+}
+
+/// Reference to the content of a `Shape`.
+#[derive(Copy, Clone, Debug)]
+pub enum ShapeRef<'a> {
+    Point(&'a Pixel),
+    Circle(&'a u32),
+    Filled(&'a Color),
+    Empty,
+}
+
+impl<'a> core::convert::TryFrom<&'a Shape> for ShapeRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a Shape) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<ShapeRef<'_>> for ShapeEnum {
+    fn from(content: ShapeRef<'_>) -> Self {
+        match content {
+            ShapeRef::Point(val) => ShapeEnum::Point(*val),
+            ShapeRef::Circle(val) => ShapeEnum::Circle(*val),
+            ShapeRef::Filled(val) => ShapeEnum::Filled(*val),
+            ShapeRef::Empty => ShapeEnum::Empty,
+        }
+    }
+}
+
+impl core::convert::TryFrom<Shape> for ShapeEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: Shape) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<ShapeEnum> for Shape {
+    fn from(content: ShapeEnum) -> Self {
+        match content {
+            ShapeEnum::Point(val) => Self::new_point(val),
+            ShapeEnum::Circle(val) => Self::new_circle(val),
+            ShapeEnum::Filled(val) => Self::new_filled(val),
+            ShapeEnum::Empty => Self::new_empty(),
+        }
+    }
+}
+
+impl core::fmt::Debug for Shape {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Shapes { // -- Tuple
+    pub v0: Shape,
+    pub v1: Color,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<Shapes>() == 40);
+    assert!(core::mem::align_of::<Shapes>() == 8);
+    assert!(core::mem::offset_of!(Shapes, v0) == 0);
+    assert!(core::mem::offset_of!(Shapes, v1) == 32);
+};
+
+/// A structure that can't be serialized
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Label {
+    pub text: WasiString,
+    pub color: Color,
+}
+
+#[cfg(target_arch = "wasm32")]
+const _: () = {
+    assert!(core::mem::size_of::<Label>() == 12);
+    assert!(core::mem::align_of::<Label>() == 4);
+    assert!(core::mem::offset_of!(Label, text) == 0);
+    assert!(core::mem::offset_of!(Label, color) == 8);
+};
+
+pub fn draw(
+    shape: Shape,
+    label: Label,
+) {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "test_serde")]
+        extern "C" {
+            pub fn draw(
+                shape: Shape,
+                label: Label,
+            );
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn draw(
+            shape: Shape,
+            label: Label,
+        ) {
+            super::with_test_serde_mock(|mock| mock.draw(shape, label))
+        }
+    }
+    unsafe { raw::draw(
+        shape,
+        label,
+    )}
+}
+
+/// Implementation of the functions of the `test_serde` module used on native targets.
+/// Install it with `set_test_serde_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait TestSerdeMock {
+    #[allow(unused_variables)]
+    fn draw(
+        &self,
+        shape: Shape,
+        label: Label,
+    ) {
+        unimplemented!("draw")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static TEST_SERDE_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn TestSerdeMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `test_serde` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_test_serde_mock(mock: impl TestSerdeMock + 'static) -> Option<std::rc::Rc<dyn TestSerdeMock>> {
+    TEST_SERDE_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_test_serde_mock<R>(f: impl FnOnce(&dyn TestSerdeMock) -> R) -> R {
+    let mock = TEST_SERDE_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `test_serde` module installed"))
+}
+
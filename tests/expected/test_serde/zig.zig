@@ -0,0 +1,201 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [test_serde] ----------------------
+
+/// A color, serialized as the name of its case
+pub const Color = enum(u8) {
+    RED = 0,
+    GREEN = 1,
+    LIGHT_BLUE = 2,
+};
+
+/// Permissions, serialized as the list of the names of the flags that are set
+pub const Permissions = u16;
+pub const PERMISSIONS_READ: Permissions = 0x1;
+pub const PERMISSIONS_WRITE: Permissions = 0x2;
+pub const PERMISSIONS_EXECUTE: Permissions = 0x4;
+
+
+
+/// A structure with padding, and a field whose name is a keyword
+pub const Pixel = extern struct {
+    x: u16,
+    __pad16_0: u16 = undefined,
+    __pad32_0: u32 = undefined,
+    y: u64,
+    color: Color,
+    __pad8_2: u8 = undefined,
+    @"type": Permissions,
+    __pad32_3: u32 = undefined,
+};
+
+
+/// A tagged union, serialized as an enum
+pub const Shape = extern struct {
+    tag: enum(u8) {
+        point = 0,
+        circle = 1,
+        filled = 2,
+        empty = 3,
+    },
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    __pad32_0: u32 = undefined,
+    member = extern union {
+        point: Pixel,
+        circle: u32,
+        filled: Color,
+    },
+
+
+fn newPoint(val: Pixel) Shape {
+    return Shape{ .tag = .point, .member = .{ .point = val } };
+}
+
+pub fn Point(self: Shape) Pixel {
+    std.debug.assert(self.tag == .point);
+    return self.member.point;
+}
+
+pub fn setPoint(self: *Shape, val: Pixel) void {
+    std.debug.assert(self.tag == .point);
+    self.member.point = val;
+}
+
+fn isPoint(self: Shape) bool {
+    return self.tag == .point;
+}
+
+
+fn newCircle(val: u32) Shape {
+    return Shape{ .tag = .circle, .member = .{ .circle = val } };
+}
+
+pub fn Circle(self: Shape) u32 {
+    std.debug.assert(self.tag == .circle);
+    return self.member.circle;
+}
+
+pub fn setCircle(self: *Shape, val: u32) void {
+    std.debug.assert(self.tag == .circle);
+    self.member.circle = val;
+}
+
+fn isCircle(self: Shape) bool {
+    return self.tag == .circle;
+}
+
+
+fn newFilled(val: Color) Shape {
+    return Shape{ .tag = .filled, .member = .{ .filled = val } };
+}
+
+pub fn Filled(self: Shape) Color {
+    std.debug.assert(self.tag == .filled);
+    return self.member.filled;
+}
+
+pub fn setFilled(self: *Shape, val: Color) void {
+    std.debug.assert(self.tag == .filled);
+    self.member.filled = val;
+}
+
+fn isFilled(self: Shape) bool {
+    return self.tag == .filled;
+}
+
+
+fn newEmpty() Shape {
+    return Shape { .tag = .empty };
+}
+
+fn isEmpty(self: Shape) bool {
+    return self.tag == .empty;
+}
+
+};
+
+
+pub const Shapes = extern struct { // -- Tuple
+    v0: Shape,
+    v1: Color,
+    __pad8_1: u8 = undefined,
+    __pad16_1: u16 = undefined,
+    __pad32_1: u32 = undefined,
+};
+
+
+/// A structure that can't be serialized
+pub const Label = extern struct {
+    text: WasiString,
+    color: Color,
+    __pad8_1: u8 = undefined,
+    __pad16_1: u16 = undefined,
+};
+
+
+pub const TestSerde = struct {
+    pub extern "test_serde" fn draw(
+        shape: Shape,
+        label: Label,
+    ) callconv(.C) void;
+
+};
+
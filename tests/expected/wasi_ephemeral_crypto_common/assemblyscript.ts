@@ -0,0 +1,960 @@
+
+/*
+ * This file was automatically generated by witx-codegen - Do not edit manually.
+ */
+
+export type WasiHandle = i32;
+export type Char8 = u8;
+export type Char32 = u32;
+export type WasiPtr<T> = usize;
+export type WasiMutPtr<T> = usize;
+export type WasiStringBytesPtr = WasiPtr<Char8>;
+
+@unmanaged
+export class WasiString {
+    ptr: WasiStringBytesPtr;
+    length: usize;
+
+    constructor(str: string) {
+        let wasiString = String.UTF8.encode(str, false);
+        // @ts-ignore: cast
+        this.ptr = changetype<WasiStringBytesPtr>(wasiString);
+        this.length = wasiString.byteLength;
+    }
+
+    toString(): string {
+        let tmp = new ArrayBuffer(this.length as u32);
+        memory.copy(changetype<usize>(tmp), this.ptr, this.length);
+        return String.UTF8.decode(tmp);
+    }
+}
+
+@unmanaged
+export class WasiSlice<T> {
+    ptr: WasiPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+@unmanaged
+export class WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>;
+    length: usize;
+
+    constructor(array: ArrayBufferView) {
+        // @ts-ignore: cast
+        this.ptr = array.dataStart;
+        this.length = array.byteLength;
+    }
+}
+
+/*
+* ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+ */
+
+// @ts-ignore: decorator
+@unmanaged
+export class Handle {
+    static fromRaw(handle: WasiHandle): Handle {
+        return changetype<Handle>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * Error codes.
+ */
+export type CryptoErrno = u16;
+
+export namespace CryptoErrno {
+    /**
+     * Operation succeeded.
+     */
+    export const SUCCESS: CryptoErrno = 0;
+    /**
+     * An error occurred when trying to during a conversion from a host type to a guest type.
+     *
+     * Only an internal bug can throw this error.
+     */
+    export const GUEST_ERROR: CryptoErrno = 1;
+    /**
+     * The requested operation is valid, but not implemented by the host.
+     */
+    export const NOT_IMPLEMENTED: CryptoErrno = 2;
+    /**
+     * The requested feature is not supported by the chosen algorithm.
+     */
+    export const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    /**
+     * The requested operation is valid, but was administratively prohibited.
+     */
+    export const PROHIBITED_OPERATION: CryptoErrno = 4;
+    /**
+     * Unsupported encoding for an import or export operation.
+     */
+    export const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    /**
+     * The requested algorithm is not supported by the host.
+     */
+    export const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    /**
+     * The requested option is not supported by the currently selected algorithm.
+     */
+    export const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    /**
+     * An invalid or incompatible key was supplied.
+     *
+     * The key may not be valid, or was generated for a different algorithm or parameters set.
+     */
+    export const INVALID_KEY: CryptoErrno = 8;
+    /**
+     * The currently selected algorithm doesn't support the requested output length.
+     *
+     * This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+     */
+    export const INVALID_LENGTH: CryptoErrno = 9;
+    /**
+     * A signature or authentication tag verification failed.
+     */
+    export const VERIFICATION_FAILED: CryptoErrno = 10;
+    /**
+     * A secure random numbers generator is not available.
+     *
+     * The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+     */
+    export const RNG_ERROR: CryptoErrno = 11;
+    /**
+     * An error was returned by the underlying cryptography library.
+     *
+     * The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+     *
+     * Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+     *
+     * Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+     * This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+     */
+    export const ALGORITHM_FAILURE: CryptoErrno = 12;
+    /**
+     * The supplied signature is invalid, or incompatible with the chosen algorithm.
+     */
+    export const INVALID_SIGNATURE: CryptoErrno = 13;
+    /**
+     * An attempt was made to close a handle that was already closed.
+     */
+    export const CLOSED: CryptoErrno = 14;
+    /**
+     * A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+     */
+    export const INVALID_HANDLE: CryptoErrno = 15;
+    /**
+     * The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+     */
+    export const OVERFLOW: CryptoErrno = 16;
+    /**
+     * An internal error occurred.
+     *
+     * This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+     */
+    export const INTERNAL_ERROR: CryptoErrno = 17;
+    /**
+     * Too many handles are currently open, and a new one cannot be created.
+     *
+     * Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+     */
+    export const TOO_MANY_HANDLES: CryptoErrno = 18;
+    /**
+     * A key was provided, but the chosen algorithm doesn't support keys.
+     *
+     * This is returned by symmetric operations.
+     *
+     * Many hash functions, in particular, do not support keys without being used in particular constructions.
+     * Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+     *
+     * These functions must refuse to create the context and return this error instead.
+     */
+    export const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    /**
+     * A key is required for the chosen algorithm, but none was given.
+     */
+    export const KEY_REQUIRED: CryptoErrno = 20;
+    /**
+     * The provided authentication tag is invalid or incompatible with the current algorithm.
+     *
+     * This error is returned by decryption functions and tag verification functions.
+     *
+     * Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+     */
+    export const INVALID_TAG: CryptoErrno = 21;
+    /**
+     * The requested operation is incompatible with the current scheme.
+     *
+     * For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+     * This error code will be returned instead.
+     */
+    export const INVALID_OPERATION: CryptoErrno = 22;
+    /**
+     * A nonce is required.
+     *
+     * Most encryption schemes require a nonce.
+     *
+     * In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+     * If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+     */
+    export const NONCE_REQUIRED: CryptoErrno = 23;
+    /**
+     * The provided nonce doesn't have a correct size for the given cipher.
+     */
+    export const INVALID_NONCE: CryptoErrno = 24;
+    /**
+     * The named option was not set.
+     *
+     * The caller tried to read the value of an option that was not set.
+     * This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+     */
+    export const OPTION_NOT_SET: CryptoErrno = 25;
+    /**
+     * A key or key pair matching the requested identifier cannot be found using the supplied information.
+     *
+     * This error is returned by a secrets manager via the `keypair_from_id()` function.
+     */
+    export const NOT_FOUND: CryptoErrno = 26;
+    /**
+     * The algorithm requires parameters that haven't been set.
+     *
+     * Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+     */
+    export const PARAMETERS_MISSING: CryptoErrno = 27;
+    /**
+     * A requested computation is not done yet, and additional calls to the function are required.
+     *
+     * Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+     *
+     * In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+     */
+    export const IN_PROGRESS: CryptoErrno = 28;
+    /**
+     * Multiple keys have been provided, but they do not share the same type.
+     *
+     * This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+     */
+    export const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    /**
+     * A managed key or secret expired and cannot be used any more.
+     */
+    export const EXPIRED: CryptoErrno = 30;
+}
+
+/**
+ * Encoding to use for importing or exporting a key pair.
+ */
+export type KeypairEncoding = u16;
+
+export namespace KeypairEncoding {
+    /**
+     * Raw bytes.
+     */
+    export const RAW: KeypairEncoding = 0;
+    /**
+     * PCSK8/DER encoding.
+     */
+    export const PKCS8: KeypairEncoding = 1;
+    /**
+     * PEM encoding.
+     */
+    export const PEM: KeypairEncoding = 2;
+    /**
+     * Implementation-defined encoding.
+     */
+    export const LOCAL: KeypairEncoding = 3;
+}
+
+/**
+ * Encoding to use for importing or exporting a public key.
+ */
+export type PublickeyEncoding = u16;
+
+export namespace PublickeyEncoding {
+    /**
+     * Raw bytes.
+     */
+    export const RAW: PublickeyEncoding = 0;
+    /**
+     * PKCS8/DER encoding.
+     */
+    export const PKCS8: PublickeyEncoding = 1;
+    /**
+     * PEM encoding.
+     */
+    export const PEM: PublickeyEncoding = 2;
+    /**
+     * SEC encoding.
+     */
+    export const SEC: PublickeyEncoding = 3;
+    /**
+     * Compressed SEC encoding.
+     */
+    export const COMPRESSED_SEC: PublickeyEncoding = 4;
+    /**
+     * Implementation-defined encoding.
+     */
+    export const LOCAL: PublickeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a secret key.
+ */
+export type SecretkeyEncoding = u16;
+
+export namespace SecretkeyEncoding {
+    /**
+     * Raw bytes.
+     */
+    export const RAW: SecretkeyEncoding = 0;
+    /**
+     * PKCS8/DER encoding.
+     */
+    export const PKCS8: SecretkeyEncoding = 1;
+    /**
+     * PEM encoding.
+     */
+    export const PEM: SecretkeyEncoding = 2;
+    /**
+     * SEC encoding.
+     */
+    export const SEC: SecretkeyEncoding = 3;
+    /**
+     * Compressed SEC encoding.
+     */
+    export const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    /**
+     * Implementation-defined encoding.
+     */
+    export const LOCAL: SecretkeyEncoding = 5;
+}
+
+/**
+ * Encoding to use for importing or exporting a signature.
+ */
+export type SignatureEncoding = u16;
+
+export namespace SignatureEncoding {
+    /**
+     * Raw bytes.
+     */
+    export const RAW: SignatureEncoding = 0;
+    /**
+     * DER encoding.
+     */
+    export const DER: SignatureEncoding = 1;
+}
+
+/**
+ * An algorithm category.
+ */
+export type AlgorithmType = u16;
+
+export namespace AlgorithmType {
+    export const SIGNATURES: AlgorithmType = 0;
+    export const SYMMETRIC: AlgorithmType = 1;
+    export const KEY_EXCHANGE: AlgorithmType = 2;
+}
+
+/**
+ * Version of a managed key.
+ *
+ * A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+ */
+export type Version = u64;
+
+export namespace Version {
+    export const UNSPECIFIED: Version = 0xff00000000000000;
+    export const LATEST: Version = 0xff00000000000001;
+    export const ALL: Version = 0xff00000000000002;
+}
+
+/**
+ * Size of a value.
+ */
+export type Size = usize;
+
+/**
+ * A UNIX timestamp, in seconds since 01/01/1970.
+ */
+export type Timestamp = u64;
+
+/**
+ * A 64-bit value
+ */
+export type U64 = u64;
+
+/**
+ * Handle for functions returning output whose size may be large or not known in advance.
+ *
+ * An `array_output` object contains a host-allocated byte array.
+ *
+ * A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+ * In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+ *
+ * An `array_output` handle is automatically closed after its full content has been consumed.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class ArrayOutput {
+    static fromRaw(handle: WasiHandle): ArrayOutput {
+        return changetype<ArrayOutput>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A set of options.
+ *
+ * This type is used to set non-default parameters.
+ *
+ * The exact set of allowed options depends on the algorithm being used.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Options {
+    static fromRaw(handle: WasiHandle): Options {
+        return changetype<Options>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A handle to the optional secrets management facilities offered by a host.
+ *
+ * This is used to generate, retrieve and invalidate managed keys.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class SecretsManager {
+    static fromRaw(handle: WasiHandle): SecretsManager {
+        return changetype<SecretsManager>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A key pair.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Keypair {
+    static fromRaw(handle: WasiHandle): Keypair {
+        return changetype<Keypair>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A state to absorb data to be signed.
+ *
+ * After a signature has been computed or verified, the state remains valid for further operations.
+ *
+ * A subsequent signature would sign all the data accumulated since the creation of the state object.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class SignatureState {
+    static fromRaw(handle: WasiHandle): SignatureState {
+        return changetype<SignatureState>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A signature.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Signature {
+    static fromRaw(handle: WasiHandle): Signature {
+        return changetype<Signature>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A public key, for key exchange and signature verification.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Publickey {
+    static fromRaw(handle: WasiHandle): Publickey {
+        return changetype<Publickey>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A secret key, for key exchange mechanisms.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class Secretkey {
+    static fromRaw(handle: WasiHandle): Secretkey {
+        return changetype<Secretkey>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A state to absorb signed data to be verified.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class SignatureVerificationState {
+    static fromRaw(handle: WasiHandle): SignatureVerificationState {
+        return changetype<SignatureVerificationState>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A state to perform symmetric operations.
+ *
+ * The state is not reset nor invalidated after an option has been performed.
+ * Incremental updates and sessions are thus supported.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class SymmetricState {
+    static fromRaw(handle: WasiHandle): SymmetricState {
+        return changetype<SymmetricState>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * A symmetric key.
+ *
+ * The key can be imported from raw bytes, or can be a reference to a managed key.
+ *
+ * If it was imported, the host will wipe it from memory as soon as the handle is closed.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class SymmetricKey {
+    static fromRaw(handle: WasiHandle): SymmetricKey {
+        return changetype<SymmetricKey>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * An authentication tag.
+ *
+ * This is an object returned by functions computing authentication tags.
+ *
+ * A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+ *
+ * This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+ *
+ * The host is reponsible for securely wiping them from memory on close.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class SymmetricTag {
+    static fromRaw(handle: WasiHandle): SymmetricTag {
+        return changetype<SymmetricTag>(handle as usize);
+    }
+
+    toRaw(): WasiHandle {
+        return changetype<usize>(this) as WasiHandle;
+    }
+}
+
+/**
+ * Options index, only required by the Interface Types translation layer.
+ */
+export type OptOptionsU = u8;
+
+export namespace OptOptionsU {
+    export const SOME: OptOptionsU = 0;
+    export const NONE: OptOptionsU = 1;
+}
+
+/**
+ * An optional options set.
+ *
+ * This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class OptOptions {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 4);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): OptOptions {
+        let tu = new OptOptions(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 4);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- some: Options if tag=0
+
+    static some(val: Options): OptOptions {
+        return OptOptions.new(0, val);
+    }
+
+    setSome(val: Options): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isSome(): bool {
+        return this.tag === 0;
+    }
+
+    getSome(): Options {
+        return this.get<Options>();
+    }
+
+    // --- none: (no associated content) if tag=1
+
+    static none(): OptOptions {
+        return OptOptions.new(1);
+    }
+
+    setNone(): void {
+        this.tag = 1;
+    }
+
+    isNone(): bool {
+        return this.tag === 1;
+    }
+}
+
+
+/**
+ * Symmetric key index, only required by the Interface Types translation layer.
+ */
+export type OptSymmetricKeyU = u8;
+
+export namespace OptSymmetricKeyU {
+    export const SOME: OptSymmetricKeyU = 0;
+    export const NONE: OptSymmetricKeyU = 1;
+}
+
+/**
+ * An optional symmetric key.
+ *
+ * This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+ */
+// @ts-ignore: decorator
+@unmanaged
+export class OptSymmetricKey {
+    tag: u8;
+    private __pad8_0: u8;
+    private __pad16_0: u16;
+
+    constructor(tag: u8) {
+        this.tag = tag;
+        memory.fill(changetype<usize>(this) + 4, 0, 4);
+    }
+
+    // @ts-ignore: default
+    static new<T>(tag: u8, val: T = 0): OptSymmetricKey {
+        let tu = new OptSymmetricKey(tag);
+        tu.set(val);
+        return tu;
+    }
+
+    get<T>(): T {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        if (isReference<T>()) {
+            return changetype<T>(valBuf);
+        } else {
+            return load<T>(valBuf);
+        }
+    }
+
+    // @ts-ignore: default
+    set<T>(val: T = 0): void {
+        // @ts-ignore: cast
+        let valBuf = changetype<usize>(this) + 4;
+        memory.fill(valBuf, 0, 4);
+        if (isReference<T>()) {
+            (val !== null) && memory.copy(valBuf, changetype<usize>(val), offsetof<T>());
+        } else {
+            store<T>(valBuf, val)
+        }
+    }
+
+    // --- some: SymmetricKey if tag=0
+
+    static some(val: SymmetricKey): OptSymmetricKey {
+        return OptSymmetricKey.new(0, val);
+    }
+
+    setSome(val: SymmetricKey): void {
+        this.tag = 0;
+        this.set(val);
+    }
+
+    isSome(): bool {
+        return this.tag === 0;
+    }
+
+    getSome(): SymmetricKey {
+        return this.get<SymmetricKey>();
+    }
+
+    // --- none: (no associated content) if tag=1
+
+    static none(): OptSymmetricKey {
+        return OptSymmetricKey.new(1);
+    }
+
+    setNone(): void {
+        this.tag = 1;
+    }
+
+    isNone(): bool {
+        return this.tag === 1;
+    }
+}
+
+
+/**
+ * Create a new object to set non-default options.
+ *
+ * Example usage:
+ *
+ * ```rust
+ * let options_handle = options_open(AlgorithmType::Symmetric)?;
+ * options_set(options_handle, "context", context)?;
+ * options_set_u64(options_handle, "threads", 4)?;
+ * let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+ * options_close(options_handle)?;
+ * ```
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_open")
+export declare function optionsOpen(
+    algorithm_type: AlgorithmType,
+    result_ptr: WasiMutPtr<Options>
+): CryptoErrno;
+
+/**
+ * Destroy an options object.
+ *
+ * Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_close")
+export declare function optionsClose(
+    handle: Options
+): CryptoErrno;
+
+/**
+ * Set or update an option.
+ *
+ * This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+ *
+ * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_set")
+export declare function optionsSet(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: WasiPtr<u8>,
+    value_len: Size
+): CryptoErrno;
+
+/**
+ * Set or update an integer option.
+ *
+ * This is used to set algorithm-specific parameters.
+ *
+ * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_set_u64")
+export declare function optionsSetU64(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: u64
+): CryptoErrno;
+
+/**
+ * Set or update a guest-allocated memory that the host can use or return data into.
+ *
+ * This is for example used to set the scratch buffer required by memory-hard functions.
+ *
+ * This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "options_set_guest_buffer")
+export declare function optionsSetGuestBuffer(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    buffer: WasiMutPtr<u8>,
+    buffer_len: Size
+): CryptoErrno;
+
+/**
+ * Return the length of an `array_output` object.
+ *
+ * This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "array_output_len")
+export declare function arrayOutputLen(
+    array_output: ArrayOutput,
+    result_ptr: WasiMutPtr<Size>
+): CryptoErrno;
+
+/**
+ * Copy the content of an `array_output` object into an application-allocated buffer.
+ *
+ * Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+ *
+ * The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+ *
+ * The handle is automatically closed after all the data has been consumed.
+ *
+ * Example usage:
+ *
+ * ```rust
+ * let len = array_output_len(output_handle)?;
+ * let mut out = vec![0u8; len];
+ * array_output_pull(output_handle, &mut out)?;
+ * ```
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "array_output_pull")
+export declare function arrayOutputPull(
+    array_output: ArrayOutput,
+    buf: WasiMutPtr<u8>,
+    buf_len: Size,
+    result_ptr: WasiMutPtr<Size>
+): CryptoErrno;
+
+/**
+ * __(optional)__
+ * Create a context to use a secrets manager.
+ *
+ * The set of required and supported options is defined by the host.
+ *
+ * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+ * This is also an optional import, meaning that the function may not even exist.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "secrets_manager_open")
+export declare function secretsManagerOpen(
+    options: OptOptions,
+    result_ptr: WasiMutPtr<SecretsManager>
+): CryptoErrno;
+
+/**
+ * __(optional)__
+ * Destroy a secrets manager context.
+ *
+ * The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+ * This is also an optional import, meaning that the function may not even exist.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "secrets_manager_close")
+export declare function secretsManagerClose(
+    secrets_manager: SecretsManager
+): CryptoErrno;
+
+/**
+ * __(optional)__
+ * Invalidate a managed key or key pair given an identifier and a version.
+ *
+ * This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+ *
+ * `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+ *
+ * The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+ *
+ * This is an optional import, meaning that the function may not even exist.
+ */
+// @ts-ignore: decorator
+@external("wasi_ephemeral_crypto_common", "secrets_manager_invalidate")
+export declare function secretsManagerInvalidate(
+    secrets_manager: SecretsManager,
+    key_id: WasiPtr<u8>,
+    key_id_len: Size,
+    key_version: Version
+): CryptoErrno;
+
@@ -0,0 +1,866 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+namespace wasi_ephemeral_crypto_common {
+
+    enum class Handle : WasiHandle {};
+
+    /// Error codes.
+    enum class CryptoErrno : uint16_t {
+        /// Operation succeeded.
+        SUCCESS = 0,
+        /// An error occurred when trying to during a conversion from a host type to a guest type.
+        ///
+        /// Only an internal bug can throw this error.
+        GUEST_ERROR = 1,
+        /// The requested operation is valid, but not implemented by the host.
+        NOT_IMPLEMENTED = 2,
+        /// The requested feature is not supported by the chosen algorithm.
+        UNSUPPORTED_FEATURE = 3,
+        /// The requested operation is valid, but was administratively prohibited.
+        PROHIBITED_OPERATION = 4,
+        /// Unsupported encoding for an import or export operation.
+        UNSUPPORTED_ENCODING = 5,
+        /// The requested algorithm is not supported by the host.
+        UNSUPPORTED_ALGORITHM = 6,
+        /// The requested option is not supported by the currently selected algorithm.
+        UNSUPPORTED_OPTION = 7,
+        /// An invalid or incompatible key was supplied.
+        ///
+        /// The key may not be valid, or was generated for a different algorithm or parameters set.
+        INVALID_KEY = 8,
+        /// The currently selected algorithm doesn't support the requested output length.
+        ///
+        /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+        INVALID_LENGTH = 9,
+        /// A signature or authentication tag verification failed.
+        VERIFICATION_FAILED = 10,
+        /// A secure random numbers generator is not available.
+        ///
+        /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+        RNG_ERROR = 11,
+        /// An error was returned by the underlying cryptography library.
+        ///
+        /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+        ///
+        /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+        ///
+        /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+        /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+        ALGORITHM_FAILURE = 12,
+        /// The supplied signature is invalid, or incompatible with the chosen algorithm.
+        INVALID_SIGNATURE = 13,
+        /// An attempt was made to close a handle that was already closed.
+        CLOSED = 14,
+        /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+        INVALID_HANDLE = 15,
+        /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+        OVERFLOW = 16,
+        /// An internal error occurred.
+        ///
+        /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+        INTERNAL_ERROR = 17,
+        /// Too many handles are currently open, and a new one cannot be created.
+        ///
+        /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+        TOO_MANY_HANDLES = 18,
+        /// A key was provided, but the chosen algorithm doesn't support keys.
+        ///
+        /// This is returned by symmetric operations.
+        ///
+        /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+        /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+        ///
+        /// These functions must refuse to create the context and return this error instead.
+        KEY_NOT_SUPPORTED = 19,
+        /// A key is required for the chosen algorithm, but none was given.
+        KEY_REQUIRED = 20,
+        /// The provided authentication tag is invalid or incompatible with the current algorithm.
+        ///
+        /// This error is returned by decryption functions and tag verification functions.
+        ///
+        /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+        INVALID_TAG = 21,
+        /// The requested operation is incompatible with the current scheme.
+        ///
+        /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+        /// This error code will be returned instead.
+        INVALID_OPERATION = 22,
+        /// A nonce is required.
+        ///
+        /// Most encryption schemes require a nonce.
+        ///
+        /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+        /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+        NONCE_REQUIRED = 23,
+        /// The provided nonce doesn't have a correct size for the given cipher.
+        INVALID_NONCE = 24,
+        /// The named option was not set.
+        ///
+        /// The caller tried to read the value of an option that was not set.
+        /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+        OPTION_NOT_SET = 25,
+        /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+        ///
+        /// This error is returned by a secrets manager via the `keypair_from_id()` function.
+        NOT_FOUND = 26,
+        /// The algorithm requires parameters that haven't been set.
+        ///
+        /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+        PARAMETERS_MISSING = 27,
+        /// A requested computation is not done yet, and additional calls to the function are required.
+        ///
+        /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+        ///
+        /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+        IN_PROGRESS = 28,
+        /// Multiple keys have been provided, but they do not share the same type.
+        ///
+        /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+        INCOMPATIBLE_KEYS = 29,
+        /// A managed key or secret expired and cannot be used any more.
+        EXPIRED = 30,
+    };
+
+    /// Encoding to use for importing or exporting a key pair.
+    enum class KeypairEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// PCSK8/DER encoding.
+        PKCS8 = 1,
+        /// PEM encoding.
+        PEM = 2,
+        /// Implementation-defined encoding.
+        LOCAL = 3,
+    };
+
+    /// Encoding to use for importing or exporting a public key.
+    enum class PublickeyEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// PKCS8/DER encoding.
+        PKCS8 = 1,
+        /// PEM encoding.
+        PEM = 2,
+        /// SEC encoding.
+        SEC = 3,
+        /// Compressed SEC encoding.
+        COMPRESSED_SEC = 4,
+        /// Implementation-defined encoding.
+        LOCAL = 5,
+    };
+
+    /// Encoding to use for importing or exporting a secret key.
+    enum class SecretkeyEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// PKCS8/DER encoding.
+        PKCS8 = 1,
+        /// PEM encoding.
+        PEM = 2,
+        /// SEC encoding.
+        SEC = 3,
+        /// Compressed SEC encoding.
+        COMPRESSED_SEC = 4,
+        /// Implementation-defined encoding.
+        LOCAL = 5,
+    };
+
+    /// Encoding to use for importing or exporting a signature.
+    enum class SignatureEncoding : uint16_t {
+        /// Raw bytes.
+        RAW = 0,
+        /// DER encoding.
+        DER = 1,
+    };
+
+    /// An algorithm category.
+    enum class AlgorithmType : uint16_t {
+        SIGNATURES = 0,
+        SYMMETRIC = 1,
+        KEY_EXCHANGE = 2,
+    };
+
+    /// Version of a managed key.
+    ///
+    /// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+    using Version = uint64_t;
+
+    namespace version {
+        constexpr Version UNSPECIFIED = 0xff00000000000000;
+        constexpr Version LATEST = 0xff00000000000001;
+        constexpr Version ALL = 0xff00000000000002;
+    } // namespace version
+
+    /// Size of a value.
+    using Size = size_t;
+
+    /// A UNIX timestamp, in seconds since 01/01/1970.
+    using Timestamp = uint64_t;
+
+    /// A 64-bit value
+    using U64 = uint64_t;
+
+    /// Handle for functions returning output whose size may be large or not known in advance.
+    ///
+    /// An `array_output` object contains a host-allocated byte array.
+    ///
+    /// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+    /// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+    ///
+    /// An `array_output` handle is automatically closed after its full content has been consumed.
+    enum class ArrayOutput : WasiHandle {};
+
+    /// A set of options.
+    ///
+    /// This type is used to set non-default parameters.
+    ///
+    /// The exact set of allowed options depends on the algorithm being used.
+    enum class Options : WasiHandle {};
+
+    /// A handle to the optional secrets management facilities offered by a host.
+    ///
+    /// This is used to generate, retrieve and invalidate managed keys.
+    enum class SecretsManager : WasiHandle {};
+
+    /// A key pair.
+    enum class Keypair : WasiHandle {};
+
+    /// A state to absorb data to be signed.
+    ///
+    /// After a signature has been computed or verified, the state remains valid for further operations.
+    ///
+    /// A subsequent signature would sign all the data accumulated since the creation of the state object.
+    enum class SignatureState : WasiHandle {};
+
+    /// A signature.
+    enum class Signature : WasiHandle {};
+
+    /// A public key, for key exchange and signature verification.
+    enum class Publickey : WasiHandle {};
+
+    /// A secret key, for key exchange mechanisms.
+    enum class Secretkey : WasiHandle {};
+
+    /// A state to absorb signed data to be verified.
+    enum class SignatureVerificationState : WasiHandle {};
+
+    /// A state to perform symmetric operations.
+    ///
+    /// The state is not reset nor invalidated after an option has been performed.
+    /// Incremental updates and sessions are thus supported.
+    enum class SymmetricState : WasiHandle {};
+
+    /// A symmetric key.
+    ///
+    /// The key can be imported from raw bytes, or can be a reference to a managed key.
+    ///
+    /// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+    enum class SymmetricKey : WasiHandle {};
+
+    /// An authentication tag.
+    ///
+    /// This is an object returned by functions computing authentication tags.
+    ///
+    /// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+    ///
+    /// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+    ///
+    /// The host is reponsible for securely wiping them from memory on close.
+    enum class SymmetricTag : WasiHandle {};
+
+    /// Options index, only required by the Interface Types translation layer.
+    enum class OptOptionsU : uint8_t {
+        SOME = 0,
+        NONE = 1,
+    };
+
+    /// An optional options set.
+    ///
+    /// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+    struct OptOptions {
+        enum class Tag : uint8_t {
+            SOME = 0,
+            NONE = 1,
+        };
+
+        Tag tag;
+        union {
+            Options some;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- some: Options if tag=0
+
+        static OptOptions make_some(Options val) {
+            OptOptions tu{};
+            tu.tag = Tag::SOME;
+            tu.member.some = val;
+            return tu;
+        }
+
+        void emplace_some(Options val) {
+            tag = Tag::SOME;
+            member.some = val;
+        }
+
+        const Options *get_if_some() const {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        Options *get_if_some() {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        bool holds_some() const {
+            return tag == Tag::SOME;
+        }
+
+        // --- none: (no associated content) if tag=1
+
+        static OptOptions make_none() {
+            OptOptions tu{};
+            tu.tag = Tag::NONE;
+            return tu;
+        }
+
+        void emplace_none() {
+            tag = Tag::NONE;
+        }
+
+        bool holds_none() const {
+            return tag == Tag::NONE;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(OptOptions, member) == 4, "unexpected layout");
+    #endif
+
+    /// Symmetric key index, only required by the Interface Types translation layer.
+    enum class OptSymmetricKeyU : uint8_t {
+        SOME = 0,
+        NONE = 1,
+    };
+
+    /// An optional symmetric key.
+    ///
+    /// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+    struct OptSymmetricKey {
+        enum class Tag : uint8_t {
+            SOME = 0,
+            NONE = 1,
+        };
+
+        Tag tag;
+        union {
+            SymmetricKey some;
+        } member;
+
+        Tag index() const {
+            return tag;
+        }
+
+        // --- some: SymmetricKey if tag=0
+
+        static OptSymmetricKey make_some(SymmetricKey val) {
+            OptSymmetricKey tu{};
+            tu.tag = Tag::SOME;
+            tu.member.some = val;
+            return tu;
+        }
+
+        void emplace_some(SymmetricKey val) {
+            tag = Tag::SOME;
+            member.some = val;
+        }
+
+        const SymmetricKey *get_if_some() const {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        SymmetricKey *get_if_some() {
+            return holds_some() ? &member.some : nullptr;
+        }
+
+        bool holds_some() const {
+            return tag == Tag::SOME;
+        }
+
+        // --- none: (no associated content) if tag=1
+
+        static OptSymmetricKey make_none() {
+            OptSymmetricKey tu{};
+            tu.tag = Tag::NONE;
+            return tu;
+        }
+
+        void emplace_none() {
+            tag = Tag::NONE;
+        }
+
+        bool holds_none() const {
+            return tag == Tag::NONE;
+        }
+    };
+    #if defined(__wasm32__)
+    static_assert(offsetof(OptSymmetricKey, member) == 4, "unexpected layout");
+    #endif
+
+    namespace raw {
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "options_open")
+        CryptoErrno options_open(
+            AlgorithmType algorithm_type,
+            WasiMutPtr<Options> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "options_close")
+        CryptoErrno options_close(
+            Options handle
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "options_set")
+        CryptoErrno options_set(
+            Options handle,
+            WasiPtr<Char8> name_ptr,
+            size_t name_len,
+            WasiPtr<uint8_t> value,
+            Size value_len
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "options_set_u64")
+        CryptoErrno options_set_u64(
+            Options handle,
+            WasiPtr<Char8> name_ptr,
+            size_t name_len,
+            uint64_t value
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "options_set_guest_buffer")
+        CryptoErrno options_set_guest_buffer(
+            Options handle,
+            WasiPtr<Char8> name_ptr,
+            size_t name_len,
+            WasiMutPtr<uint8_t> buffer,
+            Size buffer_len
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "array_output_len")
+        CryptoErrno array_output_len(
+            ArrayOutput array_output,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "array_output_pull")
+        CryptoErrno array_output_pull(
+            ArrayOutput array_output,
+            WasiMutPtr<uint8_t> buf,
+            Size buf_len,
+            WasiMutPtr<Size> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "secrets_manager_open")
+        CryptoErrno secrets_manager_open(
+            OptOptions options,
+            WasiMutPtr<SecretsManager> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "secrets_manager_close")
+        CryptoErrno secrets_manager_close(
+            SecretsManager secrets_manager
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_ephemeral_crypto_common", "secrets_manager_invalidate")
+        CryptoErrno secrets_manager_invalidate(
+            SecretsManager secrets_manager,
+            WasiPtr<uint8_t> key_id,
+            Size key_id_len,
+            Version key_version
+        );
+
+    } // namespace raw
+
+    /// Create a new object to set non-default options.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let options_handle = options_open(AlgorithmType::Symmetric)?;
+    /// options_set(options_handle, "context", context)?;
+    /// options_set_u64(options_handle, "threads", 4)?;
+    /// let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+    /// options_close(options_handle)?;
+    /// ```
+    inline WasiExpected<Options, CryptoErrno> options_open(AlgorithmType algorithm_type) {
+        Options result_ptr{};
+        const CryptoErrno res = raw::options_open(algorithm_type, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Options, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Options, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Destroy an options object.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    inline WasiExpected<void, CryptoErrno> options_close(Options handle) {
+        const CryptoErrno res = raw::options_close(handle);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Set or update an option.
+    ///
+    /// This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    inline WasiExpected<void, CryptoErrno> options_set(Options handle, WasiString name, WasiPtr<uint8_t> value, Size value_len) {
+        const CryptoErrno res = raw::options_set(handle, name.ptr, name.len, value, value_len);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Set or update an integer option.
+    ///
+    /// This is used to set algorithm-specific parameters.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    inline WasiExpected<void, CryptoErrno> options_set_u64(Options handle, WasiString name, uint64_t value) {
+        const CryptoErrno res = raw::options_set_u64(handle, name.ptr, name.len, value);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Set or update a guest-allocated memory that the host can use or return data into.
+    ///
+    /// This is for example used to set the scratch buffer required by memory-hard functions.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    inline WasiExpected<void, CryptoErrno> options_set_guest_buffer(Options handle, WasiString name, WasiMutPtr<uint8_t> buffer, Size buffer_len) {
+        const CryptoErrno res = raw::options_set_guest_buffer(handle, name.ptr, name.len, buffer, buffer_len);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Return the length of an `array_output` object.
+    ///
+    /// This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+    inline WasiExpected<Size, CryptoErrno> array_output_len(ArrayOutput array_output) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::array_output_len(array_output, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// Copy the content of an `array_output` object into an application-allocated buffer.
+    ///
+    /// Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+    ///
+    /// The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+    ///
+    /// The handle is automatically closed after all the data has been consumed.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let len = array_output_len(output_handle)?;
+    /// let mut out = vec![0u8; len];
+    /// array_output_pull(output_handle, &mut out)?;
+    /// ```
+    inline WasiExpected<Size, CryptoErrno> array_output_pull(ArrayOutput array_output, WasiMutPtr<uint8_t> buf, Size buf_len) {
+        Size result_ptr{};
+        const CryptoErrno res = raw::array_output_pull(array_output, buf, buf_len, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<Size, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<Size, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// __(optional)__
+    /// Create a context to use a secrets manager.
+    ///
+    /// The set of required and supported options is defined by the host.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+    /// This is also an optional import, meaning that the function may not even exist.
+    inline WasiExpected<SecretsManager, CryptoErrno> secrets_manager_open(OptOptions options) {
+        SecretsManager result_ptr{};
+        const CryptoErrno res = raw::secrets_manager_open(options, &result_ptr);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<SecretsManager, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<SecretsManager, CryptoErrno>::from_value(result_ptr);
+    }
+
+    /// __(optional)__
+    /// Destroy a secrets manager context.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+    /// This is also an optional import, meaning that the function may not even exist.
+    inline WasiExpected<void, CryptoErrno> secrets_manager_close(SecretsManager secrets_manager) {
+        const CryptoErrno res = raw::secrets_manager_close(secrets_manager);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// __(optional)__
+    /// Invalidate a managed key or key pair given an identifier and a version.
+    ///
+    /// This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+    ///
+    /// `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+    ///
+    /// The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    inline WasiExpected<void, CryptoErrno> secrets_manager_invalidate(SecretsManager secrets_manager, WasiPtr<uint8_t> key_id, Size key_id_len, Version key_version) {
+        const CryptoErrno res = raw::secrets_manager_invalidate(secrets_manager, key_id, key_id_len, key_version);
+        if (res != CryptoErrno{}) {
+            return WasiExpected<void, CryptoErrno>::from_error(res);
+        }
+        return WasiExpected<void, CryptoErrno>::from_value();
+    }
+
+    /// Owned `Options` handle, closed with `options_close()` when dropped.
+    class UniqueOptions {
+      public:
+        explicit UniqueOptions(Options handle) noexcept : handle_(handle), valid_(true) {}
+        UniqueOptions(const UniqueOptions &) = delete;
+        UniqueOptions &operator=(const UniqueOptions &) = delete;
+
+        UniqueOptions(UniqueOptions &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {
+            other.valid_ = false;
+        }
+
+        UniqueOptions &operator=(UniqueOptions &&other) noexcept {
+            if (this != &other) {
+                reset();
+                handle_ = other.handle_;
+                valid_ = other.valid_;
+                other.valid_ = false;
+            }
+            return *this;
+        }
+
+        ~UniqueOptions() {
+            reset();
+        }
+
+        Options get() const noexcept {
+            return handle_;
+        }
+
+        Options release() noexcept {
+            valid_ = false;
+            return handle_;
+        }
+
+        void reset() noexcept {
+            if (valid_) {
+                (void)raw::options_close(handle_);
+                valid_ = false;
+            }
+        }
+
+      private:
+        Options handle_;
+        bool valid_;
+    };
+
+    /// Owned `SecretsManager` handle, closed with `secrets_manager_close()` when dropped.
+    class UniqueSecretsManager {
+      public:
+        explicit UniqueSecretsManager(SecretsManager handle) noexcept : handle_(handle), valid_(true) {}
+        UniqueSecretsManager(const UniqueSecretsManager &) = delete;
+        UniqueSecretsManager &operator=(const UniqueSecretsManager &) = delete;
+
+        UniqueSecretsManager(UniqueSecretsManager &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {
+            other.valid_ = false;
+        }
+
+        UniqueSecretsManager &operator=(UniqueSecretsManager &&other) noexcept {
+            if (this != &other) {
+                reset();
+                handle_ = other.handle_;
+                valid_ = other.valid_;
+                other.valid_ = false;
+            }
+            return *this;
+        }
+
+        ~UniqueSecretsManager() {
+            reset();
+        }
+
+        SecretsManager get() const noexcept {
+            return handle_;
+        }
+
+        SecretsManager release() noexcept {
+            valid_ = false;
+            return handle_;
+        }
+
+        void reset() noexcept {
+            if (valid_) {
+                (void)raw::secrets_manager_close(handle_);
+                valid_ = false;
+            }
+        }
+
+      private:
+        SecretsManager handle_;
+        bool valid_;
+    };
+
+} // namespace wasi_ephemeral_crypto_common
+
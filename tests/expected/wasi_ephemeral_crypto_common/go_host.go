@@ -0,0 +1,835 @@
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+package wasiephemeralcryptocommon
+
+import (
+	"context"
+	"errors"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New("invalid guest memory access")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+type Handle WasiHandle
+
+// Error codes.
+type CryptoErrno uint16
+
+const (
+	// Operation succeeded.
+	CryptoErrnoSuccess CryptoErrno = 0
+	// An error occurred when trying to during a conversion from a host type to a guest type.
+	//
+	// Only an internal bug can throw this error.
+	CryptoErrnoGuestError CryptoErrno = 1
+	// The requested operation is valid, but not implemented by the host.
+	CryptoErrnoNotImplemented CryptoErrno = 2
+	// The requested feature is not supported by the chosen algorithm.
+	CryptoErrnoUnsupportedFeature CryptoErrno = 3
+	// The requested operation is valid, but was administratively prohibited.
+	CryptoErrnoProhibitedOperation CryptoErrno = 4
+	// Unsupported encoding for an import or export operation.
+	CryptoErrnoUnsupportedEncoding CryptoErrno = 5
+	// The requested algorithm is not supported by the host.
+	CryptoErrnoUnsupportedAlgorithm CryptoErrno = 6
+	// The requested option is not supported by the currently selected algorithm.
+	CryptoErrnoUnsupportedOption CryptoErrno = 7
+	// An invalid or incompatible key was supplied.
+	//
+	// The key may not be valid, or was generated for a different algorithm or parameters set.
+	CryptoErrnoInvalidKey CryptoErrno = 8
+	// The currently selected algorithm doesn't support the requested output length.
+	//
+	// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+	CryptoErrnoInvalidLength CryptoErrno = 9
+	// A signature or authentication tag verification failed.
+	CryptoErrnoVerificationFailed CryptoErrno = 10
+	// A secure random numbers generator is not available.
+	//
+	// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+	CryptoErrnoRngError CryptoErrno = 11
+	// An error was returned by the underlying cryptography library.
+	//
+	// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+	//
+	// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+	//
+	// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+	// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+	CryptoErrnoAlgorithmFailure CryptoErrno = 12
+	// The supplied signature is invalid, or incompatible with the chosen algorithm.
+	CryptoErrnoInvalidSignature CryptoErrno = 13
+	// An attempt was made to close a handle that was already closed.
+	CryptoErrnoClosed CryptoErrno = 14
+	// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+	CryptoErrnoInvalidHandle CryptoErrno = 15
+	// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+	CryptoErrnoOverflow CryptoErrno = 16
+	// An internal error occurred.
+	//
+	// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+	CryptoErrnoInternalError CryptoErrno = 17
+	// Too many handles are currently open, and a new one cannot be created.
+	//
+	// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+	CryptoErrnoTooManyHandles CryptoErrno = 18
+	// A key was provided, but the chosen algorithm doesn't support keys.
+	//
+	// This is returned by symmetric operations.
+	//
+	// Many hash functions, in particular, do not support keys without being used in particular constructions.
+	// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+	//
+	// These functions must refuse to create the context and return this error instead.
+	CryptoErrnoKeyNotSupported CryptoErrno = 19
+	// A key is required for the chosen algorithm, but none was given.
+	CryptoErrnoKeyRequired CryptoErrno = 20
+	// The provided authentication tag is invalid or incompatible with the current algorithm.
+	//
+	// This error is returned by decryption functions and tag verification functions.
+	//
+	// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+	CryptoErrnoInvalidTag CryptoErrno = 21
+	// The requested operation is incompatible with the current scheme.
+	//
+	// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+	// This error code will be returned instead.
+	CryptoErrnoInvalidOperation CryptoErrno = 22
+	// A nonce is required.
+	//
+	// Most encryption schemes require a nonce.
+	//
+	// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+	// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+	CryptoErrnoNonceRequired CryptoErrno = 23
+	// The provided nonce doesn't have a correct size for the given cipher.
+	CryptoErrnoInvalidNonce CryptoErrno = 24
+	// The named option was not set.
+	//
+	// The caller tried to read the value of an option that was not set.
+	// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+	CryptoErrnoOptionNotSet CryptoErrno = 25
+	// A key or key pair matching the requested identifier cannot be found using the supplied information.
+	//
+	// This error is returned by a secrets manager via the `keypair_from_id()` function.
+	CryptoErrnoNotFound CryptoErrno = 26
+	// The algorithm requires parameters that haven't been set.
+	//
+	// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+	CryptoErrnoParametersMissing CryptoErrno = 27
+	// A requested computation is not done yet, and additional calls to the function are required.
+	//
+	// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+	//
+	// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+	CryptoErrnoInProgress CryptoErrno = 28
+	// Multiple keys have been provided, but they do not share the same type.
+	//
+	// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+	CryptoErrnoIncompatibleKeys CryptoErrno = 29
+	// A managed key or secret expired and cannot be used any more.
+	CryptoErrnoExpired CryptoErrno = 30
+)
+
+// Encoding to use for importing or exporting a key pair.
+type KeypairEncoding uint16
+
+const (
+	// Raw bytes.
+	KeypairEncodingRaw KeypairEncoding = 0
+	// PCSK8/DER encoding.
+	KeypairEncodingPkcs8 KeypairEncoding = 1
+	// PEM encoding.
+	KeypairEncodingPem KeypairEncoding = 2
+	// Implementation-defined encoding.
+	KeypairEncodingLocal KeypairEncoding = 3
+)
+
+// Encoding to use for importing or exporting a public key.
+type PublickeyEncoding uint16
+
+const (
+	// Raw bytes.
+	PublickeyEncodingRaw PublickeyEncoding = 0
+	// PKCS8/DER encoding.
+	PublickeyEncodingPkcs8 PublickeyEncoding = 1
+	// PEM encoding.
+	PublickeyEncodingPem PublickeyEncoding = 2
+	// SEC encoding.
+	PublickeyEncodingSec PublickeyEncoding = 3
+	// Compressed SEC encoding.
+	PublickeyEncodingCompressedSec PublickeyEncoding = 4
+	// Implementation-defined encoding.
+	PublickeyEncodingLocal PublickeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a secret key.
+type SecretkeyEncoding uint16
+
+const (
+	// Raw bytes.
+	SecretkeyEncodingRaw SecretkeyEncoding = 0
+	// PKCS8/DER encoding.
+	SecretkeyEncodingPkcs8 SecretkeyEncoding = 1
+	// PEM encoding.
+	SecretkeyEncodingPem SecretkeyEncoding = 2
+	// SEC encoding.
+	SecretkeyEncodingSec SecretkeyEncoding = 3
+	// Compressed SEC encoding.
+	SecretkeyEncodingCompressedSec SecretkeyEncoding = 4
+	// Implementation-defined encoding.
+	SecretkeyEncodingLocal SecretkeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a signature.
+type SignatureEncoding uint16
+
+const (
+	// Raw bytes.
+	SignatureEncodingRaw SignatureEncoding = 0
+	// DER encoding.
+	SignatureEncodingDer SignatureEncoding = 1
+)
+
+// An algorithm category.
+type AlgorithmType uint16
+
+const (
+	AlgorithmTypeSignatures  AlgorithmType = 0
+	AlgorithmTypeSymmetric   AlgorithmType = 1
+	AlgorithmTypeKeyExchange AlgorithmType = 2
+)
+
+// Version of a managed key.
+//
+// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+type Version uint64
+
+const (
+	VersionUnspecified Version = 0xff00000000000000
+	VersionLatest      Version = 0xff00000000000001
+	VersionAll         Version = 0xff00000000000002
+)
+
+// Size of a value.
+type Size WasiUSize
+
+// A UNIX timestamp, in seconds since 01/01/1970.
+type Timestamp uint64
+
+// A 64-bit value
+type U64 uint64
+
+// Handle for functions returning output whose size may be large or not known in advance.
+//
+// An `array_output` object contains a host-allocated byte array.
+//
+// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+//
+// An `array_output` handle is automatically closed after its full content has been consumed.
+type ArrayOutput WasiHandle
+
+// A set of options.
+//
+// This type is used to set non-default parameters.
+//
+// The exact set of allowed options depends on the algorithm being used.
+type Options WasiHandle
+
+// A handle to the optional secrets management facilities offered by a host.
+//
+// This is used to generate, retrieve and invalidate managed keys.
+type SecretsManager WasiHandle
+
+// A key pair.
+type Keypair WasiHandle
+
+// A state to absorb data to be signed.
+//
+// After a signature has been computed or verified, the state remains valid for further operations.
+//
+// A subsequent signature would sign all the data accumulated since the creation of the state object.
+type SignatureState WasiHandle
+
+// A signature.
+type Signature WasiHandle
+
+// A public key, for key exchange and signature verification.
+type Publickey WasiHandle
+
+// A secret key, for key exchange mechanisms.
+type Secretkey WasiHandle
+
+// A state to absorb signed data to be verified.
+type SignatureVerificationState WasiHandle
+
+// A state to perform symmetric operations.
+//
+// The state is not reset nor invalidated after an option has been performed.
+// Incremental updates and sessions are thus supported.
+type SymmetricState WasiHandle
+
+// A symmetric key.
+//
+// The key can be imported from raw bytes, or can be a reference to a managed key.
+//
+// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+type SymmetricKey WasiHandle
+
+// An authentication tag.
+//
+// This is an object returned by functions computing authentication tags.
+//
+// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+//
+// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+//
+// The host is reponsible for securely wiping them from memory on close.
+type SymmetricTag WasiHandle
+
+// Options index, only required by the Interface Types translation layer.
+type OptOptionsU uint8
+
+const (
+	OptOptionsUSome OptOptionsU = 0
+	OptOptionsUNone OptOptionsU = 1
+)
+
+// An optional options set.
+//
+// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+type OptOptions struct {
+	Tag  uint8
+	Some Options
+}
+
+const (
+	OptOptionsSome uint8 = 0
+	OptOptionsNone uint8 = 1
+)
+
+func (r *memReader) OptOptions(offset uint32) OptOptions {
+	v := OptOptions{Tag: r.u8(offset)}
+	switch v.Tag {
+	case OptOptionsSome:
+		v.Some = Options(r.u32(offset + 4))
+	case OptOptionsNone:
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) OptOptions(offset uint32, v OptOptions) {
+	w.u8(offset, v.Tag)
+	switch v.Tag {
+	case OptOptionsSome:
+		w.u32(offset+4, uint32(v.Some))
+	case OptOptionsNone:
+	default:
+		w.ok = false
+	}
+}
+
+// ReadOptOptions reads a OptOptions from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadOptOptions(mem api.Memory, offset uint32) (OptOptions, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.OptOptions(offset)
+	return v, r.ok
+}
+
+// WriteOptOptions writes a OptOptions to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteOptOptions(mem api.Memory, offset uint32, v OptOptions) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.OptOptions(offset, v)
+	return w.ok
+}
+
+// Symmetric key index, only required by the Interface Types translation layer.
+type OptSymmetricKeyU uint8
+
+const (
+	OptSymmetricKeyUSome OptSymmetricKeyU = 0
+	OptSymmetricKeyUNone OptSymmetricKeyU = 1
+)
+
+// An optional symmetric key.
+//
+// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+type OptSymmetricKey struct {
+	Tag  uint8
+	Some SymmetricKey
+}
+
+const (
+	OptSymmetricKeySome uint8 = 0
+	OptSymmetricKeyNone uint8 = 1
+)
+
+func (r *memReader) OptSymmetricKey(offset uint32) OptSymmetricKey {
+	v := OptSymmetricKey{Tag: r.u8(offset)}
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		v.Some = SymmetricKey(r.u32(offset + 4))
+	case OptSymmetricKeyNone:
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) OptSymmetricKey(offset uint32, v OptSymmetricKey) {
+	w.u8(offset, v.Tag)
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		w.u32(offset+4, uint32(v.Some))
+	case OptSymmetricKeyNone:
+	default:
+		w.ok = false
+	}
+}
+
+// ReadOptSymmetricKey reads a OptSymmetricKey from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadOptSymmetricKey(mem api.Memory, offset uint32) (OptSymmetricKey, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.OptSymmetricKey(offset)
+	return v, r.ok
+}
+
+// WriteOptSymmetricKey writes a OptSymmetricKey to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteOptSymmetricKey(mem api.Memory, offset uint32, v OptSymmetricKey) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.OptSymmetricKey(offset, v)
+	return w.ok
+}
+
+// WasiEphemeralCryptoCommonHost is implemented by the host to provide the functions of the `wasi_ephemeral_crypto_common` module.
+// Register an implementation with DefineWasiEphemeralCryptoCommonImports.
+// Methods receive the module calling them, whose memory parameters are read from.
+// Functions returning an error code return it last, results only being written if it is zero.
+type WasiEphemeralCryptoCommonHost interface {
+	// Create a new object to set non-default options.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let options_handle = options_open(AlgorithmType::Symmetric)?;
+	// options_set(options_handle, "context", context)?;
+	// options_set_u64(options_handle, "threads", 4)?;
+	// let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+	// options_close(options_handle)?;
+	// ```
+	OptionsOpen(ctx context.Context, mod api.Module, algorithmType AlgorithmType) (Options, CryptoErrno)
+
+	// Destroy an options object.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	OptionsClose(ctx context.Context, mod api.Module, handle Options) CryptoErrno
+
+	// Set or update an option.
+	//
+	// This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+	//
+	// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+	OptionsSet(ctx context.Context, mod api.Module, handle Options, name string, value WasiPtr, valueLen Size) CryptoErrno
+
+	// Set or update an integer option.
+	//
+	// This is used to set algorithm-specific parameters.
+	//
+	// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+	OptionsSetU64(ctx context.Context, mod api.Module, handle Options, name string, value uint64) CryptoErrno
+
+	// Set or update a guest-allocated memory that the host can use or return data into.
+	//
+	// This is for example used to set the scratch buffer required by memory-hard functions.
+	//
+	// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+	OptionsSetGuestBuffer(ctx context.Context, mod api.Module, handle Options, name string, buffer WasiPtr, bufferLen Size) CryptoErrno
+
+	// Return the length of an `array_output` object.
+	//
+	// This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+	ArrayOutputLen(ctx context.Context, mod api.Module, arrayOutput ArrayOutput) (Size, CryptoErrno)
+
+	// Copy the content of an `array_output` object into an application-allocated buffer.
+	//
+	// Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+	//
+	// The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+	//
+	// The handle is automatically closed after all the data has been consumed.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let len = array_output_len(output_handle)?;
+	// let mut out = vec![0u8; len];
+	// array_output_pull(output_handle, &mut out)?;
+	// ```
+	ArrayOutputPull(ctx context.Context, mod api.Module, arrayOutput ArrayOutput, buf WasiPtr, bufLen Size) (Size, CryptoErrno)
+
+	// __(optional)__
+	// Create a context to use a secrets manager.
+	//
+	// The set of required and supported options is defined by the host.
+	//
+	// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+	// This is also an optional import, meaning that the function may not even exist.
+	SecretsManagerOpen(ctx context.Context, mod api.Module, options OptOptions) (SecretsManager, CryptoErrno)
+
+	// __(optional)__
+	// Destroy a secrets manager context.
+	//
+	// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+	// This is also an optional import, meaning that the function may not even exist.
+	SecretsManagerClose(ctx context.Context, mod api.Module, secretsManager SecretsManager) CryptoErrno
+
+	// __(optional)__
+	// Invalidate a managed key or key pair given an identifier and a version.
+	//
+	// This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+	//
+	// `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+	//
+	// The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SecretsManagerInvalidate(ctx context.Context, mod api.Module, secretsManager SecretsManager, keyId WasiPtr, keyIdLen Size, keyVersion Version) CryptoErrno
+}
+
+// DefineWasiEphemeralCryptoCommonImports returns a builder for the `wasi_ephemeral_crypto_common` host module, whose functions call host.
+// Instantiate it before the guest modules importing them.
+func DefineWasiEphemeralCryptoCommonImports(runtime wazero.Runtime, host WasiEphemeralCryptoCommonHost) wazero.HostModuleBuilder {
+	builder := runtime.NewHostModuleBuilder("wasi_ephemeral_crypto_common")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			algorithmType := AlgorithmType(api.DecodeU32(stack[0]))
+			resultPtr := api.DecodeU32(stack[1])
+			result, errno := host.OptionsOpen(ctx, mod, algorithmType)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_type", "result_ptr").
+		Export("options_open")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := Options(api.DecodeU32(stack[0]))
+			errno := host.OptionsClose(ctx, mod, handle)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle").
+		Export("options_close")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			handle := Options(api.DecodeU32(stack[0]))
+			namePtr := api.DecodeU32(stack[1])
+			nameLen := api.DecodeU32(stack[2])
+			name := string(r.bytes(namePtr, nameLen))
+			value := api.DecodeU32(stack[3])
+			valueLen := Size(api.DecodeU32(stack[4]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			errno := host.OptionsSet(ctx, mod, handle, name, value, valueLen)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "value", "value_len").
+		Export("options_set")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			handle := Options(api.DecodeU32(stack[0]))
+			namePtr := api.DecodeU32(stack[1])
+			nameLen := api.DecodeU32(stack[2])
+			name := string(r.bytes(namePtr, nameLen))
+			value := stack[3]
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			errno := host.OptionsSetU64(ctx, mod, handle, name, value)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI64}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "value").
+		Export("options_set_u64")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			handle := Options(api.DecodeU32(stack[0]))
+			namePtr := api.DecodeU32(stack[1])
+			nameLen := api.DecodeU32(stack[2])
+			name := string(r.bytes(namePtr, nameLen))
+			buffer := api.DecodeU32(stack[3])
+			bufferLen := Size(api.DecodeU32(stack[4]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			errno := host.OptionsSetGuestBuffer(ctx, mod, handle, name, buffer, bufferLen)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "buffer", "buffer_len").
+		Export("options_set_guest_buffer")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			arrayOutput := ArrayOutput(api.DecodeU32(stack[0]))
+			resultPtr := api.DecodeU32(stack[1])
+			result, errno := host.ArrayOutputLen(ctx, mod, arrayOutput)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("array_output", "result_ptr").
+		Export("array_output_len")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			arrayOutput := ArrayOutput(api.DecodeU32(stack[0]))
+			buf := api.DecodeU32(stack[1])
+			bufLen := Size(api.DecodeU32(stack[2]))
+			resultPtr := api.DecodeU32(stack[3])
+			result, errno := host.ArrayOutputPull(ctx, mod, arrayOutput, buf, bufLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("array_output", "buf", "buf_len", "result_ptr").
+		Export("array_output_pull")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			options := r.OptOptions(api.DecodeU32(stack[0]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[1])
+			result, errno := host.SecretsManagerOpen(ctx, mod, options)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("options", "result_ptr").
+		Export("secrets_manager_open")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			secretsManager := SecretsManager(api.DecodeU32(stack[0]))
+			errno := host.SecretsManagerClose(ctx, mod, secretsManager)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager").
+		Export("secrets_manager_close")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			secretsManager := SecretsManager(api.DecodeU32(stack[0]))
+			keyId := api.DecodeU32(stack[1])
+			keyIdLen := Size(api.DecodeU32(stack[2]))
+			keyVersion := Version(stack[3])
+			errno := host.SecretsManagerInvalidate(ctx, mod, secretsManager, keyId, keyIdLen, keyVersion)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI64}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "key_id", "key_id_len", "key_version").
+		Export("secrets_manager_invalidate")
+	return builder
+}
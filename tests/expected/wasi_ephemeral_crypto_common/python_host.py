@@ -0,0 +1,750 @@
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    """A string in guest memory."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    """A slice in guest memory, `len` being its number of elements."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host function to return the error code `code` to the guest."""
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    """Memory exported by the guest instance calling a host function."""
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get("memory")
+            if self._memory is None:
+                raise RuntimeError("The guest doesn't export its memory")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        """Return the `length` bytes at address `ptr`."""
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        """Write `data` at address `ptr`."""
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        """Return the UTF-8 string of `length` bytes at address `ptr`."""
+        return self.read(ptr, length).decode("utf-8")
+
+    def read_value(self, ctype, ptr: int):
+        """Return a copy of the value of type `ctype` at address `ptr`."""
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        """Return a copy of the `length` values of type `ctype` at address `ptr`."""
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        """Write `value`, converted to `ctype` if necessary, at address `ptr`."""
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    """A buffer of `size` bytes in guest memory, to be filled by a host function."""
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        """Write `data` to the buffer, `offset` bytes after its start."""
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        self.memory.write(self.ptr + offset, data)
+
+
+# ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+Handle = typing.NewType("Handle", int)
+
+
+class CryptoErrno(enum.IntEnum):
+    """Error codes."""
+
+    # Operation succeeded.
+    SUCCESS = 0
+    # An error occurred when trying to during a conversion from a host type to a guest type.
+    #
+    # Only an internal bug can throw this error.
+    GUEST_ERROR = 1
+    # The requested operation is valid, but not implemented by the host.
+    NOT_IMPLEMENTED = 2
+    # The requested feature is not supported by the chosen algorithm.
+    UNSUPPORTED_FEATURE = 3
+    # The requested operation is valid, but was administratively prohibited.
+    PROHIBITED_OPERATION = 4
+    # Unsupported encoding for an import or export operation.
+    UNSUPPORTED_ENCODING = 5
+    # The requested algorithm is not supported by the host.
+    UNSUPPORTED_ALGORITHM = 6
+    # The requested option is not supported by the currently selected algorithm.
+    UNSUPPORTED_OPTION = 7
+    # An invalid or incompatible key was supplied.
+    #
+    # The key may not be valid, or was generated for a different algorithm or parameters set.
+    INVALID_KEY = 8
+    # The currently selected algorithm doesn't support the requested output length.
+    #
+    # This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+    INVALID_LENGTH = 9
+    # A signature or authentication tag verification failed.
+    VERIFICATION_FAILED = 10
+    # A secure random numbers generator is not available.
+    #
+    # The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+    RNG_ERROR = 11
+    # An error was returned by the underlying cryptography library.
+    #
+    # The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    #
+    # Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    #
+    # Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    # This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+    ALGORITHM_FAILURE = 12
+    # The supplied signature is invalid, or incompatible with the chosen algorithm.
+    INVALID_SIGNATURE = 13
+    # An attempt was made to close a handle that was already closed.
+    CLOSED = 14
+    # A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+    INVALID_HANDLE = 15
+    # The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+    OVERFLOW = 16
+    # An internal error occurred.
+    #
+    # This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+    INTERNAL_ERROR = 17
+    # Too many handles are currently open, and a new one cannot be created.
+    #
+    # Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+    TOO_MANY_HANDLES = 18
+    # A key was provided, but the chosen algorithm doesn't support keys.
+    #
+    # This is returned by symmetric operations.
+    #
+    # Many hash functions, in particular, do not support keys without being used in particular constructions.
+    # Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    #
+    # These functions must refuse to create the context and return this error instead.
+    KEY_NOT_SUPPORTED = 19
+    # A key is required for the chosen algorithm, but none was given.
+    KEY_REQUIRED = 20
+    # The provided authentication tag is invalid or incompatible with the current algorithm.
+    #
+    # This error is returned by decryption functions and tag verification functions.
+    #
+    # Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+    INVALID_TAG = 21
+    # The requested operation is incompatible with the current scheme.
+    #
+    # For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    # This error code will be returned instead.
+    INVALID_OPERATION = 22
+    # A nonce is required.
+    #
+    # Most encryption schemes require a nonce.
+    #
+    # In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    # If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+    NONCE_REQUIRED = 23
+    # The provided nonce doesn't have a correct size for the given cipher.
+    INVALID_NONCE = 24
+    # The named option was not set.
+    #
+    # The caller tried to read the value of an option that was not set.
+    # This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+    OPTION_NOT_SET = 25
+    # A key or key pair matching the requested identifier cannot be found using the supplied information.
+    #
+    # This error is returned by a secrets manager via the `keypair_from_id()` function.
+    NOT_FOUND = 26
+    # The algorithm requires parameters that haven't been set.
+    #
+    # Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+    PARAMETERS_MISSING = 27
+    # A requested computation is not done yet, and additional calls to the function are required.
+    #
+    # Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    #
+    # In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+    IN_PROGRESS = 28
+    # Multiple keys have been provided, but they do not share the same type.
+    #
+    # This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+    INCOMPATIBLE_KEYS = 29
+    # A managed key or secret expired and cannot be used any more.
+    EXPIRED = 30
+
+
+class KeypairEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a key pair."""
+
+    # Raw bytes.
+    RAW = 0
+    # PCSK8/DER encoding.
+    PKCS8 = 1
+    # PEM encoding.
+    PEM = 2
+    # Implementation-defined encoding.
+    LOCAL = 3
+
+
+class PublickeyEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a public key."""
+
+    # Raw bytes.
+    RAW = 0
+    # PKCS8/DER encoding.
+    PKCS8 = 1
+    # PEM encoding.
+    PEM = 2
+    # SEC encoding.
+    SEC = 3
+    # Compressed SEC encoding.
+    COMPRESSED_SEC = 4
+    # Implementation-defined encoding.
+    LOCAL = 5
+
+
+class SecretkeyEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a secret key."""
+
+    # Raw bytes.
+    RAW = 0
+    # PKCS8/DER encoding.
+    PKCS8 = 1
+    # PEM encoding.
+    PEM = 2
+    # SEC encoding.
+    SEC = 3
+    # Compressed SEC encoding.
+    COMPRESSED_SEC = 4
+    # Implementation-defined encoding.
+    LOCAL = 5
+
+
+class SignatureEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a signature."""
+
+    # Raw bytes.
+    RAW = 0
+    # DER encoding.
+    DER = 1
+
+
+class AlgorithmType(enum.IntEnum):
+    """An algorithm category."""
+
+    SIGNATURES = 0
+    SYMMETRIC = 1
+    KEY_EXCHANGE = 2
+
+
+# Version of a managed key.
+#
+# A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+Version = ctypes.c_uint64
+
+
+VERSION_UNSPECIFIED = 0xff00000000000000
+VERSION_LATEST = 0xff00000000000001
+VERSION_ALL = 0xff00000000000002
+
+
+# Size of a value.
+Size = WasiUSize
+
+
+# A UNIX timestamp, in seconds since 01/01/1970.
+Timestamp = ctypes.c_uint64
+
+
+# A 64-bit value
+U64 = ctypes.c_uint64
+
+
+# Handle for functions returning output whose size may be large or not known in advance.
+#
+# An `array_output` object contains a host-allocated byte array.
+#
+# A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+# In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+#
+# An `array_output` handle is automatically closed after its full content has been consumed.
+ArrayOutput = typing.NewType("ArrayOutput", int)
+
+
+# A set of options.
+#
+# This type is used to set non-default parameters.
+#
+# The exact set of allowed options depends on the algorithm being used.
+Options = typing.NewType("Options", int)
+
+
+# A handle to the optional secrets management facilities offered by a host.
+#
+# This is used to generate, retrieve and invalidate managed keys.
+SecretsManager = typing.NewType("SecretsManager", int)
+
+
+# A key pair.
+Keypair = typing.NewType("Keypair", int)
+
+
+# A state to absorb data to be signed.
+#
+# After a signature has been computed or verified, the state remains valid for further operations.
+#
+# A subsequent signature would sign all the data accumulated since the creation of the state object.
+SignatureState = typing.NewType("SignatureState", int)
+
+
+# A signature.
+Signature = typing.NewType("Signature", int)
+
+
+# A public key, for key exchange and signature verification.
+Publickey = typing.NewType("Publickey", int)
+
+
+# A secret key, for key exchange mechanisms.
+Secretkey = typing.NewType("Secretkey", int)
+
+
+# A state to absorb signed data to be verified.
+SignatureVerificationState = typing.NewType("SignatureVerificationState", int)
+
+
+# A state to perform symmetric operations.
+#
+# The state is not reset nor invalidated after an option has been performed.
+# Incremental updates and sessions are thus supported.
+SymmetricState = typing.NewType("SymmetricState", int)
+
+
+# A symmetric key.
+#
+# The key can be imported from raw bytes, or can be a reference to a managed key.
+#
+# If it was imported, the host will wipe it from memory as soon as the handle is closed.
+SymmetricKey = typing.NewType("SymmetricKey", int)
+
+
+# An authentication tag.
+#
+# This is an object returned by functions computing authentication tags.
+#
+# A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+#
+# This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+#
+# The host is reponsible for securely wiping them from memory on close.
+SymmetricTag = typing.NewType("SymmetricTag", int)
+
+
+class OptOptionsU(enum.IntEnum):
+    """Options index, only required by the Interface Types translation layer."""
+
+    SOME = 0
+    NONE = 1
+
+
+class OptOptions(ctypes.Structure):
+    """An optional options set.
+
+    This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+    """
+
+    class _Member(ctypes.Union):
+        _pack_ = 1
+        _fields_ = [
+            ("some", WasiHandle),
+            ("_size", ctypes.c_uint8 * 4),
+        ]
+
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("_pad0", ctypes.c_uint8 * 3),
+        ("member", _Member),
+    ]
+
+    SOME = 0
+    NONE = 1
+
+    @classmethod
+    def new_some(cls, value) -> OptOptions:
+        """Return a `OptOptions` with the `some` case."""
+        union = cls(tag=cls.SOME)
+        union.member.some = value
+        return union
+
+    @classmethod
+    def new_none(cls) -> OptOptions:
+        """Return a `OptOptions` with the `none` case."""
+        union = cls(tag=cls.NONE)
+        return union
+
+
+class OptSymmetricKeyU(enum.IntEnum):
+    """Symmetric key index, only required by the Interface Types translation layer."""
+
+    SOME = 0
+    NONE = 1
+
+
+class OptSymmetricKey(ctypes.Structure):
+    """An optional symmetric key.
+
+    This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+    """
+
+    class _Member(ctypes.Union):
+        _pack_ = 1
+        _fields_ = [
+            ("some", WasiHandle),
+            ("_size", ctypes.c_uint8 * 4),
+        ]
+
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("_pad0", ctypes.c_uint8 * 3),
+        ("member", _Member),
+    ]
+
+    SOME = 0
+    NONE = 1
+
+    @classmethod
+    def new_some(cls, value) -> OptSymmetricKey:
+        """Return a `OptSymmetricKey` with the `some` case."""
+        union = cls(tag=cls.SOME)
+        union.member.some = value
+        return union
+
+    @classmethod
+    def new_none(cls) -> OptSymmetricKey:
+        """Return a `OptSymmetricKey` with the `none` case."""
+        union = cls(tag=cls.NONE)
+        return union
+
+
+class WasiEphemeralCryptoCommonHost:
+    """Functions of the `wasi_ephemeral_crypto_common` module, implemented by the host.
+
+    Override them in a subclass, and register an instance with `define_wasi_ephemeral_crypto_common_imports()`.
+    Methods receive the memory of the calling instance first.
+    Functions returning an error code raise `WasiError` to return it.
+    """
+
+    def options_open(self, memory: GuestMemory, algorithm_type: AlgorithmType) -> Options:
+        """Create a new object to set non-default options.
+
+        Example usage:
+
+        ```rust
+        let options_handle = options_open(AlgorithmType::Symmetric)?;
+        options_set(options_handle, "context", context)?;
+        options_set_u64(options_handle, "threads", 4)?;
+        let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+        options_close(options_handle)?;
+        ```
+        """
+        raise NotImplementedError("options_open")
+
+    def options_close(self, memory: GuestMemory, handle: Options) -> None:
+        """Destroy an options object.
+
+        Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+        """
+        raise NotImplementedError("options_close")
+
+    def options_set(self, memory: GuestMemory, handle: Options, name: str, value: int, value_len: int) -> None:
+        """Set or update an option.
+
+        This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+
+        This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+        """
+        raise NotImplementedError("options_set")
+
+    def options_set_u64(self, memory: GuestMemory, handle: Options, name: str, value: int) -> None:
+        """Set or update an integer option.
+
+        This is used to set algorithm-specific parameters.
+
+        This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+        """
+        raise NotImplementedError("options_set_u64")
+
+    def options_set_guest_buffer(self, memory: GuestMemory, handle: Options, name: str, buffer: int, buffer_len: int) -> None:
+        """Set or update a guest-allocated memory that the host can use or return data into.
+
+        This is for example used to set the scratch buffer required by memory-hard functions.
+
+        This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+        """
+        raise NotImplementedError("options_set_guest_buffer")
+
+    def array_output_len(self, memory: GuestMemory, array_output: ArrayOutput) -> int:
+        """Return the length of an `array_output` object.
+
+        This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+        """
+        raise NotImplementedError("array_output_len")
+
+    def array_output_pull(self, memory: GuestMemory, array_output: ArrayOutput, buf: int, buf_len: int) -> int:
+        """Copy the content of an `array_output` object into an application-allocated buffer.
+
+        Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+
+        The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+
+        The handle is automatically closed after all the data has been consumed.
+
+        Example usage:
+
+        ```rust
+        let len = array_output_len(output_handle)?;
+        let mut out = vec![0u8; len];
+        array_output_pull(output_handle, &mut out)?;
+        ```
+        """
+        raise NotImplementedError("array_output_pull")
+
+    def secrets_manager_open(self, memory: GuestMemory, options: OptOptions) -> SecretsManager:
+        """__(optional)__
+        Create a context to use a secrets manager.
+
+        The set of required and supported options is defined by the host.
+
+        The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+        This is also an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("secrets_manager_open")
+
+    def secrets_manager_close(self, memory: GuestMemory, secrets_manager: SecretsManager) -> None:
+        """__(optional)__
+        Destroy a secrets manager context.
+
+        The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+        This is also an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("secrets_manager_close")
+
+    def secrets_manager_invalidate(self, memory: GuestMemory, secrets_manager: SecretsManager, key_id: int, key_id_len: int, key_version: int) -> None:
+        """__(optional)__
+        Invalidate a managed key or key pair given an identifier and a version.
+
+        This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+
+        `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+
+        The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+
+        This is an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("secrets_manager_invalidate")
+
+
+def define_wasi_ephemeral_crypto_common_imports(linker: wasmtime.Linker, store: wasmtime.Store, impl: WasiEphemeralCryptoCommonHost) -> None:
+    """Define the functions of the `wasi_ephemeral_crypto_common` module in `linker`, implemented by `impl`."""
+
+    def _options_open(_caller, algorithm_type, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.options_open(_memory, AlgorithmType(algorithm_type))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "options_open", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _options_open,
+        access_caller=True,
+    ))
+
+    def _options_close(_caller, handle):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.options_close(_memory, Options(handle))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "options_close", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _options_close,
+        access_caller=True,
+    ))
+
+    def _options_set(_caller, handle, name_ptr, name_len, value, value_len):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.options_set(_memory, Options(handle), _memory.read_string(name_ptr, name_len), value, value_len)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "options_set", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _options_set,
+        access_caller=True,
+    ))
+
+    def _options_set_u64(_caller, handle, name_ptr, name_len, value):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.options_set_u64(_memory, Options(handle), _memory.read_string(name_ptr, name_len), value)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "options_set_u64", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i64()], [wasmtime.ValType.i32()]),
+        _options_set_u64,
+        access_caller=True,
+    ))
+
+    def _options_set_guest_buffer(_caller, handle, name_ptr, name_len, buffer, buffer_len):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.options_set_guest_buffer(_memory, Options(handle), _memory.read_string(name_ptr, name_len), buffer, buffer_len)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "options_set_guest_buffer", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _options_set_guest_buffer,
+        access_caller=True,
+    ))
+
+    def _array_output_len(_caller, array_output, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.array_output_len(_memory, ArrayOutput(array_output))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "array_output_len", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _array_output_len,
+        access_caller=True,
+    ))
+
+    def _array_output_pull(_caller, array_output, buf, buf_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.array_output_pull(_memory, ArrayOutput(array_output), buf, buf_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "array_output_pull", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _array_output_pull,
+        access_caller=True,
+    ))
+
+    def _secrets_manager_open(_caller, options, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.secrets_manager_open(_memory, _memory.read_value(OptOptions, options))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "secrets_manager_open", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _secrets_manager_open,
+        access_caller=True,
+    ))
+
+    def _secrets_manager_close(_caller, secrets_manager):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.secrets_manager_close(_memory, SecretsManager(secrets_manager))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "secrets_manager_close", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _secrets_manager_close,
+        access_caller=True,
+    ))
+
+    def _secrets_manager_invalidate(_caller, secrets_manager, key_id, key_id_len, key_version):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.secrets_manager_invalidate(_memory, SecretsManager(secrets_manager), key_id, key_id_len, key_version)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_common", "secrets_manager_invalidate", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i64()], [wasmtime.ValType.i32()]),
+        _secrets_manager_invalidate,
+        access_caller=True,
+    ))
+
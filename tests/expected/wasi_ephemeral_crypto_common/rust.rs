@@ -0,0 +1,1692 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Handle(pub WasiHandle);
+
+/// Error codes.
+pub type CryptoErrno = u16;
+
+#[allow(non_snake_case)]
+pub mod CRYPTO_ERRNO {
+    use super::CryptoErrno;
+    /// Operation succeeded.
+    pub const SUCCESS: CryptoErrno = 0;
+    /// An error occurred when trying to during a conversion from a host type to a guest type.
+    ///
+    /// Only an internal bug can throw this error.
+    pub const GUEST_ERROR: CryptoErrno = 1;
+    /// The requested operation is valid, but not implemented by the host.
+    pub const NOT_IMPLEMENTED: CryptoErrno = 2;
+    /// The requested feature is not supported by the chosen algorithm.
+    pub const UNSUPPORTED_FEATURE: CryptoErrno = 3;
+    /// The requested operation is valid, but was administratively prohibited.
+    pub const PROHIBITED_OPERATION: CryptoErrno = 4;
+    /// Unsupported encoding for an import or export operation.
+    pub const UNSUPPORTED_ENCODING: CryptoErrno = 5;
+    /// The requested algorithm is not supported by the host.
+    pub const UNSUPPORTED_ALGORITHM: CryptoErrno = 6;
+    /// The requested option is not supported by the currently selected algorithm.
+    pub const UNSUPPORTED_OPTION: CryptoErrno = 7;
+    /// An invalid or incompatible key was supplied.
+    ///
+    /// The key may not be valid, or was generated for a different algorithm or parameters set.
+    pub const INVALID_KEY: CryptoErrno = 8;
+    /// The currently selected algorithm doesn't support the requested output length.
+    ///
+    /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+    pub const INVALID_LENGTH: CryptoErrno = 9;
+    /// A signature or authentication tag verification failed.
+    pub const VERIFICATION_FAILED: CryptoErrno = 10;
+    /// A secure random numbers generator is not available.
+    ///
+    /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+    pub const RNG_ERROR: CryptoErrno = 11;
+    /// An error was returned by the underlying cryptography library.
+    ///
+    /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    ///
+    /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    ///
+    /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+    pub const ALGORITHM_FAILURE: CryptoErrno = 12;
+    /// The supplied signature is invalid, or incompatible with the chosen algorithm.
+    pub const INVALID_SIGNATURE: CryptoErrno = 13;
+    /// An attempt was made to close a handle that was already closed.
+    pub const CLOSED: CryptoErrno = 14;
+    /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+    pub const INVALID_HANDLE: CryptoErrno = 15;
+    /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+    pub const OVERFLOW: CryptoErrno = 16;
+    /// An internal error occurred.
+    ///
+    /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+    pub const INTERNAL_ERROR: CryptoErrno = 17;
+    /// Too many handles are currently open, and a new one cannot be created.
+    ///
+    /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+    pub const TOO_MANY_HANDLES: CryptoErrno = 18;
+    /// A key was provided, but the chosen algorithm doesn't support keys.
+    ///
+    /// This is returned by symmetric operations.
+    ///
+    /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+    /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    ///
+    /// These functions must refuse to create the context and return this error instead.
+    pub const KEY_NOT_SUPPORTED: CryptoErrno = 19;
+    /// A key is required for the chosen algorithm, but none was given.
+    pub const KEY_REQUIRED: CryptoErrno = 20;
+    /// The provided authentication tag is invalid or incompatible with the current algorithm.
+    ///
+    /// This error is returned by decryption functions and tag verification functions.
+    ///
+    /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+    pub const INVALID_TAG: CryptoErrno = 21;
+    /// The requested operation is incompatible with the current scheme.
+    ///
+    /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    /// This error code will be returned instead.
+    pub const INVALID_OPERATION: CryptoErrno = 22;
+    /// A nonce is required.
+    ///
+    /// Most encryption schemes require a nonce.
+    ///
+    /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+    pub const NONCE_REQUIRED: CryptoErrno = 23;
+    /// The provided nonce doesn't have a correct size for the given cipher.
+    pub const INVALID_NONCE: CryptoErrno = 24;
+    /// The named option was not set.
+    ///
+    /// The caller tried to read the value of an option that was not set.
+    /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+    pub const OPTION_NOT_SET: CryptoErrno = 25;
+    /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+    ///
+    /// This error is returned by a secrets manager via the `keypair_from_id()` function.
+    pub const NOT_FOUND: CryptoErrno = 26;
+    /// The algorithm requires parameters that haven't been set.
+    ///
+    /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+    pub const PARAMETERS_MISSING: CryptoErrno = 27;
+    /// A requested computation is not done yet, and additional calls to the function are required.
+    ///
+    /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    ///
+    /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+    pub const IN_PROGRESS: CryptoErrno = 28;
+    /// Multiple keys have been provided, but they do not share the same type.
+    ///
+    /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+    pub const INCOMPATIBLE_KEYS: CryptoErrno = 29;
+    /// A managed key or secret expired and cannot be used any more.
+    pub const EXPIRED: CryptoErrno = 30;
+}
+
+/// Encoding to use for importing or exporting a key pair.
+pub type KeypairEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod KEYPAIR_ENCODING {
+    use super::KeypairEncoding;
+    /// Raw bytes.
+    pub const RAW: KeypairEncoding = 0;
+    /// PCSK8/DER encoding.
+    pub const PKCS8: KeypairEncoding = 1;
+    /// PEM encoding.
+    pub const PEM: KeypairEncoding = 2;
+    /// Implementation-defined encoding.
+    pub const LOCAL: KeypairEncoding = 3;
+}
+
+/// Encoding to use for importing or exporting a public key.
+pub type PublickeyEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod PUBLICKEY_ENCODING {
+    use super::PublickeyEncoding;
+    /// Raw bytes.
+    pub const RAW: PublickeyEncoding = 0;
+    /// PKCS8/DER encoding.
+    pub const PKCS8: PublickeyEncoding = 1;
+    /// PEM encoding.
+    pub const PEM: PublickeyEncoding = 2;
+    /// SEC encoding.
+    pub const SEC: PublickeyEncoding = 3;
+    /// Compressed SEC encoding.
+    pub const COMPRESSED_SEC: PublickeyEncoding = 4;
+    /// Implementation-defined encoding.
+    pub const LOCAL: PublickeyEncoding = 5;
+}
+
+/// Encoding to use for importing or exporting a secret key.
+pub type SecretkeyEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod SECRETKEY_ENCODING {
+    use super::SecretkeyEncoding;
+    /// Raw bytes.
+    pub const RAW: SecretkeyEncoding = 0;
+    /// PKCS8/DER encoding.
+    pub const PKCS8: SecretkeyEncoding = 1;
+    /// PEM encoding.
+    pub const PEM: SecretkeyEncoding = 2;
+    /// SEC encoding.
+    pub const SEC: SecretkeyEncoding = 3;
+    /// Compressed SEC encoding.
+    pub const COMPRESSED_SEC: SecretkeyEncoding = 4;
+    /// Implementation-defined encoding.
+    pub const LOCAL: SecretkeyEncoding = 5;
+}
+
+/// Encoding to use for importing or exporting a signature.
+pub type SignatureEncoding = u16;
+
+#[allow(non_snake_case)]
+pub mod SIGNATURE_ENCODING {
+    use super::SignatureEncoding;
+    /// Raw bytes.
+    pub const RAW: SignatureEncoding = 0;
+    /// DER encoding.
+    pub const DER: SignatureEncoding = 1;
+}
+
+/// An algorithm category.
+pub type AlgorithmType = u16;
+
+#[allow(non_snake_case)]
+pub mod ALGORITHM_TYPE {
+    use super::AlgorithmType;
+    pub const SIGNATURES: AlgorithmType = 0;
+    pub const SYMMETRIC: AlgorithmType = 1;
+    pub const KEY_EXCHANGE: AlgorithmType = 2;
+}
+
+/// Version of a managed key.
+///
+/// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+pub type Version = u64;
+
+#[allow(non_snake_case)]
+pub mod VERSION {
+    use super::Version;
+    pub const UNSPECIFIED: Version = 0xff00000000000000;
+    pub const LATEST: Version = 0xff00000000000001;
+    pub const ALL: Version = 0xff00000000000002;
+}
+
+/// Size of a value.
+pub type Size = usize;
+
+/// A UNIX timestamp, in seconds since 01/01/1970.
+pub type Timestamp = u64;
+
+/// A 64-bit value
+pub type U64 = u64;
+
+/// Handle for functions returning output whose size may be large or not known in advance.
+///
+/// An `array_output` object contains a host-allocated byte array.
+///
+/// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+/// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+///
+/// An `array_output` handle is automatically closed after its full content has been consumed.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ArrayOutput(pub WasiHandle);
+
+/// A set of options.
+///
+/// This type is used to set non-default parameters.
+///
+/// The exact set of allowed options depends on the algorithm being used.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Options(pub WasiHandle);
+
+/// A handle to the optional secrets management facilities offered by a host.
+///
+/// This is used to generate, retrieve and invalidate managed keys.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SecretsManager(pub WasiHandle);
+
+/// A key pair.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Keypair(pub WasiHandle);
+
+/// A state to absorb data to be signed.
+///
+/// After a signature has been computed or verified, the state remains valid for further operations.
+///
+/// A subsequent signature would sign all the data accumulated since the creation of the state object.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SignatureState(pub WasiHandle);
+
+/// A signature.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Signature(pub WasiHandle);
+
+/// A public key, for key exchange and signature verification.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Publickey(pub WasiHandle);
+
+/// A secret key, for key exchange mechanisms.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Secretkey(pub WasiHandle);
+
+/// A state to absorb signed data to be verified.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SignatureVerificationState(pub WasiHandle);
+
+/// A state to perform symmetric operations.
+///
+/// The state is not reset nor invalidated after an option has been performed.
+/// Incremental updates and sessions are thus supported.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SymmetricState(pub WasiHandle);
+
+/// A symmetric key.
+///
+/// The key can be imported from raw bytes, or can be a reference to a managed key.
+///
+/// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SymmetricKey(pub WasiHandle);
+
+/// An authentication tag.
+///
+/// This is an object returned by functions computing authentication tags.
+///
+/// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+///
+/// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+///
+/// The host is reponsible for securely wiping them from memory on close.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SymmetricTag(pub WasiHandle);
+
+/// Options index, only required by the Interface Types translation layer.
+pub type OptOptionsU = u8;
+
+#[allow(non_snake_case)]
+pub mod OPT_OPTIONS_U {
+    use super::OptOptionsU;
+    pub const SOME: OptOptionsU = 0;
+    pub const NONE: OptOptionsU = 1;
+}
+
+/// An optional options set.
+///
+/// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union OptOptionsMember {
+    some: Options, // if tag=0
+    // none with no associated value if tag=1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct OptOptions {
+    pub tag: u8,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    pub member: core::mem::MaybeUninit<OptOptionsMember>,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<OptOptions>() == 8);
+    assert!(core::mem::align_of::<OptOptions>() == 4);
+    assert!(core::mem::offset_of!(OptOptions, tag) == 0);
+    assert!(core::mem::offset_of!(OptOptions, member) == 4);
+};
+
+impl OptOptions {
+    fn new(tag: u8) -> Self {
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<OptOptionsRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(OptOptionsRef::Some(unsafe { &self.member.assume_init_ref().some })),
+            1 => Ok(OptOptionsRef::None),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
+    // --- some: Options if tag=0
+
+    pub fn new_some(val: Options) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = core::mem::MaybeUninit::new(OptOptionsMember { some: val });
+        tu
+    }
+
+    pub fn into_some(self) -> Options {
+        assert_eq!(self.tag, 0);
+        unsafe { self.member.assume_init().some }
+    }
+
+    pub fn set_some(&mut self, val: Options) {
+        assert_eq!(self.tag, 0);
+        let uval = OptOptionsMember { some: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.tag == 0
+    }
+
+    // --- none: (no associated content) if tag=1
+
+    pub fn new_none() -> Self {
+        Self::new(1)
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.tag == 1
+    }
+}
+
+/// Content of a `OptOptions`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptOptionsEnum {
+    Some(Options),
+    None,
+}
+
+/// Reference to the content of a `OptOptions`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptOptionsRef<'a> {
+    Some(&'a Options),
+    None,
+}
+
+impl<'a> core::convert::TryFrom<&'a OptOptions> for OptOptionsRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a OptOptions) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<OptOptionsRef<'_>> for OptOptionsEnum {
+    fn from(content: OptOptionsRef<'_>) -> Self {
+        match content {
+            OptOptionsRef::Some(val) => OptOptionsEnum::Some(*val),
+            OptOptionsRef::None => OptOptionsEnum::None,
+        }
+    }
+}
+
+impl core::convert::TryFrom<OptOptions> for OptOptionsEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: OptOptions) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<OptOptionsEnum> for OptOptions {
+    fn from(content: OptOptionsEnum) -> Self {
+        match content {
+            OptOptionsEnum::Some(val) => Self::new_some(val),
+            OptOptionsEnum::None => Self::new_none(),
+        }
+    }
+}
+
+impl core::fmt::Debug for OptOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
+/// Symmetric key index, only required by the Interface Types translation layer.
+pub type OptSymmetricKeyU = u8;
+
+#[allow(non_snake_case)]
+pub mod OPT_SYMMETRIC_KEY_U {
+    use super::OptSymmetricKeyU;
+    pub const SOME: OptSymmetricKeyU = 0;
+    pub const NONE: OptSymmetricKeyU = 1;
+}
+
+/// An optional symmetric key.
+///
+/// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union OptSymmetricKeyMember {
+    some: SymmetricKey, // if tag=0
+    // none with no associated value if tag=1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct OptSymmetricKey {
+    pub tag: u8,
+    __pad8_0: u8,
+    __pad16_0: u16,
+    pub member: core::mem::MaybeUninit<OptSymmetricKeyMember>,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<OptSymmetricKey>() == 8);
+    assert!(core::mem::align_of::<OptSymmetricKey>() == 4);
+    assert!(core::mem::offset_of!(OptSymmetricKey, tag) == 0);
+    assert!(core::mem::offset_of!(OptSymmetricKey, member) == 4);
+};
+
+impl OptSymmetricKey {
+    fn new(tag: u8) -> Self {
+        let mut tu = unsafe { core::mem::zeroed::<Self>() };
+        tu.tag = tag;
+        tu
+    }
+
+    /// Return the content of the union, for all the cases to be handled with `match`, or an
+    /// error if the tag doesn't match any of them.
+    pub fn as_enum(&self) -> Result<OptSymmetricKeyRef<'_>, WasiInvalidTag> {
+        match self.tag {
+            0 => Ok(OptSymmetricKeyRef::Some(unsafe { &self.member.assume_init_ref().some })),
+            1 => Ok(OptSymmetricKeyRef::None),
+            tag => Err(WasiInvalidTag(u64::from(tag))),
+        }
+    }
+
+    // --- some: SymmetricKey if tag=0
+
+    pub fn new_some(val: SymmetricKey) -> Self {
+        let mut tu = Self::new(0);
+        tu.member = core::mem::MaybeUninit::new(OptSymmetricKeyMember { some: val });
+        tu
+    }
+
+    pub fn into_some(self) -> SymmetricKey {
+        assert_eq!(self.tag, 0);
+        unsafe { self.member.assume_init().some }
+    }
+
+    pub fn set_some(&mut self, val: SymmetricKey) {
+        assert_eq!(self.tag, 0);
+        let uval = OptSymmetricKeyMember { some: val };
+        self.member = core::mem::MaybeUninit::new(uval);
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.tag == 0
+    }
+
+    // --- none: (no associated content) if tag=1
+
+    pub fn new_none() -> Self {
+        Self::new(1)
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.tag == 1
+    }
+}
+
+/// Content of a `OptSymmetricKey`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptSymmetricKeyEnum {
+    Some(SymmetricKey),
+    None,
+}
+
+/// Reference to the content of a `OptSymmetricKey`.
+#[derive(Copy, Clone, Debug)]
+pub enum OptSymmetricKeyRef<'a> {
+    Some(&'a SymmetricKey),
+    None,
+}
+
+impl<'a> core::convert::TryFrom<&'a OptSymmetricKey> for OptSymmetricKeyRef<'a> {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: &'a OptSymmetricKey) -> Result<Self, Self::Error> {
+        tu.as_enum()
+    }
+}
+
+impl From<OptSymmetricKeyRef<'_>> for OptSymmetricKeyEnum {
+    fn from(content: OptSymmetricKeyRef<'_>) -> Self {
+        match content {
+            OptSymmetricKeyRef::Some(val) => OptSymmetricKeyEnum::Some(*val),
+            OptSymmetricKeyRef::None => OptSymmetricKeyEnum::None,
+        }
+    }
+}
+
+impl core::convert::TryFrom<OptSymmetricKey> for OptSymmetricKeyEnum {
+    type Error = WasiInvalidTag;
+
+    fn try_from(tu: OptSymmetricKey) -> Result<Self, Self::Error> {
+        tu.as_enum().map(Self::from)
+    }
+}
+
+impl From<OptSymmetricKeyEnum> for OptSymmetricKey {
+    fn from(content: OptSymmetricKeyEnum) -> Self {
+        match content {
+            OptSymmetricKeyEnum::Some(val) => Self::new_some(val),
+            OptSymmetricKeyEnum::None => Self::new_none(),
+        }
+    }
+}
+
+impl core::fmt::Debug for OptSymmetricKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_enum() {
+            Ok(content) => content.fmt(f),
+            Err(invalid_tag) => invalid_tag.fmt(f),
+        }
+    }
+}
+
+/// Error returned by the functions of the `wasi_ephemeral_crypto_common` module.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum WasiEphemeralCryptoCommonError {
+    /// An error occurred when trying to during a conversion from a host type to a guest type.
+    ///
+    /// Only an internal bug can throw this error.
+    GuestError,
+    /// The requested operation is valid, but not implemented by the host.
+    NotImplemented,
+    /// The requested feature is not supported by the chosen algorithm.
+    UnsupportedFeature,
+    /// The requested operation is valid, but was administratively prohibited.
+    ProhibitedOperation,
+    /// Unsupported encoding for an import or export operation.
+    UnsupportedEncoding,
+    /// The requested algorithm is not supported by the host.
+    UnsupportedAlgorithm,
+    /// The requested option is not supported by the currently selected algorithm.
+    UnsupportedOption,
+    /// An invalid or incompatible key was supplied.
+    ///
+    /// The key may not be valid, or was generated for a different algorithm or parameters set.
+    InvalidKey,
+    /// The currently selected algorithm doesn't support the requested output length.
+    ///
+    /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+    InvalidLength,
+    /// A signature or authentication tag verification failed.
+    VerificationFailed,
+    /// A secure random numbers generator is not available.
+    ///
+    /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+    RngError,
+    /// An error was returned by the underlying cryptography library.
+    ///
+    /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    ///
+    /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    ///
+    /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+    AlgorithmFailure,
+    /// The supplied signature is invalid, or incompatible with the chosen algorithm.
+    InvalidSignature,
+    /// An attempt was made to close a handle that was already closed.
+    Closed,
+    /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+    InvalidHandle,
+    /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+    Overflow,
+    /// An internal error occurred.
+    ///
+    /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+    InternalError,
+    /// Too many handles are currently open, and a new one cannot be created.
+    ///
+    /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+    TooManyHandles,
+    /// A key was provided, but the chosen algorithm doesn't support keys.
+    ///
+    /// This is returned by symmetric operations.
+    ///
+    /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+    /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    ///
+    /// These functions must refuse to create the context and return this error instead.
+    KeyNotSupported,
+    /// A key is required for the chosen algorithm, but none was given.
+    KeyRequired,
+    /// The provided authentication tag is invalid or incompatible with the current algorithm.
+    ///
+    /// This error is returned by decryption functions and tag verification functions.
+    ///
+    /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+    InvalidTag,
+    /// The requested operation is incompatible with the current scheme.
+    ///
+    /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    /// This error code will be returned instead.
+    InvalidOperation,
+    /// A nonce is required.
+    ///
+    /// Most encryption schemes require a nonce.
+    ///
+    /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+    NonceRequired,
+    /// The provided nonce doesn't have a correct size for the given cipher.
+    InvalidNonce,
+    /// The named option was not set.
+    ///
+    /// The caller tried to read the value of an option that was not set.
+    /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+    OptionNotSet,
+    /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+    ///
+    /// This error is returned by a secrets manager via the `keypair_from_id()` function.
+    NotFound,
+    /// The algorithm requires parameters that haven't been set.
+    ///
+    /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+    ParametersMissing,
+    /// A requested computation is not done yet, and additional calls to the function are required.
+    ///
+    /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    ///
+    /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+    InProgress,
+    /// Multiple keys have been provided, but they do not share the same type.
+    ///
+    /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+    IncompatibleKeys,
+    /// A managed key or secret expired and cannot be used any more.
+    Expired,
+    /// An error code that is not an error case of `CryptoErrno`.
+    Other(CryptoErrno),
+}
+
+impl From<CryptoErrno> for WasiEphemeralCryptoCommonError {
+    fn from(e: CryptoErrno) -> Self {
+        match e {
+            CRYPTO_ERRNO::GUEST_ERROR => WasiEphemeralCryptoCommonError::GuestError,
+            CRYPTO_ERRNO::NOT_IMPLEMENTED => WasiEphemeralCryptoCommonError::NotImplemented,
+            CRYPTO_ERRNO::UNSUPPORTED_FEATURE => WasiEphemeralCryptoCommonError::UnsupportedFeature,
+            CRYPTO_ERRNO::PROHIBITED_OPERATION => WasiEphemeralCryptoCommonError::ProhibitedOperation,
+            CRYPTO_ERRNO::UNSUPPORTED_ENCODING => WasiEphemeralCryptoCommonError::UnsupportedEncoding,
+            CRYPTO_ERRNO::UNSUPPORTED_ALGORITHM => WasiEphemeralCryptoCommonError::UnsupportedAlgorithm,
+            CRYPTO_ERRNO::UNSUPPORTED_OPTION => WasiEphemeralCryptoCommonError::UnsupportedOption,
+            CRYPTO_ERRNO::INVALID_KEY => WasiEphemeralCryptoCommonError::InvalidKey,
+            CRYPTO_ERRNO::INVALID_LENGTH => WasiEphemeralCryptoCommonError::InvalidLength,
+            CRYPTO_ERRNO::VERIFICATION_FAILED => WasiEphemeralCryptoCommonError::VerificationFailed,
+            CRYPTO_ERRNO::RNG_ERROR => WasiEphemeralCryptoCommonError::RngError,
+            CRYPTO_ERRNO::ALGORITHM_FAILURE => WasiEphemeralCryptoCommonError::AlgorithmFailure,
+            CRYPTO_ERRNO::INVALID_SIGNATURE => WasiEphemeralCryptoCommonError::InvalidSignature,
+            CRYPTO_ERRNO::CLOSED => WasiEphemeralCryptoCommonError::Closed,
+            CRYPTO_ERRNO::INVALID_HANDLE => WasiEphemeralCryptoCommonError::InvalidHandle,
+            CRYPTO_ERRNO::OVERFLOW => WasiEphemeralCryptoCommonError::Overflow,
+            CRYPTO_ERRNO::INTERNAL_ERROR => WasiEphemeralCryptoCommonError::InternalError,
+            CRYPTO_ERRNO::TOO_MANY_HANDLES => WasiEphemeralCryptoCommonError::TooManyHandles,
+            CRYPTO_ERRNO::KEY_NOT_SUPPORTED => WasiEphemeralCryptoCommonError::KeyNotSupported,
+            CRYPTO_ERRNO::KEY_REQUIRED => WasiEphemeralCryptoCommonError::KeyRequired,
+            CRYPTO_ERRNO::INVALID_TAG => WasiEphemeralCryptoCommonError::InvalidTag,
+            CRYPTO_ERRNO::INVALID_OPERATION => WasiEphemeralCryptoCommonError::InvalidOperation,
+            CRYPTO_ERRNO::NONCE_REQUIRED => WasiEphemeralCryptoCommonError::NonceRequired,
+            CRYPTO_ERRNO::INVALID_NONCE => WasiEphemeralCryptoCommonError::InvalidNonce,
+            CRYPTO_ERRNO::OPTION_NOT_SET => WasiEphemeralCryptoCommonError::OptionNotSet,
+            CRYPTO_ERRNO::NOT_FOUND => WasiEphemeralCryptoCommonError::NotFound,
+            CRYPTO_ERRNO::PARAMETERS_MISSING => WasiEphemeralCryptoCommonError::ParametersMissing,
+            CRYPTO_ERRNO::IN_PROGRESS => WasiEphemeralCryptoCommonError::InProgress,
+            CRYPTO_ERRNO::INCOMPATIBLE_KEYS => WasiEphemeralCryptoCommonError::IncompatibleKeys,
+            CRYPTO_ERRNO::EXPIRED => WasiEphemeralCryptoCommonError::Expired,
+            e => WasiEphemeralCryptoCommonError::Other(e),
+        }
+    }
+}
+
+impl From<WasiEphemeralCryptoCommonError> for CryptoErrno {
+    fn from(e: WasiEphemeralCryptoCommonError) -> Self {
+        match e {
+            WasiEphemeralCryptoCommonError::GuestError => CRYPTO_ERRNO::GUEST_ERROR,
+            WasiEphemeralCryptoCommonError::NotImplemented => CRYPTO_ERRNO::NOT_IMPLEMENTED,
+            WasiEphemeralCryptoCommonError::UnsupportedFeature => CRYPTO_ERRNO::UNSUPPORTED_FEATURE,
+            WasiEphemeralCryptoCommonError::ProhibitedOperation => CRYPTO_ERRNO::PROHIBITED_OPERATION,
+            WasiEphemeralCryptoCommonError::UnsupportedEncoding => CRYPTO_ERRNO::UNSUPPORTED_ENCODING,
+            WasiEphemeralCryptoCommonError::UnsupportedAlgorithm => CRYPTO_ERRNO::UNSUPPORTED_ALGORITHM,
+            WasiEphemeralCryptoCommonError::UnsupportedOption => CRYPTO_ERRNO::UNSUPPORTED_OPTION,
+            WasiEphemeralCryptoCommonError::InvalidKey => CRYPTO_ERRNO::INVALID_KEY,
+            WasiEphemeralCryptoCommonError::InvalidLength => CRYPTO_ERRNO::INVALID_LENGTH,
+            WasiEphemeralCryptoCommonError::VerificationFailed => CRYPTO_ERRNO::VERIFICATION_FAILED,
+            WasiEphemeralCryptoCommonError::RngError => CRYPTO_ERRNO::RNG_ERROR,
+            WasiEphemeralCryptoCommonError::AlgorithmFailure => CRYPTO_ERRNO::ALGORITHM_FAILURE,
+            WasiEphemeralCryptoCommonError::InvalidSignature => CRYPTO_ERRNO::INVALID_SIGNATURE,
+            WasiEphemeralCryptoCommonError::Closed => CRYPTO_ERRNO::CLOSED,
+            WasiEphemeralCryptoCommonError::InvalidHandle => CRYPTO_ERRNO::INVALID_HANDLE,
+            WasiEphemeralCryptoCommonError::Overflow => CRYPTO_ERRNO::OVERFLOW,
+            WasiEphemeralCryptoCommonError::InternalError => CRYPTO_ERRNO::INTERNAL_ERROR,
+            WasiEphemeralCryptoCommonError::TooManyHandles => CRYPTO_ERRNO::TOO_MANY_HANDLES,
+            WasiEphemeralCryptoCommonError::KeyNotSupported => CRYPTO_ERRNO::KEY_NOT_SUPPORTED,
+            WasiEphemeralCryptoCommonError::KeyRequired => CRYPTO_ERRNO::KEY_REQUIRED,
+            WasiEphemeralCryptoCommonError::InvalidTag => CRYPTO_ERRNO::INVALID_TAG,
+            WasiEphemeralCryptoCommonError::InvalidOperation => CRYPTO_ERRNO::INVALID_OPERATION,
+            WasiEphemeralCryptoCommonError::NonceRequired => CRYPTO_ERRNO::NONCE_REQUIRED,
+            WasiEphemeralCryptoCommonError::InvalidNonce => CRYPTO_ERRNO::INVALID_NONCE,
+            WasiEphemeralCryptoCommonError::OptionNotSet => CRYPTO_ERRNO::OPTION_NOT_SET,
+            WasiEphemeralCryptoCommonError::NotFound => CRYPTO_ERRNO::NOT_FOUND,
+            WasiEphemeralCryptoCommonError::ParametersMissing => CRYPTO_ERRNO::PARAMETERS_MISSING,
+            WasiEphemeralCryptoCommonError::InProgress => CRYPTO_ERRNO::IN_PROGRESS,
+            WasiEphemeralCryptoCommonError::IncompatibleKeys => CRYPTO_ERRNO::INCOMPATIBLE_KEYS,
+            WasiEphemeralCryptoCommonError::Expired => CRYPTO_ERRNO::EXPIRED,
+            WasiEphemeralCryptoCommonError::Other(e) => e,
+        }
+    }
+}
+
+impl From<WasiEphemeralCryptoCommonError> for Error {
+    fn from(e: WasiEphemeralCryptoCommonError) -> Self {
+        Error::WasiError(CryptoErrno::from(e) as _)
+    }
+}
+
+impl core::fmt::Display for WasiEphemeralCryptoCommonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            WasiEphemeralCryptoCommonError::GuestError => "An error occurred when trying to during a conversion from a host type to a guest type.",
+            WasiEphemeralCryptoCommonError::NotImplemented => "The requested operation is valid, but not implemented by the host.",
+            WasiEphemeralCryptoCommonError::UnsupportedFeature => "The requested feature is not supported by the chosen algorithm.",
+            WasiEphemeralCryptoCommonError::ProhibitedOperation => "The requested operation is valid, but was administratively prohibited.",
+            WasiEphemeralCryptoCommonError::UnsupportedEncoding => "Unsupported encoding for an import or export operation.",
+            WasiEphemeralCryptoCommonError::UnsupportedAlgorithm => "The requested algorithm is not supported by the host.",
+            WasiEphemeralCryptoCommonError::UnsupportedOption => "The requested option is not supported by the currently selected algorithm.",
+            WasiEphemeralCryptoCommonError::InvalidKey => "An invalid or incompatible key was supplied.",
+            WasiEphemeralCryptoCommonError::InvalidLength => "The currently selected algorithm doesn't support the requested output length.",
+            WasiEphemeralCryptoCommonError::VerificationFailed => "A signature or authentication tag verification failed.",
+            WasiEphemeralCryptoCommonError::RngError => "A secure random numbers generator is not available.",
+            WasiEphemeralCryptoCommonError::AlgorithmFailure => "An error was returned by the underlying cryptography library.",
+            WasiEphemeralCryptoCommonError::InvalidSignature => "The supplied signature is invalid, or incompatible with the chosen algorithm.",
+            WasiEphemeralCryptoCommonError::Closed => "An attempt was made to close a handle that was already closed.",
+            WasiEphemeralCryptoCommonError::InvalidHandle => "A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.",
+            WasiEphemeralCryptoCommonError::Overflow => "The host needs to copy data to a guest-allocated buffer, but that buffer is too small.",
+            WasiEphemeralCryptoCommonError::InternalError => "An internal error occurred.",
+            WasiEphemeralCryptoCommonError::TooManyHandles => "Too many handles are currently open, and a new one cannot be created.",
+            WasiEphemeralCryptoCommonError::KeyNotSupported => "A key was provided, but the chosen algorithm doesn't support keys.",
+            WasiEphemeralCryptoCommonError::KeyRequired => "A key is required for the chosen algorithm, but none was given.",
+            WasiEphemeralCryptoCommonError::InvalidTag => "The provided authentication tag is invalid or incompatible with the current algorithm.",
+            WasiEphemeralCryptoCommonError::InvalidOperation => "The requested operation is incompatible with the current scheme.",
+            WasiEphemeralCryptoCommonError::NonceRequired => "A nonce is required.",
+            WasiEphemeralCryptoCommonError::InvalidNonce => "The provided nonce doesn't have a correct size for the given cipher.",
+            WasiEphemeralCryptoCommonError::OptionNotSet => "The named option was not set.",
+            WasiEphemeralCryptoCommonError::NotFound => "A key or key pair matching the requested identifier cannot be found using the supplied information.",
+            WasiEphemeralCryptoCommonError::ParametersMissing => "The algorithm requires parameters that haven't been set.",
+            WasiEphemeralCryptoCommonError::InProgress => "A requested computation is not done yet, and additional calls to the function are required.",
+            WasiEphemeralCryptoCommonError::IncompatibleKeys => "Multiple keys have been provided, but they do not share the same type.",
+            WasiEphemeralCryptoCommonError::Expired => "A managed key or secret expired and cannot be used any more.",
+            WasiEphemeralCryptoCommonError::Other(e) => return write!(f, "Unknown error {}", e),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for WasiEphemeralCryptoCommonError {}
+
+/// Create a new object to set non-default options.
+///
+/// Example usage:
+///
+/// ```rust
+/// let options_handle = options_open(AlgorithmType::Symmetric)?;
+/// options_set(options_handle, "context", context)?;
+/// options_set_u64(options_handle, "threads", 4)?;
+/// let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+/// options_close(options_handle)?;
+/// ```
+pub fn options_open(
+    algorithm_type: AlgorithmType,
+) -> Result<Options, WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn options_open(
+                algorithm_type: AlgorithmType,
+                result_ptr: WasiMutPtr<Options>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn options_open(
+            algorithm_type: AlgorithmType,
+            result_ptr: WasiMutPtr<Options>,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.options_open(algorithm_type)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::options_open(
+        algorithm_type,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Destroy an options object.
+///
+/// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+pub fn options_close(
+    handle: Options,
+) -> Result<(), WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn options_close(
+                handle: Options,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn options_close(
+            handle: Options,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.options_close(handle)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::options_close(
+        handle,
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(())
+}
+
+/// Set or update an option.
+///
+/// This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+///
+/// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+pub fn options_set(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: WasiPtr<u8>,
+    value_len: Size,
+) -> Result<(), WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn options_set(
+                handle: Options,
+                name_ptr: WasiPtr<Char8>,
+                name_len: usize,
+                value: WasiPtr<u8>,
+                value_len: Size,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn options_set(
+            handle: Options,
+            name_ptr: WasiPtr<Char8>,
+            name_len: usize,
+            value: WasiPtr<u8>,
+            value_len: Size,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.options_set(handle, name_ptr, name_len, value, value_len)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::options_set(
+        handle,
+        name_ptr,
+        name_len,
+        value,
+        value_len,
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(())
+}
+
+/// Set or update an integer option.
+///
+/// This is used to set algorithm-specific parameters.
+///
+/// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+pub fn options_set_u64(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    value: u64,
+) -> Result<(), WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn options_set_u64(
+                handle: Options,
+                name_ptr: WasiPtr<Char8>,
+                name_len: usize,
+                value: u64,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn options_set_u64(
+            handle: Options,
+            name_ptr: WasiPtr<Char8>,
+            name_len: usize,
+            value: u64,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.options_set_u64(handle, name_ptr, name_len, value)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::options_set_u64(
+        handle,
+        name_ptr,
+        name_len,
+        value,
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(())
+}
+
+/// Set or update a guest-allocated memory that the host can use or return data into.
+///
+/// This is for example used to set the scratch buffer required by memory-hard functions.
+///
+/// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+pub fn options_set_guest_buffer(
+    handle: Options,
+    name_ptr: WasiPtr<Char8>,
+    name_len: usize,
+    buffer: WasiMutPtr<u8>,
+    buffer_len: Size,
+) -> Result<(), WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn options_set_guest_buffer(
+                handle: Options,
+                name_ptr: WasiPtr<Char8>,
+                name_len: usize,
+                buffer: WasiMutPtr<u8>,
+                buffer_len: Size,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn options_set_guest_buffer(
+            handle: Options,
+            name_ptr: WasiPtr<Char8>,
+            name_len: usize,
+            buffer: WasiMutPtr<u8>,
+            buffer_len: Size,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.options_set_guest_buffer(handle, name_ptr, name_len, buffer, buffer_len)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::options_set_guest_buffer(
+        handle,
+        name_ptr,
+        name_len,
+        buffer,
+        buffer_len,
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(())
+}
+
+/// Return the length of an `array_output` object.
+///
+/// This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+pub fn array_output_len(
+    array_output: ArrayOutput,
+) -> Result<Size, WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn array_output_len(
+                array_output: ArrayOutput,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn array_output_len(
+            array_output: ArrayOutput,
+            result_ptr: WasiMutPtr<Size>,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.array_output_len(array_output)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::array_output_len(
+        array_output,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Copy the content of an `array_output` object into an application-allocated buffer.
+///
+/// Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+///
+/// The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+///
+/// The handle is automatically closed after all the data has been consumed.
+///
+/// Example usage:
+///
+/// ```rust
+/// let len = array_output_len(output_handle)?;
+/// let mut out = vec![0u8; len];
+/// array_output_pull(output_handle, &mut out)?;
+/// ```
+pub fn array_output_pull(
+    array_output: ArrayOutput,
+    buf: WasiMutPtr<u8>,
+    buf_len: Size,
+) -> Result<Size, WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn array_output_pull(
+                array_output: ArrayOutput,
+                buf: WasiMutPtr<u8>,
+                buf_len: Size,
+                result_ptr: WasiMutPtr<Size>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn array_output_pull(
+            array_output: ArrayOutput,
+            buf: WasiMutPtr<u8>,
+            buf_len: Size,
+            result_ptr: WasiMutPtr<Size>,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.array_output_pull(array_output, buf, buf_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::array_output_pull(
+        array_output,
+        buf,
+        buf_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// __(optional)__
+/// Create a context to use a secrets manager.
+///
+/// The set of required and supported options is defined by the host.
+///
+/// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+/// This is also an optional import, meaning that the function may not even exist.
+pub fn secrets_manager_open(
+    options: OptOptions,
+) -> Result<SecretsManager, WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn secrets_manager_open(
+                options: OptOptions,
+                result_ptr: WasiMutPtr<SecretsManager>,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn secrets_manager_open(
+            options: OptOptions,
+            result_ptr: WasiMutPtr<SecretsManager>,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.secrets_manager_open(options)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::secrets_manager_open(
+        options,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// __(optional)__
+/// Destroy a secrets manager context.
+///
+/// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+/// This is also an optional import, meaning that the function may not even exist.
+pub fn secrets_manager_close(
+    secrets_manager: SecretsManager,
+) -> Result<(), WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn secrets_manager_close(
+                secrets_manager: SecretsManager,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn secrets_manager_close(
+            secrets_manager: SecretsManager,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.secrets_manager_close(secrets_manager)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::secrets_manager_close(
+        secrets_manager,
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(())
+}
+
+/// __(optional)__
+/// Invalidate a managed key or key pair given an identifier and a version.
+///
+/// This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+///
+/// `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+///
+/// The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+///
+/// This is an optional import, meaning that the function may not even exist.
+pub fn secrets_manager_invalidate(
+    secrets_manager: SecretsManager,
+    key_id: WasiPtr<u8>,
+    key_id_len: Size,
+    key_version: Version,
+) -> Result<(), WasiEphemeralCryptoCommonError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_ephemeral_crypto_common")]
+        extern "C" {
+            pub fn secrets_manager_invalidate(
+                secrets_manager: SecretsManager,
+                key_id: WasiPtr<u8>,
+                key_id_len: Size,
+                key_version: Version,
+            ) -> CryptoErrno;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn secrets_manager_invalidate(
+            secrets_manager: SecretsManager,
+            key_id: WasiPtr<u8>,
+            key_id_len: Size,
+            key_version: Version,
+        ) -> CryptoErrno {
+            match super::with_wasi_ephemeral_crypto_common_mock(|mock| mock.secrets_manager_invalidate(secrets_manager, key_id, key_id_len, key_version)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::secrets_manager_invalidate(
+        secrets_manager,
+        key_id,
+        key_id_len,
+        key_version,
+    )};
+    if res != 0 {
+        return Err(WasiEphemeralCryptoCommonError::from(res));
+    }
+    Ok(())
+}
+
+/// Implementation of the functions of the `wasi_ephemeral_crypto_common` module used on native targets.
+/// Install it with `set_wasi_ephemeral_crypto_common_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait WasiEphemeralCryptoCommonMock {
+    /// Create a new object to set non-default options.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let options_handle = options_open(AlgorithmType::Symmetric)?;
+    /// options_set(options_handle, "context", context)?;
+    /// options_set_u64(options_handle, "threads", 4)?;
+    /// let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+    /// options_close(options_handle)?;
+    /// ```
+    #[allow(unused_variables)]
+    fn options_open(
+        &self,
+        algorithm_type: AlgorithmType,
+    ) -> Result<Options, WasiEphemeralCryptoCommonError> {
+        unimplemented!("options_open")
+    }
+
+    /// Destroy an options object.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    #[allow(unused_variables)]
+    fn options_close(
+        &self,
+        handle: Options,
+    ) -> Result<(), WasiEphemeralCryptoCommonError> {
+        unimplemented!("options_close")
+    }
+
+    /// Set or update an option.
+    ///
+    /// This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    #[allow(unused_variables)]
+    fn options_set(
+        &self,
+        handle: Options,
+        name_ptr: WasiPtr<Char8>,
+        name_len: usize,
+        value: WasiPtr<u8>,
+        value_len: Size,
+    ) -> Result<(), WasiEphemeralCryptoCommonError> {
+        unimplemented!("options_set")
+    }
+
+    /// Set or update an integer option.
+    ///
+    /// This is used to set algorithm-specific parameters.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    #[allow(unused_variables)]
+    fn options_set_u64(
+        &self,
+        handle: Options,
+        name_ptr: WasiPtr<Char8>,
+        name_len: usize,
+        value: u64,
+    ) -> Result<(), WasiEphemeralCryptoCommonError> {
+        unimplemented!("options_set_u64")
+    }
+
+    /// Set or update a guest-allocated memory that the host can use or return data into.
+    ///
+    /// This is for example used to set the scratch buffer required by memory-hard functions.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    #[allow(unused_variables)]
+    fn options_set_guest_buffer(
+        &self,
+        handle: Options,
+        name_ptr: WasiPtr<Char8>,
+        name_len: usize,
+        buffer: WasiMutPtr<u8>,
+        buffer_len: Size,
+    ) -> Result<(), WasiEphemeralCryptoCommonError> {
+        unimplemented!("options_set_guest_buffer")
+    }
+
+    /// Return the length of an `array_output` object.
+    ///
+    /// This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+    #[allow(unused_variables)]
+    fn array_output_len(
+        &self,
+        array_output: ArrayOutput,
+    ) -> Result<Size, WasiEphemeralCryptoCommonError> {
+        unimplemented!("array_output_len")
+    }
+
+    /// Copy the content of an `array_output` object into an application-allocated buffer.
+    ///
+    /// Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+    ///
+    /// The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+    ///
+    /// The handle is automatically closed after all the data has been consumed.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let len = array_output_len(output_handle)?;
+    /// let mut out = vec![0u8; len];
+    /// array_output_pull(output_handle, &mut out)?;
+    /// ```
+    #[allow(unused_variables)]
+    fn array_output_pull(
+        &self,
+        array_output: ArrayOutput,
+        buf: WasiMutPtr<u8>,
+        buf_len: Size,
+    ) -> Result<Size, WasiEphemeralCryptoCommonError> {
+        unimplemented!("array_output_pull")
+    }
+
+    /// __(optional)__
+    /// Create a context to use a secrets manager.
+    ///
+    /// The set of required and supported options is defined by the host.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+    /// This is also an optional import, meaning that the function may not even exist.
+    #[allow(unused_variables)]
+    fn secrets_manager_open(
+        &self,
+        options: OptOptions,
+    ) -> Result<SecretsManager, WasiEphemeralCryptoCommonError> {
+        unimplemented!("secrets_manager_open")
+    }
+
+    /// __(optional)__
+    /// Destroy a secrets manager context.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+    /// This is also an optional import, meaning that the function may not even exist.
+    #[allow(unused_variables)]
+    fn secrets_manager_close(
+        &self,
+        secrets_manager: SecretsManager,
+    ) -> Result<(), WasiEphemeralCryptoCommonError> {
+        unimplemented!("secrets_manager_close")
+    }
+
+    /// __(optional)__
+    /// Invalidate a managed key or key pair given an identifier and a version.
+    ///
+    /// This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+    ///
+    /// `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+    ///
+    /// The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    #[allow(unused_variables)]
+    fn secrets_manager_invalidate(
+        &self,
+        secrets_manager: SecretsManager,
+        key_id: WasiPtr<u8>,
+        key_id_len: Size,
+        key_version: Version,
+    ) -> Result<(), WasiEphemeralCryptoCommonError> {
+        unimplemented!("secrets_manager_invalidate")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static WASI_EPHEMERAL_CRYPTO_COMMON_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn WasiEphemeralCryptoCommonMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `wasi_ephemeral_crypto_common` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_wasi_ephemeral_crypto_common_mock(mock: impl WasiEphemeralCryptoCommonMock + 'static) -> Option<std::rc::Rc<dyn WasiEphemeralCryptoCommonMock>> {
+    WASI_EPHEMERAL_CRYPTO_COMMON_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_wasi_ephemeral_crypto_common_mock<R>(f: impl FnOnce(&dyn WasiEphemeralCryptoCommonMock) -> R) -> R {
+    let mock = WASI_EPHEMERAL_CRYPTO_COMMON_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `wasi_ephemeral_crypto_common` module installed"))
+}
+
+/// Owned `Options` handle, closed with `options_close()` when dropped.
+#[derive(Debug)]
+pub struct OwnedOptions(Options);
+
+impl OwnedOptions {
+    pub fn new(handle: Options) -> Self {
+        OwnedOptions(handle)
+    }
+
+    pub fn get(&self) -> Options {
+        self.0
+    }
+
+    pub fn release(self) -> Options {
+        let handle = self.0;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedOptions {
+    fn drop(&mut self) {
+        let _ = options_close(self.0);
+    }
+}
+
+/// Owned `SecretsManager` handle, closed with `secrets_manager_close()` when dropped.
+#[derive(Debug)]
+pub struct OwnedSecretsManager(SecretsManager);
+
+impl OwnedSecretsManager {
+    pub fn new(handle: SecretsManager) -> Self {
+        OwnedSecretsManager(handle)
+    }
+
+    pub fn get(&self) -> SecretsManager {
+        self.0
+    }
+
+    pub fn release(self) -> SecretsManager {
+        let handle = self.0;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedSecretsManager {
+    fn drop(&mut self) {
+        let _ = secrets_manager_close(self.0);
+    }
+}
+
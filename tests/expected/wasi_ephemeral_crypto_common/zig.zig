@@ -0,0 +1,559 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+pub const WasiHandle = i32;
+pub const Char8 = u8;
+pub const Char32 = u32;
+pub fn WasiPtr(comptime T: type) type {
+    return [*c]const T;
+}
+pub fn WasiMutPtr(comptime T: type) type {
+    return [*c]T;
+}
+pub const WasiStringBytesPtr = WasiPtr(Char8);
+
+pub const WasiString = extern struct {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+
+    fn from_slice(slice: []const u8) WasiString {
+        return WasiString{ .ptr = slice.ptr, .len = slice.len };
+    }
+
+    fn as_slice(wasi_string: WasiString) []const u8 {
+        return wasi_string.ptr[wasi_string.len];
+    }
+};
+
+pub fn WasiSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []const u8) WasiSlice {
+            return WasiSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiSlice) []const u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+pub fn WasiMutSlice(comptime T: type) type {
+    return extern struct {
+        ptr: WasiMutPtr(T),
+        len: usize,
+
+        fn from_slice(slice: []u8) WasiMutSlice {
+            return WasiMutSlice{ .ptr = slice.ptr, .len = slice.len };
+        }
+
+        fn as_slice(wasi_slice: WasiMutSlice) []u8 {
+            return wasi_slice.ptr[wasi_slice.len];
+        }
+    };
+}
+
+// ---------------------- Module: [wasi_ephemeral_crypto_common] ----------------------
+
+pub const Handle = enum(WasiHandle) { _ };
+
+/// Error codes.
+pub const CryptoErrno = enum(u16) {
+    /// Operation succeeded.
+    SUCCESS = 0,
+    /// An error occurred when trying to during a conversion from a host type to a guest type.
+    ///
+    /// Only an internal bug can throw this error.
+    GUEST_ERROR = 1,
+    /// The requested operation is valid, but not implemented by the host.
+    NOT_IMPLEMENTED = 2,
+    /// The requested feature is not supported by the chosen algorithm.
+    UNSUPPORTED_FEATURE = 3,
+    /// The requested operation is valid, but was administratively prohibited.
+    PROHIBITED_OPERATION = 4,
+    /// Unsupported encoding for an import or export operation.
+    UNSUPPORTED_ENCODING = 5,
+    /// The requested algorithm is not supported by the host.
+    UNSUPPORTED_ALGORITHM = 6,
+    /// The requested option is not supported by the currently selected algorithm.
+    UNSUPPORTED_OPTION = 7,
+    /// An invalid or incompatible key was supplied.
+    ///
+    /// The key may not be valid, or was generated for a different algorithm or parameters set.
+    INVALID_KEY = 8,
+    /// The currently selected algorithm doesn't support the requested output length.
+    ///
+    /// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+    INVALID_LENGTH = 9,
+    /// A signature or authentication tag verification failed.
+    VERIFICATION_FAILED = 10,
+    /// A secure random numbers generator is not available.
+    ///
+    /// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+    RNG_ERROR = 11,
+    /// An error was returned by the underlying cryptography library.
+    ///
+    /// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    ///
+    /// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    ///
+    /// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    /// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+    ALGORITHM_FAILURE = 12,
+    /// The supplied signature is invalid, or incompatible with the chosen algorithm.
+    INVALID_SIGNATURE = 13,
+    /// An attempt was made to close a handle that was already closed.
+    CLOSED = 14,
+    /// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+    INVALID_HANDLE = 15,
+    /// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+    OVERFLOW = 16,
+    /// An internal error occurred.
+    ///
+    /// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+    INTERNAL_ERROR = 17,
+    /// Too many handles are currently open, and a new one cannot be created.
+    ///
+    /// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+    TOO_MANY_HANDLES = 18,
+    /// A key was provided, but the chosen algorithm doesn't support keys.
+    ///
+    /// This is returned by symmetric operations.
+    ///
+    /// Many hash functions, in particular, do not support keys without being used in particular constructions.
+    /// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    ///
+    /// These functions must refuse to create the context and return this error instead.
+    KEY_NOT_SUPPORTED = 19,
+    /// A key is required for the chosen algorithm, but none was given.
+    KEY_REQUIRED = 20,
+    /// The provided authentication tag is invalid or incompatible with the current algorithm.
+    ///
+    /// This error is returned by decryption functions and tag verification functions.
+    ///
+    /// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+    INVALID_TAG = 21,
+    /// The requested operation is incompatible with the current scheme.
+    ///
+    /// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    /// This error code will be returned instead.
+    INVALID_OPERATION = 22,
+    /// A nonce is required.
+    ///
+    /// Most encryption schemes require a nonce.
+    ///
+    /// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    /// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+    NONCE_REQUIRED = 23,
+    /// The provided nonce doesn't have a correct size for the given cipher.
+    INVALID_NONCE = 24,
+    /// The named option was not set.
+    ///
+    /// The caller tried to read the value of an option that was not set.
+    /// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+    OPTION_NOT_SET = 25,
+    /// A key or key pair matching the requested identifier cannot be found using the supplied information.
+    ///
+    /// This error is returned by a secrets manager via the `keypair_from_id()` function.
+    NOT_FOUND = 26,
+    /// The algorithm requires parameters that haven't been set.
+    ///
+    /// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+    PARAMETERS_MISSING = 27,
+    /// A requested computation is not done yet, and additional calls to the function are required.
+    ///
+    /// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    ///
+    /// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+    IN_PROGRESS = 28,
+    /// Multiple keys have been provided, but they do not share the same type.
+    ///
+    /// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+    INCOMPATIBLE_KEYS = 29,
+    /// A managed key or secret expired and cannot be used any more.
+    EXPIRED = 30,
+};
+
+/// Encoding to use for importing or exporting a key pair.
+pub const KeypairEncoding = enum(u16) {
+    /// Raw bytes.
+    RAW = 0,
+    /// PCSK8/DER encoding.
+    PKCS8 = 1,
+    /// PEM encoding.
+    PEM = 2,
+    /// Implementation-defined encoding.
+    LOCAL = 3,
+};
+
+/// Encoding to use for importing or exporting a public key.
+pub const PublickeyEncoding = enum(u16) {
+    /// Raw bytes.
+    RAW = 0,
+    /// PKCS8/DER encoding.
+    PKCS8 = 1,
+    /// PEM encoding.
+    PEM = 2,
+    /// SEC encoding.
+    SEC = 3,
+    /// Compressed SEC encoding.
+    COMPRESSED_SEC = 4,
+    /// Implementation-defined encoding.
+    LOCAL = 5,
+};
+
+/// Encoding to use for importing or exporting a secret key.
+pub const SecretkeyEncoding = enum(u16) {
+    /// Raw bytes.
+    RAW = 0,
+    /// PKCS8/DER encoding.
+    PKCS8 = 1,
+    /// PEM encoding.
+    PEM = 2,
+    /// SEC encoding.
+    SEC = 3,
+    /// Compressed SEC encoding.
+    COMPRESSED_SEC = 4,
+    /// Implementation-defined encoding.
+    LOCAL = 5,
+};
+
+/// Encoding to use for importing or exporting a signature.
+pub const SignatureEncoding = enum(u16) {
+    /// Raw bytes.
+    RAW = 0,
+    /// DER encoding.
+    DER = 1,
+};
+
+/// An algorithm category.
+pub const AlgorithmType = enum(u16) {
+    SIGNATURES = 0,
+    SYMMETRIC = 1,
+    KEY_EXCHANGE = 2,
+};
+
+/// Version of a managed key.
+///
+/// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+pub const Version = u64;
+
+pub const VERSION_UNSPECIFIED: Version = 0xff00000000000000;
+pub const VERSION_LATEST: Version = 0xff00000000000001;
+pub const VERSION_ALL: Version = 0xff00000000000002;
+
+/// Size of a value.
+pub const Size = usize;
+
+/// A UNIX timestamp, in seconds since 01/01/1970.
+pub const Timestamp = u64;
+
+/// A 64-bit value
+pub const U64 = u64;
+
+/// Handle for functions returning output whose size may be large or not known in advance.
+///
+/// An `array_output` object contains a host-allocated byte array.
+///
+/// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+/// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+///
+/// An `array_output` handle is automatically closed after its full content has been consumed.
+pub const ArrayOutput = enum(WasiHandle) { _ };
+
+/// A set of options.
+///
+/// This type is used to set non-default parameters.
+///
+/// The exact set of allowed options depends on the algorithm being used.
+pub const Options = enum(WasiHandle) { _ };
+
+/// A handle to the optional secrets management facilities offered by a host.
+///
+/// This is used to generate, retrieve and invalidate managed keys.
+pub const SecretsManager = enum(WasiHandle) { _ };
+
+/// A key pair.
+pub const Keypair = enum(WasiHandle) { _ };
+
+/// A state to absorb data to be signed.
+///
+/// After a signature has been computed or verified, the state remains valid for further operations.
+///
+/// A subsequent signature would sign all the data accumulated since the creation of the state object.
+pub const SignatureState = enum(WasiHandle) { _ };
+
+/// A signature.
+pub const Signature = enum(WasiHandle) { _ };
+
+/// A public key, for key exchange and signature verification.
+pub const Publickey = enum(WasiHandle) { _ };
+
+/// A secret key, for key exchange mechanisms.
+pub const Secretkey = enum(WasiHandle) { _ };
+
+/// A state to absorb signed data to be verified.
+pub const SignatureVerificationState = enum(WasiHandle) { _ };
+
+/// A state to perform symmetric operations.
+///
+/// The state is not reset nor invalidated after an option has been performed.
+/// Incremental updates and sessions are thus supported.
+pub const SymmetricState = enum(WasiHandle) { _ };
+
+/// A symmetric key.
+///
+/// The key can be imported from raw bytes, or can be a reference to a managed key.
+///
+/// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+pub const SymmetricKey = enum(WasiHandle) { _ };
+
+/// An authentication tag.
+///
+/// This is an object returned by functions computing authentication tags.
+///
+/// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+///
+/// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+///
+/// The host is reponsible for securely wiping them from memory on close.
+pub const SymmetricTag = enum(WasiHandle) { _ };
+
+/// Options index, only required by the Interface Types translation layer.
+pub const OptOptionsU = enum(u8) {
+    SOME = 0,
+    NONE = 1,
+};
+
+/// An optional options set.
+///
+/// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+pub const OptOptions = extern struct {
+    tag: enum(u8) {
+        some = 0,
+        none = 1,
+    },
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    member = extern union {
+        some: Options,
+    },
+
+
+fn newSome(val: Options) OptOptions {
+    return OptOptions{ .tag = .some, .member = .{ .some = val } };
+}
+
+pub fn Some(self: OptOptions) Options {
+    std.debug.assert(self.tag == .some);
+    return self.member.some;
+}
+
+pub fn setSome(self: *OptOptions, val: Options) void {
+    std.debug.assert(self.tag == .some);
+    self.member.some = val;
+}
+
+fn isSome(self: OptOptions) bool {
+    return self.tag == .some;
+}
+
+
+fn newNone() OptOptions {
+    return OptOptions { .tag = .none };
+}
+
+fn isNone(self: OptOptions) bool {
+    return self.tag == .none;
+}
+
+};
+
+
+/// Symmetric key index, only required by the Interface Types translation layer.
+pub const OptSymmetricKeyU = enum(u8) {
+    SOME = 0,
+    NONE = 1,
+};
+
+/// An optional symmetric key.
+///
+/// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+pub const OptSymmetricKey = extern struct {
+    tag: enum(u8) {
+        some = 0,
+        none = 1,
+    },
+    __pad8_0: u8 = undefined,
+    __pad16_0: u16 = undefined,
+    member = extern union {
+        some: SymmetricKey,
+    },
+
+
+fn newSome(val: SymmetricKey) OptSymmetricKey {
+    return OptSymmetricKey{ .tag = .some, .member = .{ .some = val } };
+}
+
+pub fn Some(self: OptSymmetricKey) SymmetricKey {
+    std.debug.assert(self.tag == .some);
+    return self.member.some;
+}
+
+pub fn setSome(self: *OptSymmetricKey, val: SymmetricKey) void {
+    std.debug.assert(self.tag == .some);
+    self.member.some = val;
+}
+
+fn isSome(self: OptSymmetricKey) bool {
+    return self.tag == .some;
+}
+
+
+fn newNone() OptSymmetricKey {
+    return OptSymmetricKey { .tag = .none };
+}
+
+fn isNone(self: OptSymmetricKey) bool {
+    return self.tag == .none;
+}
+
+};
+
+
+pub const WasiEphemeralCryptoCommon = struct {
+    /// Create a new object to set non-default options.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let options_handle = options_open(AlgorithmType::Symmetric)?;
+    /// options_set(options_handle, "context", context)?;
+    /// options_set_u64(options_handle, "threads", 4)?;
+    /// let state = symmetric_state_open("BLAKE3", None, Some(options_handle))?;
+    /// options_close(options_handle)?;
+    /// ```
+    pub extern "wasi_ephemeral_crypto_common" fn options_open(
+        algorithm_type: AlgorithmType,
+        result_ptr: WasiMutPtr(Options),
+    ) callconv(.C) CryptoErrno;
+
+    /// Destroy an options object.
+    ///
+    /// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+    pub extern "wasi_ephemeral_crypto_common" fn options_close(
+        handle: Options,
+    ) callconv(.C) CryptoErrno;
+
+    /// Set or update an option.
+    ///
+    /// This is used to set algorithm-specific parameters, but also to provide credentials for the secrets management facilities, if required.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    pub extern "wasi_ephemeral_crypto_common" fn options_set(
+        handle: Options,
+        name_ptr: WasiPtr(Char8),
+        name_len: usize,
+        value: WasiPtr(u8),
+        value_len: Size,
+    ) callconv(.C) CryptoErrno;
+
+    /// Set or update an integer option.
+    ///
+    /// This is used to set algorithm-specific parameters.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    pub extern "wasi_ephemeral_crypto_common" fn options_set_u64(
+        handle: Options,
+        name_ptr: WasiPtr(Char8),
+        name_len: usize,
+        value: u64,
+    ) callconv(.C) CryptoErrno;
+
+    /// Set or update a guest-allocated memory that the host can use or return data into.
+    ///
+    /// This is for example used to set the scratch buffer required by memory-hard functions.
+    ///
+    /// This function may return `unsupported_option` if an option that doesn't exist for any implemented algorithms is specified.
+    pub extern "wasi_ephemeral_crypto_common" fn options_set_guest_buffer(
+        handle: Options,
+        name_ptr: WasiPtr(Char8),
+        name_len: usize,
+        buffer: WasiMutPtr(u8),
+        buffer_len: Size,
+    ) callconv(.C) CryptoErrno;
+
+    /// Return the length of an `array_output` object.
+    ///
+    /// This allows a guest to allocate a buffer of the correct size in order to copy the output of a function returning this object type.
+    pub extern "wasi_ephemeral_crypto_common" fn array_output_len(
+        array_output: ArrayOutput,
+        result_ptr: WasiMutPtr(Size),
+    ) callconv(.C) CryptoErrno;
+
+    /// Copy the content of an `array_output` object into an application-allocated buffer.
+    ///
+    /// Multiple calls to that function can be made in order to consume the data in a streaming fashion, if necessary.
+    ///
+    /// The function returns the number of bytes that were actually copied. `0` means that the end of the stream has been reached. The total size always matches the output of `array_output_len()`.
+    ///
+    /// The handle is automatically closed after all the data has been consumed.
+    ///
+    /// Example usage:
+    ///
+    /// ```rust
+    /// let len = array_output_len(output_handle)?;
+    /// let mut out = vec![0u8; len];
+    /// array_output_pull(output_handle, &mut out)?;
+    /// ```
+    pub extern "wasi_ephemeral_crypto_common" fn array_output_pull(
+        array_output: ArrayOutput,
+        buf: WasiMutPtr(u8),
+        buf_len: Size,
+        result_ptr: WasiMutPtr(Size),
+    ) callconv(.C) CryptoErrno;
+
+    /// __(optional)__
+    /// Create a context to use a secrets manager.
+    ///
+    /// The set of required and supported options is defined by the host.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+    /// This is also an optional import, meaning that the function may not even exist.
+    pub extern "wasi_ephemeral_crypto_common" fn secrets_manager_open(
+        options: OptOptions,
+        result_ptr: WasiMutPtr(SecretsManager),
+    ) callconv(.C) CryptoErrno;
+
+    /// __(optional)__
+    /// Destroy a secrets manager context.
+    ///
+    /// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host.
+    /// This is also an optional import, meaning that the function may not even exist.
+    pub extern "wasi_ephemeral_crypto_common" fn secrets_manager_close(
+        secrets_manager: SecretsManager,
+    ) callconv(.C) CryptoErrno;
+
+    /// __(optional)__
+    /// Invalidate a managed key or key pair given an identifier and a version.
+    ///
+    /// This asks the secrets manager to delete or revoke a stored key, a specific version of a key.
+    ///
+    /// `key_version` can be set to a version number, to `version.latest` to invalidate the current version, or to `version.all` to invalidate all versions of a key.
+    ///
+    /// The function returns `unsupported_feature` if this operation is not supported by the host, and `not_found` if the identifier and version don't match any existing key.
+    ///
+    /// This is an optional import, meaning that the function may not even exist.
+    pub extern "wasi_ephemeral_crypto_common" fn secrets_manager_invalidate(
+        secrets_manager: SecretsManager,
+        key_id: WasiPtr(u8),
+        key_id_len: Size,
+        key_version: Version,
+    ) callconv(.C) CryptoErrno;
+
+};
+
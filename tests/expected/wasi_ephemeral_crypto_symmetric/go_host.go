@@ -0,0 +1,1512 @@
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+package wasiephemeralcryptosymmetric
+
+import (
+	"context"
+	"errors"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New("invalid guest memory access")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
+
+type Handle WasiHandle
+
+// Error codes.
+type CryptoErrno uint16
+
+const (
+	// Operation succeeded.
+	CryptoErrnoSuccess CryptoErrno = 0
+	// An error occurred when trying to during a conversion from a host type to a guest type.
+	//
+	// Only an internal bug can throw this error.
+	CryptoErrnoGuestError CryptoErrno = 1
+	// The requested operation is valid, but not implemented by the host.
+	CryptoErrnoNotImplemented CryptoErrno = 2
+	// The requested feature is not supported by the chosen algorithm.
+	CryptoErrnoUnsupportedFeature CryptoErrno = 3
+	// The requested operation is valid, but was administratively prohibited.
+	CryptoErrnoProhibitedOperation CryptoErrno = 4
+	// Unsupported encoding for an import or export operation.
+	CryptoErrnoUnsupportedEncoding CryptoErrno = 5
+	// The requested algorithm is not supported by the host.
+	CryptoErrnoUnsupportedAlgorithm CryptoErrno = 6
+	// The requested option is not supported by the currently selected algorithm.
+	CryptoErrnoUnsupportedOption CryptoErrno = 7
+	// An invalid or incompatible key was supplied.
+	//
+	// The key may not be valid, or was generated for a different algorithm or parameters set.
+	CryptoErrnoInvalidKey CryptoErrno = 8
+	// The currently selected algorithm doesn't support the requested output length.
+	//
+	// This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+	CryptoErrnoInvalidLength CryptoErrno = 9
+	// A signature or authentication tag verification failed.
+	CryptoErrnoVerificationFailed CryptoErrno = 10
+	// A secure random numbers generator is not available.
+	//
+	// The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+	CryptoErrnoRngError CryptoErrno = 11
+	// An error was returned by the underlying cryptography library.
+	//
+	// The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+	//
+	// Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+	//
+	// Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+	// This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+	CryptoErrnoAlgorithmFailure CryptoErrno = 12
+	// The supplied signature is invalid, or incompatible with the chosen algorithm.
+	CryptoErrnoInvalidSignature CryptoErrno = 13
+	// An attempt was made to close a handle that was already closed.
+	CryptoErrnoClosed CryptoErrno = 14
+	// A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+	CryptoErrnoInvalidHandle CryptoErrno = 15
+	// The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+	CryptoErrnoOverflow CryptoErrno = 16
+	// An internal error occurred.
+	//
+	// This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+	CryptoErrnoInternalError CryptoErrno = 17
+	// Too many handles are currently open, and a new one cannot be created.
+	//
+	// Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+	CryptoErrnoTooManyHandles CryptoErrno = 18
+	// A key was provided, but the chosen algorithm doesn't support keys.
+	//
+	// This is returned by symmetric operations.
+	//
+	// Many hash functions, in particular, do not support keys without being used in particular constructions.
+	// Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+	//
+	// These functions must refuse to create the context and return this error instead.
+	CryptoErrnoKeyNotSupported CryptoErrno = 19
+	// A key is required for the chosen algorithm, but none was given.
+	CryptoErrnoKeyRequired CryptoErrno = 20
+	// The provided authentication tag is invalid or incompatible with the current algorithm.
+	//
+	// This error is returned by decryption functions and tag verification functions.
+	//
+	// Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+	CryptoErrnoInvalidTag CryptoErrno = 21
+	// The requested operation is incompatible with the current scheme.
+	//
+	// For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+	// This error code will be returned instead.
+	CryptoErrnoInvalidOperation CryptoErrno = 22
+	// A nonce is required.
+	//
+	// Most encryption schemes require a nonce.
+	//
+	// In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+	// If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+	CryptoErrnoNonceRequired CryptoErrno = 23
+	// The provided nonce doesn't have a correct size for the given cipher.
+	CryptoErrnoInvalidNonce CryptoErrno = 24
+	// The named option was not set.
+	//
+	// The caller tried to read the value of an option that was not set.
+	// This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+	CryptoErrnoOptionNotSet CryptoErrno = 25
+	// A key or key pair matching the requested identifier cannot be found using the supplied information.
+	//
+	// This error is returned by a secrets manager via the `keypair_from_id()` function.
+	CryptoErrnoNotFound CryptoErrno = 26
+	// The algorithm requires parameters that haven't been set.
+	//
+	// Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+	CryptoErrnoParametersMissing CryptoErrno = 27
+	// A requested computation is not done yet, and additional calls to the function are required.
+	//
+	// Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+	//
+	// In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+	CryptoErrnoInProgress CryptoErrno = 28
+	// Multiple keys have been provided, but they do not share the same type.
+	//
+	// This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+	CryptoErrnoIncompatibleKeys CryptoErrno = 29
+	// A managed key or secret expired and cannot be used any more.
+	CryptoErrnoExpired CryptoErrno = 30
+)
+
+// Encoding to use for importing or exporting a key pair.
+type KeypairEncoding uint16
+
+const (
+	// Raw bytes.
+	KeypairEncodingRaw KeypairEncoding = 0
+	// PCSK8/DER encoding.
+	KeypairEncodingPkcs8 KeypairEncoding = 1
+	// PEM encoding.
+	KeypairEncodingPem KeypairEncoding = 2
+	// Implementation-defined encoding.
+	KeypairEncodingLocal KeypairEncoding = 3
+)
+
+// Encoding to use for importing or exporting a public key.
+type PublickeyEncoding uint16
+
+const (
+	// Raw bytes.
+	PublickeyEncodingRaw PublickeyEncoding = 0
+	// PKCS8/DER encoding.
+	PublickeyEncodingPkcs8 PublickeyEncoding = 1
+	// PEM encoding.
+	PublickeyEncodingPem PublickeyEncoding = 2
+	// SEC encoding.
+	PublickeyEncodingSec PublickeyEncoding = 3
+	// Compressed SEC encoding.
+	PublickeyEncodingCompressedSec PublickeyEncoding = 4
+	// Implementation-defined encoding.
+	PublickeyEncodingLocal PublickeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a secret key.
+type SecretkeyEncoding uint16
+
+const (
+	// Raw bytes.
+	SecretkeyEncodingRaw SecretkeyEncoding = 0
+	// PKCS8/DER encoding.
+	SecretkeyEncodingPkcs8 SecretkeyEncoding = 1
+	// PEM encoding.
+	SecretkeyEncodingPem SecretkeyEncoding = 2
+	// SEC encoding.
+	SecretkeyEncodingSec SecretkeyEncoding = 3
+	// Compressed SEC encoding.
+	SecretkeyEncodingCompressedSec SecretkeyEncoding = 4
+	// Implementation-defined encoding.
+	SecretkeyEncodingLocal SecretkeyEncoding = 5
+)
+
+// Encoding to use for importing or exporting a signature.
+type SignatureEncoding uint16
+
+const (
+	// Raw bytes.
+	SignatureEncodingRaw SignatureEncoding = 0
+	// DER encoding.
+	SignatureEncodingDer SignatureEncoding = 1
+)
+
+// An algorithm category.
+type AlgorithmType uint16
+
+const (
+	AlgorithmTypeSignatures  AlgorithmType = 0
+	AlgorithmTypeSymmetric   AlgorithmType = 1
+	AlgorithmTypeKeyExchange AlgorithmType = 2
+)
+
+// Version of a managed key.
+//
+// A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+type Version uint64
+
+// Size of a value.
+type Size WasiUSize
+
+// A UNIX timestamp, in seconds since 01/01/1970.
+type Timestamp uint64
+
+// A 64-bit value
+type U64 uint64
+
+// Handle for functions returning output whose size may be large or not known in advance.
+//
+// An `array_output` object contains a host-allocated byte array.
+//
+// A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+// In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+//
+// An `array_output` handle is automatically closed after its full content has been consumed.
+type ArrayOutput WasiHandle
+
+// A set of options.
+//
+// This type is used to set non-default parameters.
+//
+// The exact set of allowed options depends on the algorithm being used.
+type Options WasiHandle
+
+// A handle to the optional secrets management facilities offered by a host.
+//
+// This is used to generate, retrieve and invalidate managed keys.
+type SecretsManager WasiHandle
+
+// A key pair.
+type Keypair WasiHandle
+
+// A state to absorb data to be signed.
+//
+// After a signature has been computed or verified, the state remains valid for further operations.
+//
+// A subsequent signature would sign all the data accumulated since the creation of the state object.
+type SignatureState WasiHandle
+
+// A signature.
+type Signature WasiHandle
+
+// A public key, for key exchange and signature verification.
+type Publickey WasiHandle
+
+// A secret key, for key exchange mechanisms.
+type Secretkey WasiHandle
+
+// A state to absorb signed data to be verified.
+type SignatureVerificationState WasiHandle
+
+// A state to perform symmetric operations.
+//
+// The state is not reset nor invalidated after an option has been performed.
+// Incremental updates and sessions are thus supported.
+type SymmetricState WasiHandle
+
+// A symmetric key.
+//
+// The key can be imported from raw bytes, or can be a reference to a managed key.
+//
+// If it was imported, the host will wipe it from memory as soon as the handle is closed.
+type SymmetricKey WasiHandle
+
+// An authentication tag.
+//
+// This is an object returned by functions computing authentication tags.
+//
+// A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+//
+// This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+//
+// The host is reponsible for securely wiping them from memory on close.
+type SymmetricTag WasiHandle
+
+// Options index, only required by the Interface Types translation layer.
+type OptOptionsU uint8
+
+const (
+	OptOptionsUSome OptOptionsU = 0
+	OptOptionsUNone OptOptionsU = 1
+)
+
+// An optional options set.
+//
+// This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+type OptOptions struct {
+	Tag  uint8
+	Some Options
+}
+
+const (
+	OptOptionsSome uint8 = 0
+	OptOptionsNone uint8 = 1
+)
+
+func (r *memReader) OptOptions(offset uint32) OptOptions {
+	v := OptOptions{Tag: r.u8(offset)}
+	switch v.Tag {
+	case OptOptionsSome:
+		v.Some = Options(r.u32(offset + 4))
+	case OptOptionsNone:
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) OptOptions(offset uint32, v OptOptions) {
+	w.u8(offset, v.Tag)
+	switch v.Tag {
+	case OptOptionsSome:
+		w.u32(offset+4, uint32(v.Some))
+	case OptOptionsNone:
+	default:
+		w.ok = false
+	}
+}
+
+// ReadOptOptions reads a OptOptions from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadOptOptions(mem api.Memory, offset uint32) (OptOptions, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.OptOptions(offset)
+	return v, r.ok
+}
+
+// WriteOptOptions writes a OptOptions to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteOptOptions(mem api.Memory, offset uint32, v OptOptions) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.OptOptions(offset, v)
+	return w.ok
+}
+
+// Symmetric key index, only required by the Interface Types translation layer.
+type OptSymmetricKeyU uint8
+
+const (
+	OptSymmetricKeyUSome OptSymmetricKeyU = 0
+	OptSymmetricKeyUNone OptSymmetricKeyU = 1
+)
+
+// An optional symmetric key.
+//
+// This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+type OptSymmetricKey struct {
+	Tag  uint8
+	Some SymmetricKey
+}
+
+const (
+	OptSymmetricKeySome uint8 = 0
+	OptSymmetricKeyNone uint8 = 1
+)
+
+func (r *memReader) OptSymmetricKey(offset uint32) OptSymmetricKey {
+	v := OptSymmetricKey{Tag: r.u8(offset)}
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		v.Some = SymmetricKey(r.u32(offset + 4))
+	case OptSymmetricKeyNone:
+	default:
+		r.ok = false
+	}
+	return v
+}
+
+func (w *memWriter) OptSymmetricKey(offset uint32, v OptSymmetricKey) {
+	w.u8(offset, v.Tag)
+	switch v.Tag {
+	case OptSymmetricKeySome:
+		w.u32(offset+4, uint32(v.Some))
+	case OptSymmetricKeyNone:
+	default:
+		w.ok = false
+	}
+}
+
+// ReadOptSymmetricKey reads a OptSymmetricKey from guest memory at offset, and returns false if it is out of range or invalid.
+func ReadOptSymmetricKey(mem api.Memory, offset uint32) (OptSymmetricKey, bool) {
+	r := memReader{mem: mem, ok: true}
+	v := r.OptSymmetricKey(offset)
+	return v, r.ok
+}
+
+// WriteOptSymmetricKey writes a OptSymmetricKey to guest memory at offset, and returns false if it is out of range or invalid.
+func WriteOptSymmetricKey(mem api.Memory, offset uint32, v OptSymmetricKey) bool {
+	w := memWriter{mem: mem, ok: true}
+	w.OptSymmetricKey(offset, v)
+	return w.ok
+}
+
+// WasiEphemeralCryptoSymmetricHost is implemented by the host to provide the functions of the `wasi_ephemeral_crypto_symmetric` module.
+// Register an implementation with DefineWasiEphemeralCryptoSymmetricImports.
+// Methods receive the module calling them, whose memory parameters are read from.
+// Functions returning an error code return it last, results only being written if it is zero.
+type WasiEphemeralCryptoSymmetricHost interface {
+	// Generate a new symmetric key for a given algorithm.
+	//
+	// `options` can be `None` to use the default parameters, or an algoritm-specific set of parameters to override.
+	//
+	// This function may return `unsupported_feature` if key generation is not supported by the host for the chosen algorithm, or `unsupported_algorithm` if the algorithm is not supported by the host.
+	SymmetricKeyGenerate(ctx context.Context, mod api.Module, algorithm string, options OptOptions) (SymmetricKey, CryptoErrno)
+
+	// Create a symmetric key from raw material.
+	//
+	// The algorithm is internally stored along with the key, and trying to use the key with an operation expecting a different algorithm will return `invalid_key`.
+	//
+	// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+	SymmetricKeyImport(ctx context.Context, mod api.Module, algorithm string, raw WasiPtr, rawLen Size) (SymmetricKey, CryptoErrno)
+
+	// Export a symmetric key as raw material.
+	//
+	// This is mainly useful to export a managed key.
+	//
+	// May return `prohibited_operation` if this operation is denied.
+	SymmetricKeyExport(ctx context.Context, mod api.Module, symmetricKey SymmetricKey) (ArrayOutput, CryptoErrno)
+
+	// Destroy a symmetric key.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	SymmetricKeyClose(ctx context.Context, mod api.Module, symmetricKey SymmetricKey) CryptoErrno
+
+	// __(optional)__
+	// Generate a new managed symmetric key.
+	//
+	// The key is generated and stored by the secrets management facilities.
+	//
+	// It may be used through its identifier, but the host may not allow it to be exported.
+	//
+	// The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+	// or `unsupported_algorithm` if a key cannot be created for the chosen algorithm.
+	//
+	// The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+	//
+	// This is also an optional import, meaning that the function may not even exist.
+	SymmetricKeyGenerateManaged(ctx context.Context, mod api.Module, secretsManager SecretsManager, algorithm string, options OptOptions) (SymmetricKey, CryptoErrno)
+
+	// __(optional)__
+	// Store a symmetric key into the secrets manager.
+	//
+	// On success, the function stores the key identifier into `$symmetric_key_id`,
+	// into which up to `$symmetric_key_id_max_len` can be written.
+	//
+	// The function returns `overflow` if the supplied buffer is too small.
+	SymmetricKeyStoreManaged(ctx context.Context, mod api.Module, secretsManager SecretsManager, symmetricKey SymmetricKey, symmetricKeyId WasiPtr, symmetricKeyIdMaxLen Size) CryptoErrno
+
+	// __(optional)__
+	// Replace a managed symmetric key.
+	//
+	// This function crates a new version of a managed symmetric key, by replacing `$kp_old` with `$kp_new`.
+	//
+	// It does several things:
+	//
+	// - The key identifier for `$symmetric_key_new` is set to the one of `$symmetric_key_old`.
+	// - A new, unique version identifier is assigned to `$kp_new`. This version will be equivalent to using `$version_latest` until the key is replaced.
+	// - The `$symmetric_key_old` handle is closed.
+	//
+	// Both keys must share the same algorithm and have compatible parameters. If this is not the case, `incompatible_keys` is returned.
+	//
+	// The function may also return the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+	// or if keys cannot be rotated.
+	//
+	// Finally, `prohibited_operation` can be returned if `$symmetric_key_new` wasn't created by the secrets manager, and the secrets manager prohibits imported keys.
+	//
+	// If the operation succeeded, the new version is returned.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SymmetricKeyReplaceManaged(ctx context.Context, mod api.Module, secretsManager SecretsManager, symmetricKeyOld SymmetricKey, symmetricKeyNew SymmetricKey) (Version, CryptoErrno)
+
+	// __(optional)__
+	// Return the key identifier and version of a managed symmetric key.
+	//
+	// If the key is not managed, `unsupported_feature` is returned instead.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SymmetricKeyId(ctx context.Context, mod api.Module, symmetricKey SymmetricKey, symmetricKeyId WasiPtr, symmetricKeyIdMaxLen Size) (Size, Version, CryptoErrno)
+
+	// __(optional)__
+	// Return a managed symmetric key from a key identifier.
+	//
+	// `kp_version` can be set to `version_latest` to retrieve the most recent version of a symmetric key.
+	//
+	// If no key matching the provided information is found, `not_found` is returned instead.
+	//
+	// This is an optional import, meaning that the function may not even exist.
+	SymmetricKeyFromId(ctx context.Context, mod api.Module, secretsManager SecretsManager, symmetricKeyId WasiPtr, symmetricKeyIdLen Size, symmetricKeyVersion Version) (SymmetricKey, CryptoErrno)
+
+	// Create a new state to aborb and produce data using symmetric operations.
+	//
+	// The state remains valid after every operation in order to support incremental updates.
+	//
+	// The function has two optional parameters: a key and an options set.
+	//
+	// It will fail with a `key_not_supported` error code if a key was provided but the chosen algorithm doesn't natively support keying.
+	//
+	// On the other hand, if a key is required, but was not provided, a `key_required` error will be thrown.
+	//
+	// Some algorithms may require additional parameters. They have to be supplied as an options set:
+	//
+	// ```rust
+	// let options_handle = ctx.options_open()?;
+	// ctx.options_set("context", b"My application")?;
+	// ctx.options_set_u64("fanout", 16)?;
+	// let state_handle = ctx.symmetric_state_open("BLAKE2b-512", None, Some(options_handle))?;
+	// ```
+	//
+	// If some parameters are mandatory but were not set, the `parameters_missing` error code will be returned.
+	//
+	// A notable exception is the `nonce` parameter, that is common to most AEAD constructions.
+	//
+	// If a nonce is required but was not supplied:
+	//
+	// - If it is safe to do so, the host will automatically generate a nonce. This is true for nonces that are large enough to be randomly generated, or if the host is able to maintain a global counter.
+	// - If not, the function will fail and return the dedicated `nonce_required` error code.
+	//
+	// A nonce that was automatically generated can be retrieved after the function returns with `symmetric_state_get(state_handle, "nonce")`.
+	//
+	// **Sample usage patterns:**
+	//
+	// - **Hashing**
+	//
+	// ```rust
+	// let mut out = [0u8; 64];
+	// let state_handle = ctx.symmetric_state_open("SHAKE-128", None, None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+	// ```
+	//
+	// - **MAC**
+	//
+	// ```rust
+	// let mut raw_tag = [0u8; 64];
+	// let key_handle = ctx.symmetric_key_import("HMAC/SHA-512", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+	// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// ctx.symmetric_tag_pull(computed_tag_handle, &mut raw_tag)?;
+	// ```
+	//
+	// Verification:
+	//
+	// ```rust
+	// let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+	// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+	// ```
+	//
+	// - **Tuple hashing**
+	//
+	// ```rust
+	// let mut out = [0u8; 64];
+	// let state_handle = ctx.symmetric_state_open("TupleHashXOF256", None, None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"value 1")?;
+	// ctx.symmetric_state_absorb(state_handle, b"value 2")?;
+	// ctx.symmetric_state_absorb(state_handle, b"value 3")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+	// ```
+	// Unlike MACs and regular hash functions, inputs are domain separated instead of being concatenated.
+	//
+	// - **Key derivation using extract-and-expand**
+	//
+	// Extract:
+	//
+	// ```rust
+	// let mut prk = vec![0u8; 64];
+	// let key_handle = ctx.symmetric_key_import("HKDF-EXTRACT/SHA-512", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("HKDF-EXTRACT/SHA-512", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"salt")?;
+	// let prk_handle = ctx.symmetric_state_squeeze_key(state_handle, "HKDF-EXPAND/SHA-512")?;
+	// ```
+	//
+	// Expand:
+	//
+	// ```rust
+	// let mut subkey = vec![0u8; 32];
+	// let state_handle = ctx.symmetric_state_open("HKDF-EXPAND/SHA-512", Some(prk_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"info")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut subkey)?;
+	// ```
+	//
+	// - **Key derivation using a XOF**
+	//
+	// ```rust
+	// let mut subkey1 = vec![0u8; 32];
+	// let mut subkey2 = vec![0u8; 32];
+	// let key_handle = ctx.symmetric_key_import("BLAKE3", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("BLAKE3", Some(key_handle), None)?;
+	// ctx.symmetric_absorb(state_handle, b"context")?;
+	// ctx.squeeze(state_handle, &mut subkey1)?;
+	// ctx.squeeze(state_handle, &mut subkey2)?;
+	// ```
+	//
+	// - **Password hashing**
+	//
+	// ```rust
+	// let mut memory = vec![0u8; 1_000_000_000];
+	// let options_handle = ctx.symmetric_options_open()?;
+	// ctx.symmetric_options_set_guest_buffer(options_handle, "memory", &mut memory)?;
+	// ctx.symmetric_options_set_u64(options_handle, "opslimit", 5)?;
+	// ctx.symmetric_options_set_u64(options_handle, "parallelism", 8)?;
+	//
+	// let state_handle = ctx.symmetric_state_open("ARGON2-ID-13", None, Some(options))?;
+	// ctx.symmtric_state_absorb(state_handle, b"password")?;
+	//
+	// let pw_str_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// let mut pw_str = vec![0u8; ctx.symmetric_tag_len(pw_str_handle)?];
+	// ctx.symmetric_tag_pull(pw_str_handle, &mut pw_str)?;
+	// ```
+	//
+	// - **AEAD encryption with an explicit nonce**
+	//
+	// ```rust
+	// let key_handle = ctx.symmetric_key_generate("AES-256-GCM", None)?;
+	// let message = b"test";
+	//
+	// let options_handle = ctx.symmetric_options_open()?;
+	// ctx.symmetric_options_set(options_handle, "nonce", nonce)?;
+	//
+	// let state_handle = ctx.symmetric_state_open("AES-256-GCM", Some(key_handle), Some(options_handle))?;
+	// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+	// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+	// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+	// ```
+	//
+	// - **AEAD encryption with automatic nonce generation**
+	//
+	// ```rust
+	// let key_handle = ctx.symmetric_key_generate("AES-256-GCM-SIV", None)?;
+	// let message = b"test";
+	// let mut nonce = [0u8; 24];
+	//
+	// let state_handle = ctx.symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), None)?;
+	//
+	// let nonce_handle = ctx.symmetric_state_options_get(state_handle, "nonce")?;
+	// ctx.array_output_pull(nonce_handle, &mut nonce)?;
+	//
+	// let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+	// ctx.symmetric_state_absorb(state_handle, "additional data")?;
+	// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+	// ```
+	//
+	// - **Session authenticated modes**
+	//
+	// ```rust
+	// let mut out = [0u8; 16];
+	// let mut out2 = [0u8; 16];
+	// let mut ciphertext = [0u8; 20];
+	// let key_handle = ctx.symmetric_key_generate("Xoodyak-128", None)?;
+	// let state_handle = ctx.symmetric_state_open("Xoodyak-128", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, b"abcd")?;
+	// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+	// ctx.symmetric_state_squeeze(state_handle, &mut out2)?;
+	// ctx.symmetric_state_ratchet(state_handle)?;
+	// ctx.symmetric_state_absorb(state_handle, b"more data")?;
+	// let next_key_handle = ctx.symmetric_state_squeeze_key(state_handle, "Xoodyak-128")?;
+	// // ...
+	// ```
+	SymmetricStateOpen(ctx context.Context, mod api.Module, algorithm string, key OptSymmetricKey, options OptOptions) (SymmetricState, CryptoErrno)
+
+	// Retrieve a parameter from the current state.
+	//
+	// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+	//
+	// The function may return `options_not_set` if an option was not set, which is different from an empty value.
+	//
+	// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+	SymmetricStateOptionsGet(ctx context.Context, mod api.Module, handle SymmetricState, name string, value WasiPtr, valueMaxLen Size) (Size, CryptoErrno)
+
+	// Retrieve an integer parameter from the current state.
+	//
+	// In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+	//
+	// The function may return `options_not_set` if an option was not set.
+	//
+	// It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+	SymmetricStateOptionsGetU64(ctx context.Context, mod api.Module, handle SymmetricState, name string) (U64, CryptoErrno)
+
+	// Destroy a symmetric state.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	SymmetricStateClose(ctx context.Context, mod api.Module, handle SymmetricState) CryptoErrno
+
+	// Absorb data into the state.
+	//
+	// - **Hash functions:** adds data to be hashed.
+	// - **MAC functions:** adds data to be authenticated.
+	// - **Tuplehash-like constructions:** adds a new tuple to the state.
+	// - **Key derivation functions:** adds to the IKM or to the subkey information.
+	// - **AEAD constructions:** adds additional data to be authenticated.
+	// - **Stateful hash objects, permutation-based constructions:** absorbs.
+	//
+	// If the chosen algorithm doesn't accept input data, the `invalid_operation` error code is returned.
+	//
+	// If too much data has been fed for the algorithm, `overflow` may be thrown.
+	SymmetricStateAbsorb(ctx context.Context, mod api.Module, handle SymmetricState, data WasiPtr, dataLen Size) CryptoErrno
+
+	// Squeeze bytes from the state.
+	//
+	// - **Hash functions:** this tries to output an `out_len` bytes digest from the absorbed data. The hash function output will be truncated if necessary. If the requested size is too large, the `invalid_len` error code is returned.
+	// - **Key derivation functions:** : outputs an arbitrary-long derived key.
+	// - **RNGs, DRBGs, stream ciphers:**: outputs arbitrary-long data.
+	// - **Stateful hash objects, permutation-based constructions:** squeeze.
+	//
+	// Other kinds of algorithms may return `invalid_operation` instead.
+	//
+	// For password-stretching functions, the function may return `in_progress`.
+	// In that case, the guest should retry with the same parameters until the function completes.
+	SymmetricStateSqueeze(ctx context.Context, mod api.Module, handle SymmetricState, out WasiPtr, outLen Size) CryptoErrno
+
+	// Compute and return a tag for all the data injected into the state so far.
+	//
+	// - **MAC functions**: returns a tag authenticating the absorbed data.
+	// - **Tuplehash-like constructions:** returns a tag authenticating all the absorbed tuples.
+	// - **Password-hashing functions:** returns a standard string containing all the required parameters for password verification.
+	//
+	// Other kinds of algorithms may return `invalid_operation` instead.
+	//
+	// For password-stretching functions, the function may return `in_progress`.
+	// In that case, the guest should retry with the same parameters until the function completes.
+	SymmetricStateSqueezeTag(ctx context.Context, mod api.Module, handle SymmetricState) (SymmetricTag, CryptoErrno)
+
+	// Use the current state to produce a key for a target algorithm.
+	//
+	// For extract-then-expand constructions, this returns the PRK.
+	// For session-base authentication encryption, this returns a key that can be used to resume a session without storing a nonce.
+	//
+	// `invalid_operation` is returned for algorithms not supporting this operation.
+	SymmetricStateSqueezeKey(ctx context.Context, mod api.Module, handle SymmetricState, algStr string) (SymmetricKey, CryptoErrno)
+
+	// Return the maximum length of an authentication tag for the current algorithm.
+	//
+	// This allows guests to compute the size required to store a ciphertext along with its authentication tag.
+	//
+	// The returned length may include the encryption mode's padding requirements in addition to the actual tag.
+	//
+	// For an encryption operation, the size of the output buffer should be `input_len + symmetric_state_max_tag_len()`.
+	//
+	// For a decryption operation, the size of the buffer that will store the decrypted data must be `ciphertext_len - symmetric_state_max_tag_len()`.
+	SymmetricStateMaxTagLen(ctx context.Context, mod api.Module, handle SymmetricState) (Size, CryptoErrno)
+
+	// Encrypt data with an attached tag.
+	//
+	// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+	// - **AEAD:** encrypts `data` into `out`, including the authentication tag to the output. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The `symmetric_state_max_tag_len()` function can be used to retrieve the overhead of adding the tag, as well as padding if necessary.
+	// - **SHOE, Xoodyak, Strobe:** encrypts data, squeezes a tag and appends it to the output.
+	//
+	// If `out` and `data` are the same address, encryption may happen in-place.
+	//
+	// The function returns the actual size of the ciphertext along with the tag.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateEncrypt(ctx context.Context, mod api.Module, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size) (Size, CryptoErrno)
+
+	// Encrypt data, with a detached tag.
+	//
+	// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+	// - **AEAD:** encrypts `data` into `out` and returns the tag separately. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The output and input buffers must be of the same length.
+	// - **SHOE, Xoodyak, Strobe:** encrypts data and squeezes a tag.
+	//
+	// If `out` and `data` are the same address, encryption may happen in-place.
+	//
+	// The function returns the tag.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateEncryptDetached(ctx context.Context, mod api.Module, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size) (SymmetricTag, CryptoErrno)
+
+	// - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+	// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+	// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the one that was appended to the ciphertext.
+	//
+	// If `out` and `data` are the same address, decryption may happen in-place.
+	//
+	// `out_len` must be exactly `data_len` + `max_tag_len` bytes.
+	//
+	// The function returns the actual size of the decrypted message, which can be smaller than `out_len` for modes that requires padding.
+	//
+	// `invalid_tag` is returned if the tag didn't verify.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateDecrypt(ctx context.Context, mod api.Module, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size) (Size, CryptoErrno)
+
+	// - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+	// - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+	// - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the expected one.
+	//
+	// `raw_tag` is the expected tag, as raw bytes.
+	//
+	// `out` and `data` be must have the same length.
+	// If they also share the same address, decryption may happen in-place.
+	//
+	// The function returns the actual size of the decrypted message.
+	//
+	// `invalid_tag` is returned if the tag verification failed.
+	//
+	// `invalid_operation` is returned for algorithms not supporting encryption.
+	SymmetricStateDecryptDetached(ctx context.Context, mod api.Module, handle SymmetricState, out WasiPtr, outLen Size, data WasiPtr, dataLen Size, rawTag WasiPtr, rawTagLen Size) (Size, CryptoErrno)
+
+	// Make it impossible to recover the previous state.
+	//
+	// This operation is supported by some systems keeping a rolling state over an entire session, for forward security.
+	//
+	// `invalid_operation` is returned for algorithms not supporting ratcheting.
+	SymmetricStateRatchet(ctx context.Context, mod api.Module, handle SymmetricState) CryptoErrno
+
+	// Return the length of an authentication tag.
+	//
+	// This function can be used by a guest to allocate the correct buffer size to copy a computed authentication tag.
+	SymmetricTagLen(ctx context.Context, mod api.Module, symmetricTag SymmetricTag) (Size, CryptoErrno)
+
+	// Copy an authentication tag into a guest-allocated buffer.
+	//
+	// The handle automatically becomes invalid after this operation. Manually closing it is not required.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let mut raw_tag = [0u8; 16];
+	// ctx.symmetric_tag_pull(raw_tag_handle, &mut raw_tag)?;
+	// ```
+	//
+	// The function returns `overflow` if the supplied buffer is too small to copy the tag.
+	//
+	// Otherwise, it returns the number of bytes that have been copied.
+	SymmetricTagPull(ctx context.Context, mod api.Module, symmetricTag SymmetricTag, buf WasiPtr, bufLen Size) (Size, CryptoErrno)
+
+	// Verify that a computed authentication tag matches the expected value, in constant-time.
+	//
+	// The expected tag must be provided as a raw byte string.
+	//
+	// The function returns `invalid_tag` if the tags don't match.
+	//
+	// Example usage:
+	//
+	// ```rust
+	// let key_handle = ctx.symmetric_key_import("HMAC/SHA-256", b"key")?;
+	// let state_handle = ctx.symmetric_state_open("HMAC/SHA-256", Some(key_handle), None)?;
+	// ctx.symmetric_state_absorb(state_handle, b"data")?;
+	// let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+	// ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+	// ```
+	SymmetricTagVerify(ctx context.Context, mod api.Module, symmetricTag SymmetricTag, expectedRawTagPtr WasiPtr, expectedRawTagLen Size) CryptoErrno
+
+	// Explicitly destroy an unused authentication tag.
+	//
+	// This is usually not necessary, as `symmetric_tag_pull()` automatically closes a tag after it has been copied.
+	//
+	// Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+	SymmetricTagClose(ctx context.Context, mod api.Module, symmetricTag SymmetricTag) CryptoErrno
+}
+
+// DefineWasiEphemeralCryptoSymmetricImports returns a builder for the `wasi_ephemeral_crypto_symmetric` host module, whose functions call host.
+// Instantiate it before the guest modules importing them.
+func DefineWasiEphemeralCryptoSymmetricImports(runtime wazero.Runtime, host WasiEphemeralCryptoSymmetricHost) wazero.HostModuleBuilder {
+	builder := runtime.NewHostModuleBuilder("wasi_ephemeral_crypto_symmetric")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			algorithmPtr := api.DecodeU32(stack[0])
+			algorithmLen := api.DecodeU32(stack[1])
+			algorithm := string(r.bytes(algorithmPtr, algorithmLen))
+			options := r.OptOptions(api.DecodeU32(stack[2]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[3])
+			result, errno := host.SymmetricKeyGenerate(ctx, mod, algorithm, options)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_ptr", "algorithm_len", "options", "result_ptr").
+		Export("symmetric_key_generate")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			algorithmPtr := api.DecodeU32(stack[0])
+			algorithmLen := api.DecodeU32(stack[1])
+			algorithm := string(r.bytes(algorithmPtr, algorithmLen))
+			raw := api.DecodeU32(stack[2])
+			rawLen := Size(api.DecodeU32(stack[3]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[4])
+			result, errno := host.SymmetricKeyImport(ctx, mod, algorithm, raw, rawLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_ptr", "algorithm_len", "raw", "raw_len", "result_ptr").
+		Export("symmetric_key_import")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			symmetricKey := SymmetricKey(api.DecodeU32(stack[0]))
+			resultPtr := api.DecodeU32(stack[1])
+			result, errno := host.SymmetricKeyExport(ctx, mod, symmetricKey)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_key", "result_ptr").
+		Export("symmetric_key_export")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			symmetricKey := SymmetricKey(api.DecodeU32(stack[0]))
+			errno := host.SymmetricKeyClose(ctx, mod, symmetricKey)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_key").
+		Export("symmetric_key_close")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			secretsManager := SecretsManager(api.DecodeU32(stack[0]))
+			algorithmPtr := api.DecodeU32(stack[1])
+			algorithmLen := api.DecodeU32(stack[2])
+			algorithm := string(r.bytes(algorithmPtr, algorithmLen))
+			options := r.OptOptions(api.DecodeU32(stack[3]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[4])
+			result, errno := host.SymmetricKeyGenerateManaged(ctx, mod, secretsManager, algorithm, options)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "algorithm_ptr", "algorithm_len", "options", "result_ptr").
+		Export("symmetric_key_generate_managed")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			secretsManager := SecretsManager(api.DecodeU32(stack[0]))
+			symmetricKey := SymmetricKey(api.DecodeU32(stack[1]))
+			symmetricKeyId := api.DecodeU32(stack[2])
+			symmetricKeyIdMaxLen := Size(api.DecodeU32(stack[3]))
+			errno := host.SymmetricKeyStoreManaged(ctx, mod, secretsManager, symmetricKey, symmetricKeyId, symmetricKeyIdMaxLen)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "symmetric_key", "symmetric_key_id", "symmetric_key_id_max_len").
+		Export("symmetric_key_store_managed")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			secretsManager := SecretsManager(api.DecodeU32(stack[0]))
+			symmetricKeyOld := SymmetricKey(api.DecodeU32(stack[1]))
+			symmetricKeyNew := SymmetricKey(api.DecodeU32(stack[2]))
+			resultPtr := api.DecodeU32(stack[3])
+			result, errno := host.SymmetricKeyReplaceManaged(ctx, mod, secretsManager, symmetricKeyOld, symmetricKeyNew)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u64(resultPtr, uint64(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "symmetric_key_old", "symmetric_key_new", "result_ptr").
+		Export("symmetric_key_replace_managed")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			symmetricKey := SymmetricKey(api.DecodeU32(stack[0]))
+			symmetricKeyId := api.DecodeU32(stack[1])
+			symmetricKeyIdMaxLen := Size(api.DecodeU32(stack[2]))
+			result0Ptr := api.DecodeU32(stack[3])
+			result1Ptr := api.DecodeU32(stack[4])
+			result0, result1, errno := host.SymmetricKeyId(ctx, mod, symmetricKey, symmetricKeyId, symmetricKeyIdMaxLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(result0Ptr, uint32(result0))
+				w.u64(result1Ptr, uint64(result1))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_key", "symmetric_key_id", "symmetric_key_id_max_len", "result0_ptr", "result1_ptr").
+		Export("symmetric_key_id")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			secretsManager := SecretsManager(api.DecodeU32(stack[0]))
+			symmetricKeyId := api.DecodeU32(stack[1])
+			symmetricKeyIdLen := Size(api.DecodeU32(stack[2]))
+			symmetricKeyVersion := Version(stack[3])
+			resultPtr := api.DecodeU32(stack[4])
+			result, errno := host.SymmetricKeyFromId(ctx, mod, secretsManager, symmetricKeyId, symmetricKeyIdLen, symmetricKeyVersion)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI64, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("secrets_manager", "symmetric_key_id", "symmetric_key_id_len", "symmetric_key_version", "result_ptr").
+		Export("symmetric_key_from_id")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			algorithmPtr := api.DecodeU32(stack[0])
+			algorithmLen := api.DecodeU32(stack[1])
+			algorithm := string(r.bytes(algorithmPtr, algorithmLen))
+			key := r.OptSymmetricKey(api.DecodeU32(stack[2]))
+			options := r.OptOptions(api.DecodeU32(stack[3]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[4])
+			result, errno := host.SymmetricStateOpen(ctx, mod, algorithm, key, options)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("algorithm_ptr", "algorithm_len", "key", "options", "result_ptr").
+		Export("symmetric_state_open")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			namePtr := api.DecodeU32(stack[1])
+			nameLen := api.DecodeU32(stack[2])
+			name := string(r.bytes(namePtr, nameLen))
+			value := api.DecodeU32(stack[3])
+			valueMaxLen := Size(api.DecodeU32(stack[4]))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[5])
+			result, errno := host.SymmetricStateOptionsGet(ctx, mod, handle, name, value, valueMaxLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "value", "value_max_len", "result_ptr").
+		Export("symmetric_state_options_get")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			namePtr := api.DecodeU32(stack[1])
+			nameLen := api.DecodeU32(stack[2])
+			name := string(r.bytes(namePtr, nameLen))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[3])
+			result, errno := host.SymmetricStateOptionsGetU64(ctx, mod, handle, name)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u64(resultPtr, uint64(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "name_ptr", "name_len", "result_ptr").
+		Export("symmetric_state_options_get_u64")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			errno := host.SymmetricStateClose(ctx, mod, handle)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle").
+		Export("symmetric_state_close")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			data := api.DecodeU32(stack[1])
+			dataLen := Size(api.DecodeU32(stack[2]))
+			errno := host.SymmetricStateAbsorb(ctx, mod, handle, data, dataLen)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "data", "data_len").
+		Export("symmetric_state_absorb")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			out := api.DecodeU32(stack[1])
+			outLen := Size(api.DecodeU32(stack[2]))
+			errno := host.SymmetricStateSqueeze(ctx, mod, handle, out, outLen)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len").
+		Export("symmetric_state_squeeze")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			resultPtr := api.DecodeU32(stack[1])
+			result, errno := host.SymmetricStateSqueezeTag(ctx, mod, handle)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "result_ptr").
+		Export("symmetric_state_squeeze_tag")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			algStrPtr := api.DecodeU32(stack[1])
+			algStrLen := api.DecodeU32(stack[2])
+			algStr := string(r.bytes(algStrPtr, algStrLen))
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[3])
+			result, errno := host.SymmetricStateSqueezeKey(ctx, mod, handle, algStr)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "alg_str_ptr", "alg_str_len", "result_ptr").
+		Export("symmetric_state_squeeze_key")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			resultPtr := api.DecodeU32(stack[1])
+			result, errno := host.SymmetricStateMaxTagLen(ctx, mod, handle)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "result_ptr").
+		Export("symmetric_state_max_tag_len")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			out := api.DecodeU32(stack[1])
+			outLen := Size(api.DecodeU32(stack[2]))
+			data := api.DecodeU32(stack[3])
+			dataLen := Size(api.DecodeU32(stack[4]))
+			resultPtr := api.DecodeU32(stack[5])
+			result, errno := host.SymmetricStateEncrypt(ctx, mod, handle, out, outLen, data, dataLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "result_ptr").
+		Export("symmetric_state_encrypt")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			out := api.DecodeU32(stack[1])
+			outLen := Size(api.DecodeU32(stack[2]))
+			data := api.DecodeU32(stack[3])
+			dataLen := Size(api.DecodeU32(stack[4]))
+			resultPtr := api.DecodeU32(stack[5])
+			result, errno := host.SymmetricStateEncryptDetached(ctx, mod, handle, out, outLen, data, dataLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "result_ptr").
+		Export("symmetric_state_encrypt_detached")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			out := api.DecodeU32(stack[1])
+			outLen := Size(api.DecodeU32(stack[2]))
+			data := api.DecodeU32(stack[3])
+			dataLen := Size(api.DecodeU32(stack[4]))
+			resultPtr := api.DecodeU32(stack[5])
+			result, errno := host.SymmetricStateDecrypt(ctx, mod, handle, out, outLen, data, dataLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "result_ptr").
+		Export("symmetric_state_decrypt")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			out := api.DecodeU32(stack[1])
+			outLen := Size(api.DecodeU32(stack[2]))
+			data := api.DecodeU32(stack[3])
+			dataLen := Size(api.DecodeU32(stack[4]))
+			rawTag := api.DecodeU32(stack[5])
+			rawTagLen := Size(api.DecodeU32(stack[6]))
+			resultPtr := api.DecodeU32(stack[7])
+			result, errno := host.SymmetricStateDecryptDetached(ctx, mod, handle, out, outLen, data, dataLen, rawTag, rawTagLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle", "out", "out_len", "data", "data_len", "raw_tag", "raw_tag_len", "result_ptr").
+		Export("symmetric_state_decrypt_detached")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			handle := SymmetricState(api.DecodeU32(stack[0]))
+			errno := host.SymmetricStateRatchet(ctx, mod, handle)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("handle").
+		Export("symmetric_state_ratchet")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			symmetricTag := SymmetricTag(api.DecodeU32(stack[0]))
+			resultPtr := api.DecodeU32(stack[1])
+			result, errno := host.SymmetricTagLen(ctx, mod, symmetricTag)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag", "result_ptr").
+		Export("symmetric_tag_len")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			symmetricTag := SymmetricTag(api.DecodeU32(stack[0]))
+			buf := api.DecodeU32(stack[1])
+			bufLen := Size(api.DecodeU32(stack[2]))
+			resultPtr := api.DecodeU32(stack[3])
+			result, errno := host.SymmetricTagPull(ctx, mod, symmetricTag, buf, bufLen)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag", "buf", "buf_len", "result_ptr").
+		Export("symmetric_tag_pull")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			symmetricTag := SymmetricTag(api.DecodeU32(stack[0]))
+			expectedRawTagPtr := api.DecodeU32(stack[1])
+			expectedRawTagLen := Size(api.DecodeU32(stack[2]))
+			errno := host.SymmetricTagVerify(ctx, mod, symmetricTag, expectedRawTagPtr, expectedRawTagLen)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag", "expected_raw_tag_ptr", "expected_raw_tag_len").
+		Export("symmetric_tag_verify")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			symmetricTag := SymmetricTag(api.DecodeU32(stack[0]))
+			errno := host.SymmetricTagClose(ctx, mod, symmetricTag)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("symmetric_tag").
+		Export("symmetric_tag_close")
+	return builder
+}
@@ -0,0 +1,1420 @@
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    """A string in guest memory."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    """A slice in guest memory, `len` being its number of elements."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host function to return the error code `code` to the guest."""
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    """Memory exported by the guest instance calling a host function."""
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get("memory")
+            if self._memory is None:
+                raise RuntimeError("The guest doesn't export its memory")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        """Return the `length` bytes at address `ptr`."""
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        """Write `data` at address `ptr`."""
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        """Return the UTF-8 string of `length` bytes at address `ptr`."""
+        return self.read(ptr, length).decode("utf-8")
+
+    def read_value(self, ctype, ptr: int):
+        """Return a copy of the value of type `ctype` at address `ptr`."""
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        """Return a copy of the `length` values of type `ctype` at address `ptr`."""
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        """Write `value`, converted to `ctype` if necessary, at address `ptr`."""
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    """A buffer of `size` bytes in guest memory, to be filled by a host function."""
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        """Write `data` to the buffer, `offset` bytes after its start."""
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        self.memory.write(self.ptr + offset, data)
+
+
+# ---------------------- Module: [wasi_ephemeral_crypto_symmetric] ----------------------
+
+Handle = typing.NewType("Handle", int)
+
+
+class CryptoErrno(enum.IntEnum):
+    """Error codes."""
+
+    # Operation succeeded.
+    SUCCESS = 0
+    # An error occurred when trying to during a conversion from a host type to a guest type.
+    #
+    # Only an internal bug can throw this error.
+    GUEST_ERROR = 1
+    # The requested operation is valid, but not implemented by the host.
+    NOT_IMPLEMENTED = 2
+    # The requested feature is not supported by the chosen algorithm.
+    UNSUPPORTED_FEATURE = 3
+    # The requested operation is valid, but was administratively prohibited.
+    PROHIBITED_OPERATION = 4
+    # Unsupported encoding for an import or export operation.
+    UNSUPPORTED_ENCODING = 5
+    # The requested algorithm is not supported by the host.
+    UNSUPPORTED_ALGORITHM = 6
+    # The requested option is not supported by the currently selected algorithm.
+    UNSUPPORTED_OPTION = 7
+    # An invalid or incompatible key was supplied.
+    #
+    # The key may not be valid, or was generated for a different algorithm or parameters set.
+    INVALID_KEY = 8
+    # The currently selected algorithm doesn't support the requested output length.
+    #
+    # This error is thrown by non-extensible hash functions, when requesting an output size larger than they produce out of a single block.
+    INVALID_LENGTH = 9
+    # A signature or authentication tag verification failed.
+    VERIFICATION_FAILED = 10
+    # A secure random numbers generator is not available.
+    #
+    # The requested operation requires random numbers, but the host cannot securely generate them at the moment.
+    RNG_ERROR = 11
+    # An error was returned by the underlying cryptography library.
+    #
+    # The host may be running out of memory, parameters may be incompatible with the chosen implementation of an algorithm or another unexpected error may have happened.
+    #
+    # Ideally, the specification should provide enough details and guidance to make this error impossible to ever be thrown.
+    #
+    # Realistically, the WASI crypto module cannot possibly cover all possible error types implementations can return, especially since some of these may be language-specific.
+    # This error can thus be thrown when other error types are not suitable, and when the original error comes from the cryptographic primitives themselves and not from the WASI module.
+    ALGORITHM_FAILURE = 12
+    # The supplied signature is invalid, or incompatible with the chosen algorithm.
+    INVALID_SIGNATURE = 13
+    # An attempt was made to close a handle that was already closed.
+    CLOSED = 14
+    # A function was called with an unassigned handle, a closed handle, or handle of an unexpected type.
+    INVALID_HANDLE = 15
+    # The host needs to copy data to a guest-allocated buffer, but that buffer is too small.
+    OVERFLOW = 16
+    # An internal error occurred.
+    #
+    # This error is reserved to internal consistency checks, and must only be sent if the internal state of the host remains safe after an inconsistency was detected.
+    INTERNAL_ERROR = 17
+    # Too many handles are currently open, and a new one cannot be created.
+    #
+    # Implementations are free to represent handles as they want, and to enforce limits to limit resources usage.
+    TOO_MANY_HANDLES = 18
+    # A key was provided, but the chosen algorithm doesn't support keys.
+    #
+    # This is returned by symmetric operations.
+    #
+    # Many hash functions, in particular, do not support keys without being used in particular constructions.
+    # Blindly ignoring a key provided by mistake while trying to open a context for such as function could cause serious security vulnerabilities.
+    #
+    # These functions must refuse to create the context and return this error instead.
+    KEY_NOT_SUPPORTED = 19
+    # A key is required for the chosen algorithm, but none was given.
+    KEY_REQUIRED = 20
+    # The provided authentication tag is invalid or incompatible with the current algorithm.
+    #
+    # This error is returned by decryption functions and tag verification functions.
+    #
+    # Unlike `verification_failed`, this error code is returned when the tag cannot possibly verify for any input.
+    INVALID_TAG = 21
+    # The requested operation is incompatible with the current scheme.
+    #
+    # For example, the `symmetric_state_encrypt()` function cannot complete if the selected construction is a key derivation function.
+    # This error code will be returned instead.
+    INVALID_OPERATION = 22
+    # A nonce is required.
+    #
+    # Most encryption schemes require a nonce.
+    #
+    # In the absence of a nonce, the WASI cryptography module can automatically generate one, if that can be done safely. The nonce can be retrieved later with the `symmetric_state_option_get()` function using the `nonce` parameter.
+    # If automatically generating a nonce cannot be done safely, the module never falls back to an insecure option and requests an explicit nonce by throwing that error.
+    NONCE_REQUIRED = 23
+    # The provided nonce doesn't have a correct size for the given cipher.
+    INVALID_NONCE = 24
+    # The named option was not set.
+    #
+    # The caller tried to read the value of an option that was not set.
+    # This error is used to make the distinction between an empty option, and an option that was not set and left to its default value.
+    OPTION_NOT_SET = 25
+    # A key or key pair matching the requested identifier cannot be found using the supplied information.
+    #
+    # This error is returned by a secrets manager via the `keypair_from_id()` function.
+    NOT_FOUND = 26
+    # The algorithm requires parameters that haven't been set.
+    #
+    # Non-generic options are required and must be given by building an `options` set and giving that object to functions instantiating that algorithm.
+    PARAMETERS_MISSING = 27
+    # A requested computation is not done yet, and additional calls to the function are required.
+    #
+    # Some functions, such as functions generating key pairs and password stretching functions, can take a long time to complete.
+    #
+    # In order to avoid a host call to be blocked for too long, these functions can return prematurely, requiring additional calls with the same parameters until they complete.
+    IN_PROGRESS = 28
+    # Multiple keys have been provided, but they do not share the same type.
+    #
+    # This error is returned when trying to build a key pair from a public key and a secret key that were created for different and incompatible algorithms.
+    INCOMPATIBLE_KEYS = 29
+    # A managed key or secret expired and cannot be used any more.
+    EXPIRED = 30
+
+
+class KeypairEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a key pair."""
+
+    # Raw bytes.
+    RAW = 0
+    # PCSK8/DER encoding.
+    PKCS8 = 1
+    # PEM encoding.
+    PEM = 2
+    # Implementation-defined encoding.
+    LOCAL = 3
+
+
+class PublickeyEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a public key."""
+
+    # Raw bytes.
+    RAW = 0
+    # PKCS8/DER encoding.
+    PKCS8 = 1
+    # PEM encoding.
+    PEM = 2
+    # SEC encoding.
+    SEC = 3
+    # Compressed SEC encoding.
+    COMPRESSED_SEC = 4
+    # Implementation-defined encoding.
+    LOCAL = 5
+
+
+class SecretkeyEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a secret key."""
+
+    # Raw bytes.
+    RAW = 0
+    # PKCS8/DER encoding.
+    PKCS8 = 1
+    # PEM encoding.
+    PEM = 2
+    # SEC encoding.
+    SEC = 3
+    # Compressed SEC encoding.
+    COMPRESSED_SEC = 4
+    # Implementation-defined encoding.
+    LOCAL = 5
+
+
+class SignatureEncoding(enum.IntEnum):
+    """Encoding to use for importing or exporting a signature."""
+
+    # Raw bytes.
+    RAW = 0
+    # DER encoding.
+    DER = 1
+
+
+class AlgorithmType(enum.IntEnum):
+    """An algorithm category."""
+
+    SIGNATURES = 0
+    SYMMETRIC = 1
+    KEY_EXCHANGE = 2
+
+
+# Version of a managed key.
+#
+# A version can be an arbitrary `u64` integer, with the expection of some reserved values.
+Version = ctypes.c_uint64
+
+
+# Size of a value.
+Size = WasiUSize
+
+
+# A UNIX timestamp, in seconds since 01/01/1970.
+Timestamp = ctypes.c_uint64
+
+
+# A 64-bit value
+U64 = ctypes.c_uint64
+
+
+# Handle for functions returning output whose size may be large or not known in advance.
+#
+# An `array_output` object contains a host-allocated byte array.
+#
+# A guest can get the size of that array after a function returns in order to then allocate a buffer of the correct size.
+# In addition, the content of such an object can be consumed by a guest in a streaming fashion.
+#
+# An `array_output` handle is automatically closed after its full content has been consumed.
+ArrayOutput = typing.NewType("ArrayOutput", int)
+
+
+# A set of options.
+#
+# This type is used to set non-default parameters.
+#
+# The exact set of allowed options depends on the algorithm being used.
+Options = typing.NewType("Options", int)
+
+
+# A handle to the optional secrets management facilities offered by a host.
+#
+# This is used to generate, retrieve and invalidate managed keys.
+SecretsManager = typing.NewType("SecretsManager", int)
+
+
+# A key pair.
+Keypair = typing.NewType("Keypair", int)
+
+
+# A state to absorb data to be signed.
+#
+# After a signature has been computed or verified, the state remains valid for further operations.
+#
+# A subsequent signature would sign all the data accumulated since the creation of the state object.
+SignatureState = typing.NewType("SignatureState", int)
+
+
+# A signature.
+Signature = typing.NewType("Signature", int)
+
+
+# A public key, for key exchange and signature verification.
+Publickey = typing.NewType("Publickey", int)
+
+
+# A secret key, for key exchange mechanisms.
+Secretkey = typing.NewType("Secretkey", int)
+
+
+# A state to absorb signed data to be verified.
+SignatureVerificationState = typing.NewType("SignatureVerificationState", int)
+
+
+# A state to perform symmetric operations.
+#
+# The state is not reset nor invalidated after an option has been performed.
+# Incremental updates and sessions are thus supported.
+SymmetricState = typing.NewType("SymmetricState", int)
+
+
+# A symmetric key.
+#
+# The key can be imported from raw bytes, or can be a reference to a managed key.
+#
+# If it was imported, the host will wipe it from memory as soon as the handle is closed.
+SymmetricKey = typing.NewType("SymmetricKey", int)
+
+
+# An authentication tag.
+#
+# This is an object returned by functions computing authentication tags.
+#
+# A tag can be compared against another tag (directly supplied as raw bytes) in constant time with the `symmetric_tag_verify()` function.
+#
+# This object type can't be directly created from raw bytes. They are only returned by functions computing MACs.
+#
+# The host is reponsible for securely wiping them from memory on close.
+SymmetricTag = typing.NewType("SymmetricTag", int)
+
+
+class OptOptionsU(enum.IntEnum):
+    """Options index, only required by the Interface Types translation layer."""
+
+    SOME = 0
+    NONE = 1
+
+
+class OptOptions(ctypes.Structure):
+    """An optional options set.
+
+    This union simulates an `Option<Options>` type to make the `options` parameter of some functions optional.
+    """
+
+    class _Member(ctypes.Union):
+        _pack_ = 1
+        _fields_ = [
+            ("some", WasiHandle),
+            ("_size", ctypes.c_uint8 * 4),
+        ]
+
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("_pad0", ctypes.c_uint8 * 3),
+        ("member", _Member),
+    ]
+
+    SOME = 0
+    NONE = 1
+
+    @classmethod
+    def new_some(cls, value) -> OptOptions:
+        """Return a `OptOptions` with the `some` case."""
+        union = cls(tag=cls.SOME)
+        union.member.some = value
+        return union
+
+    @classmethod
+    def new_none(cls) -> OptOptions:
+        """Return a `OptOptions` with the `none` case."""
+        union = cls(tag=cls.NONE)
+        return union
+
+
+class OptSymmetricKeyU(enum.IntEnum):
+    """Symmetric key index, only required by the Interface Types translation layer."""
+
+    SOME = 0
+    NONE = 1
+
+
+class OptSymmetricKey(ctypes.Structure):
+    """An optional symmetric key.
+
+    This union simulates an `Option<SymmetricKey>` type to make the `symmetric_key` parameter of some functions optional.
+    """
+
+    class _Member(ctypes.Union):
+        _pack_ = 1
+        _fields_ = [
+            ("some", WasiHandle),
+            ("_size", ctypes.c_uint8 * 4),
+        ]
+
+    _pack_ = 1
+    _fields_ = [
+        ("tag", ctypes.c_uint8),
+        ("_pad0", ctypes.c_uint8 * 3),
+        ("member", _Member),
+    ]
+
+    SOME = 0
+    NONE = 1
+
+    @classmethod
+    def new_some(cls, value) -> OptSymmetricKey:
+        """Return a `OptSymmetricKey` with the `some` case."""
+        union = cls(tag=cls.SOME)
+        union.member.some = value
+        return union
+
+    @classmethod
+    def new_none(cls) -> OptSymmetricKey:
+        """Return a `OptSymmetricKey` with the `none` case."""
+        union = cls(tag=cls.NONE)
+        return union
+
+
+class WasiEphemeralCryptoSymmetricHost:
+    """Functions of the `wasi_ephemeral_crypto_symmetric` module, implemented by the host.
+
+    Override them in a subclass, and register an instance with `define_wasi_ephemeral_crypto_symmetric_imports()`.
+    Methods receive the memory of the calling instance first.
+    Functions returning an error code raise `WasiError` to return it.
+    """
+
+    def symmetric_key_generate(self, memory: GuestMemory, algorithm: str, options: OptOptions) -> SymmetricKey:
+        """Generate a new symmetric key for a given algorithm.
+
+        `options` can be `None` to use the default parameters, or an algoritm-specific set of parameters to override.
+
+        This function may return `unsupported_feature` if key generation is not supported by the host for the chosen algorithm, or `unsupported_algorithm` if the algorithm is not supported by the host.
+        """
+        raise NotImplementedError("symmetric_key_generate")
+
+    def symmetric_key_import(self, memory: GuestMemory, algorithm: str, raw: int, raw_len: int) -> SymmetricKey:
+        """Create a symmetric key from raw material.
+
+        The algorithm is internally stored along with the key, and trying to use the key with an operation expecting a different algorithm will return `invalid_key`.
+
+        The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+        """
+        raise NotImplementedError("symmetric_key_import")
+
+    def symmetric_key_export(self, memory: GuestMemory, symmetric_key: SymmetricKey) -> ArrayOutput:
+        """Export a symmetric key as raw material.
+
+        This is mainly useful to export a managed key.
+
+        May return `prohibited_operation` if this operation is denied.
+        """
+        raise NotImplementedError("symmetric_key_export")
+
+    def symmetric_key_close(self, memory: GuestMemory, symmetric_key: SymmetricKey) -> None:
+        """Destroy a symmetric key.
+
+        Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+        """
+        raise NotImplementedError("symmetric_key_close")
+
+    def symmetric_key_generate_managed(self, memory: GuestMemory, secrets_manager: SecretsManager, algorithm: str, options: OptOptions) -> SymmetricKey:
+        """__(optional)__
+        Generate a new managed symmetric key.
+
+        The key is generated and stored by the secrets management facilities.
+
+        It may be used through its identifier, but the host may not allow it to be exported.
+
+        The function returns the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+        or `unsupported_algorithm` if a key cannot be created for the chosen algorithm.
+
+        The function may also return `unsupported_algorithm` if the algorithm is not supported by the host.
+
+        This is also an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("symmetric_key_generate_managed")
+
+    def symmetric_key_store_managed(self, memory: GuestMemory, secrets_manager: SecretsManager, symmetric_key: SymmetricKey, symmetric_key_id: int, symmetric_key_id_max_len: int) -> None:
+        """__(optional)__
+        Store a symmetric key into the secrets manager.
+
+        On success, the function stores the key identifier into `$symmetric_key_id`,
+        into which up to `$symmetric_key_id_max_len` can be written.
+
+        The function returns `overflow` if the supplied buffer is too small.
+        """
+        raise NotImplementedError("symmetric_key_store_managed")
+
+    def symmetric_key_replace_managed(self, memory: GuestMemory, secrets_manager: SecretsManager, symmetric_key_old: SymmetricKey, symmetric_key_new: SymmetricKey) -> int:
+        """__(optional)__
+        Replace a managed symmetric key.
+
+        This function crates a new version of a managed symmetric key, by replacing `$kp_old` with `$kp_new`.
+
+        It does several things:
+
+        - The key identifier for `$symmetric_key_new` is set to the one of `$symmetric_key_old`.
+        - A new, unique version identifier is assigned to `$kp_new`. This version will be equivalent to using `$version_latest` until the key is replaced.
+        - The `$symmetric_key_old` handle is closed.
+
+        Both keys must share the same algorithm and have compatible parameters. If this is not the case, `incompatible_keys` is returned.
+
+        The function may also return the `unsupported_feature` error code if secrets management facilities are not supported by the host,
+        or if keys cannot be rotated.
+
+        Finally, `prohibited_operation` can be returned if `$symmetric_key_new` wasn't created by the secrets manager, and the secrets manager prohibits imported keys.
+
+        If the operation succeeded, the new version is returned.
+
+        This is an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("symmetric_key_replace_managed")
+
+    def symmetric_key_id(self, memory: GuestMemory, symmetric_key: SymmetricKey, symmetric_key_id: int, symmetric_key_id_max_len: int) -> tuple[int, int]:
+        """__(optional)__
+        Return the key identifier and version of a managed symmetric key.
+
+        If the key is not managed, `unsupported_feature` is returned instead.
+
+        This is an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("symmetric_key_id")
+
+    def symmetric_key_from_id(self, memory: GuestMemory, secrets_manager: SecretsManager, symmetric_key_id: int, symmetric_key_id_len: int, symmetric_key_version: int) -> SymmetricKey:
+        """__(optional)__
+        Return a managed symmetric key from a key identifier.
+
+        `kp_version` can be set to `version_latest` to retrieve the most recent version of a symmetric key.
+
+        If no key matching the provided information is found, `not_found` is returned instead.
+
+        This is an optional import, meaning that the function may not even exist.
+        """
+        raise NotImplementedError("symmetric_key_from_id")
+
+    def symmetric_state_open(self, memory: GuestMemory, algorithm: str, key: OptSymmetricKey, options: OptOptions) -> SymmetricState:
+        """Create a new state to aborb and produce data using symmetric operations.
+
+        The state remains valid after every operation in order to support incremental updates.
+
+        The function has two optional parameters: a key and an options set.
+
+        It will fail with a `key_not_supported` error code if a key was provided but the chosen algorithm doesn't natively support keying.
+
+        On the other hand, if a key is required, but was not provided, a `key_required` error will be thrown.
+
+        Some algorithms may require additional parameters. They have to be supplied as an options set:
+
+        ```rust
+        let options_handle = ctx.options_open()?;
+        ctx.options_set("context", b"My application")?;
+        ctx.options_set_u64("fanout", 16)?;
+        let state_handle = ctx.symmetric_state_open("BLAKE2b-512", None, Some(options_handle))?;
+        ```
+
+        If some parameters are mandatory but were not set, the `parameters_missing` error code will be returned.
+
+        A notable exception is the `nonce` parameter, that is common to most AEAD constructions.
+
+        If a nonce is required but was not supplied:
+
+        - If it is safe to do so, the host will automatically generate a nonce. This is true for nonces that are large enough to be randomly generated, or if the host is able to maintain a global counter.
+        - If not, the function will fail and return the dedicated `nonce_required` error code.
+
+        A nonce that was automatically generated can be retrieved after the function returns with `symmetric_state_get(state_handle, "nonce")`.
+
+        **Sample usage patterns:**
+
+        - **Hashing**
+
+        ```rust
+        let mut out = [0u8; 64];
+        let state_handle = ctx.symmetric_state_open("SHAKE-128", None, None)?;
+        ctx.symmetric_state_absorb(state_handle, b"data")?;
+        ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+        ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+        ```
+
+        - **MAC**
+
+        ```rust
+        let mut raw_tag = [0u8; 64];
+        let key_handle = ctx.symmetric_key_import("HMAC/SHA-512", b"key")?;
+        let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+        ctx.symmetric_state_absorb(state_handle, b"data")?;
+        ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+        let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+        ctx.symmetric_tag_pull(computed_tag_handle, &mut raw_tag)?;
+        ```
+
+        Verification:
+
+        ```rust
+        let state_handle = ctx.symmetric_state_open("HMAC/SHA-512", Some(key_handle), None)?;
+        ctx.symmetric_state_absorb(state_handle, b"data")?;
+        ctx.symmetric_state_absorb(state_handle, b"more_data")?;
+        let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+        ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+        ```
+
+        - **Tuple hashing**
+
+        ```rust
+        let mut out = [0u8; 64];
+        let state_handle = ctx.symmetric_state_open("TupleHashXOF256", None, None)?;
+        ctx.symmetric_state_absorb(state_handle, b"value 1")?;
+        ctx.symmetric_state_absorb(state_handle, b"value 2")?;
+        ctx.symmetric_state_absorb(state_handle, b"value 3")?;
+        ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+        ```
+        Unlike MACs and regular hash functions, inputs are domain separated instead of being concatenated.
+
+        - **Key derivation using extract-and-expand**
+
+        Extract:
+
+        ```rust
+        let mut prk = vec![0u8; 64];
+        let key_handle = ctx.symmetric_key_import("HKDF-EXTRACT/SHA-512", b"key")?;
+        let state_handle = ctx.symmetric_state_open("HKDF-EXTRACT/SHA-512", Some(key_handle), None)?;
+        ctx.symmetric_state_absorb(state_handle, b"salt")?;
+        let prk_handle = ctx.symmetric_state_squeeze_key(state_handle, "HKDF-EXPAND/SHA-512")?;
+        ```
+
+        Expand:
+
+        ```rust
+        let mut subkey = vec![0u8; 32];
+        let state_handle = ctx.symmetric_state_open("HKDF-EXPAND/SHA-512", Some(prk_handle), None)?;
+        ctx.symmetric_state_absorb(state_handle, b"info")?;
+        ctx.symmetric_state_squeeze(state_handle, &mut subkey)?;
+        ```
+
+        - **Key derivation using a XOF**
+
+        ```rust
+        let mut subkey1 = vec![0u8; 32];
+        let mut subkey2 = vec![0u8; 32];
+        let key_handle = ctx.symmetric_key_import("BLAKE3", b"key")?;
+        let state_handle = ctx.symmetric_state_open("BLAKE3", Some(key_handle), None)?;
+        ctx.symmetric_absorb(state_handle, b"context")?;
+        ctx.squeeze(state_handle, &mut subkey1)?;
+        ctx.squeeze(state_handle, &mut subkey2)?;
+        ```
+
+        - **Password hashing**
+
+        ```rust
+        let mut memory = vec![0u8; 1_000_000_000];
+        let options_handle = ctx.symmetric_options_open()?;
+        ctx.symmetric_options_set_guest_buffer(options_handle, "memory", &mut memory)?;
+        ctx.symmetric_options_set_u64(options_handle, "opslimit", 5)?;
+        ctx.symmetric_options_set_u64(options_handle, "parallelism", 8)?;
+
+        let state_handle = ctx.symmetric_state_open("ARGON2-ID-13", None, Some(options))?;
+        ctx.symmtric_state_absorb(state_handle, b"password")?;
+
+        let pw_str_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+        let mut pw_str = vec![0u8; ctx.symmetric_tag_len(pw_str_handle)?];
+        ctx.symmetric_tag_pull(pw_str_handle, &mut pw_str)?;
+        ```
+
+        - **AEAD encryption with an explicit nonce**
+
+        ```rust
+        let key_handle = ctx.symmetric_key_generate("AES-256-GCM", None)?;
+        let message = b"test";
+
+        let options_handle = ctx.symmetric_options_open()?;
+        ctx.symmetric_options_set(options_handle, "nonce", nonce)?;
+
+        let state_handle = ctx.symmetric_state_open("AES-256-GCM", Some(key_handle), Some(options_handle))?;
+        let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+        ctx.symmetric_state_absorb(state_handle, "additional data")?;
+        ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+        ```
+
+        - **AEAD encryption with automatic nonce generation**
+
+        ```rust
+        let key_handle = ctx.symmetric_key_generate("AES-256-GCM-SIV", None)?;
+        let message = b"test";
+        let mut nonce = [0u8; 24];
+
+        let state_handle = ctx.symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), None)?;
+
+        let nonce_handle = ctx.symmetric_state_options_get(state_handle, "nonce")?;
+        ctx.array_output_pull(nonce_handle, &mut nonce)?;
+
+        let mut ciphertext = vec![0u8; message.len() + ctx.symmetric_state_max_tag_len(state_handle)?];
+        ctx.symmetric_state_absorb(state_handle, "additional data")?;
+        ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, message)?;
+        ```
+
+        - **Session authenticated modes**
+
+        ```rust
+        let mut out = [0u8; 16];
+        let mut out2 = [0u8; 16];
+        let mut ciphertext = [0u8; 20];
+        let key_handle = ctx.symmetric_key_generate("Xoodyak-128", None)?;
+        let state_handle = ctx.symmetric_state_open("Xoodyak-128", Some(key_handle), None)?;
+        ctx.symmetric_state_absorb(state_handle, b"data")?;
+        ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, b"abcd")?;
+        ctx.symmetric_state_absorb(state_handle, b"more data")?;
+        ctx.symmetric_state_squeeze(state_handle, &mut out)?;
+        ctx.symmetric_state_squeeze(state_handle, &mut out2)?;
+        ctx.symmetric_state_ratchet(state_handle)?;
+        ctx.symmetric_state_absorb(state_handle, b"more data")?;
+        let next_key_handle = ctx.symmetric_state_squeeze_key(state_handle, "Xoodyak-128")?;
+        // ...
+        ```
+        """
+        raise NotImplementedError("symmetric_state_open")
+
+    def symmetric_state_options_get(self, memory: GuestMemory, handle: SymmetricState, name: str, value: int, value_max_len: int) -> int:
+        """Retrieve a parameter from the current state.
+
+        In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+
+        The function may return `options_not_set` if an option was not set, which is different from an empty value.
+
+        It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+        """
+        raise NotImplementedError("symmetric_state_options_get")
+
+    def symmetric_state_options_get_u64(self, memory: GuestMemory, handle: SymmetricState, name: str) -> int:
+        """Retrieve an integer parameter from the current state.
+
+        In particular, `symmetric_state_options_get("nonce")` can be used to get a nonce that as automatically generated.
+
+        The function may return `options_not_set` if an option was not set.
+
+        It may also return `unsupported_option` if the option doesn't exist for the chosen algorithm.
+        """
+        raise NotImplementedError("symmetric_state_options_get_u64")
+
+    def symmetric_state_close(self, memory: GuestMemory, handle: SymmetricState) -> None:
+        """Destroy a symmetric state.
+
+        Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+        """
+        raise NotImplementedError("symmetric_state_close")
+
+    def symmetric_state_absorb(self, memory: GuestMemory, handle: SymmetricState, data: int, data_len: int) -> None:
+        """Absorb data into the state.
+
+        - **Hash functions:** adds data to be hashed.
+        - **MAC functions:** adds data to be authenticated.
+        - **Tuplehash-like constructions:** adds a new tuple to the state.
+        - **Key derivation functions:** adds to the IKM or to the subkey information.
+        - **AEAD constructions:** adds additional data to be authenticated.
+        - **Stateful hash objects, permutation-based constructions:** absorbs.
+
+        If the chosen algorithm doesn't accept input data, the `invalid_operation` error code is returned.
+
+        If too much data has been fed for the algorithm, `overflow` may be thrown.
+        """
+        raise NotImplementedError("symmetric_state_absorb")
+
+    def symmetric_state_squeeze(self, memory: GuestMemory, handle: SymmetricState, out: int, out_len: int) -> None:
+        """Squeeze bytes from the state.
+
+        - **Hash functions:** this tries to output an `out_len` bytes digest from the absorbed data. The hash function output will be truncated if necessary. If the requested size is too large, the `invalid_len` error code is returned.
+        - **Key derivation functions:** : outputs an arbitrary-long derived key.
+        - **RNGs, DRBGs, stream ciphers:**: outputs arbitrary-long data.
+        - **Stateful hash objects, permutation-based constructions:** squeeze.
+
+        Other kinds of algorithms may return `invalid_operation` instead.
+
+        For password-stretching functions, the function may return `in_progress`.
+        In that case, the guest should retry with the same parameters until the function completes.
+        """
+        raise NotImplementedError("symmetric_state_squeeze")
+
+    def symmetric_state_squeeze_tag(self, memory: GuestMemory, handle: SymmetricState) -> SymmetricTag:
+        """Compute and return a tag for all the data injected into the state so far.
+
+        - **MAC functions**: returns a tag authenticating the absorbed data.
+        - **Tuplehash-like constructions:** returns a tag authenticating all the absorbed tuples.
+        - **Password-hashing functions:** returns a standard string containing all the required parameters for password verification.
+
+        Other kinds of algorithms may return `invalid_operation` instead.
+
+        For password-stretching functions, the function may return `in_progress`.
+        In that case, the guest should retry with the same parameters until the function completes.
+        """
+        raise NotImplementedError("symmetric_state_squeeze_tag")
+
+    def symmetric_state_squeeze_key(self, memory: GuestMemory, handle: SymmetricState, alg_str: str) -> SymmetricKey:
+        """Use the current state to produce a key for a target algorithm.
+
+        For extract-then-expand constructions, this returns the PRK.
+        For session-base authentication encryption, this returns a key that can be used to resume a session without storing a nonce.
+
+        `invalid_operation` is returned for algorithms not supporting this operation.
+        """
+        raise NotImplementedError("symmetric_state_squeeze_key")
+
+    def symmetric_state_max_tag_len(self, memory: GuestMemory, handle: SymmetricState) -> int:
+        """Return the maximum length of an authentication tag for the current algorithm.
+
+        This allows guests to compute the size required to store a ciphertext along with its authentication tag.
+
+        The returned length may include the encryption mode's padding requirements in addition to the actual tag.
+
+        For an encryption operation, the size of the output buffer should be `input_len + symmetric_state_max_tag_len()`.
+
+        For a decryption operation, the size of the buffer that will store the decrypted data must be `ciphertext_len - symmetric_state_max_tag_len()`.
+        """
+        raise NotImplementedError("symmetric_state_max_tag_len")
+
+    def symmetric_state_encrypt(self, memory: GuestMemory, handle: SymmetricState, out: int, out_len: int, data: int, data_len: int) -> int:
+        """Encrypt data with an attached tag.
+
+        - **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+        - **AEAD:** encrypts `data` into `out`, including the authentication tag to the output. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The `symmetric_state_max_tag_len()` function can be used to retrieve the overhead of adding the tag, as well as padding if necessary.
+        - **SHOE, Xoodyak, Strobe:** encrypts data, squeezes a tag and appends it to the output.
+
+        If `out` and `data` are the same address, encryption may happen in-place.
+
+        The function returns the actual size of the ciphertext along with the tag.
+
+        `invalid_operation` is returned for algorithms not supporting encryption.
+        """
+        raise NotImplementedError("symmetric_state_encrypt")
+
+    def symmetric_state_encrypt_detached(self, memory: GuestMemory, handle: SymmetricState, out: int, out_len: int, data: int, data_len: int) -> SymmetricTag:
+        """Encrypt data, with a detached tag.
+
+        - **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+        - **AEAD:** encrypts `data` into `out` and returns the tag separately. Additional data must have been previously absorbed using `symmetric_state_absorb()`. The output and input buffers must be of the same length.
+        - **SHOE, Xoodyak, Strobe:** encrypts data and squeezes a tag.
+
+        If `out` and `data` are the same address, encryption may happen in-place.
+
+        The function returns the tag.
+
+        `invalid_operation` is returned for algorithms not supporting encryption.
+        """
+        raise NotImplementedError("symmetric_state_encrypt_detached")
+
+    def symmetric_state_decrypt(self, memory: GuestMemory, handle: SymmetricState, out: int, out_len: int, data: int, data_len: int) -> int:
+        """- **Stream cipher:** adds the input to the stream cipher output. `out_len` and `data_len` can be equal, as no authentication tags will be added.
+        - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+        - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the one that was appended to the ciphertext.
+
+        If `out` and `data` are the same address, decryption may happen in-place.
+
+        `out_len` must be exactly `data_len` + `max_tag_len` bytes.
+
+        The function returns the actual size of the decrypted message, which can be smaller than `out_len` for modes that requires padding.
+
+        `invalid_tag` is returned if the tag didn't verify.
+
+        `invalid_operation` is returned for algorithms not supporting encryption.
+        """
+        raise NotImplementedError("symmetric_state_decrypt")
+
+    def symmetric_state_decrypt_detached(self, memory: GuestMemory, handle: SymmetricState, out: int, out_len: int, data: int, data_len: int, raw_tag: int, raw_tag_len: int) -> int:
+        """- **Stream cipher:** returns `invalid_operation` since stream ciphers do not include authentication tags.
+        - **AEAD:** decrypts `data` into `out`. Additional data must have been previously absorbed using `symmetric_state_absorb()`.
+        - **SHOE, Xoodyak, Strobe:** decrypts data, squeezes a tag and verify that it matches the expected one.
+
+        `raw_tag` is the expected tag, as raw bytes.
+
+        `out` and `data` be must have the same length.
+        If they also share the same address, decryption may happen in-place.
+
+        The function returns the actual size of the decrypted message.
+
+        `invalid_tag` is returned if the tag verification failed.
+
+        `invalid_operation` is returned for algorithms not supporting encryption.
+        """
+        raise NotImplementedError("symmetric_state_decrypt_detached")
+
+    def symmetric_state_ratchet(self, memory: GuestMemory, handle: SymmetricState) -> None:
+        """Make it impossible to recover the previous state.
+
+        This operation is supported by some systems keeping a rolling state over an entire session, for forward security.
+
+        `invalid_operation` is returned for algorithms not supporting ratcheting.
+        """
+        raise NotImplementedError("symmetric_state_ratchet")
+
+    def symmetric_tag_len(self, memory: GuestMemory, symmetric_tag: SymmetricTag) -> int:
+        """Return the length of an authentication tag.
+
+        This function can be used by a guest to allocate the correct buffer size to copy a computed authentication tag.
+        """
+        raise NotImplementedError("symmetric_tag_len")
+
+    def symmetric_tag_pull(self, memory: GuestMemory, symmetric_tag: SymmetricTag, buf: int, buf_len: int) -> int:
+        """Copy an authentication tag into a guest-allocated buffer.
+
+        The handle automatically becomes invalid after this operation. Manually closing it is not required.
+
+        Example usage:
+
+        ```rust
+        let mut raw_tag = [0u8; 16];
+        ctx.symmetric_tag_pull(raw_tag_handle, &mut raw_tag)?;
+        ```
+
+        The function returns `overflow` if the supplied buffer is too small to copy the tag.
+
+        Otherwise, it returns the number of bytes that have been copied.
+        """
+        raise NotImplementedError("symmetric_tag_pull")
+
+    def symmetric_tag_verify(self, memory: GuestMemory, symmetric_tag: SymmetricTag, expected_raw_tag_ptr: int, expected_raw_tag_len: int) -> None:
+        """Verify that a computed authentication tag matches the expected value, in constant-time.
+
+        The expected tag must be provided as a raw byte string.
+
+        The function returns `invalid_tag` if the tags don't match.
+
+        Example usage:
+
+        ```rust
+        let key_handle = ctx.symmetric_key_import("HMAC/SHA-256", b"key")?;
+        let state_handle = ctx.symmetric_state_open("HMAC/SHA-256", Some(key_handle), None)?;
+        ctx.symmetric_state_absorb(state_handle, b"data")?;
+        let computed_tag_handle = ctx.symmetric_state_squeeze_tag(state_handle)?;
+        ctx.symmetric_tag_verify(computed_tag_handle, expected_raw_tag)?;
+        ```
+        """
+        raise NotImplementedError("symmetric_tag_verify")
+
+    def symmetric_tag_close(self, memory: GuestMemory, symmetric_tag: SymmetricTag) -> None:
+        """Explicitly destroy an unused authentication tag.
+
+        This is usually not necessary, as `symmetric_tag_pull()` automatically closes a tag after it has been copied.
+
+        Objects are reference counted. It is safe to close an object immediately after the last function needing it is called.
+        """
+        raise NotImplementedError("symmetric_tag_close")
+
+
+def define_wasi_ephemeral_crypto_symmetric_imports(linker: wasmtime.Linker, store: wasmtime.Store, impl: WasiEphemeralCryptoSymmetricHost) -> None:
+    """Define the functions of the `wasi_ephemeral_crypto_symmetric` module in `linker`, implemented by `impl`."""
+
+    def _symmetric_key_generate(_caller, algorithm_ptr, algorithm_len, options, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_key_generate(_memory, _memory.read_string(algorithm_ptr, algorithm_len), _memory.read_value(OptOptions, options))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_generate", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_generate,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_import(_caller, algorithm_ptr, algorithm_len, raw, raw_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_key_import(_memory, _memory.read_string(algorithm_ptr, algorithm_len), raw, raw_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_import", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_import,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_export(_caller, symmetric_key, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_key_export(_memory, SymmetricKey(symmetric_key))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_export", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_export,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_close(_caller, symmetric_key):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_key_close(_memory, SymmetricKey(symmetric_key))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_close", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_close,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_generate_managed(_caller, secrets_manager, algorithm_ptr, algorithm_len, options, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_key_generate_managed(_memory, SecretsManager(secrets_manager), _memory.read_string(algorithm_ptr, algorithm_len), _memory.read_value(OptOptions, options))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_generate_managed", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_generate_managed,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_store_managed(_caller, secrets_manager, symmetric_key, symmetric_key_id, symmetric_key_id_max_len):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_key_store_managed(_memory, SecretsManager(secrets_manager), SymmetricKey(symmetric_key), symmetric_key_id, symmetric_key_id_max_len)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_store_managed", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_store_managed,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_replace_managed(_caller, secrets_manager, symmetric_key_old, symmetric_key_new, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_key_replace_managed(_memory, SecretsManager(secrets_manager), SymmetricKey(symmetric_key_old), SymmetricKey(symmetric_key_new))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Version, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_replace_managed", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_replace_managed,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_id(_caller, symmetric_key, symmetric_key_id, symmetric_key_id_max_len, result0_ptr, result1_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_key_id(_memory, SymmetricKey(symmetric_key), symmetric_key_id, symmetric_key_id_max_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result0_ptr, _results[0])
+        _memory.write_value(Version, result1_ptr, _results[1])
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_id", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_id,
+        access_caller=True,
+    ))
+
+    def _symmetric_key_from_id(_caller, secrets_manager, symmetric_key_id, symmetric_key_id_len, symmetric_key_version, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_key_from_id(_memory, SecretsManager(secrets_manager), symmetric_key_id, symmetric_key_id_len, symmetric_key_version)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_key_from_id", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i64(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_key_from_id,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_open(_caller, algorithm_ptr, algorithm_len, key, options, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_open(_memory, _memory.read_string(algorithm_ptr, algorithm_len), _memory.read_value(OptSymmetricKey, key), _memory.read_value(OptOptions, options))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_open", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_open,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_options_get(_caller, handle, name_ptr, name_len, value, value_max_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_options_get(_memory, SymmetricState(handle), _memory.read_string(name_ptr, name_len), value, value_max_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_options_get", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_options_get,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_options_get_u64(_caller, handle, name_ptr, name_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_options_get_u64(_memory, SymmetricState(handle), _memory.read_string(name_ptr, name_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(U64, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_options_get_u64", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_options_get_u64,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_close(_caller, handle):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_state_close(_memory, SymmetricState(handle))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_close", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_close,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_absorb(_caller, handle, data, data_len):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_state_absorb(_memory, SymmetricState(handle), data, data_len)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_absorb", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_absorb,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_squeeze(_caller, handle, out, out_len):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_state_squeeze(_memory, SymmetricState(handle), out, out_len)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_squeeze", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_squeeze,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_squeeze_tag(_caller, handle, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_squeeze_tag(_memory, SymmetricState(handle))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_squeeze_tag", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_squeeze_tag,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_squeeze_key(_caller, handle, alg_str_ptr, alg_str_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_squeeze_key(_memory, SymmetricState(handle), _memory.read_string(alg_str_ptr, alg_str_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_squeeze_key", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_squeeze_key,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_max_tag_len(_caller, handle, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_max_tag_len(_memory, SymmetricState(handle))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_max_tag_len", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_max_tag_len,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_encrypt(_caller, handle, out, out_len, data, data_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_encrypt(_memory, SymmetricState(handle), out, out_len, data, data_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_encrypt", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_encrypt,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_encrypt_detached(_caller, handle, out, out_len, data, data_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_encrypt_detached(_memory, SymmetricState(handle), out, out_len, data, data_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WasiHandle, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_encrypt_detached", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_encrypt_detached,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_decrypt(_caller, handle, out, out_len, data, data_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_decrypt(_memory, SymmetricState(handle), out, out_len, data, data_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_decrypt", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_decrypt,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_decrypt_detached(_caller, handle, out, out_len, data, data_len, raw_tag, raw_tag_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_state_decrypt_detached(_memory, SymmetricState(handle), out, out_len, data, data_len, raw_tag, raw_tag_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_decrypt_detached", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_decrypt_detached,
+        access_caller=True,
+    ))
+
+    def _symmetric_state_ratchet(_caller, handle):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_state_ratchet(_memory, SymmetricState(handle))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_state_ratchet", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_state_ratchet,
+        access_caller=True,
+    ))
+
+    def _symmetric_tag_len(_caller, symmetric_tag, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_tag_len(_memory, SymmetricTag(symmetric_tag))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_tag_len", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_tag_len,
+        access_caller=True,
+    ))
+
+    def _symmetric_tag_pull(_caller, symmetric_tag, buf, buf_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.symmetric_tag_pull(_memory, SymmetricTag(symmetric_tag), buf, buf_len)
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(Size, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_tag_pull", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_tag_pull,
+        access_caller=True,
+    ))
+
+    def _symmetric_tag_verify(_caller, symmetric_tag, expected_raw_tag_ptr, expected_raw_tag_len):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_tag_verify(_memory, SymmetricTag(symmetric_tag), expected_raw_tag_ptr, expected_raw_tag_len)
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_tag_verify", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_tag_verify,
+        access_caller=True,
+    ))
+
+    def _symmetric_tag_close(_caller, symmetric_tag):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.symmetric_tag_close(_memory, SymmetricTag(symmetric_tag))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_ephemeral_crypto_symmetric", "symmetric_tag_close", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _symmetric_tag_close,
+        access_caller=True,
+    ))
+
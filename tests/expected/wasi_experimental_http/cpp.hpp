@@ -0,0 +1,320 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <string_view>
+#include <tuple>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+
+#ifndef WITX_CODEGEN_CPP_PRELUDE
+#define WITX_CODEGEN_CPP_PRELUDE
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_IMPORT(module_name, function_name) \
+    __attribute__((import_module(module_name), import_name(function_name)))
+#else
+#define WITX_CODEGEN_IMPORT(module_name, function_name)
+#endif
+
+#if defined(__wasm__)
+#define WITX_CODEGEN_EXPORT(function_name) __attribute__((export_name(function_name)))
+#else
+#define WITX_CODEGEN_EXPORT(function_name)
+#endif
+
+using WasiHandle = int32_t;
+using Char8 = uint8_t;
+using Char32 = uint32_t;
+template <typename T> using WasiPtr = const T *;
+template <typename T> using WasiMutPtr = T *;
+using WasiStringBytesPtr = WasiPtr<Char8>;
+
+struct WasiString {
+    WasiStringBytesPtr ptr;
+    size_t len;
+
+    WasiString() = default;
+    WasiString(std::string_view str)
+        : ptr(reinterpret_cast<WasiStringBytesPtr>(str.data())), len(str.size()) {}
+
+    std::string_view as_string_view() const {
+        return std::string_view(reinterpret_cast<const char *>(ptr), len);
+    }
+};
+
+template <typename T> struct WasiSlice {
+    WasiPtr<T> ptr;
+    size_t len;
+
+    WasiSlice() = default;
+    WasiSlice(WasiPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiSlice(std::span<const T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<const T> as_span() const { return std::span<const T>(ptr, len); }
+#endif
+};
+
+template <typename T> struct WasiMutSlice {
+    WasiMutPtr<T> ptr;
+    size_t len;
+
+    WasiMutSlice() = default;
+    WasiMutSlice(WasiMutPtr<T> ptr, size_t len) : ptr(ptr), len(len) {}
+#if __cplusplus >= 202002L
+    WasiMutSlice(std::span<T> span) : ptr(span.data()), len(span.size()) {}
+
+    std::span<T> as_span() const { return std::span<T>(ptr, len); }
+#endif
+};
+
+template <typename T, typename E> class WasiExpected {
+  public:
+    static WasiExpected from_value(T value) {
+        WasiExpected res;
+        res.value_ = value;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    const T &value() const { return value_; }
+    T &value() { return value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    T value_;
+    E error_;
+    bool has_value_;
+};
+
+template <typename E> class WasiExpected<void, E> {
+  public:
+    static WasiExpected from_value() {
+        WasiExpected res;
+        res.has_value_ = true;
+        return res;
+    }
+
+    static WasiExpected from_error(E error) {
+        WasiExpected res;
+        res.error_ = error;
+        res.has_value_ = false;
+        return res;
+    }
+
+    bool has_value() const { return has_value_; }
+    explicit operator bool() const { return has_value_; }
+    E error() const { return error_; }
+
+  private:
+    WasiExpected() = default;
+
+    E error_;
+    bool has_value_;
+};
+
+#endif // WITX_CODEGEN_CPP_PRELUDE
+
+// ---------------------- Module: [wasi_experimental_http] ----------------------
+
+namespace wasi_experimental_http {
+
+    /// Handles for the HTTP extensions
+    enum class HttpHandle : WasiHandle {};
+
+    enum class HttpError : uint32_t {
+        /// Success
+        SUCCESS = 0,
+        /// Invalid handle
+        INVALID_HANDLE = 1,
+        /// Memory not found
+        MEMORY_NOT_FOUND = 2,
+        /// Memory access error
+        MEMORY_ACCESS_ERROR = 3,
+        /// Buffer too small
+        BUFFER_TOO_SMALL = 4,
+        /// Header not found
+        HEADER_NOT_FOUND = 5,
+        /// UTF-8 error
+        UTF8_ERROR = 6,
+        /// Destination not allowed
+        DESTINATION_NOT_ALLOWED = 7,
+        /// Invalid method
+        INVALID_METHOD = 8,
+        /// Invalid encoding
+        INVALID_ENCODING = 9,
+        /// Invalid URL
+        INVALID_URL = 10,
+        /// Request error
+        REQUEST_ERROR = 11,
+        /// Runtime error
+        RUNTIME_ERROR = 12,
+        /// Too many sessions
+        TOO_MANY_SESSIONS = 13,
+    };
+
+    /// HTTP status code
+    using StatusCode = uint16_t;
+
+    /// An HTTP body being sent
+    using OutgoingBody = WasiSlice<uint8_t>;
+
+    /// Buffer for an HTTP body being received
+    using IncomingBody = WasiMutSlice<uint8_t>;
+
+    /// A response handle
+    enum class ResponseHandle : WasiHandle {};
+
+    /// Buffer to store a header value
+    using HeaderValueBuf = WasiMutSlice<uint8_t>;
+
+    /// Number of bytes having been written
+    using WrittenBytes = size_t;
+
+    namespace raw {
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_experimental_http", "req")
+        HttpError req(
+            WasiPtr<Char8> url_ptr,
+            size_t url_len,
+            WasiPtr<Char8> method_ptr,
+            size_t method_len,
+            WasiPtr<Char8> headers_ptr,
+            size_t headers_len,
+            WasiPtr<uint8_t> body_ptr,
+            size_t body_len,
+            WasiMutPtr<StatusCode> result0_ptr,
+            WasiMutPtr<ResponseHandle> result1_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_experimental_http", "close")
+        HttpError close(
+            ResponseHandle response_handle
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_experimental_http", "header_get")
+        HttpError header_get(
+            ResponseHandle response_handle,
+            WasiPtr<Char8> header_name_ptr,
+            size_t header_name_len,
+            WasiMutPtr<uint8_t> header_value_buf_ptr,
+            size_t header_value_buf_len,
+            WasiMutPtr<WrittenBytes> result_ptr
+        );
+
+        extern "C" WITX_CODEGEN_IMPORT("wasi_experimental_http", "body_read")
+        HttpError body_read(
+            ResponseHandle response_handle,
+            WasiMutPtr<uint8_t> body_buf_ptr,
+            size_t body_buf_len,
+            WasiMutPtr<WrittenBytes> result_ptr
+        );
+
+    } // namespace raw
+
+    /// Send a request
+    inline WasiExpected<std::tuple<StatusCode, ResponseHandle>, HttpError> req(WasiString url, WasiString method, WasiString headers, OutgoingBody body) {
+        StatusCode result0_ptr{};
+        ResponseHandle result1_ptr{};
+        const HttpError res = raw::req(url.ptr, url.len, method.ptr, method.len, headers.ptr, headers.len, body.ptr, body.len, &result0_ptr, &result1_ptr);
+        if (res != HttpError{}) {
+            return WasiExpected<std::tuple<StatusCode, ResponseHandle>, HttpError>::from_error(res);
+        }
+        return WasiExpected<std::tuple<StatusCode, ResponseHandle>, HttpError>::from_value(std::make_tuple(result0_ptr, result1_ptr));
+    }
+
+    /// Close a request handle
+    inline WasiExpected<void, HttpError> close(ResponseHandle response_handle) {
+        const HttpError res = raw::close(response_handle);
+        if (res != HttpError{}) {
+            return WasiExpected<void, HttpError>::from_error(res);
+        }
+        return WasiExpected<void, HttpError>::from_value();
+    }
+
+    /// Get the value associated with a header
+    inline WasiExpected<WrittenBytes, HttpError> header_get(ResponseHandle response_handle, WasiString header_name, HeaderValueBuf header_value_buf) {
+        WrittenBytes result_ptr{};
+        const HttpError res = raw::header_get(response_handle, header_name.ptr, header_name.len, header_value_buf.ptr, header_value_buf.len, &result_ptr);
+        if (res != HttpError{}) {
+            return WasiExpected<WrittenBytes, HttpError>::from_error(res);
+        }
+        return WasiExpected<WrittenBytes, HttpError>::from_value(result_ptr);
+    }
+
+    /// Fill a buffer with the streamed content of a response body
+    inline WasiExpected<WrittenBytes, HttpError> body_read(ResponseHandle response_handle, IncomingBody body_buf) {
+        WrittenBytes result_ptr{};
+        const HttpError res = raw::body_read(response_handle, body_buf.ptr, body_buf.len, &result_ptr);
+        if (res != HttpError{}) {
+            return WasiExpected<WrittenBytes, HttpError>::from_error(res);
+        }
+        return WasiExpected<WrittenBytes, HttpError>::from_value(result_ptr);
+    }
+
+    /// Owned `ResponseHandle` handle, closed with `close()` when dropped.
+    class UniqueResponseHandle {
+      public:
+        explicit UniqueResponseHandle(ResponseHandle handle) noexcept : handle_(handle), valid_(true) {}
+        UniqueResponseHandle(const UniqueResponseHandle &) = delete;
+        UniqueResponseHandle &operator=(const UniqueResponseHandle &) = delete;
+
+        UniqueResponseHandle(UniqueResponseHandle &&other) noexcept : handle_(other.handle_), valid_(other.valid_) {
+            other.valid_ = false;
+        }
+
+        UniqueResponseHandle &operator=(UniqueResponseHandle &&other) noexcept {
+            if (this != &other) {
+                reset();
+                handle_ = other.handle_;
+                valid_ = other.valid_;
+                other.valid_ = false;
+            }
+            return *this;
+        }
+
+        ~UniqueResponseHandle() {
+            reset();
+        }
+
+        ResponseHandle get() const noexcept {
+            return handle_;
+        }
+
+        ResponseHandle release() noexcept {
+            valid_ = false;
+            return handle_;
+        }
+
+        void reset() noexcept {
+            if (valid_) {
+                (void)raw::close(handle_);
+                valid_ = false;
+            }
+        }
+
+      private:
+        ResponseHandle handle_;
+        bool valid_;
+    };
+
+} // namespace wasi_experimental_http
+
@@ -0,0 +1,361 @@
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+package wasiexperimentalhttp
+
+import (
+	"context"
+	"errors"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+// Used by host functions, that modules without any don't define.
+var (
+	_ context.Context
+	_ wazero.Runtime
+)
+
+type WasiHandle = int32
+type WasiPtr = uint32
+type WasiUSize = uint32
+type Char8 = uint8
+type Char32 = uint32
+
+// WasiString is a string in guest memory.
+type WasiString struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Read returns a copy of the string, and false if it is out of range.
+func (s WasiString) Read(mem api.Memory) (string, bool) {
+	bytes, ok := mem.Read(s.Ptr, s.Len)
+	return string(bytes), ok
+}
+
+// WasiSlice is a slice in guest memory, Len being its number of elements.
+type WasiSlice struct {
+	Ptr WasiPtr
+	Len WasiUSize
+}
+
+// Bytes returns a view of the guest memory of the slice, whose elements are size bytes long,
+// and false if it is out of range.
+func (s WasiSlice) Bytes(mem api.Memory, size uint32) ([]byte, bool) {
+	if uint64(s.Len)*uint64(size) > uint64(mem.Size()) {
+		return nil, false
+	}
+	return mem.Read(s.Ptr, s.Len*size)
+}
+
+// errMemoryAccess is raised as a trap by host functions accessing guest memory out of range,
+// or reading invalid values.
+var errMemoryAccess = errors.New("invalid guest memory access")
+
+// memReader reads little-endian values from guest memory, and records out of range accesses.
+type memReader struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (r *memReader) u8(offset uint32) uint8 {
+	v, ok := r.mem.ReadByte(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u16(offset uint32) uint16 {
+	v, ok := r.mem.ReadUint16Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u32(offset uint32) uint32 {
+	v, ok := r.mem.ReadUint32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) u64(offset uint32) uint64 {
+	v, ok := r.mem.ReadUint64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f32(offset uint32) float32 {
+	v, ok := r.mem.ReadFloat32Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) f64(offset uint32) float64 {
+	v, ok := r.mem.ReadFloat64Le(offset)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) boolean(offset uint32) bool {
+	return r.u8(offset) != 0
+}
+
+// bytes returns a view of length bytes of guest memory.
+func (r *memReader) bytes(offset, length uint32) []byte {
+	v, ok := r.mem.Read(offset, length)
+	r.ok = r.ok && ok
+	return v
+}
+
+func (r *memReader) WasiString(offset uint32) WasiString {
+	return WasiString{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+func (r *memReader) WasiSlice(offset uint32) WasiSlice {
+	return WasiSlice{Ptr: r.u32(offset), Len: r.u32(offset + 4)}
+}
+
+// slice returns a view of the guest memory of length elements of size bytes at ptr.
+func (r *memReader) slice(ptr, length, size uint32) []byte {
+	if uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	return r.bytes(ptr, length*size)
+}
+
+// readSlice returns a copy of the length elements of size bytes at ptr, read with read.
+func readSlice[T any](r *memReader, ptr, length, size uint32, read func(offset uint32) T) []T {
+	if uint64(ptr)+uint64(length)*uint64(size) > uint64(r.mem.Size()) {
+		r.ok = false
+		return nil
+	}
+	values := make([]T, length)
+	for i := range values {
+		values[i] = read(ptr + uint32(i)*size)
+	}
+	return values
+}
+
+// memWriter writes little-endian values to guest memory, and records out of range accesses.
+type memWriter struct {
+	mem api.Memory
+	ok  bool
+}
+
+func (w *memWriter) u8(offset uint32, v uint8) {
+	w.ok = w.mem.WriteByte(offset, v) && w.ok
+}
+
+func (w *memWriter) u16(offset uint32, v uint16) {
+	w.ok = w.mem.WriteUint16Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u32(offset uint32, v uint32) {
+	w.ok = w.mem.WriteUint32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) u64(offset uint32, v uint64) {
+	w.ok = w.mem.WriteUint64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f32(offset uint32, v float32) {
+	w.ok = w.mem.WriteFloat32Le(offset, v) && w.ok
+}
+
+func (w *memWriter) f64(offset uint32, v float64) {
+	w.ok = w.mem.WriteFloat64Le(offset, v) && w.ok
+}
+
+func (w *memWriter) boolean(offset uint32, v bool) {
+	w.u8(offset, uint8(boolToU32(v)))
+}
+
+func (w *memWriter) WasiString(offset uint32, v WasiString) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func (w *memWriter) WasiSlice(offset uint32, v WasiSlice) {
+	w.u32(offset, v.Ptr)
+	w.u32(offset+4, v.Len)
+}
+
+func boolToU32(v bool) uint32 {
+	if v {
+		return 1
+	}
+	return 0
+}
+
+// ---------------------- Module: [wasi_experimental_http] ----------------------
+
+// Handles for the HTTP extensions
+type HttpHandle WasiHandle
+
+type HttpError uint32
+
+const (
+	// Success
+	HttpErrorSuccess HttpError = 0
+	// Invalid handle
+	HttpErrorInvalidHandle HttpError = 1
+	// Memory not found
+	HttpErrorMemoryNotFound HttpError = 2
+	// Memory access error
+	HttpErrorMemoryAccessError HttpError = 3
+	// Buffer too small
+	HttpErrorBufferTooSmall HttpError = 4
+	// Header not found
+	HttpErrorHeaderNotFound HttpError = 5
+	// UTF-8 error
+	HttpErrorUtf8Error HttpError = 6
+	// Destination not allowed
+	HttpErrorDestinationNotAllowed HttpError = 7
+	// Invalid method
+	HttpErrorInvalidMethod HttpError = 8
+	// Invalid encoding
+	HttpErrorInvalidEncoding HttpError = 9
+	// Invalid URL
+	HttpErrorInvalidUrl HttpError = 10
+	// Request error
+	HttpErrorRequestError HttpError = 11
+	// Runtime error
+	HttpErrorRuntimeError HttpError = 12
+	// Too many sessions
+	HttpErrorTooManySessions HttpError = 13
+)
+
+// HTTP status code
+type StatusCode uint16
+
+// An HTTP body being sent
+type OutgoingBody = WasiSlice
+
+// Buffer for an HTTP body being received
+type IncomingBody = WasiSlice
+
+// A response handle
+type ResponseHandle WasiHandle
+
+// Buffer to store a header value
+type HeaderValueBuf = WasiSlice
+
+// Number of bytes having been written
+type WrittenBytes WasiUSize
+
+// WasiExperimentalHttpHost is implemented by the host to provide the functions of the `wasi_experimental_http` module.
+// Register an implementation with DefineWasiExperimentalHttpImports.
+// Methods receive the module calling them, whose memory parameters are read from.
+// Functions returning an error code return it last, results only being written if it is zero.
+type WasiExperimentalHttpHost interface {
+	// Send a request
+	Req(ctx context.Context, mod api.Module, url string, method string, headers string, body []byte) (StatusCode, ResponseHandle, HttpError)
+
+	// Close a request handle
+	Close(ctx context.Context, mod api.Module, responseHandle ResponseHandle) HttpError
+
+	// Get the value associated with a header
+	HeaderGet(ctx context.Context, mod api.Module, responseHandle ResponseHandle, headerName string, headerValueBuf []byte) (WrittenBytes, HttpError)
+
+	// Fill a buffer with the streamed content of a response body
+	BodyRead(ctx context.Context, mod api.Module, responseHandle ResponseHandle, bodyBuf []byte) (WrittenBytes, HttpError)
+}
+
+// DefineWasiExperimentalHttpImports returns a builder for the `wasi_experimental_http` host module, whose functions call host.
+// Instantiate it before the guest modules importing them.
+func DefineWasiExperimentalHttpImports(runtime wazero.Runtime, host WasiExperimentalHttpHost) wazero.HostModuleBuilder {
+	builder := runtime.NewHostModuleBuilder("wasi_experimental_http")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			urlPtr := api.DecodeU32(stack[0])
+			urlLen := api.DecodeU32(stack[1])
+			url := string(r.bytes(urlPtr, urlLen))
+			methodPtr := api.DecodeU32(stack[2])
+			methodLen := api.DecodeU32(stack[3])
+			method := string(r.bytes(methodPtr, methodLen))
+			headersPtr := api.DecodeU32(stack[4])
+			headersLen := api.DecodeU32(stack[5])
+			headers := string(r.bytes(headersPtr, headersLen))
+			bodyPtr := api.DecodeU32(stack[6])
+			bodyLen := api.DecodeU32(stack[7])
+			body := r.bytes(bodyPtr, bodyLen)
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			result0Ptr := api.DecodeU32(stack[8])
+			result1Ptr := api.DecodeU32(stack[9])
+			result0, result1, errno := host.Req(ctx, mod, url, method, headers, body)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u16(result0Ptr, uint16(result0))
+				w.u32(result1Ptr, uint32(result1))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("url_ptr", "url_len", "method_ptr", "method_len", "headers_ptr", "headers_len", "body_ptr", "body_len", "result0_ptr", "result1_ptr").
+		Export("req")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			responseHandle := ResponseHandle(api.DecodeU32(stack[0]))
+			errno := host.Close(ctx, mod, responseHandle)
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("response_handle").
+		Export("close")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			responseHandle := ResponseHandle(api.DecodeU32(stack[0]))
+			headerNamePtr := api.DecodeU32(stack[1])
+			headerNameLen := api.DecodeU32(stack[2])
+			headerName := string(r.bytes(headerNamePtr, headerNameLen))
+			headerValueBufPtr := api.DecodeU32(stack[3])
+			headerValueBufLen := api.DecodeU32(stack[4])
+			headerValueBuf := r.bytes(headerValueBufPtr, headerValueBufLen)
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[5])
+			result, errno := host.HeaderGet(ctx, mod, responseHandle, headerName, headerValueBuf)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("response_handle", "header_name_ptr", "header_name_len", "header_value_buf_ptr", "header_value_buf_len", "result_ptr").
+		Export("header_get")
+	builder.NewFunctionBuilder().
+		WithGoModuleFunction(api.GoModuleFunc(func(ctx context.Context, mod api.Module, stack []uint64) {
+			r := memReader{mem: mod.Memory(), ok: true}
+			responseHandle := ResponseHandle(api.DecodeU32(stack[0]))
+			bodyBufPtr := api.DecodeU32(stack[1])
+			bodyBufLen := api.DecodeU32(stack[2])
+			bodyBuf := r.bytes(bodyBufPtr, bodyBufLen)
+			if !r.ok {
+				panic(errMemoryAccess)
+			}
+			resultPtr := api.DecodeU32(stack[3])
+			result, errno := host.BodyRead(ctx, mod, responseHandle, bodyBuf)
+			if errno == 0 {
+				w := memWriter{mem: mod.Memory(), ok: true}
+				w.u32(resultPtr, uint32(result))
+				if !w.ok {
+					panic(errMemoryAccess)
+				}
+			}
+			stack[0] = api.EncodeU32(uint32(errno))
+		}), []api.ValueType{api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32, api.ValueTypeI32}, []api.ValueType{api.ValueTypeI32}).
+		WithParameterNames("response_handle", "body_buf_ptr", "body_buf_len", "result_ptr").
+		Export("body_read")
+	return builder
+}
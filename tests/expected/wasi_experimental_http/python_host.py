@@ -0,0 +1,250 @@
+#
+# This file was automatically generated by witx-codegen - Do not edit manually.
+#
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+import wasmtime
+
+WasiHandle = ctypes.c_int32
+WasiPtr = ctypes.c_uint32
+WasiUSize = ctypes.c_uint32
+Char8 = ctypes.c_uint8
+Char32 = ctypes.c_uint32
+
+
+class WasiString(ctypes.Structure):
+    """A string in guest memory."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiSlice(ctypes.Structure):
+    """A slice in guest memory, `len` being its number of elements."""
+
+    _pack_ = 1
+    _fields_ = [("ptr", WasiPtr), ("len", WasiUSize)]
+
+
+class WasiError(Exception):
+    """Raised by a host function to return the error code `code` to the guest."""
+
+    def __init__(self, code: int):
+        super().__init__(code)
+        self.code = code
+
+
+class GuestMemory:
+    """Memory exported by the guest instance calling a host function."""
+
+    def __init__(self, caller: wasmtime.Caller):
+        self._caller = caller
+        self._memory = None
+
+    def _export(self) -> wasmtime.Memory:
+        if self._memory is None:
+            self._memory = self._caller.get("memory")
+            if self._memory is None:
+                raise RuntimeError("The guest doesn't export its memory")
+        return self._memory
+
+    def read(self, ptr: int, length: int) -> bytes:
+        """Return the `length` bytes at address `ptr`."""
+        return bytes(self._export().read(self._caller, ptr, ptr + length))
+
+    def write(self, ptr: int, data: bytes) -> None:
+        """Write `data` at address `ptr`."""
+        self._export().write(self._caller, data, ptr)
+
+    def read_string(self, ptr: int, length: int) -> str:
+        """Return the UTF-8 string of `length` bytes at address `ptr`."""
+        return self.read(ptr, length).decode("utf-8")
+
+    def read_value(self, ctype, ptr: int):
+        """Return a copy of the value of type `ctype` at address `ptr`."""
+        return ctype.from_buffer_copy(self.read(ptr, ctypes.sizeof(ctype)))
+
+    def read_array(self, ctype, ptr: int, length: int) -> ctypes.Array:
+        """Return a copy of the `length` values of type `ctype` at address `ptr`."""
+        return self.read_value(ctype * length, ptr)
+
+    def write_value(self, ctype, ptr: int, value) -> None:
+        """Write `value`, converted to `ctype` if necessary, at address `ptr`."""
+        if not isinstance(value, ctype):
+            value = ctype(value)
+        self.write(ptr, bytes(value))
+
+
+class GuestBuffer:
+    """A buffer of `size` bytes in guest memory, to be filled by a host function."""
+
+    def __init__(self, memory: GuestMemory, ptr: int, size: int):
+        self.memory = memory
+        self.ptr = ptr
+        self.size = size
+
+    def write(self, data: bytes, offset: int = 0) -> None:
+        """Write `data` to the buffer, `offset` bytes after its start."""
+        if offset < 0 or offset + len(data) > self.size:
+            raise ValueError("The data doesn't fit in the guest buffer")
+        self.memory.write(self.ptr + offset, data)
+
+
+# ---------------------- Module: [wasi_experimental_http] ----------------------
+
+# Handles for the HTTP extensions
+HttpHandle = typing.NewType("HttpHandle", int)
+
+
+class HttpError(enum.IntEnum):
+    # Success
+    SUCCESS = 0
+    # Invalid handle
+    INVALID_HANDLE = 1
+    # Memory not found
+    MEMORY_NOT_FOUND = 2
+    # Memory access error
+    MEMORY_ACCESS_ERROR = 3
+    # Buffer too small
+    BUFFER_TOO_SMALL = 4
+    # Header not found
+    HEADER_NOT_FOUND = 5
+    # UTF-8 error
+    UTF8_ERROR = 6
+    # Destination not allowed
+    DESTINATION_NOT_ALLOWED = 7
+    # Invalid method
+    INVALID_METHOD = 8
+    # Invalid encoding
+    INVALID_ENCODING = 9
+    # Invalid URL
+    INVALID_URL = 10
+    # Request error
+    REQUEST_ERROR = 11
+    # Runtime error
+    RUNTIME_ERROR = 12
+    # Too many sessions
+    TOO_MANY_SESSIONS = 13
+
+
+# HTTP status code
+StatusCode = ctypes.c_uint16
+
+
+# An HTTP body being sent
+OutgoingBody = WasiSlice
+
+
+# Buffer for an HTTP body being received
+IncomingBody = WasiSlice
+
+
+# A response handle
+ResponseHandle = typing.NewType("ResponseHandle", int)
+
+
+# Buffer to store a header value
+HeaderValueBuf = WasiSlice
+
+
+# Number of bytes having been written
+WrittenBytes = WasiUSize
+
+
+class WasiExperimentalHttpHost:
+    """Functions of the `wasi_experimental_http` module, implemented by the host.
+
+    Override them in a subclass, and register an instance with `define_wasi_experimental_http_imports()`.
+    Methods receive the memory of the calling instance first.
+    Functions returning an error code raise `WasiError` to return it.
+    """
+
+    def req(self, memory: GuestMemory, url: str, method: str, headers: str, body: bytes) -> tuple[int, ResponseHandle]:
+        """Send a request"""
+        raise NotImplementedError("req")
+
+    def close(self, memory: GuestMemory, response_handle: ResponseHandle) -> None:
+        """Close a request handle"""
+        raise NotImplementedError("close")
+
+    def header_get(self, memory: GuestMemory, response_handle: ResponseHandle, header_name: str, header_value_buf: GuestBuffer) -> int:
+        """Get the value associated with a header"""
+        raise NotImplementedError("header_get")
+
+    def body_read(self, memory: GuestMemory, response_handle: ResponseHandle, body_buf: GuestBuffer) -> int:
+        """Fill a buffer with the streamed content of a response body"""
+        raise NotImplementedError("body_read")
+
+
+def define_wasi_experimental_http_imports(linker: wasmtime.Linker, store: wasmtime.Store, impl: WasiExperimentalHttpHost) -> None:
+    """Define the functions of the `wasi_experimental_http` module in `linker`, implemented by `impl`."""
+
+    def _req(_caller, url_ptr, url_len, method_ptr, method_len, headers_ptr, headers_len, body_ptr, body_len, result0_ptr, result1_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.req(_memory, _memory.read_string(url_ptr, url_len), _memory.read_string(method_ptr, method_len), _memory.read_string(headers_ptr, headers_len), _memory.read(body_ptr, body_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(StatusCode, result0_ptr, _results[0])
+        _memory.write_value(WasiHandle, result1_ptr, _results[1])
+        return 0
+
+    linker.define(store, "wasi_experimental_http", "req", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _req,
+        access_caller=True,
+    ))
+
+    def _close(_caller, response_handle):
+        _memory = GuestMemory(_caller)
+        try:
+            impl.close(_memory, ResponseHandle(response_handle))
+        except WasiError as _error:
+            return int(_error.code)
+        return 0
+
+    linker.define(store, "wasi_experimental_http", "close", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _close,
+        access_caller=True,
+    ))
+
+    def _header_get(_caller, response_handle, header_name_ptr, header_name_len, header_value_buf_ptr, header_value_buf_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.header_get(_memory, ResponseHandle(response_handle), _memory.read_string(header_name_ptr, header_name_len), GuestBuffer(_memory, header_value_buf_ptr, header_value_buf_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WrittenBytes, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_experimental_http", "header_get", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _header_get,
+        access_caller=True,
+    ))
+
+    def _body_read(_caller, response_handle, body_buf_ptr, body_buf_len, result_ptr):
+        _memory = GuestMemory(_caller)
+        try:
+            _results = impl.body_read(_memory, ResponseHandle(response_handle), GuestBuffer(_memory, body_buf_ptr, body_buf_len))
+        except WasiError as _error:
+            return int(_error.code)
+        _memory.write_value(WrittenBytes, result_ptr, _results)
+        return 0
+
+    linker.define(store, "wasi_experimental_http", "body_read", wasmtime.Func(
+        store,
+        wasmtime.FuncType([wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32(), wasmtime.ValType.i32()], [wasmtime.ValType.i32()]),
+        _body_read,
+        access_caller=True,
+    ))
+
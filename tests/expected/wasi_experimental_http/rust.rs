@@ -0,0 +1,593 @@
+
+//
+// This file was automatically generated by witx-codegen - Do not edit manually.
+//
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    WasiError(i32),
+}
+impl std::error::Error for Error {}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WasiError(e) => write!(f, "Wasi error {}", e),
+        }
+    }
+}
+
+/// The tag of a tagged union doesn't match any of its cases.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WasiInvalidTag(pub u64);
+
+impl core::fmt::Display for WasiInvalidTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid tag {}", self.0)
+    }
+}
+impl std::error::Error for WasiInvalidTag {}
+
+pub type WasiHandle = i32;
+pub type Char8 = u8;
+pub type Char32 = u32;
+pub type WasiPtr<T> = *const T;
+pub type WasiMutPtr<T> = *mut T;
+pub type WasiStringBytesPtr = WasiPtr<Char8>;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiSlice<T> {
+    ptr: WasiPtr<T>,
+    len: usize,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiMutSlice<T> {
+    ptr: WasiMutPtr<T>,
+    len: usize,
+}
+
+impl<T> WasiSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+impl<T> WasiMutSlice<T> {
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        WasiMutSlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct WasiString {
+    ptr: WasiStringBytesPtr,
+    len: usize,
+}
+
+impl<T: AsRef<str>> From<T> for WasiString {
+    fn from(s: T) -> Self {
+        let s = s.as_ref();
+        WasiString {
+            ptr: s.as_ptr() as _,
+            len: s.len(),
+        }
+    }
+}
+
+impl WasiString {
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(unsafe { core::slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Self {
+        WasiString {
+            ptr: slice.as_ptr() as _,
+            len: slice.len(),
+        }
+    }
+}
+
+// ---------------------- Module: [wasi_experimental_http] ----------------------
+
+/// Handles for the HTTP extensions
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct HttpHandle(pub WasiHandle);
+
+pub type HttpError = u32;
+
+#[allow(non_snake_case)]
+pub mod HTTP_ERROR {
+    use super::HttpError;
+    /// Success
+    pub const SUCCESS: HttpError = 0;
+    /// Invalid handle
+    pub const INVALID_HANDLE: HttpError = 1;
+    /// Memory not found
+    pub const MEMORY_NOT_FOUND: HttpError = 2;
+    /// Memory access error
+    pub const MEMORY_ACCESS_ERROR: HttpError = 3;
+    /// Buffer too small
+    pub const BUFFER_TOO_SMALL: HttpError = 4;
+    /// Header not found
+    pub const HEADER_NOT_FOUND: HttpError = 5;
+    /// UTF-8 error
+    pub const UTF8_ERROR: HttpError = 6;
+    /// Destination not allowed
+    pub const DESTINATION_NOT_ALLOWED: HttpError = 7;
+    /// Invalid method
+    pub const INVALID_METHOD: HttpError = 8;
+    /// Invalid encoding
+    pub const INVALID_ENCODING: HttpError = 9;
+    /// Invalid URL
+    pub const INVALID_URL: HttpError = 10;
+    /// Request error
+    pub const REQUEST_ERROR: HttpError = 11;
+    /// Runtime error
+    pub const RUNTIME_ERROR: HttpError = 12;
+    /// Too many sessions
+    pub const TOO_MANY_SESSIONS: HttpError = 13;
+}
+
+/// HTTP status code
+pub type StatusCode = u16;
+
+/// An HTTP body being sent
+pub type OutgoingBody = WasiSlice<u8>;
+
+/// Buffer for an HTTP body being received
+pub type IncomingBody = WasiMutSlice<u8>;
+
+/// A response handle
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ResponseHandle(pub WasiHandle);
+
+/// Buffer to store a header value
+pub type HeaderValueBuf = WasiMutSlice<u8>;
+
+/// Number of bytes having been written
+pub type WrittenBytes = usize;
+
+/// Error returned by the functions of the `wasi_experimental_http` module.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum WasiExperimentalHttpError {
+    /// Invalid handle
+    InvalidHandle,
+    /// Memory not found
+    MemoryNotFound,
+    /// Memory access error
+    MemoryAccessError,
+    /// Buffer too small
+    BufferTooSmall,
+    /// Header not found
+    HeaderNotFound,
+    /// UTF-8 error
+    Utf8Error,
+    /// Destination not allowed
+    DestinationNotAllowed,
+    /// Invalid method
+    InvalidMethod,
+    /// Invalid encoding
+    InvalidEncoding,
+    /// Invalid URL
+    InvalidUrl,
+    /// Request error
+    RequestError,
+    /// Runtime error
+    RuntimeError,
+    /// Too many sessions
+    TooManySessions,
+    /// An error code that is not an error case of `HttpError`.
+    Other(HttpError),
+}
+
+impl From<HttpError> for WasiExperimentalHttpError {
+    fn from(e: HttpError) -> Self {
+        match e {
+            HTTP_ERROR::INVALID_HANDLE => WasiExperimentalHttpError::InvalidHandle,
+            HTTP_ERROR::MEMORY_NOT_FOUND => WasiExperimentalHttpError::MemoryNotFound,
+            HTTP_ERROR::MEMORY_ACCESS_ERROR => WasiExperimentalHttpError::MemoryAccessError,
+            HTTP_ERROR::BUFFER_TOO_SMALL => WasiExperimentalHttpError::BufferTooSmall,
+            HTTP_ERROR::HEADER_NOT_FOUND => WasiExperimentalHttpError::HeaderNotFound,
+            HTTP_ERROR::UTF8_ERROR => WasiExperimentalHttpError::Utf8Error,
+            HTTP_ERROR::DESTINATION_NOT_ALLOWED => WasiExperimentalHttpError::DestinationNotAllowed,
+            HTTP_ERROR::INVALID_METHOD => WasiExperimentalHttpError::InvalidMethod,
+            HTTP_ERROR::INVALID_ENCODING => WasiExperimentalHttpError::InvalidEncoding,
+            HTTP_ERROR::INVALID_URL => WasiExperimentalHttpError::InvalidUrl,
+            HTTP_ERROR::REQUEST_ERROR => WasiExperimentalHttpError::RequestError,
+            HTTP_ERROR::RUNTIME_ERROR => WasiExperimentalHttpError::RuntimeError,
+            HTTP_ERROR::TOO_MANY_SESSIONS => WasiExperimentalHttpError::TooManySessions,
+            e => WasiExperimentalHttpError::Other(e),
+        }
+    }
+}
+
+impl From<WasiExperimentalHttpError> for HttpError {
+    fn from(e: WasiExperimentalHttpError) -> Self {
+        match e {
+            WasiExperimentalHttpError::InvalidHandle => HTTP_ERROR::INVALID_HANDLE,
+            WasiExperimentalHttpError::MemoryNotFound => HTTP_ERROR::MEMORY_NOT_FOUND,
+            WasiExperimentalHttpError::MemoryAccessError => HTTP_ERROR::MEMORY_ACCESS_ERROR,
+            WasiExperimentalHttpError::BufferTooSmall => HTTP_ERROR::BUFFER_TOO_SMALL,
+            WasiExperimentalHttpError::HeaderNotFound => HTTP_ERROR::HEADER_NOT_FOUND,
+            WasiExperimentalHttpError::Utf8Error => HTTP_ERROR::UTF8_ERROR,
+            WasiExperimentalHttpError::DestinationNotAllowed => HTTP_ERROR::DESTINATION_NOT_ALLOWED,
+            WasiExperimentalHttpError::InvalidMethod => HTTP_ERROR::INVALID_METHOD,
+            WasiExperimentalHttpError::InvalidEncoding => HTTP_ERROR::INVALID_ENCODING,
+            WasiExperimentalHttpError::InvalidUrl => HTTP_ERROR::INVALID_URL,
+            WasiExperimentalHttpError::RequestError => HTTP_ERROR::REQUEST_ERROR,
+            WasiExperimentalHttpError::RuntimeError => HTTP_ERROR::RUNTIME_ERROR,
+            WasiExperimentalHttpError::TooManySessions => HTTP_ERROR::TOO_MANY_SESSIONS,
+            WasiExperimentalHttpError::Other(e) => e,
+        }
+    }
+}
+
+impl From<WasiExperimentalHttpError> for Error {
+    fn from(e: WasiExperimentalHttpError) -> Self {
+        Error::WasiError(HttpError::from(e) as _)
+    }
+}
+
+impl core::fmt::Display for WasiExperimentalHttpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            WasiExperimentalHttpError::InvalidHandle => "Invalid handle",
+            WasiExperimentalHttpError::MemoryNotFound => "Memory not found",
+            WasiExperimentalHttpError::MemoryAccessError => "Memory access error",
+            WasiExperimentalHttpError::BufferTooSmall => "Buffer too small",
+            WasiExperimentalHttpError::HeaderNotFound => "Header not found",
+            WasiExperimentalHttpError::Utf8Error => "UTF-8 error",
+            WasiExperimentalHttpError::DestinationNotAllowed => "Destination not allowed",
+            WasiExperimentalHttpError::InvalidMethod => "Invalid method",
+            WasiExperimentalHttpError::InvalidEncoding => "Invalid encoding",
+            WasiExperimentalHttpError::InvalidUrl => "Invalid URL",
+            WasiExperimentalHttpError::RequestError => "Request error",
+            WasiExperimentalHttpError::RuntimeError => "Runtime error",
+            WasiExperimentalHttpError::TooManySessions => "Too many sessions",
+            WasiExperimentalHttpError::Other(e) => return write!(f, "Unknown error {}", e),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for WasiExperimentalHttpError {}
+
+/// Send a request
+pub fn req(
+    url_ptr: WasiPtr<Char8>,
+    url_len: usize,
+    method_ptr: WasiPtr<Char8>,
+    method_len: usize,
+    headers_ptr: WasiPtr<Char8>,
+    headers_len: usize,
+    body_ptr: WasiPtr<u8>,
+    body_len: usize,
+) -> Result<(StatusCode, ResponseHandle), WasiExperimentalHttpError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_experimental_http")]
+        extern "C" {
+            pub fn req(
+                url_ptr: WasiPtr<Char8>,
+                url_len: usize,
+                method_ptr: WasiPtr<Char8>,
+                method_len: usize,
+                headers_ptr: WasiPtr<Char8>,
+                headers_len: usize,
+                body_ptr: WasiPtr<u8>,
+                body_len: usize,
+                result0_ptr: WasiMutPtr<StatusCode>,
+                result1_ptr: WasiMutPtr<ResponseHandle>,
+            ) -> HttpError;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn req(
+            url_ptr: WasiPtr<Char8>,
+            url_len: usize,
+            method_ptr: WasiPtr<Char8>,
+            method_len: usize,
+            headers_ptr: WasiPtr<Char8>,
+            headers_len: usize,
+            body_ptr: WasiPtr<u8>,
+            body_len: usize,
+            result0_ptr: WasiMutPtr<StatusCode>,
+            result1_ptr: WasiMutPtr<ResponseHandle>,
+        ) -> HttpError {
+            match super::with_wasi_experimental_http_mock(|mock| mock.req(url_ptr, url_len, method_ptr, method_len, headers_ptr, headers_len, body_ptr, body_len)) {
+                Ok((res0, res1)) => {
+                    unsafe { result0_ptr.write(res0) };
+                    unsafe { result1_ptr.write(res1) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result0_ptr = core::mem::MaybeUninit::uninit();
+    let mut result1_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::req(
+        url_ptr,
+        url_len,
+        method_ptr,
+        method_len,
+        headers_ptr,
+        headers_len,
+        body_ptr,
+        body_len,
+        result0_ptr.as_mut_ptr(),
+        result1_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(WasiExperimentalHttpError::from(res));
+    }
+    Ok(unsafe { (result0_ptr.assume_init(), result1_ptr.assume_init()) })
+}
+
+/// Close a request handle
+pub fn close(
+    response_handle: ResponseHandle,
+) -> Result<(), WasiExperimentalHttpError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_experimental_http")]
+        extern "C" {
+            pub fn close(
+                response_handle: ResponseHandle,
+            ) -> HttpError;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn close(
+            response_handle: ResponseHandle,
+        ) -> HttpError {
+            match super::with_wasi_experimental_http_mock(|mock| mock.close(response_handle)) {
+                Ok(()) => {
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let res = unsafe { raw::close(
+        response_handle,
+    )};
+    if res != 0 {
+        return Err(WasiExperimentalHttpError::from(res));
+    }
+    Ok(())
+}
+
+/// Get the value associated with a header
+pub fn header_get(
+    response_handle: ResponseHandle,
+    header_name_ptr: WasiPtr<Char8>,
+    header_name_len: usize,
+    header_value_buf_ptr: WasiMutPtr<u8>,
+    header_value_buf_len: usize,
+) -> Result<WrittenBytes, WasiExperimentalHttpError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_experimental_http")]
+        extern "C" {
+            pub fn header_get(
+                response_handle: ResponseHandle,
+                header_name_ptr: WasiPtr<Char8>,
+                header_name_len: usize,
+                header_value_buf_ptr: WasiMutPtr<u8>,
+                header_value_buf_len: usize,
+                result_ptr: WasiMutPtr<WrittenBytes>,
+            ) -> HttpError;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn header_get(
+            response_handle: ResponseHandle,
+            header_name_ptr: WasiPtr<Char8>,
+            header_name_len: usize,
+            header_value_buf_ptr: WasiMutPtr<u8>,
+            header_value_buf_len: usize,
+            result_ptr: WasiMutPtr<WrittenBytes>,
+        ) -> HttpError {
+            match super::with_wasi_experimental_http_mock(|mock| mock.header_get(response_handle, header_name_ptr, header_name_len, header_value_buf_ptr, header_value_buf_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::header_get(
+        response_handle,
+        header_name_ptr,
+        header_name_len,
+        header_value_buf_ptr,
+        header_value_buf_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(WasiExperimentalHttpError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Fill a buffer with the streamed content of a response body
+pub fn body_read(
+    response_handle: ResponseHandle,
+    body_buf_ptr: WasiMutPtr<u8>,
+    body_buf_len: usize,
+) -> Result<WrittenBytes, WasiExperimentalHttpError> {
+    mod raw {
+        use super::*;
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "wasi_experimental_http")]
+        extern "C" {
+            pub fn body_read(
+                response_handle: ResponseHandle,
+                body_buf_ptr: WasiMutPtr<u8>,
+                body_buf_len: usize,
+                result_ptr: WasiMutPtr<WrittenBytes>,
+            ) -> HttpError;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        pub unsafe fn body_read(
+            response_handle: ResponseHandle,
+            body_buf_ptr: WasiMutPtr<u8>,
+            body_buf_len: usize,
+            result_ptr: WasiMutPtr<WrittenBytes>,
+        ) -> HttpError {
+            match super::with_wasi_experimental_http_mock(|mock| mock.body_read(response_handle, body_buf_ptr, body_buf_len)) {
+                Ok(res) => {
+                    unsafe { result_ptr.write(res) };
+                    0
+                }
+                Err(e) => e.into(),
+            }
+        }
+    }
+    let mut result_ptr = core::mem::MaybeUninit::uninit();
+    let res = unsafe { raw::body_read(
+        response_handle,
+        body_buf_ptr,
+        body_buf_len,
+        result_ptr.as_mut_ptr(),
+    )};
+    if res != 0 {
+        return Err(WasiExperimentalHttpError::from(res));
+    }
+    Ok(unsafe { result_ptr.assume_init() })
+}
+
+/// Implementation of the functions of the `wasi_experimental_http` module used on native targets.
+/// Install it with `set_wasi_experimental_http_mock()`. Functions that are not implemented panic.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait WasiExperimentalHttpMock {
+    /// Send a request
+    #[allow(unused_variables)]
+    fn req(
+        &self,
+        url_ptr: WasiPtr<Char8>,
+        url_len: usize,
+        method_ptr: WasiPtr<Char8>,
+        method_len: usize,
+        headers_ptr: WasiPtr<Char8>,
+        headers_len: usize,
+        body_ptr: WasiPtr<u8>,
+        body_len: usize,
+    ) -> Result<(StatusCode, ResponseHandle), WasiExperimentalHttpError> {
+        unimplemented!("req")
+    }
+
+    /// Close a request handle
+    #[allow(unused_variables)]
+    fn close(
+        &self,
+        response_handle: ResponseHandle,
+    ) -> Result<(), WasiExperimentalHttpError> {
+        unimplemented!("close")
+    }
+
+    /// Get the value associated with a header
+    #[allow(unused_variables)]
+    fn header_get(
+        &self,
+        response_handle: ResponseHandle,
+        header_name_ptr: WasiPtr<Char8>,
+        header_name_len: usize,
+        header_value_buf_ptr: WasiMutPtr<u8>,
+        header_value_buf_len: usize,
+    ) -> Result<WrittenBytes, WasiExperimentalHttpError> {
+        unimplemented!("header_get")
+    }
+
+    /// Fill a buffer with the streamed content of a response body
+    #[allow(unused_variables)]
+    fn body_read(
+        &self,
+        response_handle: ResponseHandle,
+        body_buf_ptr: WasiMutPtr<u8>,
+        body_buf_len: usize,
+    ) -> Result<WrittenBytes, WasiExperimentalHttpError> {
+        unimplemented!("body_read")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static WASI_EXPERIMENTAL_HTTP_MOCK: std::cell::RefCell<Option<std::rc::Rc<dyn WasiExperimentalHttpMock>>> = std::cell::RefCell::new(None);
+}
+
+/// Install the implementation of the `wasi_experimental_http` module used by the current thread, and return the previous one.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_wasi_experimental_http_mock(mock: impl WasiExperimentalHttpMock + 'static) -> Option<std::rc::Rc<dyn WasiExperimentalHttpMock>> {
+    WASI_EXPERIMENTAL_HTTP_MOCK.with(|registry| registry.borrow_mut().replace(std::rc::Rc::new(mock)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn with_wasi_experimental_http_mock<R>(f: impl FnOnce(&dyn WasiExperimentalHttpMock) -> R) -> R {
+    let mock = WASI_EXPERIMENTAL_HTTP_MOCK.with(|registry| registry.borrow().clone());
+    f(mock.as_deref().expect("No implementation of the `wasi_experimental_http` module installed"))
+}
+
+/// Owned `ResponseHandle` handle, closed with `close()` when dropped.
+#[derive(Debug)]
+pub struct OwnedResponseHandle(ResponseHandle);
+
+impl OwnedResponseHandle {
+    pub fn new(handle: ResponseHandle) -> Self {
+        OwnedResponseHandle(handle)
+    }
+
+    pub fn get(&self) -> ResponseHandle {
+        self.0
+    }
+
+    pub fn release(self) -> ResponseHandle {
+        let handle = self.0;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedResponseHandle {
+    fn drop(&mut self) {
+        let _ = close(self.0);
+    }
+}
+
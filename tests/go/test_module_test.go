@@ -0,0 +1,85 @@
+// Write the results of the functions of `test/test_module.witx` to guest memory, through the
+// generated Go host bindings.
+package testmodule
+
+import (
+	"context"
+	"encoding/binary"
+	"strconv"
+	"testing"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+type host struct {
+	TestModuleHost
+}
+
+func (h *host) AFunctionThatReturnsMultipleValues(ctx context.Context, mod api.Module, someParameter uint64, someOtherParameter string) (TestMediumInt, TestBigInt, TestErrno) {
+	if someOtherParameter == "" {
+		return 0, 0, TestErrnoSomeOtherError
+	}
+	return TestMediumInt(someParameter * 2), TestBigInt(len(someOtherParameter)), TestErrnoSuccess
+}
+
+func (h *host) AFunctionThatReturnsATaggedUnion(ctx context.Context, mod api.Module, str string) (TestTaggedUnion, TestErrno) {
+	v, err := strconv.ParseFloat(str, 32)
+	if err != nil {
+		return TestTaggedUnion{}, TestErrnoGuestError
+	}
+	return TestTaggedUnion{Tag: TestTaggedUnionThirdChoice, ThirdChoice: float32(v)}, TestErrnoSuccess
+}
+
+func setUp() (wazero.HostModuleBuilder, *api.TestModule) {
+	builder := DefineTestModuleImports(wazero.NewRuntime(context.Background()), &host{})
+	return builder, api.NewTestModule(64)
+}
+
+func TestMultipleValues(t *testing.T) {
+	builder, mod := setUp()
+	copy(mod.Mem.Bytes, "hello")
+	if v := wazero.Call(builder, mod, "a_function_that_returns_multiple_values", 21, 0, 5, 8, 16)[0]; v != 0 {
+		t.Fatalf("errno: %d", v)
+	}
+	if v := binary.LittleEndian.Uint32(mod.Mem.Bytes[8:]); v != 42 {
+		t.Errorf("result0: %d", v)
+	}
+	if v := binary.LittleEndian.Uint64(mod.Mem.Bytes[16:]); v != 5 {
+		t.Errorf("result1: %d", v)
+	}
+}
+
+func TestError(t *testing.T) {
+	builder, mod := setUp()
+	if v := wazero.Call(builder, mod, "a_function_that_returns_multiple_values", 21, 0, 0, 8, 16)[0]; v != uint64(TestErrnoSomeOtherError) {
+		t.Errorf("errno: %d", v)
+	}
+	for _, b := range mod.Mem.Bytes[8:24] {
+		if b != 0 {
+			t.Fatal("results were written")
+		}
+	}
+}
+
+func TestTaggedUnion(t *testing.T) {
+	builder, mod := setUp()
+	copy(mod.Mem.Bytes, "1.5")
+	if v := wazero.Call(builder, mod, "a_function_that_returns_a_tagged_union", 0, 3, 8)[0]; v != 0 {
+		t.Fatalf("errno: %d", v)
+	}
+	union, ok := ReadTestTaggedUnion(mod.Memory(), 8)
+	if !ok || union.Tag != TestTaggedUnionThirdChoice || union.ThirdChoice != 1.5 {
+		t.Errorf("union: %+v", union)
+	}
+}
+
+func TestOutOfRange(t *testing.T) {
+	builder, mod := setUp()
+	defer func() {
+		if v := recover(); v != errMemoryAccess {
+			t.Errorf("out of range access panicked with %v", v)
+		}
+	}()
+	wazero.Call(builder, mod, "a_function_that_returns_multiple_values", 21, 60, 5, 8, 16)
+}
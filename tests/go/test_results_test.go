@@ -0,0 +1,105 @@
+// Call the functions of `test/test_results.witx` through the generated Go host bindings.
+package testresults
+
+import (
+	"context"
+	"reflect"
+	"testing"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+type host struct {
+	yielded bool
+}
+
+func (h *host) ProcExit(ctx context.Context, mod api.Module, rval Exitcode) {
+	panic(rval)
+}
+
+func (h *host) SchedYield(ctx context.Context, mod api.Module) {
+	h.yielded = true
+}
+
+func (h *host) RandomU32(ctx context.Context, mod api.Module) uint32 {
+	return 4
+}
+
+func (h *host) FdFlags(ctx context.Context, mod api.Module, fd Fd) Fdflags {
+	return FdflagsAppend | FdflagsNonblock
+}
+
+func (h *host) FdDup(ctx context.Context, mod api.Module, fd Fd) Fd {
+	return fd + 1
+}
+
+func (h *host) BufferPtr(ctx context.Context, mod api.Module) WasiPtr {
+	return 16
+}
+
+func (h *host) FdClose(ctx context.Context, mod api.Module, fd Fd) Errno {
+	if fd < 0 {
+		return ErrnoBadf
+	}
+	return ErrnoSuccess
+}
+
+func define(h *host) wazero.HostModuleBuilder {
+	return DefineTestResultsImports(wazero.NewRuntime(context.Background()), h)
+}
+
+func TestSignatures(t *testing.T) {
+	builder := define(&host{})
+	fdClose := wazero.Function(builder, "fd_close")
+	i32 := []api.ValueType{api.ValueTypeI32}
+	if !reflect.DeepEqual(fdClose.Params, i32) || !reflect.DeepEqual(fdClose.Results, i32) {
+		t.Errorf("fd_close: %v -> %v", fdClose.Params, fdClose.Results)
+	}
+	if !reflect.DeepEqual(fdClose.ParameterNames, []string{"fd"}) {
+		t.Errorf("fd_close parameter names: %v", fdClose.ParameterNames)
+	}
+	schedYield := wazero.Function(builder, "sched_yield")
+	if len(schedYield.Params) != 0 || len(schedYield.Results) != 0 {
+		t.Errorf("sched_yield: %v -> %v", schedYield.Params, schedYield.Results)
+	}
+}
+
+func TestValues(t *testing.T) {
+	h := &host{}
+	builder := define(h)
+	mod := api.NewTestModule(64)
+	wazero.Call(builder, mod, "sched_yield")
+	if !h.yielded {
+		t.Error("sched_yield wasn't called")
+	}
+	if v := wazero.Call(builder, mod, "random_u32")[0]; v != 4 {
+		t.Errorf("random_u32: %d", v)
+	}
+	if v := wazero.Call(builder, mod, "fd_flags", 3)[0]; v != 3 {
+		t.Errorf("fd_flags: %d", v)
+	}
+	if v := wazero.Call(builder, mod, "fd_dup", 3)[0]; v != 4 {
+		t.Errorf("fd_dup: %d", v)
+	}
+}
+
+func TestErrors(t *testing.T) {
+	builder := define(&host{})
+	mod := api.NewTestModule(64)
+	if v := wazero.Call(builder, mod, "fd_close", 3)[0]; v != 0 {
+		t.Errorf("fd_close(3): %d", v)
+	}
+	if v := wazero.Call(builder, mod, "fd_close", api.EncodeU32(0xffffffff))[0]; v != uint64(ErrnoBadf) {
+		t.Errorf("fd_close(-1): %d", v)
+	}
+}
+
+func TestNoreturn(t *testing.T) {
+	defer func() {
+		if v := recover(); v != Exitcode(1) {
+			t.Errorf("proc_exit panicked with %v", v)
+		}
+	}()
+	wazero.Call(define(&host{}), api.NewTestModule(64), "proc_exit", 1)
+}
@@ -0,0 +1,75 @@
+// Read and write the types of `test/test_serde.witx` in guest memory through the generated Go
+// host bindings, and pass them to a host function.
+package testserde
+
+import (
+	"context"
+	"testing"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+)
+
+type host struct {
+	shape Shape
+	label Label
+	text  string
+}
+
+func (h *host) Draw(ctx context.Context, mod api.Module, shape Shape, label Label) {
+	h.shape = shape
+	h.label = label
+	h.text, _ = label.Text.Read(mod.Memory())
+}
+
+func TestEnums(t *testing.T) {
+	if ColorLightBlue != 2 || PermissionsRead|PermissionsExecute != 5 {
+		t.Error("unexpected constant values")
+	}
+}
+
+func TestLayout(t *testing.T) {
+	mod := api.NewTestModule(64)
+	pixel := Pixel{X: 1, Y: 2, Color: ColorGreen, Type: PermissionsWrite}
+	if !WriteShapes(mod.Memory(), 0, Shapes{V0: Shape{Tag: ShapePoint, Point: pixel}, V1: ColorLightBlue}) {
+		t.Fatal("write failed")
+	}
+	bytes := mod.Mem.Bytes
+	if bytes[0] != ShapePoint || bytes[8] != 1 || bytes[16] != 2 || bytes[24] != 1 || bytes[26] != 2 || bytes[32] != 2 {
+		t.Errorf("unexpected layout: %v", bytes[:40])
+	}
+	shapes, ok := ReadShapes(mod.Memory(), 0)
+	if !ok || shapes.V0.Point != pixel || shapes.V1 != ColorLightBlue {
+		t.Errorf("shapes: %+v", shapes)
+	}
+}
+
+func TestInvalid(t *testing.T) {
+	mod := api.NewTestModule(64)
+	mod.Mem.Bytes[0] = 4
+	if _, ok := ReadShape(mod.Memory(), 0); ok {
+		t.Error("invalid tag was read")
+	}
+	if _, ok := ReadShape(mod.Memory(), 40); ok {
+		t.Error("out of range union was read")
+	}
+	if WriteShape(mod.Memory(), 0, Shape{Tag: 4}) {
+		t.Error("invalid tag was written")
+	}
+}
+
+func TestCall(t *testing.T) {
+	h := &host{}
+	builder := DefineTestSerdeImports(wazero.NewRuntime(context.Background()), h)
+	mod := api.NewTestModule(256)
+	WriteShape(mod.Memory(), 0, Shape{Tag: ShapeCircle, Circle: 7})
+	copy(mod.Mem.Bytes[64:], "hello")
+	WriteLabel(mod.Memory(), 32, Label{Text: WasiString{Ptr: 64, Len: 5}, Color: ColorRed})
+	wazero.Call(builder, mod, "draw", 0, 32)
+	if h.shape.Tag != ShapeCircle || h.shape.Circle != 7 {
+		t.Errorf("shape: %+v", h.shape)
+	}
+	if h.label.Color != ColorRed || h.text != "hello" {
+		t.Errorf("label: %+v, %q", h.label, h.text)
+	}
+}
@@ -0,0 +1,168 @@
+// Package api is the subset of the wazero API used by the generated host bindings, with an
+// in-memory implementation of Memory.
+package api
+
+import (
+	"context"
+	"encoding/binary"
+	"math"
+)
+
+type ValueType = byte
+
+const (
+	ValueTypeI32 ValueType = 0x7f
+	ValueTypeI64 ValueType = 0x7e
+	ValueTypeF32 ValueType = 0x7d
+	ValueTypeF64 ValueType = 0x7c
+)
+
+func EncodeU32(v uint32) uint64 { return uint64(v) }
+
+func DecodeU32(v uint64) uint32 { return uint32(v) }
+
+func EncodeF32(v float32) uint64 { return uint64(math.Float32bits(v)) }
+
+func DecodeF32(v uint64) float32 { return math.Float32frombits(uint32(v)) }
+
+func EncodeF64(v float64) uint64 { return math.Float64bits(v) }
+
+func DecodeF64(v uint64) float64 { return math.Float64frombits(v) }
+
+type Module interface {
+	Memory() Memory
+}
+
+type GoModuleFunction interface {
+	Call(ctx context.Context, mod Module, stack []uint64)
+}
+
+type GoModuleFunc func(ctx context.Context, mod Module, stack []uint64)
+
+func (f GoModuleFunc) Call(ctx context.Context, mod Module, stack []uint64) {
+	f(ctx, mod, stack)
+}
+
+type Memory interface {
+	Size() uint32
+	ReadByte(offset uint32) (byte, bool)
+	ReadUint16Le(offset uint32) (uint16, bool)
+	ReadUint32Le(offset uint32) (uint32, bool)
+	ReadUint64Le(offset uint32) (uint64, bool)
+	ReadFloat32Le(offset uint32) (float32, bool)
+	ReadFloat64Le(offset uint32) (float64, bool)
+	Read(offset, byteCount uint32) ([]byte, bool)
+	WriteByte(offset uint32, v byte) bool
+	WriteUint16Le(offset uint32, v uint16) bool
+	WriteUint32Le(offset uint32, v uint32) bool
+	WriteUint64Le(offset uint32, v uint64) bool
+	WriteFloat32Le(offset uint32, v float32) bool
+	WriteFloat64Le(offset uint32, v float64) bool
+}
+
+// BytesMemory is a Memory backed by a byte slice.
+type BytesMemory struct {
+	Bytes []byte
+}
+
+// TestModule is a Module with a memory of size bytes.
+type TestModule struct {
+	Mem *BytesMemory
+}
+
+func NewTestModule(size uint32) *TestModule {
+	return &TestModule{Mem: &BytesMemory{Bytes: make([]byte, size)}}
+}
+
+func (m *TestModule) Memory() Memory { return m.Mem }
+
+func (m *BytesMemory) Size() uint32 { return uint32(len(m.Bytes)) }
+
+func (m *BytesMemory) Read(offset, byteCount uint32) ([]byte, bool) {
+	if uint64(offset)+uint64(byteCount) > uint64(len(m.Bytes)) {
+		return nil, false
+	}
+	return m.Bytes[offset : offset+byteCount : offset+byteCount], true
+}
+
+func (m *BytesMemory) ReadByte(offset uint32) (byte, bool) {
+	b, ok := m.Read(offset, 1)
+	if !ok {
+		return 0, false
+	}
+	return b[0], true
+}
+
+func (m *BytesMemory) ReadUint16Le(offset uint32) (uint16, bool) {
+	b, ok := m.Read(offset, 2)
+	if !ok {
+		return 0, false
+	}
+	return binary.LittleEndian.Uint16(b), true
+}
+
+func (m *BytesMemory) ReadUint32Le(offset uint32) (uint32, bool) {
+	b, ok := m.Read(offset, 4)
+	if !ok {
+		return 0, false
+	}
+	return binary.LittleEndian.Uint32(b), true
+}
+
+func (m *BytesMemory) ReadUint64Le(offset uint32) (uint64, bool) {
+	b, ok := m.Read(offset, 8)
+	if !ok {
+		return 0, false
+	}
+	return binary.LittleEndian.Uint64(b), true
+}
+
+func (m *BytesMemory) ReadFloat32Le(offset uint32) (float32, bool) {
+	v, ok := m.ReadUint32Le(offset)
+	return math.Float32frombits(v), ok
+}
+
+func (m *BytesMemory) ReadFloat64Le(offset uint32) (float64, bool) {
+	v, ok := m.ReadUint64Le(offset)
+	return math.Float64frombits(v), ok
+}
+
+func (m *BytesMemory) WriteByte(offset uint32, v byte) bool {
+	b, ok := m.Read(offset, 1)
+	if ok {
+		b[0] = v
+	}
+	return ok
+}
+
+func (m *BytesMemory) WriteUint16Le(offset uint32, v uint16) bool {
+	b, ok := m.Read(offset, 2)
+	if ok {
+		binary.LittleEndian.PutUint16(b, v)
+	}
+	return ok
+}
+
+func (m *BytesMemory) WriteUint32Le(offset uint32, v uint32) bool {
+	b, ok := m.Read(offset, 4)
+	if ok {
+		binary.LittleEndian.PutUint32(b, v)
+	}
+	return ok
+}
+
+func (m *BytesMemory) WriteUint64Le(offset uint32, v uint64) bool {
+	b, ok := m.Read(offset, 8)
+	if ok {
+		binary.LittleEndian.PutUint64(b, v)
+	}
+	return ok
+}
+
+func (m *BytesMemory) WriteFloat32Le(offset uint32, v float32) bool {
+	return m.WriteUint32Le(offset, math.Float32bits(v))
+}
+
+func (m *BytesMemory) WriteFloat64Le(offset uint32, v float64) bool {
+	return m.WriteUint64Le(offset, math.Float64bits(v))
+}
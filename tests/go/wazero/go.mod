@@ -0,0 +1,4 @@
+// Stands in for wazero, so that host functions can be called without a WebAssembly runtime.
+module github.com/tetratelabs/wazero
+
+go 1.21
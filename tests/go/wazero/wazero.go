@@ -0,0 +1,86 @@
+// Package wazero is the subset of the wazero API used by the generated host bindings. Host
+// modules record their functions, that tests call with Call.
+package wazero
+
+import (
+	"context"
+
+	"github.com/tetratelabs/wazero/api"
+)
+
+type Runtime interface {
+	NewHostModuleBuilder(moduleName string) HostModuleBuilder
+}
+
+type HostModuleBuilder interface {
+	NewFunctionBuilder() HostFunctionBuilder
+}
+
+type HostFunctionBuilder interface {
+	WithGoModuleFunction(fn api.GoModuleFunction, params, results []api.ValueType) HostFunctionBuilder
+	WithParameterNames(names ...string) HostFunctionBuilder
+	Export(name string) HostModuleBuilder
+}
+
+// HostFunction is a function exported by a host module.
+type HostFunction struct {
+	Fn             api.GoModuleFunction
+	Params         []api.ValueType
+	Results        []api.ValueType
+	ParameterNames []string
+}
+
+type runtime struct{}
+
+func NewRuntime(ctx context.Context) Runtime {
+	return &runtime{}
+}
+
+func (r *runtime) NewHostModuleBuilder(moduleName string) HostModuleBuilder {
+	return &hostModuleBuilder{name: moduleName, functions: map[string]*HostFunction{}}
+}
+
+type hostModuleBuilder struct {
+	name      string
+	functions map[string]*HostFunction
+}
+
+func (b *hostModuleBuilder) NewFunctionBuilder() HostFunctionBuilder {
+	return &hostFunctionBuilder{module: b, function: &HostFunction{}}
+}
+
+type hostFunctionBuilder struct {
+	module   *hostModuleBuilder
+	function *HostFunction
+}
+
+func (b *hostFunctionBuilder) WithGoModuleFunction(fn api.GoModuleFunction, params, results []api.ValueType) HostFunctionBuilder {
+	b.function.Fn = fn
+	b.function.Params = params
+	b.function.Results = results
+	return b
+}
+
+func (b *hostFunctionBuilder) WithParameterNames(names ...string) HostFunctionBuilder {
+	b.function.ParameterNames = names
+	return b
+}
+
+func (b *hostFunctionBuilder) Export(name string) HostModuleBuilder {
+	b.module.functions[name] = b.function
+	return b.module
+}
+
+// Function returns the function exported as name by a host module, or nil.
+func Function(builder HostModuleBuilder, name string) *HostFunction {
+	return builder.(*hostModuleBuilder).functions[name]
+}
+
+// Call calls the function exported as name by a host module with args, and returns its results.
+func Call(builder HostModuleBuilder, mod api.Module, name string, args ...uint64) []uint64 {
+	function := Function(builder, name)
+	stack := make([]uint64, max(len(function.Params), len(function.Results)))
+	copy(stack, args)
+	function.Fn.Call(context.Background(), mod, stack)
+	return stack[:len(function.Results)]
+}
@@ -0,0 +1,124 @@
+//! Run the tests of `tests/go/<name>_test.go` with Go, against the Go host bindings generated
+//! for `test/<name>.witx`.
+//!
+//! `tests/go/wazero` stands in for wazero, so that host functions can be called without a
+//! WebAssembly runtime. Bindings without tests are only built, and all of them are checked to be
+//! formatted with `gofmt`. All the bindings are also built against the release of wazero
+//! `WAZERO_VERSION`, downloaded with the Go module proxy.
+//!
+//! These tests require `go`, and are ignored by default: run them with
+//! `cargo test --test go_host -- --include-ignored`.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The release of wazero that the bindings are checked against.
+const WAZERO_VERSION: &str = "v1.8.0";
+
+fn go() -> Command {
+    let mut command = Command::new(std::env::var_os("GO").unwrap_or_else(|| "go".into()));
+    command.env("GOFLAGS", "-mod=mod");
+    command
+}
+
+fn check_go() {
+    assert!(
+        go().arg("version").output().is_ok(),
+        "go isn't installed, set `GO` to its path if it isn't in `PATH`"
+    );
+}
+
+/// Generate the Go host bindings for `witx_file` into a package of `out_dir`, whose `go.mod`
+/// ends with `wazero`, and return the directory of the package.
+fn generate(out_dir: &Path, witx_file: &Path, wazero: &str) -> PathBuf {
+    let name = witx_file.file_stem().unwrap().to_string_lossy();
+    let package_dir = out_dir.join(name.as_ref());
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+        package_dir.join("go.mod"),
+        format!("module example.com/{}\n\ngo 1.21\n\n{}", name, wazero),
+    )
+    .unwrap();
+    let go_file = package_dir.join(format!("{}_host.go", name));
+    let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+        .arg("--output-type")
+        .arg("go-host")
+        .arg("--output")
+        .arg(&go_file)
+        .arg(witx_file)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Generation failed for {}: {}",
+        witx_file.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    package_dir
+}
+
+/// Run `command` in `package_dir`.
+fn run(mut command: Command, package_dir: &Path, witx_file: &Path) {
+    let output = command.arg(".").current_dir(package_dir).output().unwrap();
+    assert!(
+        output.status.success(),
+        "Go host bindings for {} failed:\n{}{}",
+        witx_file.display(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+#[ignore = "requires go"]
+fn go_host() {
+    check_go();
+    let wazero_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/go/wazero");
+    let wazero = format!(
+        "require github.com/tetratelabs/wazero v1.0.0\n\nreplace github.com/tetratelabs/wazero => {}\n",
+        wazero_dir.display()
+    );
+    let out_dir = common::tmp_dir("go_host");
+    for witx_file in common::witx_files() {
+        let name = witx_file.file_stem().unwrap().to_string_lossy();
+        let package_dir = generate(&out_dir, &witx_file, &wazero);
+
+        let go_file = package_dir.join(format!("{}_host.go", name));
+        if let Ok(output) = Command::new("gofmt").arg("-l").arg(&go_file).output() {
+            assert!(
+                output.status.success() && output.stdout.is_empty(),
+                "Go host bindings for {} aren't formatted with gofmt",
+                witx_file.display()
+            );
+        }
+
+        // Without tests, the bindings are only built, which checks the definitions of the types
+        let test_file = Path::new("tests/go").join(format!("{}_test.go", name));
+        let mut command = go();
+        command.env("GOPROXY", "off");
+        if test_file.exists() {
+            fs::copy(&test_file, package_dir.join(test_file.file_name().unwrap())).unwrap();
+            command.arg("test");
+        } else {
+            command.arg("build");
+        }
+        run(command, &package_dir, &witx_file);
+    }
+}
+
+#[test]
+#[ignore = "requires go, and access to the Go module proxy"]
+fn go_host_wazero() {
+    check_go();
+    let wazero = format!("require github.com/tetratelabs/wazero {}\n", WAZERO_VERSION);
+    let out_dir = common::tmp_dir("go_host_wazero");
+    for witx_file in common::witx_files() {
+        let package_dir = generate(&out_dir, &witx_file, &wazero);
+        let mut command = go();
+        command.arg("build");
+        run(command, &package_dir, &witx_file);
+    }
+}
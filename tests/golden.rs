@@ -0,0 +1,119 @@
+//! Run every generator over the WITX files in `test/`, and compare the outputs with the expected
+//! ones in `tests/expected/`.
+//!
+//! Run with `BLESS=1` to update the expected outputs after an intended change, which also removes
+//! the expected outputs that are no longer generated.
+
+mod common;
+
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const OUTPUT_TYPES: &[&str] = &[
+    "assemblyscript",
+    "zig",
+    "rust",
+    "cpp",
+    "python-host",
+    "go-host",
+    "overview",
+    "markdown",
+];
+
+/// Return the first line that differs between `expected` and `actual`, starting from 1.
+fn first_difference(expected: &str, actual: &str) -> usize {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    while expected_lines.next() == actual_lines.next() {
+        line += 1;
+    }
+    line
+}
+
+/// Return the names of the entries of `dir`, or an empty set if it doesn't exist.
+fn entry_names(dir: &Path) -> BTreeSet<OsString> {
+    match fs::read_dir(dir) {
+        Ok(entries) => entries.map(|entry| entry.unwrap().file_name()).collect(),
+        Err(_) => BTreeSet::new(),
+    }
+}
+
+#[test]
+fn golden() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let expected_root = root.join("tests").join("expected");
+    let bless = std::env::var_os("BLESS").is_some_and(|x| x != "0");
+    let mut mismatches = vec![];
+    let mut stems = BTreeSet::new();
+
+    for witx_file in common::witx_files() {
+        let stem = witx_file.file_stem().unwrap();
+        stems.insert(stem.to_os_string());
+        let out_dir = common::tmp_dir(&format!("golden/{}", stem.to_string_lossy()));
+        let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+            .arg("--output-type")
+            .arg(OUTPUT_TYPES.join(","))
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .arg(&witx_file)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "Generation failed for {}: {}",
+            witx_file.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let expected_dir = expected_root.join(stem);
+        let output_names = entry_names(&out_dir);
+        for output_name in &output_names {
+            let actual = fs::read_to_string(out_dir.join(output_name)).unwrap();
+            let expected_file = expected_dir.join(output_name);
+            if bless {
+                fs::create_dir_all(&expected_dir).unwrap();
+                fs::write(&expected_file, &actual).unwrap();
+                continue;
+            }
+            match fs::read_to_string(&expected_file) {
+                Ok(expected) if expected == actual => {}
+                Ok(expected) => mismatches.push(format!(
+                    "{}: differs from line {}",
+                    expected_file.display(),
+                    first_difference(&expected, &actual)
+                )),
+                Err(_) => mismatches.push(format!("{}: missing", expected_file.display())),
+            }
+        }
+
+        // Expected files that are no longer generated
+        for stale_name in entry_names(&expected_dir).difference(&output_names) {
+            let stale_file = expected_dir.join(stale_name);
+            if bless {
+                fs::remove_file(&stale_file).unwrap();
+            } else {
+                mismatches.push(format!("{}: not generated", stale_file.display()));
+            }
+        }
+    }
+
+    // Expected outputs of WITX files that no longer exist
+    for stale_name in entry_names(&expected_root).difference(&stems) {
+        let stale_dir = expected_root.join(stale_name);
+        if bless {
+            fs::remove_dir_all(&stale_dir).unwrap();
+        } else {
+            mismatches.push(format!("{}: no matching WITX file", stale_dir.display()));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "Outputs don't match the expected ones (run with BLESS=1 to update them):\n{}",
+        mismatches.join("\n")
+    );
+}
@@ -0,0 +1,29 @@
+
+// Guest implementation of the exported functions, appended to the generated bindings
+struct Guest;
+
+impl TestExportsExports for Guest {
+    fn on_tick() {}
+
+    fn interface_version() -> u32 {
+        1
+    }
+
+    fn on_data(_data: WasiPtr<u8>, data_len: Size) -> Result<Size, Errno> {
+        if data_len == 0 {
+            return Err(ERRNO::INVAL);
+        }
+        Ok(data_len)
+    }
+
+    fn dimensions() -> Result<(Size, Size), Errno> {
+        Ok((80, 25))
+    }
+
+    fn on_fatal(code: u32) -> ! {
+        log(b"fatal".as_ptr(), 5);
+        panic!("fatal error {}", code)
+    }
+}
+
+export_test_exports!(Guest);
@@ -0,0 +1,14 @@
+
+// Guest implementation of the exported functions, appended to the generated bindings
+struct Guest;
+
+impl TestKeywordsExports for Guest {
+    fn r#async(r#type: u32) -> Result<Type, Errno> {
+        if r#type == 0 {
+            return Err(ERRNO::INVAL);
+        }
+        Ok(r#type)
+    }
+}
+
+export_test_keywords!(Guest);
@@ -0,0 +1,27 @@
+//! Check that the layout of every type of the WITX files in `test/` matches the one computed by
+//! the `witx` crate.
+
+mod common;
+
+use std::process::Command;
+
+#[test]
+fn layout() {
+    for witx_file in common::witx_files() {
+        let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+            .arg("--verify-layout")
+            .arg("--output-type")
+            .arg("overview")
+            .arg("--output")
+            .arg(common::tmp_dir("layout").join("overview.txt"))
+            .arg(&witx_file)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}: {}",
+            witx_file.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
@@ -0,0 +1,52 @@
+
+// Tests of the conversions between tagged unions and enums
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn union_to_enum() {
+        let tu = SmallUnion::new_half_word(7);
+        match tu.as_enum().unwrap() {
+            SmallUnionRef::HalfWord(val) => assert_eq!(*val, 7),
+            SmallUnionRef::Word(_) => panic!("Unexpected case"),
+        }
+        assert!(matches!(
+            SmallUnionEnum::try_from(tu),
+            Ok(SmallUnionEnum::HalfWord(7))
+        ));
+        assert!(matches!(
+            SmallUnionRef::try_from(&tu),
+            Ok(SmallUnionRef::HalfWord(&7))
+        ));
+    }
+
+    #[test]
+    fn enum_to_union() {
+        let tu = SmallUnion::from(SmallUnionEnum::Word(0x12345678));
+        assert!(tu.is_word());
+        assert_eq!(tu.into_word(), 0x12345678);
+    }
+
+    #[test]
+    fn debug() {
+        let wrapped = WrappedUnion {
+            value: SmallUnion::new_word(3),
+        };
+        assert_eq!(
+            format!("{:?}", wrapped),
+            "WrappedUnion { value: Word(3) }"
+        );
+    }
+
+    #[test]
+    fn invalid_tag() {
+        let mut tu = SmallUnion::new_word(3);
+        tu.tag = 2;
+        assert!(matches!(tu.as_enum(), Err(WasiInvalidTag(2))));
+        assert_eq!(SmallUnionRef::try_from(&tu).err(), Some(WasiInvalidTag(2)));
+        assert_eq!(SmallUnionEnum::try_from(tu).err(), Some(WasiInvalidTag(2)));
+        assert_eq!(format!("{:?}", tu), "WasiInvalidTag(2)");
+    }
+}
@@ -0,0 +1,98 @@
+
+// Tests of the generated wrappers, using a mock implementation of the host functions
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct Host {
+        closed: Rc<RefCell<Vec<Fd>>>,
+    }
+
+    impl TestResultsMock for Host {
+        fn random_u32(&self) -> u32 {
+            4
+        }
+
+        fn fd_dup(&self, fd: Fd) -> Fd {
+            Fd(fd.0 + 1)
+        }
+
+        fn fd_close(&self, fd: Fd) -> Result<(), TestResultsError> {
+            if fd.0 < 0 {
+                return Err(TestResultsError::Badf);
+            }
+            self.closed.borrow_mut().push(fd);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn values() {
+        set_test_results_mock(Host::default());
+        assert_eq!(random_u32(), 4);
+        assert_eq!(fd_dup(Fd(3)), Fd(4));
+    }
+
+    #[test]
+    fn errors() {
+        set_test_results_mock(Host::default());
+        assert!(fd_close(Fd(3)).is_ok());
+        assert_eq!(fd_close(Fd(-1)), Err(TestResultsError::Badf));
+    }
+
+    #[test]
+    fn error_conversions() {
+        assert_eq!(TestResultsError::from(ERRNO::BADF), TestResultsError::Badf);
+        assert_eq!(TestResultsError::from(7), TestResultsError::Other(7));
+        assert_eq!(
+            TestResultsError::from(ERRNO::SUCCESS),
+            TestResultsError::Other(ERRNO::SUCCESS)
+        );
+        assert_eq!(Errno::from(TestResultsError::Badf), ERRNO::BADF);
+        assert_eq!(
+            Error::from(TestResultsError::Badf),
+            Error::WasiError(ERRNO::BADF as _)
+        );
+        assert_eq!(TestResultsError::Badf.to_string(), "Bad file descriptor.");
+        assert_eq!(TestResultsError::Other(7).to_string(), "Unknown error 7");
+    }
+
+    #[test]
+    fn owned_handles() {
+        let host = Host::default();
+        let closed = host.closed.clone();
+        set_test_results_mock(host);
+        drop(OwnedFd::new(Fd(5)));
+        OwnedFd::new(Fd(6)).release();
+        assert_eq!(*closed.borrow(), vec![Fd(5)]);
+    }
+
+    /// An implementation calling imported functions itself.
+    struct ReentrantHost;
+
+    impl TestResultsMock for ReentrantHost {
+        fn random_u32(&self) -> u32 {
+            4
+        }
+
+        fn fd_dup(&self, fd: Fd) -> Fd {
+            Fd(fd.0 + random_u32() as i32)
+        }
+    }
+
+    #[test]
+    fn reentrant() {
+        set_test_results_mock(ReentrantHost);
+        assert_eq!(fd_dup(Fd(3)), Fd(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "sched_yield")]
+    fn unimplemented() {
+        set_test_results_mock(Host::default());
+        sched_yield();
+    }
+}
@@ -0,0 +1,15 @@
+"""Define the imports of the host bindings for the module named on the command line, in a
+wasmtime-py linker."""
+
+import importlib
+import sys
+
+import wasmtime
+
+name = sys.argv[1]
+bindings = importlib.import_module(name + "_host")
+define = getattr(bindings, "define_%s_imports" % name, None)
+if define is not None:
+    host = getattr(bindings, "".join(part.capitalize() for part in name.split("_")) + "Host")
+    engine = wasmtime.Engine()
+    define(wasmtime.Linker(engine), wasmtime.Store(engine), host())
@@ -0,0 +1,66 @@
+"""Call the functions of `test/test_results.witx` from a guest instance, with wasmtime-py."""
+
+import unittest
+
+import wasmtime
+from test_results_host import *
+
+GUEST = """
+(module
+  (import "test_results" "sched_yield" (func $sched_yield))
+  (import "test_results" "random_u32" (func $random_u32 (result i32)))
+  (import "test_results" "fd_dup" (func $fd_dup (param i32) (result i32)))
+  (import "test_results" "fd_close" (func $fd_close (param i32) (result i32)))
+  (memory (export "memory") 1)
+  (func (export "sched_yield") (call $sched_yield))
+  (func (export "random_u32") (result i32) (call $random_u32))
+  (func (export "fd_dup") (param i32) (result i32) (call $fd_dup (local.get 0)))
+  (func (export "fd_close") (param i32) (result i32) (call $fd_close (local.get 0)))
+)
+"""
+
+
+class Host(TestResultsHost):
+    def sched_yield(self, memory):
+        memory.write(16, b"yield")
+        self.yielded = memory.read(16, 5)
+
+    def random_u32(self, memory):
+        return 4
+
+    def fd_dup(self, memory, fd):
+        return fd + 1
+
+    def fd_close(self, memory, fd):
+        if fd < 0:
+            raise WasiError(Errno.BADF)
+
+
+class TestResults(unittest.TestCase):
+    def setUp(self):
+        engine = wasmtime.Engine()
+        self.store = wasmtime.Store(engine)
+        self.host = Host()
+        linker = wasmtime.Linker(engine)
+        define_test_results_imports(linker, self.store, self.host)
+        module = wasmtime.Module(engine, GUEST)
+        self.exports = linker.instantiate(self.store, module).exports(self.store)
+
+    def call(self, name, *args):
+        return self.exports[name](self.store, *args)
+
+    def test_memory(self):
+        self.call("sched_yield")
+        self.assertEqual(self.host.yielded, b"yield")
+
+    def test_values(self):
+        self.assertEqual(self.call("random_u32"), 4)
+        self.assertEqual(self.call("fd_dup", 3), 4)
+
+    def test_errors(self):
+        self.assertEqual(self.call("fd_close", 3), 0)
+        self.assertEqual(self.call("fd_close", -1), Errno.BADF)
+
+
+if __name__ == "__main__":
+    unittest.main()
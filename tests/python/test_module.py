@@ -0,0 +1,53 @@
+"""Write the results of the functions of `test/test_module.witx` to guest memory, through the
+generated Python host bindings."""
+
+import ctypes
+import unittest
+
+import wasmtime
+from test_module_host import *
+
+MODULE = "test_module"
+
+
+class Host(TestModuleHost):
+    def a_function_that_returns_multiple_values(self, memory, some_parameter, some_other_parameter):
+        if not some_other_parameter:
+            raise WasiError(TestErrno.SOME_OTHER_ERROR)
+        return some_parameter * 2, len(some_other_parameter)
+
+    def a_function_that_returns_a_tagged_union(self, memory, str):
+        return TestTaggedUnion.new_third_choice(float(str))
+
+
+class TestModule(unittest.TestCase):
+    def setUp(self):
+        self.memory = wasmtime.Memory(64)
+        self.caller = wasmtime.Caller(self.memory)
+        self.linker = wasmtime.Linker()
+        define_test_module_imports(self.linker, wasmtime.Store(), Host())
+
+    def call(self, name, *args):
+        return self.linker.call(self.caller, MODULE, name, *args)
+
+    def test_multiple_values(self):
+        self.memory.data[0:5] = b"hello"
+        self.assertEqual(self.call("a_function_that_returns_multiple_values", 21, 0, 5, 8, 16), 0)
+        self.assertEqual(int.from_bytes(self.memory.data[8:12], "little"), 42)
+        self.assertEqual(int.from_bytes(self.memory.data[16:24], "little"), 5)
+
+    def test_error(self):
+        result = self.call("a_function_that_returns_multiple_values", 21, 0, 0, 8, 16)
+        self.assertEqual(result, TestErrno.SOME_OTHER_ERROR)
+        self.assertEqual(bytes(self.memory.data[8:24]), bytes(16))
+
+    def test_tagged_union(self):
+        self.memory.data[0:3] = b"1.5"
+        self.assertEqual(self.call("a_function_that_returns_a_tagged_union", 0, 3, 8), 0)
+        union = TestTaggedUnion.from_buffer_copy(self.memory.data[8:20])
+        self.assertEqual(union.tag, TestTaggedUnion.THIRD_CHOICE)
+        self.assertEqual(union.member.third_choice, 1.5)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,73 @@
+"""Call the functions of `test/test_results.witx` through the generated Python host bindings."""
+
+import ctypes
+import unittest
+
+import wasmtime
+from test_results_host import *
+
+MODULE = "test_results"
+
+
+class Host(TestResultsHost):
+    def proc_exit(self, memory, rval):
+        raise SystemExit(rval)
+
+    def sched_yield(self, memory):
+        self.yielded = True
+
+    def random_u32(self, memory):
+        return 4
+
+    def fd_flags(self, memory, fd):
+        return Fdflags.APPEND | Fdflags.NONBLOCK
+
+    def fd_dup(self, memory, fd):
+        return fd + 1
+
+    def fd_close(self, memory, fd):
+        if fd < 0:
+            raise WasiError(Errno.BADF)
+
+
+class TestResults(unittest.TestCase):
+    def setUp(self):
+        self.linker = wasmtime.Linker()
+        self.caller = wasmtime.Caller(wasmtime.Memory(64))
+        self.host = Host()
+        define_test_results_imports(self.linker, wasmtime.Store(), self.host)
+
+    def call(self, name, *args):
+        return self.linker.call(self.caller, MODULE, name, *args)
+
+    def test_signatures(self):
+        i32 = wasmtime.ValType.i32()
+        func = self.linker.funcs[(MODULE, "fd_close")]
+        self.assertEqual(func.ty.params, [i32])
+        self.assertEqual(func.ty.results, [i32])
+        func = self.linker.funcs[(MODULE, "sched_yield")]
+        self.assertEqual(func.ty.params, [])
+        self.assertEqual(func.ty.results, [])
+
+    def test_values(self):
+        self.call("sched_yield")
+        self.assertTrue(self.host.yielded)
+        self.assertEqual(self.call("random_u32"), 4)
+        self.assertEqual(self.call("fd_flags", 3), 3)
+        self.assertEqual(self.call("fd_dup", 3), 4)
+
+    def test_errors(self):
+        self.assertEqual(self.call("fd_close", 3), 0)
+        self.assertEqual(self.call("fd_close", -1), Errno.BADF)
+
+    def test_noreturn(self):
+        with self.assertRaises(SystemExit):
+            self.call("proc_exit", 1)
+
+    def test_not_implemented(self):
+        with self.assertRaises(NotImplementedError):
+            self.call("buffer_ptr")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,63 @@
+"""Check the layout of the types of `test/test_serde.witx` in the generated Python host bindings,
+and pass them to a host function."""
+
+import ctypes
+import unittest
+
+import wasmtime
+from test_serde_host import *
+
+MODULE = "test_serde"
+
+
+class Host(TestSerdeHost):
+    def draw(self, memory, shape, label):
+        self.drawn = (shape, label, memory.read_string(label.text.ptr, label.text.len))
+
+
+class TestSerde(unittest.TestCase):
+    def test_layout(self):
+        self.assertEqual(ctypes.sizeof(Pixel), 24)
+        self.assertEqual(Pixel.x.offset, 0)
+        self.assertEqual(Pixel.y.offset, 8)
+        self.assertEqual(Pixel.color.offset, 16)
+        self.assertEqual(Pixel.type.offset, 18)
+        self.assertEqual(ctypes.sizeof(Shape), 32)
+        self.assertEqual(Shape.member.offset, 8)
+        self.assertEqual(ctypes.sizeof(Shapes), 40)
+        self.assertEqual(Shapes.v1.offset, 32)
+        self.assertEqual(ctypes.sizeof(Label), 12)
+
+    def test_enums(self):
+        self.assertEqual(Color.LIGHT_BLUE, 2)
+        self.assertEqual(Permissions.READ | Permissions.EXECUTE, 5)
+
+    def test_union(self):
+        shape = Shape.new_circle(7)
+        self.assertEqual(shape.tag, Shape.CIRCLE)
+        self.assertEqual(shape.member.circle, 7)
+        self.assertEqual(Shape.new_empty().tag, Shape.EMPTY)
+
+    def test_call(self):
+        memory = wasmtime.Memory(256)
+        caller = wasmtime.Caller(memory)
+        linker = wasmtime.Linker()
+        host = Host()
+        define_test_serde_imports(linker, wasmtime.Store(), host)
+
+        pixel = Pixel(x=1, y=2, color=Color.GREEN, type=Permissions.WRITE)
+        memory.data[0:32] = bytes(Shape.new_point(pixel))
+        memory.data[64:69] = b"hello"
+        memory.data[32:44] = bytes(Label(text=WasiString(ptr=64, len=5), color=Color.RED))
+        linker.call(caller, MODULE, "draw", 0, 32)
+
+        shape, label, text = host.drawn
+        self.assertEqual(shape.tag, Shape.POINT)
+        self.assertEqual(shape.member.point.y, 2)
+        self.assertEqual(Color(shape.member.point.color), Color.GREEN)
+        self.assertEqual(label.color, Color.RED)
+        self.assertEqual(text, "hello")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,102 @@
+//! Run the tests of `tests/python/<name>.py` with Python, against the Python host bindings
+//! generated for `test/<name>.witx`.
+//!
+//! `tests/python/wasmtime.py` stands in for wasmtime-py, so that host functions can be called
+//! without a WebAssembly runtime. Bindings without tests are only imported. The tests are skipped
+//! if `python3` isn't installed.
+//!
+//! The bindings are also checked against the real wasmtime-py if it is installed: the tests of
+//! `tests/python-wasmtime/<name>.py` call them from a guest instance, and the imports of the other
+//! bindings are defined in a linker.
+
+mod common;
+
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::Command;
+
+fn python() -> Command {
+    Command::new(std::env::var_os("PYTHON").unwrap_or_else(|| "python3".into()))
+}
+
+/// Generate the Python host bindings for `witx_file` into `out_dir`.
+fn generate(out_dir: &Path, witx_file: &Path) {
+    let name = witx_file.file_stem().unwrap().to_string_lossy();
+    let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+        .arg("--output-type")
+        .arg("python-host")
+        .arg("--output")
+        .arg(out_dir.join(format!("{}_host.py", name)))
+        .arg(witx_file)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Generation failed for {}: {}",
+        witx_file.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Run `command` with `python_path` as the module search path.
+fn run(mut command: Command, python_path: &[&Path], witx_file: &Path) {
+    let python_path = std::env::join_paths(python_path.iter().map(|p| p.as_os_str()));
+    let output = command
+        .env("PYTHONPATH", python_path.unwrap())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Python host bindings for {} failed:\n{}",
+        witx_file.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn python_host() {
+    if python().arg("--version").output().is_err() {
+        eprintln!("python3 isn't installed, skipping the Python host tests");
+        return;
+    }
+    let out_dir = common::tmp_dir("python_host");
+    for witx_file in common::witx_files() {
+        let name = witx_file.file_stem().unwrap().to_string_lossy();
+        generate(&out_dir, &witx_file);
+
+        // Without tests, the bindings are only imported, which checks the definitions of the types
+        let test_file = Path::new("tests/python").join(format!("{}.py", name));
+        let mut command = python();
+        if test_file.exists() {
+            command.arg(&test_file);
+        } else {
+            command.arg("-c").arg(format!("import {}_host", name));
+        }
+        run(command, &[&out_dir, Path::new("tests/python")], &witx_file);
+    }
+}
+
+#[test]
+fn python_host_wasmtime() {
+    let installed = python().arg("-c").arg("import wasmtime").output();
+    if !installed.is_ok_and(|output| output.status.success()) {
+        eprintln!("wasmtime-py isn't installed, skipping the Python host tests against it");
+        return;
+    }
+    let out_dir = common::tmp_dir("python_host_wasmtime");
+    for witx_file in common::witx_files() {
+        let name = witx_file.file_stem().unwrap().to_string_lossy();
+        generate(&out_dir, &witx_file);
+
+        let test_file = Path::new("tests/python-wasmtime").join(format!("{}.py", name));
+        let mut command = python();
+        if test_file.exists() {
+            command.arg(&test_file);
+        } else {
+            command
+                .arg("tests/python-wasmtime/define_imports.py")
+                .arg(OsStr::new(name.as_ref()));
+        }
+        run(command, &[&out_dir], &witx_file);
+    }
+}
@@ -0,0 +1,138 @@
+//! Generate Rust bindings for the WITX files in `test/`, and check that they compile without
+//! warnings.
+//!
+//! Bindings are compiled for the first installed WASI target, which is required by these tests,
+//! so they are ignored by default: install it with `rustup target add wasm32-wasip1`, and run
+//! them with `cargo test --test rust_compile -- --include-ignored`. Bindings are also compiled
+//! for the host, where functions dispatch to mocks instead of WebAssembly imports.
+//!
+//! With `--no-std`, bindings are compiled as a `no_std` crate, with and without the `alloc`
+//! feature.
+//!
+//! Functions exported by the guest are implemented by `tests/guest/<name>.rs`, which is appended
+//! to the bindings generated for `test/<name>.witx`.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const WASI_TARGETS: &[&str] = &["wasm32-wasip1", "wasm32-wasi"];
+
+fn rustc() -> Command {
+    Command::new(std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into()))
+}
+
+/// Return the first WASI target the standard library is installed for.
+fn wasi_target() -> &'static str {
+    let output = rustc().arg("--print").arg("sysroot").output().unwrap();
+    let sysroot = PathBuf::from(String::from_utf8(output.stdout).unwrap().trim());
+    WASI_TARGETS
+        .iter()
+        .copied()
+        .find(|target| sysroot.join("lib").join("rustlib").join(target).is_dir())
+        .unwrap_or_else(|| {
+            panic!(
+                "None of the {:?} targets is installed, add one with `rustup target add {}`",
+                WASI_TARGETS, WASI_TARGETS[0]
+            )
+        })
+}
+
+fn check(rust_file: &Path, out_dir: &Path, target: Option<&str>, cfgs: &[&str]) {
+    let mut command = rustc();
+    command
+        .arg("--edition=2018")
+        .arg("--crate-type=lib")
+        .arg("--emit=metadata")
+        .arg("-Dwarnings")
+        .arg("--out-dir")
+        .arg(out_dir)
+        .arg(rust_file);
+    if let Some(target) = target {
+        command.arg("--target").arg(target);
+    }
+    for cfg in cfgs {
+        command.arg("--cfg").arg(cfg);
+    }
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{} doesn't compile with {:?}:\n{}",
+        rust_file.display(),
+        cfgs,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Generate the Rust bindings for `witx_file` into `out_dir`, with the given extra arguments,
+/// followed by the guest implementation of its exported functions, if there is one.
+/// `prefix` is written before the bindings.
+fn bindings(witx_file: &Path, out_dir: &Path, args: &[&str], prefix: &str) -> PathBuf {
+    let rust_file = out_dir
+        .join(witx_file.file_stem().unwrap())
+        .with_extension("rs");
+    let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+        .arg("--output-type")
+        .arg("rust")
+        .args(args)
+        .arg(witx_file)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Generation failed for {}: {}",
+        witx_file.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let mut source = prefix.to_string();
+    source.push_str(&String::from_utf8(output.stdout).unwrap());
+    let guest_file = Path::new("tests/guest")
+        .join(witx_file.file_stem().unwrap())
+        .with_extension("rs");
+    if guest_file.exists() {
+        source.push_str(&fs::read_to_string(&guest_file).unwrap());
+    }
+    fs::write(&rust_file, source).unwrap();
+    rust_file
+}
+
+fn compile(out_dir_name: &str, target: Option<&str>) {
+    let out_dir = common::tmp_dir(out_dir_name);
+    for witx_file in common::witx_files() {
+        let rust_file = bindings(&witx_file, &out_dir, &[], "");
+        check(&rust_file, &out_dir, target, &[]);
+    }
+}
+
+fn compile_no_std(out_dir_name: &str, target: Option<&str>) {
+    let out_dir = common::tmp_dir(out_dir_name);
+    for witx_file in common::witx_files() {
+        let rust_file = bindings(&witx_file, &out_dir, &["--no-std"], "#![no_std]\n");
+        check(&rust_file, &out_dir, target, &[]);
+        check(&rust_file, &out_dir, target, &["feature=\"alloc\""]);
+    }
+}
+
+#[test]
+#[ignore = "requires the wasm32-wasip1 target"]
+fn rust_compile() {
+    compile("rust_compile", Some(wasi_target()));
+}
+
+#[test]
+#[ignore = "requires the wasm32-wasip1 target"]
+fn rust_compile_no_std() {
+    compile_no_std("rust_compile_no_std", Some(wasi_target()));
+}
+
+#[test]
+fn rust_compile_host() {
+    compile("rust_compile_host", None);
+}
+
+#[test]
+fn rust_compile_host_no_std() {
+    compile_no_std("rust_compile_host_no_std", None);
+}
@@ -0,0 +1,60 @@
+//! Run the tests of `tests/mock/<name>.rs` natively, along with the Rust bindings generated for
+//! `test/<name>.witx`, using the mock implementations of the imported functions they install.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn rust_mock() {
+    let out_dir = common::tmp_dir("rust_mock");
+    for witx_file in common::witx_files() {
+        let name = witx_file.file_stem().unwrap();
+        let mock_file = Path::new("tests/mock").join(name).with_extension("rs");
+        if !mock_file.exists() {
+            continue;
+        }
+        let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+            .arg("--output-type")
+            .arg("rust")
+            .arg(&witx_file)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "Generation failed for {}: {}",
+            witx_file.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let mut source = String::from_utf8(output.stdout).unwrap();
+        source.push_str(&fs::read_to_string(&mock_file).unwrap());
+        let rust_file = out_dir.join(name).with_extension("rs");
+        fs::write(&rust_file, source).unwrap();
+
+        let test_bin = out_dir.join(name);
+        let output = Command::new(std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into()))
+            .arg("--edition=2018")
+            .arg("--test")
+            .arg("-Dwarnings")
+            .arg("-o")
+            .arg(&test_bin)
+            .arg(&rust_file)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{} doesn't compile:\n{}",
+            rust_file.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let output = Command::new(&test_bin).output().unwrap();
+        assert!(
+            output.status.success(),
+            "Tests failed for {}:\n{}",
+            witx_file.display(),
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+}
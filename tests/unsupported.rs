@@ -0,0 +1,38 @@
+//! Check that backends report types they can't represent as errors, instead of panicking.
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+const OUTPUT_TYPES: &[&str] = &["cpp", "python-host", "go-host"];
+
+/// A named `expected` type, that is only supported as the result of a function.
+const RESULT_TYPENAME_WITX: &str = r#"(module $unsupported_result
+  (typename $errno (enum (@witx tag u16) $success $inval))
+  (typename $res (expected u32 (error $errno)))
+)
+"#;
+
+#[test]
+fn unsupported_result_typename() {
+    let out_dir = common::tmp_dir("unsupported_result_typename");
+    let witx_file = out_dir.join("unsupported_result.witx");
+    fs::write(&witx_file, RESULT_TYPENAME_WITX).unwrap();
+    for output_type in OUTPUT_TYPES {
+        let output = Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+            .arg("--output-type")
+            .arg(output_type)
+            .arg(&witx_file)
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(1), "{}: {}", output_type, stderr);
+        assert!(
+            stderr.starts_with("Unsupported: type [res]"),
+            "{}: {}",
+            output_type,
+            stderr
+        );
+    }
+}
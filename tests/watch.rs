@@ -0,0 +1,82 @@
+//! Check that `--watch` regenerates the outputs when a WITX file, or a file it uses, changes.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Kills the watching process when the test ends, even if it fails.
+struct Watcher(Child);
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Wait until the content of `path` contains `pattern`, and return it.
+fn wait_for(path: &Path, pattern: &str) -> String {
+    let start = Instant::now();
+    loop {
+        let content = fs::read_to_string(path).unwrap_or_default();
+        if content.contains(pattern) {
+            return content;
+        }
+        assert!(
+            start.elapsed() < TIMEOUT,
+            "{} doesn't contain [{}]:\n{}",
+            path.display(),
+            pattern,
+            content
+        );
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn watch() {
+    let dir = common::tmp_dir("watch");
+    let used_file = dir.join("watch_used.witx");
+    let witx_file = dir.join("watch_main.witx");
+    let output_file = dir.join("overview.txt");
+    fs::write(&used_file, "(module $watch_used (typename $value u8))").unwrap();
+    fs::write(
+        &witx_file,
+        "(module $watch_main (use $value from $watch_used) (typename $values (list $value)))",
+    )
+    .unwrap();
+
+    let _watcher = Watcher(
+        Command::new(env!("CARGO_BIN_EXE_witx-codegen"))
+            .arg("--watch")
+            .arg("--output-type")
+            .arg("overview")
+            .arg("--output")
+            .arg(&output_file)
+            .arg(&witx_file)
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap(),
+    );
+    wait_for(&output_file, "u8");
+
+    // Changes to the used file are picked up
+    fs::write(&used_file, "(module $watch_used (typename $value u16))").unwrap();
+    wait_for(&output_file, "u16");
+
+    // Errors don't stop watching
+    fs::write(&witx_file, "(module $watch_main (typename").unwrap();
+    thread::sleep(Duration::from_secs(1));
+    fs::write(
+        &witx_file,
+        "(module $watch_main (use $value from $watch_used) (typename $count u32))",
+    )
+    .unwrap();
+    wait_for(&output_file, "u32");
+}